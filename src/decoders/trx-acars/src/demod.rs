@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! MSK tone demodulator for AM-detected audio.
+//!
+//! The audio carries 2400 bit/s MSK on 1200 and 2400 Hz tones.  It is mixed
+//! down around the 1800 Hz centre, low-pass filtered and passed through a
+//! phase discriminator, so the decision is independent of audio level.
+
+use std::f32::consts::{PI, TAU};
+
+use crate::BAUD;
+
+const CENTER_HZ: f32 = 1800.0;
+/// Low-pass cutoff; keeps the ±600 Hz tones and rejects the mixing images.
+const CUTOFF_HZ: f32 = 1800.0;
+/// Filter length in bit periods.
+const FILTER_BITS: f32 = 3.0;
+/// Proportional gain of the bit clock loop.
+const PLL_GAIN: f32 = 0.3;
+
+/// Turns audio into one tone decision per bit; `true` is the 2400 Hz tone.
+pub(crate) struct MskDemodulator {
+    samples_per_bit: f32,
+    phase: f32,
+    phase_inc: f32,
+    taps: Vec<f32>,
+    i_hist: Vec<f32>,
+    q_hist: Vec<f32>,
+    hist_idx: usize,
+    prev: (f32, f32),
+    window: Vec<f32>,
+    window_idx: usize,
+    window_sum: f32,
+    last_level: bool,
+    bit_phase: f32,
+}
+
+impl MskDemodulator {
+    pub(crate) fn new(sample_rate: u32) -> Self {
+        let sr = sample_rate as f32;
+        let samples_per_bit = sr / BAUD;
+        let len = (samples_per_bit * FILTER_BITS).round() as usize | 1;
+        let taps = lowpass_taps(len, CUTOFF_HZ / sr);
+        // The discriminator is averaged over half a bit, like an NRZ slicer.
+        let window = (samples_per_bit / 2.0).round().max(1.0) as usize;
+        Self {
+            samples_per_bit,
+            phase: 0.0,
+            phase_inc: TAU * CENTER_HZ / sr,
+            taps,
+            i_hist: vec![0.0; len],
+            q_hist: vec![0.0; len],
+            hist_idx: 0,
+            prev: (0.0, 0.0),
+            window: vec![0.0; window],
+            window_idx: 0,
+            window_sum: 0.0,
+            last_level: false,
+            bit_phase: samples_per_bit / 2.0,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.phase = 0.0;
+        self.i_hist.fill(0.0);
+        self.q_hist.fill(0.0);
+        self.hist_idx = 0;
+        self.prev = (0.0, 0.0);
+        self.window.fill(0.0);
+        self.window_idx = 0;
+        self.window_sum = 0.0;
+        self.last_level = false;
+        self.bit_phase = self.samples_per_bit / 2.0;
+    }
+
+    pub(crate) fn process(&mut self, samples: &[f32], tones: &mut Vec<bool>) {
+        let len = self.taps.len();
+        for &s in samples {
+            self.i_hist[self.hist_idx] = s * self.phase.cos();
+            self.q_hist[self.hist_idx] = -s * self.phase.sin();
+            self.hist_idx = (self.hist_idx + 1) % len;
+            self.phase += self.phase_inc;
+            if self.phase > TAU {
+                self.phase -= TAU;
+            }
+
+            let (mut i, mut q) = (0.0, 0.0);
+            for (k, tap) in self.taps.iter().enumerate() {
+                let idx = (self.hist_idx + k) % len;
+                i += tap * self.i_hist[idx];
+                q += tap * self.q_hist[idx];
+            }
+            // Phase step since the previous sample: positive above 1800 Hz.
+            let (pi, pq) = self.prev;
+            let freq = (q * pi - i * pq).atan2(i * pi + q * pq);
+            self.prev = (i, q);
+
+            self.window_sum += freq - self.window[self.window_idx];
+            self.window[self.window_idx] = freq;
+            self.window_idx = (self.window_idx + 1) % self.window.len();
+
+            // Pull the sampling instant towards mid-bit at every transition.
+            let level = self.window_sum > 0.0;
+            if level != self.last_level {
+                self.last_level = level;
+                let error = self.bit_phase - self.samples_per_bit / 2.0;
+                self.bit_phase -= PLL_GAIN * error;
+            }
+            self.bit_phase -= 1.0;
+            if self.bit_phase <= 0.0 {
+                self.bit_phase += self.samples_per_bit;
+                tones.push(level);
+            }
+        }
+    }
+}
+
+/// Hamming-windowed sinc with unity DC gain; `cutoff` is relative to the
+/// sample rate.
+fn lowpass_taps(len: usize, cutoff: f32) -> Vec<f32> {
+    let mid = (len - 1) as f32 / 2.0;
+    let mut taps: Vec<f32> = (0..len)
+        .map(|n| {
+            let x = n as f32 - mid;
+            let sinc = if x == 0.0 {
+                2.0 * cutoff
+            } else {
+                (TAU * cutoff * x).sin() / (PI * x)
+            };
+            let window = 0.54 - 0.46 * (TAU * n as f32 / (len - 1) as f32).cos();
+            sinc * window
+        })
+        .collect();
+    let sum: f32 = taps.iter().sum();
+    taps.iter_mut().for_each(|t| *t /= sum);
+    taps
+}
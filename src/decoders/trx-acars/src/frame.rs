@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Block synchronisation and validation.
+//!
+//! After the pre-key a block starts with `+`, `*`, SYN, SYN and SOH, and ends
+//! with ETX or ETB followed by a 16-bit block check sequence.  Characters are
+//! 7-bit ASCII with odd parity, sent least significant bit first.  The check
+//! is a reflected CCITT CRC over everything from the mode character up to and
+//! including the terminator.
+
+const SOH: u8 = 0x01;
+pub(crate) const ETX: u8 = 0x03;
+pub(crate) const ETB: u8 = 0x17;
+/// `+`, `*`, SYN, SYN with parity, as received LSB first into a 32-bit
+/// shift register.
+const SYNC_WORD: u32 = u32::from_le_bytes([0xab, 0x2a, 0x16, 0x16]);
+const SYNC_MAX_ERRORS: u32 = 2;
+/// SOH, 12 header characters, STX, 220 text characters and the terminator.
+const MAX_BLOCK_BYTES: usize = 236;
+
+/// A block that passed the check, parity stripped.
+#[derive(Debug, Clone)]
+pub(crate) struct RawBlock {
+    /// Characters from the mode character up to, not including, the
+    /// terminator.
+    pub(crate) chars: Vec<u8>,
+    pub(crate) terminator: u8,
+    pub(crate) corrected_bits: u32,
+}
+
+enum State {
+    Hunt,
+    /// Collecting characters; `bcs_left` counts check bytes still expected
+    /// once the terminator has been seen.
+    Block {
+        bcs_left: Option<u8>,
+    },
+}
+
+pub(crate) struct Deframer {
+    shreg: u32,
+    nbits: u32,
+    state: State,
+    bytes: Vec<u8>,
+}
+
+impl Deframer {
+    pub(crate) fn new() -> Self {
+        Self {
+            shreg: 0,
+            nbits: 0,
+            state: State::Hunt,
+            bytes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.shreg = 0;
+        self.nbits = 0;
+        self.state = State::Hunt;
+        self.bytes.clear();
+    }
+
+    pub(crate) fn push_bit(&mut self, bit: bool) -> Option<RawBlock> {
+        self.shreg = self.shreg >> 1 | u32::from(bit) << 31;
+        match self.state {
+            State::Hunt => {
+                if (self.shreg ^ SYNC_WORD).count_ones() <= SYNC_MAX_ERRORS {
+                    self.state = State::Block { bcs_left: None };
+                    self.bytes.clear();
+                    self.nbits = 0;
+                }
+                None
+            }
+            State::Block { bcs_left } => {
+                self.nbits += 1;
+                if self.nbits < 8 {
+                    return None;
+                }
+                self.nbits = 0;
+                let byte = (self.shreg >> 24) as u8;
+                self.bytes.push(byte);
+                if self.bytes.len() == 1 && byte != SOH {
+                    self.state = State::Hunt;
+                    return None;
+                }
+                match bcs_left {
+                    Some(1) => {
+                        self.state = State::Hunt;
+                        return check(&self.bytes);
+                    }
+                    Some(n) => {
+                        self.state = State::Block {
+                            bcs_left: Some(n - 1),
+                        }
+                    }
+                    None if self.bytes.len() > 1 && is_terminator(byte) => {
+                        self.state = State::Block { bcs_left: Some(2) };
+                    }
+                    None if self.bytes.len() >= MAX_BLOCK_BYTES => self.state = State::Hunt,
+                    None => {}
+                }
+                None
+            }
+        }
+    }
+}
+
+fn is_terminator(byte: u8) -> bool {
+    byte == with_parity(ETX) || byte == with_parity(ETB)
+}
+
+/// Validate SOH + body + terminator + BCS, repairing one bit when a single
+/// character fails parity.
+fn check(bytes: &[u8]) -> Option<RawBlock> {
+    let body = &bytes[1..];
+    let text_len = body.len() - 2;
+    let parity_errors: Vec<usize> = (0..text_len)
+        .filter(|&i| body[i].count_ones().is_multiple_of(2))
+        .collect();
+    let mut body = body.to_vec();
+    let corrected_bits = match parity_errors.as_slice() {
+        [] if crc16(&body) == 0 => 0,
+        [idx] => {
+            let idx = *idx;
+            let bit = (0..8).find(|bit| {
+                body[idx] ^= 1 << bit;
+                let ok = crc16(&body) == 0;
+                body[idx] ^= 1 << bit;
+                ok
+            })?;
+            body[idx] ^= 1 << bit;
+            1
+        }
+        _ => return None,
+    };
+    let chars = body[..text_len - 1].iter().map(|b| b & 0x7f).collect();
+    Some(RawBlock {
+        chars,
+        terminator: body[text_len - 1] & 0x7f,
+        corrected_bits,
+    })
+}
+
+pub(crate) fn with_parity(c: u8) -> u8 {
+    let c = c & 0x7f;
+    if c.count_ones().is_multiple_of(2) {
+        c | 0x80
+    } else {
+        c
+    }
+}
+
+/// CRC-16 with the reflected CCITT polynomial and zero initial value; the
+/// result over a block including its BCS is zero.
+pub(crate) fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |mut crc, &byte| {
+        crc ^= u16::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                crc >> 1 ^ 0x8408
+            } else {
+                crc >> 1
+            };
+        }
+        crc
+    })
+}
+
+/// Build the bytes of a complete transmission, pre-key to trailing DEL.
+#[cfg(test)]
+pub(crate) fn encode_transmission(body: &[u8], terminator: u8) -> Vec<u8> {
+    let mut out = vec![0xff; 16];
+    out.extend([b'+', b'*', 0x16, 0x16, SOH].map(with_parity));
+    let start = out.len();
+    out.extend(body.iter().map(|&c| with_parity(c)));
+    out.push(with_parity(terminator));
+    let crc = crc16(&out[start..]);
+    out.extend(crc.to_le_bytes());
+    out.push(with_parity(0x7f));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
+        bytes
+            .iter()
+            .flat_map(|&b| (0..8).map(move |i| b >> i & 1 == 1))
+    }
+
+    fn run(bytes: &[u8]) -> Vec<RawBlock> {
+        let mut deframer = Deframer::new();
+        bits(bytes).filter_map(|b| deframer.push_bit(b)).collect()
+    }
+
+    #[test]
+    fn crc_residue_is_zero() {
+        let tx = encode_transmission(b"2.N123AB\x15H11", ETX);
+        // Mode character through BCS.
+        assert_eq!(crc16(&tx[21..tx.len() - 1]), 0);
+    }
+
+    #[test]
+    fn finds_block_and_repairs_single_bit() {
+        let mut tx = encode_transmission(b"2.N123AB\x15H11\x02M01ABA0123HELLO", ETB);
+        let blocks = run(&tx);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].terminator, ETB);
+        assert_eq!(blocks[0].corrected_bits, 0);
+
+        tx[30] ^= 0x08;
+        let blocks = run(&tx);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].corrected_bits, 1);
+        assert_eq!(&blocks[0].chars[..8], b"2.N123AB");
+
+        tx[31] ^= 0x02;
+        assert!(run(&tx).is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! ACARS (ARINC 618) decoder.
+//!
+//! Consumes AM-demodulated audio from one VHF channel and decodes the
+//! 2400 bit/s MSK air-ground link.  Each data bit is carried by the tone
+//! change rather than the tone itself: 2400 Hz repeats the previous bit and
+//! 1200 Hz inverts it.  Blocks are validated with the 16-bit block check,
+//! repairing a single bit when one character fails parity.
+
+mod demod;
+mod frame;
+mod message;
+
+use demod::MskDemodulator;
+use frame::Deframer;
+use trx_core::decode::AcarsMessage;
+
+/// Bit rate of the air-ground link.
+pub(crate) const BAUD: f32 = 2400.0;
+
+/// Streaming ACARS decoder for one audio channel.
+pub struct AcarsDecoder {
+    demod: MskDemodulator,
+    tones: Vec<bool>,
+    data_bit: bool,
+    // The absolute bit polarity is lost in the differential coding, so both
+    // are tried.
+    deframers: [Deframer; 2],
+}
+
+impl AcarsDecoder {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            demod: MskDemodulator::new(sample_rate),
+            tones: Vec::new(),
+            data_bit: false,
+            deframers: [Deframer::new(), Deframer::new()],
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.demod.reset();
+        self.data_bit = false;
+        for deframer in &mut self.deframers {
+            deframer.reset();
+        }
+    }
+
+    pub fn process_samples(&mut self, samples: &[f32]) -> Vec<AcarsMessage> {
+        let mut out = Vec::new();
+        self.tones.clear();
+        self.demod.process(samples, &mut self.tones);
+        for &high in &self.tones {
+            self.data_bit ^= !high;
+            for (deframer, invert) in self.deframers.iter_mut().zip([false, true]) {
+                if let Some(block) = deframer.push_bit(self.data_bit ^ invert) {
+                    out.extend(message::parse(block));
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::TAU;
+
+    const SR: u32 = 48_000;
+
+    /// Phase-continuous MSK audio for a byte stream, LSB first.
+    fn modulate(bytes: &[u8]) -> Vec<f32> {
+        let spb = SR as f32 / BAUD;
+        let mut out = vec![0.0; SR as usize / 10];
+        let (mut phase, mut t, mut prev) = (0.0f32, 0.0f32, true);
+        for bit in bytes
+            .iter()
+            .flat_map(|&b| (0..8).map(move |i| b >> i & 1 == 1))
+        {
+            let freq = if bit == prev { 2400.0 } else { 1200.0 };
+            prev = bit;
+            t += spb;
+            let n = t.round() as usize - (t - spb).round() as usize;
+            for _ in 0..n {
+                out.push(0.3 * phase.sin());
+                phase = (phase + TAU * freq / SR as f32) % TAU;
+            }
+        }
+        out.extend(std::iter::repeat_n(0.0, SR as usize / 10));
+        out
+    }
+
+    fn decode(audio: &[f32]) -> Vec<AcarsMessage> {
+        let mut decoder = AcarsDecoder::new(SR);
+        audio
+            .chunks(960)
+            .flat_map(|chunk| decoder.process_samples(chunk))
+            .collect()
+    }
+
+    #[test]
+    fn decodes_downlink_block() {
+        let tx = frame::encode_transmission(
+            b"2.N123AB\x15H13\x02M01ABA0123#DFB/PIREP SMOOTH",
+            frame::ETX,
+        );
+        let msgs = decode(&modulate(&tx));
+        assert_eq!(msgs.len(), 1, "{msgs:?}");
+        let msg = &msgs[0];
+        assert_eq!(msg.registration.as_deref(), Some("N123AB"));
+        assert_eq!(msg.label, "H1");
+        assert_eq!(msg.flight.as_deref(), Some("BA0123"));
+        assert_eq!(msg.text.as_deref(), Some("#DFB/PIREP SMOOTH"));
+        assert_eq!(msg.corrected_bits, 0);
+    }
+
+    #[test]
+    fn decodes_with_noise_and_inverted_polarity() {
+        let tx = frame::encode_transmission(b"2.G-ABCDA_\x7fB", frame::ETX);
+        let inverted: Vec<u8> = tx.iter().map(|b| !b).collect();
+        let mut state = 0x1357_9bdfu32;
+        let audio: Vec<f32> = modulate(&inverted)
+            .into_iter()
+            .map(|s| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                s + (state as f32 / u32::MAX as f32 - 0.5) * 0.2
+            })
+            .collect();
+        let msgs = decode(&audio);
+        assert_eq!(msgs.len(), 1, "{msgs:?}");
+        assert_eq!(msgs[0].label, "_d");
+    }
+
+    #[test]
+    fn noise_decodes_nothing() {
+        let mut state = 0x2468_ace1u32;
+        let noise: Vec<f32> = (0..SR * 2)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 - 0.5) * 0.5
+            })
+            .collect();
+        assert!(decode(&noise).is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Header and text field extraction.
+//!
+//! The header is mode (1), address (7), technical acknowledgement (1),
+//! label (2) and block ID (1).  Text, when present, follows STX; downlink
+//! text starts with a 4-character sequence number and a 6-character flight
+//! number.
+
+use trx_core::decode::AcarsMessage;
+
+use crate::frame::{RawBlock, ETB};
+
+const STX: u8 = 0x02;
+const NAK: u8 = 0x15;
+const DEL: u8 = 0x7f;
+const HEADER_LEN: usize = 12;
+
+pub(crate) fn parse(block: RawBlock) -> Option<AcarsMessage> {
+    let chars = &block.chars;
+    if chars.len() < HEADER_LEN {
+        return None;
+    }
+    let mode = chars[0];
+    let label = &chars[9..11];
+    let block_id = chars[11];
+    if !mode.is_ascii_graphic()
+        || !block_id.is_ascii_graphic()
+        || !label.iter().all(|&c| c.is_ascii_graphic() || c == DEL)
+    {
+        return None;
+    }
+
+    let text = match chars.get(HEADER_LEN) {
+        None => "",
+        Some(&STX) => std::str::from_utf8(&chars[HEADER_LEN + 1..]).ok()?,
+        Some(_) => return None,
+    };
+    let downlink = block_id.is_ascii_digit();
+    let (msn, flight, text) = if downlink && text.len() >= 10 {
+        (Some(&text[..4]), Some(&text[4..10]), &text[10..])
+    } else {
+        (None, None, text)
+    };
+
+    let registration = String::from_utf8_lossy(&chars[1..8])
+        .trim_start_matches('.')
+        .trim()
+        .to_string();
+    let label: String = label
+        .iter()
+        .map(|&c| if c == DEL { 'd' } else { char::from(c) })
+        .collect();
+
+    Some(AcarsMessage {
+        rig_id: None,
+        ts_ms: None,
+        freq_hz: None,
+        mode: char::from(mode).to_string(),
+        registration: non_empty(&registration),
+        ack: (chars[8] != NAK).then(|| char::from(chars[8]).to_string()),
+        label,
+        block_id: char::from(block_id).to_string(),
+        downlink,
+        msn: msn.and_then(non_empty),
+        flight: flight.and_then(non_empty),
+        text: non_empty(&clean_text(text)),
+        more: block.terminator == ETB,
+        corrected_bits: block.corrected_bits,
+    })
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    let s = s.trim();
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+/// Normalise CR/LF line breaks and drop other control characters.
+fn clean_text(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .chars()
+        .filter(|&c| c == '\n' || !c.is_control())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::ETX;
+
+    fn block(chars: &[u8], terminator: u8) -> RawBlock {
+        RawBlock {
+            chars: chars.to_vec(),
+            terminator,
+            corrected_bits: 0,
+        }
+    }
+
+    #[test]
+    fn downlink_with_flight_number() {
+        let msg = parse(block(b"2.N123AB\x15H15\x02M01ABA0123POS N51 W001\r\n", ETB)).unwrap();
+        assert_eq!(msg.registration.as_deref(), Some("N123AB"));
+        assert_eq!(msg.ack, None);
+        assert_eq!(msg.label, "H1");
+        assert!(msg.downlink);
+        assert_eq!(msg.msn.as_deref(), Some("M01A"));
+        assert_eq!(msg.flight.as_deref(), Some("BA0123"));
+        assert_eq!(msg.text.as_deref(), Some("POS N51 W001"));
+        assert!(msg.more);
+    }
+
+    #[test]
+    fn uplink_general_response() {
+        let msg = parse(block(b"2.G-ABCDA_\x7fB", ETX)).unwrap();
+        assert_eq!(msg.registration.as_deref(), Some("G-ABCD"));
+        assert_eq!(msg.ack.as_deref(), Some("A"));
+        assert_eq!(msg.label, "_d");
+        assert!(!msg.downlink);
+        assert_eq!(msg.flight, None);
+        assert_eq!(msg.text, None);
+        assert!(!msg.more);
+    }
+}
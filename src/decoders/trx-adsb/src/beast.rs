@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Beast binary output, the raw-frame feed format understood by most
+//! ADS-B tools (port 30005 by convention).
+
+use crate::ModeSFrame;
+
+const ESCAPE: u8 = 0x1a;
+
+impl ModeSFrame {
+    /// Encode as `<1a> '2'|'3' <6-byte timestamp> <signal> <frame>`, with
+    /// every `0x1a` after the leading one doubled.
+    pub fn to_beast(&self) -> Vec<u8> {
+        let kind = if self.data.len() == 7 { b'2' } else { b'3' };
+        let signal = (self.signal.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        let mut body = Vec::with_capacity(7 + self.data.len());
+        body.extend_from_slice(&self.timestamp.to_be_bytes()[2..]);
+        body.push(signal);
+        body.extend_from_slice(&self.data);
+
+        let mut out = Vec::with_capacity(2 + body.len() * 2);
+        out.push(ESCAPE);
+        out.push(kind);
+        for byte in body {
+            out.push(byte);
+            if byte == ESCAPE {
+                out.push(ESCAPE);
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_sync_bytes() {
+        let frame = ModeSFrame {
+            data: vec![0x5d, 0x1a, 0x00, 0x01, 0x02, 0x03, 0x04],
+            timestamp: 0x1a_0000_0001,
+            signal: 1.0,
+            corrected_bits: 0,
+        };
+        assert_eq!(
+            frame.to_beast(),
+            vec![
+                0x1a, b'2', 0x00, 0x1a, 0x1a, 0x00, 0x00, 0x00, 0x01, 0xff, 0x5d, 0x1a, 0x1a, 0x00,
+                0x01, 0x02, 0x03, 0x04
+            ]
+        );
+    }
+}
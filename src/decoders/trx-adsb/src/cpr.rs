@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Compact Position Reporting for airborne positions.
+//!
+//! A single message only fixes the position within a zone; an even/odd
+//! pair received close together resolves it globally, after which single
+//! messages can be decoded relative to the last known position.
+
+use std::f64::consts::PI;
+
+const NZ: f64 = 15.0;
+const CPR_SCALE: f64 = 131_072.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CprFrame {
+    pub(crate) lat: u32,
+    pub(crate) lon: u32,
+}
+
+/// Number of longitude zones at a given latitude.
+fn nl(lat: f64) -> u32 {
+    let lat = lat.abs();
+    if lat < 1e-9 {
+        return 59;
+    }
+    if lat > 87.0 {
+        return 1;
+    }
+    let a = 1.0 - (PI / (2.0 * NZ)).cos();
+    let b = (lat.to_radians()).cos().powi(2);
+    (2.0 * PI / (1.0 - a / b).acos()).floor() as u32
+}
+
+/// Resolve a position from an even/odd pair; the most recent frame decides
+/// which latitude zone is reported.
+pub(crate) fn global_airborne(
+    even: CprFrame,
+    odd: CprFrame,
+    latest_odd: bool,
+) -> Option<(f64, f64)> {
+    let lat_e = f64::from(even.lat) / CPR_SCALE;
+    let lat_o = f64::from(odd.lat) / CPR_SCALE;
+    let lon_e = f64::from(even.lon) / CPR_SCALE;
+    let lon_o = f64::from(odd.lon) / CPR_SCALE;
+
+    let j = (59.0 * lat_e - 60.0 * lat_o + 0.5).floor();
+    let wrap = |lat: f64| if lat >= 270.0 { lat - 360.0 } else { lat };
+    let rlat_e = wrap(360.0 / 60.0 * (j.rem_euclid(60.0) + lat_e));
+    let rlat_o = wrap(360.0 / 59.0 * (j.rem_euclid(59.0) + lat_o));
+    if !(-90.0..=90.0).contains(&rlat_e) || !(-90.0..=90.0).contains(&rlat_o) {
+        return None;
+    }
+    // Both frames must lie in the same longitude zone band.
+    if nl(rlat_e) != nl(rlat_o) {
+        return None;
+    }
+
+    let (lat, lon_cpr, zones) = if latest_odd {
+        (rlat_o, lon_o, nl(rlat_o).saturating_sub(1).max(1))
+    } else {
+        (rlat_e, lon_e, nl(rlat_e))
+    };
+    let nl_lat = f64::from(nl(lat));
+    let zones = f64::from(zones);
+    let m = (lon_e * (nl_lat - 1.0) - lon_o * nl_lat + 0.5).floor();
+    let lon = 360.0 / zones * (m.rem_euclid(zones) + lon_cpr);
+    Some((lat, if lon >= 180.0 { lon - 360.0 } else { lon }))
+}
+
+/// Decode a single frame relative to a reference within 180 NM.
+pub(crate) fn local_airborne(reference: (f64, f64), frame: CprFrame, odd: bool) -> (f64, f64) {
+    let (ref_lat, ref_lon) = reference;
+    let i = if odd { 1.0 } else { 0.0 };
+    let lat_cpr = f64::from(frame.lat) / CPR_SCALE;
+    let lon_cpr = f64::from(frame.lon) / CPR_SCALE;
+
+    let dlat = 360.0 / (60.0 - i);
+    let j = (ref_lat / dlat).floor() + (ref_lat.rem_euclid(dlat) / dlat - lat_cpr + 0.5).floor();
+    let lat = dlat * (j + lat_cpr);
+
+    let dlon = 360.0 / (f64::from(nl(lat)) - i).max(1.0);
+    let m = (ref_lon / dlon).floor() + (ref_lon.rem_euclid(dlon) / dlon - lon_cpr + 0.5).floor();
+    (lat, dlon * (m + lon_cpr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVEN: CprFrame = CprFrame {
+        lat: 93_000,
+        lon: 51_372,
+    };
+    const ODD: CprFrame = CprFrame {
+        lat: 74_158,
+        lon: 50_194,
+    };
+
+    #[test]
+    fn zone_count() {
+        assert_eq!(nl(0.0), 59);
+        assert_eq!(nl(52.0), 36);
+        assert_eq!(nl(-52.0), 36);
+        assert_eq!(nl(88.0), 1);
+    }
+
+    #[test]
+    fn global_pair() {
+        let (lat, lon) = global_airborne(EVEN, ODD, false).unwrap();
+        assert!((lat - 52.257_20).abs() < 1e-4, "{lat}");
+        assert!((lon - 3.919_37).abs() < 1e-4, "{lon}");
+    }
+
+    #[test]
+    fn local_matches_global() {
+        let (lat, lon) = local_airborne((52.258, 3.918), EVEN, false);
+        assert!((lat - 52.257_20).abs() < 1e-4, "{lat}");
+        assert!((lon - 3.919_37).abs() < 1e-4, "{lon}");
+    }
+}
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Mode S CRC-24 and single-bit error correction.
+//!
+//! The last 24 bits of every frame are parity.  For DF11/17/18 they are the
+//! plain CRC of the preceding bits; for surveillance replies (DF0/4/5/16/20/21)
+//! the CRC is XORed with the ICAO address, so the residual *is* the address.
+
+use std::collections::HashMap;
+
+/// Generator polynomial x^24 + ... + 1 without the leading term.
+const GENERATOR: u32 = 0xff_f409;
+
+const TABLE: [u32; 256] = build_table();
+
+const fn build_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = (i as u32) << 16;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 0x80_0000 != 0 {
+                (c << 1) ^ GENERATOR
+            } else {
+                c << 1
+            };
+            k += 1;
+        }
+        table[i] = c & 0xff_ffff;
+        i += 1;
+    }
+    table
+}
+
+/// CRC of everything but the parity field, XORed with the parity field.
+pub(crate) fn residual(data: &[u8]) -> u32 {
+    let n = data.len() - 3;
+    let mut crc = 0u32;
+    for &byte in &data[..n] {
+        let idx = ((crc >> 16) as u8 ^ byte) as usize;
+        crc = ((crc << 8) ^ TABLE[idx]) & 0xff_ffff;
+    }
+    let parity = u32::from(data[n]) << 16 | u32::from(data[n + 1]) << 8 | u32::from(data[n + 2]);
+    crc ^ parity
+}
+
+/// Syndrome lookup for single-bit errors in 112-bit frames.
+pub(crate) struct Corrector {
+    syndromes: HashMap<u32, usize>,
+}
+
+impl Corrector {
+    pub(crate) fn new() -> Self {
+        // The first five bits hold the downlink format; flipping one of those
+        // would change the frame length, so they are never "corrected".
+        let syndromes = (5..112)
+            .map(|bit| {
+                let mut data = [0u8; 14];
+                data[bit / 8] ^= 0x80 >> (bit % 8);
+                (residual(&data), bit)
+            })
+            .collect();
+        Self { syndromes }
+    }
+
+    /// Validate a 112-bit frame, fixing one bit if needed.
+    ///
+    /// Returns the number of corrected bits, or `None` when the parity
+    /// does not check out.
+    pub(crate) fn fix_long(&self, data: &mut [u8]) -> Option<u8> {
+        let syndrome = residual(data);
+        if syndrome == 0 {
+            return Some(0);
+        }
+        let bit = *self.syndromes.get(&syndrome)?;
+        data[bit / 8] ^= 0x80 >> (bit % 8);
+        Some(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::hex;
+
+    #[test]
+    fn known_frames_have_zero_residual() {
+        assert_eq!(residual(&hex("8D4840D6202CC371C32CE0576098")), 0);
+        assert_eq!(residual(&hex("8D485020994409940838175B284F")), 0);
+    }
+
+    #[test]
+    fn corrects_any_single_bit_after_df() {
+        let corrector = Corrector::new();
+        let good = hex("8D4840D6202CC371C32CE0576098");
+        for bit in 5..112 {
+            let mut data = good.clone();
+            data[bit / 8] ^= 0x80 >> (bit % 8);
+            assert_eq!(corrector.fix_long(&mut data), Some(1), "bit {bit}");
+            assert_eq!(data, good);
+        }
+        let mut two = good.clone();
+        two[6] ^= 0x11;
+        assert_ne!(
+            corrector.fix_long(&mut two).map(|_| two.clone()),
+            Some(good)
+        );
+    }
+}
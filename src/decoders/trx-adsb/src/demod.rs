@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Preamble detection and PPM demodulation at 2 Msps.
+//!
+//! One Mode S bit lasts 1 us, i.e. two samples: a 1 puts the pulse in the
+//! first half, a 0 in the second.  The 8 us preamble has pulses at 0, 1.0,
+//! 3.5 and 4.5 us, which land exactly on samples 0, 2, 7 and 9.
+
+use num_complex::Complex;
+
+const PREAMBLE_SAMPLES: usize = 16;
+pub(crate) const LONG_BITS: usize = 112;
+pub(crate) const SHORT_BITS: usize = 56;
+const FRAME_SAMPLES: usize = PREAMBLE_SAMPLES + 2 * LONG_BITS;
+
+/// Pulses must stand this far above the quiet part of the preamble.
+const MIN_PULSE_TO_NOISE: f32 = 2.0;
+
+/// A demodulated frame that still has to pass parity.
+pub(crate) struct Candidate {
+    /// 14 bytes; short frames only use the first 7.
+    pub(crate) bytes: [u8; LONG_BITS / 8],
+    /// Absolute index of the first preamble sample.
+    pub(crate) sample: u64,
+    /// Mean preamble pulse magnitude.
+    pub(crate) level: f32,
+}
+
+pub(crate) struct Demodulator {
+    mag: Vec<f32>,
+    /// Absolute sample index of `mag[0]`.
+    base: u64,
+    /// Absolute index before which no new preamble is searched, so a frame
+    /// is not decoded twice.
+    resume_at: u64,
+}
+
+impl Demodulator {
+    pub(crate) fn new() -> Self {
+        Self {
+            mag: Vec::new(),
+            base: 0,
+            resume_at: 0,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.mag.clear();
+        self.resume_at = self.base;
+    }
+
+    /// Feed IQ samples; `accept` is called for every candidate and returns
+    /// the frame length in bits when the frame passed parity.
+    pub(crate) fn process(
+        &mut self,
+        iq: &[Complex<f32>],
+        mut accept: impl FnMut(&Candidate) -> Option<usize>,
+    ) {
+        self.mag.extend(iq.iter().map(|s| s.norm()));
+        if self.mag.len() < FRAME_SAMPLES {
+            return;
+        }
+        let last = self.mag.len() - FRAME_SAMPLES;
+        let mut i = (self.resume_at.saturating_sub(self.base) as usize).min(last + 1);
+        while i <= last {
+            let window = &self.mag[i..i + FRAME_SAMPLES];
+            let Some(level) = preamble_level(window) else {
+                i += 1;
+                continue;
+            };
+            let candidate = Candidate {
+                bytes: slice_bits(&window[PREAMBLE_SAMPLES..]),
+                sample: self.base + i as u64,
+                level,
+            };
+            match accept(&candidate) {
+                Some(bits) => i += PREAMBLE_SAMPLES + 2 * bits,
+                None => i += 1,
+            }
+        }
+        self.resume_at = self.base + i as u64;
+        self.mag.drain(..=last);
+        self.base += last as u64 + 1;
+    }
+}
+
+fn preamble_level(m: &[f32]) -> Option<f32> {
+    let pulses = [m[0], m[2], m[7], m[9]];
+    // Each pulse is a local peak ...
+    if m[1] >= m[0] || m[1] >= m[2] || m[3] >= m[2] || m[6] >= m[7] || m[8] >= m[7] || m[8] >= m[9]
+    {
+        return None;
+    }
+    // ... and the gaps between the pulse pairs are quiet.
+    let noise = (m[4] + m[5] + m[11] + m[12] + m[13] + m[14]) / 6.0;
+    let weakest = pulses.iter().copied().fold(f32::INFINITY, f32::min);
+    if weakest <= MIN_PULSE_TO_NOISE * noise {
+        return None;
+    }
+    Some(pulses.iter().sum::<f32>() / 4.0)
+}
+
+fn slice_bits(m: &[f32]) -> [u8; LONG_BITS / 8] {
+    let mut bytes = [0u8; LONG_BITS / 8];
+    for bit in 0..LONG_BITS {
+        if m[2 * bit] > m[2 * bit + 1] {
+            bytes[bit / 8] |= 0x80 >> (bit % 8);
+        }
+    }
+    bytes
+}
+
+/// Pulse-position modulate `bytes` with a preamble, for tests.
+#[cfg(test)]
+pub(crate) fn modulate(bytes: &[u8], amplitude: f32) -> Vec<Complex<f32>> {
+    let mut out = vec![Complex::new(0.0, 0.0); PREAMBLE_SAMPLES + bytes.len() * 16];
+    for idx in [0, 2, 7, 9] {
+        out[idx] = Complex::new(amplitude, 0.0);
+    }
+    for bit in 0..bytes.len() * 8 {
+        let one = bytes[bit / 8] & (0x80 >> (bit % 8)) != 0;
+        let idx = PREAMBLE_SAMPLES + 2 * bit + usize::from(!one);
+        out[idx] = Complex::new(0.0, amplitude);
+    }
+    out
+}
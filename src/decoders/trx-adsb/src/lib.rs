@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! ADS-B / Mode S decoder for 1090 MHz.
+//!
+//! Consumes complex baseband at exactly 2 Msps and performs:
+//! - magnitude conversion and preamble detection
+//! - pulse-position demodulation of 56- and 112-bit frames
+//! - CRC-24 validation with single-bit correction for extended squitters
+//! - DF17/18 identification, airborne position (CPR) and velocity decoding
+//! - DF4/5/20/21 altitude and squawk for already known aircraft
+//!
+//! Validated frames are also returned raw for Beast output and as
+//! BaseStation (SBS-1) lines, so external tools can be fed directly.
+
+mod beast;
+mod cpr;
+mod crc;
+mod demod;
+mod message;
+mod sbs;
+mod tracker;
+
+use num_complex::Complex;
+use trx_core::decode::AdsbMessage;
+
+use crc::Corrector;
+use demod::Demodulator;
+use tracker::Tracker;
+
+/// Required input sample rate.
+pub const SAMPLE_RATE: u32 = 2_000_000;
+
+/// Beast timestamps count a 12 MHz clock.
+const TICKS_PER_SAMPLE: u64 = 12_000_000 / SAMPLE_RATE as u64;
+
+/// A Mode S frame that passed parity.
+#[derive(Debug, Clone)]
+pub struct ModeSFrame {
+    /// 7 or 14 bytes, parity included.
+    pub data: Vec<u8>,
+    /// Receive time on a free-running 12 MHz clock.
+    pub timestamp: u64,
+    /// Preamble pulse magnitude, full scale 1.0.
+    pub signal: f32,
+    pub corrected_bits: u8,
+}
+
+#[derive(Debug, Default)]
+pub struct AdsbOutput {
+    pub frames: Vec<ModeSFrame>,
+    /// SBS-1 lines, CRLF-terminated.
+    pub sbs_lines: Vec<String>,
+    /// Aircraft whose state changed, at most once per second each.
+    pub aircraft: Vec<AdsbMessage>,
+}
+
+pub struct AdsbDecoder {
+    demod: Demodulator,
+    corrector: Corrector,
+    tracker: Tracker,
+}
+
+impl Default for AdsbDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdsbDecoder {
+    pub fn new() -> Self {
+        Self {
+            demod: Demodulator::new(),
+            corrector: Corrector::new(),
+            tracker: Tracker::new(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.demod.reset();
+        self.tracker.clear();
+    }
+
+    /// Process a block of 2 Msps IQ; `now_ms` is the wall-clock time used
+    /// for CPR pairing, aircraft expiry and SBS timestamps.
+    pub fn process_iq(&mut self, iq: &[Complex<f32>], now_ms: i64) -> AdsbOutput {
+        let mut out = AdsbOutput::default();
+        let Self {
+            demod,
+            corrector,
+            tracker,
+        } = self;
+        demod.process(iq, |candidate| {
+            let bits = message::frame_bits(message::downlink_format(&candidate.bytes));
+            let mut data = candidate.bytes[..bits / 8].to_vec();
+            let (icao, corrected_bits) = validate(corrector, tracker, &mut data, now_ms)?;
+            let rssi_db = 20.0 * candidate.level.max(1e-6).log10();
+            if let Some(line) = tracker.update(icao, message::parse(&data), rssi_db, now_ms) {
+                out.sbs_lines.push(line);
+            }
+            out.frames.push(ModeSFrame {
+                data,
+                timestamp: candidate.sample * TICKS_PER_SAMPLE,
+                signal: candidate.level,
+                corrected_bits,
+            });
+            Some(bits)
+        });
+        out.aircraft = tracker.take_updates(now_ms);
+        out
+    }
+}
+
+/// Check parity and recover the aircraft address.
+fn validate(
+    corrector: &Corrector,
+    tracker: &Tracker,
+    data: &mut [u8],
+    now_ms: i64,
+) -> Option<(u32, u8)> {
+    match message::downlink_format(data) {
+        17 | 18 => {
+            let corrected = corrector.fix_long(data)?;
+            Some((message::address(data), corrected))
+        }
+        11 => {
+            // The low seven residual bits may carry an interrogator code.
+            let residual = crc::residual(data);
+            let icao = message::address(data);
+            (residual == 0 || (residual < 0x80 && tracker.knows(icao, now_ms))).then_some((icao, 0))
+        }
+        0 | 4 | 5 | 16 | 20 | 21 => {
+            let icao = crc::residual(data);
+            tracker.knows(icao, now_ms).then_some((icao, 0))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const IDENT: &str = "8D4840D6202CC371C32CE0576098";
+    const VELOCITY: &str = "8D485020994409940838175B284F";
+    const POS_ODD: &str = "8D40621D58C386435CC412692AD6";
+    const POS_EVEN: &str = "8D40621D58C382D690C8AC2863A7";
+
+    fn transmission(frames: &[Vec<u8>]) -> Vec<Complex<f32>> {
+        let mut state = 0x2468_ace1u32;
+        let mut noise = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state as f32 / u32::MAX as f32 - 0.5) * 0.02
+        };
+        let mut iq = Vec::new();
+        for frame in frames {
+            iq.extend((0..300).map(|_| Complex::new(noise(), noise())));
+            iq.extend(
+                demod::modulate(frame, 0.3)
+                    .into_iter()
+                    .map(|s| s + Complex::new(noise(), noise())),
+            );
+        }
+        iq.extend((0..300).map(|_| Complex::new(noise(), noise())));
+        iq
+    }
+
+    fn run(iq: &[Complex<f32>]) -> (Vec<ModeSFrame>, Vec<String>, Vec<AdsbMessage>) {
+        let mut decoder = AdsbDecoder::new();
+        let (mut frames, mut lines, mut aircraft) = (Vec::new(), Vec::new(), Vec::new());
+        for (i, chunk) in iq.chunks(1_000).enumerate() {
+            let out = decoder.process_iq(chunk, i as i64);
+            frames.extend(out.frames);
+            lines.extend(out.sbs_lines);
+            aircraft.extend(out.aircraft);
+        }
+        aircraft.extend(decoder.process_iq(&[], 5_000).aircraft);
+        (frames, lines, aircraft)
+    }
+
+    #[test]
+    fn decodes_aircraft_across_block_boundaries() {
+        let iq = transmission(&[
+            hex(IDENT),
+            hex(VELOCITY),
+            hex(POS_ODD),
+            hex(IDENT),
+            hex(POS_EVEN),
+            hex(VELOCITY),
+        ]);
+        let (frames, lines, aircraft) = run(&iq);
+        assert_eq!(frames.len(), 6);
+        assert!(frames.iter().all(|f| f.corrected_bits == 0));
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("MSG,1,1,1,4840D6,"));
+
+        let klm = aircraft.iter().rfind(|a| a.icao == 0x48_40d6).unwrap();
+        assert_eq!(klm.callsign.as_deref(), Some("KLM1023"));
+        assert_eq!(klm.messages, 2);
+
+        let positioned = aircraft.iter().rfind(|a| a.icao == 0x40_621d).unwrap();
+        assert_eq!(positioned.altitude_ft, Some(38_000));
+        assert!((positioned.lat.unwrap() - 52.2572).abs() < 1e-3);
+        assert!((positioned.lon.unwrap() - 3.9194).abs() < 1e-3);
+
+        let moving = aircraft.iter().rfind(|a| a.icao == 0x48_5020).unwrap();
+        assert_eq!(moving.vertical_rate_fpm, Some(-832));
+    }
+
+    #[test]
+    fn corrects_single_bit_error() {
+        let mut damaged = hex(VELOCITY);
+        damaged[9] ^= 0x04;
+        let (frames, _, _) = run(&transmission(&[damaged]));
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].corrected_bits, 1);
+        assert_eq!(frames[0].data, hex(VELOCITY));
+    }
+
+    #[test]
+    fn surveillance_replies_need_a_known_address() {
+        // DF5 identity reply from 4840D6 with squawk 7500.
+        let mut reply = vec![0x28, 0x00, 0x0a, 0xa2, 0, 0, 0];
+        let parity = crc::residual(&reply) ^ 0x48_40d6;
+        reply[4..].copy_from_slice(&parity.to_be_bytes()[1..]);
+
+        let (frames, _, _) = run(&transmission(&[reply.clone()]));
+        assert!(frames.is_empty());
+
+        let (frames, lines, aircraft) = run(&transmission(&[hex(IDENT), reply]));
+        assert_eq!(frames.len(), 2);
+        assert!(lines[1].starts_with("MSG,6,1,1,4840D6,"));
+        assert_eq!(aircraft.last().unwrap().squawk, Some(7500));
+    }
+
+    #[test]
+    fn noise_decodes_nothing() {
+        let (frames, _, aircraft) = run(&transmission(&[]).repeat(500));
+        assert!(frames.is_empty());
+        assert!(aircraft.is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Field extraction for the downlink formats the tracker uses.
+//!
+//! Bit numbers follow ICAO Annex 10: bit 1 is the first transmitted bit,
+//! the ADS-B message (ME) field of DF17/18 starts at bit 33.
+
+use crate::cpr::CprFrame;
+
+const CALLSIGN_CHARS: &[u8; 64] =
+    b"#ABCDEFGHIJKLMNOPQRSTUVWXYZ##### ###############0123456789######";
+
+pub(crate) enum Content {
+    /// DF11 all-call reply; carries nothing but the address.
+    AllCall,
+    Identification {
+        callsign: String,
+    },
+    AirbornePosition {
+        altitude_ft: Option<i32>,
+        odd: bool,
+        cpr: CprFrame,
+    },
+    SurfacePosition,
+    Velocity {
+        ground_speed_kt: f32,
+        track_deg: f32,
+        vertical_rate_fpm: Option<i32>,
+    },
+    /// DF0/4/16/20 altitude reply.
+    Altitude {
+        altitude_ft: Option<i32>,
+    },
+    /// DF5/21 identity reply.
+    Identity {
+        squawk: u16,
+    },
+    Other,
+}
+
+pub(crate) struct Parsed {
+    pub(crate) on_ground: Option<bool>,
+    pub(crate) content: Content,
+}
+
+/// Read `len` bits starting at 1-based bit `first`.
+fn field(data: &[u8], first: usize, len: usize) -> u32 {
+    (first - 1..first - 1 + len).fold(0, |acc, bit| {
+        acc << 1 | u32::from(data[bit / 8] >> (7 - bit % 8) & 1)
+    })
+}
+
+pub(crate) fn downlink_format(data: &[u8]) -> u8 {
+    data[0] >> 3
+}
+
+/// Frame length in bits for a downlink format.
+pub(crate) fn frame_bits(df: u8) -> usize {
+    if df >= 16 {
+        crate::demod::LONG_BITS
+    } else {
+        crate::demod::SHORT_BITS
+    }
+}
+
+pub(crate) fn address(data: &[u8]) -> u32 {
+    field(data, 9, 24)
+}
+
+pub(crate) fn parse(data: &[u8]) -> Parsed {
+    let df = downlink_format(data);
+    match df {
+        11 | 17 => Parsed {
+            on_ground: capability_ground(field(data, 6, 3)),
+            content: if df == 11 {
+                Content::AllCall
+            } else {
+                parse_extended_squitter(data)
+            },
+        },
+        18 => Parsed {
+            on_ground: None,
+            content: parse_extended_squitter(data),
+        },
+        0 | 16 => Parsed {
+            on_ground: Some(field(data, 6, 1) == 1),
+            content: Content::Altitude {
+                altitude_ft: decode_ac13(field(data, 20, 13)),
+            },
+        },
+        4 | 20 => Parsed {
+            on_ground: flight_status_ground(field(data, 6, 3)),
+            content: Content::Altitude {
+                altitude_ft: decode_ac13(field(data, 20, 13)),
+            },
+        },
+        5 | 21 => Parsed {
+            on_ground: flight_status_ground(field(data, 6, 3)),
+            content: Content::Identity {
+                squawk: decode_id13(field(data, 20, 13)),
+            },
+        },
+        _ => Parsed {
+            on_ground: None,
+            content: Content::Other,
+        },
+    }
+}
+
+fn capability_ground(ca: u32) -> Option<bool> {
+    match ca {
+        4 => Some(true),
+        5 => Some(false),
+        _ => None,
+    }
+}
+
+fn flight_status_ground(fs: u32) -> Option<bool> {
+    match fs {
+        0 | 2 => Some(false),
+        1 | 3 => Some(true),
+        _ => None,
+    }
+}
+
+fn parse_extended_squitter(data: &[u8]) -> Content {
+    let tc = field(data, 33, 5);
+    match tc {
+        1..=4 => {
+            let callsign: String = (0..8)
+                .map(|i| char::from(CALLSIGN_CHARS[field(data, 41 + 6 * i, 6) as usize]))
+                .collect();
+            Content::Identification {
+                callsign: callsign.trim_end().to_string(),
+            }
+        }
+        5..=8 => Content::SurfacePosition,
+        9..=18 | 20..=22 => {
+            let alt = field(data, 41, 12);
+            let altitude_ft = if tc <= 18 {
+                decode_ac12(alt)
+            } else {
+                // GNSS height is sent in metres.
+                (alt != 0).then(|| (f64::from(alt) * 3.280_84).round() as i32)
+            };
+            Content::AirbornePosition {
+                altitude_ft,
+                odd: field(data, 54, 1) == 1,
+                cpr: CprFrame {
+                    lat: field(data, 55, 17),
+                    lon: field(data, 72, 17),
+                },
+            }
+        }
+        19 => parse_velocity(data),
+        _ => Content::Other,
+    }
+}
+
+fn parse_velocity(data: &[u8]) -> Content {
+    let subtype = field(data, 38, 3);
+    // Subtypes 3/4 report airspeed and heading rather than ground track.
+    let scale = match subtype {
+        1 => 1.0,
+        2 => 4.0,
+        _ => return Content::Other,
+    };
+    let v_ew = field(data, 47, 10);
+    let v_ns = field(data, 58, 10);
+    if v_ew == 0 || v_ns == 0 {
+        return Content::Other;
+    }
+    let sign = |negative: bool| if negative { -1.0 } else { 1.0 };
+    let vx = sign(field(data, 46, 1) == 1) * f64::from(v_ew - 1) * scale;
+    let vy = sign(field(data, 57, 1) == 1) * f64::from(v_ns - 1) * scale;
+    let vr = field(data, 70, 9);
+    let vertical_rate_fpm = (vr != 0).then(|| {
+        let rate = (vr as i32 - 1) * 64;
+        if field(data, 69, 1) == 1 {
+            -rate
+        } else {
+            rate
+        }
+    });
+    Content::Velocity {
+        ground_speed_kt: vx.hypot(vy) as f32,
+        track_deg: vx.atan2(vy).to_degrees().rem_euclid(360.0) as f32,
+        vertical_rate_fpm,
+    }
+}
+
+/// 25 ft altitude code with the Q bit removed; Gillham coding is not handled.
+fn q_altitude(n: u32) -> i32 {
+    n as i32 * 25 - 1000
+}
+
+/// 12-bit altitude field of airborne position messages.
+fn decode_ac12(alt: u32) -> Option<i32> {
+    if alt & 0x010 == 0 {
+        return None;
+    }
+    Some(q_altitude((alt & 0xfe0) >> 1 | alt & 0x00f))
+}
+
+/// 13-bit altitude code of surveillance replies (M bit 0x40, Q bit 0x10).
+fn decode_ac13(ac: u32) -> Option<i32> {
+    if ac & 0x40 != 0 || ac & 0x10 == 0 {
+        return None;
+    }
+    Some(q_altitude(
+        (ac & 0x1f80) >> 2 | (ac & 0x0020) >> 1 | ac & 0x000f,
+    ))
+}
+
+/// 13-bit identity code: C1 A1 C2 A2 C4 A4 X B1 D1 B2 D2 B4 D4.
+fn decode_id13(id: u32) -> u16 {
+    let bit = |n: u32| ((id >> n) & 1) as u16;
+    let a = bit(7) << 2 | bit(9) << 1 | bit(11);
+    let b = bit(1) << 2 | bit(3) << 1 | bit(5);
+    let c = bit(8) << 2 | bit(10) << 1 | bit(12);
+    let d = bit(0) << 2 | bit(2) << 1 | bit(4);
+    a * 1000 + b * 100 + c * 10 + d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::hex;
+
+    #[test]
+    fn identification() {
+        let data = hex("8D4840D6202CC371C32CE0576098");
+        assert_eq!(address(&data), 0x48_40d6);
+        match parse(&data).content {
+            Content::Identification { callsign } => assert_eq!(callsign, "KLM1023"),
+            _ => panic!("expected identification"),
+        }
+    }
+
+    #[test]
+    fn airborne_position_and_altitude() {
+        let data = hex("8D40621D58C382D690C8AC2863A7");
+        match parse(&data).content {
+            Content::AirbornePosition {
+                altitude_ft,
+                odd,
+                cpr,
+            } => {
+                assert_eq!(altitude_ft, Some(38_000));
+                assert!(!odd);
+                assert_eq!((cpr.lat, cpr.lon), (93_000, 51_372));
+            }
+            _ => panic!("expected position"),
+        }
+    }
+
+    #[test]
+    fn ground_speed_velocity() {
+        match parse(&hex("8D485020994409940838175B284F")).content {
+            Content::Velocity {
+                ground_speed_kt,
+                track_deg,
+                vertical_rate_fpm,
+            } => {
+                assert!((ground_speed_kt - 159.20).abs() < 0.01);
+                assert!((track_deg - 182.88).abs() < 0.01);
+                assert_eq!(vertical_rate_fpm, Some(-832));
+            }
+            _ => panic!("expected velocity"),
+        }
+    }
+
+    #[test]
+    fn identity_code() {
+        // A=7 B=5 C=0 D=0
+        assert_eq!(decode_id13(0b0_1010_1010_0010), 7500);
+        assert_eq!(decode_ac13(0), None);
+    }
+}
@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! BaseStation (SBS-1) text output, as served on port 30003 by most
+//! Mode S receivers.
+//!
+//! Each line has 22 comma-separated fields; only the ones that belong to
+//! the transmission type are filled in.
+
+use trx_core::decode::AdsbMessage;
+
+pub(crate) const IDENTIFICATION: u8 = 1;
+pub(crate) const AIRBORNE_POSITION: u8 = 3;
+pub(crate) const AIRBORNE_VELOCITY: u8 = 4;
+pub(crate) const SURVEILLANCE_ALTITUDE: u8 = 5;
+pub(crate) const SURVEILLANCE_ID: u8 = 6;
+pub(crate) const ALL_CALL: u8 = 8;
+
+pub(crate) fn format_line(kind: u8, state: &AdsbMessage, now_ms: i64) -> String {
+    let (date, time) = date_time(now_ms);
+    let text = |value: Option<String>| value.unwrap_or_default();
+
+    let callsign = (kind == IDENTIFICATION)
+        .then(|| state.callsign.clone())
+        .flatten();
+    let altitude = matches!(
+        kind,
+        AIRBORNE_POSITION | SURVEILLANCE_ALTITUDE | SURVEILLANCE_ID
+    )
+    .then_some(state.altitude_ft)
+    .flatten();
+    let velocity = kind == AIRBORNE_VELOCITY;
+    let position = kind == AIRBORNE_POSITION;
+    let ground = matches!(
+        kind,
+        AIRBORNE_POSITION | SURVEILLANCE_ALTITUDE | SURVEILLANCE_ID | ALL_CALL
+    )
+    .then_some(if state.on_ground { "-1" } else { "0" })
+    .unwrap_or_default();
+
+    format!(
+        "MSG,{kind},1,1,{:06X},1,{date},{time},{date},{time},{},{},{},{},{},{},{},{},,,,{ground}\r\n",
+        state.icao,
+        text(callsign),
+        text(altitude.map(|a| a.to_string())),
+        text(velocity.then_some(state.ground_speed_kt).flatten().map(|v| format!("{v:.0}"))),
+        text(velocity.then_some(state.track_deg).flatten().map(|v| format!("{v:.0}"))),
+        text(position.then_some(state.lat).flatten().map(|v| format!("{v:.5}"))),
+        text(position.then_some(state.lon).flatten().map(|v| format!("{v:.5}"))),
+        text(velocity.then_some(state.vertical_rate_fpm).flatten().map(|v| v.to_string())),
+        text((kind == SURVEILLANCE_ID).then_some(state.squawk).flatten().map(|s| format!("{s:04}"))),
+    )
+}
+
+/// UTC `yyyy/mm/dd` and `hh:mm:ss.sss` for a Unix timestamp in milliseconds.
+fn date_time(ms: i64) -> (String, String) {
+    let days = ms.div_euclid(86_400_000);
+    let rem = ms.rem_euclid(86_400_000);
+    // Days-to-civil conversion from Howard Hinnant's date algorithms.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (
+        format!("{year:04}/{month:02}/{day:02}"),
+        format!(
+            "{:02}:{:02}:{:02}.{:03}",
+            rem / 3_600_000,
+            rem / 60_000 % 60,
+            rem / 1_000 % 60,
+            rem % 1_000
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        assert_eq!(
+            date_time(0),
+            ("1970/01/01".to_string(), "00:00:00.000".to_string())
+        );
+        assert_eq!(
+            date_time(1_700_000_000_123),
+            ("2023/11/14".to_string(), "22:13:20.123".to_string())
+        );
+        assert_eq!(date_time(951_782_400_000).0, "2000/02/29");
+    }
+
+    #[test]
+    fn lines_fill_only_relevant_fields() {
+        let state = AdsbMessage {
+            icao: 0x48_40d6,
+            callsign: Some("KLM1023".to_string()),
+            altitude_ft: Some(38_000),
+            lat: Some(52.2572),
+            lon: Some(3.91937),
+            squawk: Some(1200),
+            ..AdsbMessage::default()
+        };
+        assert_eq!(
+            format_line(IDENTIFICATION, &state, 1_700_000_000_000),
+            "MSG,1,1,1,4840D6,1,2023/11/14,22:13:20.000,2023/11/14,22:13:20.000,KLM1023,,,,,,,,,,,\r\n"
+        );
+        assert_eq!(
+            format_line(AIRBORNE_POSITION, &state, 1_700_000_000_000),
+            "MSG,3,1,1,4840D6,1,2023/11/14,22:13:20.000,2023/11/14,22:13:20.000,,38000,,,52.25720,3.91937,,,,,,0\r\n"
+        );
+        assert!(format_line(SURVEILLANCE_ID, &state, 0).contains(",38000,,,,,,1200,,,,0\r\n"));
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Per-aircraft state assembled from individual frames.
+
+use std::collections::HashMap;
+
+use trx_core::decode::AdsbMessage;
+
+use crate::cpr::{self, CprFrame};
+use crate::message::{Content, Parsed};
+use crate::sbs;
+
+/// Aircraft not heard for this long are dropped.
+const AIRCRAFT_TIMEOUT_MS: i64 = 60_000;
+/// Even and odd CPR frames further apart than this are not paired.
+const CPR_PAIR_WINDOW_MS: i64 = 10_000;
+/// A position older than this is not trusted as a local-decode reference.
+const LOCAL_REFERENCE_MAX_AGE_MS: i64 = 30_000;
+/// Minimum spacing of state updates per aircraft.
+const UPDATE_INTERVAL_MS: i64 = 1_000;
+/// Aircraft are only reported once a second frame has confirmed them.
+const MIN_MESSAGES: u32 = 2;
+
+struct Aircraft {
+    state: AdsbMessage,
+    last_seen_ms: i64,
+    even: Option<(CprFrame, i64)>,
+    odd: Option<(CprFrame, i64)>,
+    position_ms: Option<i64>,
+    last_emit_ms: Option<i64>,
+    dirty: bool,
+}
+
+impl Aircraft {
+    fn new(icao: u32) -> Self {
+        Self {
+            state: AdsbMessage {
+                icao,
+                ..AdsbMessage::default()
+            },
+            last_seen_ms: 0,
+            even: None,
+            odd: None,
+            position_ms: None,
+            last_emit_ms: None,
+            dirty: false,
+        }
+    }
+
+    fn apply_position(&mut self, frame: CprFrame, odd: bool, now_ms: i64) {
+        if odd {
+            self.odd = Some((frame, now_ms));
+        } else {
+            self.even = Some((frame, now_ms));
+        }
+        let global = match (self.even, self.odd) {
+            (Some((even, t_even)), Some((odd_frame, t_odd)))
+                if (t_even - t_odd).abs() <= CPR_PAIR_WINDOW_MS =>
+            {
+                cpr::global_airborne(even, odd_frame, odd)
+            }
+            _ => None,
+        };
+        let position = global.or_else(|| {
+            let reference = self.state.lat.zip(self.state.lon)?;
+            let fresh = self
+                .position_ms
+                .is_some_and(|ts| now_ms - ts <= LOCAL_REFERENCE_MAX_AGE_MS);
+            fresh.then(|| cpr::local_airborne(reference, frame, odd))
+        });
+        if let Some((lat, lon)) = position {
+            self.state.lat = Some(lat);
+            self.state.lon = Some(lon);
+            self.position_ms = Some(now_ms);
+        }
+    }
+}
+
+pub(crate) struct Tracker {
+    aircraft: HashMap<u32, Aircraft>,
+}
+
+impl Tracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            aircraft: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.aircraft.clear();
+    }
+
+    /// Whether `icao` was heard recently; surveillance replies only carry
+    /// the address folded into their parity, so they are accepted for known
+    /// aircraft only.
+    pub(crate) fn knows(&self, icao: u32, now_ms: i64) -> bool {
+        self.aircraft
+            .get(&icao)
+            .is_some_and(|a| now_ms - a.last_seen_ms <= AIRCRAFT_TIMEOUT_MS)
+    }
+
+    /// Apply a validated frame and return the matching SBS-1 line.
+    pub(crate) fn update(
+        &mut self,
+        icao: u32,
+        parsed: Parsed,
+        rssi_db: f32,
+        now_ms: i64,
+    ) -> Option<String> {
+        let aircraft = self
+            .aircraft
+            .entry(icao)
+            .or_insert_with(|| Aircraft::new(icao));
+        aircraft.last_seen_ms = now_ms;
+        aircraft.dirty = true;
+        aircraft.state.messages = aircraft.state.messages.saturating_add(1);
+        aircraft.state.rssi_db = Some(rssi_db);
+        if let Some(on_ground) = parsed.on_ground {
+            aircraft.state.on_ground = on_ground;
+        }
+
+        let kind = match parsed.content {
+            Content::AllCall => sbs::ALL_CALL,
+            Content::Identification { callsign } => {
+                if !callsign.is_empty() {
+                    aircraft.state.callsign = Some(callsign);
+                }
+                sbs::IDENTIFICATION
+            }
+            Content::AirbornePosition {
+                altitude_ft,
+                odd,
+                cpr,
+            } => {
+                if altitude_ft.is_some() {
+                    aircraft.state.altitude_ft = altitude_ft;
+                }
+                aircraft.state.on_ground = false;
+                aircraft.apply_position(cpr, odd, now_ms);
+                sbs::AIRBORNE_POSITION
+            }
+            Content::SurfacePosition => {
+                aircraft.state.on_ground = true;
+                return None;
+            }
+            Content::Velocity {
+                ground_speed_kt,
+                track_deg,
+                vertical_rate_fpm,
+            } => {
+                aircraft.state.ground_speed_kt = Some(ground_speed_kt);
+                aircraft.state.track_deg = Some(track_deg);
+                aircraft.state.vertical_rate_fpm = vertical_rate_fpm;
+                sbs::AIRBORNE_VELOCITY
+            }
+            Content::Altitude { altitude_ft } => {
+                if altitude_ft.is_some() {
+                    aircraft.state.altitude_ft = altitude_ft;
+                }
+                sbs::SURVEILLANCE_ALTITUDE
+            }
+            Content::Identity { squawk } => {
+                aircraft.state.squawk = Some(squawk);
+                sbs::SURVEILLANCE_ID
+            }
+            Content::Other => return None,
+        };
+        Some(sbs::format_line(kind, &aircraft.state, now_ms))
+    }
+
+    /// Expire silent aircraft and return those due for an update.
+    pub(crate) fn take_updates(&mut self, now_ms: i64) -> Vec<AdsbMessage> {
+        self.aircraft
+            .retain(|_, a| now_ms - a.last_seen_ms <= AIRCRAFT_TIMEOUT_MS);
+        let mut out: Vec<AdsbMessage> = self
+            .aircraft
+            .values_mut()
+            .filter(|a| {
+                a.dirty
+                    && a.state.messages >= MIN_MESSAGES
+                    && a.last_emit_ms
+                        .is_none_or(|ts| now_ms - ts >= UPDATE_INTERVAL_MS)
+            })
+            .map(|a| {
+                a.dirty = false;
+                a.last_emit_ms = Some(now_ms);
+                AdsbMessage {
+                    ts_ms: Some(now_ms),
+                    ..a.state.clone()
+                }
+            })
+            .collect();
+        out.sort_by_key(|msg| msg.icao);
+        out
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Callsign extraction from decoded CW text.
+
+/// Whether `token` looks like an amateur callsign, optionally with a
+/// `/P`-style suffix or a country prefix (`DL/K1ABC`).
+pub fn is_callsign(token: &str) -> bool {
+    if !(3..=12).contains(&token.len())
+        || !token
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'/')
+    {
+        return false;
+    }
+    let Some(base) = token.split('/').max_by_key(|part| part.len()) else {
+        return false;
+    };
+    if !(3..=7).contains(&base.len()) {
+        return false;
+    }
+    // Prefix (one to three characters ending in a digit), then a one to
+    // four letter suffix: K1ABC, DL1XYZ, 2E0ABC, 9A1A.
+    let Some(digit) = base.rfind(|c: char| c.is_ascii_digit()) else {
+        return false;
+    };
+    let (prefix, suffix) = (&base[..digit], &base[digit + 1..]);
+    (1..=3).contains(&prefix.len())
+        && prefix.bytes().any(|b| b.is_ascii_uppercase())
+        && (1..=4).contains(&suffix.len())
+        && suffix.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+/// Callsigns announced in `text`, with the kind of announcement.
+///
+/// `CQ [TEST|DX|...] CALL` and `TEST CALL` / `CALL TEST` are reported as
+/// `"CQ"`, `DE CALL` as `"DE"`.
+pub fn find_spot_calls(text: &str) -> Vec<(String, &'static str)> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut found: Vec<(String, &'static str)> = Vec::new();
+    let mut push = |call: &str, kind: &'static str| {
+        if !found.iter().any(|(c, _)| c == call) {
+            found.push((call.to_string(), kind));
+        }
+    };
+    for (i, word) in words.iter().enumerate() {
+        match *word {
+            "CQ" => {
+                // Skip repeated CQs and qualifiers such as TEST or DX.
+                if let Some(call) = words[i + 1..]
+                    .iter()
+                    .take(4)
+                    .find(|w| !matches!(**w, "CQ" | "TEST" | "DX" | "DE"))
+                    .filter(|w| is_callsign(w))
+                {
+                    push(call, "CQ");
+                }
+            }
+            "TEST" => {
+                if let Some(call) = words.get(i + 1).filter(|w| is_callsign(w)) {
+                    push(call, "CQ");
+                } else if let Some(call) = i
+                    .checked_sub(1)
+                    .and_then(|p| words.get(p))
+                    .filter(|w| is_callsign(w))
+                {
+                    push(call, "CQ");
+                }
+            }
+            "DE" => {
+                if let Some(call) = words.get(i + 1).filter(|w| is_callsign(w)) {
+                    push(call, "DE");
+                }
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_common_callsign_shapes() {
+        for call in [
+            "K1ABC", "DL1XYZ", "2E0ABC", "9A1A", "K1ABC/P", "DL/K1ABC", "VK2AB",
+        ] {
+            assert!(is_callsign(call), "{call}");
+        }
+        for word in [
+            "TEST", "5NN", "599", "73", "TU", "CQ", "K1", "ABCDEFG", "K1ABCDE",
+        ] {
+            assert!(!is_callsign(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn finds_cq_test_and_de_patterns() {
+        assert_eq!(
+            find_spot_calls("CQ CQ TEST K1ABC K1ABC"),
+            vec![("K1ABC".to_string(), "CQ")]
+        );
+        assert_eq!(
+            find_spot_calls("DL1XYZ TEST"),
+            vec![("DL1XYZ".to_string(), "CQ")]
+        );
+        assert_eq!(
+            find_spot_calls("K1ABC DE DL1XYZ 5NN"),
+            vec![("DL1XYZ".to_string(), "DE")]
+        );
+        assert!(find_spot_calls("TU 5NN 14 K").is_empty());
+    }
+}
@@ -4,10 +4,17 @@
 
 //! Goertzel-based CW (Morse code) decoder.
 //!
-//! Ported from the browser-side JavaScript implementation.
+//! Ported from the browser-side JavaScript implementation.  [`CwSkimmer`]
+//! runs one decoder per CW signal found across an SDR passband.
+
+mod callsign;
+mod skimmer;
 
 use trx_core::decode::CwEvent;
 
+pub use callsign::{find_spot_calls, is_callsign};
+pub use skimmer::{CwSkimmer, SkimmerSpot};
+
 // ITU Morse code lookup
 fn morse_lookup(code: &str) -> Option<char> {
     match code {
@@ -334,6 +341,10 @@ impl CwDecoder {
         }
 
         let detected = self.goertzel_detect();
+        self.apply_key_state(detected);
+    }
+
+    fn apply_key_state(&mut self, detected: bool) {
         let now = self.now_ms();
 
         // Emit signal state event on transitions
@@ -420,6 +431,14 @@ impl CwDecoder {
         std::mem::take(&mut self.events)
     }
 
+    /// Feed the key state of one 10 ms detection window, for callers that
+    /// detect keying themselves (e.g. the skimmer's per-signal envelope).
+    pub fn push_key_state(&mut self, key_down: bool) -> Vec<CwEvent> {
+        self.sample_counter += self.window_size as u64;
+        self.apply_key_state(key_down);
+        std::mem::take(&mut self.events)
+    }
+
     pub fn reset(&mut self) {
         let tone = self.tone_freq;
         let wpm = self.wpm;
@@ -0,0 +1,766 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Multi-signal CW skimmer.
+//!
+//! Baseband IQ is mixed to the skimmer window and decimated to the window
+//! span.  A periodic FFT scan finds narrow carriers above the noise floor and
+//! opens a channel on each: the channel filters its signal down to about
+//! 100 Hz, keys on the envelope with an adaptive threshold and feeds a
+//! [`CwDecoder`] that tracks the sending speed.  Callsigns announced with
+//! CQ/TEST/DE are reported once they have been copied twice.
+
+use std::f32::consts::PI;
+use std::sync::Arc;
+
+use num_complex::Complex32;
+use rustfft::{Fft, FftPlanner};
+
+use crate::callsign::find_spot_calls;
+use crate::CwDecoder;
+
+/// Upper bound on simultaneously decoded signals.
+const MAX_CHANNELS: usize = 32;
+/// Peak bin power above the noise floor required to open a channel.
+const DEFAULT_SNR_THRESHOLD_DB: f32 = 10.0;
+/// Approximate FFT resolution of the scanner (Hz).
+const SCAN_BIN_HZ: f32 = 15.0;
+/// Fraction of the window searched; the edges alias through the decimator.
+const SCAN_SPAN_RATIO: f32 = 0.8;
+/// Scans averaged before the first channel is opened.
+const SCAN_SETTLE: u32 = 3;
+/// Minimum spacing between two decoded signals (Hz).
+const MIN_SPACING_HZ: f32 = 80.0;
+/// Per-channel complex rate after decimation (Hz).
+const CHANNEL_RATE: f32 = 1000.0;
+/// Channel low-pass cutoff either side of the carrier (Hz).
+const CHANNEL_CUTOFF_HZ: f32 = 50.0;
+const CHANNEL_TAPS: usize = 61;
+/// Channel samples per keying decision (10 ms, matching [`CwDecoder`]).
+const KEY_WINDOW: usize = 10;
+/// Envelope peak required over the noise floor before keying (~10 dB).
+const KEY_MIN_RATIO: f32 = 3.0;
+/// Per-window decay of the envelope peak (about 5 s).
+const PEAK_DECAY: f32 = 0.998;
+/// Fraction of the measured offset applied per scan, and its limit (Hz).
+const AFC_GAIN: f32 = 0.3;
+const AFC_RANGE_HZ: f32 = 50.0;
+/// A channel is closed after this long below the threshold...
+const QUIET_TIMEOUT_SECS: f32 = 10.0;
+/// ...or after this long without decoding a character (a steady carrier).
+const IDLE_TIMEOUT_SECS: f32 = 60.0;
+/// After closing an idle channel, ignore the same frequency for this long.
+const RESPAWN_COOLDOWN_SECS: f32 = 60.0;
+/// Decoded text kept per channel for callsign extraction.
+const TEXT_KEEP: usize = 160;
+/// Copies of a callsign needed before it is reported.
+const CONFIRMATIONS: usize = 2;
+/// The same callsign near the same frequency is reported at most this often.
+const REPORT_INTERVAL_SECS: f32 = 600.0;
+const REPORT_MERGE_HZ: f64 = 1000.0;
+
+/// A callsign heard by the skimmer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkimmerSpot {
+    pub call: String,
+    /// Signal frequency relative to the IQ centre (Hz).
+    pub offset_hz: f64,
+    pub snr_db: f32,
+    pub wpm: u32,
+    /// `"CQ"` or `"DE"`, see [`find_spot_calls`].
+    pub kind: &'static str,
+}
+
+/// Finds and decodes every CW signal in a window of baseband IQ.
+pub struct CwSkimmer {
+    input_rate: f32,
+    span_hz: u32,
+    window_offset_hz: f64,
+    max_channels: usize,
+    snr_threshold_db: f32,
+    // Mixer and decimator from the input rate to the window span.
+    nco_phase: f32,
+    decim_step: f32,
+    decim_acc: f32,
+    decim_taps: Vec<f32>,
+    decim_hist: Vec<Complex32>,
+    decim_pos: usize,
+    // Spectrum scanner.
+    fft: Arc<dyn Fft<f32>>,
+    fft_len: usize,
+    window: Vec<f32>,
+    scan_buf: Vec<Complex32>,
+    scan_pos: usize,
+    since_scan: usize,
+    avg_power: Vec<f32>,
+    scans: u32,
+    channels: Vec<SkimmerChannel>,
+    cooldowns: Vec<(f32, f32)>,
+    reported: Vec<(String, f64, f32)>,
+    clock_secs: f32,
+}
+
+impl CwSkimmer {
+    /// Skim `span_hz` of IQ sampled at `input_rate`.
+    pub fn new(input_rate: u32, span_hz: u32) -> Self {
+        let span_hz = span_hz.clamp(CHANNEL_RATE as u32 * 4, input_rate.max(4000));
+        let decim_step = input_rate as f32 / span_hz as f32;
+        // Four input periods per output sample put the first Hann null at
+        // the window edge.
+        let decim_len = if decim_step > 1.0 {
+            (4.0 * decim_step).round() as usize
+        } else {
+            1
+        };
+        let fft_len = ((span_hz as f32 / SCAN_BIN_HZ) as usize)
+            .next_power_of_two()
+            .max(256);
+        Self {
+            input_rate: input_rate as f32,
+            span_hz,
+            window_offset_hz: 0.0,
+            max_channels: MAX_CHANNELS,
+            snr_threshold_db: DEFAULT_SNR_THRESHOLD_DB,
+            nco_phase: 0.0,
+            decim_step,
+            decim_acc: 0.0,
+            decim_taps: normalized_hann(decim_len),
+            decim_hist: vec![Complex32::new(0.0, 0.0); decim_len],
+            decim_pos: 0,
+            fft: FftPlanner::new().plan_fft_forward(fft_len),
+            fft_len,
+            window: (0..fft_len)
+                .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / fft_len as f32).cos())
+                .collect(),
+            scan_buf: vec![Complex32::new(0.0, 0.0); fft_len],
+            scan_pos: 0,
+            since_scan: 0,
+            avg_power: vec![0.0; fft_len],
+            scans: 0,
+            channels: Vec::new(),
+            cooldowns: Vec::new(),
+            reported: Vec::new(),
+            clock_secs: 0.0,
+        }
+    }
+
+    pub fn span_hz(&self) -> u32 {
+        self.span_hz
+    }
+
+    pub fn window_offset_hz(&self) -> f64 {
+        self.window_offset_hz
+    }
+
+    /// Move the skimmed window relative to the IQ centre.  Open channels are
+    /// dropped; recently reported callsigns are still suppressed.
+    pub fn set_window_offset_hz(&mut self, offset_hz: f64) {
+        if offset_hz != self.window_offset_hz {
+            self.window_offset_hz = offset_hz;
+            self.restart_scan();
+        }
+    }
+
+    pub fn set_max_channels(&mut self, max_channels: usize) {
+        self.max_channels = max_channels.clamp(1, MAX_CHANNELS);
+        self.channels.truncate(self.max_channels);
+    }
+
+    pub fn set_snr_threshold_db(&mut self, snr_db: f32) {
+        self.snr_threshold_db = snr_db.max(0.0);
+    }
+
+    /// Number of signals currently being decoded.
+    pub fn channel_count(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Forget everything, e.g. after the hardware centre moved.
+    pub fn reset(&mut self) {
+        self.restart_scan();
+        self.reported.clear();
+    }
+
+    fn restart_scan(&mut self) {
+        self.decim_hist.fill(Complex32::new(0.0, 0.0));
+        self.decim_acc = 0.0;
+        self.scan_buf.fill(Complex32::new(0.0, 0.0));
+        self.scan_pos = 0;
+        self.since_scan = 0;
+        self.avg_power.fill(0.0);
+        self.scans = 0;
+        self.channels.clear();
+        self.cooldowns.clear();
+    }
+
+    /// Feed baseband IQ; returns callsigns confirmed in this block.
+    pub fn process_iq(&mut self, samples: &[Complex32]) -> Vec<SkimmerSpot> {
+        let skim = self.front_end(samples);
+        let hop = self.fft_len / 2;
+        let mut rest = skim.as_slice();
+        while !rest.is_empty() {
+            let take = rest.len().min(hop - self.since_scan);
+            let (chunk, tail) = rest.split_at(take);
+            rest = tail;
+            for ch in &mut self.channels {
+                ch.process(chunk);
+            }
+            for &z in chunk {
+                self.scan_buf[self.scan_pos] = z;
+                self.scan_pos = (self.scan_pos + 1) % self.fft_len;
+            }
+            self.since_scan += take;
+            if self.since_scan >= hop {
+                self.since_scan = 0;
+                self.scan(hop as f32 / self.span_hz as f32);
+            }
+        }
+        self.clock_secs += skim.len() as f32 / self.span_hz as f32;
+        self.collect_spots()
+    }
+
+    /// Mix the window centre to 0 Hz and decimate to the span.
+    fn front_end(&mut self, samples: &[Complex32]) -> Vec<Complex32> {
+        let step = -2.0 * PI * (self.window_offset_hz as f32) / self.input_rate;
+        let mixing = self.window_offset_hz != 0.0;
+        let decim_len = self.decim_taps.len();
+        let mut out = Vec::with_capacity((samples.len() as f32 / self.decim_step) as usize + 1);
+        for &x in samples {
+            let x = if mixing {
+                let (sin, cos) = self.nco_phase.sin_cos();
+                self.nco_phase += step;
+                if self.nco_phase > PI {
+                    self.nco_phase -= 2.0 * PI;
+                } else if self.nco_phase < -PI {
+                    self.nco_phase += 2.0 * PI;
+                }
+                x * Complex32::new(cos, sin)
+            } else {
+                x
+            };
+            if decim_len == 1 {
+                out.push(x);
+                continue;
+            }
+            self.decim_hist[self.decim_pos] = x;
+            self.decim_pos = (self.decim_pos + 1) % decim_len;
+            self.decim_acc += 1.0;
+            if self.decim_acc >= self.decim_step {
+                self.decim_acc -= self.decim_step;
+                let mut acc = Complex32::new(0.0, 0.0);
+                for (i, &tap) in self.decim_taps.iter().enumerate() {
+                    acc += self.decim_hist[(self.decim_pos + i) % decim_len] * tap;
+                }
+                out.push(acc);
+            }
+        }
+        out
+    }
+
+    fn scan(&mut self, elapsed_secs: f32) {
+        let mut spectrum: Vec<Complex32> = (0..self.fft_len)
+            .map(|i| self.scan_buf[(self.scan_pos + i) % self.fft_len] * self.window[i])
+            .collect();
+        self.fft.process(&mut spectrum);
+        // Store the power spectrum with 0 Hz in the middle.
+        let half = self.fft_len / 2;
+        let alpha = if self.scans == 0 { 1.0 } else { 0.3 };
+        for (i, avg) in self.avg_power.iter_mut().enumerate() {
+            let bin = spectrum[(i + half) % self.fft_len];
+            *avg += alpha * (bin.norm_sqr() - *avg);
+        }
+        self.scans = self.scans.saturating_add(1);
+
+        let bin_hz = self.span_hz as f32 / self.fft_len as f32;
+        let reach = ((SCAN_SPAN_RATIO * half as f32) as usize).max(2);
+        let (lo, hi) = (half - reach + 1, half + reach - 1);
+        let freq_of = |k: f32| (k - half as f32) * bin_hz;
+        let bin_of = |f: f32| (f / bin_hz + half as f32).round() as usize;
+
+        let mut sorted: Vec<f32> = self.avg_power[lo..hi].to_vec();
+        sorted.sort_by(f32::total_cmp);
+        let noise_bin = sorted[sorted.len() / 2].max(f32::MIN_POSITIVE);
+        let spawn_level = noise_bin * 10f32.powf(self.snr_threshold_db / 10.0);
+        let hold_level = spawn_level * 0.5;
+
+        // Track each signal and measure its SNR.
+        let afc_bins = ((AFC_RANGE_HZ / bin_hz).ceil() as usize).max(1);
+        for ch in &mut self.channels {
+            let k = bin_of(ch.freq_hz).clamp(lo + afc_bins, hi - afc_bins);
+            let Some(peak) = (k - afc_bins..=k + afc_bins)
+                .filter(|&n| n > lo && n < hi)
+                .max_by(|&a, &b| self.avg_power[a].total_cmp(&self.avg_power[b]))
+            else {
+                continue;
+            };
+            let power = self.avg_power[peak];
+            ch.snr_db = 10.0 * (power / noise_bin).log10();
+            if power >= hold_level {
+                ch.quiet_secs = 0.0;
+                let window = &self.avg_power[peak - 1..=peak + 1];
+                let total: f32 = window.iter().sum();
+                let centroid = window
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| (peak - 1 + i) as f32 * p)
+                    .sum::<f32>()
+                    / total;
+                ch.retune(freq_of(centroid));
+            } else {
+                ch.quiet_secs += elapsed_secs;
+            }
+            ch.idle_secs += elapsed_secs;
+        }
+
+        let mut idle = Vec::new();
+        self.channels.retain(|ch| {
+            if ch.idle_secs >= IDLE_TIMEOUT_SECS {
+                idle.push(ch.freq_hz);
+                false
+            } else {
+                ch.quiet_secs < QUIET_TIMEOUT_SECS
+            }
+        });
+        for cd in &mut self.cooldowns {
+            cd.1 -= elapsed_secs;
+        }
+        self.cooldowns.retain(|cd| cd.1 > 0.0);
+        self.cooldowns
+            .extend(idle.into_iter().map(|f| (f, RESPAWN_COOLDOWN_SECS)));
+
+        // Merge channels that AFC pulled onto the same signal; keep the older.
+        let mut idx = 0;
+        while idx < self.channels.len() {
+            let f = self.channels[idx].freq_hz;
+            let mut j = idx + 1;
+            while j < self.channels.len() {
+                if (self.channels[j].freq_hz - f).abs() < MIN_SPACING_HZ / 2.0 {
+                    self.channels.remove(j);
+                } else {
+                    j += 1;
+                }
+            }
+            idx += 1;
+        }
+
+        if self.scans < SCAN_SETTLE {
+            return;
+        }
+        let spacing = ((MIN_SPACING_HZ / bin_hz).round() as usize).max(1);
+        for k in lo + 1..hi - 1 {
+            if self.channels.len() >= self.max_channels {
+                break;
+            }
+            let power = self.avg_power[k];
+            if power < spawn_level {
+                continue;
+            }
+            let from = k.saturating_sub(spacing).max(lo);
+            let to = (k + spacing).min(hi - 1);
+            if (from..=to).any(|n| self.avg_power[n] > power) {
+                continue;
+            }
+            let window = &self.avg_power[k - 1..=k + 1];
+            let total: f32 = window.iter().sum();
+            let centroid = window
+                .iter()
+                .enumerate()
+                .map(|(i, p)| (k - 1 + i) as f32 * p)
+                .sum::<f32>()
+                / total;
+            let freq = freq_of(centroid);
+            let taken = self
+                .channels
+                .iter()
+                .map(|ch| ch.freq_hz)
+                .chain(self.cooldowns.iter().map(|cd| cd.0))
+                .any(|f| (f - freq).abs() < MIN_SPACING_HZ);
+            if taken {
+                continue;
+            }
+            let mut ch = SkimmerChannel::new(self.span_hz as f32, freq);
+            ch.snr_db = 10.0 * (power / noise_bin).log10();
+            self.channels.push(ch);
+        }
+    }
+
+    fn collect_spots(&mut self) -> Vec<SkimmerSpot> {
+        let now = self.clock_secs;
+        self.reported
+            .retain(|(_, _, at)| now - at < REPORT_INTERVAL_SECS);
+        let mut spots = Vec::new();
+        for ch in &mut self.channels {
+            if !std::mem::take(&mut ch.word_done) {
+                continue;
+            }
+            let offset_hz = self.window_offset_hz + ch.freq_hz as f64;
+            for (call, kind) in find_spot_calls(&ch.text) {
+                let copies = ch.text.split_whitespace().filter(|w| *w == call).count();
+                if copies < CONFIRMATIONS {
+                    continue;
+                }
+                let seen = self
+                    .reported
+                    .iter()
+                    .any(|(c, f, _)| *c == call && (f - offset_hz).abs() < REPORT_MERGE_HZ);
+                if seen {
+                    continue;
+                }
+                self.reported.push((call.clone(), offset_hz, now));
+                spots.push(SkimmerSpot {
+                    call,
+                    offset_hz,
+                    snr_db: ch.snr_db,
+                    wpm: ch.wpm,
+                    kind,
+                });
+            }
+        }
+        spots
+    }
+}
+
+/// One decoded signal.
+struct SkimmerChannel {
+    /// Carrier offset within the skimmer window (Hz).
+    freq_hz: f32,
+    start_hz: f32,
+    sample_rate: f32,
+    nco_phase: f32,
+    decim_step: f32,
+    decim_acc: f32,
+    decim_taps: Vec<f32>,
+    decim_hist: Vec<Complex32>,
+    decim_pos: usize,
+    lp_taps: Vec<f32>,
+    lp_hist: Vec<Complex32>,
+    lp_pos: usize,
+    // Envelope keying.
+    env_acc: f32,
+    env_count: usize,
+    noise: f32,
+    peak: f32,
+    primed: bool,
+    key_down: bool,
+    decoder: CwDecoder,
+    text: String,
+    /// Set when a word was completed since the last callsign check.
+    word_done: bool,
+    wpm: u32,
+    snr_db: f32,
+    quiet_secs: f32,
+    idle_secs: f32,
+}
+
+impl SkimmerChannel {
+    fn new(sample_rate: f32, freq_hz: f32) -> Self {
+        let decim_step = sample_rate / CHANNEL_RATE;
+        let decim_len = ((4.0 * decim_step).round() as usize).max(2);
+        let mut decoder = CwDecoder::new(CHANNEL_RATE as u32);
+        decoder.set_auto(true);
+        Self {
+            freq_hz,
+            start_hz: freq_hz,
+            sample_rate,
+            nco_phase: 0.0,
+            decim_step,
+            decim_acc: 0.0,
+            decim_taps: normalized_hann(decim_len),
+            decim_hist: vec![Complex32::new(0.0, 0.0); decim_len],
+            decim_pos: 0,
+            lp_taps: lowpass_taps(CHANNEL_TAPS, CHANNEL_CUTOFF_HZ / CHANNEL_RATE),
+            lp_hist: vec![Complex32::new(0.0, 0.0); CHANNEL_TAPS],
+            lp_pos: 0,
+            env_acc: 0.0,
+            env_count: 0,
+            noise: 0.0,
+            peak: 0.0,
+            primed: false,
+            key_down: false,
+            decoder,
+            text: String::new(),
+            word_done: false,
+            wpm: 0,
+            snr_db: 0.0,
+            quiet_secs: 0.0,
+            idle_secs: 0.0,
+        }
+    }
+
+    fn retune(&mut self, centroid_hz: f32) {
+        let target = self.freq_hz + AFC_GAIN * (centroid_hz - self.freq_hz);
+        self.freq_hz = target.clamp(self.start_hz - AFC_RANGE_HZ, self.start_hz + AFC_RANGE_HZ);
+    }
+
+    fn process(&mut self, samples: &[Complex32]) {
+        let step = -2.0 * PI * self.freq_hz / self.sample_rate;
+        let decim_len = self.decim_taps.len();
+        for &x in samples {
+            let (sin, cos) = self.nco_phase.sin_cos();
+            self.decim_hist[self.decim_pos] = x * Complex32::new(cos, sin);
+            self.decim_pos = (self.decim_pos + 1) % decim_len;
+            self.nco_phase += step;
+            if self.nco_phase > PI {
+                self.nco_phase -= 2.0 * PI;
+            } else if self.nco_phase < -PI {
+                self.nco_phase += 2.0 * PI;
+            }
+
+            self.decim_acc += 1.0;
+            if self.decim_acc >= self.decim_step {
+                self.decim_acc -= self.decim_step;
+                let mut acc = Complex32::new(0.0, 0.0);
+                for (i, &tap) in self.decim_taps.iter().enumerate() {
+                    acc += self.decim_hist[(self.decim_pos + i) % decim_len] * tap;
+                }
+                self.process_subsample(acc);
+            }
+        }
+    }
+
+    fn process_subsample(&mut self, sample: Complex32) {
+        self.lp_hist[self.lp_pos] = sample;
+        self.lp_pos = (self.lp_pos + 1) % CHANNEL_TAPS;
+        let mut z = Complex32::new(0.0, 0.0);
+        for (i, &tap) in self.lp_taps.iter().enumerate() {
+            z += self.lp_hist[(self.lp_pos + i) % CHANNEL_TAPS] * tap;
+        }
+        self.env_acc += z.norm_sqr();
+        self.env_count += 1;
+        if self.env_count == KEY_WINDOW {
+            let amp = (self.env_acc / KEY_WINDOW as f32).sqrt();
+            self.env_acc = 0.0;
+            self.env_count = 0;
+            self.key(amp);
+        }
+    }
+
+    /// Decide the key state for one 10 ms window.  The threshold sits
+    /// between the tracked noise floor and the recent envelope peak.
+    fn key(&mut self, amp: f32) {
+        if !self.primed {
+            self.noise = amp;
+            self.peak = amp;
+            self.primed = true;
+        }
+        if amp > self.peak {
+            self.peak = amp;
+        } else {
+            self.peak = (self.peak * PEAK_DECAY).max(self.noise);
+        }
+        if !self.key_down {
+            // Follow the floor down quickly and up slowly, so keyed elements
+            // missed before the first transition do not lift it.
+            let gain = if amp < self.noise { 0.3 } else { 0.02 };
+            self.noise += gain * (amp - self.noise);
+        }
+        let span = self.peak - self.noise;
+        self.key_down = if self.peak < KEY_MIN_RATIO * self.noise {
+            false
+        } else if self.key_down {
+            amp > self.noise + 0.35 * span
+        } else {
+            amp > self.noise + 0.5 * span
+        };
+
+        for event in self.decoder.push_key_state(self.key_down) {
+            if event.text.is_empty() {
+                continue;
+            }
+            self.wpm = event.wpm;
+            if event.text == " " {
+                if !self.text.is_empty() && !self.text.ends_with(' ') {
+                    self.text.push(' ');
+                    self.word_done = true;
+                }
+                continue;
+            }
+            self.idle_secs = 0.0;
+            self.text.push_str(&event.text);
+            if self.text.len() > TEXT_KEEP {
+                let cut = self.text.len() - TEXT_KEEP;
+                self.text.drain(..cut);
+            }
+        }
+    }
+}
+
+fn normalized_hann(len: usize) -> Vec<f32> {
+    let taps: Vec<f32> = (0..len)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * (i as f32 + 0.5) / len as f32).cos())
+        .collect();
+    let sum: f32 = taps.iter().sum();
+    taps.into_iter().map(|t| t / sum).collect()
+}
+
+/// Hamming-windowed sinc low-pass; `cutoff` is relative to the sample rate.
+fn lowpass_taps(len: usize, cutoff: f32) -> Vec<f32> {
+    let mid = (len - 1) as f32 / 2.0;
+    let taps: Vec<f32> = (0..len)
+        .map(|i| {
+            let t = i as f32 - mid;
+            let sinc = if t == 0.0 {
+                2.0 * cutoff
+            } else {
+                (2.0 * PI * cutoff * t).sin() / (PI * t)
+            };
+            let window = 0.54 - 0.46 * (2.0 * PI * i as f32 / (len - 1) as f32).cos();
+            sinc * window
+        })
+        .collect();
+    let sum: f32 = taps.iter().sum();
+    taps.into_iter().map(|t| t / sum).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::morse_lookup;
+
+    const RATE: u32 = 48_000;
+
+    /// Morse code for `ch`, found by searching the decoder's table.
+    fn morse_code(ch: char) -> String {
+        for len in 1..=5 {
+            for bits in 0..(1u32 << len) {
+                let code: String = (0..len)
+                    .map(|i| if bits >> i & 1 == 1 { '-' } else { '.' })
+                    .collect();
+                if morse_lookup(&code) == Some(ch) {
+                    return code;
+                }
+            }
+        }
+        panic!("no code for {ch}");
+    }
+
+    /// Key state per sample for `text` at `wpm`.
+    fn keying(text: &str, wpm: u32) -> Vec<bool> {
+        let unit = (RATE as f32 * 1.2 / wpm as f32) as usize;
+        let mut out = Vec::new();
+        for word in text.split_whitespace() {
+            for ch in word.chars() {
+                for element in morse_code(ch).chars() {
+                    let len = if element == '-' { 3 } else { 1 };
+                    out.extend(std::iter::repeat_n(true, len * unit));
+                    out.extend(std::iter::repeat_n(false, unit));
+                }
+                out.extend(std::iter::repeat_n(false, 2 * unit));
+            }
+            out.extend(std::iter::repeat_n(false, 4 * unit));
+        }
+        out
+    }
+
+    /// Simple deterministic Gaussian noise source.
+    struct Noise(u64);
+
+    impl Noise {
+        fn uniform(&mut self) -> f32 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 40) as f32 + 0.5) / (1u64 << 24) as f32
+        }
+
+        fn complex(&mut self, sigma: f32) -> Complex32 {
+            let r = sigma * (-2.0 * self.uniform().ln()).sqrt() / std::f32::consts::SQRT_2;
+            let theta = 2.0 * PI * self.uniform();
+            Complex32::new(r * theta.cos(), r * theta.sin())
+        }
+    }
+
+    /// IQ carrying each `(offset_hz, text, wpm)` as keyed CW in noise.
+    fn synthesize(rate: u32, signals: &[(f32, &str, u32)], lead_secs: f32) -> Vec<Complex32> {
+        let lead = (lead_secs * RATE as f32) as usize;
+        let keys: Vec<Vec<bool>> = signals
+            .iter()
+            .map(|(_, text, wpm)| keying(text, *wpm))
+            .collect();
+        let len = lead + keys.iter().map(Vec::len).max().unwrap_or(0);
+        let ratio = rate / RATE;
+        let ramp = RATE as f32 * 0.004;
+        let mut noise = Noise(7);
+        let mut out = Vec::with_capacity(len * ratio as usize);
+        let mut envelopes = vec![0.0f32; signals.len()];
+        for n in 0..len * ratio as usize {
+            let mut z = noise.complex(0.2);
+            for (s, (freq, _, _)) in signals.iter().enumerate() {
+                let on = (n / ratio as usize)
+                    .checked_sub(lead)
+                    .and_then(|i| keys[s].get(i))
+                    .copied()
+                    .unwrap_or(false);
+                let delta = 1.0 / (ramp * ratio as f32);
+                envelopes[s] = if on {
+                    (envelopes[s] + delta).min(1.0)
+                } else {
+                    (envelopes[s] - delta).max(0.0)
+                };
+                let phase = std::f64::consts::TAU * *freq as f64 * n as f64 / rate as f64;
+                let carrier = Complex32::new(phase.cos() as f32, phase.sin() as f32);
+                z += carrier * (0.1 * envelopes[s]);
+            }
+            out.push(z);
+        }
+        out
+    }
+
+    fn skim(skimmer: &mut CwSkimmer, iq: &[Complex32]) -> Vec<SkimmerSpot> {
+        iq.chunks(4800)
+            .flat_map(|block| skimmer.process_iq(block))
+            .collect()
+    }
+
+    #[test]
+    fn reports_two_signals_with_speed_and_offset() {
+        let iq = synthesize(
+            RATE,
+            &[
+                (3000.0, &"CQ TEST K1ABC K1ABC ".repeat(3), 25),
+                (-5200.0, &"CQ CQ DE DL1XYZ DL1XYZ K ".repeat(3), 30),
+            ],
+            0.5,
+        );
+        let mut skimmer = CwSkimmer::new(RATE, RATE);
+        let spots = skim(&mut skimmer, &iq);
+
+        let k1abc: Vec<_> = spots.iter().filter(|s| s.call == "K1ABC").collect();
+        assert_eq!(k1abc.len(), 1, "{spots:?}");
+        assert!((k1abc[0].offset_hz - 3000.0).abs() < 30.0, "{spots:?}");
+        assert!((22..=28).contains(&k1abc[0].wpm), "{spots:?}");
+        assert_eq!(k1abc[0].kind, "CQ");
+        assert!(k1abc[0].snr_db > 10.0);
+
+        let dl1xyz: Vec<_> = spots.iter().filter(|s| s.call == "DL1XYZ").collect();
+        assert_eq!(dl1xyz.len(), 1, "{spots:?}");
+        assert!((dl1xyz[0].offset_hz + 5200.0).abs() < 30.0, "{spots:?}");
+        assert!((27..=33).contains(&dl1xyz[0].wpm), "{spots:?}");
+        assert_eq!(spots.len(), 2, "{spots:?}");
+    }
+
+    #[test]
+    fn decimates_an_offset_window() {
+        let iq = synthesize(
+            4 * RATE,
+            &[(60_000.0, &"TEST DL1XYZ TEST DL1XYZ ".repeat(2), 28)],
+            0.3,
+        );
+        let mut skimmer = CwSkimmer::new(4 * RATE, RATE);
+        skimmer.set_window_offset_hz(50_000.0);
+        let spots = skim(&mut skimmer, &iq);
+        assert_eq!(spots.len(), 1, "{spots:?}");
+        assert_eq!(spots[0].call, "DL1XYZ");
+        assert!((spots[0].offset_hz - 60_000.0).abs() < 30.0, "{spots:?}");
+    }
+
+    #[test]
+    fn noise_alone_opens_no_channels() {
+        let iq = synthesize(RATE, &[], 3.0);
+        let mut skimmer = CwSkimmer::new(RATE, RATE);
+        assert!(skim(&mut skimmer, &iq).is_empty());
+        assert_eq!(skimmer.channel_count(), 0);
+    }
+}
@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: BSD-2-Clause
 
-//! Server-side decoder file logging (APRS / CW / FT8 / WSPR).
+//! Server-side decoder file logging (APRS / DSC / POCSAG / ACARS / CW / PSK / FT8 / WSPR).
 //!
 //! Provides [`DecodeLogsConfig`] for TOML configuration and [`DecoderLoggers`]
 //! for writing JSON-Lines log files with automatic daily rotation.
@@ -18,7 +18,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tracing::warn;
 
-use trx_core::decode::{AprsPacket, CwEvent, Ft8Message, WefaxMessage, WsprMessage};
+use trx_core::decode::{
+    AcarsMessage, AprsPacket, CwEvent, DscMessage, Ft8Message, PocsagMessage, PskEvent,
+    WefaxMessage, WsprMessage,
+};
 
 // ---------------------------------------------------------------------------
 // Configuration
@@ -45,8 +48,16 @@ pub struct DecodeLogsConfig {
     pub dir: String,
     /// APRS decoder log filename
     pub aprs_file: String,
+    /// DSC decoder log filename
+    pub dsc_file: String,
+    /// POCSAG decoder log filename
+    pub pocsag_file: String,
+    /// ACARS decoder log filename
+    pub acars_file: String,
     /// CW decoder log filename
     pub cw_file: String,
+    /// PSK decoder log filename
+    pub psk_file: String,
     /// FT8 decoder log filename
     pub ft8_file: String,
     /// WSPR decoder log filename
@@ -61,7 +72,11 @@ impl Default for DecodeLogsConfig {
             enabled: false,
             dir: default_decode_logs_dir(),
             aprs_file: "TRXRS-APRS-%YYYY%-%MM%-%DD%.log".to_string(),
+            dsc_file: "TRXRS-DSC-%YYYY%-%MM%-%DD%.log".to_string(),
+            pocsag_file: "TRXRS-POCSAG-%YYYY%-%MM%-%DD%.log".to_string(),
+            acars_file: "TRXRS-ACARS-%YYYY%-%MM%-%DD%.log".to_string(),
             cw_file: "TRXRS-CW-%YYYY%-%MM%-%DD%.log".to_string(),
+            psk_file: "TRXRS-PSK-%YYYY%-%MM%-%DD%.log".to_string(),
             ft8_file: "TRXRS-FT8-%YYYY%-%MM%-%DD%.log".to_string(),
             wspr_file: "TRXRS-WSPR-%YYYY%-%MM%-%DD%.log".to_string(),
             wefax_file: "TRXRS-WEFAX-%YYYY%-%MM%-%DD%.log".to_string(),
@@ -176,7 +191,11 @@ impl DecoderFileLogger {
 /// Aggregate logger for all four server-side decoders.
 pub struct DecoderLoggers {
     aprs: DecoderFileLogger,
+    dsc: DecoderFileLogger,
+    pocsag: DecoderFileLogger,
+    acars: DecoderFileLogger,
     cw: DecoderFileLogger,
+    psk: DecoderFileLogger,
     ft8: DecoderFileLogger,
     wspr: DecoderFileLogger,
     wefax: DecoderFileLogger,
@@ -195,7 +214,11 @@ impl DecoderLoggers {
 
         let loggers = Self {
             aprs: DecoderFileLogger::open(&base_dir, &cfg.aprs_file, "aprs")?,
+            dsc: DecoderFileLogger::open(&base_dir, &cfg.dsc_file, "dsc")?,
+            pocsag: DecoderFileLogger::open(&base_dir, &cfg.pocsag_file, "pocsag")?,
+            acars: DecoderFileLogger::open(&base_dir, &cfg.acars_file, "acars")?,
             cw: DecoderFileLogger::open(&base_dir, &cfg.cw_file, "cw")?,
+            psk: DecoderFileLogger::open(&base_dir, &cfg.psk_file, "psk")?,
             ft8: DecoderFileLogger::open(&base_dir, &cfg.ft8_file, "ft8")?,
             wspr: DecoderFileLogger::open(&base_dir, &cfg.wspr_file, "wspr")?,
             wefax: DecoderFileLogger::open(&base_dir, &cfg.wefax_file, "wefax")?,
@@ -208,10 +231,26 @@ impl DecoderLoggers {
         self.aprs.write_payload(pkt);
     }
 
+    pub fn log_dsc(&self, msg: &DscMessage) {
+        self.dsc.write_payload(msg);
+    }
+
+    pub fn log_pocsag(&self, msg: &PocsagMessage) {
+        self.pocsag.write_payload(msg);
+    }
+
+    pub fn log_acars(&self, msg: &AcarsMessage) {
+        self.acars.write_payload(msg);
+    }
+
     pub fn log_cw(&self, evt: &CwEvent) {
         self.cw.write_payload(evt);
     }
 
+    pub fn log_psk(&self, evt: &PskEvent) {
+        self.psk.write_payload(evt);
+    }
+
     pub fn log_ft8(&self, msg: &Ft8Message) {
         self.ft8.write_payload(msg);
     }
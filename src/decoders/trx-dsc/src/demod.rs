@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Non-coherent two-tone FSK demodulator with PLL bit clock recovery.
+
+use trx_core::decode::DscBand;
+
+const TWO_PI: f32 = std::f32::consts::TAU;
+const PLL_GAIN: f32 = 0.3;
+/// RMS level below which the input is treated as silence.
+const SILENCE_RMS: f32 = 0.001;
+
+struct Tone {
+    phase: f32,
+    phase_inc: f32,
+    i_buf: Vec<f32>,
+    q_buf: Vec<f32>,
+    i_sum: f32,
+    q_sum: f32,
+}
+
+impl Tone {
+    fn new(freq_hz: f32, sample_rate: f32, window: usize) -> Self {
+        Self {
+            phase: 0.0,
+            phase_inc: TWO_PI * freq_hz / sample_rate,
+            i_buf: vec![0.0; window],
+            q_buf: vec![0.0; window],
+            i_sum: 0.0,
+            q_sum: 0.0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.phase = 0.0;
+        self.i_buf.fill(0.0);
+        self.q_buf.fill(0.0);
+        self.i_sum = 0.0;
+        self.q_sum = 0.0;
+    }
+
+    /// Correlate one sample over a sliding window and return the energy.
+    fn push(&mut self, s: f32, idx: usize) -> f32 {
+        let i = s * self.phase.cos();
+        let q = s * self.phase.sin();
+        self.phase += self.phase_inc;
+        if self.phase > TWO_PI {
+            self.phase -= TWO_PI;
+        }
+        self.i_sum += i - self.i_buf[idx];
+        self.q_sum += q - self.q_buf[idx];
+        self.i_buf[idx] = i;
+        self.q_buf[idx] = q;
+        self.i_sum * self.i_sum + self.q_sum * self.q_sum
+    }
+}
+
+/// Turns audio into a bit stream; `true` is the higher tone.
+pub(crate) struct FskDemodulator {
+    samples_per_bit: f32,
+    window: usize,
+    idx: usize,
+    low: Tone,
+    high: Tone,
+    energy_acc: f32,
+    energy_count: usize,
+    energy_window: usize,
+    last_bit: bool,
+    bit_phase: f32,
+}
+
+impl FskDemodulator {
+    pub(crate) fn new(sample_rate: u32, band: DscBand) -> Self {
+        let sr = sample_rate as f32;
+        let samples_per_bit = sr / band.baud();
+        let window = samples_per_bit.round().max(2.0) as usize;
+        let (lo_hz, hi_hz) = band.tones_hz();
+        Self {
+            samples_per_bit,
+            window,
+            idx: 0,
+            low: Tone::new(lo_hz, sr, window),
+            high: Tone::new(hi_hz, sr, window),
+            energy_acc: 0.0,
+            energy_count: 0,
+            energy_window: (sr * 0.05).round() as usize,
+            last_bit: false,
+            bit_phase: samples_per_bit / 2.0,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.idx = 0;
+        self.low.reset();
+        self.high.reset();
+        self.energy_acc = 0.0;
+        self.energy_count = 0;
+        self.last_bit = false;
+        self.bit_phase = self.samples_per_bit / 2.0;
+    }
+
+    pub(crate) fn process(&mut self, samples: &[f32]) -> Vec<bool> {
+        let mut bits = Vec::new();
+        for &s in samples {
+            self.energy_acc += s * s;
+            self.energy_count += 1;
+            if self.energy_count >= self.energy_window {
+                let rms = (self.energy_acc / self.energy_count as f32).sqrt();
+                self.energy_acc = 0.0;
+                self.energy_count = 0;
+                if rms < SILENCE_RMS {
+                    self.reset();
+                    continue;
+                }
+            }
+
+            let lo = self.low.push(s, self.idx);
+            let hi = self.high.push(s, self.idx);
+            self.idx = (self.idx + 1) % self.window;
+
+            // The correlation window spans one bit, so the tone decision
+            // settles at the end of each bit; nudge the sampling instant
+            // towards half a bit after every transition.
+            let bit = hi > lo;
+            if bit != self.last_bit {
+                self.last_bit = bit;
+                let error = self.bit_phase - self.samples_per_bit / 2.0;
+                self.bit_phase -= PLL_GAIN * error;
+            }
+            self.bit_phase -= 1.0;
+            if self.bit_phase <= 0.0 {
+                self.bit_phase += self.samples_per_bit;
+                bits.push(bit);
+            }
+        }
+        bits
+    }
+}
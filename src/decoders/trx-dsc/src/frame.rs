@@ -0,0 +1,277 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! 10-bit DSC symbols, phasing detection and DX/RX time-diversity.
+//!
+//! Every character is sent twice: once in the DX slot and again in the RX
+//! slot four characters later.  The phasing sequence fills the leading slots
+//! with DX = 125 and RX = 111, 110, ... 104, so detecting two consecutive
+//! phasing pairs both aligns the symbol clock and tells where data starts.
+
+/// Phasing symbol sent in the DX slots ahead of the message.
+const DX_PHASING: u8 = 125;
+/// First and last RX phasing symbols.
+const RX_PHASING_FIRST: u8 = 111;
+const RX_PHASING_LAST: u8 = 104;
+/// DX slots carrying phasing before the first message character.
+const DX_PHASING_PAIRS: usize = 6;
+/// RX slots carrying phasing; the RX copy therefore lags by two pairs.
+const RX_PHASING_PAIRS: usize = (RX_PHASING_FIRST - RX_PHASING_LAST + 1) as usize;
+/// Longest call we accept, format specifier through ECC.
+const MAX_CHARS: usize = 48;
+
+/// Valid format specifiers (distress, all ships, group, individual,
+/// geographic area, semi-automatic individual).
+const FORMAT_SPECIFIERS: [u8; 6] = [112, 116, 114, 120, 102, 123];
+/// End-of-sequence characters (acknowledge RQ, acknowledge BQ, other).
+const EOS_CHARS: [u8; 3] = [117, 122, 127];
+
+/// A deframed call: information characters from the first format
+/// specifier through EOS.
+#[derive(Debug, Clone)]
+pub(crate) struct RawCall {
+    pub(crate) chars: Vec<u8>,
+    pub(crate) ecc_ok: bool,
+}
+
+/// Decode a 10-bit symbol.  Bit 0 of `word` is the first transmitted bit.
+///
+/// The first seven bits are the value, LSB first; the last three carry
+/// the number of zero bits in the value, MSB first.
+fn decode_symbol(word: u16) -> Option<u8> {
+    let value = (word & 0x7f) as u8;
+    let check = ((word >> 7) & 1) << 2 | ((word >> 8) & 1) << 1 | ((word >> 9) & 1);
+    (check == 7 - u16::from(value.count_ones() as u8)).then_some(value)
+}
+
+#[cfg(test)]
+fn encode_symbol(value: u8) -> u16 {
+    let value = value & 0x7f;
+    let zeros = 7 - value.count_ones() as u16;
+    value as u16 | ((zeros >> 2) & 1) << 7 | ((zeros >> 1) & 1) << 8 | (zeros & 1) << 9
+}
+
+fn ecc(chars: &[u8]) -> u8 {
+    // The format specifier is only counted once.
+    chars.iter().skip(1).fold(0, |acc, &c| acc ^ c)
+}
+
+enum State {
+    Hunt,
+    Sync {
+        /// Index of the DX/RX pair currently being received.
+        pair: usize,
+        in_rx_slot: bool,
+        dx: Vec<Option<u8>>,
+        rx: Vec<Option<u8>>,
+        chars: Vec<u8>,
+        eos_at: Option<usize>,
+    },
+}
+
+pub(crate) struct Deframer {
+    shreg: u64,
+    nbits: usize,
+    word: u16,
+    word_bits: usize,
+    state: State,
+}
+
+impl Deframer {
+    pub(crate) fn new() -> Self {
+        Self {
+            shreg: 0,
+            nbits: 0,
+            word: 0,
+            word_bits: 0,
+            state: State::Hunt,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    pub(crate) fn push_bit(&mut self, bit: bool) -> Option<RawCall> {
+        match self.state {
+            State::Hunt => {
+                self.hunt(bit);
+                None
+            }
+            State::Sync { .. } => {
+                self.word |= u16::from(bit) << self.word_bits;
+                self.word_bits += 1;
+                if self.word_bits < 10 {
+                    return None;
+                }
+                let symbol = decode_symbol(self.word);
+                self.word = 0;
+                self.word_bits = 0;
+                self.push_symbol(symbol)
+            }
+        }
+    }
+
+    fn hunt(&mut self, bit: bool) {
+        self.shreg = ((self.shreg << 1) | u64::from(bit)) & ((1 << 40) - 1);
+        self.nbits = (self.nbits + 1).min(40);
+        if self.nbits < 40 {
+            return;
+        }
+        let mut syms = [None; 4];
+        for (n, sym) in syms.iter_mut().enumerate() {
+            let mut word = 0u16;
+            for k in 0..10 {
+                let pos = 39 - (n * 10 + k);
+                word |= (((self.shreg >> pos) & 1) as u16) << k;
+            }
+            *sym = decode_symbol(word);
+        }
+        let [Some(DX_PHASING), Some(rx_a), Some(DX_PHASING), Some(rx_b)] = syms else {
+            return;
+        };
+        if !(RX_PHASING_LAST..RX_PHASING_FIRST).contains(&rx_b) || rx_a != rx_b + 1 {
+            return;
+        }
+        // Resume with the pair after the one carrying RX phasing `rx_b`;
+        // later pairs already carry message characters in their DX slot.
+        let pair = (RX_PHASING_FIRST - rx_b) as usize + 1;
+        if pair > DX_PHASING_PAIRS {
+            return;
+        }
+        self.state = State::Sync {
+            pair,
+            in_rx_slot: false,
+            dx: Vec::new(),
+            rx: Vec::new(),
+            chars: Vec::new(),
+            eos_at: None,
+        };
+        self.word = 0;
+        self.word_bits = 0;
+    }
+
+    fn push_symbol(&mut self, symbol: Option<u8>) -> Option<RawCall> {
+        let State::Sync {
+            pair,
+            in_rx_slot,
+            dx,
+            rx,
+            chars,
+            eos_at,
+        } = &mut self.state
+        else {
+            return None;
+        };
+
+        if !*in_rx_slot {
+            if *pair >= DX_PHASING_PAIRS {
+                dx.push(symbol);
+            }
+            *in_rx_slot = true;
+            return None;
+        }
+        if *pair >= RX_PHASING_PAIRS {
+            rx.push(symbol);
+        }
+        *pair += 1;
+        *in_rx_slot = false;
+
+        // Both copies of every character up to `rx.len()` have arrived.
+        let mut done = None;
+        while chars.len() < rx.len() {
+            let k = chars.len();
+            let Some(c) = dx[k].or(rx[k]) else {
+                // If only the ECC is lost, deliver the call unchecked.
+                done = eos_at.map(|eos| RawCall {
+                    chars: chars[..=eos].to_vec(),
+                    ecc_ok: false,
+                });
+                self.reset();
+                return done;
+            };
+            if k == 0 && !FORMAT_SPECIFIERS.contains(&c) {
+                break;
+            }
+            chars.push(c);
+            if let Some(eos) = *eos_at {
+                let body = &chars[..=eos];
+                done = Some(RawCall {
+                    ecc_ok: ecc(body) == c,
+                    chars: body.to_vec(),
+                });
+                break;
+            }
+            if k >= 2 && EOS_CHARS.contains(&c) {
+                *eos_at = Some(k);
+            }
+        }
+        let failed = chars.is_empty() && !rx.is_empty();
+        if done.is_some() || failed || chars.len() >= MAX_CHARS {
+            self.reset();
+        }
+        done
+    }
+}
+
+/// Build the bit stream for a call: dot pattern, phasing, the characters
+/// with DX/RX time-diversity, ECC and the trailing EOS repeats.
+#[cfg(test)]
+pub(crate) fn encode_call(chars: &[u8], band: trx_core::decode::DscBand) -> Vec<bool> {
+    let eos = *chars.last().expect("call must end with EOS");
+    let check = ecc(chars);
+    let mut dx = vec![DX_PHASING; DX_PHASING_PAIRS];
+    dx.extend_from_slice(chars);
+    dx.extend_from_slice(&[check, eos, eos]);
+    let mut rx: Vec<u8> = (RX_PHASING_LAST..=RX_PHASING_FIRST).rev().collect();
+    rx.extend_from_slice(chars);
+    rx.push(check);
+    assert_eq!(dx.len(), rx.len());
+
+    let dots = match band {
+        trx_core::decode::DscBand::Vhf => 20,
+        trx_core::decode::DscBand::Hf => 200,
+    };
+    let mut bits: Vec<bool> = (0..dots).map(|i| i % 2 == 0).collect();
+    for (d, r) in dx.into_iter().zip(rx) {
+        for sym in [d, r] {
+            let word = encode_symbol(sym);
+            bits.extend((0..10).map(|k| (word >> k) & 1 == 1));
+        }
+    }
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbol_round_trip_and_check() {
+        for value in 0..128u8 {
+            let word = encode_symbol(value);
+            assert_eq!(decode_symbol(word), Some(value));
+            for bit in 0..10 {
+                assert_eq!(decode_symbol(word ^ (1 << bit)), None, "{value} bit {bit}");
+            }
+        }
+    }
+
+    #[test]
+    fn recovers_characters_from_rx_copy() {
+        let chars = [116, 116, 108, 21, 12, 34, 56, 70, 118, 126, 127];
+        let mut bits = encode_call(&chars, trx_core::decode::DscBand::Vhf);
+        // Corrupt the DX copy of the category character (pair 6 + 2).
+        let dx_start = 20 + (DX_PHASING_PAIRS + 2) * 20;
+        bits[dx_start] = !bits[dx_start];
+
+        let mut deframer = Deframer::new();
+        let call = bits
+            .into_iter()
+            .find_map(|bit| deframer.push_bit(bit))
+            .expect("call");
+        assert_eq!(call.chars, chars);
+        assert!(call.ecc_ok);
+    }
+}
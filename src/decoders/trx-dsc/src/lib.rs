@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Digital Selective Calling (ITU-R M.493) decoder.
+//!
+//! Handles 1200 baud AFSK on VHF channel 70 and 100 baud FSK on the MF/HF
+//! distress frequencies.  The receiver must be set to FM for VHF and to USB
+//! with the dial 1.7 kHz below the assigned frequency for MF/HF.
+
+mod demod;
+mod frame;
+mod message;
+
+use demod::FskDemodulator;
+use frame::Deframer;
+use trx_core::decode::{DscBand, DscMessage};
+
+/// Streaming DSC decoder for one audio channel.
+pub struct DscDecoder {
+    sample_rate: u32,
+    band: DscBand,
+    demod: FskDemodulator,
+    // Tone-to-bit polarity differs between equipment, so both are tried.
+    deframers: [Deframer; 2],
+}
+
+impl DscDecoder {
+    pub fn new(sample_rate: u32) -> Self {
+        let band = DscBand::default();
+        Self {
+            sample_rate,
+            band,
+            demod: FskDemodulator::new(sample_rate, band),
+            deframers: [Deframer::new(), Deframer::new()],
+        }
+    }
+
+    pub fn band(&self) -> DscBand {
+        self.band
+    }
+
+    /// Switch between VHF and MF/HF modulation.  No-op when unchanged.
+    pub fn set_band(&mut self, band: DscBand) {
+        if band == self.band {
+            return;
+        }
+        self.band = band;
+        self.demod = FskDemodulator::new(self.sample_rate, band);
+        self.reset();
+    }
+
+    pub fn reset(&mut self) {
+        self.demod.reset();
+        for deframer in &mut self.deframers {
+            deframer.reset();
+        }
+    }
+
+    pub fn process_samples(&mut self, samples: &[f32]) -> Vec<DscMessage> {
+        let mut out = Vec::new();
+        for bit in self.demod.process(samples) {
+            for (deframer, invert) in self.deframers.iter_mut().zip([false, true]) {
+                if let Some(call) = deframer.push_bit(bit ^ invert) {
+                    if let Some(msg) = message::parse(&call, self.band) {
+                        out.push(msg);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trx_core::decode::{DscCategory, DscFormat};
+
+    const SR: u32 = 48_000;
+
+    fn modulate(band: DscBand, bits: &[bool], invert: bool) -> Vec<f32> {
+        let (lo, hi) = band.tones_hz();
+        let spb = SR as f32 / band.baud();
+        let mut out = Vec::new();
+        let mut phase = 0.0f32;
+        let mut t = 0.0f32;
+        // Leading and trailing silence so the decoder sees a clean start/end.
+        out.extend(std::iter::repeat_n(0.0, SR as usize / 10));
+        for &bit in bits {
+            t += spb;
+            let n = t.round() as usize - (t - spb).round() as usize;
+            let freq = if bit ^ invert { hi } else { lo };
+            for _ in 0..n {
+                phase += std::f32::consts::TAU * freq / SR as f32;
+                out.push(0.5 * phase.sin());
+            }
+        }
+        out.extend(std::iter::repeat_n(0.0, SR as usize / 10));
+        out
+    }
+
+    fn decode(band: DscBand, chars: &[u8], invert: bool) -> Vec<DscMessage> {
+        let bits = frame::encode_call(chars, band);
+        let audio = modulate(band, &bits, invert);
+        let mut decoder = DscDecoder::new(SR);
+        decoder.set_band(band);
+        let mut out = Vec::new();
+        for chunk in audio.chunks(960) {
+            out.extend(decoder.process_samples(chunk));
+        }
+        out
+    }
+
+    fn distress_call() -> Vec<u8> {
+        vec![
+            112, 112, // format: distress
+            21, 12, 34, 56, 70,  // self-ID 211234567
+            101, // flooding
+            5, 43, 0, 18, 20, // NE, 54°30' N 018°20' E
+            14, 25,  // 14:25 UTC
+            100, // subsequent comms: F3E/G3E
+            127, // EOS
+        ]
+    }
+
+    #[test]
+    fn decodes_vhf_distress() {
+        let msgs = decode(DscBand::Vhf, &distress_call(), false);
+        assert_eq!(msgs.len(), 1, "{msgs:?}");
+        let msg = &msgs[0];
+        assert_eq!(msg.format, DscFormat::Distress);
+        assert_eq!(msg.category, DscCategory::Distress);
+        assert_eq!(msg.self_mmsi, 211_234_567);
+        assert_eq!(msg.nature.as_deref(), Some("Flooding"));
+        assert!((msg.lat.unwrap() - 54.5).abs() < 1e-9);
+        assert!((msg.lon.unwrap() - (18.0 + 20.0 / 60.0)).abs() < 1e-9);
+        assert_eq!(msg.utc_time.as_deref(), Some("14:25"));
+        assert!(msg.ecc_ok);
+    }
+
+    #[test]
+    fn decodes_hf_individual_with_inverted_tones() {
+        let chars = [
+            120, 120, // format: individual
+            24, 41, 23, 45, 60,  // address 244123456
+            100, // routine
+            0, 26, 11, 23, 0, // self-ID 002611230 (coast station)
+            109, 126, // J3E telephony, no information
+            126, 126, 126, // no frequency information
+            127,
+        ];
+        let msgs = decode(DscBand::Hf, &chars, true);
+        assert_eq!(msgs.len(), 1, "{msgs:?}");
+        let msg = &msgs[0];
+        assert_eq!(msg.format, DscFormat::Individual);
+        assert_eq!(msg.category, DscCategory::Routine);
+        assert_eq!(msg.address_mmsi, Some(244_123_456));
+        assert_eq!(msg.self_mmsi, 2_611_230);
+        assert_eq!(msg.telecommand.as_deref(), Some("J3E telephony"));
+    }
+
+    #[test]
+    fn silence_and_noise_decode_nothing() {
+        let mut decoder = DscDecoder::new(SR);
+        let mut state = 0x1234_5678u32;
+        let noise: Vec<f32> = (0..SR * 2)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 - 0.5) * 0.5
+            })
+            .collect();
+        assert!(decoder.process_samples(&noise).is_empty());
+        assert!(decoder.process_samples(&vec![0.0; SR as usize]).is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! ITU-R M.493 call formats.
+
+use trx_core::decode::{DscBand, DscCategory, DscFormat, DscMessage};
+
+use crate::frame::RawCall;
+
+/// Telecommands that carry distress information about another vessel.
+const TC_DISTRESS_ACK: u8 = 110;
+const TC_DISTRESS_RELAY: u8 = 112;
+/// Telecommand for position requests/replies.
+const TC_POSITION: u8 = 121;
+/// Marker preceding a position in the frequency/position field.
+const POSITION_MARKER: u8 = 55;
+
+fn format_of(symbol: u8) -> Option<DscFormat> {
+    match symbol {
+        112 => Some(DscFormat::Distress),
+        116 => Some(DscFormat::AllShips),
+        114 => Some(DscFormat::Group),
+        120 | 123 => Some(DscFormat::Individual),
+        102 => Some(DscFormat::GeographicArea),
+        _ => None,
+    }
+}
+
+fn category_of(symbol: u8) -> Option<DscCategory> {
+    match symbol {
+        100 => Some(DscCategory::Routine),
+        108 => Some(DscCategory::Safety),
+        110 => Some(DscCategory::Urgency),
+        112 => Some(DscCategory::Distress),
+        _ => None,
+    }
+}
+
+pub(crate) fn nature_text(symbol: u8) -> Option<&'static str> {
+    Some(match symbol {
+        100 => "Fire, explosion",
+        101 => "Flooding",
+        102 => "Collision",
+        103 => "Grounding",
+        104 => "Listing, in danger of capsizing",
+        105 => "Sinking",
+        106 => "Disabled and adrift",
+        107 => "Undesignated distress",
+        108 => "Abandoning ship",
+        109 => "Piracy/armed robbery attack",
+        110 => "Man overboard",
+        112 => "EPIRB emission",
+        _ => return None,
+    })
+}
+
+pub(crate) fn telecommand_text(symbol: u8) -> Option<&'static str> {
+    Some(match symbol {
+        100 => "F3E/G3E all modes telephony",
+        101 => "F3E/G3E duplex telephony",
+        103 => "Polling",
+        104 => "Unable to comply",
+        105 => "End of call",
+        106 => "Data",
+        109 => "J3E telephony",
+        110 => "Distress acknowledgement",
+        112 => "Distress relay",
+        113 => "F1B/J2B TTY-FEC",
+        115 => "F1B/J2B TTY-ARQ",
+        118 => "Test",
+        121 => "Position update",
+        _ => return None,
+    })
+}
+
+fn digits(symbols: &[u8]) -> Option<u64> {
+    symbols.iter().try_fold(0u64, |acc, &s| {
+        (s <= 99).then_some(acc * 100 + u64::from(s))
+    })
+}
+
+/// Five symbols carry ten digits; the MMSI is the first nine.
+fn mmsi(symbols: &[u8]) -> Option<u32> {
+    let value = digits(symbols.get(..5)?)?;
+    u32::try_from(value / 10).ok()
+}
+
+/// Ten digits: quadrant, latitude degrees and minutes (2 + 2), longitude
+/// degrees and minutes (3 + 2).  All nines means "position unknown".
+fn position(symbols: &[u8]) -> Option<(f64, f64)> {
+    let value = digits(symbols.get(..5)?)?;
+    if value == 9_999_999_999 {
+        return None;
+    }
+    let quadrant = value / 1_000_000_000;
+    let lat_deg = (value / 10_000_000) % 100;
+    let lat_min = (value / 100_000) % 100;
+    let lon_deg = (value / 100) % 1000;
+    let lon_min = value % 100;
+    if quadrant > 3 || lat_deg > 90 || lon_deg > 180 || lat_min >= 60 || lon_min >= 60 {
+        return None;
+    }
+    let lat = lat_deg as f64 + lat_min as f64 / 60.0;
+    let lon = lon_deg as f64 + lon_min as f64 / 60.0;
+    // 0 = NE, 1 = NW, 2 = SE, 3 = SW
+    let lat = if quadrant >= 2 { -lat } else { lat };
+    let lon = if quadrant % 2 == 1 { -lon } else { lon };
+    Some((lat, lon))
+}
+
+/// "HH:MM" from two symbols; 88 88 means "time unknown".
+fn utc_time(symbols: &[u8]) -> Option<String> {
+    let [hh, mm] = *symbols.get(..2)? else {
+        return None;
+    };
+    (hh < 24 && mm < 60).then(|| format!("{hh:02}:{mm:02}"))
+}
+
+fn empty_message(band: DscBand, format: DscFormat, category: DscCategory) -> DscMessage {
+    DscMessage {
+        rig_id: None,
+        ts_ms: None,
+        band,
+        format,
+        category,
+        self_mmsi: 0,
+        address_mmsi: None,
+        distress_mmsi: None,
+        nature: None,
+        telecommand: None,
+        lat: None,
+        lon: None,
+        utc_time: None,
+        ecc_ok: false,
+        symbols: Vec::new(),
+        vessel_name: None,
+        vessel_callsign: None,
+        vessel_lat: None,
+        vessel_lon: None,
+    }
+}
+
+/// Fill in the distress fields shared by distress alerts, acknowledgements
+/// and relays: nature, position and time.
+fn apply_distress_info(msg: &mut DscMessage, info: &[u8]) {
+    msg.nature = info
+        .first()
+        .and_then(|&s| nature_text(s))
+        .map(str::to_owned);
+    if let Some((lat, lon)) = info.get(1..6).and_then(position) {
+        msg.lat = Some(lat);
+        msg.lon = Some(lon);
+    }
+    msg.utc_time = info.get(6..8).and_then(utc_time);
+}
+
+/// Decode the information characters of a deframed call.
+pub(crate) fn parse(call: &RawCall, band: DscBand) -> Option<DscMessage> {
+    let chars = &call.chars;
+    let format = format_of(*chars.first()?)?;
+    // Skip the repeated format specifier and drop the EOS.
+    let body = chars.get(2..chars.len() - 1)?;
+
+    let mut msg = match format {
+        DscFormat::Distress => {
+            let mut msg = empty_message(band, format, DscCategory::Distress);
+            msg.self_mmsi = mmsi(body)?;
+            apply_distress_info(&mut msg, body.get(5..)?);
+            msg.telecommand = body
+                .get(13)
+                .and_then(|&s| telecommand_text(s))
+                .map(str::to_owned);
+            msg
+        }
+        DscFormat::AllShips => {
+            let category = category_of(*body.first()?)?;
+            let mut msg = empty_message(band, format, category);
+            msg.self_mmsi = mmsi(body.get(1..)?)?;
+            apply_telecommand(&mut msg, body.get(6..)?);
+            msg
+        }
+        DscFormat::Individual | DscFormat::Group | DscFormat::GeographicArea => {
+            let category = category_of(*body.get(5)?)?;
+            let mut msg = empty_message(band, format, category);
+            if format != DscFormat::GeographicArea {
+                msg.address_mmsi = Some(mmsi(body)?);
+            }
+            msg.self_mmsi = mmsi(body.get(6..)?)?;
+            apply_telecommand(&mut msg, body.get(11..)?);
+            msg
+        }
+    };
+    msg.ecc_ok = call.ecc_ok;
+    msg.symbols = chars.clone();
+    Some(msg)
+}
+
+/// Handle the two telecommands and whatever follows them.
+fn apply_telecommand(msg: &mut DscMessage, tail: &[u8]) {
+    let Some(&tc1) = tail.first() else {
+        return;
+    };
+    msg.telecommand = telecommand_text(tc1).map(str::to_owned);
+    let rest = tail.get(2..).unwrap_or_default();
+    match tc1 {
+        TC_DISTRESS_ACK | TC_DISTRESS_RELAY => {
+            msg.distress_mmsi = mmsi(rest);
+            apply_distress_info(msg, rest.get(5..).unwrap_or_default());
+        }
+        TC_POSITION if rest.first() == Some(&POSITION_MARKER) => {
+            if let Some((lat, lon)) = rest.get(1..6).and_then(position) {
+                msg.lat = Some(lat);
+                msg.lon = Some(lon);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(chars: &[u8]) -> RawCall {
+        RawCall {
+            chars: chars.to_vec(),
+            ecc_ok: true,
+        }
+    }
+
+    #[test]
+    fn distress_relay_carries_distressed_vessel() {
+        let msg = parse(
+            &call(&[
+                116, 116, 112, // all ships, distress category
+                0, 2, 61, 12, 30, // coast station 002611230
+                112, 126, // distress relay
+                21, 12, 34, 56, 70,  // vessel in distress
+                105, // sinking
+                31, 23, 40, 56, 7, // SW, 12°34' S 056°07' W
+                88, 88, // time unknown
+                127,
+            ]),
+            DscBand::Hf,
+        )
+        .expect("parse");
+        assert_eq!(msg.category, DscCategory::Distress);
+        assert!(msg.category.is_priority());
+        assert_eq!(msg.distress_mmsi, Some(211_234_567));
+        assert_eq!(msg.nature.as_deref(), Some("Sinking"));
+        assert!(msg.lat.unwrap() < 0.0 && msg.lon.unwrap() < 0.0);
+        assert_eq!(msg.utc_time, None);
+    }
+
+    #[test]
+    fn position_reply_and_unknown_position() {
+        let msg = parse(
+            &call(&[
+                120, 120, 24, 41, 23, 45, 60, 100, 21, 12, 34, 56, 70, 121, 126, 55, 15, 43, 0, 18,
+                20, 127,
+            ]),
+            DscBand::Vhf,
+        )
+        .expect("parse");
+        assert!((msg.lat.unwrap() - 54.5).abs() < 1e-9);
+        assert!(msg.lon.unwrap() < 0.0);
+        assert_eq!(position(&[99; 5]), None);
+    }
+}
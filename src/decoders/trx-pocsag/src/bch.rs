@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! BCH(31,21) plus even parity, as used by every POCSAG codeword.
+//!
+//! Bit 31 is transmitted first.  Bits 31..11 carry the 21 information bits,
+//! bits 10..1 the BCH check bits and bit 0 makes the overall parity even.
+
+/// Generator polynomial x^10 + x^9 + x^8 + x^6 + x^5 + x^3 + 1.
+const GENERATOR: u32 = 0x769;
+
+fn syndrome(word: u32) -> u32 {
+    let mut v = word >> 1;
+    for bit in (10..31).rev() {
+        if v & (1 << bit) != 0 {
+            v ^= GENERATOR << (bit - 10);
+        }
+    }
+    v
+}
+
+fn parity_ok(word: u32) -> bool {
+    word.count_ones().is_multiple_of(2)
+}
+
+/// Correct up to two bit errors in the BCH part, plus a parity error.
+///
+/// Returns the corrected codeword and the number of bits flipped, or `None`
+/// when the word is beyond repair.
+pub(crate) fn correct(word: u32) -> Option<(u32, u32)> {
+    let syn = syndrome(word);
+    if syn == 0 {
+        return Some(if parity_ok(word) {
+            (word, 0)
+        } else {
+            (word ^ 1, 1)
+        });
+    }
+
+    // Single and double errors in bits 31..1; the syndrome is linear, so an
+    // error pattern fixes the word when its syndrome equals the word's.
+    let bit_syn = |bit: u32| syndrome(1 << bit);
+    for i in 1..32 {
+        if bit_syn(i) == syn {
+            let fixed = word ^ (1 << i);
+            return Some(if parity_ok(fixed) {
+                (fixed, 1)
+            } else {
+                (fixed ^ 1, 2)
+            });
+        }
+    }
+    for i in 1..32 {
+        let si = bit_syn(i);
+        for j in (i + 1)..32 {
+            if si ^ bit_syn(j) == syn {
+                let fixed = word ^ (1 << i) ^ (1 << j);
+                // A parity mismatch here means a third error.
+                return parity_ok(fixed).then_some((fixed, 2));
+            }
+        }
+    }
+    None
+}
+
+/// Build a codeword from 21 information bits.
+#[cfg(test)]
+pub(crate) fn encode(info: u32) -> u32 {
+    let mut word = (info & 0x1f_ffff) << 11;
+    word |= syndrome(word) << 1;
+    if !parity_ok(word) {
+        word |= 1;
+    }
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IDLE_CODEWORD, SYNC_CODEWORD};
+
+    #[test]
+    fn sync_and_idle_are_valid_codewords() {
+        assert_eq!(correct(SYNC_CODEWORD), Some((SYNC_CODEWORD, 0)));
+        assert_eq!(correct(IDLE_CODEWORD), Some((IDLE_CODEWORD, 0)));
+    }
+
+    #[test]
+    fn corrects_two_errors_and_rejects_three() {
+        let word = encode(0x15_a5a5);
+        for i in 0..32 {
+            for j in (i + 1)..32 {
+                let damaged = word ^ (1 << i) ^ (1 << j);
+                assert_eq!(correct(damaged).map(|(w, _)| w), Some(word), "{i} {j}");
+            }
+        }
+        let three = word ^ 0b1_0000_0000_0100_0000_0000_1000_0000;
+        assert_ne!(correct(three).map(|(w, _)| w), Some(word));
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Batch synchronisation and page assembly.
+//!
+//! A transmission is a preamble followed by batches of one sync codeword and
+//! eight frames of two codewords each.  A pager only listens to the frame
+//! numbered by the low three bits of its capcode, so the frame position
+//! supplies those bits and the address codeword carries the other eighteen.
+
+use crate::bch;
+use crate::{IDLE_CODEWORD, SYNC_CODEWORD};
+
+/// Codewords per batch, excluding the sync codeword.
+const BATCH_CODEWORDS: usize = 16;
+/// Bit errors tolerated when re-synchronising at a batch boundary.  Initial
+/// acquisition only allows one so noise rarely starts a batch.
+const RESYNC_MAX_ERRORS: u32 = 2;
+const ACQUIRE_MAX_ERRORS: u32 = 1;
+/// Longest page we assemble, in message codewords.
+const MAX_MESSAGE_CODEWORDS: usize = 120;
+
+/// An address with the message codewords that followed it.
+#[derive(Debug, Clone)]
+pub(crate) struct RawPage {
+    pub(crate) capcode: u32,
+    pub(crate) function: u8,
+    /// 20 data bits per message codeword, first transmitted bit in bit 19.
+    pub(crate) data: Vec<u32>,
+    pub(crate) corrected_bits: u32,
+}
+
+enum State {
+    Hunt,
+    /// Receiving codeword `index` of the current batch; `BATCH_CODEWORDS`
+    /// means the next word should be the following batch's sync codeword.
+    Sync {
+        index: usize,
+    },
+}
+
+pub(crate) struct Receiver {
+    shreg: u32,
+    nbits: u32,
+    state: State,
+    page: Option<RawPage>,
+}
+
+impl Receiver {
+    pub(crate) fn new() -> Self {
+        Self {
+            shreg: 0,
+            nbits: 0,
+            state: State::Hunt,
+            page: None,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    pub(crate) fn push_bit(&mut self, bit: bool, out: &mut Vec<RawPage>) {
+        self.shreg = (self.shreg << 1) | u32::from(bit);
+        self.nbits = (self.nbits + 1).min(32);
+        match self.state {
+            State::Hunt => {
+                if self.nbits == 32
+                    && (self.shreg ^ SYNC_CODEWORD).count_ones() <= ACQUIRE_MAX_ERRORS
+                {
+                    self.start_batch();
+                }
+            }
+            State::Sync { index } => {
+                if self.nbits < 32 {
+                    return;
+                }
+                let word = self.shreg;
+                self.nbits = 0;
+                if index == BATCH_CODEWORDS {
+                    if (word ^ SYNC_CODEWORD).count_ones() <= RESYNC_MAX_ERRORS {
+                        self.start_batch();
+                    } else {
+                        // End of transmission.
+                        self.flush(out);
+                        self.state = State::Hunt;
+                        self.nbits = 32;
+                    }
+                    return;
+                }
+                self.state = State::Sync { index: index + 1 };
+                self.codeword(word, index, out);
+            }
+        }
+    }
+
+    fn start_batch(&mut self) {
+        self.state = State::Sync { index: 0 };
+        self.nbits = 0;
+    }
+
+    fn flush(&mut self, out: &mut Vec<RawPage>) {
+        if let Some(page) = self.page.take() {
+            out.push(page);
+        }
+    }
+
+    fn codeword(&mut self, word: u32, index: usize, out: &mut Vec<RawPage>) {
+        let Some((word, corrected)) = bch::correct(word) else {
+            // A page with a hole in it is more misleading than no page.
+            self.page = None;
+            return;
+        };
+        if word == IDLE_CODEWORD {
+            self.flush(out);
+            return;
+        }
+        if word & (1 << 31) == 0 {
+            self.flush(out);
+            let frame = (index / 2) as u32;
+            self.page = Some(RawPage {
+                capcode: ((word >> 13) & 0x3_ffff) << 3 | frame,
+                function: ((word >> 11) & 0x3) as u8,
+                data: Vec::new(),
+                corrected_bits: corrected,
+            });
+            return;
+        }
+        if let Some(page) = self.page.as_mut() {
+            page.data.push((word >> 11) & 0xf_ffff);
+            page.corrected_bits += corrected;
+            if page.data.len() >= MAX_MESSAGE_CODEWORDS {
+                self.flush(out);
+            }
+        }
+    }
+}
+
+/// Build the codewords of one transmission: preamble, then batches with
+/// the page in the frame chosen by its capcode, padded with idle words.
+#[cfg(test)]
+pub(crate) fn encode_transmission(capcode: u32, function: u8, data: &[u32]) -> Vec<bool> {
+    let mut words = Vec::new();
+    let frame = (capcode & 7) as usize;
+    words.extend(std::iter::repeat_n(IDLE_CODEWORD, frame * 2));
+    words.push(bch::encode(((capcode >> 3) << 2) | u32::from(function)));
+    words.extend(data.iter().map(|&d| bch::encode((1 << 20) | d)));
+    words.push(IDLE_CODEWORD);
+    while words.len() % BATCH_CODEWORDS != 0 {
+        words.push(IDLE_CODEWORD);
+    }
+
+    let mut bits: Vec<bool> = (0..576).map(|i| i % 2 == 0).collect();
+    for batch in words.chunks(BATCH_CODEWORDS) {
+        for word in std::iter::once(&SYNC_CODEWORD).chain(batch) {
+            bits.extend((0..32).rev().map(|k| (word >> k) & 1 == 1));
+        }
+    }
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receive(bits: &[bool]) -> Vec<RawPage> {
+        let mut rx = Receiver::new();
+        let mut out = Vec::new();
+        for &bit in bits {
+            rx.push_bit(bit, &mut out);
+        }
+        out
+    }
+
+    #[test]
+    fn page_spanning_batches_keeps_capcode() {
+        let data: Vec<u32> = (0..20).map(|n| n * 0x1111).collect();
+        let pages = receive(&encode_transmission(1_234_567, 3, &data));
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].capcode, 1_234_567);
+        assert_eq!(pages[0].function, 3);
+        assert_eq!(pages[0].data, data);
+    }
+
+    #[test]
+    fn uncorrectable_codeword_drops_page() {
+        let mut bits = encode_transmission(9, 0, &[0x12345, 0x6789a]);
+        // Three errors in the first message codeword (after preamble, sync
+        // and the address in frame 1's first slot, preceded by two idles).
+        let start = 576 + 32 * 4;
+        for k in [0, 5, 9] {
+            bits[start + k] = !bits[start + k];
+        }
+        assert!(receive(&bits).is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! POCSAG pager decoder.
+//!
+//! Consumes FM-demodulated audio and decodes 512, 1200 and 2400 bit/s
+//! transmissions in parallel, so the rate never has to be configured.
+//! Codewords are repaired with BCH(31,21) and numeric or alphanumeric
+//! bodies are assembled per capcode.
+
+mod bch;
+mod frame;
+mod message;
+mod slicer;
+
+use frame::Receiver;
+use slicer::Slicer;
+use trx_core::decode::PocsagMessage;
+
+/// Supported bit rates.
+pub const BAUD_RATES: [u16; 3] = [512, 1200, 2400];
+
+pub(crate) const SYNC_CODEWORD: u32 = 0x7cd2_15d8;
+pub(crate) const IDLE_CODEWORD: u32 = 0x7a89_c197;
+
+struct RateChannel {
+    baud: u16,
+    slicer: Slicer,
+    // The discriminator's polarity depends on the receiver, so both are tried.
+    receivers: [Receiver; 2],
+    bits: Vec<bool>,
+}
+
+/// Streaming POCSAG decoder for one audio channel.
+pub struct PocsagDecoder {
+    channels: Vec<RateChannel>,
+}
+
+impl PocsagDecoder {
+    pub fn new(sample_rate: u32) -> Self {
+        let channels = BAUD_RATES
+            .iter()
+            .map(|&baud| RateChannel {
+                baud,
+                slicer: Slicer::new(sample_rate, baud),
+                receivers: [Receiver::new(), Receiver::new()],
+                bits: Vec::new(),
+            })
+            .collect();
+        Self { channels }
+    }
+
+    pub fn reset(&mut self) {
+        for channel in &mut self.channels {
+            channel.slicer.reset();
+            for receiver in &mut channel.receivers {
+                receiver.reset();
+            }
+        }
+    }
+
+    pub fn process_samples(&mut self, samples: &[f32]) -> Vec<PocsagMessage> {
+        let mut out = Vec::new();
+        let mut pages = Vec::new();
+        for channel in &mut self.channels {
+            channel.bits.clear();
+            channel.slicer.process(samples, &mut channel.bits);
+            for (receiver, invert) in channel.receivers.iter_mut().zip([false, true]) {
+                for &bit in &channel.bits {
+                    receiver.push_bit(bit ^ invert, &mut pages);
+                }
+            }
+            out.extend(
+                pages
+                    .drain(..)
+                    .map(|page| message::to_message(page, channel.baud)),
+            );
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trx_core::decode::PocsagContent;
+
+    const SR: u32 = 48_000;
+
+    /// NRZ audio as an FM discriminator would produce it: logic 1 is the
+    /// lower RF frequency, i.e. negative audio.
+    fn modulate(baud: u16, bits: &[bool], invert: bool) -> Vec<f32> {
+        let spb = SR as f32 / f32::from(baud);
+        let mut out = vec![0.0; SR as usize / 10];
+        let mut t = 0.0f32;
+        for &bit in bits {
+            t += spb;
+            let n = t.round() as usize - (t - spb).round() as usize;
+            let level = if bit ^ invert { -0.4 } else { 0.4 };
+            out.extend(std::iter::repeat_n(level, n));
+        }
+        out.extend(std::iter::repeat_n(0.0, SR as usize / 10));
+        out
+    }
+
+    fn decode(baud: u16, bits: &[bool], invert: bool) -> Vec<PocsagMessage> {
+        let audio = modulate(baud, bits, invert);
+        let mut decoder = PocsagDecoder::new(SR);
+        let mut out = Vec::new();
+        for chunk in audio.chunks(960) {
+            out.extend(decoder.process_samples(chunk));
+        }
+        out
+    }
+
+    #[test]
+    fn decodes_alpha_page_at_every_rate() {
+        let data = message::encode_text("Test page 42", false);
+        let bits = frame::encode_transmission(1_600_001, 3, &data);
+        for baud in BAUD_RATES {
+            for invert in [false, true] {
+                let msgs = decode(baud, &bits, invert);
+                assert_eq!(msgs.len(), 1, "{baud} {invert}: {msgs:?}");
+                let msg = &msgs[0];
+                assert_eq!(msg.baud, baud);
+                assert_eq!(msg.capcode, 1_600_001);
+                assert_eq!(msg.content, PocsagContent::Alpha);
+                assert_eq!(msg.text.as_deref(), Some("Test page 42"));
+            }
+        }
+    }
+
+    #[test]
+    fn decodes_numeric_and_tone_pages_with_bit_errors() {
+        let data = message::encode_text("5551234", true);
+        let mut bits = frame::encode_transmission(42, 0, &data);
+        // Two errors in the address codeword (frame 2, after two idle pairs).
+        let addr = 576 + 32 + 4 * 32;
+        bits[addr + 3] = !bits[addr + 3];
+        bits[addr + 20] = !bits[addr + 20];
+        bits.extend(frame::encode_transmission(77, 1, &[]));
+
+        let msgs = decode(1200, &bits, false);
+        assert_eq!(msgs.len(), 2, "{msgs:?}");
+        assert_eq!(msgs[0].capcode, 42);
+        assert_eq!(msgs[0].content, PocsagContent::Numeric);
+        assert_eq!(msgs[0].text.as_deref(), Some("5551234"));
+        assert_eq!(msgs[0].corrected_bits, 2);
+        assert_eq!(msgs[1].capcode, 77);
+        assert_eq!(msgs[1].content, PocsagContent::Tone);
+        assert_eq!(msgs[1].text, None);
+    }
+
+    #[test]
+    fn noise_decodes_nothing() {
+        let mut decoder = PocsagDecoder::new(SR);
+        let mut state = 0x1234_5678u32;
+        let noise: Vec<f32> = (0..SR * 2)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 - 0.5) * 0.5
+            })
+            .collect();
+        assert!(decoder.process_samples(&noise).is_empty());
+    }
+}
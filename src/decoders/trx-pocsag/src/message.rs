@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Numeric and alphanumeric page bodies.
+//!
+//! Characters are packed LSB first across message codewords: numeric pages
+//! use 4-bit BCD digits, alphanumeric pages 7-bit ASCII.  Which one a page
+//! carries is not signalled; by convention numeric pagers use function 0.
+
+use trx_core::decode::{PocsagContent, PocsagMessage};
+
+use crate::frame::RawPage;
+
+const NUMERIC_CHARS: [char; 16] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '*', 'U', ' ', '-', ')', '(',
+];
+
+/// Data bits in transmission order.
+fn bits(data: &[u32]) -> impl Iterator<Item = bool> + '_ {
+    data.iter()
+        .flat_map(|&word| (0..20).rev().map(move |k| (word >> k) & 1 == 1))
+}
+
+/// Collect `width`-bit characters, first transmitted bit as the LSB.
+fn chars_lsb_first(data: &[u32], width: usize) -> Vec<u8> {
+    let all: Vec<bool> = bits(data).collect();
+    all.chunks_exact(width)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |acc, (k, &b)| acc | (u8::from(b) << k))
+        })
+        .collect()
+}
+
+fn numeric_text(data: &[u32]) -> String {
+    let text: String = chars_lsb_first(data, 4)
+        .into_iter()
+        .map(|d| NUMERIC_CHARS[usize::from(d)])
+        .collect();
+    text.trim_end().to_string()
+}
+
+fn alpha_text(data: &[u32]) -> String {
+    let mut text = String::new();
+    for c in chars_lsb_first(data, 7) {
+        match c {
+            // NUL, ETX and EOT terminate; unused bits are zero-filled.
+            0x00 | 0x03 | 0x04 => break,
+            b'\n' | b'\r' | b'\t' => text.push(' '),
+            0x20..=0x7e => text.push(char::from(c)),
+            _ => {}
+        }
+    }
+    text.trim_end().to_string()
+}
+
+pub(crate) fn to_message(page: RawPage, baud: u16) -> PocsagMessage {
+    let (content, text) = if page.data.is_empty() {
+        (PocsagContent::Tone, None)
+    } else if page.function == 0 {
+        (PocsagContent::Numeric, Some(numeric_text(&page.data)))
+    } else {
+        (PocsagContent::Alpha, Some(alpha_text(&page.data)))
+    };
+    PocsagMessage {
+        rig_id: None,
+        ts_ms: None,
+        baud,
+        capcode: page.capcode,
+        function: page.function,
+        content,
+        text,
+        corrected_bits: page.corrected_bits,
+        redacted: false,
+    }
+}
+
+/// Pack text into 20-bit message codeword payloads.
+#[cfg(test)]
+pub(crate) fn encode_text(text: &str, numeric: bool) -> Vec<u32> {
+    let width = if numeric { 4 } else { 7 };
+    let mut stream = Vec::new();
+    for c in text.chars() {
+        let value = if numeric {
+            NUMERIC_CHARS
+                .iter()
+                .position(|&n| n == c)
+                .expect("numeric char") as u32
+        } else {
+            c as u32
+        };
+        stream.extend((0..width).map(|k| (value >> k) & 1 == 1));
+    }
+    if !numeric {
+        stream.extend((0..7).map(|k| (0x04 >> k) & 1 == 1));
+    }
+    stream
+        .chunks(20)
+        .map(|chunk| {
+            let mut word = 0u32;
+            for k in 0..20 {
+                let bit = match chunk.get(k) {
+                    Some(&b) => b,
+                    // Numeric pages are padded with spaces (0xC = 0011 LSB first).
+                    None if numeric => (k - chunk.len()) % 4 >= 2,
+                    None => false,
+                };
+                word = (word << 1) | u32::from(bit);
+            }
+            word
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_and_alpha_round_trip() {
+        assert_eq!(
+            numeric_text(&encode_text("0123-456 789", true)),
+            "0123-456 789"
+        );
+        assert_eq!(
+            alpha_text(&encode_text("FIRE ALARM Bldg 7, call 112", false)),
+            "FIRE ALARM Bldg 7, call 112"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! NRZ bit slicer for FM-discriminator audio.
+
+/// Proportional gain of the bit clock loop.
+const PLL_GAIN: f32 = 0.3;
+/// DC tracker time constant, in bits.
+const DC_TRACK_BITS: f32 = 32.0;
+
+/// Recovers bits at one rate from discriminator audio.  `true` means the
+/// audio is above its running mean.
+pub(crate) struct Slicer {
+    samples_per_bit: f32,
+    dc: f32,
+    dc_alpha: f32,
+    window: Vec<f32>,
+    window_idx: usize,
+    window_sum: f32,
+    last_level: bool,
+    bit_phase: f32,
+}
+
+impl Slicer {
+    pub(crate) fn new(sample_rate: u32, baud: u16) -> Self {
+        let samples_per_bit = sample_rate as f32 / f32::from(baud);
+        // Averaging over half a bit rejects FM noise without smearing edges.
+        let window = (samples_per_bit / 2.0).round().max(1.0) as usize;
+        Self {
+            samples_per_bit,
+            dc: 0.0,
+            dc_alpha: 1.0 / (samples_per_bit * DC_TRACK_BITS),
+            window: vec![0.0; window],
+            window_idx: 0,
+            window_sum: 0.0,
+            last_level: false,
+            bit_phase: samples_per_bit / 2.0,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.dc = 0.0;
+        self.window.fill(0.0);
+        self.window_idx = 0;
+        self.window_sum = 0.0;
+        self.last_level = false;
+        self.bit_phase = self.samples_per_bit / 2.0;
+    }
+
+    pub(crate) fn process(&mut self, samples: &[f32], bits: &mut Vec<bool>) {
+        for &s in samples {
+            self.window_sum += s - self.window[self.window_idx];
+            self.window[self.window_idx] = s;
+            self.window_idx = (self.window_idx + 1) % self.window.len();
+            let filtered = self.window_sum / self.window.len() as f32;
+            self.dc += self.dc_alpha * (filtered - self.dc);
+
+            // Pull the sampling instant towards mid-bit at every transition.
+            let level = filtered > self.dc;
+            if level != self.last_level {
+                self.last_level = level;
+                let error = self.bit_phase - self.samples_per_bit / 2.0;
+                self.bit_phase -= PLL_GAIN * error;
+            }
+            self.bit_phase -= 1.0;
+            if self.bit_phase <= 0.0 {
+                self.bit_phase += self.samples_per_bit;
+                bits.push(level);
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Single-carrier PSK demodulator.
+//!
+//! The carrier is mixed to baseband, decimated to 16 complex samples per
+//! symbol, matched-filtered and sampled by an early/late symbol clock.
+//! Symbols are detected differentially, so no carrier phase lock is needed;
+//! the differential phase error drives a slow AFC loop instead.
+
+use std::f32::consts::PI;
+
+use num_complex::Complex32;
+use trx_core::decode::PskMode;
+
+use crate::varicode::VaricodeDeframer;
+use crate::viterbi::ViterbiDecoder;
+
+/// Complex samples per symbol after decimation.
+const SUBSAMPLES: usize = 16;
+/// Matched filter length: the PSK31 raised-cosine pulse spans two symbols.
+const MATCHED_TAPS: usize = 2 * SUBSAMPLES;
+/// Symbol quality above which decoded characters are emitted.
+const QUALITY_GATE: f32 = 0.4;
+/// Fraction of the measured frequency error applied per symbol.
+const AFC_GAIN: f32 = 0.08;
+/// How far (in multiples of the baud rate) AFC may pull a channel.
+const AFC_RANGE_BAUD: f32 = 2.0;
+
+pub(crate) struct PskChannel {
+    pub id: u32,
+    mode: PskMode,
+    sample_rate: f32,
+    freq_hz: f32,
+    start_hz: f32,
+    nco_phase: f32,
+    // Decimating low-pass filter (Hann over two subsample periods).
+    decim_step: f32,
+    decim_acc: f32,
+    decim_taps: Vec<f32>,
+    decim_hist: Vec<Complex32>,
+    decim_pos: usize,
+    // Matched filter.
+    matched_taps: [f32; MATCHED_TAPS],
+    matched_hist: [Complex32; MATCHED_TAPS],
+    matched_pos: usize,
+    // Symbol timing.
+    sync_buf: [f32; SUBSAMPLES],
+    bit_clock: f32,
+    prev_symbol: Complex32,
+    // Bit/character recovery.
+    viterbi: ViterbiDecoder,
+    deframer: VaricodeDeframer,
+    quality: f32,
+    pending: String,
+    /// Seconds of audio processed while quality stayed below the gate.
+    quiet_secs: f32,
+    pub snr_db: f32,
+}
+
+impl PskChannel {
+    pub fn new(id: u32, mode: PskMode, sample_rate: u32, freq_hz: f32) -> Self {
+        let sample_rate = sample_rate as f32;
+        let decim_step = sample_rate / (mode.baud() * SUBSAMPLES as f32);
+        let decim_len = ((2.0 * decim_step).round() as usize).max(2);
+        let decim_taps = normalized_hann(decim_len);
+        let mut matched_taps = [0.0; MATCHED_TAPS];
+        matched_taps.copy_from_slice(&normalized_hann(MATCHED_TAPS));
+        Self {
+            id,
+            mode,
+            sample_rate,
+            freq_hz,
+            start_hz: freq_hz,
+            nco_phase: 0.0,
+            decim_step,
+            decim_acc: 0.0,
+            decim_taps,
+            decim_hist: vec![Complex32::new(0.0, 0.0); decim_len],
+            decim_pos: 0,
+            matched_taps,
+            matched_hist: [Complex32::new(0.0, 0.0); MATCHED_TAPS],
+            matched_pos: 0,
+            sync_buf: [0.0; SUBSAMPLES],
+            bit_clock: 0.0,
+            prev_symbol: Complex32::new(0.0, 0.0),
+            viterbi: ViterbiDecoder::new(),
+            deframer: VaricodeDeframer::default(),
+            quality: 0.0,
+            pending: String::new(),
+            quiet_secs: 0.0,
+            snr_db: 0.0,
+        }
+    }
+
+    pub fn freq_hz(&self) -> f32 {
+        self.freq_hz
+    }
+
+    pub fn quiet_secs(&self) -> f32 {
+        self.quiet_secs
+    }
+
+    /// Retune the channel, resetting the AFC anchor.
+    pub fn retune(&mut self, freq_hz: f32) {
+        self.freq_hz = freq_hz;
+        self.start_hz = freq_hz;
+    }
+
+    pub fn take_text(&mut self) -> Option<String> {
+        if self.pending.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.pending))
+        }
+    }
+
+    pub fn process(&mut self, samples: &[f32]) {
+        let decim_len = self.decim_taps.len();
+        for &x in samples {
+            let (sin, cos) = self.nco_phase.sin_cos();
+            self.decim_hist[self.decim_pos] = Complex32::new(x * cos, -x * sin);
+            self.decim_pos = (self.decim_pos + 1) % decim_len;
+            self.nco_phase += 2.0 * PI * self.freq_hz / self.sample_rate;
+            if self.nco_phase > PI {
+                self.nco_phase -= 2.0 * PI;
+            }
+
+            self.decim_acc += 1.0;
+            if self.decim_acc >= self.decim_step {
+                self.decim_acc -= self.decim_step;
+                let mut acc = Complex32::new(0.0, 0.0);
+                for (i, &tap) in self.decim_taps.iter().enumerate() {
+                    acc += self.decim_hist[(self.decim_pos + i) % decim_len] * tap;
+                }
+                self.process_subsample(acc);
+            }
+        }
+        let secs = samples.len() as f32 / self.sample_rate;
+        if self.quality < QUALITY_GATE {
+            self.quiet_secs += secs;
+        } else {
+            self.quiet_secs = 0.0;
+        }
+    }
+
+    fn process_subsample(&mut self, sample: Complex32) {
+        self.matched_hist[self.matched_pos] = sample;
+        self.matched_pos = (self.matched_pos + 1) % MATCHED_TAPS;
+        let mut z = Complex32::new(0.0, 0.0);
+        for (i, &tap) in self.matched_taps.iter().enumerate() {
+            z += self.matched_hist[(self.matched_pos + i) % MATCHED_TAPS] * tap;
+        }
+
+        // Early/late gate: balance the averaged magnitude across the symbol
+        // so the peak sits where the clock wraps.
+        let idx = (self.bit_clock as usize).min(SUBSAMPLES - 1);
+        self.sync_buf[idx] = 0.8 * self.sync_buf[idx] + 0.2 * z.norm();
+        let half = SUBSAMPLES / 2;
+        let mut diff = 0.0;
+        let mut sum = 0.0;
+        for i in 0..half {
+            diff += self.sync_buf[i] - self.sync_buf[i + half];
+            sum += self.sync_buf[i] + self.sync_buf[i + half];
+        }
+        if sum > 0.0 {
+            self.bit_clock -= diff / sum / 5.0;
+        }
+        self.bit_clock += 1.0;
+        if self.bit_clock < 0.0 {
+            self.bit_clock += SUBSAMPLES as f32;
+        }
+        if self.bit_clock >= SUBSAMPLES as f32 {
+            self.bit_clock -= SUBSAMPLES as f32;
+            self.process_symbol(z);
+        }
+    }
+
+    fn process_symbol(&mut self, z: Complex32) {
+        let diff = z * self.prev_symbol.conj();
+        self.prev_symbol = z;
+        let mag = diff.norm();
+        if mag <= f32::EPSILON {
+            return;
+        }
+        let unit = diff / mag;
+
+        // Remove the modulation to expose the residual rotation per symbol.
+        let (stripped, order) = if self.mode.is_qpsk() {
+            (unit * unit * unit * unit, 4.0)
+        } else {
+            (unit * unit, 2.0)
+        };
+        self.quality = 0.95 * self.quality + 0.05 * stripped.re;
+
+        let baud = self.mode.baud();
+        let freq_err = stripped.arg() / order * baud / (2.0 * PI);
+        let limit = AFC_RANGE_BAUD * baud;
+        self.freq_hz = (self.freq_hz + AFC_GAIN * freq_err)
+            .clamp(self.start_hz - limit, self.start_hz + limit);
+
+        let bit = if self.mode.is_qpsk() {
+            match self.viterbi.push(unit) {
+                Some(bit) => bit,
+                None => return,
+            }
+        } else {
+            // No phase change is a one, a reversal is a zero.
+            diff.re > 0.0
+        };
+        if let Some(byte) = self.deframer.push_bit(bit) {
+            if self.quality >= QUALITY_GATE {
+                match byte {
+                    b'\n' => self.pending.push('\n'),
+                    0x20..=0x7e => self.pending.push(byte as char),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn normalized_hann(len: usize) -> Vec<f32> {
+    let taps: Vec<f32> = (0..len)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * (i as f32 + 0.5) / len as f32).cos())
+        .collect();
+    let sum: f32 = taps.iter().sum();
+    taps.into_iter().map(|t| t / sum).collect()
+}
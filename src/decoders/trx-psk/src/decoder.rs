@@ -0,0 +1,417 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+use std::sync::Arc;
+
+use num_complex::Complex32;
+use rustfft::{Fft, FftPlanner};
+use trx_core::decode::{PskEvent, PskMode};
+
+use crate::channel::PskChannel;
+
+/// Upper bound on simultaneously tracked signals in waterfall mode.
+const MAX_CHANNELS: usize = 32;
+/// Audio range searched for signals in waterfall mode (Hz).
+const SCAN_MIN_HZ: f32 = 200.0;
+const SCAN_MAX_HZ: f32 = 3000.0;
+/// Band power above the noise floor required to open a channel (6 dB).
+const SPAWN_THRESHOLD: f32 = 4.0;
+/// A waterfall channel is closed after this long without a usable signal.
+const CHANNEL_TIMEOUT_SECS: f32 = 5.0;
+/// After closing a channel, ignore the same frequency for this long.
+const RESPAWN_COOLDOWN_SECS: f32 = 30.0;
+/// SNR reference bandwidth (Hz), matching the usual PSK reporting convention.
+const SNR_REF_BW_HZ: f32 = 2500.0;
+
+/// Multi-channel PSK decoder.
+pub struct PskDecoder {
+    sample_rate: u32,
+    mode: PskMode,
+    center_hz: u32,
+    waterfall: bool,
+    channels: Vec<PskChannel>,
+    next_id: u32,
+    // Spectrum scanner used for signal detection and SNR estimates.
+    fft: Arc<dyn Fft<f32>>,
+    fft_len: usize,
+    window: Vec<f32>,
+    scan_buf: Vec<f32>,
+    scan_pos: usize,
+    since_scan: usize,
+    avg_power: Vec<f32>,
+    scans: u32,
+    cooldowns: Vec<(f32, f32)>,
+}
+
+impl PskDecoder {
+    pub fn new(sample_rate: u32) -> Self {
+        let mut decoder = Self {
+            sample_rate,
+            mode: PskMode::default(),
+            center_hz: 1000,
+            waterfall: false,
+            channels: Vec::new(),
+            next_id: 0,
+            fft: FftPlanner::new().plan_fft_forward(1),
+            fft_len: 1,
+            window: Vec::new(),
+            scan_buf: Vec::new(),
+            scan_pos: 0,
+            since_scan: 0,
+            avg_power: Vec::new(),
+            scans: 0,
+            cooldowns: Vec::new(),
+        };
+        decoder.reset();
+        decoder
+    }
+
+    pub fn mode(&self) -> PskMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: PskMode) {
+        if mode != self.mode {
+            self.mode = mode;
+            self.reset();
+        }
+    }
+
+    /// Set the audio offset followed in single-channel mode.
+    pub fn set_center_hz(&mut self, center_hz: u32) {
+        if center_hz == self.center_hz {
+            return;
+        }
+        self.center_hz = center_hz;
+        if !self.waterfall {
+            if let Some(ch) = self.channels.first_mut() {
+                ch.retune(center_hz as f32);
+            }
+        }
+    }
+
+    /// Switch between single-channel and waterfall (all signals) decoding.
+    pub fn set_waterfall(&mut self, waterfall: bool) {
+        if waterfall != self.waterfall {
+            self.waterfall = waterfall;
+            self.reset();
+        }
+    }
+
+    pub fn reset(&mut self) {
+        let baud = self.mode.baud();
+        let fft_len = ((self.sample_rate as f32 / (baud / 4.0)) as usize)
+            .next_power_of_two()
+            .max(256);
+        self.fft = FftPlanner::new().plan_fft_forward(fft_len);
+        self.fft_len = fft_len;
+        self.window = (0..fft_len)
+            .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / fft_len as f32).cos())
+            .collect();
+        self.scan_buf = vec![0.0; fft_len];
+        self.scan_pos = 0;
+        self.since_scan = 0;
+        self.avg_power = vec![0.0; fft_len / 2];
+        self.scans = 0;
+        self.cooldowns.clear();
+        self.channels.clear();
+        if !self.waterfall {
+            self.channels.push(PskChannel::new(
+                self.next_id,
+                self.mode,
+                self.sample_rate,
+                self.center_hz as f32,
+            ));
+            self.next_id = self.next_id.wrapping_add(1);
+        }
+    }
+
+    /// Feed mono PCM samples; returns any text decoded in this block.
+    pub fn process_samples(&mut self, samples: &[f32]) -> Vec<PskEvent> {
+        let hop = self.fft_len / 2;
+        let mut rest = samples;
+        while !rest.is_empty() {
+            let take = rest.len().min(hop - self.since_scan);
+            let (chunk, tail) = rest.split_at(take);
+            rest = tail;
+            for ch in &mut self.channels {
+                ch.process(chunk);
+            }
+            for &x in chunk {
+                self.scan_buf[self.scan_pos] = x;
+                self.scan_pos = (self.scan_pos + 1) % self.fft_len;
+            }
+            self.since_scan += take;
+            if self.since_scan >= hop {
+                self.since_scan = 0;
+                self.scan(hop as f32 / self.sample_rate as f32);
+            }
+        }
+
+        let mode = self.mode;
+        self.channels
+            .iter_mut()
+            .filter_map(|ch| {
+                ch.take_text().map(|text| PskEvent {
+                    rig_id: None,
+                    ts_ms: None,
+                    mode,
+                    channel: ch.id,
+                    freq_hz: ch.freq_hz(),
+                    snr_db: ch.snr_db,
+                    text,
+                })
+            })
+            .collect()
+    }
+
+    fn scan(&mut self, elapsed_secs: f32) {
+        let mut spectrum: Vec<Complex32> = (0..self.fft_len)
+            .map(|i| {
+                let x = self.scan_buf[(self.scan_pos + i) % self.fft_len];
+                Complex32::new(x * self.window[i], 0.0)
+            })
+            .collect();
+        self.fft.process(&mut spectrum);
+        let alpha = if self.scans == 0 { 1.0 } else { 0.3 };
+        for (avg, bin) in self.avg_power.iter_mut().zip(spectrum.iter()) {
+            *avg += alpha * (bin.norm_sqr() - *avg);
+        }
+        self.scans = self.scans.saturating_add(1);
+
+        let baud = self.mode.baud();
+        let bin_hz = self.sample_rate as f32 / self.fft_len as f32;
+        let half_band = ((0.75 * baud / bin_hz).ceil() as usize).max(1);
+        let nyquist_limit = self.sample_rate as f32 / 2.0 - baud;
+        let lo = ((SCAN_MIN_HZ / bin_hz) as usize).max(half_band);
+        let hi = ((SCAN_MAX_HZ.min(nyquist_limit) / bin_hz) as usize)
+            .min(self.avg_power.len().saturating_sub(half_band + 1));
+        if hi <= lo {
+            return;
+        }
+
+        let band_power =
+            |k: usize| -> f32 { self.avg_power[k - half_band..=k + half_band].iter().sum() };
+        let mut sorted: Vec<f32> = self.avg_power[lo..hi].to_vec();
+        sorted.sort_by(f32::total_cmp);
+        let noise_bin = sorted[sorted.len() / 2].max(f32::MIN_POSITIVE);
+        let noise_band = noise_bin * (2 * half_band + 1) as f32;
+        let noise_ref = noise_bin / bin_hz * SNR_REF_BW_HZ;
+
+        for ch in &mut self.channels {
+            let k = (ch.freq_hz() / bin_hz).round() as usize;
+            if k >= half_band && k + half_band < self.avg_power.len() {
+                let signal = (band_power(k) - noise_band).max(noise_ref * 1e-4);
+                ch.snr_db = 10.0 * (signal / noise_ref).log10();
+            }
+        }
+
+        if !self.waterfall {
+            return;
+        }
+
+        // Close channels that lost their signal, remembering the spot so a
+        // steady non-PSK carrier does not reopen a channel every few seconds.
+        let mut closed = Vec::new();
+        self.channels.retain(|ch| {
+            let keep = ch.quiet_secs() < CHANNEL_TIMEOUT_SECS;
+            if !keep {
+                closed.push(ch.freq_hz());
+            }
+            keep
+        });
+        for cd in &mut self.cooldowns {
+            cd.1 -= elapsed_secs;
+        }
+        self.cooldowns.retain(|cd| cd.1 > 0.0);
+        self.cooldowns
+            .extend(closed.into_iter().map(|f| (f, RESPAWN_COOLDOWN_SECS)));
+
+        // Merge channels that AFC pulled onto the same signal; keep the older.
+        self.channels.sort_by_key(|ch| ch.id);
+        let mut idx = 0;
+        while idx < self.channels.len() {
+            let f = self.channels[idx].freq_hz();
+            let mut j = idx + 1;
+            while j < self.channels.len() {
+                if (self.channels[j].freq_hz() - f).abs() < baud / 2.0 {
+                    self.channels.remove(j);
+                } else {
+                    j += 1;
+                }
+            }
+            idx += 1;
+        }
+
+        let span = 2 * half_band;
+        for k in lo..hi {
+            if self.channels.len() >= MAX_CHANNELS {
+                break;
+            }
+            let power = band_power(k);
+            if power < noise_band * SPAWN_THRESHOLD {
+                continue;
+            }
+            let from = k.saturating_sub(span).max(lo);
+            let to = (k + span).min(hi - 1);
+            if (from..=to).any(|n| band_power(n) > power) {
+                continue;
+            }
+            let window = &self.avg_power[k - half_band..=k + half_band];
+            let total: f32 = window.iter().sum();
+            let centroid = window
+                .iter()
+                .enumerate()
+                .map(|(i, p)| (k - half_band + i) as f32 * p)
+                .sum::<f32>()
+                / total;
+            let freq = centroid * bin_hz;
+            let taken = self
+                .channels
+                .iter()
+                .map(|ch| ch.freq_hz())
+                .chain(self.cooldowns.iter().map(|cd| cd.0))
+                .any(|f| (f - freq).abs() < baud);
+            if taken {
+                continue;
+            }
+            self.channels.push(PskChannel::new(
+                self.next_id,
+                self.mode,
+                self.sample_rate,
+                freq,
+            ));
+            self.next_id = self.next_id.wrapping_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::varicode::varicode_encode;
+    use crate::viterbi::{symbol_rotation, ConvEncoder};
+
+    const SAMPLE_RATE: u32 = 8000;
+
+    fn text_bits(text: &str) -> Vec<bool> {
+        let mut bits = vec![false; 40];
+        for b in text.bytes() {
+            bits.extend(varicode_encode(b).bytes().map(|c| c == b'1'));
+            bits.extend([false, false]);
+        }
+        bits.extend(std::iter::repeat_n(false, 40));
+        bits
+    }
+
+    /// Raised-cosine shaped differential PSK, as transmitted by G3PLX's
+    /// reference implementation.
+    fn modulate(mode: PskMode, freq_hz: f32, text: &str, amplitude: f32) -> Vec<f32> {
+        let symbols: Vec<u8> = if mode.is_qpsk() {
+            let mut enc = ConvEncoder::default();
+            text_bits(text).into_iter().map(|b| enc.encode(b)).collect()
+        } else {
+            text_bits(text)
+                .into_iter()
+                .map(|b| (b as u8) << 1)
+                .collect()
+        };
+        let sps = (SAMPLE_RATE as f32 / mode.baud()) as usize;
+        let mut out = Vec::with_capacity(symbols.len() * sps);
+        let mut prev = Complex32::new(1.0, 0.0);
+        let mut n = 0usize;
+        for sym in symbols {
+            let cur = prev * symbol_rotation(sym);
+            for i in 0..sps {
+                let w = 0.5 + 0.5 * (std::f32::consts::PI * i as f32 / sps as f32).cos();
+                let bb = prev * w + cur * (1.0 - w);
+                let phase = 2.0 * std::f32::consts::PI * freq_hz * n as f32 / SAMPLE_RATE as f32;
+                out.push(amplitude * (bb * Complex32::from_polar(1.0, phase)).re);
+                n += 1;
+            }
+            prev = cur;
+        }
+        out
+    }
+
+    fn decode_all(decoder: &mut PskDecoder, audio: &[f32]) -> Vec<PskEvent> {
+        audio
+            .chunks(1024)
+            .flat_map(|chunk| decoder.process_samples(chunk))
+            .collect()
+    }
+
+    fn joined(events: &[PskEvent], channel_freq: Option<f32>) -> String {
+        events
+            .iter()
+            .filter(|e| channel_freq.is_none_or(|f| (e.freq_hz - f).abs() < 20.0))
+            .map(|e| e.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn decodes_bpsk31_with_frequency_offset() {
+        let audio = modulate(PskMode::Bpsk31, 1003.0, "cq cq de sp2sjg sp2sjg k", 0.5);
+        let mut decoder = PskDecoder::new(SAMPLE_RATE);
+        decoder.set_center_hz(1000);
+        let events = decode_all(&mut decoder, &audio);
+        let text = joined(&events, None);
+        assert!(text.contains("de sp2sjg sp2sjg"), "decoded {text:?}");
+        let last = events.last().expect("events");
+        assert!(
+            (last.freq_hz - 1003.0).abs() < 1.0,
+            "afc at {}",
+            last.freq_hz
+        );
+        assert_eq!(last.mode, PskMode::Bpsk31);
+    }
+
+    #[test]
+    fn decodes_bpsk63() {
+        let audio = modulate(PskMode::Bpsk63, 1500.0, "TEST 73 de SP2SJG", 0.5);
+        let mut decoder = PskDecoder::new(SAMPLE_RATE);
+        decoder.set_mode(PskMode::Bpsk63);
+        decoder.set_center_hz(1500);
+        let text = joined(&decode_all(&mut decoder, &audio), None);
+        assert!(text.contains("73 de SP2SJG"), "decoded {text:?}");
+    }
+
+    #[test]
+    fn decodes_qpsk31() {
+        let audio = modulate(PskMode::Qpsk31, 1200.0, "qpsk test message 123", 0.5);
+        let mut decoder = PskDecoder::new(SAMPLE_RATE);
+        decoder.set_mode(PskMode::Qpsk31);
+        decoder.set_center_hz(1200);
+        let text = joined(&decode_all(&mut decoder, &audio), None);
+        assert!(text.contains("test message 123"), "decoded {text:?}");
+    }
+
+    #[test]
+    fn waterfall_mode_separates_signals() {
+        let a = modulate(PskMode::Bpsk31, 800.0, "first station calling", 0.3);
+        let b = modulate(PskMode::Bpsk31, 1400.0, "second station here", 0.3);
+        let len = a.len().max(b.len());
+        let mix: Vec<f32> = (0..len)
+            .map(|i| a.get(i).copied().unwrap_or(0.0) + b.get(i).copied().unwrap_or(0.0))
+            .collect();
+        let mut decoder = PskDecoder::new(SAMPLE_RATE);
+        decoder.set_waterfall(true);
+        let events = decode_all(&mut decoder, &mix);
+        let low = joined(&events, Some(800.0));
+        let high = joined(&events, Some(1400.0));
+        assert!(low.contains("station calling"), "800 Hz decoded {low:?}");
+        assert!(high.contains("station here"), "1400 Hz decoded {high:?}");
+        assert!(events.iter().all(|e| e.snr_db > 10.0));
+    }
+
+    #[test]
+    fn silence_produces_nothing() {
+        let mut decoder = PskDecoder::new(SAMPLE_RATE);
+        decoder.set_waterfall(true);
+        let noise: Vec<f32> = (0..SAMPLE_RATE as usize * 4)
+            .map(|i| (((i as u32).wrapping_mul(2_654_435_761) >> 16) as f32 / 65536.0 - 0.5) * 0.01)
+            .collect();
+        assert!(decode_all(&mut decoder, &noise).is_empty());
+    }
+}
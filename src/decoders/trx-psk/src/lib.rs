@@ -0,0 +1,17 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! PSK31/PSK63/PSK125 and QPSK31 keyboard-mode decoder.
+//!
+//! A single decoder either follows one carrier at a user-selected audio
+//! offset or, in waterfall mode, scans the passband and runs an independent
+//! demodulator for every PSK signal it finds.
+
+mod channel;
+mod decoder;
+mod varicode;
+mod viterbi;
+
+pub use decoder::PskDecoder;
+pub use varicode::{varicode_decode, varicode_encode};
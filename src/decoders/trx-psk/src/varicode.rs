@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! PSK31 varicode (G3PLX).
+//!
+//! Every code starts and ends with a `1` and never contains `00`, so two
+//! consecutive zero bits mark a character boundary.
+
+#[rustfmt::skip]
+const VARICODE: [&str; 128] = [
+    // NUL SOH STX ETX
+    "1010101011", "1011011011", "1011101101", "1101110111",
+    // EOT ENQ ACK BEL
+    "1011101011", "1101011111", "1011101111", "1011111101",
+    // BS HT LF VT
+    "1011111111", "11101111", "11101", "1101101111",
+    // FF CR SO SI
+    "1011011101", "11111", "1101110101", "1110101011",
+    // DLE DC1 DC2 DC3
+    "1011110111", "1011110101", "1110101101", "1110101111",
+    // DC4 NAK SYN ETB
+    "1101011011", "1101101011", "1101101101", "1101010111",
+    // CAN EM SUB ESC
+    "1101111011", "1101111101", "1110110111", "1101010101",
+    // FS GS RS US
+    "1101011101", "1110111011", "1011111011", "1101111111",
+    // SP ! " #
+    "1", "111111111", "101011111", "111110101",
+    // $ % & '
+    "111011011", "1011010101", "1010111011", "101111111",
+    // ( ) * +
+    "11111011", "11110111", "101101111", "111011111",
+    // , - . /
+    "1110101", "110101", "1010111", "110101111",
+    // 0 1 2 3
+    "10110111", "10111101", "11101101", "11111111",
+    // 4 5 6 7
+    "101110111", "101011011", "101101011", "110101101",
+    // 8 9 : ;
+    "110101011", "110110111", "11110101", "110111101",
+    // < = > ?
+    "111101101", "1010101", "111010111", "1010101111",
+    // @ A B C
+    "1010111101", "1111101", "11101011", "10101101",
+    // D E F G
+    "10110101", "1110111", "11011011", "11111101",
+    // H I J K
+    "101010101", "1111111", "111111101", "101111101",
+    // L M N O
+    "11010111", "10111011", "11011101", "10101011",
+    // P Q R S
+    "11010101", "111011101", "10101111", "1101111",
+    // T U V W
+    "1101101", "101010111", "110110101", "101011101",
+    // X Y Z [
+    "101110101", "101111011", "1010101101", "111110111",
+    // \ ] ^ _
+    "111101111", "111111011", "1010111111", "101101101",
+    // ` a b c
+    "1011011111", "1011", "1011111", "101111",
+    // d e f g
+    "101101", "11", "111101", "1011011",
+    // h i j k
+    "101011", "1101", "111101011", "10111111",
+    // l m n o
+    "11011", "111011", "1111", "111",
+    // p q r s
+    "111111", "110111111", "10101", "10111",
+    // t u v w
+    "101", "110111", "1111011", "1101011",
+    // x y z {
+    "11011111", "1011101", "111010101", "1010110111",
+    // | } ~ DEL
+    "110111011", "1010110101", "1011010111", "1110110101",
+];
+
+/// Longest code in the table, in bits.
+pub(crate) const MAX_CODE_BITS: u32 = 10;
+
+/// Return the varicode bit string for an ASCII byte (high bit ignored).
+pub fn varicode_encode(byte: u8) -> &'static str {
+    VARICODE[(byte & 0x7f) as usize]
+}
+
+/// Look up a received code (without the trailing `00` separator).
+pub fn varicode_decode(code: u32) -> Option<u8> {
+    if code == 0 {
+        return None;
+    }
+    VARICODE
+        .iter()
+        .position(|entry| {
+            entry
+                .bytes()
+                .fold(0u32, |acc, b| (acc << 1) | (b == b'1') as u32)
+                == code
+        })
+        .map(|idx| idx as u8)
+}
+
+/// Bit-serial varicode deframer.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct VaricodeDeframer {
+    shreg: u32,
+    bits: u32,
+}
+
+impl VaricodeDeframer {
+    /// Feed one received bit; returns a byte when a character boundary
+    /// (`00`) completes a valid code.
+    pub fn push_bit(&mut self, bit: bool) -> Option<u8> {
+        self.shreg = (self.shreg << 1) | bit as u32;
+        self.bits += 1;
+        if self.shreg & 0b11 == 0 {
+            let code = self.shreg >> 2;
+            self.shreg = 0;
+            self.bits = 0;
+            return varicode_decode(code);
+        }
+        if self.bits > MAX_CODE_BITS + 2 {
+            // Noise: no separator seen within the longest code.
+            self.shreg = 0;
+            self.bits = 0;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_prefix_free_and_zero_run_free() {
+        for (i, code) in VARICODE.iter().enumerate() {
+            assert!(code.starts_with('1') && code.ends_with('1'), "{i}");
+            assert!(!code.contains("00"), "{i}");
+            assert!(code.len() as u32 <= MAX_CODE_BITS, "{i}");
+            for (j, other) in VARICODE.iter().enumerate() {
+                if i != j {
+                    assert_ne!(code, other, "{i} and {j} collide");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deframer_roundtrip() {
+        let text = b"CQ CQ de SP2SJG pse k\n";
+        let mut deframer = VaricodeDeframer::default();
+        let mut out = Vec::new();
+        // Leading idle zeros must not produce characters.
+        for _ in 0..8 {
+            assert!(deframer.push_bit(false).is_none());
+        }
+        for &b in text {
+            for bit in varicode_encode(b)
+                .bytes()
+                .map(|c| c == b'1')
+                .chain([false, false])
+            {
+                if let Some(ch) = deframer.push_bit(bit) {
+                    out.push(ch);
+                }
+            }
+        }
+        assert_eq!(out, text);
+    }
+}
@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Rate 1/2, K=5 convolutional code used by QPSK31.
+
+use num_complex::Complex32;
+
+const POLY1: u32 = 0x17;
+const POLY2: u32 = 0x19;
+const NUM_STATES: usize = 16;
+/// Traceback depth in bits; also the decoder latency.
+const DEPTH: u32 = 20;
+
+fn parity(x: u32) -> u32 {
+    x.count_ones() & 1
+}
+
+/// Encoder output (two-bit symbol) for a 5-bit shift register value.
+fn encode_shreg(shreg: u32) -> u8 {
+    (parity(shreg & POLY1) | (parity(shreg & POLY2) << 1)) as u8
+}
+
+/// Differential phase rotation transmitted for each two-bit symbol.
+///
+/// Symbol `2` is no phase change and `0` a reversal, so plain BPSK is the
+/// special case `symbol = bit << 1`.
+pub(crate) fn symbol_rotation(symbol: u8) -> Complex32 {
+    match symbol & 3 {
+        0 => Complex32::new(-1.0, 0.0),
+        1 => Complex32::new(0.0, -1.0),
+        2 => Complex32::new(1.0, 0.0),
+        _ => Complex32::new(0.0, 1.0),
+    }
+}
+
+/// Reference encoder, used to synthesise test signals.
+#[cfg(test)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConvEncoder {
+    shreg: u32,
+}
+
+#[cfg(test)]
+impl ConvEncoder {
+    pub fn encode(&mut self, bit: bool) -> u8 {
+        self.shreg = ((self.shreg << 1) | bit as u32) & 0x1f;
+        encode_shreg(self.shreg)
+    }
+}
+
+/// Soft-decision Viterbi decoder fed with normalised differential phasors.
+#[derive(Debug, Clone)]
+pub(crate) struct ViterbiDecoder {
+    metrics: [f32; NUM_STATES],
+    paths: [u64; NUM_STATES],
+    filled: u32,
+}
+
+impl Default for ViterbiDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViterbiDecoder {
+    pub fn new() -> Self {
+        let mut metrics = [f32::NEG_INFINITY; NUM_STATES];
+        metrics[0] = 0.0;
+        Self {
+            metrics,
+            paths: [0; NUM_STATES],
+            filled: 0,
+        }
+    }
+
+    /// Feed one received symbol. Returns a decoded bit once the traceback
+    /// window is full.
+    pub fn push(&mut self, diff: Complex32) -> Option<bool> {
+        let mut next_metrics = [f32::NEG_INFINITY; NUM_STATES];
+        let mut next_paths = [0u64; NUM_STATES];
+        for (state, &metric) in self.metrics.iter().enumerate() {
+            if metric == f32::NEG_INFINITY {
+                continue;
+            }
+            for bit in 0..2u32 {
+                let shreg = ((state as u32) << 1) | bit;
+                let expected = symbol_rotation(encode_shreg(shreg));
+                let branch = (diff * expected.conj()).re;
+                let next = (shreg & 0xf) as usize;
+                let candidate = metric + branch;
+                if candidate > next_metrics[next] {
+                    next_metrics[next] = candidate;
+                    next_paths[next] = (self.paths[state] << 1) | bit as u64;
+                }
+            }
+        }
+        // Keep metrics bounded.
+        let best = next_metrics
+            .iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, f32::max);
+        for m in next_metrics.iter_mut() {
+            if *m != f32::NEG_INFINITY {
+                *m -= best;
+            }
+        }
+        self.metrics = next_metrics;
+        self.paths = next_paths;
+
+        if self.filled < DEPTH {
+            self.filled += 1;
+            return None;
+        }
+        let best_state = (0..NUM_STATES)
+            .max_by(|&a, &b| self.metrics[a].total_cmp(&self.metrics[b]))
+            .unwrap_or(0);
+        Some((self.paths[best_state] >> DEPTH) & 1 != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_roundtrip_after_latency() {
+        let bits: Vec<bool> = (0..200u32).map(|i| (i * 7 + i / 3) % 5 < 2).collect();
+        let mut enc = ConvEncoder::default();
+        let mut dec = ViterbiDecoder::new();
+        let mut out = Vec::new();
+        for &b in bits
+            .iter()
+            .chain(std::iter::repeat_n(&false, DEPTH as usize))
+        {
+            if let Some(d) = dec.push(symbol_rotation(enc.encode(b))) {
+                out.push(d);
+            }
+        }
+        assert_eq!(&out[..bits.len()], &bits[..]);
+    }
+
+    #[test]
+    fn corrects_isolated_symbol_errors() {
+        let bits: Vec<bool> = (0..200u32).map(|i| (i * 11) % 3 == 0).collect();
+        let mut enc = ConvEncoder::default();
+        let mut dec = ViterbiDecoder::new();
+        let mut out = Vec::new();
+        for (i, &b) in bits
+            .iter()
+            .chain(std::iter::repeat_n(&false, DEPTH as usize))
+            .enumerate()
+        {
+            let mut rx = symbol_rotation(enc.encode(b));
+            if i % 25 == 10 {
+                // Flip to the adjacent constellation point.
+                rx *= Complex32::new(0.0, 1.0);
+            }
+            if let Some(d) = dec.push(rx) {
+                out.push(d);
+            }
+        }
+        assert_eq!(&out[..bits.len()], &bits[..]);
+    }
+}
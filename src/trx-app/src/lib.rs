@@ -5,6 +5,7 @@
 pub mod config;
 pub mod logging;
 pub mod shared_config;
+pub mod tls;
 pub mod util;
 
 pub use config::{ConfigError, ConfigFile};
@@ -17,23 +17,25 @@ use tokio::net::TcpStream;
 use tokio::sync::{broadcast, mpsc, watch};
 use tokio::time;
 use tracing::{info, warn};
+use trx_app::tls::{TlsConnector, TransportStream};
 use trx_frontend::RemoteRigEntry;
 
 use uuid::Uuid;
 
-use crate::remote_client::RemoteEndpoint;
+use crate::remote_client::{MuxConnector, RemoteEndpoint};
 use trx_core::audio::{
     parse_vchan_audio_frame, parse_vchan_uuid_msg, read_audio_msg, write_audio_msg,
-    write_vchan_uuid_msg, AudioStreamInfo, AUDIO_MSG_AIS_DECODE, AUDIO_MSG_APRS_DECODE,
-    AUDIO_MSG_CW_DECODE, AUDIO_MSG_FT2_DECODE, AUDIO_MSG_FT4_DECODE, AUDIO_MSG_FT8_DECODE,
+    write_vchan_uuid_msg, AudioStreamInfo, AUDIO_MSG_ACARS_DECODE, AUDIO_MSG_ADSB_DECODE, AUDIO_MSG_AIS_DECODE, AUDIO_MSG_APRS_DECODE,
+    AUDIO_MSG_CW_DECODE, AUDIO_MSG_CW_SPOT, AUDIO_MSG_DSC_DECODE, AUDIO_MSG_FT2_DECODE, AUDIO_MSG_FT4_DECODE, AUDIO_MSG_FT8_DECODE,
     AUDIO_MSG_HF_APRS_DECODE, AUDIO_MSG_HISTORY_COMPRESSED, AUDIO_MSG_LRPT_IMAGE,
-    AUDIO_MSG_LRPT_PROGRESS, AUDIO_MSG_RX_FRAME, AUDIO_MSG_RX_FRAME_CH, AUDIO_MSG_STREAM_INFO,
+    AUDIO_MSG_LRPT_PROGRESS, AUDIO_MSG_POCSAG_DECODE, AUDIO_MSG_PSK_DECODE, AUDIO_MSG_RX_FRAME, AUDIO_MSG_RX_FRAME_CH, AUDIO_MSG_STREAM_INFO,
     AUDIO_MSG_TX_FRAME, AUDIO_MSG_VCHAN_ALLOCATED, AUDIO_MSG_VCHAN_BW, AUDIO_MSG_VCHAN_DESTROYED,
     AUDIO_MSG_VCHAN_FREQ, AUDIO_MSG_VCHAN_MODE, AUDIO_MSG_VCHAN_REMOVE, AUDIO_MSG_VCHAN_SUB,
     AUDIO_MSG_VCHAN_UNSUB, AUDIO_MSG_VDES_DECODE, AUDIO_MSG_WEFAX_DECODE, AUDIO_MSG_WEFAX_PROGRESS,
     AUDIO_MSG_WSPR_DECODE,
 };
 use trx_core::decode::DecodedMessage;
+use trx_core::mux::MuxOpen;
 use trx_frontend::VChanAudioCmd;
 
 #[derive(Clone, Debug)]
@@ -45,11 +47,18 @@ struct ActiveVChanSub {
     decoder_kinds: Vec<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct AudioConnectConfig {
     pub server_host: String,
     pub default_port: u16,
     pub fixed_addr: Option<String>,
+    /// TLS connector for the audio link; `None` for plaintext.
+    pub tls: Option<TlsConnector>,
+    /// Open audio as a stream of this multiplexed session instead.
+    pub mux: Option<MuxConnector>,
+    /// Server rig the multiplexed audio stream asks for; `None` selects the
+    /// server's default rig.
+    pub server_rig_id: Option<String>,
 }
 
 impl AudioConnectConfig {
@@ -58,6 +67,9 @@ impl AudioConnectConfig {
             server_host,
             default_port,
             fixed_addr: None,
+            tls: None,
+            mux: None,
+            server_rig_id: None,
         }
     }
 
@@ -66,8 +78,22 @@ impl AudioConnectConfig {
             server_host: String::new(),
             default_port: 0,
             fixed_addr: Some(addr),
+            tls: None,
+            mux: None,
+            server_rig_id: None,
         }
     }
+
+    pub fn with_tls(mut self, tls: Option<TlsConnector>) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    pub fn with_mux(mut self, mux: MuxConnector, server_rig_id: Option<String>) -> Self {
+        self.mux = Some(mux);
+        self.server_rig_id = server_rig_id;
+        self
+    }
 }
 
 /// Per-rig audio task state, tracked by the multi-rig manager.
@@ -188,10 +214,15 @@ pub async fn run_multi_rig_audio_manager(
                     let tx_rx_clone = tx_rx.clone();
                     let addr = addr.clone();
                     let task_addr = addr.clone();
+                    let task_connect = rig_connect
+                        .get(rig_id)
+                        .unwrap_or(&default_connect)
+                        .clone();
 
                     let handle = tokio::spawn(async move {
                         run_single_rig_audio_client(
                             task_addr,
+                            task_connect,
                             rig_id_clone,
                             selected_clone,
                             per_rig_rx_tx,
@@ -242,10 +273,15 @@ fn resolve_audio_addr(
     if let Some(addr) = &connect.fixed_addr {
         return addr.clone();
     }
+    if let Some(mux) = &connect.mux {
+        return format!("{} (multiplexed)", mux.addr());
+    }
 
     RemoteEndpoint {
         host: connect.server_host.clone(),
         port: advertised_port.unwrap_or(connect.default_port),
+        tls: Default::default(),
+        uplink: false,
     }
     .connect_addr()
 }
@@ -256,6 +292,7 @@ fn resolve_audio_addr(
 #[allow(clippy::too_many_arguments)]
 async fn run_single_rig_audio_client(
     server_addr: String,
+    connect: AudioConnectConfig,
     rig_id: String,
     selected_rig_id: Arc<Mutex<Option<String>>>,
     per_rig_rx_tx: broadcast::Sender<Bytes>,
@@ -287,7 +324,7 @@ async fn run_single_rig_audio_client(
         }
 
         info!("Audio client [{}]: connecting to {}", rig_id, server_addr);
-        match TcpStream::connect(&server_addr).await {
+        match connect_audio(&server_addr, &connect).await {
             Ok(stream) => {
                 reconnect_delay = Duration::from_secs(1);
                 if let Err(e) = handle_single_rig_connection(
@@ -395,11 +432,21 @@ mod tests {
     }
 }
 
+/// Open the audio connection, running the TLS handshake when configured,
+/// or an audio stream on the remote's multiplexed session.
+async fn connect_audio(addr: &str, connect: &AudioConnectConfig) -> std::io::Result<TransportStream> {
+    if let Some(mux) = &connect.mux {
+        return mux.open(&MuxOpen::audio(connect.server_rig_id.clone())).await;
+    }
+    let stream = TcpStream::connect(addr).await?;
+    TransportStream::connect(stream, connect.tls.as_ref()).await
+}
+
 /// Handle a single TCP connection for one rig.  Similar to `handle_audio_connection`
 /// but publishes to per-rig channels directly and mirrors to global when selected.
 #[allow(clippy::too_many_arguments)]
 async fn handle_single_rig_connection(
-    stream: TcpStream,
+    stream: TransportStream,
     rig_id: &str,
     selected_rig_id: &Arc<Mutex<Option<String>>>,
     per_rig_rx_tx: &broadcast::Sender<Bytes>,
@@ -415,7 +462,7 @@ async fn handle_single_rig_connection(
     active_subs: &mut HashMap<Uuid, ActiveVChanSub>,
     vchan_destroyed_tx: &Option<broadcast::Sender<Uuid>>,
 ) -> std::io::Result<()> {
-    let (reader, writer) = stream.into_split();
+    let (reader, writer) = tokio::io::split(stream);
     let mut reader = BufReader::new(reader);
     let mut writer = tokio::io::BufWriter::new(writer);
 
@@ -565,6 +612,12 @@ async fn handle_single_rig_connection(
                     | AUDIO_MSG_APRS_DECODE
                     | AUDIO_MSG_HF_APRS_DECODE
                     | AUDIO_MSG_CW_DECODE
+                    | AUDIO_MSG_PSK_DECODE
+                    | AUDIO_MSG_DSC_DECODE
+                    | AUDIO_MSG_POCSAG_DECODE
+                    | AUDIO_MSG_ADSB_DECODE
+                    | AUDIO_MSG_ACARS_DECODE
+                    | AUDIO_MSG_CW_SPOT
                     | AUDIO_MSG_FT8_DECODE
                     | AUDIO_MSG_FT4_DECODE
                     | AUDIO_MSG_FT2_DECODE
@@ -17,7 +17,9 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
+use trx_app::tls::{TlsClientConfig, TlsServerConfig};
 use trx_app::{validate_log_level, validate_tokens, ConfigError, ConfigFile};
+use trx_core::dxspot::SpotFilter;
 
 /// Top-level client configuration structure.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -33,6 +35,10 @@ pub struct ClientConfig {
     pub remotes: Vec<RemoteEntry>,
     /// Frontend configurations
     pub frontends: FrontendsConfig,
+    /// DX cluster / RBN spot feed
+    pub dxcluster: DxClusterConfig,
+    /// Listener for servers that dial in (`uplink://` remotes)
+    pub uplink_listener: UplinkListenerConfig,
 }
 
 /// General application settings.
@@ -67,7 +73,7 @@ impl Default for GeneralConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RemoteConfig {
-    /// Remote URL (host:port or tcp://host:port).
+    /// Remote URL (host:port, tcp://host:port or tls://host:port).
     pub url: Option<String>,
     /// Optional target rig ID on the remote multi-rig server.
     pub rig_id: Option<String>,
@@ -75,6 +81,11 @@ pub struct RemoteConfig {
     pub auth: RemoteAuthConfig,
     /// Poll interval in milliseconds.
     pub poll_interval_ms: u64,
+    /// TLS settings for the control and audio connections.
+    pub tls: TlsClientConfig,
+    /// Carry control, spectrum, meter, state and audio traffic as streams of
+    /// one multiplexed connection instead of one connection each.
+    pub mux: bool,
 }
 
 impl Default for RemoteConfig {
@@ -84,6 +95,8 @@ impl Default for RemoteConfig {
             rig_id: None,
             auth: RemoteAuthConfig::default(),
             poll_interval_ms: 750,
+            tls: TlsClientConfig::default(),
+            mux: false,
         }
     }
 }
@@ -106,7 +119,7 @@ pub struct RemoteAuthConfig {
 pub struct RemoteEntry {
     /// Short name used to identify this remote in frontends and config.
     pub name: String,
-    /// Remote server URL (`host:port` or `tcp://host:port`).
+    /// Remote server URL (`host:port`, `tcp://host:port` or `tls://host:port`).
     pub url: String,
     /// Optional target rig ID on a multi-rig server.
     /// When omitted, the server's default (or only) rig is used.
@@ -117,12 +130,50 @@ pub struct RemoteEntry {
     /// Poll interval in milliseconds. Defaults to 750.
     #[serde(default = "default_poll_interval_ms")]
     pub poll_interval_ms: u64,
+    /// TLS settings for the control and audio connections.
+    #[serde(default)]
+    pub tls: TlsClientConfig,
+    /// Use one multiplexed connection for all traffic to this server.
+    /// Remotes sharing a server and token share the connection, so setting
+    /// it on one of them applies to all.
+    #[serde(default)]
+    pub mux: bool,
 }
 
 fn default_poll_interval_ms() -> u64 {
     750
 }
 
+/// Listener that trx-servers with `[uplink]` dial into.  Remotes reach
+/// such a server as `uplink://<station>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UplinkListenerConfig {
+    /// Whether to accept uplinks
+    pub enabled: bool,
+    /// Listen address
+    pub listen: IpAddr,
+    /// Listen port
+    pub port: u16,
+    /// Token each station's server must present, keyed by station name.
+    /// Every `uplink://` station needs one.
+    pub tokens: HashMap<String, String>,
+    /// TLS settings for accepted uplinks
+    pub tls: TlsServerConfig,
+}
+
+impl Default for UplinkListenerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen: IpAddr::from([0, 0, 0, 0]),
+            port: 4540,
+            tokens: HashMap::new(),
+            tls: TlsServerConfig::default(),
+        }
+    }
+}
+
 /// Frontend configurations.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -131,6 +182,8 @@ pub struct FrontendsConfig {
     pub http: HttpFrontendConfig,
     /// rigctl frontend settings
     pub rigctl: RigctlFrontendConfig,
+    /// flrig XML-RPC frontend settings
+    pub flrig: FlrigFrontendConfig,
     /// JSON TCP frontend settings
     pub http_json: HttpJsonFrontendConfig,
     /// Audio streaming settings
@@ -297,6 +350,8 @@ pub struct HttpFrontendConfig {
     pub decode_history_retention_min: u64,
     /// Optional per-rig decode history retention overrides in minutes.
     pub decode_history_retention_min_by_rig: HashMap<String, u64>,
+    /// Log completed FT8/FT4 exchanges with the own callsign automatically.
+    pub logbook_auto_log: bool,
     /// Authentication settings
     pub auth: HttpAuthConfig,
 }
@@ -316,6 +371,7 @@ impl Default for HttpFrontendConfig {
             bandplan_region: "iaru_r1".to_string(),
             decode_history_retention_min: 24 * 60,
             decode_history_retention_min_by_rig: HashMap::new(),
+            logbook_auto_log: true,
             auth: HttpAuthConfig::default(),
         }
     }
@@ -348,6 +404,30 @@ impl Default for RigctlFrontendConfig {
     }
 }
 
+/// flrig XML-RPC frontend configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FlrigFrontendConfig {
+    /// Whether flrig frontend is enabled
+    pub enabled: bool,
+    /// Listen address
+    pub listen: IpAddr,
+    /// Per-rig flrig listener ports.
+    /// Maps rig ID -> local XML-RPC port (flrig itself uses 12345). One
+    /// listener is spawned per entry, each routing calls to its assigned rig.
+    pub rig_ports: HashMap<String, u16>,
+}
+
+impl Default for FlrigFrontendConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen: IpAddr::from([127, 0, 0, 1]),
+            rig_ports: HashMap::new(),
+        }
+    }
+}
+
 /// JSON TCP frontend configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -379,6 +459,46 @@ impl Default for HttpJsonFrontendConfig {
 pub struct HttpJsonAuthConfig {
     /// Accepted bearer tokens.
     pub tokens: Vec<String>,
+    /// Browser origins (e.g. `https://shack.example.com`) allowed to open
+    /// WebSockets besides the listener's own.
+    pub allowed_origins: Vec<String>,
+}
+
+/// DX cluster or Reverse Beacon Network telnet feed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DxClusterConfig {
+    /// Whether to connect to the cluster node
+    pub enabled: bool,
+    /// Cluster node host name or address
+    pub host: String,
+    /// Cluster node telnet port
+    pub port: u16,
+    /// Callsign sent at the login prompt; defaults to `[general].callsign`
+    pub login: Option<String>,
+    /// Password, for nodes that ask for one
+    pub password: Option<String>,
+    /// Node commands sent after login (e.g. `set/skimmer`)
+    pub commands: Vec<String>,
+    /// Spots older than this are dropped
+    pub max_age_min: u64,
+    /// Which spots to keep
+    pub filter: SpotFilter,
+}
+
+impl Default for DxClusterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "telnet.reversebeacon.net".to_string(),
+            port: 7000,
+            login: None,
+            password: None,
+            commands: Vec::new(),
+            max_age_min: 30,
+            filter: SpotFilter::default(),
+        }
+    }
 }
 
 impl ClientConfig {
@@ -405,6 +525,8 @@ impl ClientConfig {
                 rig_id: self.remote.rig_id.clone(),
                 auth: self.remote.auth.clone(),
                 poll_interval_ms: self.remote.poll_interval_ms,
+                tls: self.remote.tls.clone(),
+                mux: self.remote.mux,
             }]
         } else {
             Vec::new()
@@ -452,6 +574,7 @@ impl ClientConfig {
                         i, entry.name
                     ));
                 }
+                entry.tls.validate(&format!("[[remotes]][{}].tls", i))?;
             }
         }
 
@@ -474,6 +597,7 @@ impl ClientConfig {
                 return Err("[remote.auth].token must not be empty when set".to_string());
             }
         }
+        self.remote.tls.validate("[remote.tls]")?;
         if let Some(url) = &self.general.website_url {
             if url.trim().is_empty() {
                 return Err("[general].website_url must not be empty when set".to_string());
@@ -556,6 +680,23 @@ impl ClientConfig {
                 ));
             }
         }
+        if self.frontends.flrig.enabled && self.frontends.flrig.rig_ports.is_empty() {
+            return Err(
+                "[frontends.flrig].rig_ports must contain at least one rig when enabled"
+                    .to_string(),
+            );
+        }
+        for (rig_id, port) in &self.frontends.flrig.rig_ports {
+            if rig_id.trim().is_empty() {
+                return Err("[frontends.flrig].rig_ports keys must not be empty".to_string());
+            }
+            if *port == 0 {
+                return Err(format!(
+                    "[frontends.flrig].rig_ports[\"{}\"] must be > 0",
+                    rig_id
+                ));
+            }
+        }
         if let Some(url) = &self.frontends.audio.server_url {
             crate::remote_client::parse_audio_url(url)
                 .map_err(|e| format!("[frontends.audio].server_url {e}"))?;
@@ -603,10 +744,58 @@ impl ClientConfig {
         )?;
 
         validate_http_auth(&self.frontends.http.auth)?;
+        validate_dxcluster(&self.dxcluster, self.general.callsign.as_deref())?;
+        self.validate_uplink_listener()?;
 
         Ok(())
     }
 
+    fn validate_uplink_listener(&self) -> Result<(), String> {
+        let listener = &self.uplink_listener;
+        if listener.enabled && listener.port == 0 {
+            return Err("[uplink_listener].port must be > 0 when enabled".to_string());
+        }
+        for (station, token) in &listener.tokens {
+            validate_tokens(
+                &format!("[uplink_listener].tokens.{station}"),
+                std::slice::from_ref(token),
+            )?;
+        }
+        listener.tls.validate("[uplink_listener.tls]")?;
+
+        // One uplink connection carries one session token.
+        let remotes = self.resolved_remotes();
+        let mut station_tokens: HashMap<&str, &Option<String>> = HashMap::new();
+        for entry in &remotes {
+            let Some(station) = entry.url.trim().strip_prefix("uplink://") else {
+                continue;
+            };
+            if !listener.enabled {
+                return Err(format!(
+                    "remote \"{}\" uses uplink:// but [uplink_listener] is not enabled",
+                    entry.name
+                ));
+            }
+            // The client hands its server auth token to whoever dials in as
+            // this station, so that server must prove it is the station.
+            if !listener.tokens.contains_key(station) {
+                return Err(format!(
+                    "uplink station \"{}\" has no token in [uplink_listener].tokens",
+                    station
+                ));
+            }
+            if let Some(token) = station_tokens.insert(station, &entry.auth.token) {
+                if *token != entry.auth.token {
+                    return Err(format!(
+                        "remotes on uplink station \"{}\" must share one auth.token",
+                        station
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Load configuration from a specific file path.
     pub fn load_from_file(path: &Path) -> Result<Self, ConfigError> {
         <Self as ConfigFile>::load_from_file(path)
@@ -644,6 +833,8 @@ impl ClientConfig {
                         token: Some("my-token".to_string()),
                     },
                     poll_interval_ms: 750,
+                    tls: TlsClientConfig::default(),
+                    mux: false,
                 },
                 RemoteEntry {
                     name: "home-vhf".to_string(),
@@ -653,6 +844,8 @@ impl ClientConfig {
                         token: Some("my-token".to_string()),
                     },
                     poll_interval_ms: 750,
+                    tls: TlsClientConfig::default(),
+                    mux: false,
                 },
             ],
             frontends: FrontendsConfig {
@@ -669,6 +862,7 @@ impl ClientConfig {
                     bandplan_region: "iaru_r1".to_string(),
                     decode_history_retention_min: 24 * 60,
                     decode_history_retention_min_by_rig: HashMap::new(),
+                    logbook_auto_log: true,
                     auth: HttpAuthConfig {
                         enabled: false,
                         rx_passphrase: Some("rx-passphrase-example".to_string()),
@@ -685,9 +879,12 @@ impl ClientConfig {
                     port: 4532,
                     rig_ports: HashMap::new(),
                 },
+                flrig: FlrigFrontendConfig::default(),
                 http_json: HttpJsonFrontendConfig::default(),
                 audio: AudioClientConfig::default(),
             },
+            dxcluster: DxClusterConfig::default(),
+            uplink_listener: UplinkListenerConfig::default(),
         };
         toml::to_string_pretty(&Wrapper { inner: example }).unwrap_or_default()
     }
@@ -729,6 +926,31 @@ fn validate_http_auth(auth: &HttpAuthConfig) -> Result<(), String> {
     Ok(())
 }
 
+fn validate_dxcluster(dx: &DxClusterConfig, callsign: Option<&str>) -> Result<(), String> {
+    if !dx.enabled {
+        return Ok(());
+    }
+    if dx.host.trim().is_empty() {
+        return Err("[dxcluster].host must not be empty when enabled".to_string());
+    }
+    if dx.port == 0 {
+        return Err("[dxcluster].port must be > 0 when enabled".to_string());
+    }
+    let login = dx.login.as_deref().or(callsign).unwrap_or("");
+    if login.trim().is_empty() {
+        return Err("[dxcluster] enabled=true requires login or [general].callsign".to_string());
+    }
+    if dx.max_age_min == 0 {
+        return Err("[dxcluster].max_age_min must be > 0".to_string());
+    }
+    if let (Some(min), Some(max)) = (dx.filter.min_freq_hz, dx.filter.max_freq_hz) {
+        if min > max {
+            return Err("[dxcluster.filter].min_freq_hz must not exceed max_freq_hz".to_string());
+        }
+    }
+    Ok(())
+}
+
 impl ConfigFile for ClientConfig {
     fn section_key() -> &'static str {
         "trx-client"
@@ -751,12 +973,15 @@ mod tests {
         assert!(config.frontends.http.bandplan_enabled);
         assert_eq!(config.frontends.http.bandplan_region, "iaru_r1");
         assert_eq!(config.frontends.http.decode_history_retention_min, 1440);
+        assert!(config.frontends.http.logbook_auto_log);
         assert!(config
             .frontends
             .http
             .decode_history_retention_min_by_rig
             .is_empty());
         assert_eq!(config.frontends.rigctl.port, 4532);
+        assert!(!config.frontends.flrig.enabled);
+        assert!(config.frontends.flrig.rig_ports.is_empty());
         assert!(config.frontends.http_json.enabled);
         assert_eq!(config.frontends.http_json.port, 0);
         assert!(config.remote.url.is_none());
@@ -870,6 +1095,102 @@ home-hf = "audio://10.0.0.5:4600"
         );
     }
 
+    #[test]
+    fn test_parse_remote_tls() {
+        let toml_str = r#"
+[[remotes]]
+name = "field"
+url = "tls://field.example.com:4530"
+
+[remotes.tls]
+pin_sha256 = ["55:02:BC:C1:99:E4:3B:AB:16:0F:9B:AB:B2:81:F3:C0:31:D7:69:09:CD:1A:48:2F:38:44:D9:76:1F:BD:F0:70"]
+cert = "/etc/trx/client.pem"
+key = "/etc/trx/client.key"
+"#;
+
+        let mut config: ClientConfig = toml::from_str(toml_str).unwrap();
+        let tls = &config.remotes[0].tls;
+        assert!(!tls.enabled);
+        assert_eq!(tls.pin_sha256.len(), 1);
+        assert_eq!(tls.cert, Some(PathBuf::from("/etc/trx/client.pem")));
+        assert!(config.validate().is_ok());
+
+        config.remotes[0].tls.key = None;
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .contains("[[remotes]][0].tls cert and key"));
+    }
+
+    #[test]
+    fn test_parse_uplink_listener() {
+        let toml_str = r#"
+[uplink_listener]
+enabled = true
+port = 4541
+tokens = { field = "station-secret" }
+
+[[remotes]]
+name = "field-hf"
+url = "uplink://field"
+rig_id = "hf"
+auth = { token = "client-token" }
+
+[[remotes]]
+name = "field-vhf"
+url = "uplink://field"
+rig_id = "vhf"
+auth = { token = "client-token" }
+"#;
+
+        let mut config: ClientConfig = toml::from_str(toml_str).unwrap();
+        assert!(config.uplink_listener.enabled);
+        assert_eq!(config.uplink_listener.port, 4541);
+        assert_eq!(
+            config
+                .uplink_listener
+                .tokens
+                .get("field")
+                .map(String::as_str),
+            Some("station-secret")
+        );
+        assert!(config.validate().is_ok());
+
+        config.remotes[1].auth.token = Some("other".to_string());
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .contains("must share one auth.token"));
+
+        config.remotes[1].auth.token = Some("client-token".to_string());
+        config.uplink_listener.tokens.clear();
+        config
+            .uplink_listener
+            .tokens
+            .insert("home".to_string(), "home-secret".to_string());
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .contains("uplink station \"field\" has no token"));
+
+        config
+            .uplink_listener
+            .tokens
+            .insert("field".to_string(), " ".to_string());
+        assert!(config.validate().unwrap_err().contains("empty tokens"));
+        config
+            .uplink_listener
+            .tokens
+            .insert("field".to_string(), "station-secret".to_string());
+        assert!(config.validate().is_ok());
+
+        config.uplink_listener.enabled = false;
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .contains("[uplink_listener] is not enabled"));
+    }
+
     #[test]
     fn test_example_combined_toml_parses() {
         let example = ClientConfig::example_combined_toml();
@@ -910,6 +1231,21 @@ home-hf = "audio://10.0.0.5:4600"
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_rejects_flrig_without_rig_ports() {
+        let mut config = ClientConfig::default();
+        config.frontends.flrig.enabled = true;
+        assert!(config.validate().is_err());
+        config
+            .frontends
+            .flrig
+            .rig_ports
+            .insert("ft817".to_string(), 12345);
+        assert!(config.validate().is_ok());
+        config.frontends.flrig.rig_ports.insert("hf".to_string(), 0);
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_validate_rejects_invalid_audio_url() {
         let mut config = ClientConfig::default();
@@ -1011,6 +1347,7 @@ name = "home-hf"
 url = "192.168.1.10:4530"
 rig_id = "hf"
 poll_interval_ms = 500
+mux = true
 
 [remotes.auth]
 token = "secret"
@@ -1027,11 +1364,13 @@ url = "remote.example.com:4530"
         assert_eq!(config.remotes[0].rig_id, Some("hf".to_string()));
         assert_eq!(config.remotes[0].auth.token, Some("secret".to_string()));
         assert_eq!(config.remotes[0].poll_interval_ms, 500);
+        assert!(config.remotes[0].mux);
         assert_eq!(config.remotes[1].name, "remote");
         assert_eq!(config.remotes[1].url, "remote.example.com:4530");
         assert!(config.remotes[1].rig_id.is_none());
         assert!(config.remotes[1].auth.token.is_none());
         assert_eq!(config.remotes[1].poll_interval_ms, 750); // default
+        assert!(!config.remotes[1].mux);
         assert!(config.validate().is_ok());
     }
 
@@ -1044,6 +1383,8 @@ url = "remote.example.com:4530"
                 rig_id: None,
                 auth: RemoteAuthConfig::default(),
                 poll_interval_ms: 750,
+                tls: TlsClientConfig::default(),
+                mux: false,
             }],
             ..Default::default()
         };
@@ -1062,6 +1403,8 @@ url = "remote.example.com:4530"
                     token: Some("tok".to_string()),
                 },
                 poll_interval_ms: 750,
+                tls: TlsClientConfig::default(),
+                mux: false,
             },
             ..Default::default()
         };
@@ -1100,6 +1443,8 @@ url = "remote.example.com:4530"
                 rig_id: None,
                 auth: RemoteAuthConfig::default(),
                 poll_interval_ms: 750,
+                tls: TlsClientConfig::default(),
+                mux: false,
             }],
             ..Default::default()
         };
@@ -1118,6 +1463,8 @@ url = "remote.example.com:4530"
                 rig_id: None,
                 auth: RemoteAuthConfig::default(),
                 poll_interval_ms: 750,
+                tls: TlsClientConfig::default(),
+                mux: false,
             },
             RemoteEntry {
                 name: "dup".to_string(),
@@ -1125,6 +1472,8 @@ url = "remote.example.com:4530"
                 rig_id: None,
                 auth: RemoteAuthConfig::default(),
                 poll_interval_ms: 750,
+                tls: TlsClientConfig::default(),
+                mux: false,
             },
         ];
         assert!(config.validate().unwrap_err().contains("duplicate name"));
@@ -1139,6 +1488,8 @@ url = "remote.example.com:4530"
             rig_id: None,
             auth: RemoteAuthConfig::default(),
             poll_interval_ms: 750,
+            tls: TlsClientConfig::default(),
+            mux: false,
         }];
         assert!(config
             .validate()
@@ -1155,6 +1506,8 @@ url = "remote.example.com:4530"
             rig_id: None,
             auth: RemoteAuthConfig::default(),
             poll_interval_ms: 750,
+            tls: TlsClientConfig::default(),
+            mux: false,
         }];
         assert!(config
             .validate()
@@ -1171,6 +1524,8 @@ url = "remote.example.com:4530"
             rig_id: None,
             auth: RemoteAuthConfig::default(),
             poll_interval_ms: 0,
+            tls: TlsClientConfig::default(),
+            mux: false,
         }];
         assert!(config
             .validate()
@@ -1209,4 +1564,39 @@ bandplan_region = "iaru_r2"
         assert!(!config.frontends.http.bandplan_enabled);
         assert_eq!(config.frontends.http.bandplan_region, "iaru_r2");
     }
+
+    #[test]
+    fn test_parse_dxcluster_config_from_toml() {
+        let toml_str = r#"
+[general]
+callsign = "N0CALL"
+
+[dxcluster]
+enabled = true
+host = "dxc.example.net"
+port = 7300
+commands = ["set/skimmer"]
+
+[dxcluster.filter]
+modes = ["CW"]
+calls = ["DL*"]
+"#;
+        let config: ClientConfig = toml::from_str(toml_str).unwrap();
+        assert!(config.dxcluster.enabled);
+        assert_eq!(config.dxcluster.host, "dxc.example.net");
+        assert_eq!(config.dxcluster.port, 7300);
+        assert_eq!(config.dxcluster.max_age_min, 30);
+        assert_eq!(config.dxcluster.filter.modes, vec!["CW".to_string()]);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_dxcluster_requires_login() {
+        let mut config = ClientConfig::default();
+        config.general.callsign = None;
+        config.dxcluster.enabled = true;
+        assert!(config.validate().unwrap_err().contains("[dxcluster]"));
+        config.dxcluster.login = Some("N0CALL".to_string());
+        assert!(config.validate().is_ok());
+    }
 }
@@ -6,6 +6,7 @@ mod audio_bridge;
 mod audio_client;
 mod config;
 mod remote_client;
+mod uplink_listener;
 
 use std::collections::HashMap;
 use std::net::{IpAddr, SocketAddr};
@@ -19,21 +20,25 @@ use tokio::sync::{broadcast, mpsc, watch};
 use tokio::task::JoinHandle;
 use tracing::{error, info};
 
+use trx_app::tls::{TlsClientConfig, TlsConnector};
 use trx_app::{init_logging, normalize_name};
 use trx_core::audio::AudioStreamInfo;
 
 use trx_core::decode::DecodedMessage;
+use trx_core::dxspot::SpotTable;
 use trx_core::rig::request::RigRequest;
 use trx_core::rig::state::RigState;
 use trx_core::DynResult;
-use trx_frontend::{FrontendRegistrationContext, FrontendRuntimeContext};
+use trx_frontend::{DxClusterFeed, FrontendRegistrationContext, FrontendRuntimeContext};
+use trx_frontend_flrig::register_frontend_on as register_flrig_frontend;
 use trx_frontend_http::register_frontend_on as register_http_frontend;
 use trx_frontend_http_json::register_frontend_on as register_http_json_frontend;
 use trx_frontend_rigctl::register_frontend_on as register_rigctl_frontend;
 
 use audio_client::AudioConnectConfig;
 use config::{ClientConfig, RemoteEntry};
-use remote_client::{parse_audio_url, parse_remote_url, RemoteClientConfig};
+use remote_client::{parse_audio_url, parse_remote_url, MuxConnector, RemoteClientConfig};
+use uplink_listener::{UplinkSessions, UplinkStation};
 
 const PKG_DESCRIPTION: &str = concat!(env!("CARGO_PKG_NAME"), " - remote rig client");
 const RIG_TASK_CHANNEL_BUFFER: usize = 32;
@@ -127,6 +132,7 @@ async fn async_init() -> DynResult<AppState> {
     register_http_frontend(&mut frontend_reg_ctx);
     register_http_json_frontend(&mut frontend_reg_ctx);
     register_rigctl_frontend(&mut frontend_reg_ctx);
+    register_flrig_frontend(&mut frontend_reg_ctx);
 
     let cli = Cli::parse();
 
@@ -159,6 +165,15 @@ async fn async_init() -> DynResult<AppState> {
         .filter(|t| !t.is_empty())
         .cloned()
         .collect();
+    frontend_runtime.http_auth.allowed_origins = cfg
+        .frontends
+        .http_json
+        .auth
+        .allowed_origins
+        .iter()
+        .map(|o| o.trim().trim_end_matches('/').to_string())
+        .filter(|o| !o.is_empty())
+        .collect();
 
     // Set HTTP frontend authentication config
     frontend_runtime.http_auth.enabled = cfg.frontends.http.auth.enabled;
@@ -189,6 +204,7 @@ async fn async_init() -> DynResult<AppState> {
         .http
         .decode_history_retention_min_by_rig
         .clone();
+    frontend_runtime.http_ui.logbook_auto_log = cfg.frontends.http.logbook_auto_log;
 
     // Resolve remote entries: CLI --url > [[remotes]] > legacy [remote] > error
     let resolved_remotes: Vec<RemoteEntry> = if let Some(ref url) = cli.url {
@@ -203,6 +219,8 @@ async fn async_init() -> DynResult<AppState> {
             rig_id,
             auth: config::RemoteAuthConfig { token },
             poll_interval_ms,
+            tls: cfg.remote.tls.clone(),
+            mux: cfg.remote.mux,
         }]
     } else {
         let entries = cfg.resolved_remotes();
@@ -236,6 +254,9 @@ async fn async_init() -> DynResult<AppState> {
         if cfg.frontends.rigctl.enabled {
             fes.push("rigctl".to_string());
         }
+        if cfg.frontends.flrig.enabled {
+            fes.push("flrig".to_string());
+        }
         if cfg.frontends.http_json.enabled {
             fes.push("httpjson".to_string());
         }
@@ -258,6 +279,7 @@ async fn async_init() -> DynResult<AppState> {
     let http_listen = cli.http_listen.unwrap_or(cfg.frontends.http.listen);
     let http_port = cli.http_port.unwrap_or(cfg.frontends.http.port);
     let rigctl_listen = cli.rigctl_listen.unwrap_or(cfg.frontends.rigctl.listen);
+    let flrig_listen = cfg.frontends.flrig.listen;
     let http_json_listen = cli
         .http_json_listen
         .unwrap_or(cfg.frontends.http_json.listen);
@@ -270,6 +292,25 @@ async fn async_init() -> DynResult<AppState> {
     frontend_runtime.owner.website_url = cfg.general.website_url.clone();
     frontend_runtime.owner.website_name = cfg.general.website_name.clone();
     frontend_runtime.owner.ais_vessel_url_base = cfg.general.ais_vessel_url_base.clone();
+    if cfg.dxcluster.enabled {
+        let dx = &cfg.dxcluster;
+        frontend_runtime.dx_cluster.feed = Some(DxClusterFeed {
+            host: dx.host.clone(),
+            port: dx.port,
+            login: dx
+                .login
+                .clone()
+                .or_else(|| callsign.clone())
+                .unwrap_or_default(),
+            password: dx.password.clone(),
+            commands: dx.commands.clone(),
+            filter: dx.filter.clone(),
+        });
+        frontend_runtime.dx_cluster.spots = Arc::new(std::sync::RwLock::new(SpotTable::new(
+            (dx.max_age_min * 60 * 1000) as i64,
+            500,
+        )));
+    }
 
     let remote_names: Vec<&str> = resolved_remotes.iter().map(|e| e.name.as_str()).collect();
     info!(
@@ -295,38 +336,46 @@ async fn async_init() -> DynResult<AppState> {
         .iter()
         .map(|entry| {
             let ep = parse_remote_url(&entry.url)
-                .map_err(|e| format!("Invalid URL for remote '{}': {}", entry.name, e))?;
+                .map_err(|e| format!("Invalid URL for remote '{}': {}", entry.name, e))?
+                .with_tls(&entry.tls);
             Ok((entry.clone(), ep))
         })
         .collect::<Result<Vec<_>, String>>()?;
 
-    let global_audio_addr = cfg
+    let global_audio_endpoint = cfg
         .frontends
         .audio
         .server_url
         .as_deref()
         .map(|url| {
-            parse_audio_url(url)
-                .map(|endpoint| endpoint.connect_addr())
-                .map_err(|e| format!("Invalid audio URL override '{}': {}", url, e))
+            parse_audio_url(url).map_err(|e| format!("Invalid audio URL override '{}': {}", url, e))
         })
         .transpose()?;
+    // Audio links reuse their remote's TLS settings (CA, pins, client
+    // certificate); a `tls://` audio URL enables TLS on its own.
+    let audio_connector = |endpoint: &remote_client::RemoteEndpoint, tls: &TlsClientConfig| {
+        endpoint
+            .clone()
+            .with_tls(tls)
+            .tls_connector()
+            .map_err(|e| format!("TLS setup for audio {}: {}", endpoint.connect_addr(), e))
+    };
 
     // Build per-short-name audio connection defaults.
     let mut audio_connect: HashMap<String, AudioConnectConfig> = HashMap::new();
     for (entry, ep) in &parsed_remotes {
         let connect = if let Some(url) = cfg.frontends.audio.rig_urls.get(&entry.name) {
-            let addr = parse_audio_url(url)
-                .map(|endpoint| endpoint.connect_addr())
-                .map_err(|e| {
-                    format!(
-                        "Invalid audio URL override for remote '{}': {}",
-                        entry.name, e
-                    )
-                })?;
-            AudioConnectConfig::fixed(addr)
-        } else if let Some(addr) = global_audio_addr.clone() {
-            AudioConnectConfig::fixed(addr)
+            let endpoint = parse_audio_url(url).map_err(|e| {
+                format!(
+                    "Invalid audio URL override for remote '{}': {}",
+                    entry.name, e
+                )
+            })?;
+            AudioConnectConfig::fixed(endpoint.connect_addr())
+                .with_tls(audio_connector(&endpoint, &ep.tls)?)
+        } else if let Some(endpoint) = &global_audio_endpoint {
+            AudioConnectConfig::fixed(endpoint.connect_addr())
+                .with_tls(audio_connector(endpoint, &ep.tls)?)
         } else {
             let audio_port = cfg
                 .frontends
@@ -336,6 +385,7 @@ async fn async_init() -> DynResult<AppState> {
                 .copied()
                 .unwrap_or(cfg.frontends.audio.server_port);
             AudioConnectConfig::from_host_port(ep.host.clone(), audio_port)
+                .with_tls(audio_connector(ep, &ep.tls)?)
         };
         audio_connect.insert(entry.name.clone(), connect);
     }
@@ -351,6 +401,87 @@ async fn async_init() -> DynResult<AppState> {
         server_groups.entry(key).or_default().push(entry);
     }
 
+    // One TLS connector per server, shared by its control, spectrum and
+    // meter connections.
+    let mut tls_by_addr: HashMap<String, Option<TlsConnector>> = HashMap::new();
+    for (addr, ep) in &endpoint_by_addr {
+        let connector = ep
+            .tls_connector()
+            .map_err(|e| format!("TLS setup for remote {}: {}", addr, e))?;
+        tls_by_addr.insert(addr.clone(), connector);
+    }
+
+    // One multiplexed session per server group that asked for it.  Uplink
+    // remotes always use one: the session the server dialled in on.
+    let uplink_sessions = UplinkSessions::new(cfg.uplink_listener.tls.enabled);
+    let mut uplink_stations: HashMap<String, UplinkStation> = HashMap::new();
+    let mut mux_by_group: HashMap<(String, Option<String>), MuxConnector> = HashMap::new();
+    for ((addr, token), entries) in &server_groups {
+        let ep = &endpoint_by_addr[addr];
+        if ep.uplink {
+            if let Some(uplink_token) = cfg.uplink_listener.tokens.get(&ep.host) {
+                uplink_stations.insert(
+                    ep.host.clone(),
+                    UplinkStation {
+                        token: uplink_token.clone(),
+                        client_token: token.clone(),
+                    },
+                );
+            }
+            mux_by_group.insert(
+                (addr.clone(), token.clone()),
+                MuxConnector::uplink(ep.host.clone(), uplink_sessions.clone()),
+            );
+        } else if entries.iter().any(|entry| entry.mux) {
+            let tls = tls_by_addr.get(addr).cloned().flatten();
+            mux_by_group.insert(
+                (addr.clone(), token.clone()),
+                MuxConnector::new(addr.clone(), token.clone(), tls),
+            );
+        }
+    }
+    // Audio rides the session too, unless an audio URL points elsewhere.
+    for (entry, ep) in &parsed_remotes {
+        let key = (ep.connect_addr(), entry.auth.token.clone());
+        let Some(mux) = mux_by_group.get(&key) else {
+            continue;
+        };
+        if global_audio_endpoint.is_some() || cfg.frontends.audio.rig_urls.contains_key(&entry.name)
+        {
+            continue;
+        }
+        if let Some(connect) = audio_connect.remove(&entry.name) {
+            audio_connect.insert(
+                entry.name.clone(),
+                connect.with_mux(mux.clone(), entry.rig_id.clone()),
+            );
+        }
+    }
+
+    if cfg.uplink_listener.enabled {
+        let listener_cfg = cfg.uplink_listener.clone();
+        let tls = listener_cfg
+            .tls
+            .acceptor()
+            .map_err(|e| format!("[uplink_listener.tls]: {}", e))?;
+        let addr = SocketAddr::from((listener_cfg.listen, listener_cfg.port));
+        let uplink_sessions = uplink_sessions.clone();
+        let uplink_shutdown_rx = shutdown_rx.clone();
+        task_handles.push(tokio::spawn(async move {
+            if let Err(e) = uplink_listener::run_uplink_listener(
+                addr,
+                tls,
+                uplink_stations,
+                uplink_sessions,
+                uplink_shutdown_rx,
+            )
+            .await
+            {
+                error!("Uplink listener error: {}", e);
+            }
+        }));
+    }
+
     // Per-server request senders for the routing dispatcher.
     let mut route_map: HashMap<String, mpsc::Sender<RigRequest>> = HashMap::new();
 
@@ -387,6 +518,8 @@ async fn async_init() -> DynResult<AppState> {
             short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
             sat_passes: frontend_runtime.routing.sat_passes.clone(),
             rig_meters: frontend_runtime.routing.rig_meters.clone(),
+            tls: tls_by_addr.get(addr).cloned().flatten(),
+            mux: mux_by_group.get(&(addr.clone(), token.clone())).cloned(),
         };
         let state_tx = state_tx.clone();
         let remote_shutdown_rx = shutdown_rx.clone();
@@ -434,11 +567,13 @@ async fn async_init() -> DynResult<AppState> {
         }));
     }
 
-    // Extract first remote host for audio backward-compat fallback.
-    let remote_host = parsed_remotes
-        .first()
-        .map(|(_, ep)| ep.host.clone())
+    // Extract first remote for audio backward-compat fallback.
+    let first_remote = parsed_remotes.first().map(|(_, ep)| ep.clone());
+    let remote_host = first_remote
+        .as_ref()
+        .map(|ep| ep.host.clone())
         .unwrap_or_else(|| "127.0.0.1".to_string());
+    let remote_tls = first_remote.map(|ep| ep.tls).unwrap_or_default();
 
     // Audio streaming setup
     let mut pending_audio_client = None;
@@ -462,9 +597,14 @@ async fn async_init() -> DynResult<AppState> {
         frontend_runtime.vchan.destroyed = Some(vchan_destroyed_tx.clone());
         let ais_history = frontend_runtime.decode_history.ais.clone();
         let vdes_history = frontend_runtime.decode_history.vdes.clone();
+        let dsc_history = frontend_runtime.decode_history.dsc.clone();
+        let pocsag_history = frontend_runtime.decode_history.pocsag.clone();
+        let adsb_history = frontend_runtime.decode_history.adsb.clone();
+        let acars_history = frontend_runtime.decode_history.acars.clone();
         let aprs_history = frontend_runtime.decode_history.aprs.clone();
         let hf_aprs_history = frontend_runtime.decode_history.hf_aprs.clone();
         let cw_history = frontend_runtime.decode_history.cw.clone();
+        let psk_history = frontend_runtime.decode_history.psk.clone();
         let ft8_history = frontend_runtime.decode_history.ft8.clone();
         let wspr_history = frontend_runtime.decode_history.wspr.clone();
         let replay_history_sink: Arc<dyn Fn(DecodedMessage) + Send + Sync> = Arc::new(move |msg| {
@@ -486,6 +626,38 @@ async fn async_init() -> DynResult<AppState> {
                         history.push_back((now, None, message));
                     }
                 }
+                DecodedMessage::Dsc(mut message) => {
+                    if message.ts_ms.is_none() {
+                        message.ts_ms = Some(current_timestamp_ms());
+                    }
+                    if let Ok(mut history) = dsc_history.lock() {
+                        history.push_back((now, None, message));
+                    }
+                }
+                DecodedMessage::Pocsag(mut message) => {
+                    if message.ts_ms.is_none() {
+                        message.ts_ms = Some(current_timestamp_ms());
+                    }
+                    if let Ok(mut history) = pocsag_history.lock() {
+                        history.push_back((now, None, message));
+                    }
+                }
+                DecodedMessage::Adsb(mut message) => {
+                    if message.ts_ms.is_none() {
+                        message.ts_ms = Some(current_timestamp_ms());
+                    }
+                    if let Ok(mut history) = adsb_history.lock() {
+                        history.push_back((now, None, message));
+                    }
+                }
+                DecodedMessage::Acars(mut message) => {
+                    if message.ts_ms.is_none() {
+                        message.ts_ms = Some(current_timestamp_ms());
+                    }
+                    if let Ok(mut history) = acars_history.lock() {
+                        history.push_back((now, None, message));
+                    }
+                }
                 DecodedMessage::Aprs(mut packet) => {
                     if packet.ts_ms.is_none() {
                         packet.ts_ms = Some(current_timestamp_ms());
@@ -507,6 +679,17 @@ async fn async_init() -> DynResult<AppState> {
                         history.push_back((now, None, event));
                     }
                 }
+                DecodedMessage::CwSpot(_) => {
+                    // Skimmer spots are kept in the frontend DX spot table
+                }
+                DecodedMessage::Psk(mut event) => {
+                    if event.ts_ms.is_none() {
+                        event.ts_ms = Some(current_timestamp_ms());
+                    }
+                    if let Ok(mut history) = psk_history.lock() {
+                        history.push_back((now, None, event));
+                    }
+                }
                 DecodedMessage::Ft8(message) => {
                     if let Ok(mut history) = ft8_history.lock() {
                         history.push_back((now, None, message));
@@ -537,10 +720,18 @@ async fn async_init() -> DynResult<AppState> {
         let rig_audio_rx_map = frontend_runtime.rig_audio.rx.clone();
         let rig_audio_info_map = frontend_runtime.rig_audio.info.clone();
         let rig_vchan_cmd_map = frontend_runtime.vchan.rig_audio_cmd.clone();
-        let default_audio_connect = if let Some(addr) = global_audio_addr {
-            AudioConnectConfig::fixed(addr)
+        let default_audio_connect = if let Some(endpoint) = &global_audio_endpoint {
+            AudioConnectConfig::fixed(endpoint.connect_addr())
+                .with_tls(audio_connector(endpoint, &remote_tls)?)
         } else {
+            let endpoint = remote_client::RemoteEndpoint {
+                host: remote_host.clone(),
+                port: cfg.frontends.audio.server_port,
+                tls: TlsClientConfig::default(),
+                uplink: false,
+            };
             AudioConnectConfig::from_host_port(remote_host.clone(), cfg.frontends.audio.server_port)
+                .with_tls(audio_connector(&endpoint, &remote_tls)?)
         };
         pending_audio_client = Some(tokio::spawn(audio_client::run_multi_rig_audio_manager(
             default_audio_connect,
@@ -594,25 +785,28 @@ async fn async_init() -> DynResult<AppState> {
                     }
                     first = false;
                 }
-                // Proxy channel: inject rig_id_override before forwarding to main tx.
-                let (proxy_tx, mut proxy_rx) = mpsc::channel::<RigRequest>(RIG_TASK_CHANNEL_BUFFER);
-                let main_tx = tx.clone();
-                let rig_id_owned = rig_id.clone();
-                tokio::spawn(async move {
-                    while let Some(req) = proxy_rx.recv().await {
-                        let forwarded = RigRequest {
-                            cmd: req.cmd,
-                            respond_to: req.respond_to,
-                            rig_id_override: Some(rig_id_owned.clone()),
-                        };
-                        let _ = main_tx.send(forwarded).await;
-                    }
-                });
                 info!("rigctl frontend for rig '{}' on {}", rig_id, addr);
                 frontend_reg_ctx.spawn_frontend(
                     frontend,
                     state_rx.clone(),
-                    proxy_tx,
+                    rig_proxy(&tx, rig_id),
+                    callsign.clone(),
+                    addr,
+                    frontend_runtime_ctx.clone(),
+                )?;
+            }
+            continue;
+        }
+
+        // flrig: likewise one XML-RPC listener per configured rig entry.
+        if frontend == "flrig" {
+            for (rig_id, &port) in &cfg.frontends.flrig.rig_ports {
+                let addr = SocketAddr::from((flrig_listen, port));
+                info!("flrig frontend for rig '{}' on {}", rig_id, addr);
+                frontend_reg_ctx.spawn_frontend(
+                    frontend,
+                    state_rx.clone(),
+                    rig_proxy(&tx, rig_id),
                     callsign.clone(),
                     addr,
                     frontend_runtime_ctx.clone(),
@@ -676,6 +870,25 @@ async fn async_init() -> DynResult<AppState> {
     })
 }
 
+/// Proxy channel for a per-rig frontend listener: injects `rig_id_override`
+/// before forwarding to the main rig task channel.
+fn rig_proxy(main_tx: &mpsc::Sender<RigRequest>, rig_id: &str) -> mpsc::Sender<RigRequest> {
+    let (proxy_tx, mut proxy_rx) = mpsc::channel::<RigRequest>(RIG_TASK_CHANNEL_BUFFER);
+    let main_tx = main_tx.clone();
+    let rig_id = rig_id.to_string();
+    tokio::spawn(async move {
+        while let Some(req) = proxy_rx.recv().await {
+            let forwarded = RigRequest {
+                cmd: req.cmd,
+                respond_to: req.respond_to,
+                rig_id_override: Some(rig_id.clone()),
+            };
+            let _ = main_tx.send(forwarded).await;
+        }
+    });
+    proxy_tx
+}
+
 fn current_timestamp_ms() -> i64 {
     let millis = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
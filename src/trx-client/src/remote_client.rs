@@ -8,19 +8,26 @@ use std::sync::RwLock;
 use std::time::Duration;
 use std::{sync::Arc, sync::Mutex};
 
-use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader, ReadHalf, WriteHalf};
 use tokio::net::TcpStream;
 use tokio::sync::{mpsc, watch};
 use tokio::time::{self, Instant};
 use tracing::{info, warn};
 
+use trx_app::tls::{TlsClientConfig, TlsConnector, TransportStream};
+use trx_core::mux::{Mux, MuxHello, MuxOpen, MuxPriority};
 use trx_core::rig::request::RigRequest;
 use trx_core::rig::state::RigState;
 use trx_core::{RigError, RigResult};
 use trx_frontend::{RemoteRigEntry, SharedSpectrum};
 use trx_protocol::rig_command_to_client;
-use trx_protocol::types::RigEntry;
-use trx_protocol::{ClientCommand, ClientEnvelope, ClientResponse, MeterUpdate};
+use trx_protocol::state_stream::STATE_IDLE_TIMEOUT;
+use trx_protocol::types::{RigEntry, STATE_PUSH_VERSION};
+use trx_protocol::{
+    ClientCommand, ClientEnvelope, ClientResponse, MeterUpdate, StateDecoder, StateUpdate,
+};
+
+use crate::uplink_listener::UplinkSessions;
 
 const DEFAULT_REMOTE_PORT: u16 = 4530;
 const DEFAULT_AUDIO_PORT: u16 = 4531;
@@ -29,16 +36,47 @@ const IO_TIMEOUT: Duration = Duration::from_secs(15);
 const SPECTRUM_IO_TIMEOUT: Duration = Duration::from_secs(3);
 const MAX_JSON_LINE_BYTES: usize = 256 * 1024;
 const MAX_CONSECUTIVE_POLL_FAILURES: u32 = 3;
+/// GetRigs refresh interval while rig state arrives over `SubscribeState`
+/// streams; only needed to pick up added or removed rigs.
+const PUSH_RIG_LIST_INTERVAL: Duration = Duration::from_secs(10);
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RemoteEndpoint {
     pub host: String,
     pub port: u16,
+    /// TLS settings; `enabled` is set by a `tls://` URL or the remote's
+    /// `tls` table.
+    pub tls: TlsClientConfig,
+    /// `uplink://<station>`: the server dials in and `host` is its station
+    /// name; there is no address to connect to.
+    pub uplink: bool,
 }
 
 impl RemoteEndpoint {
+    /// Apply a remote's configured TLS settings (CA, pins, client
+    /// certificate).  TLS stays enabled when the URL already asked for it.
+    pub fn with_tls(mut self, tls: &TlsClientConfig) -> Self {
+        let enabled = self.tls.enabled || tls.enabled;
+        self.tls = TlsClientConfig {
+            enabled,
+            ..tls.clone()
+        };
+        self
+    }
+
+    /// Build the TLS connector for this endpoint, or `None` for plaintext.
+    pub fn tls_connector(&self) -> std::io::Result<Option<TlsConnector>> {
+        if self.uplink {
+            // TLS on an uplink is set by the listener.
+            return Ok(None);
+        }
+        self.tls.connector(&self.host)
+    }
+
     pub fn connect_addr(&self) -> String {
-        if self.host.contains(':') && !self.host.starts_with('[') {
+        if self.uplink {
+            format!("uplink://{}", self.host)
+        } else if self.host.contains(':') && !self.host.starts_with('[') {
             format!("[{}]:{}", self.host, self.port)
         } else {
             format!("{}:{}", self.host, self.port)
@@ -80,6 +118,11 @@ pub struct RemoteClientConfig {
     /// Per-rig meter watch senders, keyed by short name (or rig_id in legacy mode).
     /// Populated lazily by the meter-connection supervisor.
     pub rig_meters: Arc<RwLock<HashMap<String, watch::Sender<Option<MeterUpdate>>>>>,
+    /// TLS connector for every connection to this server; `None` for plaintext.
+    pub tls: Option<TlsConnector>,
+    /// Shared multiplexed session; when set every connection to this server
+    /// is a stream of it.
+    pub mux: Option<MuxConnector>,
 }
 
 pub async fn run_remote_client(
@@ -108,16 +151,16 @@ pub async fn run_remote_client(
         }
 
         info!("Remote client: connecting to {}", config.addr);
-        match time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&config.addr)).await {
+        match time::timeout(
+            CONNECT_TIMEOUT,
+            connect_transport(&config, MuxPriority::High),
+        )
+        .await
+        {
             Ok(Ok(stream)) => {
                 // Reset backoff on successful TCP connect: server is reachable, so the
                 // next disconnect should retry quickly rather than waiting up to 10 s.
                 reconnect_delay = Duration::from_secs(1);
-                // Disable Nagle's algorithm so each framed command is sent immediately
-                // rather than being held for up to 40 ms waiting for ACKs.
-                if let Err(e) = stream.set_nodelay(true) {
-                    warn!("TCP_NODELAY failed: {}", e);
-                }
                 config.server_connected.store(true, Ordering::Relaxed);
                 if let Err(e) =
                     handle_connection(&config, stream, &mut rx, &state_tx, &mut shutdown_rx).await
@@ -184,6 +227,119 @@ pub async fn run_remote_client(
     }
 }
 
+/// Open a connection to the server, running the TLS handshake when the
+/// remote is configured for it.  With multiplexing enabled this opens a
+/// control stream of `priority` on the shared session instead.
+async fn connect_transport(
+    config: &RemoteClientConfig,
+    priority: MuxPriority,
+) -> std::io::Result<TransportStream> {
+    if let Some(mux) = &config.mux {
+        return mux.open(&MuxOpen::control(priority)).await;
+    }
+    connect_tcp(&config.addr, config.tls.as_ref()).await
+}
+
+async fn connect_tcp(addr: &str, tls: Option<&TlsConnector>) -> std::io::Result<TransportStream> {
+    let stream = TcpStream::connect(addr).await?;
+    // Disable Nagle's algorithm so each framed command is sent immediately
+    // rather than being held for up to 40 ms waiting for ACKs.
+    if let Err(e) = stream.set_nodelay(true) {
+        warn!("TCP_NODELAY failed: {}", e);
+    }
+    TransportStream::connect(stream, tls).await
+}
+
+/// One multiplexed session to a server, shared by every control, spectrum,
+/// meter, state and audio connection to it.  A dialled session is opened on
+/// first use and reopened after it drops; an uplink session is whichever
+/// connection the server last dialled in on.
+#[derive(Clone)]
+pub struct MuxConnector {
+    addr: String,
+    source: MuxSource,
+}
+
+#[derive(Clone)]
+enum MuxSource {
+    Dial {
+        token: Option<String>,
+        tls: Option<TlsConnector>,
+        session: Arc<tokio::sync::Mutex<Option<Mux>>>,
+    },
+    Uplink {
+        station: String,
+        sessions: UplinkSessions,
+    },
+}
+
+impl MuxConnector {
+    pub fn new(addr: String, token: Option<String>, tls: Option<TlsConnector>) -> Self {
+        Self {
+            addr,
+            source: MuxSource::Dial {
+                token,
+                tls,
+                session: Arc::new(tokio::sync::Mutex::new(None)),
+            },
+        }
+    }
+
+    /// Use the session `station` opens through the uplink listener.
+    pub fn uplink(station: String, sessions: UplinkSessions) -> Self {
+        Self {
+            addr: format!("uplink://{station}"),
+            source: MuxSource::Uplink { station, sessions },
+        }
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Open a stream, connecting the session first when needed.  For an
+    /// uplink this waits until the server has dialled in.
+    pub async fn open(&self, open: &MuxOpen) -> std::io::Result<TransportStream> {
+        let (mux, tls) = match &self.source {
+            MuxSource::Dial {
+                token,
+                tls,
+                session,
+            } => {
+                let mut session = session.lock().await;
+                let mux = match session.as_ref().filter(|mux| !mux.is_closed()) {
+                    Some(mux) => mux.clone(),
+                    None => {
+                        info!("Opening multiplexed session to {}", self.addr);
+                        let stream = connect_tcp(&self.addr, tls.as_ref()).await?;
+                        let (reader, writer) = tokio::io::split(stream);
+                        let mux =
+                            Mux::connect(reader, writer, &MuxHello::new(token.clone())).await?;
+                        *session = Some(mux.clone());
+                        mux
+                    }
+                };
+                (mux, tls.is_some())
+            }
+            MuxSource::Uplink { station, sessions } => {
+                (sessions.wait(station).await, sessions.is_tls())
+            }
+        };
+        Ok(TransportStream::Mux {
+            stream: Box::new(mux.open(open)?),
+            tls,
+        })
+    }
+}
+
+impl std::fmt::Debug for MuxConnector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MuxConnector")
+            .field("addr", &self.addr)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Spectrum polling runs on a dedicated TCP connection so it never blocks
 /// state polls or user commands on the main connection.  Reconnects
 /// independently with a short fixed delay.
@@ -196,11 +352,13 @@ async fn run_spectrum_connection(
             break;
         }
 
-        match time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&config.addr)).await {
+        match time::timeout(
+            CONNECT_TIMEOUT,
+            connect_transport(&config, MuxPriority::Low),
+        )
+        .await
+        {
             Ok(Ok(stream)) => {
-                if let Err(e) = stream.set_nodelay(true) {
-                    warn!("Spectrum TCP_NODELAY failed: {}", e);
-                }
                 if let Err(e) = handle_spectrum_connection(&config, stream, &mut shutdown_rx).await
                 {
                     warn!("Spectrum connection dropped: {}", e);
@@ -294,7 +452,12 @@ async fn run_meter_connection(
             return;
         }
 
-        let stream = match time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&config.addr)).await {
+        let stream = match time::timeout(
+            CONNECT_TIMEOUT,
+            connect_transport(&config, MuxPriority::High),
+        )
+        .await
+        {
             Ok(Ok(s)) => s,
             Ok(Err(e)) => {
                 warn!("Meter[{}]: connect failed: {}", short_name, e);
@@ -311,7 +474,6 @@ async fn run_meter_connection(
                 continue;
             }
         };
-        let _ = stream.set_nodelay(true);
 
         if let Err(e) = stream_meter(&config, &short_name, stream, &mut shutdown_rx).await {
             warn!("Meter[{}]: stream ended: {}", short_name, e);
@@ -335,10 +497,10 @@ async fn wait_reconnect(shutdown_rx: &mut watch::Receiver<bool>, delay: Duration
 async fn stream_meter(
     config: &RemoteClientConfig,
     short_name: &str,
-    stream: TcpStream,
+    stream: TransportStream,
     shutdown_rx: &mut watch::Receiver<bool>,
 ) -> RigResult<()> {
-    let (reader, mut writer) = stream.into_split();
+    let (reader, mut writer) = tokio::io::split(stream);
     let mut reader = BufReader::new(reader);
 
     let envelope = build_envelope(
@@ -395,6 +557,196 @@ async fn stream_meter(
     }
 }
 
+/// State stream supervisor.  Spawns one `SubscribeState` connection per
+/// known rig, like the meter supervisor, and feeds the pushed snapshots into
+/// the per-rig and global watch channels.  Streams of rigs that are no
+/// longer known are stopped.  Aborting the supervisor drops the `JoinSet`
+/// and with it every stream task.
+async fn run_state_supervisor(
+    config: RemoteClientConfig,
+    state_tx: watch::Sender<RigState>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) {
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut running: HashMap<String, tokio::task::AbortHandle> = HashMap::new();
+    let mut poll = time::interval(Duration::from_millis(500));
+
+    loop {
+        tokio::select! {
+            _ = poll.tick() => {}
+            changed = shutdown_rx.changed() => {
+                if matches!(changed, Ok(()) | Err(_)) && *shutdown_rx.borrow() {
+                    return;
+                }
+            }
+        }
+        // Reap stopped tasks so the set does not grow.
+        while tasks.try_join_next().is_some() {}
+
+        let known = collect_known_short_names(&config);
+        running.retain(|name, task| {
+            let keep = known.contains(name);
+            if !keep {
+                task.abort();
+            }
+            keep
+        });
+        for name in known {
+            if !running.contains_key(&name) {
+                let task = tasks.spawn(run_state_connection(
+                    config.clone(),
+                    name.clone(),
+                    state_tx.clone(),
+                    shutdown_rx.clone(),
+                ));
+                running.insert(name, task);
+            }
+        }
+    }
+}
+
+/// One dedicated connection per short name that sends `SubscribeState` and
+/// applies the pushed frames.  A frame out of sequence, or no data for
+/// [`STATE_IDLE_TIMEOUT`], drops the connection; resubscribing starts again
+/// from a full snapshot.
+async fn run_state_connection(
+    config: RemoteClientConfig,
+    short_name: String,
+    state_tx: watch::Sender<RigState>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) {
+    loop {
+        if *shutdown_rx.borrow() {
+            return;
+        }
+
+        match time::timeout(
+            CONNECT_TIMEOUT,
+            connect_transport(&config, MuxPriority::High),
+        )
+        .await
+        {
+            Ok(Ok(stream)) => {
+                if let Err(e) = stream_state(
+                    &config,
+                    &short_name,
+                    stream,
+                    STATE_IDLE_TIMEOUT,
+                    &state_tx,
+                    &mut shutdown_rx,
+                )
+                .await
+                {
+                    warn!("State[{}]: stream ended: {}", short_name, e);
+                }
+            }
+            Ok(Err(e)) => warn!("State[{}]: connect failed: {}", short_name, e),
+            Err(_) => warn!("State[{}]: connect timed out", short_name),
+        }
+
+        if wait_reconnect(&mut shutdown_rx, Duration::from_secs(1)).await {
+            return;
+        }
+    }
+}
+
+async fn stream_state(
+    config: &RemoteClientConfig,
+    short_name: &str,
+    stream: TransportStream,
+    idle_timeout: Duration,
+    state_tx: &watch::Sender<RigState>,
+    shutdown_rx: &mut watch::Receiver<bool>,
+) -> RigResult<()> {
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+
+    let envelope = build_envelope(
+        config,
+        ClientCommand::SubscribeState,
+        Some(short_name.to_string()),
+    );
+    let mut payload = serde_json::to_string(&envelope)
+        .map_err(|e| RigError::communication(format!("JSON serialize failed: {e}")))?;
+    payload.push('\n');
+    time::timeout(IO_TIMEOUT, writer.write_all(payload.as_bytes()))
+        .await
+        .map_err(|_| RigError::communication("state subscribe write timed out".to_string()))?
+        .map_err(|e| RigError::communication(format!("state subscribe write failed: {e}")))?;
+    time::timeout(IO_TIMEOUT, writer.flush())
+        .await
+        .map_err(|_| RigError::communication("state subscribe flush timed out".to_string()))?
+        .map_err(|e| RigError::communication(format!("state subscribe flush failed: {e}")))?;
+
+    let mut decoder = StateDecoder::new();
+    loop {
+        tokio::select! {
+            changed = shutdown_rx.changed() => {
+                match changed {
+                    Ok(()) if *shutdown_rx.borrow() => return Ok(()),
+                    Ok(()) => {}
+                    Err(_) => return Ok(()),
+                }
+            }
+            // The server sends an empty line on an idle stream, so silence
+            // means the connection is gone even if TCP has not noticed.
+            line = time::timeout(
+                idle_timeout,
+                read_limited_line(&mut reader, MAX_JSON_LINE_BYTES),
+            ) => {
+                let line = line
+                    .map_err(|_| RigError::communication("state stream idle".to_string()))?
+                    .map_err(|e| RigError::communication(format!("state read failed: {e}")))?
+                    .ok_or_else(|| RigError::communication("state connection closed".to_string()))?;
+                let trimmed = line.trim_end();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                let update: StateUpdate = match serde_json::from_str(trimmed) {
+                    Ok(update) => update,
+                    Err(e) => {
+                        // Errors (auth, unknown rig) arrive as a regular response.
+                        if let Ok(resp) = serde_json::from_str::<ClientResponse>(trimmed) {
+                            return Err(RigError::communication(
+                                resp.error.unwrap_or_else(|| "remote error".into()),
+                            ));
+                        }
+                        return Err(RigError::communication(format!("bad state frame: {e}")));
+                    }
+                };
+                let snapshot = decoder.apply(update).map_err(RigError::communication)?;
+                publish_rig_state(config, short_name, state_tx, RigState::from_snapshot(snapshot));
+            }
+        }
+    }
+}
+
+/// Store a pushed state in the rig's watch channel and, when the rig is the
+/// selected one, in the global state channel.
+fn publish_rig_state(
+    config: &RemoteClientConfig,
+    short_name: &str,
+    state_tx: &watch::Sender<RigState>,
+    new_state: RigState,
+) {
+    let update = |old: &mut RigState| {
+        if *old == new_state {
+            false
+        } else {
+            *old = new_state.clone();
+            true
+        }
+    };
+    if let Ok(map) = config.rig_states.read() {
+        if let Some(tx) = map.get(short_name) {
+            tx.send_if_modified(update);
+        }
+    }
+    if selected_rig_id(config).as_deref() == Some(short_name) {
+        state_tx.send_if_modified(update);
+    }
+}
+
 /// Satellite pass prediction refresh runs on a dedicated TCP connection so it
 /// never blocks state polls or user commands on the main connection.
 /// Fetches immediately on connect, then every 5 minutes.
@@ -411,10 +763,14 @@ async fn run_sat_pass_connection(
             break;
         }
 
-        match time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&config.addr)).await {
+        match time::timeout(
+            CONNECT_TIMEOUT,
+            connect_transport(&config, MuxPriority::Low),
+        )
+        .await
+        {
             Ok(Ok(stream)) => {
-                let _ = stream.set_nodelay(true);
-                let (reader, mut writer) = stream.into_split();
+                let (reader, mut writer) = tokio::io::split(stream);
                 let mut reader = BufReader::new(reader);
                 let mut interval = time::interval(SAT_PASS_REFRESH_INTERVAL);
 
@@ -468,8 +824,8 @@ async fn run_sat_pass_connection(
 /// Send a GetSatPasses request on the given connection with a custom timeout.
 async fn send_get_sat_passes_on(
     config: &RemoteClientConfig,
-    writer: &mut tokio::net::tcp::OwnedWriteHalf,
-    reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>,
+    writer: &mut WriteHalf<TransportStream>,
+    reader: &mut BufReader<ReadHalf<TransportStream>>,
     timeout: Duration,
 ) -> RigResult<trx_core::geo::PassPredictionResult> {
     let envelope = build_envelope(config, ClientCommand::GetSatPasses, None);
@@ -507,10 +863,10 @@ async fn send_get_sat_passes_on(
 
 async fn handle_spectrum_connection(
     config: &RemoteClientConfig,
-    stream: TcpStream,
+    stream: TransportStream,
     shutdown_rx: &mut watch::Receiver<bool>,
 ) -> RigResult<()> {
-    let (reader, mut writer) = stream.into_split();
+    let (reader, mut writer) = tokio::io::split(stream);
     let mut reader = BufReader::new(reader);
     let mut interval = time::interval(SPECTRUM_POLL_INTERVAL);
     // Cache the token outside the poll loop to avoid cloning it every 50ms.
@@ -582,49 +938,79 @@ async fn handle_spectrum_connection(
 
 async fn handle_connection(
     config: &RemoteClientConfig,
-    stream: TcpStream,
+    stream: TransportStream,
     rx: &mut mpsc::Receiver<RigRequest>,
     state_tx: &watch::Sender<RigState>,
     shutdown_rx: &mut watch::Receiver<bool>,
 ) -> RigResult<()> {
-    let (reader, mut writer) = stream.into_split();
+    let (reader, mut writer) = tokio::io::split(stream);
     let mut reader = BufReader::new(reader);
-    let mut poll_interval = time::interval(config.poll_interval);
-    let mut last_poll = Instant::now();
     let mut poll_failure_streak: u32 = 0;
 
     // Prime rig list/state immediately after connect so frontends can render
     // rig selectors without waiting for the first poll interval.
-    if let Err(e) = refresh_remote_snapshot(config, &mut writer, &mut reader, state_tx).await {
-        warn!("Initial remote snapshot refresh failed: {}", e);
-    }
+    let server_version =
+        match refresh_remote_snapshot(config, &mut writer, &mut reader, state_tx, false).await {
+            Ok(version) => version,
+            Err(e) => {
+                warn!("Initial remote snapshot refresh failed: {}", e);
+                None
+            }
+        };
+
+    // Servers that support `SubscribeState` push every change on per-rig
+    // streams, so the main connection only refreshes the rig list.
+    let state_push = server_version.is_some_and(|v| v >= STATE_PUSH_VERSION);
+    let poll_every = if state_push {
+        info!("Remote server supports state push; polling disabled");
+        config.poll_interval.max(PUSH_RIG_LIST_INTERVAL)
+    } else {
+        config.poll_interval
+    };
+    let mut poll_interval = time::interval(poll_every);
+    let mut last_poll = Instant::now();
+    let state_task = state_push.then(|| {
+        tokio::spawn(run_state_supervisor(
+            config.clone(),
+            state_tx.clone(),
+            shutdown_rx.clone(),
+        ))
+    });
 
     // Satellite pass refresh runs on its own dedicated TCP connection so it
     // never blocks state polls or user commands on the main connection.
     let sat_pass_task = tokio::spawn(run_sat_pass_connection(config.clone(), shutdown_rx.clone()));
+    // Stop the per-connection helpers on every exit path.
+    let abort_tasks = || {
+        sat_pass_task.abort();
+        if let Some(task) = &state_task {
+            task.abort();
+        }
+    };
 
     loop {
         tokio::select! {
             changed = shutdown_rx.changed() => {
                 match changed {
                     Ok(()) if *shutdown_rx.borrow() => {
-                        sat_pass_task.abort();
+                        abort_tasks();
                         return Ok(());
                     }
                     Ok(()) => {}
                     Err(_) => {
-                        sat_pass_task.abort();
+                        abort_tasks();
                         return Ok(());
                     }
                 }
             }
             _ = poll_interval.tick() => {
-                if last_poll.elapsed() < config.poll_interval {
+                if last_poll.elapsed() < poll_every {
                     continue;
                 }
                 last_poll = Instant::now();
                 if let Err(e) =
-                    refresh_remote_snapshot(config, &mut writer, &mut reader, state_tx).await
+                    refresh_remote_snapshot(config, &mut writer, &mut reader, state_tx, state_push)
+                        .await
                 {
                     poll_failure_streak = poll_failure_streak.saturating_add(1);
                     warn!(
@@ -636,11 +1022,11 @@ async fn handle_connection(
                         e.message.contains("timed out")
                             || e.message.contains("connection closed");
                     if timeout_or_disconnect {
-                        sat_pass_task.abort();
+                        abort_tasks();
                         return Err(e);
                     }
                     if poll_failure_streak >= MAX_CONSECUTIVE_POLL_FAILURES {
-                        sat_pass_task.abort();
+                        abort_tasks();
                         return Err(RigError::communication(format!(
                             "remote poll failed {} consecutive times: {}",
                             poll_failure_streak, e
@@ -652,6 +1038,7 @@ async fn handle_connection(
             }
             req = rx.recv() => {
                 let Some(req) = req else {
+                    abort_tasks();
                     return Ok(());
                 };
                 let rig_id_override = req.rig_id_override;
@@ -669,8 +1056,8 @@ async fn handle_connection(
 
 async fn send_command(
     config: &RemoteClientConfig,
-    writer: &mut tokio::net::tcp::OwnedWriteHalf,
-    reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>,
+    writer: &mut WriteHalf<TransportStream>,
+    reader: &mut BufReader<ReadHalf<TransportStream>>,
     cmd: ClientCommand,
     rig_id_override: Option<String>,
     state_tx: &watch::Sender<RigState>,
@@ -755,13 +1142,19 @@ fn build_envelope(
     }
 }
 
+/// Refresh the rig list and, unless `state_push` is set, every rig's state
+/// from a GetRigs response.  With `state_push` the per-rig watch channels are
+/// fed by `SubscribeState` streams and only new rigs are seeded here.
+///
+/// Returns the protocol version advertised by the server.
 async fn refresh_remote_snapshot(
     config: &RemoteClientConfig,
-    writer: &mut tokio::net::tcp::OwnedWriteHalf,
-    reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>,
+    writer: &mut WriteHalf<TransportStream>,
+    reader: &mut BufReader<ReadHalf<TransportStream>>,
     state_tx: &watch::Sender<RigState>,
-) -> RigResult<()> {
-    let rigs = send_get_rigs(config, writer, reader).await?;
+    state_push: bool,
+) -> RigResult<Option<u32>> {
+    let (rigs, server_version) = send_get_rigs(config, writer, reader).await?;
 
     // In multi-server mode, filter rigs to only those that have a short name
     // mapping, and populate the reverse mapping (short_name → server rig_id).
@@ -814,7 +1207,18 @@ async fn refresh_remote_snapshot(
         if selected.is_none() {
             set_selected_rig_id(config, Some(key.clone()));
         }
-        let new_state = RigState::from_snapshot(entry.state.clone());
+        // With state push the per-rig channel holds the freshest state; the
+        // GetRigs entry may be older than the last pushed frame.
+        let pushed = state_push
+            .then(|| {
+                config
+                    .rig_states
+                    .read()
+                    .ok()
+                    .and_then(|map| map.get(key).map(|tx| tx.borrow().clone()))
+            })
+            .flatten();
+        let new_state = pushed.unwrap_or_else(|| RigState::from_snapshot(entry.state.clone()));
         state_tx.send_if_modified(|old| {
             if *old == new_state {
                 false
@@ -830,6 +1234,9 @@ async fn refresh_remote_snapshot(
         for (key, entry) in &mapped_rigs {
             let new_state = RigState::from_snapshot(entry.state.clone());
             if let Some(tx) = rig_map.get(key) {
+                if state_push {
+                    continue;
+                }
                 tx.send_if_modified(|old| {
                     if *old == new_state {
                         false
@@ -857,14 +1264,14 @@ async fn refresh_remote_snapshot(
             conn_map.insert(key.clone(), true);
         }
     }
-    Ok(())
+    Ok(server_version)
 }
 
 async fn send_get_rigs(
     config: &RemoteClientConfig,
-    writer: &mut tokio::net::tcp::OwnedWriteHalf,
-    reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>,
-) -> RigResult<Vec<RigEntry>> {
+    writer: &mut WriteHalf<TransportStream>,
+    reader: &mut BufReader<ReadHalf<TransportStream>>,
+) -> RigResult<(Vec<RigEntry>, Option<u32>)> {
     let envelope = build_envelope(config, ClientCommand::GetRigs, None);
     let mut payload = serde_json::to_string(&envelope)
         .map_err(|e| RigError::communication(format!("JSON serialize failed: {e}")))?;
@@ -888,9 +1295,10 @@ async fn send_get_rigs(
     let resp: ClientResponse = serde_json::from_str(line.trim_end())
         .map_err(|e| RigError::communication(format!("invalid response: {e}")))?;
     if resp.success {
-        return resp
+        let rigs = resp
             .rigs
-            .ok_or_else(|| RigError::communication("missing rigs list in GetRigs response"));
+            .ok_or_else(|| RigError::communication("missing rigs list in GetRigs response"))?;
+        return Ok((rigs, resp.protocol_version));
     }
 
     Err(RigError::communication(
@@ -1048,8 +1456,8 @@ fn set_selected_rig_id(config: &RemoteClientConfig, value: Option<String>) {
 
 /// Send a pre-built envelope and return the snapshot without updating state.
 async fn send_envelope_no_state_update(
-    writer: &mut tokio::net::tcp::OwnedWriteHalf,
-    reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>,
+    writer: &mut WriteHalf<TransportStream>,
+    reader: &mut BufReader<ReadHalf<TransportStream>>,
     envelope: ClientEnvelope,
 ) -> RigResult<trx_core::RigSnapshot> {
     let mut payload = serde_json::to_string(&envelope)
@@ -1196,6 +1604,17 @@ async fn read_limited_line<R: AsyncBufRead + Unpin>(
 }
 
 pub fn parse_remote_url(url: &str) -> Result<RemoteEndpoint, String> {
+    if let Some(station) = url.trim().strip_prefix("uplink://") {
+        if station.is_empty() {
+            return Err("invalid remote url: uplink station is empty".to_string());
+        }
+        return Ok(RemoteEndpoint {
+            host: station.to_string(),
+            port: 0,
+            tls: TlsClientConfig::default(),
+            uplink: true,
+        });
+    }
     parse_endpoint_url(url, DEFAULT_REMOTE_PORT, "remote")
 }
 
@@ -1209,6 +1628,12 @@ fn parse_endpoint_url(url: &str, default_port: u16, kind: &str) -> Result<Remote
         return Err(format!("{kind} url is empty"));
     }
 
+    if let Some(addr) = trimmed.strip_prefix("tls://") {
+        let mut endpoint = parse_host_port(addr, default_port, kind)?;
+        endpoint.tls.enabled = true;
+        return Ok(endpoint);
+    }
+
     let addr = trimmed
         .strip_prefix("tcp://")
         .or_else(|| trimmed.strip_prefix("http-json://"))
@@ -1238,6 +1663,8 @@ fn parse_host_port(input: &str, default_port: u16, kind: &str) -> Result<RemoteE
         return Ok(RemoteEndpoint {
             host: host.to_string(),
             port,
+            tls: TlsClientConfig::default(),
+            uplink: false,
         });
     }
 
@@ -1256,12 +1683,16 @@ fn parse_host_port(input: &str, default_port: u16, kind: &str) -> Result<RemoteE
         return Ok(RemoteEndpoint {
             host: host.to_string(),
             port: parse_port(port_str, kind)?,
+            tls: TlsClientConfig::default(),
+            uplink: false,
         });
     }
 
     Ok(RemoteEndpoint {
         host: input.to_string(),
         port: default_port,
+        tls: TlsClientConfig::default(),
+        uplink: false,
     })
 }
 
@@ -1280,7 +1711,8 @@ mod tests {
     #[allow(unused_imports)]
     use super::{has_short_names, resolve_server_rig_id, resolve_short_name};
     use super::{
-        parse_audio_url, parse_remote_url, RemoteClientConfig, RemoteEndpoint, SharedSpectrum,
+        parse_audio_url, parse_remote_url, stream_state, RemoteClientConfig, RemoteEndpoint,
+        SharedSpectrum,
     };
     use std::collections::HashMap;
     use std::sync::atomic::AtomicBool;
@@ -1291,6 +1723,7 @@ mod tests {
     use tokio::net::TcpListener;
     use tokio::sync::{mpsc, watch};
 
+    use trx_app::tls::TlsClientConfig;
     use trx_core::radio::freq::{Band, Freq};
     use trx_core::rig::state::RigSnapshot;
     use trx_core::rig::{RigAccessMethod, RigCapabilities, RigInfo, RigStatus, RigTxStatus};
@@ -1305,7 +1738,9 @@ mod tests {
             parsed,
             RemoteEndpoint {
                 host: "example.local".to_string(),
-                port: 4530
+                port: 4530,
+                tls: TlsClientConfig::default(),
+                uplink: false,
             }
         );
     }
@@ -1317,7 +1752,9 @@ mod tests {
             parsed,
             RemoteEndpoint {
                 host: "audio.example.local".to_string(),
-                port: 4531
+                port: 4531,
+                tls: TlsClientConfig::default(),
+                uplink: false,
             }
         );
     }
@@ -1329,7 +1766,9 @@ mod tests {
             parsed,
             RemoteEndpoint {
                 host: "127.0.0.1".to_string(),
-                port: 9000
+                port: 9000,
+                tls: TlsClientConfig::default(),
+                uplink: false,
             }
         );
     }
@@ -1341,7 +1780,9 @@ mod tests {
             parsed,
             RemoteEndpoint {
                 host: "::1".to_string(),
-                port: 7000
+                port: 7000,
+                tls: TlsClientConfig::default(),
+                uplink: false,
             }
         );
     }
@@ -1352,6 +1793,34 @@ mod tests {
         assert!(err.contains("must be bracketed"));
     }
 
+    #[test]
+    fn parse_tls_url_enables_tls() {
+        let parsed = parse_remote_url("tls://field.example.com").expect("must parse");
+        assert_eq!(parsed.port, 4530);
+        assert!(parsed.tls.enabled);
+
+        let configured = TlsClientConfig {
+            pin_sha256: vec!["00".repeat(32)],
+            ..Default::default()
+        };
+        let merged = parsed.with_tls(&configured);
+        assert!(merged.tls.enabled);
+        assert_eq!(merged.tls.pin_sha256, configured.pin_sha256);
+
+        let plain = parse_remote_url("tcp://field.example.com").expect("must parse");
+        assert!(!plain.with_tls(&configured).tls.enabled);
+    }
+
+    #[test]
+    fn parse_uplink_station() {
+        let parsed = parse_remote_url("uplink://field").expect("must parse");
+        assert!(parsed.uplink);
+        assert_eq!(parsed.host, "field");
+        assert_eq!(parsed.connect_addr(), "uplink://field");
+        assert!(parsed.tls_connector().expect("no tls").is_none());
+        assert!(parse_remote_url("uplink://").is_err());
+    }
+
     fn sample_snapshot() -> RigSnapshot {
         RigSnapshot {
             info: RigInfo {
@@ -1372,6 +1841,7 @@ mod tests {
                     attenuator: false,
                     preamp: false,
                     rit: false,
+                    xit: false,
                     rpt: false,
                     split: false,
                     tx: true,
@@ -1379,6 +1849,11 @@ mod tests {
                     vfo_switch: true,
                     filter_controls: false,
                     signal_meter: true,
+                    memory_channels: 0,
+                    memory_write: false,
+                    band_stack: false,
+                    ctcss_decode: false,
+                    dcs: false,
                 },
                 access: RigAccessMethod::Tcp {
                     addr: "127.0.0.1:1234".to_string(),
@@ -1412,6 +1887,15 @@ mod tests {
             cw_auto: true,
             cw_wpm: 15,
             cw_tone_hz: 700,
+            psk_mode: trx_core::decode::PskMode::default(),
+            psk_freq_hz: 1000,
+            psk_waterfall: false,
+            sat_tracking: None,
+            rotator: None,
+            cw_keyer: None,
+            memories: Vec::new(),
+            repeater: None,
+            clarifier: None,
             filter: None,
             spectrum: None,
             vchan_rds: None,
@@ -1436,6 +1920,7 @@ mod tests {
                 audio_port: Some(4531),
             }]),
             sat_passes: None,
+            denied: None,
             error: None,
         })
         .expect("serialize response")
@@ -1484,6 +1969,8 @@ mod tests {
                 short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
                 sat_passes: Arc::new(RwLock::new(None)),
                 rig_meters: Arc::new(RwLock::new(HashMap::new())),
+                tls: None,
+                mux: None,
             },
             req_rx,
             state_tx,
@@ -1511,6 +1998,56 @@ mod tests {
         let _ = server.await;
     }
 
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn state_stream_gives_up_on_a_silent_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("local addr");
+        let server = tokio::spawn(async move {
+            // Take the subscription, then never answer or close.
+            let (socket, _) = listener.accept().await.expect("accept");
+            let mut reader = BufReader::new(socket);
+            let mut buf = String::new();
+            let _ = reader.read_line(&mut buf).await.expect("read subscribe");
+            std::future::pending::<()>().await;
+        });
+
+        let (spectrum_tx, _spectrum_rx) = watch::channel(SharedSpectrum::default());
+        let config = RemoteClientConfig {
+            addr: addr.to_string(),
+            token: None,
+            selected_rig_id: Arc::new(Mutex::new(None)),
+            known_rigs: Arc::new(Mutex::new(Vec::new())),
+            poll_interval: Duration::from_millis(100),
+            spectrum: Arc::new(spectrum_tx),
+            server_connected: Arc::new(AtomicBool::new(false)),
+            rig_server_connected: Arc::new(RwLock::new(HashMap::new())),
+            rig_states: Arc::new(RwLock::new(HashMap::new())),
+            rig_spectrums: Arc::new(RwLock::new(HashMap::new())),
+            rig_id_to_short_name: HashMap::new(),
+            short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
+            sat_passes: Arc::new(RwLock::new(None)),
+            rig_meters: Arc::new(RwLock::new(HashMap::new())),
+            tls: None,
+            mux: None,
+        };
+        let (state_tx, _state_rx) = watch::channel(RigState::new_uninitialized());
+        let (_shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let socket = tokio::net::TcpStream::connect(addr).await.expect("connect");
+        let err = stream_state(
+            &config,
+            "hf",
+            trx_app::tls::TransportStream::Plain(socket),
+            Duration::from_millis(200),
+            &state_tx,
+            &mut shutdown_rx,
+        )
+        .await
+        .expect_err("silent stream must end");
+        assert!(err.message.contains("idle"), "{}", err.message);
+        server.abort();
+    }
+
     #[test]
     fn build_envelope_includes_rig_id() {
         let (spectrum_tx, _spectrum_rx) = watch::channel(SharedSpectrum::default());
@@ -1529,6 +2066,8 @@ mod tests {
             short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
             sat_passes: Arc::new(RwLock::new(None)),
             rig_meters: Arc::new(RwLock::new(HashMap::new())),
+            tls: None,
+            mux: None,
         };
         let envelope = super::build_envelope(&config, trx_protocol::ClientCommand::GetState, None);
         assert_eq!(envelope.token.as_deref(), Some("secret"));
@@ -1557,6 +2096,8 @@ mod tests {
             short_name_to_rig_id,
             sat_passes: Arc::new(RwLock::new(None)),
             rig_meters: Arc::new(RwLock::new(HashMap::new())),
+            tls: None,
+            mux: None,
         };
         // selected_rig_id is "home-hf" (short name), envelope should translate to "hf"
         let envelope = super::build_envelope(&config, trx_protocol::ClientCommand::GetState, None);
@@ -1589,6 +2130,8 @@ mod tests {
             short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
             sat_passes: Arc::new(RwLock::new(None)),
             rig_meters: Arc::new(RwLock::new(HashMap::new())),
+            tls: None,
+            mux: None,
         };
         // Legacy mode: rig_id passes through unchanged
         assert!(!has_short_names(&config));
@@ -1616,6 +2159,8 @@ mod tests {
             short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
             sat_passes: Arc::new(RwLock::new(None)),
             rig_meters: Arc::new(RwLock::new(HashMap::new())),
+            tls: None,
+            mux: None,
         };
         assert!(has_short_names(&config));
         assert_eq!(
@@ -1653,6 +2198,8 @@ mod tests {
             short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
             sat_passes: Arc::new(RwLock::new(None)),
             rig_meters: Arc::new(RwLock::new(HashMap::new())),
+            tls: None,
+            mux: None,
         };
         let snapshot = sample_snapshot();
         let rigs = vec![RigEntry {
@@ -1726,6 +2273,8 @@ mod tests {
             short_name_to_rig_id: Arc::new(RwLock::new(HashMap::new())),
             sat_passes: Arc::new(RwLock::new(None)),
             rig_meters: Arc::new(RwLock::new(HashMap::new())),
+            tls: None,
+            mux: None,
         };
 
         let ids = super::active_spectrum_rig_ids(&config);
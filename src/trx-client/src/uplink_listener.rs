@@ -0,0 +1,301 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Uplink listener (`[uplink_listener]`).
+//!
+//! Servers configured with `[uplink]` dial this listener and identify
+//! themselves by station name.  The client then runs a multiplexed session
+//! on that connection, so `uplink://<station>` remotes work exactly like
+//! `mux = true` remotes, only with the connection opened from the far end.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::time;
+use tracing::{info, warn};
+
+use trx_app::tls::{TlsAcceptor, TransportStream};
+use trx_core::mux::{Mux, MuxHello, UplinkHello};
+use trx_protocol::auth::hash_token;
+
+/// Time allowed for the TLS handshake, the uplink hello and the session
+/// handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One station that may dial in.
+#[derive(Debug, Clone)]
+pub struct UplinkStation {
+    /// Token the station's server must present in its uplink hello.
+    pub token: String,
+    /// Token the client then presents to that server.
+    pub client_token: Option<String>,
+}
+
+impl UplinkStation {
+    fn accepts(&self, token: Option<&str>) -> bool {
+        token.is_some_and(|token| hash_token(token) == hash_token(&self.token))
+    }
+}
+
+/// Live uplink sessions, keyed by station name.
+#[derive(Clone)]
+pub struct UplinkSessions {
+    sessions: Arc<watch::Sender<HashMap<String, Mux>>>,
+    tls: bool,
+}
+
+impl UplinkSessions {
+    /// `tls` records whether the listener accepts TLS, for the streams'
+    /// `is_tls` flag.
+    pub fn new(tls: bool) -> Self {
+        Self {
+            sessions: Arc::new(watch::Sender::new(HashMap::new())),
+            tls,
+        }
+    }
+
+    pub fn is_tls(&self) -> bool {
+        self.tls
+    }
+
+    /// Wait until `station` is connected and return its session.
+    pub async fn wait(&self, station: &str) -> Mux {
+        let mut rx = self.sessions.subscribe();
+        loop {
+            if let Some(mux) = rx
+                .borrow_and_update()
+                .get(station)
+                .filter(|mux| !mux.is_closed())
+            {
+                return mux.clone();
+            }
+            if rx.changed().await.is_err() {
+                // The sender lives in `self`, so this cannot happen.
+                std::future::pending::<()>().await;
+            }
+        }
+    }
+
+    /// Register a new session for `station`, closing the one it replaces.
+    fn insert(&self, station: String, mux: Mux) {
+        self.sessions.send_modify(|sessions| {
+            if let Some(old) = sessions.insert(station, mux) {
+                old.close();
+            }
+        });
+    }
+}
+
+/// Accept uplinks on `addr` until shutdown.
+///
+/// `stations` holds each station name used by an `uplink://` remote.  A
+/// server must present that station's own token; uplinks from other
+/// stations, or with another station's token, are refused.
+pub async fn run_uplink_listener(
+    addr: SocketAddr,
+    tls: Option<TlsAcceptor>,
+    stations: HashMap<String, UplinkStation>,
+    sessions: UplinkSessions,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!(
+        "Uplink listener on {}{}",
+        addr,
+        if tls.is_some() { " (TLS)" } else { "" }
+    );
+    let stations = Arc::new(stations);
+
+    loop {
+        tokio::select! {
+            accept = listener.accept() => {
+                let (socket, peer) = accept?;
+                let _ = socket.set_nodelay(true);
+                let tls = tls.clone();
+                let stations = stations.clone();
+                let sessions = sessions.clone();
+                tokio::spawn(async move {
+                    handle_uplink(socket, peer, tls, &stations, &sessions).await;
+                });
+            }
+            changed = shutdown_rx.changed() => {
+                if changed.is_err() || *shutdown_rx.borrow() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+async fn handle_uplink(
+    socket: TcpStream,
+    peer: SocketAddr,
+    tls: Option<TlsAcceptor>,
+    stations: &HashMap<String, UplinkStation>,
+    sessions: &UplinkSessions,
+) {
+    let result = time::timeout(HANDSHAKE_TIMEOUT, async {
+        let mut stream = TransportStream::accept(socket, tls.as_ref()).await?;
+        let hello = UplinkHello::read(&mut stream).await?;
+        Ok::<_, std::io::Error>((stream, hello))
+    })
+    .await;
+    let (stream, hello) = match result {
+        Ok(Ok(v)) => v,
+        Ok(Err(e)) => {
+            warn!("Uplink from {} failed: {}", peer, e);
+            return;
+        }
+        Err(_) => {
+            warn!("Uplink from {} timed out", peer);
+            return;
+        }
+    };
+    let Some(station) = stations.get(&hello.station) else {
+        warn!(
+            "Uplink from {} rejected: unknown station '{}'",
+            peer, hello.station
+        );
+        return;
+    };
+    // Only a server holding this station's token gets the client's token
+    // and may replace the station's live session.
+    if !station.accepts(hello.token.as_deref()) {
+        warn!(
+            "Uplink from {} rejected: invalid token for station '{}'",
+            peer, hello.station
+        );
+        return;
+    }
+    let (reader, writer) = tokio::io::split(stream);
+    let mux_hello = MuxHello::new(station.client_token.clone());
+    let handshake = Mux::connect(reader, writer, &mux_hello);
+    match time::timeout(HANDSHAKE_TIMEOUT, handshake).await {
+        Ok(Ok(mux)) => {
+            info!(
+                "Uplink: station '{}' connected from {}",
+                hello.station, peer
+            );
+            sessions.insert(hello.station, mux);
+        }
+        Ok(Err(e)) => warn!("Uplink session with '{}' failed: {}", hello.station, e),
+        Err(_) => warn!("Uplink session with '{}' timed out", hello.station),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::AsyncReadExt;
+    use trx_core::mux::{MuxOpen, MuxStreamKind};
+
+    fn station(token: &str, client_token: Option<&str>) -> UplinkStation {
+        UplinkStation {
+            token: token.to_string(),
+            client_token: client_token.map(str::to_string),
+        }
+    }
+
+    /// Start a listener for `stations` and dial it as a server.
+    async fn dial(
+        stations: HashMap<String, UplinkStation>,
+    ) -> (UplinkSessions, watch::Sender<bool>, TcpStream) {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let sessions = UplinkSessions::new(false);
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        tokio::spawn(run_uplink_listener(
+            addr,
+            None,
+            stations,
+            sessions.clone(),
+            shutdown_rx,
+        ));
+        let socket = loop {
+            match TcpStream::connect(addr).await {
+                Ok(socket) => break socket,
+                Err(_) => time::sleep(Duration::from_millis(20)).await,
+            }
+        };
+        (sessions, shutdown_tx, socket)
+    }
+
+    /// Expect the listener to close `socket` without registering a session.
+    async fn assert_refused(sessions: &UplinkSessions, mut socket: TcpStream) {
+        let mut buf = [0u8; 1];
+        let read = time::timeout(Duration::from_secs(5), socket.read(&mut buf))
+            .await
+            .expect("connection closed");
+        assert_eq!(read.unwrap_or(0), 0);
+        assert!(sessions.sessions.borrow().is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn accepts_known_station_and_runs_a_session() {
+        let stations = HashMap::from([(
+            "field".to_string(),
+            station("uplink-token", Some("client-token")),
+        )]);
+        let (sessions, _shutdown_tx, mut socket) = dial(stations).await;
+
+        // Play the server: introduce, then accept the session.
+        UplinkHello::new("field".to_string(), Some("uplink-token".to_string()))
+            .write(&mut socket)
+            .await
+            .expect("hello");
+        let (reader, writer) = tokio::io::split(socket);
+        let server = tokio::spawn(Mux::accept(reader, writer, |hello| {
+            if hello.token.as_deref() == Some("client-token") {
+                Ok(())
+            } else {
+                Err("bad token".to_string())
+            }
+        }));
+
+        let mux = time::timeout(Duration::from_secs(5), sessions.wait("field"))
+            .await
+            .expect("session registered");
+        let (_server_mux, _, mut streams) = server.await.unwrap().expect("accept");
+        let _stream = mux.open(&MuxOpen::audio(None)).expect("open");
+        let (open, _) = streams.recv().await.expect("stream");
+        assert!(matches!(open.stream, MuxStreamKind::Audio { .. }));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn rejects_bad_token() {
+        let stations = HashMap::from([("field".to_string(), station("uplink-token", None))]);
+        let (sessions, _shutdown_tx, mut socket) = dial(stations).await;
+        UplinkHello::new("field".to_string(), Some("wrong".to_string()))
+            .write(&mut socket)
+            .await
+            .expect("hello");
+        assert_refused(&sessions, socket).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn rejects_token_of_another_station() {
+        let stations = HashMap::from([
+            ("field".to_string(), station("field-token", None)),
+            (
+                "home".to_string(),
+                station("home-token", Some("client-token")),
+            ),
+        ]);
+        let (sessions, _shutdown_tx, mut socket) = dial(stations).await;
+        UplinkHello::new("home".to_string(), Some("field-token".to_string()))
+            .write(&mut socket)
+            .await
+            .expect("hello");
+        assert_refused(&sessions, socket).await;
+    }
+}
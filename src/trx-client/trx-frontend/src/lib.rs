@@ -17,9 +17,10 @@ use uuid::Uuid;
 
 use trx_core::audio::AudioStreamInfo;
 use trx_core::decode::{
-    AisMessage, AprsPacket, CwEvent, DecodedMessage, Ft8Message, VdesMessage, WefaxMessage,
-    WsprMessage,
+    AcarsMessage, AdsbMessage, AisMessage, AprsPacket, CwEvent, DecodedMessage, DscMessage,
+    Ft8Message, PocsagMessage, PskEvent, VdesMessage, WefaxMessage, WsprMessage,
 };
+use trx_core::dxspot::{DxSpot, SpotFilter, SpotTable};
 use trx_core::rig::state::{RigSnapshot, SpectrumData};
 use trx_core::{DynResult, RigRequest, RigState};
 use trx_protocol::MeterUpdate;
@@ -225,9 +226,14 @@ impl Default for AudioContext {
 pub struct DecodeHistoryContext {
     pub ais: DecodeHistory<AisMessage>,
     pub vdes: DecodeHistory<VdesMessage>,
+    pub dsc: DecodeHistory<DscMessage>,
+    pub pocsag: DecodeHistory<PocsagMessage>,
+    pub adsb: DecodeHistory<AdsbMessage>,
+    pub acars: DecodeHistory<AcarsMessage>,
     pub aprs: DecodeHistory<AprsPacket>,
     pub hf_aprs: DecodeHistory<AprsPacket>,
     pub cw: DecodeHistory<CwEvent>,
+    pub psk: DecodeHistory<PskEvent>,
     pub ft8: DecodeHistory<Ft8Message>,
     pub ft4: DecodeHistory<Ft8Message>,
     pub ft2: DecodeHistory<Ft8Message>,
@@ -240,9 +246,14 @@ impl Default for DecodeHistoryContext {
         Self {
             ais: Arc::new(Mutex::new(VecDeque::new())),
             vdes: Arc::new(Mutex::new(VecDeque::new())),
+            dsc: Arc::new(Mutex::new(VecDeque::new())),
+            pocsag: Arc::new(Mutex::new(VecDeque::new())),
+            adsb: Arc::new(Mutex::new(VecDeque::new())),
+            acars: Arc::new(Mutex::new(VecDeque::new())),
             aprs: Arc::new(Mutex::new(VecDeque::new())),
             hf_aprs: Arc::new(Mutex::new(VecDeque::new())),
             cw: Arc::new(Mutex::new(VecDeque::new())),
+            psk: Arc::new(Mutex::new(VecDeque::new())),
             ft8: Arc::new(Mutex::new(VecDeque::new())),
             ft4: Arc::new(Mutex::new(VecDeque::new())),
             ft2: Arc::new(Mutex::new(VecDeque::new())),
@@ -263,6 +274,8 @@ pub struct HttpAuthConfig {
     pub cookie_same_site: String,
     /// Authentication tokens for HTTP-JSON frontend.
     pub tokens: HashSet<String>,
+    /// Extra browser origins allowed to open HTTP-JSON WebSockets.
+    pub allowed_origins: HashSet<String>,
 }
 
 impl Default for HttpAuthConfig {
@@ -276,6 +289,7 @@ impl Default for HttpAuthConfig {
             cookie_secure: false,
             cookie_same_site: "Lax".to_string(),
             tokens: HashSet::new(),
+            allowed_origins: HashSet::new(),
         }
     }
 }
@@ -290,6 +304,7 @@ pub struct HttpUiConfig {
     pub bandplan_region: String,
     pub decode_history_retention_min: u64,
     pub decode_history_retention_min_by_rig: HashMap<String, u64>,
+    pub logbook_auto_log: bool,
 }
 
 impl Default for HttpUiConfig {
@@ -303,6 +318,7 @@ impl Default for HttpUiConfig {
             bandplan_region: "iaru_r1".to_string(),
             decode_history_retention_min: 24 * 60,
             decode_history_retention_min_by_rig: HashMap::new(),
+            logbook_auto_log: true,
         }
     }
 }
@@ -393,6 +409,39 @@ impl Default for SpectrumContext {
     }
 }
 
+/// DX cluster / RBN telnet feed settings.
+#[derive(Debug, Clone)]
+pub struct DxClusterFeed {
+    pub host: String,
+    pub port: u16,
+    /// Callsign sent at the login prompt.
+    pub login: String,
+    pub password: Option<String>,
+    /// Node commands sent after login (e.g. `set/skimmer`).
+    pub commands: Vec<String>,
+    pub filter: SpotFilter,
+}
+
+/// DX spots shared with frontends.
+pub struct DxClusterContext {
+    /// Telnet feed to follow; `None` when the DX cluster is disabled.
+    pub feed: Option<DxClusterFeed>,
+    /// Current deduplicated spots.
+    pub spots: Arc<RwLock<SpotTable>>,
+    /// New and refreshed spots, for SSE fan-out.
+    pub events: broadcast::Sender<DxSpot>,
+}
+
+impl Default for DxClusterContext {
+    fn default() -> Self {
+        Self {
+            feed: None,
+            spots: Arc::new(RwLock::new(SpotTable::new(30 * 60 * 1000, 500))),
+            events: broadcast::channel(256).0,
+        }
+    }
+}
+
 /// Per-rig audio channels for multi-rig setups.
 pub struct PerRigAudioContext {
     /// Per-rig RX audio broadcast senders.
@@ -433,6 +482,8 @@ pub struct FrontendRuntimeContext {
     pub spectrum: SpectrumContext,
     /// Per-rig audio channels.
     pub rig_audio: PerRigAudioContext,
+    /// DX cluster spots.
+    pub dx_cluster: DxClusterContext,
     /// Active HTTP SSE clients.
     pub sse_clients: Arc<AtomicUsize>,
     /// Active rigctl TCP clients.
@@ -524,6 +575,7 @@ impl FrontendRuntimeContext {
             vchan: VChanContext::default(),
             spectrum: SpectrumContext::default(),
             rig_audio: PerRigAudioContext::default(),
+            dx_cluster: DxClusterContext::default(),
             sse_clients: Arc::new(AtomicUsize::new(0)),
             rigctl_clients: Arc::new(AtomicUsize::new(0)),
             rigctl_listen_addr: Arc::new(Mutex::new(None)),
@@ -0,0 +1,714 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
+use trx_protocol::{mode_to_string, parse_mode};
+
+use trx_core::radio::freq::Freq;
+use trx_core::rig::state::RigSnapshot;
+use trx_core::{RigCommand, RigMode, RigRequest, RigState};
+use trx_frontend::FrontendSpawner;
+
+use crate::xmlrpc::{self, Value};
+
+/// flrig-compatible frontend.
+///
+/// Serves the XML-RPC API of flrig over HTTP, so programs that drive a rig
+/// through flrig (fldigi, JS8Call, several loggers) can use trx-rs in its
+/// place.  Each listener controls one rig; the client spawns one per entry
+/// in `[frontends.flrig].rig_ports`.
+pub struct FlrigFrontend;
+
+impl FrontendSpawner for FlrigFrontend {
+    fn spawn_frontend(
+        _state_rx: watch::Receiver<RigState>,
+        rig_tx: mpsc::Sender<RigRequest>,
+        _callsign: Option<String>,
+        listen_addr: SocketAddr,
+        _context: Arc<trx_frontend::FrontendRuntimeContext>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            if let Err(e) = serve(listen_addr, rig_tx).await {
+                error!("flrig server error: {:?}", e);
+            }
+        })
+    }
+}
+
+/// Largest request body accepted; flrig calls are a few hundred bytes.
+const MAX_BODY_BYTES: usize = 64 * 1024;
+/// Close idle keep-alive connections after this long.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// XML-RPC fault codes (from the specification for fault code interop).
+const FAULT_PARSE: i32 = -32700;
+const FAULT_METHOD_NOT_FOUND: i32 = -32601;
+const FAULT_INVALID_PARAMS: i32 = -32602;
+const FAULT_APPLICATION: i32 = -32500;
+
+/// Methods served, as reported by `system.listMethods`.
+const METHODS: &[&str] = &[
+    "main.get_version",
+    "rig.get_AB",
+    "rig.get_bw",
+    "rig.get_bws",
+    "rig.get_info",
+    "rig.get_mode",
+    "rig.get_modes",
+    "rig.get_ptt",
+    "rig.get_smeter",
+    "rig.get_split",
+    "rig.get_vfo",
+    "rig.get_vfoA",
+    "rig.get_vfoB",
+    "rig.get_xcvr",
+    "rig.set_AB",
+    "rig.set_bw",
+    "rig.set_frequency",
+    "rig.set_mode",
+    "rig.set_modeA",
+    "rig.set_ptt",
+    "rig.set_split",
+    "rig.set_vfo",
+    "rig.set_vfoA",
+    "system.listMethods",
+];
+
+async fn serve(listen_addr: SocketAddr, rig_tx: mpsc::Sender<RigRequest>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(listen_addr).await?;
+    info!("flrig frontend listening on {}", listen_addr);
+
+    loop {
+        let (stream, addr) = listener.accept().await?;
+        debug!("flrig client connected: {}", addr);
+        let rig_tx = rig_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_client(stream, rig_tx).await {
+                warn!("flrig client {} error: {:?}", addr, e);
+            }
+        });
+    }
+}
+
+/// A parsed HTTP request.
+struct HttpRequest {
+    method: String,
+    body: Vec<u8>,
+    keep_alive: bool,
+}
+
+async fn handle_client(stream: TcpStream, rig_tx: mpsc::Sender<RigRequest>) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    loop {
+        let request = match timeout(IDLE_TIMEOUT, read_request(&mut reader)).await {
+            Ok(Ok(Some(request))) => request,
+            Ok(Ok(None)) | Err(_) => return Ok(()),
+            Ok(Err(e)) if e.kind() == std::io::ErrorKind::InvalidData => {
+                let resp = http_response("400 Bad Request", "text/plain", &e.to_string(), false);
+                writer.write_all(resp.as_bytes()).await?;
+                return Ok(());
+            }
+            Ok(Err(e)) => return Err(e),
+        };
+
+        let resp = if request.method != "POST" {
+            http_response(
+                "405 Method Not Allowed",
+                "text/plain",
+                "XML-RPC requires POST\n",
+                request.keep_alive,
+            )
+        } else {
+            let body = String::from_utf8_lossy(&request.body);
+            let xml = handle_call(&body, &rig_tx).await;
+            http_response("200 OK", "text/xml", &xml, request.keep_alive)
+        };
+        writer.write_all(resp.as_bytes()).await?;
+        writer.flush().await?;
+        if !request.keep_alive {
+            return Ok(());
+        }
+    }
+}
+
+/// Read one request; `None` when the client closed between requests.
+async fn read_request<R>(reader: &mut R) -> std::io::Result<Option<HttpRequest>>
+where
+    R: AsyncBufReadExt + Unpin,
+{
+    let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, msg.to_string());
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let method = parts
+        .next()
+        .ok_or_else(|| invalid("empty request line"))?
+        .to_string();
+    let version = parts.nth(1).unwrap_or("HTTP/1.0");
+    let mut keep_alive = version == "HTTP/1.1";
+
+    let mut content_length = 0usize;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Err(invalid("truncated headers"));
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(invalid("malformed header"));
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .parse()
+                .map_err(|_| invalid("invalid Content-Length"))?;
+        } else if name.eq_ignore_ascii_case("connection") {
+            keep_alive = !value.eq_ignore_ascii_case("close")
+                && (keep_alive || value.eq_ignore_ascii_case("keep-alive"));
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Err(invalid("request body too large"));
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body).await?;
+    Ok(Some(HttpRequest {
+        method,
+        body,
+        keep_alive,
+    }))
+}
+
+fn http_response(status: &str, content_type: &str, body: &str, keep_alive: bool) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nServer: trx-rs\r\nContent-Type: {content_type}\r\n\
+         Content-Length: {}\r\nConnection: {}\r\n\r\n{body}",
+        body.len(),
+        if keep_alive { "keep-alive" } else { "close" }
+    )
+}
+
+/// A failed call, returned to the client as an XML-RPC fault.
+#[derive(Debug)]
+struct Fault {
+    code: i32,
+    msg: String,
+}
+
+impl Fault {
+    fn new(code: i32, msg: impl Into<String>) -> Self {
+        Self {
+            code,
+            msg: msg.into(),
+        }
+    }
+
+    fn params(msg: impl Into<String>) -> Self {
+        Self::new(FAULT_INVALID_PARAMS, msg)
+    }
+
+    fn app(msg: impl Into<String>) -> Self {
+        Self::new(FAULT_APPLICATION, msg)
+    }
+}
+
+/// Decode a `methodCall`, run it and encode the response.
+async fn handle_call(body: &str, rig_tx: &mpsc::Sender<RigRequest>) -> String {
+    let call = match xmlrpc::parse_call(body) {
+        Ok(call) => call,
+        Err(e) => {
+            warn!("flrig: malformed request: {}", e);
+            return xmlrpc::fault(FAULT_PARSE, &format!("parse error: {e}"));
+        }
+    };
+    debug!("flrig call: {} {:?}", call.method, call.params);
+    match execute(&call.method, &call.params, rig_tx).await {
+        Ok(value) => xmlrpc::response(&value),
+        Err(fault) => {
+            warn!("flrig {} failed: {}", call.method, fault.msg);
+            xmlrpc::fault(fault.code, &fault.msg)
+        }
+    }
+}
+
+async fn execute(
+    method: &str,
+    params: &[Value],
+    rig_tx: &mpsc::Sender<RigRequest>,
+) -> Result<Value, Fault> {
+    // Set methods return nothing; flrig answers them with an empty string.
+    let done = || Value::Str(String::new());
+    match method {
+        "system.listMethods" => Ok(Value::Array(
+            METHODS.iter().map(|m| Value::Str(m.to_string())).collect(),
+        )),
+        "main.get_version" => Ok(Value::Str(env!("CARGO_PKG_VERSION").to_string())),
+        "rig.get_xcvr" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Str(snapshot.info.model))
+        }
+        "rig.get_info" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Str(format!(
+                "R:{}\nT:{}\nFA:{}\nM:{}\nL:{}\nU:{}",
+                snapshot.info.model,
+                if snapshot.status.tx_en { "X" } else { "R" },
+                snapshot.status.freq.hz,
+                mode_name(&snapshot.status.mode),
+                passband_hz(&snapshot),
+                snapshot.info.manufacturer,
+            )))
+        }
+        // flrig reports frequencies as strings in Hz.
+        "rig.get_vfo" | "rig.get_vfoA" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Str(snapshot.status.freq.hz.to_string()))
+        }
+        "rig.get_vfoB" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            let hz = snapshot
+                .status
+                .vfo
+                .as_ref()
+                .and_then(|vfo| vfo.entries.get(1))
+                .map_or(snapshot.status.freq.hz, |entry| entry.freq.hz);
+            Ok(Value::Str(hz.to_string()))
+        }
+        "rig.set_vfo" | "rig.set_vfoA" | "rig.set_frequency" => {
+            let hz = params
+                .first()
+                .and_then(Value::as_f64)
+                .filter(|hz| *hz > 0.0 && *hz < u64::MAX as f64)
+                .ok_or_else(|| Fault::params("expected frequency in Hz"))?;
+            send_rig_command(
+                rig_tx,
+                RigCommand::SetFreq(Freq {
+                    hz: hz.round() as u64,
+                }),
+            )
+            .await?;
+            Ok(done())
+        }
+        "rig.get_mode" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Str(mode_name(&snapshot.status.mode)))
+        }
+        "rig.get_modes" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Array(
+                snapshot
+                    .info
+                    .capabilities
+                    .supported_modes
+                    .iter()
+                    .map(|mode| Value::Str(mode_name(mode)))
+                    .collect(),
+            ))
+        }
+        "rig.set_mode" | "rig.set_modeA" => {
+            let mode = params
+                .first()
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|mode| !mode.is_empty())
+                .ok_or_else(|| Fault::params("expected mode name"))?;
+            let mode = parse_mode_name(mode);
+            let snapshot = request_snapshot(rig_tx).await?;
+            if !snapshot.info.capabilities.supported_modes.contains(&mode) {
+                return Err(Fault::params(format!(
+                    "mode {} not supported",
+                    mode_name(&mode)
+                )));
+            }
+            send_rig_command(rig_tx, RigCommand::SetMode(mode)).await?;
+            Ok(done())
+        }
+        // flrig returns the bandwidth and, for rigs with two filter
+        // controls, a second value; the second entry is empty here.
+        "rig.get_bw" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Array(vec![
+                Value::Str(passband_hz(&snapshot).to_string()),
+                Value::Str(String::new()),
+            ]))
+        }
+        "rig.get_bws" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Array(vec![Value::Array(vec![
+                Value::Str("Bandwidth".to_string()),
+                Value::Str(passband_hz(&snapshot).to_string()),
+            ])]))
+        }
+        "rig.set_bw" => {
+            let hz = params
+                .first()
+                .and_then(Value::as_f64)
+                .filter(|hz| *hz > 0.0 && *hz <= f64::from(u32::MAX))
+                .ok_or_else(|| Fault::params("expected bandwidth in Hz"))?;
+            let snapshot = request_snapshot(rig_tx).await?;
+            if !snapshot.info.capabilities.filter_controls {
+                return Err(Fault::app("bandwidth control not supported"));
+            }
+            send_rig_command(rig_tx, RigCommand::SetBandwidth(hz.round() as u32)).await?;
+            Ok(done())
+        }
+        "rig.get_ptt" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Int(i64::from(snapshot.status.tx_en)))
+        }
+        "rig.set_ptt" => {
+            let ptt = params
+                .first()
+                .and_then(Value::as_f64)
+                .ok_or_else(|| Fault::params("expected PTT state (0/1)"))?
+                != 0.0;
+            let snapshot = request_snapshot(rig_tx).await?;
+            if !rig_supports_ptt(&snapshot) {
+                return Err(Fault::app("PTT not supported"));
+            }
+            send_rig_command(rig_tx, RigCommand::SetPtt(ptt)).await?;
+            Ok(done())
+        }
+        "rig.get_AB" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Str(active_vfo(&snapshot).to_string()))
+        }
+        "rig.set_AB" => {
+            let target = match params.first().and_then(Value::as_str).map(str::trim) {
+                Some("A" | "a") => "A",
+                Some("B" | "b") => "B",
+                _ => return Err(Fault::params("expected VFO A or B")),
+            };
+            let snapshot = request_snapshot(rig_tx).await?;
+            if active_vfo(&snapshot) != target {
+                let supports_toggle = snapshot.info.capabilities.num_vfos >= 2
+                    && snapshot
+                        .status
+                        .vfo
+                        .as_ref()
+                        .is_some_and(|vfo| vfo.entries.len() >= 2);
+                if !supports_toggle {
+                    return Err(Fault::app("VFO selection not supported"));
+                }
+                send_rig_command(rig_tx, RigCommand::ToggleVfo).await?;
+            }
+            Ok(done())
+        }
+        // Split is not modelled by the core rig task.
+        "rig.get_split" => Ok(Value::Int(0)),
+        "rig.set_split" => match params.first().and_then(Value::as_f64) {
+            Some(0.0) => Ok(done()),
+            Some(_) => Err(Fault::app("split not supported")),
+            None => Err(Fault::params("expected split state (0/1)")),
+        },
+        "rig.get_smeter" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(Value::Int(smeter_scale(&snapshot)))
+        }
+        _ => Err(Fault::new(
+            FAULT_METHOD_NOT_FOUND,
+            format!("method {method} not found"),
+        )),
+    }
+}
+
+/// flrig mode names; `CW-R` is what flrig uses for reverse CW.
+fn mode_name(mode: &RigMode) -> String {
+    match mode {
+        RigMode::CWR => "CW-R".to_string(),
+        mode => mode_to_string(mode).into_owned(),
+    }
+}
+
+fn parse_mode_name(name: &str) -> RigMode {
+    match name.to_ascii_uppercase().as_str() {
+        "CW-R" | "CW-REV" => RigMode::CWR,
+        other => parse_mode(other),
+    }
+}
+
+fn passband_hz(snapshot: &RigSnapshot) -> u32 {
+    snapshot.filter.as_ref().map_or(0, |f| f.bandwidth_hz)
+}
+
+fn active_vfo(snapshot: &RigSnapshot) -> &'static str {
+    match snapshot.status.vfo.as_ref().and_then(|vfo| vfo.active) {
+        Some(1) => "B",
+        _ => "A",
+    }
+}
+
+/// Signal level on flrig's 0–100 meter scale: S0 (-127 dBm) to S9+60 dB.
+fn smeter_scale(snapshot: &RigSnapshot) -> i64 {
+    const S0_DBM: f64 = -127.0;
+    const S9_PLUS_60_DBM: f64 = -13.0;
+    snapshot
+        .status
+        .rx
+        .as_ref()
+        .and_then(|rx| rx.sig)
+        .map_or(0, |dbm| {
+            ((dbm - S0_DBM) / (S9_PLUS_60_DBM - S0_DBM) * 100.0)
+                .clamp(0.0, 100.0)
+                .round() as i64
+        })
+}
+
+fn rig_supports_ptt(snapshot: &RigSnapshot) -> bool {
+    snapshot.status.tx.is_some()
+        || snapshot
+            .info
+            .capabilities
+            .supported_bands
+            .iter()
+            .any(|b| b.tx_allowed)
+}
+
+async fn request_snapshot(rig_tx: &mpsc::Sender<RigRequest>) -> Result<RigSnapshot, Fault> {
+    send_rig_command(rig_tx, RigCommand::GetSnapshot).await
+}
+
+async fn send_rig_command(
+    rig_tx: &mpsc::Sender<RigRequest>,
+    cmd: RigCommand,
+) -> Result<RigSnapshot, Fault> {
+    let (resp_tx, resp_rx) = oneshot::channel();
+    rig_tx
+        .send(RigRequest {
+            cmd,
+            respond_to: resp_tx,
+            rig_id_override: None,
+        })
+        .await
+        .map_err(|e| Fault::app(format!("failed to send to rig: {e:?}")))?;
+
+    match timeout(Duration::from_secs(15), resp_rx).await {
+        Ok(Ok(Ok(snapshot))) => Ok(snapshot),
+        Ok(Ok(Err(err))) => Err(Fault::app(err.message)),
+        Ok(Err(e)) => Err(Fault::app(format!("rig response error: {e:?}"))),
+        Err(_) => Err(Fault::app("rig response timeout")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trx_core::rig::{RigAccessMethod, RigCapabilities, RigInfo, RigStatus, RigTxStatus};
+
+    fn test_snapshot() -> RigSnapshot {
+        RigSnapshot {
+            info: RigInfo {
+                manufacturer: "TRX".to_string(),
+                model: "Virtual".to_string(),
+                revision: "0.1.0".to_string(),
+                capabilities: RigCapabilities {
+                    min_freq_step_hz: 1,
+                    supported_bands: vec![],
+                    supported_modes: vec![RigMode::USB, RigMode::LSB, RigMode::CWR],
+                    num_vfos: 1,
+                    lock: false,
+                    lockable: false,
+                    attenuator: false,
+                    preamp: false,
+                    rit: false,
+                    xit: false,
+                    rpt: false,
+                    split: false,
+                    tx: true,
+                    tx_limit: false,
+                    vfo_switch: false,
+                    filter_controls: false,
+                    signal_meter: true,
+                    memory_channels: 0,
+                    memory_write: false,
+                    band_stack: false,
+                    ctcss_decode: false,
+                    dcs: false,
+                },
+                access: RigAccessMethod::Tcp {
+                    addr: "127.0.0.1:4532".to_string(),
+                },
+            },
+            status: RigStatus {
+                freq: Freq { hz: 7_074_000 },
+                mode: RigMode::USB,
+                tx_en: false,
+                vfo: None,
+                tx: Some(RigTxStatus {
+                    power: None,
+                    limit: None,
+                    swr: None,
+                    alc: None,
+                }),
+                rx: None,
+                lock: None,
+            },
+            band: None,
+            enabled: Some(true),
+            initialized: true,
+            server_callsign: None,
+            server_version: None,
+            server_build_date: None,
+            server_latitude: None,
+            server_longitude: None,
+            pskreporter_status: None,
+            aprs_is_status: None,
+            decoders: trx_core::DecoderConfig::default(),
+            cw_auto: false,
+            cw_wpm: 0,
+            cw_tone_hz: 0,
+            psk_mode: trx_core::decode::PskMode::default(),
+            psk_freq_hz: 0,
+            psk_waterfall: false,
+            sat_tracking: None,
+            rotator: None,
+            cw_keyer: None,
+            memories: Vec::new(),
+            repeater: None,
+            clarifier: None,
+            filter: None,
+            spectrum: None,
+            vchan_rds: None,
+        }
+    }
+
+    /// Rig task that applies frequency, mode and PTT changes.
+    fn spawn_rig(mut snapshot: RigSnapshot) -> mpsc::Sender<RigRequest> {
+        let (rig_tx, mut rig_rx) = mpsc::channel::<RigRequest>(8);
+        tokio::spawn(async move {
+            while let Some(req) = rig_rx.recv().await {
+                match req.cmd {
+                    RigCommand::SetFreq(freq) => snapshot.status.freq = freq,
+                    RigCommand::SetMode(mode) => snapshot.status.mode = mode,
+                    RigCommand::SetPtt(ptt) => snapshot.status.tx_en = ptt,
+                    _ => {}
+                }
+                let _ = req.respond_to.send(Ok(snapshot.clone()));
+            }
+        });
+        rig_tx
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        format!(
+            "<?xml version=\"1.0\"?>\n<methodCall><methodName>{method}</methodName>\
+             <params>{params}</params></methodCall>"
+        )
+    }
+
+    fn string_response(value: &str) -> String {
+        xmlrpc::response(&Value::Str(value.to_string()))
+    }
+
+    #[tokio::test]
+    async fn frequency_mode_and_ptt_round_trip() {
+        let rig_tx = spawn_rig(test_snapshot());
+        assert_eq!(
+            handle_call(&call("rig.get_vfo", ""), &rig_tx).await,
+            string_response("7074000")
+        );
+        let set = call(
+            "rig.set_frequency",
+            "<param><value><double>14074000.000000</double></value></param>",
+        );
+        assert_eq!(handle_call(&set, &rig_tx).await, string_response(""));
+        assert_eq!(
+            handle_call(&call("rig.get_vfo", ""), &rig_tx).await,
+            string_response("14074000")
+        );
+
+        let set = call("rig.set_mode", "<param><value>CW-R</value></param>");
+        assert_eq!(handle_call(&set, &rig_tx).await, string_response(""));
+        assert_eq!(
+            handle_call(&call("rig.get_mode", ""), &rig_tx).await,
+            string_response("CW-R")
+        );
+        assert_eq!(
+            handle_call(&call("rig.get_modes", ""), &rig_tx).await,
+            xmlrpc::response(&Value::Array(vec![
+                Value::Str("USB".to_string()),
+                Value::Str("LSB".to_string()),
+                Value::Str("CW-R".to_string()),
+            ]))
+        );
+
+        let set = call("rig.set_ptt", "<param><value><int>1</int></value></param>");
+        assert_eq!(handle_call(&set, &rig_tx).await, string_response(""));
+        assert_eq!(
+            handle_call(&call("rig.get_ptt", ""), &rig_tx).await,
+            xmlrpc::response(&Value::Int(1))
+        );
+        assert_eq!(
+            handle_call(&call("rig.get_bw", ""), &rig_tx).await,
+            xmlrpc::response(&Value::Array(vec![
+                Value::Str("0".to_string()),
+                Value::Str(String::new()),
+            ]))
+        );
+    }
+
+    #[tokio::test]
+    async fn bad_calls_return_faults() {
+        let rig_tx = spawn_rig(test_snapshot());
+        let fault = handle_call(&call("rig.no_such", ""), &rig_tx).await;
+        assert!(fault.contains("<int>-32601</int>"));
+        let fault = handle_call(&call("rig.set_vfo", ""), &rig_tx).await;
+        assert!(fault.contains("<int>-32602</int>"));
+        let set = call("rig.set_mode", "<param><value>WFM</value></param>");
+        assert!(handle_call(&set, &rig_tx)
+            .await
+            .contains("<int>-32602</int>"));
+        let set = call("rig.set_bw", "<param><value><int>500</int></value></param>");
+        assert!(handle_call(&set, &rig_tx)
+            .await
+            .contains("<int>-32500</int>"));
+        let fault = handle_call("<methodCall>", &rig_tx).await;
+        assert!(fault.contains("<int>-32700</int>"));
+    }
+
+    #[tokio::test]
+    async fn serves_xml_rpc_over_http() {
+        let rig_tx = spawn_rig(test_snapshot());
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ = handle_client(stream, rig_tx).await;
+        });
+
+        let mut stream = TcpStream::connect(addr).await.expect("connect");
+        let body = call("rig.get_vfo", "");
+        for _ in 0..2 {
+            let request = format!(
+                "POST /RPC2 HTTP/1.1\r\nHost: localhost\r\nContent-Type: text/xml\r\n\
+                 Content-Length: {}\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(request.as_bytes()).await.unwrap();
+        }
+        stream.shutdown().await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        let expected = string_response("7074000");
+        assert_eq!(response.matches("HTTP/1.1 200 OK\r\n").count(), 2);
+        assert_eq!(response.matches(&expected).count(), 2);
+        assert!(response.contains(&format!("Content-Length: {}\r\n", expected.len())));
+    }
+}
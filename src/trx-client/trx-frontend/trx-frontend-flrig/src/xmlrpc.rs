@@ -0,0 +1,368 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Minimal XML-RPC codec.
+//!
+//! Covers what flrig clients send: a `methodCall` whose parameters are
+//! scalars or arrays.  Structs and base64 are rejected.
+
+use std::fmt::Write;
+
+/// An XML-RPC value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Double(f64),
+    Bool(bool),
+    Str(String),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    /// Numeric value; strings holding a number are accepted too, since
+    /// some clients send every parameter untyped.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(v) => Some(*v as f64),
+            Value::Double(v) => Some(*v),
+            Value::Bool(v) => Some(f64::from(u8::from(*v))),
+            Value::Str(s) => s.trim().parse().ok(),
+            Value::Array(_) => None,
+        }
+        .filter(|v| v.is_finite())
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// A decoded `methodCall`.
+#[derive(Debug, PartialEq)]
+pub struct MethodCall {
+    pub method: String,
+    pub params: Vec<Value>,
+}
+
+/// Decode a `methodCall` document.
+pub fn parse_call(xml: &str) -> Result<MethodCall, String> {
+    let mut p = Parser { s: xml, pos: 0 };
+    p.skip_prolog();
+    p.open("methodCall")?;
+    p.open("methodName")?;
+    let method = p.text()?.trim().to_string();
+    p.close("methodName")?;
+    let mut params = Vec::new();
+    match p.tag()? {
+        Tag::Open("params") => {
+            loop {
+                match p.tag()? {
+                    Tag::Open("param") => {
+                        p.open("value")?;
+                        params.push(p.value()?);
+                        p.close("param")?;
+                    }
+                    Tag::Close("params") => break,
+                    other => return Err(format!("expected <param>, found {other:?}")),
+                }
+            }
+            p.close("methodCall")?;
+        }
+        Tag::Empty("params") => p.close("methodCall")?,
+        Tag::Close("methodCall") => {}
+        other => return Err(format!("unexpected {other:?}")),
+    }
+    Ok(MethodCall { method, params })
+}
+
+/// Encode a successful `methodResponse`.
+pub fn response(value: &Value) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\"?>\n<methodResponse><params><param>");
+    write_value(&mut xml, value);
+    xml.push_str("</param></params></methodResponse>\n");
+    xml
+}
+
+/// Encode a fault `methodResponse`.
+pub fn fault(code: i32, message: &str) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\"?>\n<methodResponse><fault><value><struct>");
+    let _ = write!(
+        xml,
+        "<member><name>faultCode</name><value><int>{code}</int></value></member>"
+    );
+    xml.push_str("<member><name>faultString</name><value><string>");
+    escape_into(&mut xml, message);
+    xml.push_str("</string></value></member></struct></value></fault></methodResponse>\n");
+    xml
+}
+
+fn write_value(xml: &mut String, value: &Value) {
+    xml.push_str("<value>");
+    match value {
+        Value::Int(v) => {
+            let _ = write!(xml, "<i4>{v}</i4>");
+        }
+        Value::Double(v) => {
+            let _ = write!(xml, "<double>{v}</double>");
+        }
+        Value::Bool(v) => {
+            let _ = write!(xml, "<boolean>{}</boolean>", u8::from(*v));
+        }
+        Value::Str(s) => {
+            xml.push_str("<string>");
+            escape_into(xml, s);
+            xml.push_str("</string>");
+        }
+        Value::Array(items) => {
+            xml.push_str("<array><data>");
+            for item in items {
+                write_value(xml, item);
+            }
+            xml.push_str("</data></array>");
+        }
+    }
+    xml.push_str("</value>");
+}
+
+fn escape_into(xml: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            '&' => xml.push_str("&amp;"),
+            _ => xml.push(c),
+        }
+    }
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let end = rest[amp..]
+            .find(';')
+            .ok_or_else(|| "unterminated entity".to_string())?;
+        let entity = &rest[amp + 1..amp + end];
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32)
+                    .ok_or_else(|| format!("unknown entity &{entity};"))?
+            }
+        };
+        out.push(c);
+        rest = &rest[amp + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Tag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    Empty(&'a str),
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.s[self.pos..]
+    }
+
+    /// Skip whitespace, the XML declaration and comments.
+    fn skip_prolog(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            let end = if trimmed.starts_with("<?") {
+                trimmed.find("?>").map(|i| i + 2)
+            } else if trimmed.starts_with("<!--") {
+                trimmed.find("-->").map(|i| i + 3)
+            } else {
+                return;
+            };
+            match end {
+                Some(end) => self.pos += end,
+                None => return,
+            }
+        }
+    }
+
+    /// Next tag, skipping whitespace before it.
+    fn tag(&mut self) -> Result<Tag<'a>, String> {
+        self.skip_prolog();
+        let rest = self.rest();
+        if !rest.starts_with('<') {
+            return Err("expected a tag".to_string());
+        }
+        let end = rest
+            .find('>')
+            .ok_or_else(|| "unterminated tag".to_string())?;
+        self.pos += end + 1;
+        let inner = &rest[1..end];
+        let tag = if let Some(name) = inner.strip_prefix('/') {
+            Tag::Close(name.trim())
+        } else if let Some(name) = inner.strip_suffix('/') {
+            Tag::Empty(name.trim())
+        } else {
+            Tag::Open(inner.split_whitespace().next().unwrap_or(""))
+        };
+        Ok(tag)
+    }
+
+    fn open(&mut self, name: &str) -> Result<(), String> {
+        match self.tag()? {
+            Tag::Open(n) if n == name => Ok(()),
+            other => Err(format!("expected <{name}>, found {other:?}")),
+        }
+    }
+
+    fn close(&mut self, name: &str) -> Result<(), String> {
+        match self.tag()? {
+            Tag::Close(n) if n == name => Ok(()),
+            other => Err(format!("expected </{name}>, found {other:?}")),
+        }
+    }
+
+    /// Character data up to the next tag.
+    fn text(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let end = rest.find('<').ok_or_else(|| "unexpected end".to_string())?;
+        self.pos += end;
+        unescape(&rest[..end])
+    }
+
+    /// The contents of a `<value>`, which has been opened, through its
+    /// closing tag.
+    fn value(&mut self) -> Result<Value, String> {
+        let text = self.text()?;
+        let tag = match self.tag()? {
+            // Untyped values are strings.
+            Tag::Close("value") => return Ok(Value::Str(text)),
+            tag => tag,
+        };
+        let value = match tag {
+            Tag::Empty("string") => Value::Str(String::new()),
+            Tag::Open("array") => {
+                let mut items = Vec::new();
+                match self.tag()? {
+                    Tag::Open("data") => loop {
+                        match self.tag()? {
+                            Tag::Open("value") => items.push(self.value()?),
+                            Tag::Close("data") => break,
+                            other => return Err(format!("expected <value>, found {other:?}")),
+                        }
+                    },
+                    Tag::Empty("data") => {}
+                    other => return Err(format!("expected <data>, found {other:?}")),
+                }
+                self.close("array")?;
+                Value::Array(items)
+            }
+            Tag::Open(kind @ ("string" | "int" | "i4" | "i8" | "double" | "boolean")) => {
+                let text = self.text()?;
+                self.close(kind)?;
+                let bad = || format!("invalid {kind} '{text}'");
+                match kind {
+                    "string" => Value::Str(text.clone()),
+                    "double" => Value::Double(text.trim().parse().map_err(|_| bad())?),
+                    "boolean" => match text.trim() {
+                        "1" => Value::Bool(true),
+                        "0" => Value::Bool(false),
+                        _ => return Err(bad()),
+                    },
+                    _ => Value::Int(text.trim().parse().map_err(|_| bad())?),
+                }
+            }
+            other => return Err(format!("unsupported value {other:?}")),
+        };
+        self.close("value")?;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_typed_and_untyped_params() {
+        let call = parse_call(
+            "<?xml version=\"1.0\"?>\n<methodCall><methodName>rig.set_vfo</methodName>\
+             <params><param><value><double>14074000.0</double></value></param>\
+             <param><value>USB &amp; more</value></param>\
+             <param><value><array><data><value><i4>1</i4></value></data></array></value></param>\
+             </params></methodCall>",
+        )
+        .expect("parse");
+        assert_eq!(call.method, "rig.set_vfo");
+        assert_eq!(
+            call.params,
+            [
+                Value::Double(14_074_000.0),
+                Value::Str("USB & more".to_string()),
+                Value::Array(vec![Value::Int(1)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_call_without_params() {
+        let call =
+            parse_call("<methodCall>\n  <methodName>rig.get_vfo</methodName>\n</methodCall>")
+                .expect("parse");
+        assert_eq!(call.method, "rig.get_vfo");
+        assert!(call.params.is_empty());
+        let call =
+            parse_call("<methodCall><methodName>rig.get_mode</methodName><params/></methodCall>")
+                .expect("parse");
+        assert!(call.params.is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_calls() {
+        assert!(parse_call("<methodCall><methodName>x</methodName>").is_err());
+        assert!(parse_call(
+            "<methodCall><methodName>x</methodName><params><param><value><int>fast</int></value></param></params></methodCall>"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn encodes_responses_and_faults() {
+        assert_eq!(
+            response(&Value::Array(vec![
+                Value::Str("2400".to_string()),
+                Value::Str(String::new())
+            ])),
+            "<?xml version=\"1.0\"?>\n<methodResponse><params><param><value><array><data>\
+             <value><string>2400</string></value><value><string></string></value>\
+             </data></array></value></param></params></methodResponse>\n"
+        );
+        let fault = fault(-32601, "no <such> method");
+        assert!(fault.contains("<int>-32601</int>"));
+        assert!(fault.contains("no &lt;such&gt; method"));
+    }
+}
@@ -2,43 +2,67 @@
 //
 // SPDX-License-Identifier: BSD-2-Clause
 
-use std::net::SocketAddr;
+//! JSON control frontend.
+//!
+//! One port serves two transports.  Plain TCP clients exchange
+//! newline-delimited `ClientEnvelope`/`ClientResponse` JSON.  Clients that
+//! open with an HTTP `GET` are upgraded to WebSocket; each text frame then
+//! carries one envelope or response, and `subscribe_state`,
+//! `subscribe_meter` and `subscribe_decode` add `PushEvent` frames to the
+//! same socket.  Browsers may only upgrade from the same origin or one in
+//! `allowed_origins`, so other web pages cannot drive the rig.
+
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures_util::stream::SplitSink;
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
 use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::{mpsc, oneshot, watch};
-use tokio::task::JoinHandle;
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+use tokio::task::{JoinHandle, JoinSet};
 use tokio::time;
-use tracing::{error, info};
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tracing::{error, info, warn};
 
+use trx_core::decode::DecodedMessage;
 use trx_core::rig::request::RigRequest;
 use trx_core::rig::state::RigState;
 use trx_frontend::{FrontendRuntimeContext, FrontendSpawner};
 use trx_protocol::auth::{SimpleTokenValidator, TokenValidator};
 use trx_protocol::codec::parse_envelope;
 use trx_protocol::mapping;
-use trx_protocol::types::{ClientCommand, RigEntry};
-use trx_protocol::ClientResponse;
+use trx_protocol::types::{ClientCommand, ClientEnvelope, PushEvent, RigEntry};
+use trx_protocol::{ClientResponse, MeterUpdate, StateEncoder};
 
 const IO_TIMEOUT: Duration = Duration::from_secs(10);
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(12);
 const MAX_JSON_LINE_BYTES: usize = 256 * 1024;
+/// Push frames queued per WebSocket client before producers wait.
+const PUSH_QUEUE_CAPACITY: usize = 256;
+/// Requests queued per WebSocket client while one is with the rig.
+const REQUEST_QUEUE_CAPACITY: usize = 16;
 
-/// JSON-over-TCP frontend for control and status.
+/// JSON-over-TCP and JSON-over-WebSocket frontend for control and status.
 pub struct HttpJsonFrontend;
 
 impl FrontendSpawner for HttpJsonFrontend {
     fn spawn_frontend(
-        _state_rx: watch::Receiver<RigState>,
+        state_rx: watch::Receiver<RigState>,
         rig_tx: mpsc::Sender<RigRequest>,
         _callsign: Option<String>,
         listen_addr: SocketAddr,
         context: Arc<FrontendRuntimeContext>,
     ) -> JoinHandle<()> {
         tokio::spawn(async move {
-            if let Err(e) = serve(listen_addr, rig_tx, context).await {
+            if let Err(e) = serve(listen_addr, state_rx, rig_tx, context).await {
                 error!("json tcp server error: {:?}", e);
             }
         })
@@ -47,26 +71,43 @@ impl FrontendSpawner for HttpJsonFrontend {
 
 async fn serve(
     listen_addr: SocketAddr,
+    state_rx: watch::Receiver<RigState>,
     rig_tx: mpsc::Sender<RigRequest>,
     context: Arc<FrontendRuntimeContext>,
 ) -> std::io::Result<()> {
     let listener = TcpListener::bind(listen_addr).await?;
     info!("json tcp frontend listening on {}", listen_addr);
+    let loopback_bind = listen_addr.ip().is_loopback();
 
     loop {
         let (socket, addr) = listener.accept().await?;
         info!("json tcp client connected: {}", addr);
 
+        let state_rx = state_rx.clone();
         let tx_clone = rig_tx.clone();
         let context = context.clone();
         tokio::spawn(async move {
-            if let Err(e) = handle_client(socket, addr, tx_clone, context).await {
+            let result = if is_websocket_upgrade(&socket).await {
+                handle_ws_client(socket, addr, loopback_bind, state_rx, tx_clone, context).await
+            } else {
+                handle_client(socket, addr, tx_clone, context).await
+            };
+            if let Err(e) = result {
                 error!("json tcp client {} error: {:?}", addr, e);
             }
         });
     }
 }
 
+/// WebSocket clients open with an HTTP `GET`; JSON lines start with `{`.
+async fn is_websocket_upgrade(socket: &TcpStream) -> bool {
+    let mut first = [0u8; 1];
+    matches!(
+        time::timeout(IO_TIMEOUT, socket.peek(&mut first)).await,
+        Ok(Ok(1))
+    ) && first[0] == b'G'
+}
+
 async fn handle_client(
     socket: TcpStream,
     addr: SocketAddr,
@@ -98,159 +139,426 @@ async fn handle_client(
             continue;
         }
 
-        let envelope = match parse_envelope(trimmed) {
-            Ok(envelope) => envelope,
+        let resp = match parse_envelope(trimmed) {
+            Ok(envelope) => process_envelope(envelope, &tx, &context).await,
             Err(e) => {
                 error!("Invalid JSON from {}: {} / {:?}", addr, trimmed, e);
-                let resp = ClientResponse {
-                    success: false,
-                    rig_id: None,
-                    protocol_version: None,
-                    state: None,
-                    rigs: None,
-                    sat_passes: None,
-                    error: Some(format!("Invalid JSON: {}", e)),
-                };
-                send_response(&mut writer, &resp).await?;
-                continue;
+                error_response(None, format!("Invalid JSON: {}", e))
             }
         };
+        send_response(&mut writer, &resp).await?;
+    }
 
-        if let Err(err) = authorize(&envelope.token, &context) {
-            let resp = ClientResponse {
-                success: false,
-                rig_id: None,
-                protocol_version: None,
-                state: None,
-                rigs: None,
-                sat_passes: None,
-                error: Some(err),
-            };
-            send_response(&mut writer, &resp).await?;
-            continue;
+    Ok(())
+}
+
+async fn handle_ws_client(
+    socket: TcpStream,
+    addr: SocketAddr,
+    loopback_bind: bool,
+    state_rx: watch::Receiver<RigState>,
+    tx: mpsc::Sender<RigRequest>,
+    context: Arc<FrontendRuntimeContext>,
+) -> std::io::Result<()> {
+    let config = WebSocketConfig::default()
+        .max_message_size(Some(MAX_JSON_LINE_BYTES))
+        .max_frame_size(Some(MAX_JSON_LINE_BYTES));
+    let allowed_origins = &context.http_auth.allowed_origins;
+    let open_access = context.http_auth.tokens.is_empty();
+    // The error type is fixed by tungstenite's handshake callback.
+    #[allow(clippy::result_large_err)]
+    let check_origin = |req: &Request, resp: Response| {
+        let header = |name: &str| req.headers().get(name).and_then(|v| v.to_str().ok());
+        if origin_allowed(
+            header("origin"),
+            header("host"),
+            allowed_origins,
+            open_access,
+            loopback_bind,
+        ) {
+            Ok(resp)
+        } else {
+            warn!(
+                "json websocket client {} refused: origin {:?} not allowed",
+                addr,
+                header("origin")
+            );
+            let mut resp = ErrorResponse::new(Some("origin not allowed".to_string()));
+            *resp.status_mut() = StatusCode::FORBIDDEN;
+            Err(resp)
+        }
+    };
+    let ws = time::timeout(
+        IO_TIMEOUT,
+        tokio_tungstenite::accept_hdr_async_with_config(socket, check_origin, Some(config)),
+    )
+    .await
+    .map_err(|_| std::io::Error::new(std::io::ErrorKind::TimedOut, "websocket handshake timeout"))?
+    .map_err(std::io::Error::other)?;
+    info!("json websocket client upgraded: {}", addr);
+
+    let (mut sink, mut stream) = ws.split();
+    let (push_tx, mut push_rx) = mpsc::channel::<PushEvent>(PUSH_QUEUE_CAPACITY);
+    // Dropping the set on disconnect aborts every push task.
+    let mut pushers = JoinSet::new();
+    let mut subscribed = HashSet::new();
+
+    // Rig commands run in a worker, one at a time and in order, so pushes
+    // keep flowing while a command waits for the rig.
+    let (req_tx, mut req_rx) = mpsc::channel::<ClientEnvelope>(REQUEST_QUEUE_CAPACITY);
+    let (resp_tx, mut resp_rx) = mpsc::channel::<ClientResponse>(REQUEST_QUEUE_CAPACITY);
+    let worker_context = context.clone();
+    pushers.spawn(async move {
+        while let Some(envelope) = req_rx.recv().await {
+            let resp = process_envelope(envelope, &tx, &worker_context).await;
+            if resp_tx.send(resp).await.is_err() {
+                return;
+            }
         }
+    });
 
-        if let Some(rig_id) = envelope.rig_id.as_ref() {
-            if let Ok(mut active) = context.routing.active_rig_id.lock() {
-                *active = Some(rig_id.clone());
+    loop {
+        tokio::select! {
+            msg = stream.next() => {
+                let Some(msg) = msg else {
+                    break;
+                };
+                let text = match msg.map_err(std::io::Error::other)? {
+                    Message::Text(text) => text,
+                    Message::Close(_) => break,
+                    Message::Binary(_) => {
+                        let resp = error_response(None, "binary frames are not supported");
+                        send_ws(&mut sink, &resp).await?;
+                        continue;
+                    }
+                    // Pings are answered by tungstenite itself.
+                    _ => continue,
+                };
+                let trimmed = text.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+
+                let resp = match parse_envelope(trimmed) {
+                    Ok(envelope) if is_subscription(&envelope.cmd) => {
+                        match authorize(&envelope.token, &context) {
+                            Ok(()) => subscribe(
+                                envelope,
+                                &state_rx,
+                                &context,
+                                &push_tx,
+                                &mut pushers,
+                                &mut subscribed,
+                            ),
+                            Err(err) => error_response(None, err),
+                        }
+                    }
+                    Ok(envelope) => {
+                        if req_tx.send(envelope).await.is_err() {
+                            break;
+                        }
+                        continue;
+                    }
+                    Err(e) => {
+                        error!("Invalid JSON from {}: {} / {:?}", addr, trimmed, e);
+                        error_response(None, format!("Invalid JSON: {}", e))
+                    }
+                };
+                send_ws(&mut sink, &resp).await?;
+            }
+            Some(resp) = resp_rx.recv() => {
+                send_ws(&mut sink, &resp).await?;
             }
+            Some(event) = push_rx.recv() => {
+                send_ws(&mut sink, &event).await?;
+            }
+        }
+    }
+
+    info!("json websocket client {} disconnected", addr);
+    Ok(())
+}
+
+/// Run one request/response command and build its response.
+async fn process_envelope(
+    envelope: ClientEnvelope,
+    tx: &mpsc::Sender<RigRequest>,
+    context: &FrontendRuntimeContext,
+) -> ClientResponse {
+    if let Err(err) = authorize(&envelope.token, context) {
+        return error_response(None, err);
+    }
+
+    if let Some(rig_id) = envelope.rig_id.as_ref() {
+        if let Ok(mut active) = context.routing.active_rig_id.lock() {
+            *active = Some(rig_id.clone());
         }
+    }
 
-        if matches!(&envelope.cmd, ClientCommand::GetRigs) {
-            let resp = ClientResponse {
+    match &envelope.cmd {
+        ClientCommand::GetRigs => {
+            return ClientResponse {
                 success: true,
                 rig_id: Some("client".to_string()),
                 protocol_version: None,
                 state: None,
-                rigs: Some(snapshot_remote_rigs(context.as_ref())),
+                rigs: Some(snapshot_remote_rigs(context)),
                 sat_passes: None,
+                denied: None,
                 error: None,
             };
-            send_response(&mut writer, &resp).await?;
-            continue;
         }
+        ClientCommand::GetSatPasses => {
+            let passes = context
+                .routing
+                .sat_passes
+                .read()
+                .ok()
+                .and_then(|passes| passes.clone());
+            return match passes {
+                Some(passes) => ClientResponse {
+                    success: true,
+                    rig_id: Some("client".to_string()),
+                    protocol_version: None,
+                    state: None,
+                    rigs: None,
+                    sat_passes: Some(passes),
+                    denied: None,
+                    error: None,
+                },
+                None => error_response(None, "satellite passes not available yet"),
+            };
+        }
+        cmd if is_subscription(cmd) => {
+            return error_response(None, "push subscriptions require a WebSocket connection");
+        }
+        _ => {}
+    }
 
-        let active_rig_id = context
-            .routing
-            .active_rig_id
-            .lock()
-            .ok()
-            .and_then(|v| v.clone());
+    let active_rig_id = active_rig_id(context);
 
-        let rig_cmd = mapping::client_command_to_rig(envelope.cmd);
+    let rig_cmd = mapping::client_command_to_rig(envelope.cmd);
 
-        let (resp_tx, resp_rx) = oneshot::channel();
-        let req = RigRequest {
-            cmd: rig_cmd,
-            respond_to: resp_tx,
-            rig_id_override: None,
-        };
+    let (resp_tx, resp_rx) = oneshot::channel();
+    let req = RigRequest {
+        cmd: rig_cmd,
+        respond_to: resp_tx,
+        rig_id_override: None,
+    };
 
-        match time::timeout(IO_TIMEOUT, tx.send(req)).await {
-            Ok(Ok(())) => {}
-            Ok(Err(e)) => {
-                error!("Failed to send request to rig_task: {:?}", e);
-                let resp = ClientResponse {
-                    success: false,
-                    rig_id: active_rig_id.clone(),
-                    protocol_version: None,
-                    state: None,
-                    rigs: None,
-                    sat_passes: None,
-                    error: Some("Internal error: rig task not available".into()),
-                };
-                send_response(&mut writer, &resp).await?;
-                continue;
+    match time::timeout(IO_TIMEOUT, tx.send(req)).await {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            error!("Failed to send request to rig_task: {:?}", e);
+            return error_response(active_rig_id, "Internal error: rig task not available");
+        }
+        Err(_) => {
+            return error_response(active_rig_id, "Internal error: request queue timeout");
+        }
+    }
+
+    match time::timeout(REQUEST_TIMEOUT, resp_rx).await {
+        Ok(Ok(Ok(snapshot))) => ClientResponse {
+            success: true,
+            rig_id: active_rig_id,
+            protocol_version: None,
+            state: Some(snapshot),
+            rigs: None,
+            sat_passes: None,
+            denied: None,
+            error: None,
+        },
+        Ok(Ok(Err(err))) => error_response(active_rig_id, err.message),
+        Ok(Err(e)) => {
+            error!("Rig response oneshot recv error: {:?}", e);
+            error_response(active_rig_id, "Internal error waiting for rig response")
+        }
+        Err(_) => error_response(active_rig_id, "Request timed out waiting for rig response"),
+    }
+}
+
+fn is_subscription(cmd: &ClientCommand) -> bool {
+    matches!(
+        cmd,
+        ClientCommand::SubscribeState
+            | ClientCommand::SubscribeMeter
+            | ClientCommand::SubscribeDecode
+    )
+}
+
+/// Start a push stream on a WebSocket connection.  Repeating a subscription
+/// is acknowledged without starting a second stream.
+fn subscribe(
+    envelope: ClientEnvelope,
+    state_rx: &watch::Receiver<RigState>,
+    context: &FrontendRuntimeContext,
+    push_tx: &mpsc::Sender<PushEvent>,
+    pushers: &mut JoinSet<()>,
+    subscribed: &mut HashSet<(&'static str, Option<String>)>,
+) -> ClientResponse {
+    let rig_id = envelope.rig_id;
+    let (kind, task_rig_id) = match envelope.cmd {
+        ClientCommand::SubscribeState => {
+            let rx = match rig_id.as_deref() {
+                Some(id) => {
+                    let rx = context
+                        .routing
+                        .rig_states
+                        .read()
+                        .ok()
+                        .and_then(|states| states.get(id).map(|tx| tx.subscribe()));
+                    match rx {
+                        Some(rx) => rx,
+                        None => {
+                            return error_response(rig_id.clone(), format!("Unknown rig_id: {id}"))
+                        }
+                    }
+                }
+                None => state_rx.clone(),
+            };
+            let label = rig_id.clone().or_else(|| active_rig_id(context));
+            if subscribed.insert(("state", rig_id.clone())) {
+                pushers.spawn(push_state(
+                    label.clone().unwrap_or_default(),
+                    rx,
+                    push_tx.clone(),
+                ));
             }
-            Err(_) => {
-                let resp = ClientResponse {
-                    success: false,
-                    rig_id: active_rig_id.clone(),
-                    protocol_version: None,
-                    state: None,
-                    rigs: None,
-                    sat_passes: None,
-                    error: Some("Internal error: request queue timeout".into()),
-                };
-                send_response(&mut writer, &resp).await?;
-                continue;
+            ("state", label)
+        }
+        ClientCommand::SubscribeMeter => {
+            let Some(id) = rig_id.clone().or_else(|| active_rig_id(context)) else {
+                return error_response(None, "no active rig to stream meters from");
+            };
+            let rx = context
+                .routing
+                .rig_meters
+                .read()
+                .ok()
+                .and_then(|meters| meters.get(&id).map(|tx| tx.subscribe()));
+            let Some(rx) = rx else {
+                return error_response(Some(id.clone()), format!("Unknown rig_id: {id}"));
+            };
+            if subscribed.insert(("meter", Some(id.clone()))) {
+                pushers.spawn(push_meter(rx, push_tx.clone()));
             }
+            ("meter", Some(id))
         }
+        ClientCommand::SubscribeDecode => {
+            let Some(decode_tx) = context.audio.decode_rx.as_ref() else {
+                return error_response(rig_id, "decoded messages are not available");
+            };
+            if subscribed.insert(("decode", rig_id.clone())) {
+                pushers.spawn(push_decode(
+                    rig_id.clone(),
+                    decode_tx.subscribe(),
+                    push_tx.clone(),
+                ));
+            }
+            ("decode", rig_id)
+        }
+        _ => return error_response(rig_id, "not a subscription command"),
+    };
 
-        match time::timeout(REQUEST_TIMEOUT, resp_rx).await {
-            Ok(Ok(Ok(snapshot))) => {
-                let resp = ClientResponse {
-                    success: true,
-                    rig_id: active_rig_id.clone(),
-                    protocol_version: None,
-                    state: Some(snapshot),
-                    rigs: None,
-                    sat_passes: None,
-                    error: None,
-                };
-                send_response(&mut writer, &resp).await?;
+    info!(
+        "json websocket client subscribed to {} for rig {:?}",
+        kind, task_rig_id
+    );
+    ClientResponse {
+        success: true,
+        rig_id: task_rig_id,
+        protocol_version: None,
+        state: None,
+        rigs: None,
+        sat_passes: None,
+        denied: None,
+        error: None,
+    }
+}
+
+async fn push_state(
+    rig_id: String,
+    mut state_rx: watch::Receiver<RigState>,
+    push_tx: mpsc::Sender<PushEvent>,
+) {
+    let mut encoder = StateEncoder::new();
+    loop {
+        let snapshot = state_rx.borrow_and_update().snapshot();
+        if let Some(update) = snapshot.and_then(|s| encoder.encode(&rig_id, &s)) {
+            if push_tx.send(PushEvent::State(update)).await.is_err() {
+                return;
             }
-            Ok(Ok(Err(err))) => {
-                let resp = ClientResponse {
-                    success: false,
-                    rig_id: active_rig_id.clone(),
-                    protocol_version: None,
-                    state: None,
-                    rigs: None,
-                    sat_passes: None,
-                    error: Some(err.message),
-                };
-                send_response(&mut writer, &resp).await?;
+        }
+        if state_rx.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn push_meter(
+    mut meter_rx: watch::Receiver<Option<MeterUpdate>>,
+    push_tx: mpsc::Sender<PushEvent>,
+) {
+    loop {
+        let update = meter_rx.borrow_and_update().clone();
+        if let Some(update) = update {
+            if push_tx.send(PushEvent::Meter(update)).await.is_err() {
+                return;
             }
-            Ok(Err(e)) => {
-                error!("Rig response oneshot recv error: {:?}", e);
-                let resp = ClientResponse {
-                    success: false,
-                    rig_id: active_rig_id.clone(),
-                    protocol_version: None,
-                    state: None,
-                    rigs: None,
-                    sat_passes: None,
-                    error: Some("Internal error waiting for rig response".into()),
-                };
-                send_response(&mut writer, &resp).await?;
+        }
+        if meter_rx.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn push_decode(
+    rig_id: Option<String>,
+    mut decode_rx: broadcast::Receiver<DecodedMessage>,
+    push_tx: mpsc::Sender<PushEvent>,
+) {
+    loop {
+        match decode_rx.recv().await {
+            Ok(msg) => {
+                if rig_id.is_some() && msg.rig_id() != rig_id.as_deref() {
+                    continue;
+                }
+                if push_tx
+                    .send(PushEvent::Decode(Box::new(msg)))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
             }
-            Err(_) => {
-                let resp = ClientResponse {
-                    success: false,
-                    rig_id: active_rig_id.clone(),
-                    protocol_version: None,
-                    state: None,
-                    rigs: None,
-                    sat_passes: None,
-                    error: Some("Request timed out waiting for rig response".into()),
-                };
-                send_response(&mut writer, &resp).await?;
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("json websocket decode stream lagged, {} dropped", skipped);
             }
+            Err(broadcast::error::RecvError::Closed) => return,
         }
     }
+}
 
-    Ok(())
+fn active_rig_id(context: &FrontendRuntimeContext) -> Option<String> {
+    context
+        .routing
+        .active_rig_id
+        .lock()
+        .ok()
+        .and_then(|v| v.clone())
+}
+
+fn error_response(rig_id: Option<String>, error: impl Into<String>) -> ClientResponse {
+    ClientResponse {
+        success: false,
+        rig_id,
+        protocol_version: None,
+        state: None,
+        rigs: None,
+        sat_passes: None,
+        denied: None,
+        error: Some(error.into()),
+    }
 }
 
 fn snapshot_remote_rigs(context: &FrontendRuntimeContext) -> Vec<RigEntry> {
@@ -343,6 +651,61 @@ async fn send_response(
     Ok(())
 }
 
+async fn send_ws<T: Serialize>(
+    sink: &mut SplitSink<WebSocketStream<TcpStream>, Message>,
+    frame: &T,
+) -> std::io::Result<()> {
+    let text = serde_json::to_string(frame).map_err(std::io::Error::other)?;
+    time::timeout(IO_TIMEOUT, sink.send(Message::text(text)))
+        .await
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::TimedOut, "websocket send timeout"))?
+        .map_err(std::io::Error::other)
+}
+
+/// Whether a WebSocket upgrade may proceed.  Requests without `Origin`
+/// come from non-browser clients; browsers must be on an origin listed in
+/// `allowed`, or on the listener's own origin.
+///
+/// Without tokens (`open_access`) the `Host` header proves nothing: DNS
+/// rebinding points an attacker's name at the listener and both headers
+/// then carry that name.  Only loopback origins on a loopback listener
+/// count as the listener's own origin in that case.
+fn origin_allowed(
+    origin: Option<&str>,
+    host: Option<&str>,
+    allowed: &HashSet<String>,
+    open_access: bool,
+    loopback_bind: bool,
+) -> bool {
+    let Some(origin) = origin else {
+        return true;
+    };
+    let origin = origin.trim().trim_end_matches('/');
+    if allowed.iter().any(|a| a.eq_ignore_ascii_case(origin)) {
+        return true;
+    }
+    let Some(origin_host) = origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+    else {
+        return false;
+    };
+    if open_access {
+        return loopback_bind && is_loopback_host(origin_host);
+    }
+    host.is_some_and(|h| origin_host.eq_ignore_ascii_case(h.trim()))
+}
+
+/// `localhost` or a loopback address, with or without a port.
+fn is_loopback_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
 fn authorize(token: &Option<String>, context: &FrontendRuntimeContext) -> Result<(), String> {
     let validator = SimpleTokenValidator::new(context.http_auth.tokens.clone());
     validator.validate(token)
@@ -355,12 +718,24 @@ mod tests {
     use std::net::Ipv4Addr;
 
     use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 
     use trx_core::radio::freq::{Band, Freq};
     use trx_core::rig::state::RigSnapshot;
     use trx_core::rig::{RigAccessMethod, RigCapabilities, RigInfo, RigStatus, RigTxStatus};
     use trx_core::RigMode;
 
+    /// Connect once `serve` has bound the listener.
+    async fn connect(addr: SocketAddr) -> TcpStream {
+        for _ in 0..50 {
+            if let Ok(stream) = TcpStream::connect(addr).await {
+                return stream;
+            }
+            time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("listener on {addr} never came up");
+    }
+
     fn loopback_addr() -> SocketAddr {
         let listener = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).expect("bind");
         let addr = listener.local_addr().expect("local_addr");
@@ -388,6 +763,7 @@ mod tests {
                     attenuator: false,
                     preamp: false,
                     rit: false,
+                    xit: false,
                     rpt: false,
                     split: false,
                     tx: true,
@@ -395,6 +771,11 @@ mod tests {
                     vfo_switch: true,
                     filter_controls: false,
                     signal_meter: true,
+                    memory_channels: 0,
+                    memory_write: false,
+                    band_stack: false,
+                    ctcss_decode: false,
+                    dcs: false,
                 },
                 access: RigAccessMethod::Tcp {
                     addr: "127.0.0.1:1234".to_string(),
@@ -428,6 +809,15 @@ mod tests {
             cw_auto: true,
             cw_wpm: 15,
             cw_tone_hz: 700,
+            psk_mode: trx_core::decode::PskMode::default(),
+            psk_freq_hz: 1000,
+            psk_waterfall: false,
+            sat_tracking: None,
+            rotator: None,
+            cw_keyer: None,
+            memories: Vec::new(),
+            repeater: None,
+            clarifier: None,
             filter: None,
             spectrum: None,
             vchan_rds: None,
@@ -443,9 +833,10 @@ mod tests {
         runtime.http_auth.tokens = HashSet::from(["secret".to_string()]);
         let ctx = Arc::new(runtime);
 
-        let handle = tokio::spawn(serve(addr, rig_tx, ctx));
+        let (_state_tx, state_rx) = watch::channel(RigState::new_uninitialized());
+        let handle = tokio::spawn(serve(addr, state_rx, rig_tx, ctx));
 
-        let stream = TcpStream::connect(addr).await.expect("connect");
+        let stream = connect(addr).await;
         let (reader, mut writer) = stream.into_split();
         let mut reader = BufReader::new(reader);
 
@@ -478,9 +869,10 @@ mod tests {
                 let _ = req.respond_to.send(Ok(sample_snapshot()));
             }
         });
-        let handle = tokio::spawn(serve(addr, rig_tx, ctx));
+        let (_state_tx, state_rx) = watch::channel(RigState::new_uninitialized());
+        let handle = tokio::spawn(serve(addr, state_rx, rig_tx, ctx));
 
-        let stream = TcpStream::connect(addr).await.expect("connect");
+        let stream = connect(addr).await;
         let (reader, mut writer) = stream.into_split();
         let mut reader = BufReader::new(reader);
 
@@ -501,4 +893,233 @@ mod tests {
         handle.abort();
         let _ = handle.await;
     }
+
+    #[test]
+    fn origin_must_match_host_or_allow_list() {
+        let allowed = HashSet::from(["https://shack.example.com".to_string()]);
+        let check = |origin, host| origin_allowed(origin, host, &allowed, false, false);
+        assert!(check(None, Some("10.0.0.2:4533")));
+        assert!(check(Some("http://10.0.0.2:4533"), Some("10.0.0.2:4533")));
+        assert!(check(
+            Some("https://shack.example.com/"),
+            Some("10.0.0.2:4533")
+        ));
+        assert!(!check(Some("http://evil.example"), Some("10.0.0.2:4533")));
+        assert!(!check(Some("null"), Some("10.0.0.2:4533")));
+        assert!(!check(Some("http://10.0.0.2:4533"), None));
+    }
+
+    #[test]
+    fn open_access_does_not_trust_host() {
+        let allowed = HashSet::from(["https://shack.example.com".to_string()]);
+        // DNS rebinding: the attacker's name resolves to the listener.
+        let evil = (Some("http://evil.example"), Some("evil.example"));
+        assert!(!origin_allowed(evil.0, evil.1, &allowed, true, true));
+        assert!(!origin_allowed(evil.0, evil.1, &allowed, true, false));
+        assert!(origin_allowed(evil.0, evil.1, &allowed, false, false));
+
+        let local = Some("127.0.0.1:4533");
+        assert!(origin_allowed(
+            Some("http://127.0.0.1:4533"),
+            local,
+            &allowed,
+            true,
+            true
+        ));
+        assert!(origin_allowed(
+            Some("http://localhost:4533"),
+            local,
+            &allowed,
+            true,
+            true
+        ));
+        assert!(origin_allowed(
+            Some("http://[::1]:4533"),
+            local,
+            &allowed,
+            true,
+            true
+        ));
+        assert!(!origin_allowed(
+            Some("http://127.0.0.1:4533"),
+            local,
+            &allowed,
+            true,
+            false
+        ));
+        assert!(origin_allowed(
+            Some("https://shack.example.com"),
+            Some("10.0.0.2:4533"),
+            &allowed,
+            true,
+            false
+        ));
+        assert!(origin_allowed(
+            None,
+            Some("evil.example"),
+            &allowed,
+            true,
+            false
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn websocket_refuses_foreign_origin() {
+        let addr = loopback_addr();
+        let (rig_tx, _rig_rx) = mpsc::channel::<RigRequest>(8);
+        let ctx = Arc::new(FrontendRuntimeContext::new());
+        let (_state_tx, state_rx) = watch::channel(RigState::new_uninitialized());
+        let handle = tokio::spawn(serve(addr, state_rx, rig_tx, ctx));
+
+        let stream = connect(addr).await;
+        let mut request = format!("ws://{addr}/")
+            .into_client_request()
+            .expect("request");
+        request.headers_mut().insert(
+            "Origin",
+            "http://evil.example".parse().expect("header value"),
+        );
+        assert!(tokio_tungstenite::client_async(request, stream)
+            .await
+            .is_err());
+
+        // A rebound name passes a Host check, but open access ignores Host.
+        let stream = connect(addr).await;
+        let mut request = format!("ws://{addr}/")
+            .into_client_request()
+            .expect("request");
+        let headers = request.headers_mut();
+        headers.insert("Host", "evil.example".parse().expect("header value"));
+        headers.insert(
+            "Origin",
+            "http://evil.example".parse().expect("header value"),
+        );
+        assert!(tokio_tungstenite::client_async(request, stream)
+            .await
+            .is_err());
+
+        handle.abort();
+        let _ = handle.await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn websocket_carries_responses_and_decode_events() {
+        let addr = loopback_addr();
+        let (rig_tx, mut rig_rx) = mpsc::channel::<RigRequest>(8);
+        let (decode_tx, _decode_rx) = broadcast::channel::<DecodedMessage>(8);
+        let mut runtime = FrontendRuntimeContext::new();
+        runtime.audio.decode_rx = Some(decode_tx.clone());
+        let ctx = Arc::new(runtime);
+
+        let rig_worker = tokio::spawn(async move {
+            if let Some(req) = rig_rx.recv().await {
+                let _ = req.respond_to.send(Ok(sample_snapshot()));
+            }
+        });
+        let (_state_tx, state_rx) = watch::channel(RigState::new_uninitialized());
+        let handle = tokio::spawn(serve(addr, state_rx, rig_tx, ctx));
+
+        let stream = connect(addr).await;
+        let (mut ws, _) = tokio_tungstenite::client_async(format!("ws://{addr}/"), stream)
+            .await
+            .expect("websocket handshake");
+
+        async fn next_json<S>(ws: &mut WebSocketStream<S>) -> serde_json::Value
+        where
+            S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+        {
+            loop {
+                match ws.next().await.expect("frame").expect("websocket") {
+                    Message::Text(text) => return serde_json::from_str(&text).expect("json"),
+                    _ => continue,
+                }
+            }
+        }
+
+        ws.send(Message::text(r#"{"cmd":"get_state"}"#))
+            .await
+            .expect("send");
+        let resp = next_json(&mut ws).await;
+        assert_eq!(resp["success"], true);
+        assert_eq!(resp["state"]["status"]["freq"]["hz"], 14_074_000);
+
+        ws.send(Message::text(r#"{"cmd":"subscribe_decode"}"#))
+            .await
+            .expect("send");
+        assert_eq!(next_json(&mut ws).await["success"], true);
+
+        decode_tx
+            .send(DecodedMessage::Cw(trx_core::decode::CwEvent {
+                rig_id: Some("hf".to_string()),
+                text: "CQ".to_string(),
+                wpm: 20,
+                tone_hz: 700,
+                signal_on: true,
+            }))
+            .expect("broadcast");
+        let event = next_json(&mut ws).await;
+        assert_eq!(event["event"], "decode");
+        assert_eq!(event["data"]["text"], "CQ");
+
+        let _ = rig_worker.await;
+        handle.abort();
+        let _ = handle.await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn websocket_pushes_while_command_is_pending() {
+        let addr = loopback_addr();
+        let (rig_tx, mut rig_rx) = mpsc::channel::<RigRequest>(8);
+        let (decode_tx, _decode_rx) = broadcast::channel::<DecodedMessage>(8);
+        let mut runtime = FrontendRuntimeContext::new();
+        runtime.audio.decode_rx = Some(decode_tx.clone());
+        let ctx = Arc::new(runtime);
+        let (_state_tx, state_rx) = watch::channel(RigState::new_uninitialized());
+        let handle = tokio::spawn(serve(addr, state_rx, rig_tx, ctx));
+
+        let stream = connect(addr).await;
+        let (mut ws, _) = tokio_tungstenite::client_async(format!("ws://{addr}/"), stream)
+            .await
+            .expect("websocket handshake");
+        ws.send(Message::text(r#"{"cmd":"subscribe_decode"}"#))
+            .await
+            .expect("send");
+        ws.send(Message::text(r#"{"cmd":"get_state"}"#))
+            .await
+            .expect("send");
+        // Hold the command at the rig while a decode is pushed.
+        let pending = rig_rx.recv().await.expect("request");
+        decode_tx
+            .send(DecodedMessage::Cw(trx_core::decode::CwEvent {
+                rig_id: None,
+                text: "CQ".to_string(),
+                wpm: 20,
+                tone_hz: 700,
+                signal_on: true,
+            }))
+            .expect("broadcast");
+
+        let mut frames = Vec::new();
+        while frames.len() < 2 {
+            if let Message::Text(text) = ws.next().await.expect("frame").expect("websocket") {
+                frames.push(serde_json::from_str::<serde_json::Value>(&text).expect("json"));
+            }
+        }
+        assert_eq!(frames[0]["success"], true);
+        assert_eq!(frames[1]["event"], "decode");
+
+        let _ = pending.respond_to.send(Ok(sample_snapshot()));
+        let resp = loop {
+            if let Message::Text(text) = ws.next().await.expect("frame").expect("websocket") {
+                break serde_json::from_str::<serde_json::Value>(&text).expect("json");
+            }
+        };
+        assert_eq!(resp["state"]["status"]["freq"]["hz"], 14_074_000);
+
+        handle.abort();
+        let _ = handle.await;
+    }
 }
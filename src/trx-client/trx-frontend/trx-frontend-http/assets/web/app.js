@@ -286,6 +286,11 @@ function applyAuthRestrictions() {
       "ft4-decode-toggle-btn",
       "ft2-decode-toggle-btn",
       "wspr-decode-toggle-btn",
+      "psk-decode-toggle-btn",
+      "dsc-decode-toggle-btn",
+      "pocsag-decode-toggle-btn",
+      "adsb-decode-toggle-btn",
+      "acars-decode-toggle-btn",
       "lrpt-decode-toggle-btn",
       "hf-aprs-decode-toggle-btn",
       "cw-auto",
@@ -294,6 +299,11 @@ function applyAuthRestrictions() {
       "settings-clear-aprs-history",
       "settings-clear-hf-aprs-history",
       "settings-clear-cw-history",
+      "settings-clear-psk-history",
+      "settings-clear-dsc-history",
+      "settings-clear-pocsag-history",
+      "settings-clear-adsb-history",
+      "settings-clear-acars-history",
       "settings-clear-ft8-history",
       "settings-clear-ft4-history",
       "settings-clear-ft2-history",
@@ -320,6 +330,10 @@ function applyAuthRestrictions() {
 function applyCapabilities(caps) {
   if (!caps) return;
   lastHasTx = !!caps.tx;
+  lastMemoryChannels = caps.memory_channels || 0;
+  lastMemoryWrite = !!caps.memory_write;
+  lastRepeaterCaps = { rpt: !!caps.rpt, ctcss_decode: !!caps.ctcss_decode, dcs: !!caps.dcs };
+  if (typeof bmSyncAccess === "function") bmSyncAccess();
   if (signalVisualBlockEl) signalVisualBlockEl.style.display = "";
 
   // PTT / TX controls
@@ -519,7 +533,8 @@ function syncDecoderToggle(entry, enabled, label) {
 // About-tab decoder status elements — resolved lazily after template clone.
 const _aboutDecIds = [
   "about-dec-ft8", "about-dec-ft4", "about-dec-ft2", "about-dec-wspr",
-  "about-dec-cw", "about-dec-aprs", "about-dec-lrpt",
+  "about-dec-cw", "about-dec-aprs", "about-dec-lrpt", "about-dec-psk",
+  "about-dec-dsc", "about-dec-pocsag", "about-dec-adsb", "about-dec-acars",
 ];
 let _aboutDecEls = _aboutDecIds.map(() => ({ el: null, last: null }));
 function _resolveAboutDecEls() {
@@ -556,6 +571,11 @@ window.applyDecodeHistoryRetention = function() {
   if (typeof window.pruneVdesHistoryView === "function") window.pruneVdesHistoryView();
   if (typeof window.pruneFt8HistoryView === "function") window.pruneFt8HistoryView();
   if (typeof window.pruneWsprHistoryView === "function") window.pruneWsprHistoryView();
+  if (typeof window.prunePskHistoryView === "function") window.prunePskHistoryView();
+  if (typeof window.pruneDscHistoryView === "function") window.pruneDscHistoryView();
+  if (typeof window.prunePocsagHistoryView === "function") window.prunePocsagHistoryView();
+  if (typeof window.pruneAdsbHistoryView === "function") window.pruneAdsbHistoryView();
+  if (typeof window.pruneAcarsHistoryView === "function") window.pruneAcarsHistoryView();
 };
 
 function syncTopBarAccess() {
@@ -599,7 +619,7 @@ let decodeHistoryReplayActive = false;
 let decodeMapSyncPending = false;
 
 // --- Pending decode data buffers ---
-// Map-data plugins (ais.js, aprs.js, vdes.js, hf-aprs.js) are loaded eagerly
+// Map-data plugins (ais.js, aprs.js, vdes.js, adsb.js, hf-aprs.js) are loaded eagerly
 // but dynamically-inserted scripts have no guaranteed execution order.  If
 // decode history or live SSE messages arrive before the plugin handlers are
 // registered, buffer them here and let each plugin drain on init.
@@ -610,6 +630,7 @@ window._trxDrainPendingDecode = function(kind) {
   const historyKey = {
     ais: "restoreAisHistory",
     vdes: "restoreVdesHistory",
+    adsb: "restoreAdsbHistory",
     aprs: "restoreAprsHistory",
     hf_aprs: "restoreHfAprsHistory",
   }[kind];
@@ -621,6 +642,7 @@ window._trxDrainPendingDecode = function(kind) {
   const liveKey = {
     ais: "onServerAis",
     vdes: "onServerVdes",
+    adsb: "onServerAdsb",
     aprs: "onServerAprs",
     hf_aprs: "onServerHfAprs",
   }[kind];
@@ -783,6 +805,9 @@ window.lastSpectrumData = null;
 let lastControl;
 let lastTxEn = null;
 let lastHasTx = true;
+let lastMemoryChannels = 0;
+let lastMemoryWrite = false;
+let lastRepeaterCaps = null;
 let lastRendered = null;
 let prevRenderData = {};
 let hintTimer = null;
@@ -2024,7 +2049,7 @@ function resetDecoderStateOnRigSwitch() {
   lastSpectrumRenderData = null;
 
   // Decoder status indicators
-  const decoderIds = ["ais-status", "vdes-status", "aprs-status", "cw-status", "ft8-status", "wspr-status"];
+  const decoderIds = ["ais-status", "vdes-status", "aprs-status", "cw-status", "psk-status", "dsc-status", "pocsag-status", "adsb-status", "acars-status", "ft8-status", "wspr-status"];
   decoderIds.forEach((id) => {
     const el = document.getElementById(id);
     if (el) el.textContent = "--";
@@ -3408,7 +3433,7 @@ function render(update) {
     if (d.activation !== "toggle") continue;
     const key = d.id.replace(/-/g, "_") + "_decode_enabled";
     const enabled = !!update[key];
-    const modeMatch = d.active_modes.includes(modeUpper);
+    const modeMatch = d.active_modes.length === 0 || d.active_modes.includes(modeUpper);
     const el = document.getElementById(d.id + "-status");
     if (el && (!enabled || !modeMatch) && el.textContent === "Receiving") {
       el.textContent = "Connected, listening for packets";
@@ -3442,6 +3467,8 @@ function render(update) {
     window._syncRecorderState(update.recorder_enabled);
   }
   if (window.updateSatLiveState) window.updateSatLiveState(update);
+  if (window.applyCwKeyerState) window.applyCwKeyerState(update);
+  if (window.applyPskSettingsFromServer) window.applyPskSettingsFromServer(update);
   // cwAutoEl, cwWpmEl, cwToneEl are cached at module level
   if (cwWpmEl && typeof update.cw_wpm === "number") {
     cwWpmEl.value = update.cw_wpm;
@@ -3620,6 +3647,11 @@ function render(update) {
     syncAboutDecoder(4, !!update.cw_decode_enabled);
     syncAboutDecoder(5, !!(update.aprs_decode_enabled || update.hf_aprs_decode_enabled));
     syncAboutDecoder(6, !!update.lrpt_decode_enabled);
+    syncAboutDecoder(7, !!update.psk_decode_enabled);
+    syncAboutDecoder(8, !!update.dsc_decode_enabled);
+    syncAboutDecoder(9, !!update.pocsag_decode_enabled);
+    syncAboutDecoder(10, !!update.adsb_decode_enabled);
+    syncAboutDecoder(11, !!update.acars_decode_enabled);
 
     // About — Integrations card
     if (update.pskreporter_status && aboutPskreporterEl) {
@@ -3746,6 +3778,12 @@ function connect() {
   es.addEventListener("channels", evt => {
     if (typeof vchanHandleChannels === "function") vchanHandleChannels(evt.data);
   });
+  es.addEventListener("dxspots", evt => {
+    try { dxSpotsReplace(JSON.parse(evt.data)); } catch (_) {}
+  });
+  es.addEventListener("dxspot", evt => {
+    try { dxSpotUpsert(JSON.parse(evt.data)); } catch (_) {}
+  });
   es.onerror = () => {
     // Check if this is an auth error by looking at readyState
     if (es.readyState === EventSource.CLOSED) {
@@ -4442,10 +4480,11 @@ if (spectrumBwSweetBtn) {
 
 // --- Tab navigation ---
 let _activeTab = "main"; // tracked for render-path tab awareness
-const TAB_ORDER = ["main", "bookmarks", "digital-modes", "map", "statistics", "recorder", "settings", "about"];
+const TAB_ORDER = ["main", "bookmarks", "logbook", "digital-modes", "map", "statistics", "recorder", "settings", "about"];
 const TAB_PATHS = {
   main: "/",
   bookmarks: "/bookmarks",
+  logbook: "/logbook",
   "digital-modes": "/digital-modes",
   map: "/map",
   recorder: "/recorder",
@@ -6092,6 +6131,11 @@ function dispatchDecodeMessage(msg, skipStats) {
   if (msg.type === "aprs") { if (window.onServerAprs) window.onServerAprs(msg); else (_pendingDecodeLive.aprs = _pendingDecodeLive.aprs || []).push(msg); }
   if (msg.type === "hf_aprs") { if (window.onServerHfAprs) window.onServerHfAprs(msg); else (_pendingDecodeLive.hf_aprs = _pendingDecodeLive.hf_aprs || []).push(msg); }
   if (msg.type === "cw" && window.onServerCw) window.onServerCw(msg);
+  if (msg.type === "psk" && window.onServerPsk) window.onServerPsk(msg);
+  if (msg.type === "dsc" && window.onServerDsc) window.onServerDsc(msg);
+  if (msg.type === "pocsag" && window.onServerPocsag) window.onServerPocsag(msg);
+  if (msg.type === "acars" && window.onServerAcars) window.onServerAcars(msg);
+  if (msg.type === "adsb") { if (window.onServerAdsb) window.onServerAdsb(msg); else (_pendingDecodeLive.adsb = _pendingDecodeLive.adsb || []).push(msg); }
   if (msg.type === "ft8" && window.onServerFt8) window.onServerFt8(msg);
   if (msg.type === "ft4" && window.onServerFt4) window.onServerFt4(msg);
   if (msg.type === "ft2" && window.onServerFt2) window.onServerFt2(msg);
@@ -6213,6 +6257,11 @@ function restoreDecodeHistoryGroup(kind, messages) {
     else { _pendingDecodeHistory.vdes = (_pendingDecodeHistory.vdes || []).concat(messages); }
     return;
   }
+  if (kind === "adsb") {
+    if (window.restoreAdsbHistory) { window.restoreAdsbHistory(messages); }
+    else { _pendingDecodeHistory.adsb = (_pendingDecodeHistory.adsb || []).concat(messages); }
+    return;
+  }
   if (kind === "aprs") {
     if (window.restoreAprsHistory) { window.restoreAprsHistory(messages); }
     else { _pendingDecodeHistory.aprs = (_pendingDecodeHistory.aprs || []).concat(messages); }
@@ -6227,6 +6276,22 @@ function restoreDecodeHistoryGroup(kind, messages) {
     window.restoreCwHistory(messages);
     return;
   }
+  if (kind === "psk" && window.restorePskHistory) {
+    window.restorePskHistory(messages);
+    return;
+  }
+  if (kind === "dsc" && window.restoreDscHistory) {
+    window.restoreDscHistory(messages);
+    return;
+  }
+  if (kind === "pocsag" && window.restorePocsagHistory) {
+    window.restorePocsagHistory(messages);
+    return;
+  }
+  if (kind === "acars" && window.restoreAcarsHistory) {
+    window.restoreAcarsHistory(messages);
+    return;
+  }
   if (kind === "ft8" && window.restoreFt8History) {
     window.restoreFt8History(messages);
     return;
@@ -6261,6 +6326,11 @@ function connectDecode() {
   if (window.resetVdesHistoryView) window.resetVdesHistoryView();
   if (window.resetAprsHistoryView) window.resetAprsHistoryView();
   if (window.resetCwHistoryView) window.resetCwHistoryView();
+  if (window.resetPskHistoryView) window.resetPskHistoryView();
+  if (window.resetDscHistoryView) window.resetDscHistoryView();
+  if (window.resetPocsagHistoryView) window.resetPocsagHistoryView();
+  if (window.resetAdsbHistoryView) window.resetAdsbHistoryView();
+  if (window.resetAcarsHistoryView) window.resetAcarsHistoryView();
   if (window.resetFt8HistoryView) window.resetFt8HistoryView();
   if (window.resetFt4HistoryView) window.resetFt4HistoryView();
   if (window.resetWsprHistoryView) window.resetWsprHistoryView();
@@ -6334,7 +6404,7 @@ function connectDecode() {
 
   function totalDecodeHistoryMessages(groups) {
     if (!groups || typeof groups !== "object") return 0;
-    return ["ais", "vdes", "aprs", "hf_aprs", "cw", "ft8", "ft4", "ft2", "wspr"]
+    return ["ais", "vdes", "adsb", "aprs", "hf_aprs", "cw", "psk", "dsc", "pocsag", "acars", "ft8", "ft4", "ft2", "wspr"]
       .reduce((sum, key) => sum + (Array.isArray(groups[key]) ? groups[key].length : 0), 0);
   }
 
@@ -6345,7 +6415,7 @@ function connectDecode() {
       setDecodeHistoryReplayActive(true);
       updateHistoryReplayOverlay();
     }
-    for (const kind of ["ais", "vdes", "aprs", "hf_aprs", "cw", "ft8", "ft4", "ft2", "wspr"]) {
+    for (const kind of ["ais", "vdes", "adsb", "aprs", "hf_aprs", "cw", "psk", "dsc", "pocsag", "acars", "ft8", "ft4", "ft2", "wspr"]) {
       const messages = groups && Array.isArray(groups[kind]) ? groups[kind] : [];
       if (messages.length === 0) continue;
       for (let index = 0; index < messages.length; index += DECODE_HISTORY_WORKER_GROUP_LIMIT) {
@@ -7495,6 +7565,7 @@ function drawSpectrum(data) {
 
   updateSpectrumFreqAxis(range);
   updateBookmarkAxis(range);
+  updateDxSpotAxis(range);
   updateBandplanStrip(range);  // use precise spectrum range when available
   drawSignalOverlay();
 }
@@ -7850,6 +7921,107 @@ function updateBookmarkAxis(range) {
   });
 }
 
+// ── DX cluster spots ──────────────────────────────────────────────────────────
+// Mirrors the server spot table: same call within 1 kHz is one spot.
+const DX_SPOT_MERGE_HZ = 1000;
+const DX_SPOT_ROWS = 3;
+let dxSpotList = [];
+let dxSpotMaxAgeMs = 30 * 60 * 1000;
+let dxSpotRevision = 0;
+
+function dxSpotsReplace(payload) {
+  if (Number.isFinite(payload?.max_age_ms) && payload.max_age_ms > 0) {
+    dxSpotMaxAgeMs = payload.max_age_ms;
+  }
+  dxSpotList = Array.isArray(payload?.spots) ? payload.spots : [];
+  dxSpotRevision++;
+}
+
+function dxSpotUpsert(spot) {
+  if (!spot || !spot.call || !Number.isFinite(spot.freq_hz)) return;
+  dxSpotList = dxSpotList.filter((s) =>
+    s.call !== spot.call || Math.abs(s.freq_hz - spot.freq_hz) > DX_SPOT_MERGE_HZ);
+  dxSpotList.push(spot);
+  dxSpotRevision++;
+}
+
+function dxSpotPrune() {
+  const cutoff = Date.now() - dxSpotMaxAgeMs;
+  const kept = dxSpotList.filter((s) => s.ts_ms >= cutoff);
+  if (kept.length !== dxSpotList.length) {
+    dxSpotList = kept;
+    dxSpotRevision++;
+  }
+}
+
+function dxSpotTooltip(spot) {
+  const parts = [`${spot.call} — ${formatFreq(spot.freq_hz)}`];
+  const details = [spot.mode, Number.isFinite(spot.snr_db) ? `${spot.snr_db} dB` : null,
+    Number.isFinite(spot.wpm) ? `${spot.wpm} WPM` : null].filter(Boolean);
+  if (details.length) parts.push(details.join(" · "));
+  if (spot.comment) parts.push(spot.comment);
+  const ageMin = Math.max(0, Math.round((Date.now() - spot.ts_ms) / 60000));
+  const count = spot.count > 1 ? ` (${spot.count} reports)` : "";
+  parts.push(`de ${spot.spotter}, ${ageMin} min ago${count}`);
+  return parts.join("\n");
+}
+
+function createDxSpotChip(spot) {
+  const span = document.createElement("span");
+  span.className = "spectrum-dxspot-chip";
+  span.textContent = spot.call;
+  span.title = dxSpotTooltip(spot);
+  span.addEventListener("click", (e) => {
+    e.stopPropagation();
+    postPath(`/set_freq?hz=${Math.round(spot.freq_hz)}`).catch((err) => {
+      console.warn("DX spot tune failed", err);
+    });
+  });
+  return span;
+}
+
+function updateDxSpotAxis(range) {
+  const axisEl = document.getElementById("spectrum-dxspot-axis");
+  if (!axisEl) return;
+  dxSpotPrune();
+  const visSpots = dxSpotList
+    .filter((s) => s.freq_hz >= range.visLoHz && s.freq_hz <= range.visHiHz)
+    .sort((a, b) => a.freq_hz - b.freq_hz);
+  if (visSpots.length === 0) {
+    if (axisEl.dataset.dxKey) { axisEl.replaceChildren(); axisEl.dataset.dxKey = ""; }
+    return;
+  }
+
+  const newKey = `${dxSpotRevision}:${visSpots.map((s) => `${s.call}@${s.freq_hz}`).join(",")}`;
+  if (axisEl.dataset.dxKey !== newKey) {
+    axisEl.dataset.dxKey = newKey;
+    axisEl.replaceChildren();
+    for (const spot of visSpots) axisEl.appendChild(createDxSpotChip(spot));
+  }
+
+  // Stagger overlapping labels over a few rows, left to right.
+  const axisWidth = axisEl.clientWidth || 0;
+  const chips = axisEl.querySelectorAll(":scope > span");
+  const widths = [];
+  for (let i = 0; i < chips.length; i++) widths.push(chips[i].offsetWidth || 0);
+  const rowEnds = new Array(DX_SPOT_ROWS).fill(-Infinity);
+  visSpots.forEach((spot, i) => {
+    const chip = chips[i];
+    if (!chip) return;
+    const frac = (spot.freq_hz - range.visLoHz) / range.visSpanHz;
+    if (axisWidth <= 0) {
+      chip.style.left = (frac * 100).toFixed(2) + "%";
+      return;
+    }
+    const w = widths[i];
+    const left = Math.max(0, Math.min(axisWidth - w, frac * axisWidth - w / 2));
+    let row = rowEnds.findIndex((end) => end + 2 <= left);
+    if (row < 0) row = rowEnds.indexOf(Math.min(...rowEnds));
+    rowEnds[row] = left + w;
+    chip.style.transform = `translate(${left}px, ${row * 16}px)`;
+  });
+}
+
 function updateSpectrumFreqAxis(range) {
   if (!spectrumFreqAxis) return;
   const spanHz = range.visSpanHz;
@@ -1,5 +1,5 @@
 const textDecoder = typeof TextDecoder === "function" ? new TextDecoder() : null;
-const HISTORY_GROUP_KEYS = ["ais", "vdes", "aprs", "hf_aprs", "cw", "ft8", "ft4", "ft2", "wspr", "wefax"];
+const HISTORY_GROUP_KEYS = ["ais", "vdes", "aprs", "hf_aprs", "cw", "psk", "dsc", "pocsag", "adsb", "acars", "ft8", "ft4", "ft2", "wspr", "wefax"];
 
 function decodeCborUint(view, bytes, state, additional) {
   const offset = state.offset;
@@ -51,7 +51,7 @@
   const decodeContactPaths = new Map();
   let selectedMapQsoKey = null;
   const mapMarkers = new Set();
-  const DEFAULT_MAP_SOURCE_FILTER = { ais: true, vdes: true, aprs: true, bookmark: false, ft8: true, ft4: true, ft2: true, wspr: true, sat: false };
+  const DEFAULT_MAP_SOURCE_FILTER = { ais: true, vdes: true, adsb: true, aprs: true, bookmark: false, ft8: true, ft4: true, ft2: true, wspr: true, sat: false };
   const mapFilter = { ...DEFAULT_MAP_SOURCE_FILTER };
   const mapLocatorFilter = { phase: "band", bands: new Set() };
   let mapSearchFilter = "";
@@ -64,6 +64,7 @@
   const AIS_TRACK_MAX_POINTS = 64;
   const aisMarkers = new Map();
   const vdesMarkers = new Map();
+  const adsbMarkers = new Map();
   let selectedAprsTrackCall = null;
   let selectedAisTrackMmsi = null;
   const HAM_BANDS = [
@@ -230,6 +231,30 @@
     mapMarkers.add(marker);
   }
 
+  function adsbIcon(msg) {
+    const track = Number.isFinite(msg?.track_deg) ? Number(msg.track_deg) : 0;
+    const groundClass = msg?.on_ground ? " adsb-marker-ground" : "";
+    return L.divIcon({
+      className: `adsb-marker${groundClass}`,
+      html: `<svg viewBox="0 0 20 20" width="20" height="20" style="transform:rotate(${track.toFixed(0)}deg)">` +
+        `<path d="M10 1 L13 12 L10 10 L7 12 Z M4 11 L16 11 L10 7 Z" /></svg>`,
+      iconSize: [20, 20],
+      iconAnchor: [10, 10],
+    });
+  }
+
+  function ensureAdsbMarker(key, entry) {
+    if (!aprsMap || !entry || entry.marker || entry?.msg?.lat == null || entry?.msg?.lon == null) return;
+    const marker = L.marker([entry.msg.lat, entry.msg.lon], { icon: adsbIcon(entry.msg) })
+      .addTo(aprsMap)
+      .bindPopup(buildAdsbPopupHtml(entry.msg));
+    marker.__trxType = "adsb";
+    marker.__trxRigIds = entry.rigIds || new Set();
+    marker._adsbKey = String(key);
+    entry.marker = marker;
+    mapMarkers.add(marker);
+  }
+
   function ensureDecodeLocatorMarker(entry) {
     if (!aprsMap || !entry || entry.marker || !entry.grid || (entry.sourceType !== "ft8" && entry.sourceType !== "ft4" && entry.sourceType !== "ft2" && entry.sourceType !== "wspr")) return;
     const bounds = maidenheadToBounds(entry.grid);
@@ -384,6 +409,22 @@
         entry.marker.setPopupContent(buildVdesPopupHtml(entry.msg));
       }
     }
+    for (const [key, entry] of adsbMarkers.entries()) {
+      const tsMs = Number(entry?.msg?._tsMs);
+      const visible = Number.isFinite(tsMs) && tsMs >= cutoffMs;
+      entry.visibleInHistoryWindow = visible;
+      if (!visible) {
+        setRetainedMapMarkerVisible(entry?.marker, false);
+        continue;
+      }
+      ensureAdsbMarker(key, entry);
+      setRetainedMapMarkerVisible(entry?.marker, true);
+      if (entry?.marker) {
+        entry.marker.setLatLng([entry.msg.lat, entry.msg.lon]);
+        entry.marker.setIcon(adsbIcon(entry.msg));
+        entry.marker.setPopupContent(buildAdsbPopupHtml(entry.msg));
+      }
+    }
     for (const [key, entry] of locatorMarkers.entries()) {
       pruneLocatorEntry(key, entry, cutoffMs);
     }
@@ -423,6 +464,7 @@
   function mapSourceColor(type) {
     if (type === "ais") return "#38bdf8";
     if (type === "vdes") return "#a78bfa";
+    if (type === "adsb") return "#f472b6";
     if (type === "sat") return "#f59e0b";
     if (type === "aprs") return "#00d17f";
     return locatorFilterColor(type);
@@ -1013,6 +1055,12 @@
         break;
       }
     }
+    for (const entry of adsbMarkers.values()) {
+      if (entry?.visibleInHistoryWindow) {
+        availableSources.add("adsb");
+        break;
+      }
+    }
     for (const entry of stationMarkers.values()) {
       if (entry?.type === "aprs" && entry?.visibleInHistoryWindow) {
         availableSources.add("aprs");
@@ -1051,7 +1099,7 @@
       if (!bandMap.has(key)) mapLocatorFilter.bands.delete(key);
     }
 
-    const sourceItems = ["ais", "vdes", "aprs", "bookmark", "ft8", "ft4", "ft2", "wspr"]
+    const sourceItems = ["ais", "vdes", "adsb", "aprs", "bookmark", "ft8", "ft4", "ft2", "wspr"]
       .filter((key) => availableSources.has(key))
       .map((key) => ({
         key,
@@ -1154,6 +1202,15 @@
         Number.isFinite(msg?.lon) ? String(msg.lon) : "",
       ].join(" ").toLowerCase();
     }
+    if (type === "adsb") {
+      const key = marker?._adsbKey ? String(marker._adsbKey) : "";
+      const msg = adsbMarkers.get(key)?.msg;
+      return [
+        key,
+        msg?.callsign,
+        Number.isFinite(msg?.squawk) ? String(msg.squawk).padStart(4, "0") : "",
+      ].join(" ").toLowerCase();
+    }
     return "";
   }
 
@@ -1365,6 +1422,17 @@
       return;
     }
 
+    if (type === "adsb") {
+      adsbMarkers.forEach((entry) => {
+        if (entry && entry.marker) {
+          if (aprsMap && aprsMap.hasLayer(entry.marker)) entry.marker.removeFrom(aprsMap);
+          mapMarkers.delete(entry.marker);
+        }
+      });
+      adsbMarkers.clear();
+      return;
+    }
+
     if (type === "sat") {
       window.clearSatMapOverlays();
       return;
@@ -1618,6 +1686,15 @@
         return;
       }
 
+      if (marker._adsbKey) {
+        if (!ll) return;
+        const entry = adsbMarkers.get(String(marker._adsbKey));
+        if (!entry || !entry.msg) return;
+        e.popup.setContent(buildAdsbPopupHtml(entry.msg));
+        setMapRadioPathTo(ll.lat, ll.lng, mapSourceColor("adsb"), "aprs-radio-path", marker.__trxRigIds);
+        return;
+      }
+
       if (marker.__trxType === "ft8" || marker.__trxType === "ft4" || marker.__trxType === "ft2" || marker.__trxType === "wspr") {
         const center = locatorMarkerCenter(marker);
         if (center) {
@@ -2059,6 +2136,42 @@
       `</div>`;
   }
 
+  function adsbIcaoHex(icao) {
+    return Number(icao || 0).toString(16).toUpperCase().padStart(6, "0");
+  }
+
+  function buildAdsbPopupHtml(msg) {
+    const age = formatTimeAgo(msg?.ts_ms);
+    const distKm = (T.serverLat != null && T.serverLon != null && msg?.lat != null && msg?.lon != null)
+      ? haversineKm(T.serverLat, T.serverLon, msg.lat, msg.lon)
+      : null;
+    const distStr = distKm != null
+      ? (distKm < 1 ? `${Math.round(distKm * 1000)} m` : `${distKm.toFixed(1)} km`)
+      : null;
+    const icao = adsbIcaoHex(msg?.icao);
+    const meta = [
+      age,
+      distStr,
+      msg?.on_ground ? "on ground" : null,
+    ].filter(Boolean).join(" &middot; ");
+    let rows = "";
+    rows += `<tr><td class="aprs-popup-label">ICAO</td><td>${icao}</td></tr>`;
+    if (distStr) rows += `<tr><td class="aprs-popup-label">Range</td><td>${distStr} from TRX</td></tr>`;
+    if (Number.isFinite(msg?.altitude_ft)) rows += `<tr><td class="aprs-popup-label">Altitude</td><td>${Number(msg.altitude_ft).toLocaleString()} ft</td></tr>`;
+    if (Number.isFinite(msg?.ground_speed_kt)) rows += `<tr><td class="aprs-popup-label">Speed</td><td>${Number(msg.ground_speed_kt).toFixed(0)} kt</td></tr>`;
+    if (Number.isFinite(msg?.track_deg)) rows += `<tr><td class="aprs-popup-label">Track</td><td>${Number(msg.track_deg).toFixed(0)}&deg;</td></tr>`;
+    if (Number.isFinite(msg?.vertical_rate_fpm)) rows += `<tr><td class="aprs-popup-label">V/S</td><td>${Number(msg.vertical_rate_fpm)} fpm</td></tr>`;
+    if (Number.isFinite(msg?.squawk)) rows += `<tr><td class="aprs-popup-label">Squawk</td><td>${String(msg.squawk).padStart(4, "0")}</td></tr>`;
+    if (msg?.lat != null && msg?.lon != null) rows += `<tr><td class="aprs-popup-label">Pos</td><td>${msg.lat.toFixed(5)}, ${msg.lon.toFixed(5)}</td></tr>`;
+    if (Number.isFinite(msg?.rssi_db)) rows += `<tr><td class="aprs-popup-label">RSSI</td><td>${Number(msg.rssi_db).toFixed(1)} dBFS</td></tr>`;
+    const title = escapeMapHtml(msg?.callsign || icao);
+    return `<div class="aprs-popup">` +
+      `<div class="aprs-popup-call">${title}</div>` +
+      (meta ? `<div class="aprs-popup-meta">${meta}</div>` : "") +
+      `<table class="aprs-popup-table">${rows}</table>` +
+      `</div>`;
+  }
+
   function aprsPositionsEqual(a, b) {
     if (!a || !b) return false;
     const aLat = Array.isArray(a) ? a[0] : a.lat;
@@ -2955,6 +3068,62 @@
     listEl.replaceChildren(fragment);
   }
 
+  window.adsbMapAddAircraft = function(msg) {
+    if (msg == null || msg.lat == null || msg.lon == null) return;
+    const key = adsbIcaoHex(msg.icao);
+    const popupHtml = buildAdsbPopupHtml(msg);
+    const visible = Number.isFinite(Number(msg?._tsMs))
+      && Number(msg._tsMs) >= mapHistoryCutoffMs();
+    const msgRigId = msg?.rig_id || T.lastActiveRigId;
+    const existing = adsbMarkers.get(key);
+    if (existing) {
+      existing.msg = msg;
+      existing.visibleInHistoryWindow = visible;
+      if (msgRigId) {
+        if (!existing.rigIds) existing.rigIds = new Set();
+        existing.rigIds.add(msgRigId);
+      }
+      if (!visible) {
+        if (!T.decodeHistoryMapRenderingDeferred()) {
+          setRetainedMapMarkerVisible(existing.marker, false);
+        } else {
+          T.markDecodeMapSyncPending();
+        }
+        return;
+      }
+      if (!T.decodeHistoryMapRenderingDeferred()) {
+        ensureAdsbMarker(key, existing);
+        setRetainedMapMarkerVisible(existing.marker, true);
+      } else {
+        T.markDecodeMapSyncPending();
+      }
+      if (aprsMap && existing.marker && !T.decodeHistoryReplayActive) {
+        existing.marker.setLatLng([msg.lat, msg.lon]);
+        existing.marker.setIcon(adsbIcon(msg));
+        existing.marker.setPopupContent(popupHtml);
+      }
+      return;
+    }
+    const entry = {
+      marker: null,
+      msg,
+      visibleInHistoryWindow: visible,
+      rigIds: new Set(msgRigId ? [msgRigId] : []),
+    };
+    adsbMarkers.set(key, entry);
+    if (!visible) return;
+    if (!T.decodeHistoryMapRenderingDeferred()) {
+      ensureAdsbMarker(key, entry);
+      setRetainedMapMarkerVisible(entry.marker, true);
+    } else {
+      T.markDecodeMapSyncPending();
+    }
+    if (aprsMap && entry.marker && !T.decodeHistoryReplayActive) {
+      entry.marker.setPopupContent(popupHtml);
+    }
+    scheduleDecodeMapMaintenance();
+  };
+
   // ── Statistics panel ─────────────────────────────────────────────────
   let statsRigFilter = "";
   let statsHistoryLimitMinutes = 1440;
@@ -2962,7 +3131,7 @@
   const STATS_LOG_MAX = 50000;
   const STATS_TYPE_COLORS = {
     ft8: "#4fc3f7", ft4: "#81c784", ft2: "#aed581", wspr: "#ffb74d",
-    aprs: "#ce93d8", hf_aprs: "#ba68c8", ais: "#90a4ae", vdes: "#78909c",
+    aprs: "#ce93d8", hf_aprs: "#ba68c8", ais: "#90a4ae", vdes: "#78909c", adsb: "#f48fb1",
     cw: "#fff176",
   };
   const STATS_DX_BUCKETS = [
@@ -3456,15 +3625,18 @@
     get locatorMarkers() { return locatorMarkers; },
     get aisMarkers() { return aisMarkers; },
     get vdesMarkers() { return vdesMarkers; },
+    get adsbMarkers() { return adsbMarkers; },
     get decodeContactPaths() { return decodeContactPaths; },
     pruneMapHistory,
     aprsSymbolIcon,
     buildAprsPopupHtml,
     buildAisPopupHtml,
     buildVdesPopupHtml,
+    buildAdsbPopupHtml,
     ensureAprsMarker,
     ensureAisMarker,
     ensureVdesMarker,
+    ensureAdsbMarker,
     ensureDecodeLocatorMarker,
     aprsPositionsEqual,
     aisPositionsEqual,
@@ -0,0 +1,137 @@
+// --- ACARS Decoder Plugin (server-side decode) ---
+const acarsStatusEl = document.getElementById("acars-status");
+const acarsMessagesEl = document.getElementById("acars-messages");
+const acarsFilterInput = document.getElementById("acars-filter");
+const ACARS_MAX_MESSAGES = 1000;
+let acarsMessageHistory = [];
+let acarsFilterText = "";
+
+function currentAcarsHistoryRetentionMs() {
+  return typeof window.getDecodeHistoryRetentionMs === "function"
+    ? window.getDecodeHistoryRetentionMs()
+    : 24 * 60 * 60 * 1000;
+}
+
+function pruneAcarsMessageHistory() {
+  const cutoffMs = Date.now() - currentAcarsHistoryRetentionMs();
+  acarsMessageHistory = acarsMessageHistory.filter((msg) => Number(msg._tsMs) >= cutoffMs);
+}
+
+function acarsSpan(className, text) {
+  const span = document.createElement("span");
+  span.className = className;
+  span.textContent = text;
+  return span;
+}
+
+function acarsFreqText(freqHz) {
+  return Number.isFinite(freqHz) ? `${(freqHz / 1e6).toFixed(3)}` : "";
+}
+
+function renderAcarsRow(msg) {
+  const row = document.createElement("div");
+  row.className = "acars-line";
+  const head = document.createElement("div");
+  head.className = "acars-head";
+  head.append(
+    acarsSpan("acars-meta", `${msg._ts} ${acarsFreqText(msg.freq_hz)}`.trim()),
+    acarsSpan("acars-reg", msg.registration || "-"),
+  );
+  if (msg.flight) head.appendChild(acarsSpan("acars-flight", msg.flight));
+  head.append(
+    acarsSpan("acars-label", `${msg.label} ${msg.block_id}`),
+    acarsSpan("acars-meta", msg.downlink ? "DL" : "UL"),
+  );
+  if (msg.more) head.appendChild(acarsSpan("acars-meta", "cont."));
+  if (msg.corrected_bits > 0) {
+    head.appendChild(acarsSpan("acars-fec", `${msg.corrected_bits} bit${msg.corrected_bits === 1 ? "" : "s"} fixed`));
+  }
+  row.appendChild(head);
+  if (msg.text) row.appendChild(acarsSpan("acars-text", msg.text));
+  return row;
+}
+
+function acarsMatchesFilter(msg) {
+  if (!acarsFilterText) return true;
+  return `${msg.registration || ""} ${msg.flight || ""} ${msg.label} ${msg.text || ""}`
+    .toUpperCase()
+    .includes(acarsFilterText);
+}
+
+function renderAcarsHistory() {
+  pruneAcarsMessageHistory();
+  if (!acarsMessagesEl) return;
+  const fragment = document.createDocumentFragment();
+  for (const msg of acarsMessageHistory) {
+    if (acarsMatchesFilter(msg)) fragment.appendChild(renderAcarsRow(msg));
+  }
+  acarsMessagesEl.replaceChildren(fragment);
+}
+
+function scheduleAcarsRender() {
+  if (typeof window.trxScheduleUiFrameJob === "function") {
+    window.trxScheduleUiFrameJob("acars-history", () => renderAcarsHistory());
+    return;
+  }
+  renderAcarsHistory();
+}
+
+function addAcarsMessage(msg) {
+  const tsMs = Number.isFinite(msg.ts_ms) ? Number(msg.ts_ms) : Date.now();
+  acarsMessageHistory.unshift({
+    ...msg,
+    _tsMs: tsMs,
+    _ts: new Date(tsMs).toLocaleTimeString([], { hour: "2-digit", minute: "2-digit", second: "2-digit" }),
+  });
+  if (acarsMessageHistory.length > ACARS_MAX_MESSAGES) {
+    acarsMessageHistory.length = ACARS_MAX_MESSAGES;
+  }
+}
+
+if (acarsFilterInput) {
+  acarsFilterInput.addEventListener("input", () => {
+    acarsFilterText = acarsFilterInput.value.trim().toUpperCase();
+    renderAcarsHistory();
+  });
+}
+
+document.getElementById("acars-decode-toggle-btn")?.addEventListener("click", async () => {
+  try {
+    await postPath("/toggle_acars_decode");
+  } catch (e) {
+    console.error("ACARS toggle failed", e);
+  }
+});
+
+window.resetAcarsHistoryView = function() {
+  acarsMessageHistory = [];
+  renderAcarsHistory();
+};
+
+window.pruneAcarsHistoryView = function() {
+  renderAcarsHistory();
+};
+
+document.getElementById("settings-clear-acars-history")?.addEventListener("click", async () => {
+  if (!confirm("Clear all ACARS decode history? This cannot be undone.")) return;
+  try {
+    await postPath("/clear_acars_decode");
+    window.resetAcarsHistoryView();
+  } catch (e) {
+    console.error("ACARS history clear failed", e);
+  }
+});
+
+window.onServerAcars = function(msg) {
+  if (acarsStatusEl) acarsStatusEl.textContent = "Receiving";
+  addAcarsMessage(msg);
+  scheduleAcarsRender();
+};
+
+window.restoreAcarsHistory = function(messages) {
+  if (!Array.isArray(messages) || messages.length === 0) return;
+  if (acarsStatusEl) acarsStatusEl.textContent = "Receiving";
+  for (const msg of messages) addAcarsMessage(msg);
+  acarsMessageHistory.sort((a, b) => b._tsMs - a._tsMs);
+  scheduleAcarsRender();
+};
@@ -0,0 +1,181 @@
+// --- ADS-B / Mode S Decoder Plugin (server-side decode) ---
+const adsbStatusEl = document.getElementById("adsb-status");
+const adsbBodyEl = document.getElementById("adsb-aircraft-body");
+const adsbFilterInput = document.getElementById("adsb-filter");
+const adsbAircraftCountEl = document.getElementById("adsb-aircraft-count");
+const adsbPositionedCountEl = document.getElementById("adsb-positioned-count");
+const adsbLatestSeenEl = document.getElementById("adsb-latest-seen");
+// Aircraft drop out of the table once they have been silent this long.
+const ADSB_STALE_MS = 5 * 60 * 1000;
+let adsbAircraft = new Map();
+let adsbFilterText = "";
+
+function currentAdsbHistoryRetentionMs() {
+  const retentionMs = typeof window.getDecodeHistoryRetentionMs === "function"
+    ? window.getDecodeHistoryRetentionMs()
+    : 24 * 60 * 60 * 1000;
+  return Math.min(retentionMs, ADSB_STALE_MS);
+}
+
+function pruneAdsbAircraft() {
+  const cutoffMs = Date.now() - currentAdsbHistoryRetentionMs();
+  for (const [key, msg] of adsbAircraft) {
+    if (!(Number(msg._tsMs) >= cutoffMs)) adsbAircraft.delete(key);
+  }
+}
+
+function adsbIcaoHex(icao) {
+  return Number(icao || 0).toString(16).toUpperCase().padStart(6, "0");
+}
+
+function adsbAgeText(tsMs) {
+  if (!Number.isFinite(tsMs)) return "just now";
+  const seconds = Math.round(Math.max(0, Date.now() - tsMs) / 1000);
+  if (seconds < 5) return "just now";
+  if (seconds < 60) return `${seconds}s ago`;
+  return `${Math.round(seconds / 60)}m ago`;
+}
+
+function adsbCell(text, className) {
+  const td = document.createElement("td");
+  if (className) td.className = className;
+  td.textContent = text;
+  return td;
+}
+
+function adsbNumber(value, digits = 0) {
+  return Number.isFinite(value) ? Number(value).toFixed(digits) : "";
+}
+
+function renderAdsbRow(msg) {
+  const row = document.createElement("tr");
+  if (msg.on_ground) row.classList.add("adsb-row-ground");
+  const squawk = Number.isFinite(msg.squawk) ? String(msg.squawk).padStart(4, "0") : "";
+  const squawkCell = adsbCell(squawk, "adsb-squawk");
+  if (squawk === "7500" || squawk === "7600" || squawk === "7700") {
+    squawkCell.classList.add("adsb-squawk-emergency");
+  }
+  const position = msg.lat != null && msg.lon != null
+    ? `${msg.lat.toFixed(3)}, ${msg.lon.toFixed(3)}`
+    : "";
+  row.append(
+    adsbCell(msg._icao, "adsb-icao"),
+    adsbCell(msg.callsign || ""),
+    squawkCell,
+    adsbCell(msg.on_ground ? "GND" : adsbNumber(msg.altitude_ft)),
+    adsbCell(adsbNumber(msg.ground_speed_kt)),
+    adsbCell(adsbNumber(msg.track_deg)),
+    adsbCell(adsbNumber(msg.vertical_rate_fpm)),
+    adsbCell(position),
+    adsbCell(String(msg.messages ?? "")),
+    adsbCell(adsbNumber(msg.rssi_db, 1)),
+    adsbCell(adsbAgeText(msg._tsMs)),
+  );
+  return row;
+}
+
+function adsbMatchesFilter(msg) {
+  if (!adsbFilterText) return true;
+  const squawk = Number.isFinite(msg.squawk) ? String(msg.squawk).padStart(4, "0") : "";
+  return `${msg._icao} ${msg.callsign || ""} ${squawk}`.toUpperCase().includes(adsbFilterText);
+}
+
+function updateAdsbSummary(aircraft) {
+  if (adsbAircraftCountEl) {
+    adsbAircraftCountEl.textContent = `${aircraft.length} aircraft`;
+  }
+  if (adsbPositionedCountEl) {
+    const positioned = aircraft.filter((msg) => msg.lat != null && msg.lon != null).length;
+    adsbPositionedCountEl.textContent = `${positioned} on map`;
+  }
+  if (adsbLatestSeenEl) {
+    adsbLatestSeenEl.textContent = aircraft.length ? adsbAgeText(aircraft[0]._tsMs) : "No traffic yet";
+  }
+}
+
+function renderAdsbTable() {
+  pruneAdsbAircraft();
+  const aircraft = Array.from(adsbAircraft.values()).sort((a, b) => b._tsMs - a._tsMs);
+  updateAdsbSummary(aircraft);
+  if (!adsbBodyEl) return;
+  const fragment = document.createDocumentFragment();
+  for (const msg of aircraft) {
+    if (adsbMatchesFilter(msg)) fragment.appendChild(renderAdsbRow(msg));
+  }
+  adsbBodyEl.replaceChildren(fragment);
+}
+
+function scheduleAdsbRender() {
+  if (typeof window.trxScheduleUiFrameJob === "function") {
+    window.trxScheduleUiFrameJob("adsb-table", () => renderAdsbTable());
+    return;
+  }
+  renderAdsbTable();
+}
+
+function addAdsbAircraft(msg) {
+  const tsMs = Number.isFinite(msg.ts_ms) ? Number(msg.ts_ms) : Date.now();
+  const next = { ...msg, _tsMs: tsMs, _icao: adsbIcaoHex(msg.icao) };
+  const existing = adsbAircraft.get(next._icao);
+  if (existing && existing._tsMs > tsMs) return;
+  adsbAircraft.set(next._icao, next);
+  if (next.lat != null && next.lon != null && window.adsbMapAddAircraft) {
+    window.adsbMapAddAircraft(next);
+  }
+}
+
+if (adsbFilterInput) {
+  adsbFilterInput.addEventListener("input", () => {
+    adsbFilterText = adsbFilterInput.value.trim().toUpperCase();
+    renderAdsbTable();
+  });
+}
+
+document.getElementById("adsb-decode-toggle-btn")?.addEventListener("click", async () => {
+  try {
+    await postPath("/toggle_adsb_decode");
+  } catch (e) {
+    console.error("ADS-B toggle failed", e);
+  }
+});
+
+window.resetAdsbHistoryView = function() {
+  adsbAircraft = new Map();
+  if (window.clearMapMarkersByType) window.clearMapMarkersByType("adsb");
+  renderAdsbTable();
+};
+
+window.pruneAdsbHistoryView = function() {
+  renderAdsbTable();
+};
+
+document.getElementById("settings-clear-adsb-history")?.addEventListener("click", async () => {
+  if (!confirm("Clear all ADS-B decode history? This cannot be undone.")) return;
+  try {
+    await postPath("/clear_adsb_decode");
+    window.resetAdsbHistoryView();
+  } catch (e) {
+    console.error("ADS-B history clear failed", e);
+  }
+});
+
+window.onServerAdsb = function(msg) {
+  if (adsbStatusEl) adsbStatusEl.textContent = "Receiving";
+  addAdsbAircraft(msg);
+  scheduleAdsbRender();
+};
+
+window.restoreAdsbHistory = function(messages) {
+  if (!Array.isArray(messages) || messages.length === 0) return;
+  if (adsbStatusEl) adsbStatusEl.textContent = "Receiving";
+  for (const msg of messages) addAdsbAircraft(msg);
+  scheduleAdsbRender();
+};
+
+// Keep the "seen" column ticking while the tab is open.
+setInterval(() => {
+  if (adsbAircraft.size > 0) scheduleAdsbRender();
+}, 5000);
+
+renderAdsbTable();
+if (window._trxDrainPendingDecode) window._trxDrainPendingDecode("adsb");
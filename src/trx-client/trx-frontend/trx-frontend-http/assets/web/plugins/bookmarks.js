@@ -49,6 +49,11 @@ function bmSyncAccess() {
   const selectAllBtn = document.getElementById("bm-select-all-btn");
   if (addBtn) addBtn.style.display = canCtrl ? "" : "none";
   if (selectAllBtn) selectAllBtn.style.display = canCtrl ? "" : "none";
+  const importBtn = document.getElementById("bm-import-mem-btn");
+  const hasMemories = typeof lastMemoryChannels !== "undefined" && lastMemoryChannels > 0;
+  if (importBtn) importBtn.style.display = canCtrl && hasMemories ? "" : "none";
+  const importRptBtn = document.getElementById("bm-import-rpt-btn");
+  if (importRptBtn) importRptBtn.style.display = canCtrl ? "" : "none";
 }
 
 /** The listing scope: always the active rig (to merge general + rig bookmarks). */
@@ -276,6 +281,9 @@ function bmOpenForm(bm) {
   document.getElementById("bm-mode").value = bm ? bm.mode : "";
   document.getElementById("bm-bw").value = bm && bm.bandwidth_hz ? bm.bandwidth_hz : "";
   document.getElementById("bm-locator").value = bm ? (bm.locator || "") : "";
+  document.getElementById("bm-ctcss").value = bm && bm.ctcss_hz ? bm.ctcss_hz : "";
+  document.getElementById("bm-dcs").value = bm && bm.dcs_code ? String(bm.dcs_code).padStart(3, "0") : "";
+  document.getElementById("bm-offset").value = bm && bm.offset_hz ? bm.offset_hz : "";
   document.getElementById("bm-category-input").value = bm ? (bm.category || "") : "";
   document.getElementById("bm-comment").value = bm ? (bm.comment || "") : "";
   bmWriteDecoders(bm ? bm.decoders : []);
@@ -323,6 +331,12 @@ async function bmSave(e) {
   const bwStr = document.getElementById("bm-bw").value;
   const bandwidth_hz = bwStr ? parseInt(bwStr, 10) : null;
   const locator = document.getElementById("bm-locator").value.trim().toUpperCase();
+  const ctcssStr = document.getElementById("bm-ctcss").value;
+  const ctcss_hz = ctcssStr ? parseFloat(ctcssStr) : null;
+  const dcsStr = document.getElementById("bm-dcs").value;
+  const dcs_code = dcsStr ? parseInt(dcsStr, 10) : null;
+  const offsetStr = document.getElementById("bm-offset").value;
+  const offset_hz = offsetStr ? parseInt(offsetStr, 10) : null;
   const category = document.getElementById("bm-category-input").value.trim();
   const comment = document.getElementById("bm-comment").value.trim();
   const decoders = bmReadDecoders();
@@ -338,6 +352,9 @@ async function bmSave(e) {
     mode,
     bandwidth_hz,
     locator: locator || null,
+    ctcss_hz,
+    dcs_code,
+    offset_hz,
     category,
     comment,
     decoders,
@@ -447,6 +464,7 @@ async function bmApply(bm) {
       } else {
         await postPath("/set_freq?hz=" + bm.freq_hz);
       }
+      await bmApplyRepeater(bm);
     })();
     // Decoder toggles — fire-and-forget.
     //  - Decoders incompatible with the new mode are always turned off
@@ -512,6 +530,11 @@ function bmUpdateSelectionUi() {
   if (moveWrap) moveWrap.style.display = visible ? "" : "none";
   if (moveCountEl) moveCountEl.textContent = count;
   if (visible) bmPopulateMoveTarget();
+  const exportBtn = document.getElementById("bm-export-mem-btn");
+  const exportCountEl = document.getElementById("bm-export-mem-count");
+  const canWrite = typeof lastMemoryWrite !== "undefined" && lastMemoryWrite;
+  if (exportBtn) exportBtn.style.display = visible && canWrite ? "" : "none";
+  if (exportCountEl) exportCountEl.textContent = count;
   const selectAllBtn = document.getElementById("bm-select-all-btn");
   if (selectAllBtn && bmCanControl()) {
     const allSelected = bmFilteredList.length > 0 && bmFilteredList.every((bm) => bmSelected.has(bm.id));
@@ -619,6 +642,116 @@ async function bmDeleteSelected() {
   }
 }
 
+/**
+ * Set the repeater shift and tone of an FM bookmark.  Bookmarks without
+ * them return the rig to simplex with no tone, so tuning away from a
+ * repeater does not leave the shift behind.  Rigs that lack a setting
+ * reject it; that is not worth failing the tune for.
+ */
+async function bmApplyRepeater(bm) {
+  if (String(bm.mode || "").toUpperCase() !== "FM") return;
+  const caps = typeof lastRepeaterCaps !== "undefined" ? lastRepeaterCaps : null;
+  if (!caps || !(caps.rpt || caps.ctcss_decode)) return;
+  const quiet = (path) => postPath(path).catch(() => {});
+  await quiet("/set_repeater_offset?offset_hz=" + (bm.offset_hz || 0));
+  if (bm.dcs_code) {
+    await quiet("/set_dcs?code=" + bm.dcs_code);
+  } else if (bm.ctcss_hz) {
+    await quiet("/set_ctcss?tone_hz=" + bm.ctcss_hz);
+  } else {
+    await quiet("/set_ctcss");
+    if (caps.dcs) await quiet("/set_dcs");
+  }
+}
+
+/** Add repeaters from a CHIRP or RepeaterBook CSV file to the current scope. */
+async function bmImportRepeaters(file) {
+  const btn = document.getElementById("bm-import-rpt-btn");
+  if (btn) btn.disabled = true;
+  try {
+    const resp = await fetch("/bookmarks/import_repeaters" + bmScopeParam(false), {
+      method: "POST",
+      headers: { "Content-Type": "text/csv" },
+      body: await file.text(),
+    });
+    if (!resp.ok) throw new Error((await resp.text()) || "HTTP " + resp.status);
+    const result = await resp.json();
+    await bmFetch(document.getElementById("bm-category-filter").value);
+    alert(`Imported ${result.imported} repeater${result.imported === 1 ? "" : "s"}` +
+      (result.skipped ? `, skipped ${result.skipped} (digital, unreadable or already bookmarked).` : "."));
+  } catch (err) {
+    console.error("Failed to import repeaters:", err);
+    alert("Failed to import repeaters: " + err.message);
+  } finally {
+    if (btn) btn.disabled = false;
+  }
+}
+
+/** Copy the active rig's memory channels into the current scope. */
+async function bmImportMemories() {
+  const remote = bmListScope();
+  if (!confirm("Read all memory channels from the rig? This can take a while.")) return;
+  const btn = document.getElementById("bm-import-mem-btn");
+  if (btn) btn.disabled = true;
+  try {
+    const params = new URLSearchParams({ scope: bmScope });
+    if (remote !== "general") params.set("remote", remote);
+    const resp = await fetch("/bookmarks/import_memories?" + params.toString(), { method: "POST" });
+    if (!resp.ok) throw new Error((await resp.text()) || "HTTP " + resp.status);
+    const result = await resp.json();
+    await bmFetch(document.getElementById("bm-category-filter").value);
+    alert(`Imported ${result.imported} memor${result.imported === 1 ? "y" : "ies"}` +
+      (result.skipped ? `, skipped ${result.skipped} already bookmarked.` : "."));
+  } catch (err) {
+    console.error("Failed to import rig memories:", err);
+    alert("Failed to import rig memories: " + err.message);
+  } finally {
+    if (btn) btn.disabled = false;
+  }
+}
+
+/** Write the selected bookmarks to consecutive rig memory channels. */
+async function bmExportMemories() {
+  const ids = Array.from(bmSelected);
+  if (ids.length === 0) return;
+  const input = prompt(`Write ${ids.length} bookmark${ids.length > 1 ? "s" : ""} to rig memories starting at channel:`, "1");
+  if (input === null) return;
+  const firstChannel = parseInt(input, 10);
+  if (!Number.isFinite(firstChannel) || firstChannel < 1) {
+    alert("Enter a channel number of 1 or more.");
+    return;
+  }
+  const remote = bmListScope();
+  try {
+    // Group by owning scope; later scopes continue after earlier channels.
+    const byScope = {};
+    for (const id of ids) {
+      const bm = bmList.find((b) => b.id === id);
+      const scope = bm?.scope || bmScope;
+      (byScope[scope] ||= []).push(id);
+    }
+    let channel = firstChannel;
+    let written = 0;
+    for (const [scope, scopeIds] of Object.entries(byScope)) {
+      const params = new URLSearchParams({ scope });
+      if (remote !== "general") params.set("remote", remote);
+      const resp = await fetch("/bookmarks/export_memories?" + params.toString(), {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ ids: scopeIds, first_channel: channel }),
+      });
+      if (!resp.ok) throw new Error((await resp.text()) || "HTTP " + resp.status);
+      const result = await resp.json();
+      written += result.written;
+      channel += scopeIds.length;
+    }
+    alert(`Wrote ${written} memory channel${written === 1 ? "" : "s"}.`);
+  } catch (err) {
+    console.error("Failed to write rig memories:", err);
+    alert("Failed to write rig memories: " + err.message);
+  }
+}
+
 /** Populate the scope picker with "General" + one option per rig. */
 function bmPopulateScopePicker() {
   const picker = document.getElementById("bm-scope-picker");
@@ -757,6 +890,23 @@ function bmPopulateScopePicker() {
     bmMoveSelected();
   });
 
+  document.getElementById("bm-import-mem-btn").addEventListener("click", () => {
+    bmImportMemories();
+  });
+
+  document.getElementById("bm-import-rpt-btn").addEventListener("click", () => {
+    document.getElementById("bm-import-rpt-file").click();
+  });
+  document.getElementById("bm-import-rpt-file").addEventListener("change", (e) => {
+    const file = e.target.files && e.target.files[0];
+    if (file) bmImportRepeaters(file);
+    e.target.value = "";
+  });
+
+  document.getElementById("bm-export-mem-btn").addEventListener("click", () => {
+    bmExportMemories();
+  });
+
   // Table action buttons and row checkboxes (event delegation)
   document.getElementById("bm-tbody").addEventListener("click", async (e) => {
     const checkbox = e.target.closest(".bm-row-sel");
@@ -434,6 +434,131 @@ window.restoreCwHistory = function(events) {
   }
 };
 
+// --- CW keyer (transmit) ---
+const cwKeyerEl = document.getElementById("cw-keyer");
+const cwKeyerStatusEl = document.getElementById("cw-keyer-status");
+const cwKeyerMemoriesEl = document.getElementById("cw-keyer-memories");
+const cwKeyerCallInput = document.getElementById("cw-keyer-call");
+const cwKeyerTextInput = document.getElementById("cw-keyer-text");
+const cwKeyerSendBtn = document.getElementById("cw-keyer-send");
+const cwKeyerAbortBtn = document.getElementById("cw-keyer-abort");
+const cwKeyerWpmInput = document.getElementById("cw-keyer-wpm");
+const cwKeyerWeightInput = document.getElementById("cw-keyer-weight");
+const cwKeyerFarnsworthInput = document.getElementById("cw-keyer-farnsworth");
+const cwKeyerSerialInput = document.getElementById("cw-keyer-serial");
+let cwKeyerMemoriesKey = "";
+let cwKeyerError = "";
+
+function cwKeyerSetInput(input, value) {
+  // Leave the field alone while the operator is editing it.
+  if (input && document.activeElement !== input) input.value = value;
+}
+
+function renderCwKeyerStatus(keyer) {
+  if (!cwKeyerStatusEl) return;
+  const sending = keyer?.sending || "";
+  cwKeyerStatusEl.classList.toggle("is-sending", !!sending);
+  if (sending) cwKeyerStatusEl.textContent = `Sending: ${sending}`;
+  else if (cwKeyerError) cwKeyerStatusEl.textContent = cwKeyerError;
+  else cwKeyerStatusEl.textContent = keyer ? `Output: ${keyer.output}` : "";
+}
+
+// Called by app.js render() with every server-state snapshot.
+window.applyCwKeyerState = function(update) {
+  if (!cwKeyerEl) return;
+  const keyer = update.cw_keyer || null;
+  cwKeyerEl.style.display = keyer ? "" : "none";
+  if (!keyer) return;
+  cwKeyerSetInput(cwKeyerWpmInput, keyer.settings.wpm);
+  cwKeyerSetInput(cwKeyerWeightInput, keyer.settings.weight);
+  cwKeyerSetInput(cwKeyerFarnsworthInput, keyer.settings.farnsworth_wpm || 0);
+  cwKeyerSetInput(cwKeyerSerialInput, keyer.serial);
+  const memories = Array.isArray(keyer.memories) ? keyer.memories : [];
+  const key = JSON.stringify(memories);
+  if (cwKeyerMemoriesEl && key !== cwKeyerMemoriesKey) {
+    cwKeyerMemoriesKey = key;
+    cwKeyerMemoriesEl.replaceChildren();
+    memories.forEach((memory, idx) => {
+      const btn = document.createElement("button");
+      btn.type = "button";
+      btn.textContent = `F${idx + 1} ${memory.label}`;
+      btn.title = memory.text;
+      btn.addEventListener("click", () => sendCwText(memory.text));
+      cwKeyerMemoriesEl.appendChild(btn);
+    });
+  }
+  renderCwKeyerStatus(keyer);
+};
+
+async function sendCwText(text) {
+  if (!text || !text.trim()) return;
+  const call = (cwKeyerCallInput?.value || "").trim();
+  let path = `/send_cw?text=${encodeURIComponent(text)}`;
+  if (call) path += `&call=${encodeURIComponent(call)}`;
+  cwKeyerError = "";
+  try {
+    await postPath(path);
+  } catch (e) {
+    cwKeyerError = e.message || String(e);
+    renderCwKeyerStatus(null);
+  }
+}
+
+async function abortCw() {
+  try { await postPath("/abort_cw"); }
+  catch (e) { console.error("CW abort failed", e); }
+}
+
+async function applyCwKeyerSettings() {
+  const wpm = Math.max(5, Math.min(60, Math.round(Number(cwKeyerWpmInput?.value) || 25)));
+  const weight = Math.max(25, Math.min(75, Math.round(Number(cwKeyerWeightInput?.value) || 50)));
+  const farnsworth = Math.max(0, Math.round(Number(cwKeyerFarnsworthInput?.value) || 0));
+  let path = `/set_cw_keyer?wpm=${wpm}&weight=${weight}`;
+  if (farnsworth > 0 && farnsworth < wpm) path += `&farnsworth_wpm=${farnsworth}`;
+  try { await postPath(path); }
+  catch (e) { console.error("CW keyer settings failed", e); }
+}
+
+if (cwKeyerSendBtn) {
+  cwKeyerSendBtn.addEventListener("click", async () => {
+    await sendCwText(cwKeyerTextInput?.value || "");
+  });
+}
+if (cwKeyerTextInput) {
+  cwKeyerTextInput.addEventListener("keydown", async (event) => {
+    if (event.key !== "Enter") return;
+    event.preventDefault();
+    await sendCwText(cwKeyerTextInput.value);
+  });
+}
+if (cwKeyerAbortBtn) cwKeyerAbortBtn.addEventListener("click", abortCw);
+for (const input of [cwKeyerWpmInput, cwKeyerWeightInput, cwKeyerFarnsworthInput]) {
+  if (input) input.addEventListener("change", applyCwKeyerSettings);
+}
+if (cwKeyerSerialInput) {
+  cwKeyerSerialInput.addEventListener("change", async () => {
+    const serial = Math.max(0, Math.round(Number(cwKeyerSerialInput.value) || 0));
+    try { await postPath(`/set_cw_serial?serial=${serial}`); }
+    catch (e) { console.error("CW serial set failed", e); }
+  });
+}
+if (cwKeyerEl) {
+  // Escape aborts and F1..F9 play memories while the keyer has focus.
+  cwKeyerEl.addEventListener("keydown", (event) => {
+    if (event.key === "Escape") {
+      event.preventDefault();
+      abortCw();
+      return;
+    }
+    const m = /^F([1-9])$/.exec(event.key);
+    const btn = m ? cwKeyerMemoriesEl?.children[Number(m[1]) - 1] : null;
+    if (btn) {
+      event.preventDefault();
+      btn.click();
+    }
+  });
+}
+
 window.refreshCwTonePicker = function refreshCwTonePicker() {
   ensureCwToneCanvasResolution();
   drawCwTonePicker();
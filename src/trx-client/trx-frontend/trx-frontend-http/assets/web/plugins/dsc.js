@@ -0,0 +1,195 @@
+// --- DSC Decoder Plugin (server-side decode) ---
+const dscStatusEl = document.getElementById("dsc-status");
+const dscMessagesEl = document.getElementById("dsc-messages");
+const dscAlertEl = document.getElementById("dsc-alert");
+const dscAlertTextEl = document.getElementById("dsc-alert-text");
+const DSC_MAX_MESSAGES = 500;
+// Priority calls older than this are restored into the list without alerting.
+const DSC_ALERT_MAX_AGE_MS = 10 * 60 * 1000;
+let dscMessageHistory = [];
+
+function currentDscHistoryRetentionMs() {
+  return typeof window.getDecodeHistoryRetentionMs === "function"
+    ? window.getDecodeHistoryRetentionMs()
+    : 24 * 60 * 60 * 1000;
+}
+
+function pruneDscMessageHistory() {
+  const cutoffMs = Date.now() - currentDscHistoryRetentionMs();
+  dscMessageHistory = dscMessageHistory.filter((msg) => Number(msg._tsMs) >= cutoffMs);
+}
+
+function dscIsPriority(msg) {
+  return msg.category === "distress" || msg.category === "urgency";
+}
+
+function dscMmsiText(mmsi) {
+  return String(mmsi).padStart(9, "0");
+}
+
+function dscFormatLabel(format) {
+  switch (format) {
+    case "distress": return "Distress alert";
+    case "all_ships": return "All ships";
+    case "group": return "Group";
+    case "individual": return "Individual";
+    case "geographic_area": return "Area";
+    default: return String(format || "?");
+  }
+}
+
+function dscPositionText(lat, lon) {
+  if (lat == null || lon == null) return "";
+  const ns = lat < 0 ? "S" : "N";
+  const ew = lon < 0 ? "W" : "E";
+  return `${Math.abs(lat).toFixed(3)}°${ns} ${Math.abs(lon).toFixed(3)}°${ew}`;
+}
+
+function dscStationText(msg) {
+  const parts = [dscMmsiText(msg.self_mmsi)];
+  if (msg.vessel_name) parts.push(msg.vessel_name);
+  if (msg.vessel_callsign) parts.push(`(${msg.vessel_callsign})`);
+  return parts.join(" ");
+}
+
+function dscSummaryText(msg) {
+  const parts = [`${dscFormatLabel(msg.format)} from ${dscStationText(msg)}`];
+  if (msg.distress_mmsi != null) parts.push(`re ${dscMmsiText(msg.distress_mmsi)}`);
+  if (msg.nature) parts.push(msg.nature);
+  const pos = dscPositionText(msg.lat, msg.lon);
+  if (pos) parts.push(pos);
+  return parts.join(" · ");
+}
+
+function dscSpan(className, text) {
+  const span = document.createElement("span");
+  span.className = className;
+  span.textContent = text;
+  return span;
+}
+
+function renderDscRow(msg) {
+  const row = document.createElement("div");
+  row.className = "dsc-message";
+  if (dscIsPriority(msg)) row.classList.add("dsc-priority");
+
+  const head = document.createElement("div");
+  head.className = "dsc-row-head";
+  head.append(
+    dscSpan("dsc-time", msg._ts),
+    dscSpan(`dsc-badge dsc-cat-${msg.category}`, String(msg.category || "").toUpperCase()),
+    dscSpan("dsc-badge", dscFormatLabel(msg.format)),
+    dscSpan("dsc-badge", msg.band === "hf" ? "MF/HF" : "VHF"),
+    dscSpan("dsc-call", dscStationText(msg)),
+  );
+  if (!msg.ecc_ok) head.appendChild(dscSpan("dsc-badge dsc-ecc-bad", "ECC"));
+
+  const detail = document.createElement("div");
+  detail.className = "dsc-row-meta";
+  if (msg.address_mmsi != null) detail.appendChild(dscSpan("", `To ${dscMmsiText(msg.address_mmsi)}`));
+  if (msg.distress_mmsi != null) detail.appendChild(dscSpan("", `Distress ${dscMmsiText(msg.distress_mmsi)}`));
+  if (msg.nature) detail.appendChild(dscSpan("", msg.nature));
+  if (msg.telecommand) detail.appendChild(dscSpan("", msg.telecommand));
+  const pos = dscPositionText(msg.lat, msg.lon);
+  if (pos) detail.appendChild(dscSpan("", pos));
+  if (msg.utc_time) detail.appendChild(dscSpan("", `${msg.utc_time} UTC`));
+  const vesselPos = dscPositionText(msg.vessel_lat, msg.vessel_lon);
+  if (vesselPos) detail.appendChild(dscSpan("", `AIS ${vesselPos}`));
+
+  row.append(head, detail);
+  return row;
+}
+
+function renderDscHistory() {
+  pruneDscMessageHistory();
+  if (!dscMessagesEl) return;
+  const fragment = document.createDocumentFragment();
+  for (const msg of dscMessageHistory) fragment.appendChild(renderDscRow(msg));
+  dscMessagesEl.replaceChildren(fragment);
+}
+
+function scheduleDscRender() {
+  if (typeof window.trxScheduleUiFrameJob === "function") {
+    window.trxScheduleUiFrameJob("dsc-history", () => renderDscHistory());
+    return;
+  }
+  renderDscHistory();
+}
+
+function showDscAlert(msg) {
+  if (!dscAlertEl || !dscAlertTextEl) return;
+  dscAlertEl.classList.toggle("dsc-alert-urgency", msg.category === "urgency");
+  dscAlertTextEl.textContent = `DSC ${String(msg.category).toUpperCase()}: ${dscSummaryText(msg)}`;
+  dscAlertEl.style.display = "";
+}
+
+function normalizeServerDscMessage(msg) {
+  const tsMs = Number.isFinite(msg.ts_ms) ? Number(msg.ts_ms) : Date.now();
+  return {
+    ...msg,
+    _tsMs: tsMs,
+    _ts: new Date(tsMs).toLocaleTimeString([], { hour: "2-digit", minute: "2-digit", second: "2-digit" }),
+  };
+}
+
+function addDscMessage(msg) {
+  dscMessageHistory.unshift(msg);
+  if (dscMessageHistory.length > DSC_MAX_MESSAGES) {
+    dscMessageHistory.length = DSC_MAX_MESSAGES;
+  }
+}
+
+document.getElementById("dsc-alert-dismiss")?.addEventListener("click", () => {
+  if (dscAlertEl) dscAlertEl.style.display = "none";
+});
+
+document.getElementById("dsc-decode-toggle-btn")?.addEventListener("click", async () => {
+  try {
+    await postPath("/toggle_dsc_decode");
+  } catch (e) {
+    console.error("DSC toggle failed", e);
+  }
+});
+
+window.resetDscHistoryView = function() {
+  dscMessageHistory = [];
+  if (dscAlertEl) dscAlertEl.style.display = "none";
+  renderDscHistory();
+};
+
+window.pruneDscHistoryView = function() {
+  renderDscHistory();
+};
+
+document.getElementById("settings-clear-dsc-history")?.addEventListener("click", async () => {
+  if (!confirm("Clear all DSC decode history? This cannot be undone.")) return;
+  try {
+    await postPath("/clear_dsc_decode");
+    window.resetDscHistoryView();
+  } catch (e) {
+    console.error("DSC history clear failed", e);
+  }
+});
+
+window.onServerDsc = function(msg) {
+  if (dscStatusEl) dscStatusEl.textContent = "Receiving";
+  const normalized = normalizeServerDscMessage(msg);
+  addDscMessage(normalized);
+  if (dscIsPriority(normalized)) showDscAlert(normalized);
+  scheduleDscRender();
+};
+
+window.restoreDscHistory = function(messages) {
+  if (!Array.isArray(messages) || messages.length === 0) return;
+  if (dscStatusEl) dscStatusEl.textContent = "Receiving";
+  const cutoffMs = Date.now() - DSC_ALERT_MAX_AGE_MS;
+  let latestPriority = null;
+  for (const msg of messages) {
+    const normalized = normalizeServerDscMessage(msg);
+    addDscMessage(normalized);
+    if (dscIsPriority(normalized) && normalized._tsMs >= cutoffMs) latestPriority = normalized;
+  }
+  dscMessageHistory.sort((a, b) => b._tsMs - a._tsMs);
+  if (latestPriority) showDscAlert(latestPriority);
+  scheduleDscRender();
+};
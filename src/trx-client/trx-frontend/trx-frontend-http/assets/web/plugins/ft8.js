@@ -259,6 +259,8 @@ function renderFt8Message(message) {
       const grid = token.toUpperCase();
       if (ft8IsMaidenheadGridToken(grid)) {
         out += `<span class="ft8-locator" data-locator-grid="${grid}" role="button" tabindex="0" aria-label="Show locator ${grid} on map">${grid}</span>`;
+      } else if (ft8IsLikelyCallsignToken(grid)) {
+        out += ft8RenderCallsign(grid);
       } else {
         out += ft8EscapeHtml(token);
       }
@@ -271,6 +273,17 @@ function renderFt8Message(message) {
   return out;
 }
 
+// Callsigns link to the logbook; worked-before calls get a badge with the
+// bands and modes they were worked on.
+function ft8RenderCallsign(call) {
+  const worked = typeof window.logbookWorked === "function" ? window.logbookWorked(call) : null;
+  if (!worked) {
+    return `<span class="ft8-call" data-call="${call}" role="button" tabindex="0" aria-label="Log QSO with ${call}">${call}</span>`;
+  }
+  const title = `Worked ${worked.count}× · ${worked.bands.join(" ") || "?"} · ${worked.modes.join(" ")}`;
+  return `<span class="ft8-call ft8-call-worked" data-call="${call}" role="button" tabindex="0" title="${ft8EscapeHtml(title)}" aria-label="${call}, worked before; log QSO">${call}<span class="ft8-worked-badge">W</span></span>`;
+}
+
 function ft8TokenizeMessage(message) {
   return String(message || "")
     .toUpperCase()
@@ -0,0 +1,317 @@
+// --- Logbook Tab ---
+
+let lbList = [];
+let lbFilteredList = [];
+let lbCurrentPage = 1;
+const LB_PAGE_SIZE = 50;
+const LB_WORKED_REFRESH_MS = 60 * 1000;
+/** Worked-before summaries keyed by upper-case callsign. */
+let lbWorked = {};
+
+function lbEsc(str) {
+  const d = document.createElement("div");
+  d.appendChild(document.createTextNode(String(str)));
+  return d.innerHTML;
+}
+
+function lbCanControl() {
+  return (
+    (typeof authEnabled !== "undefined" && !authEnabled) ||
+    (typeof authRole !== "undefined" && authRole === "control")
+  );
+}
+
+function lbSyncAccess() {
+  const canCtrl = lbCanControl();
+  const addBtn = document.getElementById("lb-add-btn");
+  const importBtn = document.getElementById("lb-import-btn");
+  if (addBtn) addBtn.style.display = canCtrl ? "" : "none";
+  if (importBtn) importBtn.style.display = canCtrl ? "" : "none";
+}
+
+function lbSetStatus(text) {
+  const el = document.getElementById("lb-status");
+  if (el) el.textContent = text || "";
+}
+
+function lbFmtTime(tsMs) {
+  if (!Number.isFinite(tsMs)) return "--";
+  return new Date(tsMs).toISOString().slice(0, 19).replace("T", " ");
+}
+
+function lbFmtFreq(hz) {
+  if (!Number.isFinite(hz) || hz <= 0) return "--";
+  return (hz / 1e6).toFixed(6).replace(/\.?0+$/, "") + " MHz";
+}
+
+/** Worked-before summary for `call`, or null. Used by the FT8/FT4 renderers. */
+window.logbookWorked = function(call) {
+  return lbWorked[String(call || "").toUpperCase()] || null;
+};
+
+async function lbFetchWorked() {
+  try {
+    const resp = await fetch("/logbook/worked");
+    if (!resp.ok) throw new Error("HTTP " + resp.status);
+    lbWorked = await resp.json();
+  } catch (e) {
+    console.error("Failed to fetch worked-before list:", e);
+    return;
+  }
+  window.pruneFt8HistoryView?.();
+  window.pruneFt4HistoryView?.();
+}
+
+async function lbFetch() {
+  try {
+    const resp = await fetch("/logbook");
+    if (!resp.ok) throw new Error("HTTP " + resp.status);
+    lbList = await resp.json();
+  } catch (e) {
+    console.error("Failed to fetch logbook:", e);
+    lbList = [];
+  }
+  lbSyncAccess();
+  lbApplyFilter();
+}
+
+function lbApplyFilter() {
+  const needle = (document.getElementById("lb-call-filter")?.value || "").trim().toUpperCase();
+  lbFilteredList = needle ? lbList.filter((q) => q.call.includes(needle)) : lbList.slice();
+  lbRender(lbFilteredList);
+}
+
+function lbRender(list) {
+  const tbody = document.getElementById("lb-tbody");
+  const emptyEl = document.getElementById("lb-empty");
+  const paginatorEl = document.getElementById("lb-paginator");
+  if (!tbody) return;
+  tbody.innerHTML = "";
+  if (list.length === 0) {
+    if (emptyEl) emptyEl.style.display = "";
+    if (paginatorEl) paginatorEl.style.display = "none";
+    return;
+  }
+  if (emptyEl) emptyEl.style.display = "none";
+
+  const canControl = lbCanControl();
+  const totalPages = Math.max(1, Math.ceil(list.length / LB_PAGE_SIZE));
+  lbCurrentPage = Math.min(Math.max(lbCurrentPage, 1), totalPages);
+  const startIndex = (lbCurrentPage - 1) * LB_PAGE_SIZE;
+  const endIndex = Math.min(startIndex + LB_PAGE_SIZE, list.length);
+  list.slice(startIndex, endIndex).forEach((q) => {
+    const tr = document.createElement("tr");
+    tr.innerHTML =
+      `<td>${lbFmtTime(q.ts_ms)}</td>` +
+      `<td>${lbEsc(q.call)}</td>` +
+      `<td>${lbEsc(q.band || "--")}</td>` +
+      `<td class="bm-col-freq">${lbFmtFreq(q.freq_hz)}</td>` +
+      `<td class="bm-col-mode">${lbEsc(q.mode)}</td>` +
+      `<td>${lbEsc(q.rst_sent || "")}</td>` +
+      `<td>${lbEsc(q.rst_rcvd || "")}</td>` +
+      `<td>${lbEsc(q.gridsquare || "")}</td>` +
+      `<td>${lbEsc(q.comment || "")}</td>` +
+      `<td class="bm-col-act">` +
+        (canControl
+          ? `<button class="lb-edit-btn" type="button" data-lb-id="${lbEsc(q.id)}">Edit</button>` +
+            `<button class="lb-del-btn bm-del-btn" type="button" data-lb-id="${lbEsc(q.id)}">Delete</button>`
+          : "") +
+      `</td>`;
+    tbody.appendChild(tr);
+  });
+
+  if (paginatorEl) paginatorEl.style.display = totalPages > 1 ? "flex" : "";
+  const summaryEl = document.getElementById("lb-page-summary");
+  const indicatorEl = document.getElementById("lb-page-indicator");
+  if (summaryEl) summaryEl.textContent = `Showing ${startIndex + 1}-${endIndex} of ${list.length}`;
+  if (indicatorEl) indicatorEl.textContent = `Page ${lbCurrentPage} of ${totalPages}`;
+  document.getElementById("lb-page-prev").disabled = lbCurrentPage <= 1;
+  document.getElementById("lb-page-next").disabled = lbCurrentPage >= totalPages;
+}
+
+function lbChangePage(delta) {
+  lbCurrentPage += delta;
+  lbRender(lbFilteredList);
+}
+
+/** `datetime-local` value for a UTC timestamp. */
+function lbToInputTime(tsMs) {
+  return new Date(tsMs).toISOString().slice(0, 19);
+}
+
+function lbUpdateWorkedHint() {
+  const hint = document.getElementById("lb-worked-hint");
+  if (!hint) return;
+  const worked = window.logbookWorked(document.getElementById("lb-call").value.trim());
+  hint.textContent = worked
+    ? `Worked ${worked.count}× · ${worked.bands.join(" ") || "?"} · ${worked.modes.join(" ")}`
+    : "";
+}
+
+/**
+ * Open the QSO form. New entries are pre-filled from the current rig state;
+ * `prefill` overrides individual fields (e.g. call and locator from a decode).
+ */
+function lbOpenForm(qso, prefill = {}) {
+  const set = (id, value) => { document.getElementById(id).value = value == null ? "" : value; };
+  document.getElementById("lb-form-title").textContent = qso ? "Edit QSO" : "Log QSO";
+  set("lb-id", qso ? qso.id : "");
+  set("lb-call", qso ? qso.call : prefill.call);
+  set("lb-time", lbToInputTime(qso ? qso.ts_ms : Date.now()));
+  set("lb-freq", qso ? qso.freq_hz : (typeof lastFreqHz !== "undefined" ? lastFreqHz : ""));
+  set("lb-mode", qso ? qso.mode : (prefill.mode || (typeof lastModeName !== "undefined" ? lastModeName : "")));
+  const bandText = document.getElementById("band-label")?.textContent;
+  set("lb-band", qso ? qso.band : (bandText && bandText !== "--" ? bandText : ""));
+  set("lb-rst-sent", qso ? qso.rst_sent : prefill.rst_sent);
+  set("lb-rst-rcvd", qso ? qso.rst_rcvd : prefill.rst_rcvd);
+  set("lb-grid", qso ? qso.gridsquare : prefill.gridsquare);
+  set("lb-comment", qso ? qso.comment : "");
+  document.getElementById("lb-form-wrap").style.display = "";
+  lbUpdateWorkedHint();
+  document.getElementById("lb-call").focus();
+}
+
+function lbCloseForm() {
+  document.getElementById("lb-form-wrap").style.display = "none";
+}
+
+async function lbSave(force = false) {
+  const id = document.getElementById("lb-id").value;
+  const val = (elId) => document.getElementById(elId).value.trim();
+  const timeStr = val("lb-time");
+  const freqStr = val("lb-freq");
+  const body = {
+    call: val("lb-call").toUpperCase(),
+    ts_ms: timeStr ? Date.parse(timeStr + "Z") : null,
+    freq_hz: freqStr ? parseInt(freqStr, 10) : null,
+    mode: val("lb-mode") || null,
+    band: val("lb-band") || null,
+    rst_sent: val("lb-rst-sent") || null,
+    rst_rcvd: val("lb-rst-rcvd") || null,
+    gridsquare: val("lb-grid") || null,
+    comment: val("lb-comment"),
+  };
+  if (!body.call) {
+    alert("Callsign is required.");
+    return;
+  }
+  const params = new URLSearchParams();
+  if (typeof lastActiveRigId !== "undefined" && lastActiveRigId) params.set("remote", lastActiveRigId);
+  if (force) params.set("force", "true");
+  const url = (id ? "/logbook/" + encodeURIComponent(id) : "/logbook") + "?" + params;
+  try {
+    const resp = await fetch(url, {
+      method: id ? "PUT" : "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(body),
+    });
+    if (resp.status === 409 && !force) {
+      const text = await resp.text();
+      if (confirm(`${text}. Log it anyway?`)) await lbSave(true);
+      return;
+    }
+    if (!resp.ok) throw new Error((await resp.text()) || "HTTP " + resp.status);
+    lbCloseForm();
+    await Promise.all([lbFetch(), lbFetchWorked()]);
+  } catch (err) {
+    console.error("Failed to save QSO:", err);
+    alert("Failed to save QSO: " + err.message);
+  }
+}
+
+async function lbDelete(id) {
+  if (!confirm("Delete this QSO?")) return;
+  try {
+    const resp = await fetch("/logbook/" + encodeURIComponent(id), { method: "DELETE" });
+    if (!resp.ok) throw new Error("HTTP " + resp.status);
+    await Promise.all([lbFetch(), lbFetchWorked()]);
+  } catch (err) {
+    console.error("Failed to delete QSO:", err);
+    alert("Failed to delete QSO: " + err.message);
+  }
+}
+
+async function lbImport(file) {
+  lbSetStatus("Importing…");
+  try {
+    const resp = await fetch("/logbook/import", {
+      method: "POST",
+      headers: { "Content-Type": "text/plain" },
+      body: await file.text(),
+    });
+    if (!resp.ok) throw new Error((await resp.text()) || "HTTP " + resp.status);
+    const result = await resp.json();
+    lbSetStatus(`Imported ${result.imported} QSOs, skipped ${result.duplicates} duplicates`);
+    await Promise.all([lbFetch(), lbFetchWorked()]);
+  } catch (err) {
+    console.error("ADIF import failed:", err);
+    lbSetStatus("Import failed: " + err.message);
+  }
+}
+
+/** Start a QSO with a station clicked in the FT8/FT4 decode lists. */
+function lbStartFromDecode(callEl) {
+  if (!lbCanControl()) return;
+  const call = callEl.dataset.call;
+  const row = callEl.closest(".ft8-row");
+  const grid = row?.querySelector(".ft8-locator[data-locator-grid]")?.dataset.locatorGrid;
+  const mode = row?.closest("#ft4-messages") ? "FT4" : "FT8";
+  window.trx?.navigateToTab?.("logbook");
+  lbOpenForm(null, { call, gridsquare: grid, mode });
+}
+
+document.addEventListener("click", (e) => {
+  const callEl = e.target.closest?.(".ft8-call[data-call]");
+  if (!callEl) return;
+  e.preventDefault();
+  lbStartFromDecode(callEl);
+});
+document.addEventListener("keydown", (e) => {
+  if (e.key !== "Enter" && e.key !== " ") return;
+  const callEl = e.target.closest?.(".ft8-call[data-call]");
+  if (!callEl) return;
+  e.preventDefault();
+  lbStartFromDecode(callEl);
+});
+
+document.getElementById("lb-add-btn")?.addEventListener("click", () => lbOpenForm(null));
+document.getElementById("lb-form-cancel")?.addEventListener("click", lbCloseForm);
+document.getElementById("lb-form")?.addEventListener("submit", (e) => {
+  e.preventDefault();
+  lbSave();
+});
+document.getElementById("lb-call")?.addEventListener("input", lbUpdateWorkedHint);
+document.getElementById("lb-call-filter")?.addEventListener("input", () => {
+  lbCurrentPage = 1;
+  lbApplyFilter();
+});
+document.getElementById("lb-page-prev")?.addEventListener("click", () => lbChangePage(-1));
+document.getElementById("lb-page-next")?.addEventListener("click", () => lbChangePage(1));
+document.getElementById("lb-import-btn")?.addEventListener("click", () => {
+  document.getElementById("lb-import-file")?.click();
+});
+document.getElementById("lb-import-file")?.addEventListener("change", (e) => {
+  const file = e.target.files && e.target.files[0];
+  if (file) lbImport(file);
+  e.target.value = "";
+});
+document.getElementById("lb-tbody")?.addEventListener("click", (e) => {
+  const editBtn = e.target.closest(".lb-edit-btn");
+  if (editBtn) {
+    const qso = lbList.find((q) => q.id === editBtn.dataset.lbId);
+    if (qso) lbOpenForm(qso);
+    return;
+  }
+  const delBtn = e.target.closest(".lb-del-btn");
+  if (delBtn) lbDelete(delBtn.dataset.lbId);
+});
+
+// Auto-logged FT8/FT4 QSOs arrive server-side; refresh periodically so the
+// worked-before badges and the table pick them up.
+setInterval(() => {
+  lbFetchWorked();
+  if (document.getElementById("tab-logbook")?.style.display !== "none") lbFetch();
+}, LB_WORKED_REFRESH_MS);
+
+lbFetchWorked();
+lbFetch();
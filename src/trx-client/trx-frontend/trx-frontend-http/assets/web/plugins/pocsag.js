@@ -0,0 +1,130 @@
+// --- POCSAG Pager Decoder Plugin (server-side decode) ---
+const pocsagStatusEl = document.getElementById("pocsag-status");
+const pocsagMessagesEl = document.getElementById("pocsag-messages");
+const pocsagFilterInput = document.getElementById("pocsag-filter");
+const POCSAG_MAX_MESSAGES = 1000;
+let pocsagMessageHistory = [];
+let pocsagFilterText = "";
+
+function currentPocsagHistoryRetentionMs() {
+  return typeof window.getDecodeHistoryRetentionMs === "function"
+    ? window.getDecodeHistoryRetentionMs()
+    : 24 * 60 * 60 * 1000;
+}
+
+function prunePocsagMessageHistory() {
+  const cutoffMs = Date.now() - currentPocsagHistoryRetentionMs();
+  pocsagMessageHistory = pocsagMessageHistory.filter((msg) => Number(msg._tsMs) >= cutoffMs);
+}
+
+function pocsagBodyText(msg) {
+  if (msg.redacted) return "[message withheld]";
+  if (msg.content === "tone") return "[tone only]";
+  return msg.text || "";
+}
+
+function pocsagSpan(className, text) {
+  const span = document.createElement("span");
+  span.className = className;
+  span.textContent = text;
+  return span;
+}
+
+function renderPocsagRow(msg) {
+  const row = document.createElement("div");
+  row.className = "pocsag-line";
+  const meta = pocsagSpan(
+    "pocsag-meta",
+    `${msg._ts} ${String(msg.capcode).padStart(7, "0")} F${msg.function} ${msg.baud} ${String(msg.content || "").toUpperCase()}`,
+  );
+  const body = pocsagSpan("pocsag-text", pocsagBodyText(msg));
+  if (msg.redacted || msg.content === "tone") body.classList.add("pocsag-text-muted");
+  row.append(meta, body);
+  if (msg.corrected_bits > 0) {
+    row.appendChild(pocsagSpan("pocsag-fec", `${msg.corrected_bits} bit${msg.corrected_bits === 1 ? "" : "s"} fixed`));
+  }
+  return row;
+}
+
+function pocsagMatchesFilter(msg) {
+  if (!pocsagFilterText) return true;
+  return `${msg.capcode} ${msg.text || ""}`.toUpperCase().includes(pocsagFilterText);
+}
+
+function renderPocsagHistory() {
+  prunePocsagMessageHistory();
+  if (!pocsagMessagesEl) return;
+  const fragment = document.createDocumentFragment();
+  for (const msg of pocsagMessageHistory) {
+    if (pocsagMatchesFilter(msg)) fragment.appendChild(renderPocsagRow(msg));
+  }
+  pocsagMessagesEl.replaceChildren(fragment);
+}
+
+function schedulePocsagRender() {
+  if (typeof window.trxScheduleUiFrameJob === "function") {
+    window.trxScheduleUiFrameJob("pocsag-history", () => renderPocsagHistory());
+    return;
+  }
+  renderPocsagHistory();
+}
+
+function addPocsagMessage(msg) {
+  const tsMs = Number.isFinite(msg.ts_ms) ? Number(msg.ts_ms) : Date.now();
+  pocsagMessageHistory.unshift({
+    ...msg,
+    _tsMs: tsMs,
+    _ts: new Date(tsMs).toLocaleTimeString([], { hour: "2-digit", minute: "2-digit", second: "2-digit" }),
+  });
+  if (pocsagMessageHistory.length > POCSAG_MAX_MESSAGES) {
+    pocsagMessageHistory.length = POCSAG_MAX_MESSAGES;
+  }
+}
+
+if (pocsagFilterInput) {
+  pocsagFilterInput.addEventListener("input", () => {
+    pocsagFilterText = pocsagFilterInput.value.trim().toUpperCase();
+    renderPocsagHistory();
+  });
+}
+
+document.getElementById("pocsag-decode-toggle-btn")?.addEventListener("click", async () => {
+  try {
+    await postPath("/toggle_pocsag_decode");
+  } catch (e) {
+    console.error("POCSAG toggle failed", e);
+  }
+});
+
+window.resetPocsagHistoryView = function() {
+  pocsagMessageHistory = [];
+  renderPocsagHistory();
+};
+
+window.prunePocsagHistoryView = function() {
+  renderPocsagHistory();
+};
+
+document.getElementById("settings-clear-pocsag-history")?.addEventListener("click", async () => {
+  if (!confirm("Clear all POCSAG decode history? This cannot be undone.")) return;
+  try {
+    await postPath("/clear_pocsag_decode");
+    window.resetPocsagHistoryView();
+  } catch (e) {
+    console.error("POCSAG history clear failed", e);
+  }
+});
+
+window.onServerPocsag = function(msg) {
+  if (pocsagStatusEl) pocsagStatusEl.textContent = "Receiving";
+  addPocsagMessage(msg);
+  schedulePocsagRender();
+};
+
+window.restorePocsagHistory = function(messages) {
+  if (!Array.isArray(messages) || messages.length === 0) return;
+  if (pocsagStatusEl) pocsagStatusEl.textContent = "Receiving";
+  for (const msg of messages) addPocsagMessage(msg);
+  pocsagMessageHistory.sort((a, b) => b._tsMs - a._tsMs);
+  schedulePocsagRender();
+};
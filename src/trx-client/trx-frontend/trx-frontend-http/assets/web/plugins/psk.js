@@ -0,0 +1,181 @@
+// --- PSK31/63/125 Decoder Plugin (server-side decode) ---
+const pskStatusEl = document.getElementById("psk-status");
+const pskOutputEl = document.getElementById("psk-output");
+const pskModeSelect = document.getElementById("psk-mode");
+const pskFreqInput = document.getElementById("psk-freq");
+const pskWaterfallInput = document.getElementById("psk-waterfall");
+const PSK_MAX_CHARS_PER_CHANNEL = 2000;
+const PSK_FREQ_MIN_HZ = 100;
+const PSK_FREQ_MAX_HZ = 4000;
+// channel id -> { channel, mode, freq_hz, snr_db, text, lastMs }
+let pskChannels = new Map();
+// Tracks a user-initiated settings change that is in-flight so a concurrent
+// SSE update carrying the *old* value does not undo the user's choice.
+let pskLocalOverride = false;
+
+function currentPskHistoryRetentionMs() {
+  return typeof window.getDecodeHistoryRetentionMs === "function"
+    ? window.getDecodeHistoryRetentionMs()
+    : 24 * 60 * 60 * 1000;
+}
+
+function prunePskChannels() {
+  const cutoffMs = Date.now() - currentPskHistoryRetentionMs();
+  for (const [id, ch] of pskChannels) {
+    if (ch.lastMs < cutoffMs) pskChannels.delete(id);
+  }
+}
+
+function fmtPskTime(tsMs) {
+  if (!tsMs) return "--:--:--";
+  return new Date(tsMs).toLocaleTimeString([], { hour: "2-digit", minute: "2-digit", second: "2-digit" });
+}
+
+function renderPskChannels() {
+  prunePskChannels();
+  if (!pskOutputEl) return;
+  const channels = Array.from(pskChannels.values()).sort((a, b) => a.freq_hz - b.freq_hz);
+  const fragment = document.createDocumentFragment();
+  for (const ch of channels) {
+    const row = document.createElement("div");
+    row.className = "psk-line";
+    const meta = document.createElement("span");
+    meta.className = "psk-meta";
+    const snr = Number.isFinite(ch.snr_db) ? `${ch.snr_db.toFixed(0)} dB` : "--";
+    meta.textContent = `${fmtPskTime(ch.lastMs)} ${Math.round(ch.freq_hz)} Hz ${ch.mode.toUpperCase()} ${snr}`;
+    const text = document.createElement("span");
+    text.className = "psk-text";
+    text.textContent = ch.text;
+    row.append(meta, text);
+    fragment.appendChild(row);
+  }
+  pskOutputEl.replaceChildren(fragment);
+}
+
+function schedulePskRender() {
+  if (typeof window.trxScheduleUiFrameJob === "function") {
+    window.trxScheduleUiFrameJob("psk-history", () => renderPskChannels());
+    return;
+  }
+  renderPskChannels();
+}
+
+function addPskEvent(evt) {
+  if (!evt || typeof evt.text !== "string") return;
+  const key = Number(evt.channel) || 0;
+  const tsMs = Number.isFinite(evt.ts_ms) ? Number(evt.ts_ms) : Date.now();
+  let ch = pskChannels.get(key);
+  if (!ch) {
+    ch = { channel: key, mode: "", freq_hz: 0, snr_db: NaN, text: "", lastMs: tsMs };
+    pskChannels.set(key, ch);
+  }
+  ch.mode = String(evt.mode || ch.mode);
+  if (Number.isFinite(Number(evt.freq_hz))) ch.freq_hz = Number(evt.freq_hz);
+  if (Number.isFinite(Number(evt.snr_db))) ch.snr_db = Number(evt.snr_db);
+  ch.text = (ch.text + evt.text.replace(/[\r\n]+/g, " ")).slice(-PSK_MAX_CHARS_PER_CHANNEL);
+  ch.lastMs = tsMs;
+}
+
+function clampPskFreq(freq) {
+  const numeric = Number(freq);
+  if (!Number.isFinite(numeric)) return 1000;
+  return Math.round(Math.max(PSK_FREQ_MIN_HZ, Math.min(PSK_FREQ_MAX_HZ, numeric)));
+}
+
+function applyPskWaterfallUi(enabled) {
+  if (pskWaterfallInput) pskWaterfallInput.checked = enabled;
+  if (pskFreqInput) {
+    pskFreqInput.disabled = enabled;
+    pskFreqInput.readOnly = enabled;
+  }
+}
+
+// Called by app.js render() when a server-state snapshot arrives.
+window.applyPskSettingsFromServer = function(update) {
+  if (pskLocalOverride) return;
+  if (pskModeSelect && typeof update.psk_mode === "string" && document.activeElement !== pskModeSelect) {
+    pskModeSelect.value = update.psk_mode;
+  }
+  if (pskFreqInput && typeof update.psk_freq_hz === "number" && document.activeElement !== pskFreqInput) {
+    pskFreqInput.value = update.psk_freq_hz;
+  }
+  if (typeof update.psk_waterfall === "boolean") {
+    applyPskWaterfallUi(update.psk_waterfall);
+  }
+};
+
+async function postPskSetting(path, label) {
+  pskLocalOverride = true;
+  try {
+    await postPath(path);
+  } catch (e) {
+    console.error(`PSK ${label} set failed`, e);
+  } finally {
+    pskLocalOverride = false;
+  }
+}
+
+if (pskModeSelect) {
+  pskModeSelect.addEventListener("change", async () => {
+    await postPskSetting(`/set_psk_mode?mode=${encodeURIComponent(pskModeSelect.value)}`, "mode");
+  });
+}
+
+if (pskFreqInput) {
+  pskFreqInput.addEventListener("change", async () => {
+    const freq = clampPskFreq(pskFreqInput.value);
+    pskFreqInput.value = freq;
+    await postPskSetting(`/set_psk_freq?freq_hz=${encodeURIComponent(freq)}`, "frequency");
+  });
+}
+
+if (pskWaterfallInput) {
+  pskWaterfallInput.addEventListener("change", async () => {
+    const enabled = pskWaterfallInput.checked;
+    applyPskWaterfallUi(enabled);
+    await postPskSetting(`/set_psk_waterfall?enabled=${enabled ? "true" : "false"}`, "waterfall");
+  });
+}
+
+const pskDecodeToggleBtn = document.getElementById("psk-decode-toggle-btn");
+pskDecodeToggleBtn?.addEventListener("click", async () => {
+  try {
+    await postPath("/toggle_psk_decode");
+  } catch (e) {
+    console.error("PSK toggle failed", e);
+  }
+});
+
+window.resetPskHistoryView = function() {
+  pskChannels = new Map();
+  renderPskChannels();
+};
+
+window.prunePskHistoryView = function() {
+  renderPskChannels();
+};
+
+document.getElementById("settings-clear-psk-history")?.addEventListener("click", async () => {
+  if (!confirm("Clear all PSK decode history? This cannot be undone.")) return;
+  try {
+    await postPath("/clear_psk_decode");
+    window.resetPskHistoryView();
+  } catch (e) {
+    console.error("PSK history clear failed", e);
+  }
+});
+
+window.onServerPsk = function(evt) {
+  if (pskStatusEl) pskStatusEl.textContent = "Receiving";
+  addPskEvent(evt);
+  schedulePskRender();
+};
+
+window.restorePskHistory = function(events) {
+  if (!Array.isArray(events) || events.length === 0) return;
+  if (pskStatusEl) pskStatusEl.textContent = "Receiving";
+  for (const evt of events) addPskEvent(evt);
+  schedulePskRender();
+};
+
+applyPskWaterfallUi(!!pskWaterfallInput?.checked);
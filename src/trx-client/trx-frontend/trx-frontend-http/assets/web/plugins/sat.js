@@ -1,5 +1,5 @@
 // --- SAT Plugin ---
-// Live view: decoder state, latest image card
+// Live view: decoder state, Doppler and rotator tracking, latest image card
 // History view: filterable table of all decoded images
 // Predictions view: next 24 h passes for ham satellites
 
@@ -27,6 +27,26 @@ const satDom = {
   predCurrentSec:   document.getElementById("sat-pred-current-section"),
   predUpcomingSec:  document.getElementById("sat-pred-upcoming-section"),
   predStatus:       document.getElementById("sat-pred-status"),
+  predUploadBtn:    document.getElementById("sat-pred-upload-btn"),
+  predUploadFile:   document.getElementById("sat-pred-upload-file"),
+  trackSelect:      document.getElementById("sat-track-select"),
+  trackStartBtn:    document.getElementById("sat-track-start-btn"),
+  trackStopBtn:     document.getElementById("sat-track-stop-btn"),
+  trackStatus:      document.getElementById("sat-track-status"),
+  trackReadout:     document.getElementById("sat-track-readout"),
+  trackRx:          document.getElementById("sat-track-rx"),
+  trackTx:          document.getElementById("sat-track-tx"),
+  trackShift:       document.getElementById("sat-track-shift"),
+  trackAzEl:        document.getElementById("sat-track-azel"),
+  trackRangeRate:   document.getElementById("sat-track-rr"),
+  rotCard:          document.getElementById("sat-rot-card"),
+  rotPos:           document.getElementById("sat-rot-pos"),
+  rotTrackBtn:      document.getElementById("sat-rot-track-btn"),
+  rotAz:            document.getElementById("sat-rot-az"),
+  rotEl:            document.getElementById("sat-rot-el"),
+  rotGoBtn:         document.getElementById("sat-rot-go-btn"),
+  rotStopBtn:       document.getElementById("sat-rot-stop-btn"),
+  rotStatus:        document.getElementById("sat-rot-status"),
 };
 
 // ── State ───────────────────────────────────────────────────────────
@@ -41,6 +61,7 @@ let satPredFilterText = "";
 let satPredMinEl = 0;
 let satPredCategory = "all";
 let satPredSatCount = 0;
+let satPredTleWarning = null;
 let satPredCountdownTimer = null;
 
 // ── UI scheduler helper ─────────────────────────────────────────────
@@ -85,6 +106,8 @@ satDom.viewPredBtn?.addEventListener("click", () => switchSatView("predictions")
 // ── Live view: decoder state ────────────────────────────────────────
 let _lastSatLrptOn = null;
 window.updateSatLiveState = function (update) {
+  renderSatTracking(update.sat_tracking || null);
+  renderSatRotator(update.rotator || null);
   if (!satDom.lrptState) return;
   const lrptOn = !!update.lrpt_decode_enabled;
   if (lrptOn !== _lastSatLrptOn) {
@@ -101,6 +124,141 @@ window.updateSatLiveState = function (update) {
   }
 };
 
+// ── Live view: Doppler tracking ─────────────────────────────────────
+function satTrackMHz(hz) {
+  return `${(hz / 1e6).toFixed(4)} MHz`;
+}
+
+function renderSatTracking(tracking) {
+  if (satDom.trackStartBtn) satDom.trackStartBtn.disabled = !!tracking;
+  if (satDom.trackStopBtn)  satDom.trackStopBtn.disabled = !tracking;
+  if (!tracking) {
+    if (satDom.trackStatus)  satDom.trackStatus.textContent = "Doppler tracking off";
+    if (satDom.trackReadout) satDom.trackReadout.style.display = "none";
+    return;
+  }
+  if (satDom.trackStatus) {
+    const below = tracking.elevation_deg < 0 ? " (below horizon)" : "";
+    satDom.trackStatus.textContent = `Tracking ${tracking.satellite} ${tracking.transponder}${below}`;
+  }
+  if (satDom.trackReadout) satDom.trackReadout.style.display = "";
+  if (satDom.trackRx) satDom.trackRx.textContent = satTrackMHz(tracking.rx_hz);
+  if (satDom.trackTx) {
+    let tx = "\u2014";
+    if (Number.isFinite(tracking.tx_hz)) {
+      tx = satTrackMHz(tracking.tx_hz) + (tracking.split_active ? " (split)" : " (set manually)");
+    }
+    satDom.trackTx.textContent = tx;
+  }
+  if (satDom.trackShift) {
+    const shift = tracking.rx_hz - tracking.downlink_hz;
+    satDom.trackShift.textContent = `${shift >= 0 ? "+" : ""}${shift} Hz`;
+  }
+  if (satDom.trackAzEl) {
+    satDom.trackAzEl.textContent = `${tracking.azimuth_deg.toFixed(0)}\u00B0 / ${tracking.elevation_deg.toFixed(1)}\u00B0`;
+  }
+  if (satDom.trackRangeRate) {
+    satDom.trackRangeRate.textContent = `${tracking.range_rate_km_s.toFixed(2)} km/s`;
+  }
+}
+
+async function loadSatTransponders() {
+  if (!satDom.trackSelect) return;
+  try {
+    const resp = await fetch("/sat_transponders");
+    if (!resp.ok) return;
+    const transponders = await resp.json();
+    const frag = document.createDocumentFragment();
+    for (const t of transponders) {
+      const opt = document.createElement("option");
+      opt.value = `${t.norad_id}|${t.name}`;
+      opt.textContent = `${t.satellite} \u2014 ${t.name}`;
+      frag.appendChild(opt);
+    }
+    satDom.trackSelect.replaceChildren(frag);
+  } catch (e) {
+    console.error("Transponder list load failed", e);
+  }
+}
+
+satDom.trackStartBtn?.addEventListener("click", async () => {
+  const value = satDom.trackSelect?.value;
+  if (!value) return;
+  const [noradId, name] = value.split("|");
+  try {
+    await postPath(`/start_sat_tracking?norad_id=${noradId}&transponder=${encodeURIComponent(name)}`);
+  } catch (e) {
+    if (satDom.trackStatus) satDom.trackStatus.textContent = e.message;
+  }
+});
+
+satDom.trackStopBtn?.addEventListener("click", async () => {
+  try {
+    await postPath("/stop_sat_tracking");
+  } catch (e) {
+    console.error("Stop tracking failed", e);
+  }
+});
+
+loadSatTransponders();
+
+// ── Live view: antenna rotator ──────────────────────────────────────
+let _satRotTracking = false;
+
+function satRotAzEl(pos) {
+  return `${pos.azimuth_deg.toFixed(0)}\u00B0 / ${pos.elevation_deg.toFixed(0)}\u00B0`;
+}
+
+function renderSatRotator(rotator) {
+  if (!satDom.rotCard) return;
+  satDom.rotCard.style.display = rotator ? "" : "none";
+  if (!rotator) return;
+  _satRotTracking = !!rotator.tracking;
+  if (satDom.rotPos) satDom.rotPos.textContent = rotator.position ? satRotAzEl(rotator.position) : "\u2014";
+  if (satDom.rotTrackBtn) satDom.rotTrackBtn.textContent = _satRotTracking ? "Unfollow" : "Follow";
+  if (satDom.rotStatus) {
+    let text = rotator.info?.model || "";
+    if (rotator.tracking) text = `Following ${rotator.tracking}`;
+    if (rotator.target) text += ` \u2192 ${satRotAzEl(rotator.target)}`;
+    if (rotator.error) text = rotator.error;
+    satDom.rotStatus.textContent = text;
+  }
+}
+
+satDom.rotTrackBtn?.addEventListener("click", async () => {
+  try {
+    if (_satRotTracking) {
+      await postPath("/stop_rotator_tracking");
+      return;
+    }
+    const value = satDom.trackSelect?.value;
+    if (!value) return;
+    const [noradId] = value.split("|");
+    await postPath(`/start_rotator_tracking?norad_id=${noradId}`);
+  } catch (e) {
+    if (satDom.rotStatus) satDom.rotStatus.textContent = e.message;
+  }
+});
+
+satDom.rotGoBtn?.addEventListener("click", async () => {
+  const az = Number(satDom.rotAz?.value);
+  const el = Number(satDom.rotEl?.value || 0);
+  if (!Number.isFinite(az) || satDom.rotAz?.value === "") return;
+  try {
+    await postPath(`/set_rotator_position?azimuth_deg=${az}&elevation_deg=${el}`);
+  } catch (e) {
+    if (satDom.rotStatus) satDom.rotStatus.textContent = e.message;
+  }
+});
+
+satDom.rotStopBtn?.addEventListener("click", async () => {
+  try {
+    await postPath("/stop_rotator");
+  } catch (e) {
+    console.error("Rotator stop failed", e);
+  }
+});
+
 function renderSatLatestCard() {
   if (!satDom.liveLatest) return;
   if (satImageHistory.length === 0) {
@@ -494,6 +652,7 @@ function renderSatPredictions(passes, error) {
   if (satDom.predStatus) {
     let text = `${current.length} active \u00B7 ${upcoming.length} upcoming \u00B7 times in UTC`;
     if (satPredSatCount > 0) text += ` \u00B7 ${satPredSatCount} satellites tracked`;
+    if (satPredTleWarning) text += ` \u00B7 \u26A0 ${satPredTleWarning}`;
     satDom.predStatus.textContent = text;
   }
 
@@ -513,6 +672,7 @@ async function loadSatPredictions() {
     if (!resp.ok) throw new Error(`HTTP ${resp.status}`);
     const data = await resp.json();
     satPredSatCount = data.satellite_count || 0;
+    satPredTleWarning = data.tle_warning || null;
     if (data.error) {
       satPredData = [];
       renderSatPredictions([], data.error);
@@ -525,6 +685,32 @@ async function loadSatPredictions() {
   }
 }
 
+// ── Predictions: TLE upload ─────────────────────────────────────────
+satDom.predUploadBtn?.addEventListener("click", () => satDom.predUploadFile?.click());
+
+satDom.predUploadFile?.addEventListener("change", async () => {
+  const file = satDom.predUploadFile.files?.[0];
+  satDom.predUploadFile.value = "";
+  if (!file) return;
+  try {
+    const resp = await fetch("/upload_tles", { method: "POST", body: await file.text() });
+    if (!resp.ok) {
+      let message = `HTTP ${resp.status}`;
+      try {
+        message = (await resp.clone().json()).error || message;
+      } catch (_) {
+        message = (await resp.text()) || message;
+      }
+      throw new Error(message);
+    }
+    if (satDom.predStatus) {
+      satDom.predStatus.textContent = `Uploaded ${file.name} \u00B7 predictions update within 5 minutes`;
+    }
+  } catch (e) {
+    if (satDom.predStatus) satDom.predStatus.textContent = `TLE upload failed: ${e.message}`;
+  }
+});
+
 // ── Navigate to map centered on satellite image bounds ──────────────
 window.satShowOnMap = function (south, west, north, east) {
   if (typeof window.enableMapSourceFilter === "function") {
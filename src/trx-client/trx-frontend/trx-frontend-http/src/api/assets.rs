@@ -55,9 +55,15 @@ define_gz_cache!(gz_ft4_js, status::FT4_JS, "ft4.js");
 define_gz_cache!(gz_ft2_js, status::FT2_JS, "ft2.js");
 define_gz_cache!(gz_wspr_js, status::WSPR_JS, "wspr.js");
 define_gz_cache!(gz_cw_js, status::CW_JS, "cw.js");
+define_gz_cache!(gz_psk_js, status::PSK_JS, "psk.js");
+define_gz_cache!(gz_dsc_js, status::DSC_JS, "dsc.js");
+define_gz_cache!(gz_pocsag_js, status::POCSAG_JS, "pocsag.js");
+define_gz_cache!(gz_adsb_js, status::ADSB_JS, "adsb.js");
+define_gz_cache!(gz_acars_js, status::ACARS_JS, "acars.js");
 define_gz_cache!(gz_sat_js, status::SAT_JS, "sat.js");
 define_gz_cache!(gz_wefax_js, status::WEFAX_JS, "wefax.js");
 define_gz_cache!(gz_bookmarks_js, status::BOOKMARKS_JS, "bookmarks.js");
+define_gz_cache!(gz_logbook_js, status::LOGBOOK_JS, "logbook.js");
 define_gz_cache!(gz_scheduler_js, status::SCHEDULER_JS, "scheduler.js");
 define_gz_cache!(
     gz_sat_scheduler_js,
@@ -257,6 +263,36 @@ pub(crate) async fn cw_js(req: HttpRequest) -> impl Responder {
     static_asset_response(&req, "application/javascript; charset=utf-8", c)
 }
 
+#[get("/psk.js")]
+pub(crate) async fn psk_js(req: HttpRequest) -> impl Responder {
+    let c = gz_psk_js();
+    static_asset_response(&req, "application/javascript; charset=utf-8", c)
+}
+
+#[get("/dsc.js")]
+pub(crate) async fn dsc_js(req: HttpRequest) -> impl Responder {
+    let c = gz_dsc_js();
+    static_asset_response(&req, "application/javascript; charset=utf-8", c)
+}
+
+#[get("/pocsag.js")]
+pub(crate) async fn pocsag_js(req: HttpRequest) -> impl Responder {
+    let c = gz_pocsag_js();
+    static_asset_response(&req, "application/javascript; charset=utf-8", c)
+}
+
+#[get("/adsb.js")]
+pub(crate) async fn adsb_js(req: HttpRequest) -> impl Responder {
+    let c = gz_adsb_js();
+    static_asset_response(&req, "application/javascript; charset=utf-8", c)
+}
+
+#[get("/acars.js")]
+pub(crate) async fn acars_js(req: HttpRequest) -> impl Responder {
+    let c = gz_acars_js();
+    static_asset_response(&req, "application/javascript; charset=utf-8", c)
+}
+
 #[get("/sat.js")]
 pub(crate) async fn sat_js(req: HttpRequest) -> impl Responder {
     let c = gz_sat_js();
@@ -299,6 +335,12 @@ pub(crate) async fn bookmarks_js(req: HttpRequest) -> impl Responder {
     static_asset_response(&req, "application/javascript; charset=utf-8", c)
 }
 
+#[get("/logbook.js")]
+pub(crate) async fn logbook_js(req: HttpRequest) -> impl Responder {
+    let c = gz_logbook_js();
+    static_asset_response(&req, "application/javascript; charset=utf-8", c)
+}
+
 #[get("/scheduler.js")]
 pub(crate) async fn scheduler_js(req: HttpRequest) -> impl Responder {
     let c = gz_scheduler_js();
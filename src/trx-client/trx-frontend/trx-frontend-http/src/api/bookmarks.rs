@@ -2,16 +2,26 @@
 //
 // SPDX-License-Identifier: BSD-2-Clause
 
-//! Bookmark CRUD endpoints.
+//! Bookmark CRUD endpoints, import/export against rig memories and
+//! repeater directory import.
 
 use std::sync::Arc;
 
 use actix_web::Error;
 use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse};
 
-use super::{no_cache_response, request_accepts_html, require_control};
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+use trx_core::{RigCommand, RigRequest};
+
+use super::{
+    no_cache_response, read_rig_memories, request_accepts_html, request_snapshot, require_control,
+};
 use crate::server::status;
 
+/// Largest repeater list accepted by `/bookmarks/import_repeaters`.
+const MAX_REPEATER_IMPORT_BYTES: usize = 4 * 1024 * 1024;
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -34,6 +44,9 @@ pub struct BookmarkInput {
     pub mode: String,
     pub bandwidth_hz: Option<u64>,
     pub locator: Option<String>,
+    pub ctcss_hz: Option<f32>,
+    pub dcs_code: Option<u16>,
+    pub offset_hz: Option<i64>,
     pub comment: Option<String>,
     pub category: Option<String>,
     pub decoders: Option<Vec<String>>,
@@ -58,6 +71,27 @@ struct BatchMoveRequest {
     to: String,
 }
 
+#[derive(serde::Deserialize)]
+pub struct MemoryImportQuery {
+    pub scope: Option<String>,
+    pub remote: Option<String>,
+    pub first: Option<u16>,
+    pub last: Option<u16>,
+}
+
+#[derive(serde::Deserialize)]
+struct MemoryExportRequest {
+    ids: Vec<String>,
+    /// Channel for the first bookmark; the rest follow in frequency order.
+    first_channel: u16,
+}
+
+#[derive(serde::Deserialize)]
+pub struct MemoryExportQuery {
+    pub scope: Option<String>,
+    pub remote: Option<String>,
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -179,6 +213,9 @@ pub async fn create_bookmark(
         mode: body.mode.clone(),
         bandwidth_hz: body.bandwidth_hz,
         locator: normalize_bookmark_locator(body.locator.clone()),
+        ctcss_hz: body.ctcss_hz,
+        dcs_code: body.dcs_code,
+        offset_hz: body.offset_hz.filter(|&o| o != 0),
         comment: body.comment.clone().unwrap_or_default(),
         category: body.category.clone().unwrap_or_default(),
         decoders: body.decoders.clone().unwrap_or_default(),
@@ -216,6 +253,9 @@ pub async fn update_bookmark(
         mode: body.mode.clone(),
         bandwidth_hz: body.bandwidth_hz,
         locator: normalize_bookmark_locator(body.locator.clone()),
+        ctcss_hz: body.ctcss_hz,
+        dcs_code: body.dcs_code,
+        offset_hz: body.offset_hz.filter(|&o| o != 0),
         comment: body.comment.clone().unwrap_or_default(),
         category: body.category.clone().unwrap_or_default(),
         decoders: body.decoders.clone().unwrap_or_default(),
@@ -285,3 +325,107 @@ pub async fn batch_move_bookmarks(
     }
     Ok(HttpResponse::Ok().json(serde_json::json!({ "moved": moved })))
 }
+
+/// Copy filled rig memory channels into the bookmark store.  Channels whose
+/// frequency already has a bookmark are skipped.
+#[post("/bookmarks/import_memories")]
+pub async fn import_memories(
+    req: HttpRequest,
+    query: web::Query<MemoryImportQuery>,
+    store_map: web::Data<Arc<crate::server::bookmarks::BookmarkStoreMap>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+    auth_state: web::Data<crate::server::auth::AuthState>,
+) -> Result<HttpResponse, Error> {
+    require_control(&req, &auth_state)?;
+    let q = query.into_inner();
+    let store = resolve_bookmark_store(q.scope.as_deref(), store_map.get_ref());
+    let memories = read_rig_memories(
+        &rig_tx,
+        q.remote,
+        q.first.unwrap_or(1),
+        q.last.unwrap_or(u16::MAX),
+    )
+    .await?;
+    let mut imported = 0usize;
+    let mut skipped = 0usize;
+    for memory in &memories {
+        if store.freq_taken(memory.freq.hz, None) {
+            skipped += 1;
+            continue;
+        }
+        let bm = crate::server::bookmarks::Bookmark::from_memory(gen_bookmark_id(), memory);
+        if store.insert(&bm) {
+            imported += 1;
+        }
+    }
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "imported": imported, "skipped": skipped })))
+}
+
+/// Add repeaters from an uploaded CHIRP or RepeaterBook CSV file.
+/// Repeaters whose frequency already has a bookmark are skipped.
+#[post("/bookmarks/import_repeaters")]
+pub async fn import_repeaters(
+    req: HttpRequest,
+    mut payload: web::Payload,
+    query: web::Query<BookmarkScopeQuery>,
+    store_map: web::Data<Arc<crate::server::bookmarks::BookmarkStoreMap>>,
+    auth_state: web::Data<crate::server::auth::AuthState>,
+) -> Result<HttpResponse, Error> {
+    require_control(&req, &auth_state)?;
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk?;
+        if body.len() + chunk.len() > MAX_REPEATER_IMPORT_BYTES {
+            return Ok(HttpResponse::PayloadTooLarge().body(format!(
+                "repeater import exceeds {} MiB",
+                MAX_REPEATER_IMPORT_BYTES / (1024 * 1024)
+            )));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    let text = String::from_utf8_lossy(&body);
+    let parsed = crate::server::bookmarks::parse_repeater_csv(&text)
+        .map_err(actix_web::error::ErrorBadRequest)?;
+    let store = resolve_bookmark_store(query.scope.as_deref(), store_map.get_ref());
+    let mut imported = 0usize;
+    let mut skipped = parsed.skipped_rows;
+    for mut bm in parsed.bookmarks {
+        if store.freq_taken(bm.freq_hz, None) {
+            skipped += 1;
+            continue;
+        }
+        bm.id = gen_bookmark_id();
+        if store.insert(&bm) {
+            imported += 1;
+        }
+    }
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "imported": imported, "skipped": skipped })))
+}
+
+/// Write bookmarks to consecutive rig memory channels.
+#[post("/bookmarks/export_memories")]
+pub async fn export_memories(
+    req: HttpRequest,
+    query: web::Query<MemoryExportQuery>,
+    body: web::Json<MemoryExportRequest>,
+    store_map: web::Data<Arc<crate::server::bookmarks::BookmarkStoreMap>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+    auth_state: web::Data<crate::server::auth::AuthState>,
+) -> Result<HttpResponse, Error> {
+    require_control(&req, &auth_state)?;
+    let q = query.into_inner();
+    let store = resolve_bookmark_store(q.scope.as_deref(), store_map.get_ref());
+    let mut bookmarks: Vec<_> = body.ids.iter().filter_map(|id| store.get(id)).collect();
+    bookmarks.sort_by_key(|bm| bm.freq_hz);
+    let mut written = 0usize;
+    for (bm, channel) in bookmarks.iter().zip(body.first_channel.max(1)..) {
+        request_snapshot(
+            &rig_tx,
+            RigCommand::WriteMemory(bm.to_memory(channel)),
+            q.remote.clone(),
+        )
+        .await?;
+        written += 1;
+    }
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "written": written })))
+}
@@ -52,9 +52,14 @@ pub async fn decoder_registry() -> impl Responder {
 struct DecodeHistoryPayload {
     ais: Vec<trx_core::decode::AisMessage>,
     vdes: Vec<trx_core::decode::VdesMessage>,
+    dsc: Vec<trx_core::decode::DscMessage>,
+    pocsag: Vec<trx_core::decode::PocsagMessage>,
+    adsb: Vec<trx_core::decode::AdsbMessage>,
+    acars: Vec<trx_core::decode::AcarsMessage>,
     aprs: Vec<trx_core::decode::AprsPacket>,
     hf_aprs: Vec<trx_core::decode::AprsPacket>,
     cw: Vec<trx_core::decode::CwEvent>,
+    psk: Vec<trx_core::decode::PskEvent>,
     ft8: Vec<trx_core::decode::Ft8Message>,
     ft4: Vec<trx_core::decode::Ft8Message>,
     ft2: Vec<trx_core::decode::Ft8Message>,
@@ -66,9 +71,14 @@ impl DecodeHistoryPayload {
     fn total_messages(&self) -> usize {
         self.ais.len()
             + self.vdes.len()
+            + self.dsc.len()
+            + self.pocsag.len()
+            + self.adsb.len()
+            + self.acars.len()
             + self.aprs.len()
             + self.hf_aprs.len()
             + self.cw.len()
+            + self.psk.len()
             + self.ft8.len()
             + self.ft4.len()
             + self.ft2.len()
@@ -85,9 +95,14 @@ fn collect_decode_history(
     DecodeHistoryPayload {
         ais: crate::server::audio::snapshot_ais_history(context, rig_filter),
         vdes: crate::server::audio::snapshot_vdes_history(context, rig_filter),
+        dsc: crate::server::audio::snapshot_dsc_history(context, rig_filter),
+        pocsag: crate::server::audio::snapshot_pocsag_history(context, rig_filter),
+        adsb: crate::server::audio::snapshot_adsb_history(context, rig_filter),
+        acars: crate::server::audio::snapshot_acars_history(context, rig_filter),
         aprs: crate::server::audio::snapshot_aprs_history(context, rig_filter),
         hf_aprs: crate::server::audio::snapshot_hf_aprs_history(context, rig_filter),
         cw: crate::server::audio::snapshot_cw_history(context, rig_filter),
+        psk: crate::server::audio::snapshot_psk_history(context, rig_filter),
         ft8: crate::server::audio::snapshot_ft8_history(context, rig_filter),
         ft4: crate::server::audio::snapshot_ft4_history(context, rig_filter),
         ft2: crate::server::audio::snapshot_ft2_history(context, rig_filter),
@@ -349,6 +364,139 @@ pub async fn set_cw_tone(
     send_command(&rig_tx, RigCommand::SetCwToneHz(q.tone_hz), q.remote).await
 }
 
+#[post("/toggle_dsc_decode")]
+pub async fn toggle_dsc_decode(
+    query: web::Query<RemoteQuery>,
+    state: web::Data<watch::Receiver<RigState>>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    let rig_state = resolve_rig_state(q.remote.as_deref(), &context, state.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::SetDscDecodeEnabled(!rig_state.decoders.dsc_decode_enabled),
+        q.remote,
+    )
+    .await
+}
+
+#[post("/toggle_pocsag_decode")]
+pub async fn toggle_pocsag_decode(
+    query: web::Query<RemoteQuery>,
+    state: web::Data<watch::Receiver<RigState>>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    let rig_state = resolve_rig_state(q.remote.as_deref(), &context, state.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::SetPocsagDecodeEnabled(!rig_state.decoders.pocsag_decode_enabled),
+        q.remote,
+    )
+    .await
+}
+
+#[post("/toggle_acars_decode")]
+pub async fn toggle_acars_decode(
+    query: web::Query<RemoteQuery>,
+    state: web::Data<watch::Receiver<RigState>>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    let rig_state = resolve_rig_state(q.remote.as_deref(), &context, state.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::SetAcarsDecodeEnabled(!rig_state.decoders.acars_decode_enabled),
+        q.remote,
+    )
+    .await
+}
+
+#[post("/toggle_adsb_decode")]
+pub async fn toggle_adsb_decode(
+    query: web::Query<RemoteQuery>,
+    state: web::Data<watch::Receiver<RigState>>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    let rig_state = resolve_rig_state(q.remote.as_deref(), &context, state.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::SetAdsbDecodeEnabled(!rig_state.decoders.adsb_decode_enabled),
+        q.remote,
+    )
+    .await
+}
+
+#[post("/toggle_psk_decode")]
+pub async fn toggle_psk_decode(
+    query: web::Query<RemoteQuery>,
+    state: web::Data<watch::Receiver<RigState>>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    let rig_state = resolve_rig_state(q.remote.as_deref(), &context, state.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::SetPskDecodeEnabled(!rig_state.decoders.psk_decode_enabled),
+        q.remote,
+    )
+    .await
+}
+
+#[derive(serde::Deserialize)]
+pub struct PskModeQuery {
+    pub mode: String,
+    pub remote: Option<String>,
+}
+
+#[post("/set_psk_mode")]
+pub async fn set_psk_mode(
+    query: web::Query<PskModeQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    let Some(mode) = trx_core::decode::PskMode::parse(&q.mode) else {
+        return Ok(HttpResponse::BadRequest().body(format!("unknown PSK mode '{}'", q.mode)));
+    };
+    send_command(&rig_tx, RigCommand::SetPskMode(mode), q.remote).await
+}
+
+#[derive(serde::Deserialize)]
+pub struct PskFreqQuery {
+    pub freq_hz: u32,
+    pub remote: Option<String>,
+}
+
+#[post("/set_psk_freq")]
+pub async fn set_psk_freq(
+    query: web::Query<PskFreqQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::SetPskFreqHz(q.freq_hz), q.remote).await
+}
+
+#[derive(serde::Deserialize)]
+pub struct PskWaterfallQuery {
+    pub enabled: bool,
+    pub remote: Option<String>,
+}
+
+#[post("/set_psk_waterfall")]
+pub async fn set_psk_waterfall(
+    query: web::Query<PskWaterfallQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::SetPskWaterfall(q.enabled), q.remote).await
+}
+
 #[post("/toggle_ft8_decode")]
 pub async fn toggle_ft8_decode(
     query: web::Query<RemoteQuery>,
@@ -587,6 +735,81 @@ pub async fn clear_vdes_decode(
     Ok(HttpResponse::Ok().finish())
 }
 
+#[post("/clear_dsc_decode")]
+pub async fn clear_dsc_decode(
+    query: web::Query<RemoteQuery>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    crate::server::audio::clear_dsc_history(context.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::ResetDscDecoder,
+        query.into_inner().remote,
+    )
+    .await
+}
+
+#[post("/clear_pocsag_decode")]
+pub async fn clear_pocsag_decode(
+    query: web::Query<RemoteQuery>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    crate::server::audio::clear_pocsag_history(context.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::ResetPocsagDecoder,
+        query.into_inner().remote,
+    )
+    .await
+}
+
+#[post("/clear_acars_decode")]
+pub async fn clear_acars_decode(
+    query: web::Query<RemoteQuery>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    crate::server::audio::clear_acars_history(context.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::ResetAcarsDecoder,
+        query.into_inner().remote,
+    )
+    .await
+}
+
+#[post("/clear_adsb_decode")]
+pub async fn clear_adsb_decode(
+    query: web::Query<RemoteQuery>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    crate::server::audio::clear_adsb_history(context.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::ResetAdsbDecoder,
+        query.into_inner().remote,
+    )
+    .await
+}
+
+#[post("/clear_psk_decode")]
+pub async fn clear_psk_decode(
+    query: web::Query<RemoteQuery>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    crate::server::audio::clear_psk_history(context.get_ref());
+    send_command(
+        &rig_tx,
+        RigCommand::ResetPskDecoder,
+        query.into_inner().remote,
+    )
+    .await
+}
+
 #[post("/clear_cw_decode")]
 pub async fn clear_cw_decode(
     query: web::Query<RemoteQuery>,
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! DX cluster spot endpoints.
+
+use std::sync::Arc;
+
+use actix_web::{get, web, HttpResponse, Responder};
+
+use trx_frontend::FrontendRuntimeContext;
+
+#[derive(serde::Serialize)]
+struct DxSpotsPayload {
+    max_age_ms: i64,
+    spots: Vec<trx_core::dxspot::DxSpot>,
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+fn current_spots(context: &FrontendRuntimeContext) -> DxSpotsPayload {
+    match context.dx_cluster.spots.write() {
+        Ok(mut table) => {
+            table.prune(now_ms());
+            DxSpotsPayload {
+                max_age_ms: table.max_age_ms(),
+                spots: table.spots(),
+            }
+        }
+        Err(_) => DxSpotsPayload {
+            max_age_ms: 0,
+            spots: Vec::new(),
+        },
+    }
+}
+
+/// Current spot list for the SSE prefix burst; `None` when there is no feed
+/// and nothing to show.
+pub(super) fn dx_spots_json(context: &FrontendRuntimeContext) -> Option<String> {
+    let payload = current_spots(context);
+    if context.dx_cluster.feed.is_none() && payload.spots.is_empty() {
+        return None;
+    }
+    serde_json::to_string(&payload).ok()
+}
+
+#[get("/dx_spots")]
+pub async fn dx_spots(context: web::Data<Arc<FrontendRuntimeContext>>) -> impl Responder {
+    HttpResponse::Ok().json(current_spots(context.get_ref()))
+}
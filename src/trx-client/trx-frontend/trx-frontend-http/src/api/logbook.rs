@@ -0,0 +1,319 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! QSO logbook endpoints: CRUD, ADIF import/export and worked-before lookups.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use actix_web::http::header;
+use actix_web::Error;
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse};
+use futures_util::StreamExt;
+
+use trx_frontend::FrontendRuntimeContext;
+use trx_protocol::codec::mode_to_string;
+
+use super::{no_cache_response, request_accepts_html, require_control};
+use crate::server::logbook::{self, LogbookStore, Qso};
+use crate::server::status;
+
+/// Largest ADIF file accepted by `/logbook/import`.
+const MAX_ADIF_IMPORT_BYTES: usize = 16 * 1024 * 1024;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+#[derive(serde::Deserialize)]
+pub struct LogbookQuery {
+    pub call: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct LogbookCreateQuery {
+    pub remote: Option<String>,
+    /// Log the QSO even when it duplicates an existing one.
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(serde::Deserialize)]
+pub struct WorkedQuery {
+    /// Comma-separated callsigns; all worked callsigns when absent.
+    pub calls: Option<String>,
+}
+
+/// Manual QSO entry. Frequency, mode, band, time and own locator default to
+/// the current rig state when omitted.
+#[derive(serde::Deserialize)]
+pub struct QsoInput {
+    pub call: String,
+    pub ts_ms: Option<i64>,
+    pub freq_hz: Option<u64>,
+    pub mode: Option<String>,
+    pub band: Option<String>,
+    pub rst_sent: Option<String>,
+    pub rst_rcvd: Option<String>,
+    pub gridsquare: Option<String>,
+    pub my_gridsquare: Option<String>,
+    pub comment: Option<String>,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// Build a QSO from user input, filling gaps from the selected rig.
+fn qso_from_input(
+    id: String,
+    input: QsoInput,
+    remote: Option<&str>,
+    context: &FrontendRuntimeContext,
+) -> Result<Qso, Error> {
+    let call = logbook::normalize_call(&input.call);
+    if call.is_empty() {
+        return Err(actix_web::error::ErrorBadRequest("call must not be empty"));
+    }
+    let snapshot = logbook::rig_snapshot(context, remote);
+    let freq_hz = input
+        .freq_hz
+        .or_else(|| snapshot.as_ref().map(|s| s.status.freq.hz))
+        .unwrap_or(0);
+    let mode = non_empty(input.mode)
+        .or_else(|| {
+            snapshot
+                .as_ref()
+                .map(|s| mode_to_string(&s.status.mode).into_owned())
+        })
+        .unwrap_or_default()
+        .to_uppercase();
+    let band = non_empty(input.band)
+        .map(|b| b.to_lowercase())
+        .or_else(|| logbook::band_for(snapshot.as_ref(), freq_hz));
+    let my_gridsquare = logbook::normalize_locator(input.my_gridsquare).or_else(|| {
+        snapshot
+            .as_ref()
+            .and_then(|s| Some((s.server_latitude?, s.server_longitude?)))
+            .and_then(|(lat, lon)| logbook::maidenhead(lat, lon))
+    });
+    Ok(Qso {
+        id,
+        call,
+        ts_ms: input.ts_ms.unwrap_or_else(now_ms),
+        freq_hz,
+        mode,
+        band,
+        rst_sent: non_empty(input.rst_sent),
+        rst_rcvd: non_empty(input.rst_rcvd),
+        gridsquare: logbook::normalize_locator(input.gridsquare),
+        my_gridsquare,
+        station_callsign: context
+            .owner
+            .callsign
+            .as_deref()
+            .map(logbook::normalize_call),
+        comment: input.comment.unwrap_or_default(),
+        source: "manual".to_string(),
+    })
+}
+
+fn duplicate_conflict(existing: &Qso) -> Error {
+    actix_web::error::ErrorConflict(format!(
+        "duplicate of QSO with {} on {} {}",
+        existing.call,
+        existing.band.as_deref().unwrap_or("?"),
+        existing.mode
+    ))
+}
+
+// ============================================================================
+// Endpoints
+// ============================================================================
+
+#[get("/logbook")]
+pub async fn list_logbook(
+    req: HttpRequest,
+    store: web::Data<Arc<LogbookStore>>,
+    query: web::Query<LogbookQuery>,
+) -> Result<HttpResponse, Error> {
+    if request_accepts_html(&req) {
+        return Ok(no_cache_response(
+            "text/html; charset=utf-8",
+            status::index_html(),
+        ));
+    }
+    let mut list = store.list();
+    if let Some(call) = query.call.as_deref().filter(|c| !c.trim().is_empty()) {
+        let needle = logbook::normalize_call(call);
+        list.retain(|q| q.call.contains(&needle));
+    }
+    if let Some(limit) = query.limit {
+        list.truncate(limit);
+    }
+    Ok(HttpResponse::Ok().json(list))
+}
+
+#[post("/logbook")]
+pub async fn create_qso(
+    req: HttpRequest,
+    store: web::Data<Arc<LogbookStore>>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    query: web::Query<LogbookCreateQuery>,
+    body: web::Json<QsoInput>,
+    auth_state: web::Data<crate::server::auth::AuthState>,
+) -> Result<HttpResponse, Error> {
+    require_control(&req, &auth_state)?;
+    let qso = qso_from_input(
+        logbook::gen_qso_id(),
+        body.into_inner(),
+        query.remote.as_deref(),
+        context.get_ref(),
+    )?;
+    if !query.force {
+        if let Some(existing) = store.find_duplicate(&qso) {
+            return Err(duplicate_conflict(&existing));
+        }
+    }
+    if store.insert(&qso) {
+        Ok(HttpResponse::Created().json(qso))
+    } else {
+        Err(actix_web::error::ErrorInternalServerError(
+            "failed to save QSO",
+        ))
+    }
+}
+
+#[put("/logbook/{id}")]
+pub async fn update_qso(
+    req: HttpRequest,
+    path: web::Path<String>,
+    store: web::Data<Arc<LogbookStore>>,
+    context: web::Data<Arc<FrontendRuntimeContext>>,
+    query: web::Query<LogbookCreateQuery>,
+    body: web::Json<QsoInput>,
+    auth_state: web::Data<crate::server::auth::AuthState>,
+) -> Result<HttpResponse, Error> {
+    require_control(&req, &auth_state)?;
+    let id = path.into_inner();
+    let Some(existing) = store.get(&id) else {
+        return Err(actix_web::error::ErrorNotFound("QSO not found"));
+    };
+    let mut qso = qso_from_input(
+        id.clone(),
+        body.into_inner(),
+        query.remote.as_deref(),
+        context.get_ref(),
+    )?;
+    qso.station_callsign = existing.station_callsign;
+    qso.source = existing.source;
+    if !query.force {
+        if let Some(dupe) = store.find_duplicate(&qso) {
+            return Err(duplicate_conflict(&dupe));
+        }
+    }
+    if store.upsert(&id, &qso) {
+        Ok(HttpResponse::Ok().json(qso))
+    } else {
+        Err(actix_web::error::ErrorNotFound("QSO not found"))
+    }
+}
+
+#[delete("/logbook/{id}")]
+pub async fn delete_qso(
+    req: HttpRequest,
+    path: web::Path<String>,
+    store: web::Data<Arc<LogbookStore>>,
+    auth_state: web::Data<crate::server::auth::AuthState>,
+) -> Result<HttpResponse, Error> {
+    require_control(&req, &auth_state)?;
+    if store.remove(&path.into_inner()) {
+        Ok(HttpResponse::Ok().json(serde_json::json!({ "deleted": true })))
+    } else {
+        Err(actix_web::error::ErrorNotFound("QSO not found"))
+    }
+}
+
+/// Look up worked-before summaries keyed by callsign.
+#[get("/logbook/worked")]
+pub async fn worked_before(
+    store: web::Data<Arc<LogbookStore>>,
+    query: web::Query<WorkedQuery>,
+) -> HttpResponse {
+    let all = store.worked_all();
+    let result: HashMap<String, logbook::WorkedSummary> = match query.calls.as_deref() {
+        Some(calls) => calls
+            .split(',')
+            .map(logbook::normalize_call)
+            .filter_map(|call| all.get(&call).cloned().map(|w| (call, w)))
+            .collect(),
+        None => all,
+    };
+    HttpResponse::Ok().json(result)
+}
+
+/// Download the whole log as an ADIF 3 file.
+#[get("/logbook/export.adi")]
+pub async fn export_adif(store: web::Data<Arc<LogbookStore>>) -> HttpResponse {
+    let mut qsos = store.list();
+    qsos.reverse();
+    HttpResponse::Ok()
+        .insert_header((header::CONTENT_TYPE, "text/plain; charset=utf-8"))
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"trx-rs-logbook.adi\"",
+        ))
+        .body(logbook::to_adif(&qsos))
+}
+
+/// Merge an uploaded ADIF file into the log, skipping duplicates.
+#[post("/logbook/import")]
+pub async fn import_adif(
+    req: HttpRequest,
+    mut payload: web::Payload,
+    store: web::Data<Arc<LogbookStore>>,
+    auth_state: web::Data<crate::server::auth::AuthState>,
+) -> Result<HttpResponse, Error> {
+    require_control(&req, &auth_state)?;
+    let mut body = web::BytesMut::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk?;
+        if body.len() + chunk.len() > MAX_ADIF_IMPORT_BYTES {
+            return Ok(HttpResponse::PayloadTooLarge().body(format!(
+                "ADIF import exceeds {} MiB",
+                MAX_ADIF_IMPORT_BYTES / (1024 * 1024)
+            )));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    let text = String::from_utf8_lossy(&body);
+    let parsed = logbook::parse_adif(&text).map_err(actix_web::error::ErrorBadRequest)?;
+    let mut imported = 0usize;
+    let mut duplicates = 0usize;
+    for qso in parsed {
+        if store.find_duplicate(&qso).is_some() {
+            duplicates += 1;
+        } else if store.insert(&qso) {
+            imported += 1;
+        }
+    }
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "imported": imported,
+        "duplicates": duplicates,
+    })))
+}
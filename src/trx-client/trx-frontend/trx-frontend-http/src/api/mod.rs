@@ -7,6 +7,8 @@
 mod assets;
 mod bookmarks;
 mod decoder;
+mod dxcluster;
+mod logbook;
 pub mod recorder;
 mod rig;
 mod sse;
@@ -26,6 +28,7 @@ use tokio::sync::{mpsc, oneshot, watch};
 use tokio::time::Duration;
 use uuid::Uuid;
 
+use trx_core::rig::memory::{RigMemory, MEMORY_PAGE_SIZE};
 use trx_core::rig::{RigAccessMethod, RigCapabilities, RigInfo};
 use trx_core::{RigCommand, RigRequest, RigSnapshot, RigState};
 use trx_frontend::FrontendRuntimeContext;
@@ -413,6 +416,7 @@ async fn send_command(
             state: Some(snapshot),
             rigs: None,
             sat_passes: None,
+            denied: None,
             error: None,
         })),
         Ok(Err(err)) => Ok(HttpResponse::BadRequest().json(ClientResponse {
@@ -422,6 +426,7 @@ async fn send_command(
             state: None,
             rigs: None,
             sat_passes: None,
+            denied: None,
             error: Some(err.message),
         })),
         Err(e) => Err(actix_web::error::ErrorInternalServerError(format!(
@@ -435,12 +440,23 @@ async fn send_command_to_rig(
     remote: &str,
     cmd: RigCommand,
 ) -> Result<(), actix_web::Error> {
+    request_snapshot(rig_tx, cmd, Some(remote.to_string()))
+        .await
+        .map(|_| ())
+}
+
+/// Send `cmd` and return the snapshot the rig answers with.
+async fn request_snapshot(
+    rig_tx: &mpsc::Sender<RigRequest>,
+    cmd: RigCommand,
+    remote: Option<String>,
+) -> Result<RigSnapshot, actix_web::Error> {
     let (resp_tx, resp_rx) = oneshot::channel();
     rig_tx
         .send(RigRequest {
             cmd,
             respond_to: resp_tx,
-            rig_id_override: Some(remote.to_string()),
+            rig_id_override: remote,
         })
         .await
         .map_err(|e| {
@@ -452,7 +468,7 @@ async fn send_command_to_rig(
         .map_err(|_| actix_web::error::ErrorGatewayTimeout("rig response timeout"))?;
 
     match resp {
-        Ok(Ok(_)) => Ok(()),
+        Ok(Ok(snapshot)) => Ok(snapshot),
         Ok(Err(err)) => Err(actix_web::error::ErrorBadRequest(err.message)),
         Err(e) => Err(actix_web::error::ErrorInternalServerError(format!(
             "rig response channel error: {e:?}"
@@ -460,6 +476,38 @@ async fn send_command_to_rig(
     }
 }
 
+/// Read filled memory channels `first..=last` page by page, stopping at the
+/// rig's last channel.
+async fn read_rig_memories(
+    rig_tx: &mpsc::Sender<RigRequest>,
+    remote: Option<String>,
+    first: u16,
+    last: u16,
+) -> Result<Vec<RigMemory>, actix_web::Error> {
+    let mut memories = Vec::new();
+    let mut last = last;
+    let mut channel = first.max(1);
+    while channel <= last {
+        let count = (last - channel).min(MEMORY_PAGE_SIZE - 1) + 1;
+        let snapshot = request_snapshot(
+            rig_tx,
+            RigCommand::ReadMemories {
+                first: channel,
+                count,
+            },
+            remote.clone(),
+        )
+        .await?;
+        last = last.min(snapshot.info.capabilities.memory_channels);
+        memories.extend(snapshot.memories);
+        let Some(next) = channel.checked_add(count) else {
+            break;
+        };
+        channel = next;
+    }
+    Ok(memories)
+}
+
 async fn wait_for_view(mut rx: watch::Receiver<RigState>) -> Result<RigSnapshot, actix_web::Error> {
     if let Some(view) = rx.borrow().snapshot() {
         return Ok(view);
@@ -496,6 +544,15 @@ async fn wait_for_view(mut rx: watch::Receiver<RigState>) -> Result<RigSnapshot,
         cw_auto: state.cw_auto,
         cw_wpm: state.cw_wpm,
         cw_tone_hz: state.cw_tone_hz,
+        psk_mode: state.psk_mode,
+        psk_freq_hz: state.psk_freq_hz,
+        psk_waterfall: state.psk_waterfall,
+        sat_tracking: state.sat_tracking,
+        rotator: state.rotator,
+        cw_keyer: state.cw_keyer,
+        memories: state.memories,
+        repeater: state.control.repeater(),
+        clarifier: state.control.clarifier(),
         filter: state.filter.clone(),
         spectrum: None,
         vchan_rds: None,
@@ -526,6 +583,7 @@ impl From<RigInfoPlaceholder> for RigInfo {
                 attenuator: false,
                 preamp: false,
                 rit: false,
+                xit: false,
                 rpt: false,
                 split: false,
                 tx: false,
@@ -533,6 +591,11 @@ impl From<RigInfoPlaceholder> for RigInfo {
                 vfo_switch: false,
                 filter_controls: false,
                 signal_meter: false,
+                memory_channels: 0,
+                memory_write: false,
+                band_stack: false,
+                ctcss_decode: false,
+                dcs: false,
             },
             access: RigAccessMethod::Serial {
                 path: "".into(),
@@ -571,11 +634,34 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .service(rig::set_sam_stereo_width)
         .service(rig::set_sam_carrier_sync)
         .service(rig::sat_passes)
+        .service(rig::upload_tles)
+        .service(rig::sat_transponders)
+        .service(rig::start_sat_tracking)
+        .service(rig::stop_sat_tracking)
+        .service(rig::set_rotator_position)
+        .service(rig::stop_rotator)
+        .service(rig::start_rotator_tracking)
+        .service(rig::stop_rotator_tracking)
+        .service(rig::send_cw)
+        .service(rig::abort_cw)
+        .service(rig::set_cw_keyer)
+        .service(rig::set_cw_serial)
+        .service(rig::rig_memories)
+        .service(rig::write_memory)
+        .service(rig::recall_memory)
+        .service(rig::recall_band_stack)
+        .service(rig::set_repeater_offset)
+        .service(rig::set_repeater_reverse)
+        .service(rig::set_ctcss)
+        .service(rig::set_dcs)
+        .service(rig::set_rit)
+        .service(rig::set_xit)
         // SSE streams
         .service(sse::events)
         .service(sse::spectrum)
         .service(sse::meter)
         // Decoder endpoints
+        .service(dxcluster::dx_spots)
         .service(decoder::decoder_registry)
         .service(decoder::decode_history)
         .service(decoder::decode_events)
@@ -585,6 +671,14 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .service(decoder::set_cw_auto)
         .service(decoder::set_cw_wpm)
         .service(decoder::set_cw_tone)
+        .service(decoder::toggle_psk_decode)
+        .service(decoder::toggle_dsc_decode)
+        .service(decoder::toggle_pocsag_decode)
+        .service(decoder::toggle_adsb_decode)
+        .service(decoder::toggle_acars_decode)
+        .service(decoder::set_psk_mode)
+        .service(decoder::set_psk_freq)
+        .service(decoder::set_psk_waterfall)
         .service(decoder::toggle_ft8_decode)
         .service(decoder::toggle_ft4_decode)
         .service(decoder::toggle_ft2_decode)
@@ -596,6 +690,11 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .service(decoder::clear_aprs_decode)
         .service(decoder::clear_hf_aprs_decode)
         .service(decoder::clear_cw_decode)
+        .service(decoder::clear_psk_decode)
+        .service(decoder::clear_dsc_decode)
+        .service(decoder::clear_pocsag_decode)
+        .service(decoder::clear_adsb_decode)
+        .service(decoder::clear_acars_decode)
         .service(decoder::clear_ft8_decode)
         .service(decoder::clear_ft4_decode)
         .service(decoder::clear_ft2_decode)
@@ -609,6 +708,17 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .service(bookmarks::delete_bookmark)
         .service(bookmarks::batch_delete_bookmarks)
         .service(bookmarks::batch_move_bookmarks)
+        .service(bookmarks::import_memories)
+        .service(bookmarks::export_memories)
+        .service(bookmarks::import_repeaters)
+        // Logbook
+        .service(logbook::list_logbook)
+        .service(logbook::worked_before)
+        .service(logbook::export_adif)
+        .service(logbook::import_adif)
+        .service(logbook::create_qso)
+        .service(logbook::update_qso)
+        .service(logbook::delete_qso)
         // Scheduler
         .service(crate::server::scheduler::get_scheduler)
         .service(crate::server::scheduler::put_scheduler)
@@ -657,10 +767,16 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         .service(assets::ft2_js)
         .service(assets::wspr_js)
         .service(assets::cw_js)
+        .service(assets::psk_js)
+        .service(assets::dsc_js)
+        .service(assets::pocsag_js)
+        .service(assets::adsb_js)
+        .service(assets::acars_js)
         .service(assets::sat_js)
         .service(assets::wefax_js)
         .service(assets::wefax_image)
         .service(assets::bookmarks_js)
+        .service(assets::logbook_js)
         .service(assets::scheduler_js)
         .service(assets::sat_scheduler_js)
         .service(assets::background_decode_js)
@@ -720,6 +836,7 @@ mod tests {
                     attenuator: false,
                     preamp: false,
                     rit: false,
+                    xit: false,
                     rpt: false,
                     split: false,
                     tx: false,
@@ -727,6 +844,11 @@ mod tests {
                     vfo_switch: false,
                     filter_controls: false,
                     signal_meter: false,
+                    memory_channels: 0,
+                    memory_write: false,
+                    band_stack: false,
+                    ctcss_decode: false,
+                    dcs: false,
                 },
                 access: RigAccessMethod::Serial {
                     path: "/dev/null".into(),
@@ -747,6 +869,13 @@ mod tests {
             cw_auto: false,
             cw_wpm: 20,
             cw_tone_hz: 700,
+            psk_mode: trx_core::decode::PskMode::default(),
+            psk_freq_hz: 1000,
+            psk_waterfall: false,
+            sat_tracking: None,
+            rotator: None,
+            cw_keyer: None,
+            memories: Vec::new(),
             filter: None,
             spectrum: None,
             vchan_rds: None,
@@ -771,6 +900,7 @@ mod tests {
             vchan: trx_frontend::VChanContext::default(),
             spectrum: trx_frontend::SpectrumContext::default(),
             rig_audio: trx_frontend::PerRigAudioContext::default(),
+            dx_cluster: trx_frontend::DxClusterContext::default(),
             sse_clients: Arc::new(AtomicUsize::new(0)),
             rigctl_clients: Arc::new(AtomicUsize::new(0)),
             rigctl_listen_addr: Arc::new(std::sync::Mutex::new(None)),
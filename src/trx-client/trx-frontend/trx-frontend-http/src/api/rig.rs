@@ -13,6 +13,7 @@ use tokio::sync::{mpsc, watch};
 use uuid::Uuid;
 
 use trx_core::radio::freq::Freq;
+use trx_core::rig::memory::RigMemory;
 use trx_core::rig::state::WfmDenoiseLevel;
 use trx_core::{RigCommand, RigRequest, RigState};
 use trx_frontend::{FrontendRuntimeContext, RemoteRigEntry};
@@ -21,8 +22,8 @@ use trx_protocol::parse_mode;
 use crate::server::vchan::ClientChannelManager;
 
 use super::{
-    active_rig_id_from_context, frontend_meta_from_context, send_command, wait_for_view,
-    RemoteQuery, SessionRigManager, SnapshotWithMeta, StatusQuery,
+    active_rig_id_from_context, frontend_meta_from_context, read_rig_memories, send_command,
+    wait_for_view, RemoteQuery, SessionRigManager, SnapshotWithMeta, StatusQuery,
 };
 
 // ============================================================================
@@ -499,6 +500,224 @@ struct SatPassesResponse {
     satellite_count: usize,
     /// Source of the TLE data used: "celestrak" or "unavailable".
     tle_source: trx_core::geo::TleSource,
+    /// Set when some elements are older than the server's age threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tle_warning: Option<String>,
+}
+
+/// Largest TLE upload that fits in one JSON-TCP command line.
+const MAX_TLE_UPLOAD_BYTES: usize = 200 * 1024;
+
+/// Merge an uploaded TLE set (2- or 3-line text) into the server catalogue.
+#[post("/upload_tles")]
+pub async fn upload_tles(
+    body: String,
+    query: web::Query<RemoteQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    if body.len() > MAX_TLE_UPLOAD_BYTES {
+        return Ok(HttpResponse::PayloadTooLarge().body(format!(
+            "TLE upload exceeds {} KiB; use [tle].files on the server for larger catalogues",
+            MAX_TLE_UPLOAD_BYTES / 1024
+        )));
+    }
+    send_command(
+        &rig_tx,
+        RigCommand::LoadTles(body),
+        query.into_inner().remote,
+    )
+    .await
+}
+
+/// Return the built-in satellite transponder database.
+#[get("/sat_transponders")]
+pub async fn sat_transponders() -> impl Responder {
+    web::Json(trx_core::doppler::builtin_transponders())
+}
+
+#[derive(serde::Deserialize)]
+pub struct SatTrackingQuery {
+    pub norad_id: u32,
+    pub transponder: Option<String>,
+    pub downlink_hz: Option<u64>,
+    pub remote: Option<String>,
+}
+
+#[post("/start_sat_tracking")]
+pub async fn start_sat_tracking(
+    query: web::Query<SatTrackingQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::StartSatTracking {
+            norad_id: q.norad_id,
+            transponder: q.transponder,
+            downlink_hz: q.downlink_hz,
+        },
+        q.remote,
+    )
+    .await
+}
+
+#[post("/stop_sat_tracking")]
+pub async fn stop_sat_tracking(
+    query: web::Query<RemoteQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    send_command(
+        &rig_tx,
+        RigCommand::StopSatTracking,
+        query.into_inner().remote,
+    )
+    .await
+}
+
+#[derive(serde::Deserialize)]
+pub struct RotatorPositionQuery {
+    pub azimuth_deg: f64,
+    #[serde(default)]
+    pub elevation_deg: f64,
+    pub remote: Option<String>,
+}
+
+#[post("/set_rotator_position")]
+pub async fn set_rotator_position(
+    query: web::Query<RotatorPositionQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::SetRotatorPosition {
+            azimuth_deg: q.azimuth_deg,
+            elevation_deg: q.elevation_deg,
+        },
+        q.remote,
+    )
+    .await
+}
+
+#[post("/stop_rotator")]
+pub async fn stop_rotator(
+    query: web::Query<RemoteQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    send_command(&rig_tx, RigCommand::StopRotator, query.into_inner().remote).await
+}
+
+#[derive(serde::Deserialize)]
+pub struct RotatorTrackingQuery {
+    pub norad_id: u32,
+    pub remote: Option<String>,
+}
+
+#[post("/start_rotator_tracking")]
+pub async fn start_rotator_tracking(
+    query: web::Query<RotatorTrackingQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::StartRotatorTracking {
+            norad_id: q.norad_id,
+        },
+        q.remote,
+    )
+    .await
+}
+
+#[post("/stop_rotator_tracking")]
+pub async fn stop_rotator_tracking(
+    query: web::Query<RemoteQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    send_command(
+        &rig_tx,
+        RigCommand::StopRotatorTracking,
+        query.into_inner().remote,
+    )
+    .await
+}
+
+#[derive(serde::Deserialize)]
+pub struct SendCwQuery {
+    pub text: String,
+    pub call: Option<String>,
+    pub remote: Option<String>,
+}
+
+#[post("/send_cw")]
+pub async fn send_cw(
+    query: web::Query<SendCwQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::SendCw {
+            text: q.text,
+            call: q.call.filter(|c| !c.trim().is_empty()),
+        },
+        q.remote,
+    )
+    .await
+}
+
+#[post("/abort_cw")]
+pub async fn abort_cw(
+    query: web::Query<RemoteQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    send_command(&rig_tx, RigCommand::AbortCw, query.into_inner().remote).await
+}
+
+#[derive(serde::Deserialize)]
+pub struct CwKeyerQuery {
+    pub wpm: u32,
+    #[serde(default = "default_cw_weight")]
+    pub weight: u32,
+    pub farnsworth_wpm: Option<u32>,
+    pub remote: Option<String>,
+}
+
+fn default_cw_weight() -> u32 {
+    trx_core::keyer::DEFAULT_WEIGHT
+}
+
+#[post("/set_cw_keyer")]
+pub async fn set_cw_keyer(
+    query: web::Query<CwKeyerQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::SetCwKeyer {
+            wpm: q.wpm,
+            weight: q.weight,
+            farnsworth_wpm: q.farnsworth_wpm.filter(|&f| f > 0),
+        },
+        q.remote,
+    )
+    .await
+}
+
+#[derive(serde::Deserialize)]
+pub struct CwSerialQuery {
+    pub serial: u32,
+    pub remote: Option<String>,
+}
+
+#[post("/set_cw_serial")]
+pub async fn set_cw_serial(
+    query: web::Query<CwSerialQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::SetCwSerial(q.serial), q.remote).await
 }
 
 /// Return predicted passes for all known satellites over the next 24 h.
@@ -514,7 +733,7 @@ pub async fn sat_passes(context: web::Data<Arc<FrontendRuntimeContext>>) -> impl
         Some(result) => {
             let error = match result.tle_source {
                 trx_core::geo::TleSource::Unavailable => {
-                    Some("TLE data not yet available — waiting for CelesTrak fetch".to_string())
+                    Some("TLE data not yet available — waiting for TLE sources".to_string())
                 }
                 trx_core::geo::TleSource::Celestrak => None,
             };
@@ -523,6 +742,7 @@ pub async fn sat_passes(context: web::Data<Arc<FrontendRuntimeContext>>) -> impl
                 error,
                 satellite_count: result.satellite_count,
                 tle_source: result.tle_source,
+                tle_warning: result.tle_warning,
             })
         }
         None => web::Json(SatPassesResponse {
@@ -530,6 +750,182 @@ pub async fn sat_passes(context: web::Data<Arc<FrontendRuntimeContext>>) -> impl
             error: Some("Satellite predictions not yet available from server".to_string()),
             satellite_count: 0,
             tle_source: trx_core::geo::TleSource::Unavailable,
+            tle_warning: None,
         }),
     }
 }
+
+#[derive(serde::Deserialize)]
+pub struct MemoryRangeQuery {
+    #[serde(default = "default_first_memory")]
+    pub first: u16,
+    pub last: Option<u16>,
+    pub remote: Option<String>,
+}
+
+fn default_first_memory() -> u16 {
+    1
+}
+
+/// Filled memory channels in `first..=last` (default: all of them).
+#[get("/rig_memories")]
+pub async fn rig_memories(
+    query: web::Query<MemoryRangeQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    let memories =
+        read_rig_memories(&rig_tx, q.remote, q.first, q.last.unwrap_or(u16::MAX)).await?;
+    Ok(HttpResponse::Ok().json(memories))
+}
+
+#[post("/write_memory")]
+pub async fn write_memory(
+    query: web::Query<RemoteQuery>,
+    body: web::Json<RigMemory>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    send_command(
+        &rig_tx,
+        RigCommand::WriteMemory(body.into_inner()),
+        query.into_inner().remote,
+    )
+    .await
+}
+
+#[derive(serde::Deserialize)]
+pub struct MemoryChannelQuery {
+    pub channel: u16,
+    pub remote: Option<String>,
+}
+
+#[post("/recall_memory")]
+pub async fn recall_memory(
+    query: web::Query<MemoryChannelQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::RecallMemory(q.channel), q.remote).await
+}
+
+#[derive(serde::Deserialize)]
+pub struct BandStackQuery {
+    pub freq_hz: u64,
+    pub remote: Option<String>,
+}
+
+#[post("/recall_band_stack")]
+pub async fn recall_band_stack(
+    query: web::Query<BandStackQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::RecallBandStack { freq_hz: q.freq_hz },
+        q.remote,
+    )
+    .await
+}
+
+#[derive(serde::Deserialize)]
+pub struct RepeaterOffsetQuery {
+    pub offset_hz: i32,
+    pub remote: Option<String>,
+}
+
+#[post("/set_repeater_offset")]
+pub async fn set_repeater_offset(
+    query: web::Query<RepeaterOffsetQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::SetRepeaterOffset(q.offset_hz),
+        q.remote,
+    )
+    .await
+}
+
+#[derive(serde::Deserialize)]
+pub struct RepeaterReverseQuery {
+    pub enabled: bool,
+    pub remote: Option<String>,
+}
+
+#[post("/set_repeater_reverse")]
+pub async fn set_repeater_reverse(
+    query: web::Query<RepeaterReverseQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::SetRepeaterReverse(q.enabled), q.remote).await
+}
+
+/// Omitting `tone_hz` turns tones off.
+#[derive(serde::Deserialize)]
+pub struct CtcssQuery {
+    pub tone_hz: Option<f32>,
+    #[serde(default)]
+    pub decode: bool,
+    pub remote: Option<String>,
+}
+
+#[post("/set_ctcss")]
+pub async fn set_ctcss(
+    query: web::Query<CtcssQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(
+        &rig_tx,
+        RigCommand::SetCtcss {
+            tone_hz: q.tone_hz,
+            decode: q.decode,
+        },
+        q.remote,
+    )
+    .await
+}
+
+/// Omitting `code` turns DCS off.
+#[derive(serde::Deserialize)]
+pub struct DcsQuery {
+    pub code: Option<u16>,
+    pub remote: Option<String>,
+}
+
+#[post("/set_dcs")]
+pub async fn set_dcs(
+    query: web::Query<DcsQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::SetDcs(q.code), q.remote).await
+}
+
+/// `offset_hz` of 0 turns RIT (or XIT) off and keeps the shared offset.
+#[derive(serde::Deserialize)]
+pub struct ClarifierQuery {
+    pub offset_hz: i32,
+    pub remote: Option<String>,
+}
+
+#[post("/set_rit")]
+pub async fn set_rit(
+    query: web::Query<ClarifierQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::SetRit(q.offset_hz), q.remote).await
+}
+
+#[post("/set_xit")]
+pub async fn set_xit(
+    query: web::Query<ClarifierQuery>,
+    rig_tx: web::Data<mpsc::Sender<RigRequest>>,
+) -> Result<HttpResponse, Error> {
+    let q = query.into_inner();
+    send_command(&rig_tx, RigCommand::SetXit(q.offset_hz), q.remote).await
+}
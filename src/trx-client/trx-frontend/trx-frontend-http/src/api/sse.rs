@@ -23,6 +23,7 @@ use trx_protocol::MeterUpdate;
 
 use crate::server::vchan::ClientChannelManager;
 
+use super::dxcluster::dx_spots_json;
 use super::{
     base64_encode, frontend_meta_from_context, wait_for_view, RemoteQuery, SessionRigManager,
     SnapshotWithMeta,
@@ -226,6 +227,9 @@ pub async fn events(
             ))));
         }
     }
+    if let Some(json) = dx_spots_json(context.get_ref().as_ref()) {
+        prefix.push(Ok(Bytes::from(format!("event: dxspots\ndata: {json}\n\n"))));
+    }
     let prefix_stream = futures_util::stream::iter(prefix);
 
     // Live rig-state updates; side-effect: keep primary channel metadata in sync.
@@ -313,6 +317,28 @@ pub async fn events(
         },
     );
 
+    // New and refreshed DX cluster spots.
+    let dx_updates =
+        futures_util::stream::unfold(context.dx_cluster.events.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(spot) => {
+                        let Ok(json) = serde_json::to_string(&spot) else {
+                            continue;
+                        };
+                        return Some((
+                            Ok::<Bytes, Error>(Bytes::from(format!(
+                                "event: dxspot\ndata: {json}\n\n"
+                            ))),
+                            rx,
+                        ));
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+
     // Send a named "ping" event so the JS heartbeat can observe it.
     let pings = IntervalStream::new(time::interval(Duration::from_secs(5)))
         .map(|_| Ok::<Bytes, Error>(Bytes::from("event: ping\ndata: \n\n")));
@@ -321,7 +347,7 @@ pub async fn events(
     let counter_drop = counter.clone();
     let scheduler_control_drop = scheduler_control.get_ref().clone();
     let session_rig_mgr_drop = session_rig_mgr.get_ref().clone();
-    let live = select(select(pings, updates), chan_updates);
+    let live = select(select(select(pings, updates), chan_updates), dx_updates);
     let stream = prefix_stream.chain(live);
     let stream = DropStream::new(Box::pin(stream), move || {
         counter_drop.fetch_sub(1, Ordering::Relaxed);
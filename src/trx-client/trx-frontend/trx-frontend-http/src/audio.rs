@@ -24,8 +24,8 @@ use tracing::warn;
 use uuid::Uuid;
 
 use trx_core::decode::{
-    AisMessage, AprsPacket, CwEvent, DecodedMessage, Ft8Message, VdesMessage, WefaxMessage,
-    WsprMessage,
+    AcarsMessage, AdsbMessage, AisMessage, AprsPacket, CwEvent, CwSpot, DecodedMessage, DscMessage, Ft8Message,
+    PocsagMessage, PskEvent, VdesMessage, WefaxMessage, WsprMessage,
 };
 use trx_frontend::FrontendRuntimeContext;
 
@@ -150,6 +150,58 @@ fn record_vdes(context: &FrontendRuntimeContext, mut msg: VdesMessage) {
     prune_vdes_history(context, &mut history);
 }
 
+fn prune_dsc_history(
+    context: &FrontendRuntimeContext,
+    history: &mut VecDeque<(Instant, Option<String>, DscMessage)>,
+) {
+    let cutoff = decode_history_cutoff(context);
+    while let Some((ts, _, _)) = history.front() {
+        if *ts >= cutoff {
+            break;
+        }
+        history.pop_front();
+    }
+}
+
+fn prune_pocsag_history(
+    context: &FrontendRuntimeContext,
+    history: &mut VecDeque<(Instant, Option<String>, PocsagMessage)>,
+) {
+    let cutoff = decode_history_cutoff(context);
+    while let Some((ts, _, _)) = history.front() {
+        if *ts >= cutoff {
+            break;
+        }
+        history.pop_front();
+    }
+}
+
+fn prune_acars_history(
+    context: &FrontendRuntimeContext,
+    history: &mut VecDeque<(Instant, Option<String>, AcarsMessage)>,
+) {
+    let cutoff = decode_history_cutoff(context);
+    while let Some((ts, _, _)) = history.front() {
+        if *ts >= cutoff {
+            break;
+        }
+        history.pop_front();
+    }
+}
+
+fn prune_adsb_history(
+    context: &FrontendRuntimeContext,
+    history: &mut VecDeque<(Instant, Option<String>, AdsbMessage)>,
+) {
+    let cutoff = decode_history_cutoff(context);
+    while let Some((ts, _, _)) = history.front() {
+        if *ts >= cutoff {
+            break;
+        }
+        history.pop_front();
+    }
+}
+
 fn prune_cw_history(
     context: &FrontendRuntimeContext,
     history: &mut VecDeque<(Instant, Option<String>, CwEvent)>,
@@ -163,6 +215,19 @@ fn prune_cw_history(
     }
 }
 
+fn prune_psk_history(
+    context: &FrontendRuntimeContext,
+    history: &mut VecDeque<(Instant, Option<String>, PskEvent)>,
+) {
+    let cutoff = decode_history_cutoff(context);
+    while let Some((ts, _, _)) = history.front() {
+        if *ts >= cutoff {
+            break;
+        }
+        history.pop_front();
+    }
+}
+
 fn prune_ft8_history(
     context: &FrontendRuntimeContext,
     history: &mut VecDeque<(Instant, Option<String>, Ft8Message)>,
@@ -243,6 +308,75 @@ fn record_hf_aprs(context: &FrontendRuntimeContext, mut pkt: AprsPacket) {
     prune_hf_aprs_history(context, &mut history);
 }
 
+fn record_dsc(context: &FrontendRuntimeContext, mut msg: DscMessage) {
+    if msg.ts_ms.is_none() {
+        msg.ts_ms = Some(current_timestamp_ms());
+    }
+    let rig_id = msg.rig_id.clone().or_else(|| active_rig_id(context));
+    let mut history = context
+        .decode_history
+        .dsc
+        .lock()
+        .expect("dsc history mutex poisoned");
+    history.push_back((Instant::now(), rig_id, msg));
+    prune_dsc_history(context, &mut history);
+}
+
+fn record_pocsag(context: &FrontendRuntimeContext, mut msg: PocsagMessage) {
+    if msg.ts_ms.is_none() {
+        msg.ts_ms = Some(current_timestamp_ms());
+    }
+    let rig_id = msg.rig_id.clone().or_else(|| active_rig_id(context));
+    let mut history = context
+        .decode_history
+        .pocsag
+        .lock()
+        .expect("pocsag history mutex poisoned");
+    history.push_back((Instant::now(), rig_id, msg));
+    prune_pocsag_history(context, &mut history);
+}
+
+fn record_acars(context: &FrontendRuntimeContext, mut msg: AcarsMessage) {
+    if msg.ts_ms.is_none() {
+        msg.ts_ms = Some(current_timestamp_ms());
+    }
+    let rig_id = msg.rig_id.clone().or_else(|| active_rig_id(context));
+    let mut history = context
+        .decode_history
+        .acars
+        .lock()
+        .expect("acars history mutex poisoned");
+    history.push_back((Instant::now(), rig_id, msg));
+    prune_acars_history(context, &mut history);
+}
+
+/// Keep one entry per aircraft and rig, replacing the previous update.
+fn record_adsb(context: &FrontendRuntimeContext, mut msg: AdsbMessage) {
+    if msg.ts_ms.is_none() {
+        msg.ts_ms = Some(current_timestamp_ms());
+    }
+    let rig_id = msg.rig_id.clone().or_else(|| active_rig_id(context));
+    let mut history = context
+        .decode_history
+        .adsb
+        .lock()
+        .expect("adsb history mutex poisoned");
+    history.retain(|(_, rid, prev)| prev.icao != msg.icao || *rid != rig_id);
+    history.push_back((Instant::now(), rig_id, msg));
+    prune_adsb_history(context, &mut history);
+}
+
+/// Skimmer spots go into the DX cluster table, so they are labelled on the
+/// spectrum alongside cluster and RBN spots.
+fn record_cw_spot(context: &FrontendRuntimeContext, spot: CwSpot) {
+    let spot = spot.to_dx_spot(current_timestamp_ms());
+    let stored = match context.dx_cluster.spots.write() {
+        Ok(mut table) => table.insert(spot),
+        Err(_) => return,
+    };
+    let _ = context.dx_cluster.events.send(stored);
+}
+
 fn record_cw(context: &FrontendRuntimeContext, event: CwEvent) {
     let rig_id = event.rig_id.clone().or_else(|| active_rig_id(context));
     let mut history = context
@@ -254,6 +388,20 @@ fn record_cw(context: &FrontendRuntimeContext, event: CwEvent) {
     prune_cw_history(context, &mut history);
 }
 
+fn record_psk(context: &FrontendRuntimeContext, mut event: PskEvent) {
+    if event.ts_ms.is_none() {
+        event.ts_ms = Some(current_timestamp_ms());
+    }
+    let rig_id = event.rig_id.clone().or_else(|| active_rig_id(context));
+    let mut history = context
+        .decode_history
+        .psk
+        .lock()
+        .expect("psk history mutex poisoned");
+    history.push_back((Instant::now(), rig_id, event));
+    prune_psk_history(context, &mut history);
+}
+
 fn record_ft8(context: &FrontendRuntimeContext, msg: Ft8Message) {
     let rig_id = msg.rig_id.clone().or_else(|| active_rig_id(context));
     let mut history = context
@@ -425,6 +573,74 @@ pub fn snapshot_vdes_history(
         .collect()
 }
 
+pub fn snapshot_dsc_history(
+    context: &FrontendRuntimeContext,
+    rig_filter: Option<&str>,
+) -> Vec<DscMessage> {
+    let mut history = context
+        .decode_history
+        .dsc
+        .lock()
+        .expect("dsc history mutex poisoned");
+    prune_dsc_history(context, &mut history);
+    history
+        .iter()
+        .filter(|(_, rid, _)| matches_rig_filter(rid.as_deref(), rig_filter))
+        .map(|(_, _, msg)| msg.clone())
+        .collect()
+}
+
+pub fn snapshot_pocsag_history(
+    context: &FrontendRuntimeContext,
+    rig_filter: Option<&str>,
+) -> Vec<PocsagMessage> {
+    let mut history = context
+        .decode_history
+        .pocsag
+        .lock()
+        .expect("pocsag history mutex poisoned");
+    prune_pocsag_history(context, &mut history);
+    history
+        .iter()
+        .filter(|(_, rid, _)| matches_rig_filter(rid.as_deref(), rig_filter))
+        .map(|(_, _, msg)| msg.clone())
+        .collect()
+}
+
+pub fn snapshot_acars_history(
+    context: &FrontendRuntimeContext,
+    rig_filter: Option<&str>,
+) -> Vec<AcarsMessage> {
+    let mut history = context
+        .decode_history
+        .acars
+        .lock()
+        .expect("acars history mutex poisoned");
+    prune_acars_history(context, &mut history);
+    history
+        .iter()
+        .filter(|(_, rid, _)| matches_rig_filter(rid.as_deref(), rig_filter))
+        .map(|(_, _, msg)| msg.clone())
+        .collect()
+}
+
+pub fn snapshot_adsb_history(
+    context: &FrontendRuntimeContext,
+    rig_filter: Option<&str>,
+) -> Vec<AdsbMessage> {
+    let mut history = context
+        .decode_history
+        .adsb
+        .lock()
+        .expect("adsb history mutex poisoned");
+    prune_adsb_history(context, &mut history);
+    history
+        .iter()
+        .filter(|(_, rid, _)| matches_rig_filter(rid.as_deref(), rig_filter))
+        .map(|(_, _, msg)| msg.clone())
+        .collect()
+}
+
 pub fn snapshot_cw_history(
     context: &FrontendRuntimeContext,
     rig_filter: Option<&str>,
@@ -442,6 +658,23 @@ pub fn snapshot_cw_history(
         .collect()
 }
 
+pub fn snapshot_psk_history(
+    context: &FrontendRuntimeContext,
+    rig_filter: Option<&str>,
+) -> Vec<PskEvent> {
+    let mut history = context
+        .decode_history
+        .psk
+        .lock()
+        .expect("psk history mutex poisoned");
+    prune_psk_history(context, &mut history);
+    history
+        .iter()
+        .filter(|(_, rid, _)| matches_rig_filter(rid.as_deref(), rig_filter))
+        .map(|(_, _, evt)| evt.clone())
+        .collect()
+}
+
 pub fn snapshot_ft8_history(
     context: &FrontendRuntimeContext,
     rig_filter: Option<&str>,
@@ -571,6 +804,42 @@ pub fn clear_vdes_history(context: &FrontendRuntimeContext) {
     history.clear();
 }
 
+pub fn clear_dsc_history(context: &FrontendRuntimeContext) {
+    let mut history = context
+        .decode_history
+        .dsc
+        .lock()
+        .expect("dsc history mutex poisoned");
+    history.clear();
+}
+
+pub fn clear_pocsag_history(context: &FrontendRuntimeContext) {
+    let mut history = context
+        .decode_history
+        .pocsag
+        .lock()
+        .expect("pocsag history mutex poisoned");
+    history.clear();
+}
+
+pub fn clear_acars_history(context: &FrontendRuntimeContext) {
+    let mut history = context
+        .decode_history
+        .acars
+        .lock()
+        .expect("acars history mutex poisoned");
+    history.clear();
+}
+
+pub fn clear_adsb_history(context: &FrontendRuntimeContext) {
+    let mut history = context
+        .decode_history
+        .adsb
+        .lock()
+        .expect("adsb history mutex poisoned");
+    history.clear();
+}
+
 pub fn clear_cw_history(context: &FrontendRuntimeContext) {
     let mut history = context
         .decode_history
@@ -580,6 +849,15 @@ pub fn clear_cw_history(context: &FrontendRuntimeContext) {
     history.clear();
 }
 
+pub fn clear_psk_history(context: &FrontendRuntimeContext) {
+    let mut history = context
+        .decode_history
+        .psk
+        .lock()
+        .expect("psk history mutex poisoned");
+    history.clear();
+}
+
 pub fn clear_ft8_history(context: &FrontendRuntimeContext) {
     let mut history = context
         .decode_history
@@ -641,9 +919,15 @@ pub fn start_decode_history_collector(context: Arc<FrontendRuntimeContext>) {
                 Ok(msg) => match msg {
                     DecodedMessage::Ais(msg) => record_ais(&context, msg),
                     DecodedMessage::Vdes(msg) => record_vdes(&context, msg),
+                    DecodedMessage::Dsc(msg) => record_dsc(&context, msg),
+                    DecodedMessage::Pocsag(msg) => record_pocsag(&context, msg),
+                    DecodedMessage::Adsb(msg) => record_adsb(&context, msg),
+                    DecodedMessage::Acars(msg) => record_acars(&context, msg),
                     DecodedMessage::Aprs(pkt) => record_aprs(&context, pkt),
                     DecodedMessage::HfAprs(pkt) => record_hf_aprs(&context, pkt),
                     DecodedMessage::Cw(evt) => record_cw(&context, evt),
+                    DecodedMessage::CwSpot(spot) => record_cw_spot(&context, spot),
+                    DecodedMessage::Psk(evt) => record_psk(&context, evt),
                     DecodedMessage::Ft8(msg) => record_ft8(&context, msg),
                     DecodedMessage::Ft4(msg) => record_ft4(&context, msg),
                     DecodedMessage::Ft2(msg) => record_ft2(&context, msg),
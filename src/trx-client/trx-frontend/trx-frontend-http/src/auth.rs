@@ -529,6 +529,8 @@ impl RouteAccess {
             || path == "/meter"
             || path == "/audio"
             || path == "/bookmarks"
+            || path == "/logbook"
+            || path == "/dx_spots"
             || path.starts_with("/status?")
             || path.starts_with("/rigs?")
             || path.starts_with("/events?")
@@ -539,6 +541,8 @@ impl RouteAccess {
             || path.starts_with("/audio?")
             || path.starts_with("/bookmarks?")
             || path.starts_with("/bookmarks/")
+            || path.starts_with("/logbook?")
+            || path.starts_with("/logbook/")
             || path.starts_with("/scheduler/")
             || path.starts_with("/scheduler-control")
             || path.starts_with("/channels/")
@@ -8,6 +8,10 @@ use std::sync::{Arc, Mutex, RwLock};
 
 use pickledb::{PickleDb, PickleDbDumpPolicy, SerializationMethod};
 use serde::{Deserialize, Serialize};
+use trx_core::radio::freq::Freq;
+use trx_core::rig::memory::RigMemory;
+use trx_core::rig::repeater::{ctcss_tone_index, dcs_code_index, CTCSS_TONES};
+use trx_protocol::{mode_to_string, parse_mode};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bookmark {
@@ -18,11 +22,224 @@ pub struct Bookmark {
     pub bandwidth_hz: Option<u64>,
     #[serde(default)]
     pub locator: Option<String>,
+    /// CTCSS tone in Hz, kept for round trips through rig memories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctcss_hz: Option<f32>,
+    /// DCS code, e.g. `23` for "023".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dcs_code: Option<u16>,
+    /// Repeater transmit offset in Hz (negative for a minus shift).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset_hz: Option<i64>,
     pub comment: String,
     pub category: String,
     pub decoders: Vec<String>,
 }
 
+/// Category given to bookmarks imported from rig memories.
+pub const RIG_MEMORY_CATEGORY: &str = "Rig memory";
+
+/// Category given to bookmarks imported from a repeater directory.
+pub const REPEATER_CATEGORY: &str = "Repeater";
+
+impl Bookmark {
+    /// Bookmark for a rig memory channel; unnamed channels are called
+    /// `M001` and so on.
+    pub fn from_memory(id: String, memory: &RigMemory) -> Self {
+        let name = if memory.name.trim().is_empty() {
+            format!("M{:03}", memory.channel)
+        } else {
+            memory.name.trim().to_string()
+        };
+        Self {
+            id,
+            name,
+            freq_hz: memory.freq.hz,
+            mode: mode_to_string(&memory.mode).into_owned(),
+            bandwidth_hz: None,
+            locator: None,
+            ctcss_hz: memory.ctcss_hz,
+            dcs_code: None,
+            offset_hz: memory.offset_hz.filter(|&o| o != 0),
+            comment: format!("Memory channel {}", memory.channel),
+            category: RIG_MEMORY_CATEGORY.to_string(),
+            decoders: Vec::new(),
+        }
+    }
+
+    /// Memory channel contents for storing this bookmark in `channel`.
+    pub fn to_memory(&self, channel: u16) -> RigMemory {
+        RigMemory {
+            channel,
+            name: self.name.clone(),
+            freq: Freq { hz: self.freq_hz },
+            mode: parse_mode(&self.mode),
+            ctcss_hz: self.ctcss_hz,
+            offset_hz: Some(self.offset_hz.unwrap_or(0)),
+        }
+    }
+}
+
+/// Bookmarks parsed from a repeater directory CSV.
+#[derive(Debug, Default)]
+pub struct RepeaterCsv {
+    /// Analog repeaters, with empty ids for the caller to fill in.
+    pub bookmarks: Vec<Bookmark>,
+    /// Rows without a usable frequency or with a digital or unknown mode.
+    pub skipped_rows: usize,
+}
+
+/// Parse a repeater list exported by CHIRP or RepeaterBook.
+///
+/// Headers are matched case-insensitively.  CHIRP files give the shift as
+/// `Duplex` plus `Offset` and the tone as `Tone` with `rToneFreq`,
+/// `cToneFreq` or `DtcsCode`; RepeaterBook files give `Output Freq`,
+/// `Input Freq` or a signed `Offset`, and an `Uplink Tone` that is either a
+/// CTCSS frequency or a `D023`-style DCS code.  Tones outside the standard
+/// tables are dropped.
+pub fn parse_repeater_csv(text: &str) -> Result<RepeaterCsv, String> {
+    let mut lines = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .filter(|line| !line.trim().is_empty());
+    let header: Vec<String> = split_csv_line(lines.next().ok_or("CSV file is empty")?)
+        .iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+    let col = |names: &[&str]| {
+        names
+            .iter()
+            .find_map(|n| header.iter().position(|h| h == n))
+    };
+    let freq_col = col(&["frequency", "output freq", "output frequency", "downlink"])
+        .ok_or("CSV has no Frequency or Output Freq column")?;
+    let input_col = col(&["input freq", "input frequency", "uplink"]);
+    let duplex_col = col(&["duplex"]);
+    let offset_col = col(&["offset"]);
+    let tone_mode_col = col(&["tone"]);
+    let rtone_col = col(&["rtonefreq"]);
+    let ctone_col = col(&["ctonefreq"]);
+    let dtcs_col = col(&["dtcscode"]);
+    let uplink_tone_col = col(&["uplink tone", "pl", "ctcss"]);
+    let mode_col = col(&["mode"]);
+    let name_col = col(&["name", "call", "callsign"]);
+    let comment_col = col(&["comment", "nearest city", "location"]);
+
+    let mut parsed = RepeaterCsv::default();
+    for line in lines {
+        let fields = split_csv_line(line);
+        let field = |idx: Option<usize>| {
+            idx.and_then(|i| fields.get(i))
+                .map(|f| f.trim())
+                .unwrap_or_default()
+        };
+        let mode = match field(mode_col).to_uppercase().as_str() {
+            "" | "FM" | "NFM" | "FMN" | "ANALOG" => "FM",
+            "AM" => "AM",
+            "WFM" => "WFM",
+            "USB" => "USB",
+            "LSB" => "LSB",
+            "CW" => "CW",
+            _ => {
+                parsed.skipped_rows += 1;
+                continue;
+            }
+        };
+        let Some(freq_hz) = parse_mhz(field(Some(freq_col))).filter(|&hz| hz > 0) else {
+            parsed.skipped_rows += 1;
+            continue;
+        };
+        let offset_mhz = parse_mhz(field(offset_col)).unwrap_or(0);
+        let offset_hz = match (duplex_col, parse_mhz(field(input_col))) {
+            (Some(_), _) => match field(duplex_col).to_lowercase().as_str() {
+                "+" => offset_mhz,
+                "-" => -offset_mhz,
+                // CHIRP puts the transmit frequency in Offset for splits.
+                "split" if offset_mhz > 0 => offset_mhz - freq_hz,
+                _ => 0,
+            },
+            (None, Some(input_hz)) if input_hz > 0 => input_hz - freq_hz,
+            _ => offset_mhz,
+        };
+        let (ctcss_hz, dcs_code) = if tone_mode_col.is_some() {
+            match field(tone_mode_col).to_uppercase().as_str() {
+                "TONE" => (parse_ctcss(field(rtone_col)), None),
+                "TSQL" => (parse_ctcss(field(ctone_col)), None),
+                "DTCS" => (None, parse_dcs(field(dtcs_col))),
+                _ => (None, None),
+            }
+        } else {
+            let tone = field(uplink_tone_col);
+            match tone.strip_prefix(['D', 'd']) {
+                Some(code) => (None, parse_dcs(code)),
+                None => (parse_ctcss(tone), None),
+            }
+        };
+        let name = match field(name_col) {
+            "" => format!("{:.4}", freq_hz as f64 / 1e6),
+            name => name.to_string(),
+        };
+        parsed.bookmarks.push(Bookmark {
+            id: String::new(),
+            name,
+            freq_hz: freq_hz as u64,
+            mode: mode.to_string(),
+            bandwidth_hz: None,
+            locator: None,
+            ctcss_hz,
+            dcs_code,
+            offset_hz: Some(offset_hz).filter(|&o| o != 0),
+            comment: field(comment_col).to_string(),
+            category: REPEATER_CATEGORY.to_string(),
+            decoders: Vec::new(),
+        });
+    }
+    Ok(parsed)
+}
+
+/// Split one CSV record, honouring double-quoted fields and `""` escapes.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+/// Parse a signed frequency in MHz to whole Hz.
+fn parse_mhz(value: &str) -> Option<i64> {
+    let mhz: f64 = value.trim().trim_start_matches('+').parse().ok()?;
+    Some((mhz * 1e6).round() as i64)
+}
+
+/// Parse a CTCSS tone, snapping it to the standard table.
+fn parse_ctcss(value: &str) -> Option<f32> {
+    let hz: f32 = value.trim().parse().ok()?;
+    ctcss_tone_index(hz).map(|idx| CTCSS_TONES[idx])
+}
+
+/// Parse a DCS code such as `023`, ignoring a trailing polarity letter.
+fn parse_dcs(value: &str) -> Option<u16> {
+    let code: u16 = value
+        .trim()
+        .trim_end_matches(['N', 'I', 'n', 'i'])
+        .parse()
+        .ok()?;
+    dcs_code_index(code).map(|_| code)
+}
+
 pub struct BookmarkStore {
     db: Arc<RwLock<PickleDb>>,
 }
@@ -183,3 +400,79 @@ impl BookmarkStoreMap {
         map.into_values().collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trx_core::RigMode;
+
+    #[test]
+    fn memory_round_trips_through_bookmark() {
+        let memory = RigMemory {
+            channel: 12,
+            name: "GB3XX".to_string(),
+            freq: Freq { hz: 145_725_000 },
+            mode: RigMode::FM,
+            ctcss_hz: Some(77.0),
+            offset_hz: Some(-600_000),
+        };
+        let bm = Bookmark::from_memory("id".to_string(), &memory);
+        assert_eq!(bm.name, "GB3XX");
+        assert_eq!(bm.mode, "FM");
+        assert_eq!(bm.category, RIG_MEMORY_CATEGORY);
+        assert_eq!(bm.to_memory(12), memory);
+
+        let unnamed = RigMemory {
+            name: String::new(),
+            offset_hz: Some(0),
+            ..memory
+        };
+        let bm = Bookmark::from_memory("id".to_string(), &unnamed);
+        assert_eq!(bm.name, "M012");
+        assert_eq!(bm.offset_hz, None);
+    }
+
+    #[test]
+    fn parse_chirp_repeater_csv() {
+        let csv = "Location,Name,Frequency,Duplex,Offset,Tone,rToneFreq,cToneFreq,DtcsCode,DtcsPolarity,Mode,TStep,Skip,Comment\n\
+            1,GB3XX,145.725000,-,0.600000,Tone,77.0,88.5,023,NN,FM,12.50,,\"Hill, top\"\n\
+            2,GB7DV,439.600000,-,9.000000,,88.5,88.5,023,NN,DV,12.50,,\n\
+            3,SPLIT,145.000000,split,145.600000,DTCS,88.5,88.5,065,NN,NFM,12.50,,\n\
+            4,,433.500000,,0.000000,TSQL,88.5,71.9,023,NN,FM,12.50,,\n";
+        let parsed = parse_repeater_csv(csv).unwrap();
+        assert_eq!(parsed.skipped_rows, 1);
+        let [minus, split, simplex] = parsed.bookmarks.as_slice() else {
+            panic!("expected three repeaters");
+        };
+        assert_eq!(minus.name, "GB3XX");
+        assert_eq!(minus.freq_hz, 145_725_000);
+        assert_eq!(minus.offset_hz, Some(-600_000));
+        assert_eq!(minus.ctcss_hz, Some(77.0));
+        assert_eq!(minus.comment, "Hill, top");
+        assert_eq!(minus.category, REPEATER_CATEGORY);
+        assert_eq!(split.offset_hz, Some(600_000));
+        assert_eq!(split.mode, "FM");
+        assert_eq!(split.dcs_code, Some(65));
+        assert_eq!(simplex.name, "433.5000");
+        assert_eq!(simplex.offset_hz, None);
+        assert_eq!(simplex.ctcss_hz, Some(71.9));
+    }
+
+    #[test]
+    fn parse_repeaterbook_csv() {
+        let csv = "Output Freq,Input Freq,Uplink Tone,Downlink Tone,Call,Location,Mode\n\
+            146.940,146.340,100.0,100.0,W1ABC,Springfield,FM\n\
+            442.100,447.100,D023,,W1DEF,Shelbyville,\n\
+            443.000,448.000,,,W1DMR,Ogdenville,DMR\n\
+            notafreq,,,,W1BAD,Nowhere,FM\n";
+        let parsed = parse_repeater_csv(csv).unwrap();
+        assert_eq!(parsed.skipped_rows, 2);
+        assert_eq!(parsed.bookmarks.len(), 2);
+        assert_eq!(parsed.bookmarks[0].offset_hz, Some(-600_000));
+        assert_eq!(parsed.bookmarks[0].ctcss_hz, Some(100.0));
+        assert_eq!(parsed.bookmarks[0].comment, "Springfield");
+        assert_eq!(parsed.bookmarks[1].offset_hz, Some(5_000_000));
+        assert_eq!(parsed.bookmarks[1].dcs_code, Some(23));
+        assert!(parse_repeater_csv("Name,Mode\nfoo,FM\n").is_err());
+    }
+}
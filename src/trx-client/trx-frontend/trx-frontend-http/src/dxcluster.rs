@@ -0,0 +1,302 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! DX cluster / RBN telnet client.
+//!
+//! Logs in to the configured node, parses every spot line into the shared
+//! [`SpotTable`](trx_core::dxspot::SpotTable) and broadcasts new spots for the
+//! SSE stream.  The connection is re-established with exponential backoff.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tracing::{info, warn};
+
+use trx_core::dxspot::parse_cluster_line;
+use trx_core::DynResult;
+use trx_frontend::{DxClusterFeed, FrontendRuntimeContext};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const RECONNECT_MIN: Duration = Duration::from_secs(5);
+const RECONNECT_MAX: Duration = Duration::from_secs(300);
+
+/// Telnet "interpret as command" escape.
+const IAC: u8 = 255;
+const SB: u8 = 250;
+const SE: u8 = 240;
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// Start following the configured cluster node, if any.
+pub fn spawn_dx_cluster_task(context: Arc<FrontendRuntimeContext>) {
+    let Some(feed) = context.dx_cluster.feed.clone() else {
+        return;
+    };
+    info!(
+        "dxcluster: following {}:{} as {}",
+        feed.host, feed.port, feed.login
+    );
+    tokio::spawn(async move {
+        let mut backoff = RECONNECT_MIN;
+        loop {
+            match run_session(&context, &feed).await {
+                Ok(()) => {
+                    warn!(
+                        "dxcluster: {}:{} closed the connection",
+                        feed.host, feed.port
+                    );
+                    backoff = RECONNECT_MIN;
+                }
+                Err(e) => warn!("dxcluster: {}:{}: {}", feed.host, feed.port, e),
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(RECONNECT_MAX);
+        }
+    });
+}
+
+/// Login progress on one connection.
+#[derive(Default)]
+struct LoginState {
+    login_sent: bool,
+    password_sent: bool,
+    commands_sent: bool,
+}
+
+async fn run_session(context: &FrontendRuntimeContext, feed: &DxClusterFeed) -> DynResult<()> {
+    let addr = format!("{}:{}", feed.host, feed.port);
+    let stream = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&addr))
+        .await
+        .map_err(|_| "connect timed out")??;
+    let (mut reader, mut writer) = stream.into_split();
+    let mut login = LoginState::default();
+    let mut pending: Vec<u8> = Vec::new();
+    let mut buf = [0u8; 4096];
+
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        strip_telnet(&buf[..n], &mut pending);
+
+        while let Some(pos) = pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = pending.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\r', '\n']);
+            if !answer_prompt(line, feed, &mut login, &mut writer).await? {
+                handle_line(context, feed, line);
+            }
+        }
+        // Prompts usually arrive without a trailing newline.
+        if !pending.is_empty() {
+            let partial = String::from_utf8_lossy(&pending).into_owned();
+            if answer_prompt(&partial, feed, &mut login, &mut writer).await? {
+                pending.clear();
+            }
+        }
+    }
+}
+
+/// Reply to login/password prompts.  Returns `true` when `text` was a prompt.
+async fn answer_prompt<W: AsyncWriteExt + Unpin>(
+    text: &str,
+    feed: &DxClusterFeed,
+    login: &mut LoginState,
+    writer: &mut W,
+) -> std::io::Result<bool> {
+    let lower = text.trim().to_ascii_lowercase();
+    if !lower.ends_with(':') && !lower.ends_with('>') {
+        return Ok(false);
+    }
+    if !login.login_sent && (lower.contains("login") || lower.contains("call")) {
+        writer
+            .write_all(format!("{}\r\n", feed.login).as_bytes())
+            .await?;
+        login.login_sent = true;
+    } else if login.login_sent && !login.password_sent && lower.contains("password") {
+        let password = feed.password.as_deref().unwrap_or("");
+        writer
+            .write_all(format!("{password}\r\n").as_bytes())
+            .await?;
+        login.password_sent = true;
+    } else {
+        return Ok(false);
+    }
+    // With a password configured the node asks for it before accepting
+    // commands; otherwise it is ready right after the callsign.
+    if !login.commands_sent && (feed.password.is_none() || login.password_sent) {
+        for command in &feed.commands {
+            writer
+                .write_all(format!("{}\r\n", command.trim()).as_bytes())
+                .await?;
+        }
+        login.commands_sent = true;
+    }
+    writer.flush().await?;
+    Ok(true)
+}
+
+fn handle_line(context: &FrontendRuntimeContext, feed: &DxClusterFeed, line: &str) {
+    let Some(spot) = parse_cluster_line(line, now_ms()) else {
+        return;
+    };
+    if !feed.filter.matches(&spot) {
+        return;
+    }
+    let stored = match context.dx_cluster.spots.write() {
+        Ok(mut table) => table.insert(spot),
+        Err(_) => return,
+    };
+    let _ = context.dx_cluster.events.send(stored);
+}
+
+/// Copy `input` to `out`, dropping telnet option negotiation.
+fn strip_telnet(input: &[u8], out: &mut Vec<u8>) {
+    let mut i = 0;
+    while i < input.len() {
+        let b = input[i];
+        if b != IAC {
+            out.push(b);
+            i += 1;
+            continue;
+        }
+        match input.get(i + 1) {
+            Some(&IAC) => {
+                out.push(IAC);
+                i += 2;
+            }
+            Some(&SB) => {
+                // Skip to IAC SE.
+                i += 2;
+                while i + 1 < input.len() && !(input[i] == IAC && input[i + 1] == SE) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            // WILL / WONT / DO / DONT carry one option byte.
+            Some(&cmd) if (251..=254).contains(&cmd) => i += 3,
+            _ => i += 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncBufReadExt, BufReader};
+    use tokio::net::TcpListener;
+    use trx_core::dxspot::SpotFilter;
+
+    #[test]
+    fn strips_telnet_negotiation() {
+        let mut out = Vec::new();
+        strip_telnet(&[IAC, 251, 1, b'h', b'i', IAC, IAC, b'\n'], &mut out);
+        assert_eq!(out, vec![b'h', b'i', IAC, b'\n']);
+    }
+
+    #[tokio::test]
+    async fn logs_in_and_collects_spots_from_fake_node() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (login_tx, login_rx) = tokio::sync::oneshot::channel();
+        let node = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            writer
+                .write_all(b"Welcome to the test node\r\nPlease enter your call: ")
+                .await
+                .unwrap();
+            let call = lines.next_line().await.unwrap().unwrap();
+            let command = lines.next_line().await.unwrap().unwrap();
+            let _ = login_tx.send((call, command));
+            writer
+                .write_all(
+                    b"DX de EA5WU-#:   7012.3  K1ABC        CW    18 dB  24 WPM  CQ      0915Z\r\n\
+                      DX de EA5WU-#:  14074.0  DL1XYZ       FT8   -5 dB  CQ      0915Z\r\n\
+                      DX de OH6BG-#:   7012.4  K1ABC        CW    21 dB  24 WPM  CQ      0916Z\r\n",
+                )
+                .await
+                .unwrap();
+            // Keep the connection open until the test finishes.
+            let _ = lines.next_line().await;
+        });
+
+        let mut context = FrontendRuntimeContext::new();
+        context.dx_cluster.feed = Some(DxClusterFeed {
+            host: "127.0.0.1".to_string(),
+            port,
+            login: "N0CALL".to_string(),
+            password: None,
+            commands: vec!["set/skimmer".to_string()],
+            filter: SpotFilter {
+                modes: vec!["CW".to_string()],
+                ..SpotFilter::default()
+            },
+        });
+        let context = Arc::new(context);
+        let mut events = context.dx_cluster.events.subscribe();
+        spawn_dx_cluster_task(context.clone());
+
+        let (call, command) = tokio::time::timeout(Duration::from_secs(5), login_rx)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(call, "N0CALL");
+        assert_eq!(command, "set/skimmer");
+
+        let first = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.call, "K1ABC");
+        let second = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.count, 2);
+        assert_eq!(second.spotter, "OH6BG");
+
+        let spots = context.dx_cluster.spots.read().unwrap().spots();
+        assert_eq!(spots.len(), 1, "FT8 spot is filtered, CW spots merge");
+
+        node.abort();
+        let _ = node.await;
+    }
+
+    #[tokio::test]
+    async fn sends_commands_after_password() {
+        let feed = DxClusterFeed {
+            host: String::new(),
+            port: 0,
+            login: "N0CALL".to_string(),
+            password: Some("secret".to_string()),
+            commands: vec!["sh/dx".to_string()],
+            filter: SpotFilter::default(),
+        };
+        let mut login = LoginState::default();
+        let mut sent: Vec<u8> = Vec::new();
+        assert!(answer_prompt("login:", &feed, &mut login, &mut sent)
+            .await
+            .unwrap());
+        assert_eq!(sent, b"N0CALL\r\n");
+        assert!(answer_prompt("Password:", &feed, &mut login, &mut sent)
+            .await
+            .unwrap());
+        assert_eq!(sent, b"N0CALL\r\nsecret\r\nsh/dx\r\n");
+        assert!(
+            !answer_prompt("DX de A1A: 14000.0 K1A 1200Z", &feed, &mut login, &mut sent)
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,806 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! QSO logbook: PickleDB-backed store, ADIF 3 import/export, duplicate and
+//! worked-before checks, and automatic logging of completed FT8/FT4 exchanges.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use pickledb::{PickleDb, PickleDbDumpPolicy, SerializationMethod};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+use trx_core::decode::{DecodedMessage, Ft8Message};
+use trx_core::radio::freq::Freq;
+use trx_core::rig::state::RigSnapshot;
+use trx_frontend::FrontendRuntimeContext;
+
+/// An exchange that sees no new message for this long is discarded.
+const EXCHANGE_TIMEOUT_MS: i64 = 10 * 60 * 1000;
+
+// ============================================================================
+// QSO record and store
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Qso {
+    pub id: String,
+    /// Worked station callsign (upper-case).
+    pub call: String,
+    /// QSO start time (UTC, milliseconds since epoch).
+    pub ts_ms: i64,
+    pub freq_hz: u64,
+    /// Operating mode as shown in the UI (e.g. `FT8`, `FT4`, `USB`, `CW`).
+    pub mode: String,
+    /// Band label derived from the rig band plan (e.g. `20m`, `70cm`).
+    #[serde(default)]
+    pub band: Option<String>,
+    #[serde(default)]
+    pub rst_sent: Option<String>,
+    #[serde(default)]
+    pub rst_rcvd: Option<String>,
+    /// Worked station's Maidenhead locator.
+    #[serde(default)]
+    pub gridsquare: Option<String>,
+    /// Own Maidenhead locator at the time of the QSO.
+    #[serde(default)]
+    pub my_gridsquare: Option<String>,
+    /// Own callsign used for the QSO.
+    #[serde(default)]
+    pub station_callsign: Option<String>,
+    #[serde(default)]
+    pub comment: String,
+    /// Where the record came from: `manual`, `ft8`, `ft4` or `adif`.
+    #[serde(default)]
+    pub source: String,
+}
+
+/// Summary of previous contacts with one callsign.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorkedSummary {
+    pub count: usize,
+    pub bands: Vec<String>,
+    pub modes: Vec<String>,
+    pub last_ts_ms: i64,
+}
+
+pub struct LogbookStore {
+    db: Arc<RwLock<PickleDb>>,
+}
+
+impl LogbookStore {
+    /// Open (or create) the logbook at `path`.
+    pub fn open(path: &Path) -> Self {
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let db = if path.exists() {
+            PickleDb::load(
+                path,
+                PickleDbDumpPolicy::AutoDump,
+                SerializationMethod::Json,
+            )
+            .unwrap_or_else(|_| {
+                PickleDb::new(
+                    path,
+                    PickleDbDumpPolicy::AutoDump,
+                    SerializationMethod::Json,
+                )
+            })
+        } else {
+            PickleDb::new(
+                path,
+                PickleDbDumpPolicy::AutoDump,
+                SerializationMethod::Json,
+            )
+        };
+        Self {
+            db: Arc::new(RwLock::new(db)),
+        }
+    }
+
+    /// Logbook path: `~/.config/trx-rs/logbook.db`.
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir()
+            .map(|p| p.join("trx-rs").join("logbook.db"))
+            .unwrap_or_else(|| PathBuf::from("logbook.db"))
+    }
+
+    /// All QSOs, newest first.
+    pub fn list(&self) -> Vec<Qso> {
+        let db = self.db.read().unwrap_or_else(|e| e.into_inner());
+        let mut list: Vec<Qso> = db
+            .iter()
+            .filter_map(|kv| {
+                if kv.get_key().starts_with("qso:") {
+                    kv.get_value::<Qso>()
+                } else {
+                    None
+                }
+            })
+            .collect();
+        list.sort_by_key(|q| std::cmp::Reverse(q.ts_ms));
+        list
+    }
+
+    pub fn get(&self, id: &str) -> Option<Qso> {
+        let db = self.db.read().unwrap_or_else(|e| e.into_inner());
+        db.get::<Qso>(&format!("qso:{id}"))
+    }
+
+    /// Insert a QSO. Returns false if the DB write fails.
+    pub fn insert(&self, qso: &Qso) -> bool {
+        let mut db = self.db.write().unwrap_or_else(|e| e.into_inner());
+        db.set(&format!("qso:{}", qso.id), qso).is_ok()
+    }
+
+    /// Update an existing QSO by id. Returns false if not found.
+    pub fn upsert(&self, id: &str, qso: &Qso) -> bool {
+        let mut db = self.db.write().unwrap_or_else(|e| e.into_inner());
+        let key = format!("qso:{id}");
+        if db.exists(&key) {
+            db.set(&key, qso).is_ok()
+        } else {
+            false
+        }
+    }
+
+    /// Remove a QSO by id. Returns false if not found.
+    pub fn remove(&self, id: &str) -> bool {
+        let mut db = self.db.write().unwrap_or_else(|e| e.into_inner());
+        db.rem(&format!("qso:{id}")).unwrap_or(false)
+    }
+
+    /// Return an existing QSO that `qso` duplicates, ignoring `qso.id` itself.
+    pub fn find_duplicate(&self, qso: &Qso) -> Option<Qso> {
+        self.list()
+            .into_iter()
+            .find(|other| other.id != qso.id && is_duplicate(other, qso))
+    }
+
+    /// Worked-before summary for `call`, or `None` if never worked.
+    pub fn worked(&self, call: &str) -> Option<WorkedSummary> {
+        let call = normalize_call(call);
+        summarize_worked(self.list().iter().filter(|q| q.call == call))
+            .into_values()
+            .next()
+    }
+
+    /// Worked-before summaries for every callsign in the log.
+    pub fn worked_all(&self) -> HashMap<String, WorkedSummary> {
+        summarize_worked(self.list().iter())
+    }
+}
+
+/// Two QSOs are duplicates when they are with the same callsign on the same
+/// band and mode and on the same UTC day.
+pub fn is_duplicate(a: &Qso, b: &Qso) -> bool {
+    const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+    a.call.eq_ignore_ascii_case(&b.call)
+        && a.band == b.band
+        && a.mode.eq_ignore_ascii_case(&b.mode)
+        && a.ts_ms.div_euclid(DAY_MS) == b.ts_ms.div_euclid(DAY_MS)
+}
+
+fn summarize_worked<'a>(qsos: impl Iterator<Item = &'a Qso>) -> HashMap<String, WorkedSummary> {
+    let mut acc: HashMap<String, (usize, BTreeSet<String>, BTreeSet<String>, i64)> = HashMap::new();
+    for qso in qsos {
+        let entry = acc
+            .entry(qso.call.clone())
+            .or_insert_with(|| (0, BTreeSet::new(), BTreeSet::new(), i64::MIN));
+        entry.0 += 1;
+        if let Some(band) = &qso.band {
+            entry.1.insert(band.clone());
+        }
+        entry.2.insert(qso.mode.to_uppercase());
+        entry.3 = entry.3.max(qso.ts_ms);
+    }
+    acc.into_iter()
+        .map(|(call, (count, bands, modes, last_ts_ms))| {
+            (
+                call,
+                WorkedSummary {
+                    count,
+                    bands: bands.into_iter().collect(),
+                    modes: modes.into_iter().collect(),
+                    last_ts_ms,
+                },
+            )
+        })
+        .collect()
+}
+
+pub fn gen_qso_id() -> String {
+    hex::encode(rand::random::<[u8; 16]>())
+}
+
+/// Upper-case a callsign and strip the `<...>` markers FT8 uses for hashed calls.
+pub fn normalize_call(call: &str) -> String {
+    call.trim()
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .to_uppercase()
+}
+
+/// Upper-case a locator, returning `None` when empty.
+pub fn normalize_locator(locator: Option<String>) -> Option<String> {
+    locator.and_then(|value| {
+        let trimmed = value.trim().to_uppercase();
+        if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed)
+        }
+    })
+}
+
+/// Band label for `freq_hz` from the rig's supported band list.
+pub fn band_for(snapshot: Option<&RigSnapshot>, freq_hz: u64) -> Option<String> {
+    snapshot.and_then(|s| Freq::new(freq_hz).band_name(&s.info.capabilities.supported_bands))
+}
+
+/// Six-character Maidenhead locator for a latitude/longitude pair.
+pub fn maidenhead(lat: f64, lon: f64) -> Option<String> {
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return None;
+    }
+    let lon = (lon + 180.0).min(359.999_999);
+    let lat = (lat + 90.0).min(179.999_999);
+    let field_lon = (lon / 20.0) as u8;
+    let field_lat = (lat / 10.0) as u8;
+    let square_lon = ((lon % 20.0) / 2.0) as u8;
+    let square_lat = (lat % 10.0) as u8;
+    let sub_lon = ((lon % 2.0) * 12.0) as u8;
+    let sub_lat = ((lat % 1.0) * 24.0) as u8;
+    Some(format!(
+        "{}{}{}{}{}{}",
+        (b'A' + field_lon) as char,
+        (b'A' + field_lat) as char,
+        square_lon,
+        square_lat,
+        (b'a' + sub_lon) as char,
+        (b'a' + sub_lat) as char,
+    ))
+}
+
+// ============================================================================
+// ADIF 3
+// ============================================================================
+
+/// Serialize QSOs as an ADIF 3 (`.adi`) document.
+pub fn to_adif(qsos: &[Qso]) -> String {
+    let now = Utc::now();
+    let mut out = String::from("trx-rs logbook export\n");
+    push_field(&mut out, "ADIF_VER", "3.1.4");
+    push_field(&mut out, "PROGRAMID", "trx-rs");
+    push_field(&mut out, "PROGRAMVERSION", env!("CARGO_PKG_VERSION"));
+    push_field(
+        &mut out,
+        "CREATED_TIMESTAMP",
+        &now.format("%Y%m%d %H%M%S").to_string(),
+    );
+    out.push_str("<EOH>\n\n");
+    for qso in qsos {
+        let ts = DateTime::from_timestamp_millis(qso.ts_ms).unwrap_or_default();
+        push_field(&mut out, "CALL", &qso.call);
+        push_field(&mut out, "QSO_DATE", &ts.format("%Y%m%d").to_string());
+        push_field(&mut out, "TIME_ON", &ts.format("%H%M%S").to_string());
+        if let Some(band) = &qso.band {
+            push_field(&mut out, "BAND", band);
+        }
+        push_field(
+            &mut out,
+            "FREQ",
+            &format!("{:.6}", qso.freq_hz as f64 / 1_000_000.0),
+        );
+        let (mode, submode) = adif_mode(&qso.mode);
+        push_field(&mut out, "MODE", &mode);
+        if let Some(submode) = submode {
+            push_field(&mut out, "SUBMODE", &submode);
+        }
+        let optional = [
+            ("RST_SENT", &qso.rst_sent),
+            ("RST_RCVD", &qso.rst_rcvd),
+            ("GRIDSQUARE", &qso.gridsquare),
+            ("MY_GRIDSQUARE", &qso.my_gridsquare),
+            ("STATION_CALLSIGN", &qso.station_callsign),
+        ];
+        for (name, value) in optional {
+            if let Some(value) = value {
+                push_field(&mut out, name, value);
+            }
+        }
+        if !qso.comment.is_empty() {
+            push_field(&mut out, "COMMENT", &qso.comment);
+        }
+        out.push_str("<EOR>\n");
+    }
+    out
+}
+
+fn push_field(out: &mut String, name: &str, value: &str) {
+    out.push_str(&format!("<{name}:{}>{value} ", value.chars().count()));
+}
+
+/// Map a UI mode to the ADIF `MODE`/`SUBMODE` pair.
+fn adif_mode(mode: &str) -> (String, Option<String>) {
+    let upper = mode.to_uppercase();
+    match upper.as_str() {
+        "FT4" | "FST4" | "JS8" => ("MFSK".to_string(), Some(upper)),
+        "USB" | "LSB" => ("SSB".to_string(), Some(upper)),
+        "CWR" => ("CW".to_string(), None),
+        "SAM" => ("AM".to_string(), None),
+        "WFM" => ("FM".to_string(), None),
+        _ => (upper, None),
+    }
+}
+
+/// Parse an ADIF (`.adi`) document into QSOs.
+///
+/// Records without `CALL`, `QSO_DATE` or `TIME_ON` are skipped. The band is
+/// taken from `BAND` when present; `FREQ` is optional.
+pub fn parse_adif(text: &str) -> Result<Vec<Qso>, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pos = 0;
+    // A header is any text before the first tag; if present it ends at <EOH>.
+    if chars.first() != Some(&'<') {
+        let mut found_eoh = false;
+        while let Some((name, _, next)) = next_tag(&chars, pos)? {
+            pos = next;
+            if name == "EOH" {
+                found_eoh = true;
+                break;
+            }
+        }
+        if !found_eoh {
+            return Err("ADIF header is missing <EOH>".to_string());
+        }
+    }
+
+    let mut qsos = Vec::new();
+    let mut fields: HashMap<String, String> = HashMap::new();
+    while let Some((name, value, next)) = next_tag(&chars, pos)? {
+        pos = next;
+        match name.as_str() {
+            "EOR" => {
+                if let Some(qso) = qso_from_fields(&fields) {
+                    qsos.push(qso);
+                }
+                fields.clear();
+            }
+            "EOH" => fields.clear(),
+            _ => {
+                if let Some(value) = value {
+                    fields.insert(name, value);
+                }
+            }
+        }
+    }
+    Ok(qsos)
+}
+
+/// Read the next `<NAME:LEN[:TYPE]>value` tag starting at `pos`.
+///
+/// Returns the upper-cased tag name, its value (for data-specifiers) and the
+/// position after the value.
+fn next_tag(chars: &[char], pos: usize) -> Result<Option<(String, Option<String>, usize)>, String> {
+    let Some(open) = chars[pos..].iter().position(|c| *c == '<').map(|i| pos + i) else {
+        return Ok(None);
+    };
+    let Some(close) = chars[open..]
+        .iter()
+        .position(|c| *c == '>')
+        .map(|i| open + i)
+    else {
+        return Err("unterminated ADIF tag".to_string());
+    };
+    let spec: String = chars[open + 1..close].iter().collect();
+    let mut parts = spec.split(':');
+    let name = parts.next().unwrap_or_default().trim().to_uppercase();
+    let Some(len) = parts.next() else {
+        return Ok(Some((name, None, close + 1)));
+    };
+    let len: usize = len
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid length in ADIF tag <{spec}>"))?;
+    let start = close + 1;
+    let end = start + len;
+    if end > chars.len() {
+        return Err(format!("ADIF field {name} runs past end of input"));
+    }
+    let value: String = chars[start..end].iter().collect();
+    Ok(Some((name, Some(value), end)))
+}
+
+fn qso_from_fields(fields: &HashMap<String, String>) -> Option<Qso> {
+    let get = |name: &str| {
+        fields
+            .get(name)
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let call = normalize_call(&get("CALL")?);
+    let date = NaiveDate::parse_from_str(&get("QSO_DATE")?, "%Y%m%d").ok()?;
+    let time_on = get("TIME_ON")?;
+    let time = NaiveTime::parse_from_str(&time_on, "%H%M%S")
+        .or_else(|_| NaiveTime::parse_from_str(&time_on, "%H%M"))
+        .ok()?;
+    let ts_ms = date.and_time(time).and_utc().timestamp_millis();
+    let freq_hz = get("FREQ")
+        .and_then(|v| v.parse::<f64>().ok())
+        .map(|mhz| (mhz * 1_000_000.0).round() as u64)
+        .unwrap_or(0);
+    let mode = match (get("MODE"), get("SUBMODE")) {
+        (_, Some(submode)) => submode.to_uppercase(),
+        (Some(mode), None) => mode.to_uppercase(),
+        (None, None) => return None,
+    };
+    Some(Qso {
+        id: gen_qso_id(),
+        call,
+        ts_ms,
+        freq_hz,
+        mode,
+        band: get("BAND").map(|b| b.to_lowercase()),
+        rst_sent: get("RST_SENT"),
+        rst_rcvd: get("RST_RCVD"),
+        gridsquare: normalize_locator(get("GRIDSQUARE")),
+        my_gridsquare: normalize_locator(get("MY_GRIDSQUARE")),
+        station_callsign: get("STATION_CALLSIGN")
+            .or_else(|| get("OPERATOR"))
+            .map(|c| normalize_call(&c)),
+        comment: get("COMMENT").unwrap_or_default(),
+        source: "adif".to_string(),
+    })
+}
+
+// ============================================================================
+// FT8/FT4 exchange tracking
+// ============================================================================
+
+/// A completed exchange reported by [`ExchangeTracker`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletedExchange {
+    pub call: String,
+    pub rig_id: Option<String>,
+    pub ts_ms: i64,
+    /// Audio offset of the worked station's signal (Hz).
+    pub audio_hz: f32,
+    pub gridsquare: Option<String>,
+    pub rst_sent: Option<String>,
+    pub rst_rcvd: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct Exchange {
+    first_ts_ms: i64,
+    last_ts_ms: i64,
+    audio_hz: f32,
+    gridsquare: Option<String>,
+    rst_sent: Option<String>,
+    rst_rcvd: Option<String>,
+}
+
+/// Follows standard-message FT8/FT4 exchanges addressed to the own callsign.
+///
+/// Only received messages are visible, so an exchange counts as complete once
+/// the other station has sent a report and then either a roger-report
+/// (`R-10`, to which we answer `RR73`) or `RR73`/`RRR`/`73`. The report we
+/// sent is taken to be the SNR of their first decoded message, as WSJT-X does.
+pub struct ExchangeTracker {
+    my_call: String,
+    exchanges: HashMap<(Option<String>, String), Exchange>,
+}
+
+impl ExchangeTracker {
+    pub fn new(my_call: &str) -> Self {
+        Self {
+            my_call: normalize_call(my_call),
+            exchanges: HashMap::new(),
+        }
+    }
+
+    /// Feed one decoded message; returns the exchange if it just completed.
+    pub fn observe(&mut self, msg: &Ft8Message) -> Option<CompletedExchange> {
+        self.exchanges
+            .retain(|_, ex| msg.ts_ms - ex.last_ts_ms < EXCHANGE_TIMEOUT_MS);
+
+        let tokens: Vec<&str> = msg.message.split_whitespace().collect();
+        if tokens.len() < 2 || normalize_call(tokens[0]) != self.my_call {
+            return None;
+        }
+        let call = normalize_call(tokens[1]);
+        if call == self.my_call || !call.chars().any(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let key = (msg.rig_id.clone(), call.clone());
+        let ex = self
+            .exchanges
+            .entry(key.clone())
+            .or_insert_with(|| Exchange {
+                first_ts_ms: msg.ts_ms,
+                audio_hz: msg.freq_hz,
+                rst_sent: Some(format_report(msg.snr_db)),
+                ..Exchange::default()
+            });
+        ex.last_ts_ms = msg.ts_ms;
+
+        let complete = match tokens.get(2).copied() {
+            Some("RR73" | "RRR" | "73") => ex.rst_rcvd.is_some(),
+            Some(tok) if tok.starts_with('R') && is_report(&tok[1..]) => {
+                ex.rst_rcvd = Some(tok[1..].to_string());
+                true
+            }
+            Some(tok) if is_report(tok) => {
+                ex.rst_rcvd = Some(tok.to_string());
+                false
+            }
+            Some(tok) if is_grid4(tok) => {
+                ex.gridsquare = Some(tok.to_string());
+                false
+            }
+            _ => false,
+        };
+        if !complete {
+            return None;
+        }
+        let ex = self.exchanges.remove(&key)?;
+        Some(CompletedExchange {
+            call,
+            rig_id: msg.rig_id.clone(),
+            ts_ms: ex.first_ts_ms,
+            audio_hz: ex.audio_hz,
+            gridsquare: ex.gridsquare,
+            rst_sent: ex.rst_sent,
+            rst_rcvd: ex.rst_rcvd,
+        })
+    }
+}
+
+fn format_report(snr_db: f32) -> String {
+    format!("{:+03}", snr_db.round().clamp(-30.0, 30.0) as i32)
+}
+
+fn is_report(tok: &str) -> bool {
+    let Some(digits) = tok.strip_prefix('-').or_else(|| tok.strip_prefix('+')) else {
+        return false;
+    };
+    (1..=2).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_grid4(tok: &str) -> bool {
+    let b = tok.as_bytes();
+    tok != "RR73"
+        && b.len() == 4
+        && (b'A'..=b'R').contains(&b[0])
+        && (b'A'..=b'R').contains(&b[1])
+        && b[2].is_ascii_digit()
+        && b[3].is_ascii_digit()
+}
+
+// ============================================================================
+// Auto-log task
+// ============================================================================
+
+/// Current snapshot of `rig_id`, or of the active rig when `None`.
+pub fn rig_snapshot(context: &FrontendRuntimeContext, rig_id: Option<&str>) -> Option<RigSnapshot> {
+    let active = context
+        .routing
+        .active_rig_id
+        .lock()
+        .ok()
+        .and_then(|g| g.clone());
+    let rig_id = rig_id.map(str::to_owned).or(active)?;
+    context
+        .routing
+        .remote_rigs
+        .lock()
+        .ok()?
+        .iter()
+        .find(|r| r.rig_id == rig_id)
+        .map(|r| r.state.clone())
+}
+
+/// Build a logbook entry for a completed exchange using the rig's dial
+/// frequency and station position.
+fn qso_from_exchange(
+    context: &FrontendRuntimeContext,
+    my_call: &str,
+    mode: &str,
+    ex: CompletedExchange,
+) -> Qso {
+    let snapshot = rig_snapshot(context, ex.rig_id.as_deref());
+    let dial_hz = snapshot.as_ref().map(|s| s.status.freq.hz).unwrap_or(0);
+    let freq_hz = dial_hz + ex.audio_hz.max(0.0).round() as u64;
+    let my_gridsquare = snapshot
+        .as_ref()
+        .and_then(|s| Some((s.server_latitude?, s.server_longitude?)))
+        .and_then(|(lat, lon)| maidenhead(lat, lon));
+    Qso {
+        id: gen_qso_id(),
+        call: ex.call,
+        ts_ms: ex.ts_ms,
+        freq_hz,
+        mode: mode.to_string(),
+        band: band_for(snapshot.as_ref(), freq_hz),
+        rst_sent: ex.rst_sent,
+        rst_rcvd: ex.rst_rcvd,
+        gridsquare: ex.gridsquare,
+        my_gridsquare,
+        station_callsign: Some(normalize_call(my_call)),
+        comment: String::new(),
+        source: mode.to_lowercase(),
+    }
+}
+
+/// Spawn the task that logs completed FT8/FT4 exchanges with `my_call`.
+pub fn spawn_auto_log_task(
+    context: Arc<FrontendRuntimeContext>,
+    store: Arc<LogbookStore>,
+    my_call: String,
+) {
+    let Some(tx) = context.audio.decode_rx.as_ref().cloned() else {
+        return;
+    };
+    info!("logbook: auto-logging FT8/FT4 exchanges for {}", my_call);
+    tokio::spawn(async move {
+        let mut rx = tx.subscribe();
+        let mut ft8 = ExchangeTracker::new(&my_call);
+        let mut ft4 = ExchangeTracker::new(&my_call);
+        loop {
+            let (mode, completed) = match rx.recv().await {
+                Ok(DecodedMessage::Ft8(msg)) => ("FT8", ft8.observe(&msg)),
+                Ok(DecodedMessage::Ft4(msg)) => ("FT4", ft4.observe(&msg)),
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            let Some(ex) = completed else {
+                continue;
+            };
+            let qso = qso_from_exchange(&context, &my_call, mode, ex);
+            if store.find_duplicate(&qso).is_some() {
+                continue;
+            }
+            if store.insert(&qso) {
+                info!(
+                    "logbook: logged {} QSO with {} on {}",
+                    mode,
+                    qso.call,
+                    qso.band.as_deref().unwrap_or("?")
+                );
+            } else {
+                warn!("logbook: failed to save {} QSO with {}", mode, qso.call);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ft8(ts_ms: i64, snr_db: f32, message: &str) -> Ft8Message {
+        Ft8Message {
+            rig_id: None,
+            ts_ms,
+            snr_db,
+            dt_s: 0.1,
+            freq_hz: 1500.0,
+            message: message.to_string(),
+        }
+    }
+
+    fn qso(call: &str, ts_ms: i64, band: &str, mode: &str) -> Qso {
+        Qso {
+            id: gen_qso_id(),
+            call: call.to_string(),
+            ts_ms,
+            freq_hz: 14_074_000,
+            mode: mode.to_string(),
+            band: Some(band.to_string()),
+            rst_sent: Some("-10".to_string()),
+            rst_rcvd: Some("-05".to_string()),
+            gridsquare: Some("KO02".to_string()),
+            my_gridsquare: None,
+            station_callsign: Some("SP5XYZ".to_string()),
+            comment: "first <test>".to_string(),
+            source: "manual".to_string(),
+        }
+    }
+
+    #[test]
+    fn adif_round_trip() {
+        let original = vec![
+            qso("DL1ABC", 1_760_000_000_000, "20m", "FT8"),
+            qso("G4XYZ", 1_760_000_060_000, "20m", "FT4"),
+        ];
+        let text = to_adif(&original);
+        assert!(text.contains("<MODE:4>MFSK <SUBMODE:3>FT4"));
+        let parsed = parse_adif(&text).expect("parse");
+        assert_eq!(parsed.len(), 2);
+        for (a, b) in original.iter().zip(parsed.iter()) {
+            assert_eq!(a.call, b.call);
+            assert_eq!(a.ts_ms, b.ts_ms);
+            assert_eq!(a.freq_hz, b.freq_hz);
+            assert_eq!(a.mode, b.mode);
+            assert_eq!(a.band, b.band);
+            assert_eq!(a.gridsquare, b.gridsquare);
+            assert_eq!(a.comment, b.comment);
+        }
+    }
+
+    #[test]
+    fn parse_adif_without_header_and_lowercase_tags() {
+        let text = "<call:5>ea1aa <qso_date:8>20260101 <time_on:4>1230 <band:3>40M <mode:3>SSB <submode:3>LSB <eor>";
+        let parsed = parse_adif(text).expect("parse");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].call, "EA1AA");
+        assert_eq!(parsed[0].mode, "LSB");
+        assert_eq!(parsed[0].band.as_deref(), Some("40m"));
+        assert_eq!(parsed[0].freq_hz, 0);
+    }
+
+    #[test]
+    fn parse_adif_rejects_truncated_field() {
+        assert!(parse_adif("<CALL:10>AB1 <EOR>").is_err());
+    }
+
+    #[test]
+    fn duplicates_are_same_call_band_mode_and_day() {
+        let a = qso("DL1ABC", 1_760_000_000_000, "20m", "FT8");
+        let mut b = qso("dl1abc", 1_760_000_000_000 + 3_600_000, "20m", "ft8");
+        assert!(is_duplicate(&a, &b));
+        b.band = Some("40m".to_string());
+        assert!(!is_duplicate(&a, &b));
+        let c = qso("DL1ABC", 1_760_000_000_000 + 86_400_000, "20m", "FT8");
+        assert!(!is_duplicate(&a, &c));
+    }
+
+    #[test]
+    fn tracker_completes_on_roger_report() {
+        let mut t = ExchangeTracker::new("sp5xyz");
+        assert!(t.observe(&ft8(0, -8.4, "SP5XYZ DL1ABC JO62")).is_none());
+        let done = t
+            .observe(&ft8(30_000, -9.0, "SP5XYZ DL1ABC R-12"))
+            .expect("complete");
+        assert_eq!(done.call, "DL1ABC");
+        assert_eq!(done.ts_ms, 0);
+        assert_eq!(done.gridsquare.as_deref(), Some("JO62"));
+        assert_eq!(done.rst_sent.as_deref(), Some("-08"));
+        assert_eq!(done.rst_rcvd.as_deref(), Some("-12"));
+    }
+
+    #[test]
+    fn tracker_completes_on_rr73_after_report() {
+        let mut t = ExchangeTracker::new("SP5XYZ");
+        assert!(t.observe(&ft8(0, 3.0, "CQ DL1ABC JO62")).is_none());
+        assert!(t.observe(&ft8(15_000, 3.0, "SP5XYZ DL1ABC RR73")).is_none());
+        assert!(t.observe(&ft8(30_000, 3.0, "SP5XYZ DL1ABC +03")).is_none());
+        let done = t
+            .observe(&ft8(60_000, 2.0, "SP5XYZ DL1ABC RR73"))
+            .expect("complete");
+        assert_eq!(done.rst_rcvd.as_deref(), Some("+03"));
+        assert_eq!(done.gridsquare, None);
+    }
+
+    #[test]
+    fn tracker_expires_stale_exchanges() {
+        let mut t = ExchangeTracker::new("SP5XYZ");
+        assert!(t.observe(&ft8(0, 0.0, "SP5XYZ DL1ABC -10")).is_none());
+        let late = EXCHANGE_TIMEOUT_MS + 1;
+        assert!(t.observe(&ft8(late, 0.0, "SP5XYZ DL1ABC RR73")).is_none());
+    }
+
+    #[test]
+    fn maidenhead_locator() {
+        assert_eq!(maidenhead(52.2297, 21.0122).as_deref(), Some("KO02mf"));
+        assert_eq!(maidenhead(95.0, 0.0), None);
+    }
+}
@@ -12,6 +12,10 @@ pub mod auth;
 pub mod background_decode;
 #[path = "bookmarks.rs"]
 pub mod bookmarks;
+#[path = "dxcluster.rs"]
+pub mod dxcluster;
+#[path = "logbook.rs"]
+pub mod logbook;
 #[path = "recorder.rs"]
 pub mod recorder;
 #[path = "scheduler.rs"]
@@ -42,6 +46,7 @@ use trx_frontend::{FrontendRuntimeContext, FrontendSpawner};
 
 use auth::{AuthConfig, AuthState, SameSite};
 use background_decode::{BackgroundDecodeManager, BackgroundDecodeStore};
+use logbook::LogbookStore;
 use recorder::{RecorderConfig, RecorderManager};
 use scheduler::{
     SchedulerControlManager, SchedulerStatusMap, SchedulerStoreMap, SharedActivityLogMap,
@@ -88,6 +93,7 @@ async fn serve(
     let rig_id_refs: Vec<&str> = rig_ids.iter().map(String::as_str).collect();
     let scheduler_store = Arc::new(SchedulerStoreMap::new(&rig_id_refs));
     let bookmark_store_map = Arc::new(bookmarks::BookmarkStoreMap::new());
+    let logbook_store = Arc::new(LogbookStore::open(&LogbookStore::default_path()));
     let scheduler_status: SchedulerStatusMap = Arc::new(RwLock::new(HashMap::new()));
     let scheduler_control = Arc::new(SchedulerControlManager::default());
     let activity_log_map: SharedActivityLogMap = Arc::new(RwLock::new(HashMap::new()));
@@ -149,6 +155,18 @@ async fn serve(
         });
     }
 
+    dxcluster::spawn_dx_cluster_task(context.clone());
+
+    if context.http_ui.logbook_auto_log {
+        if let Some(my_call) = callsign.as_deref().filter(|c| !c.trim().is_empty()) {
+            logbook::spawn_auto_log_task(
+                context.clone(),
+                logbook_store.clone(),
+                my_call.to_string(),
+            );
+        }
+    }
+
     let server = build_server(
         addr,
         state_rx,
@@ -156,6 +174,7 @@ async fn serve(
         callsign,
         context,
         bookmark_store_map,
+        logbook_store,
         scheduler_store,
         scheduler_status,
         scheduler_control,
@@ -184,6 +203,7 @@ fn build_server(
     _callsign: Option<String>,
     context: Arc<FrontendRuntimeContext>,
     bookmark_store_map: Arc<bookmarks::BookmarkStoreMap>,
+    logbook_store: Arc<LogbookStore>,
     scheduler_store: Arc<SchedulerStoreMap>,
     scheduler_status: SchedulerStatusMap,
     scheduler_control: Arc<SchedulerControlManager>,
@@ -200,6 +220,7 @@ fn build_server(
     let clients = web::Data::new(context.sse_clients.clone());
 
     let bookmark_store = web::Data::new(bookmark_store_map);
+    let logbook_store = web::Data::new(logbook_store);
 
     let scheduler_store = web::Data::new(scheduler_store);
     let scheduler_status = web::Data::new(scheduler_status);
@@ -259,6 +280,7 @@ fn build_server(
             .app_data(context_data.clone())
             .app_data(auth_state.clone())
             .app_data(bookmark_store.clone())
+            .app_data(logbook_store.clone())
             .app_data(scheduler_store.clone())
             .app_data(scheduler_status.clone())
             .app_data(scheduler_control.clone())
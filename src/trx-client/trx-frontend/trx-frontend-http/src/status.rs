@@ -27,9 +27,15 @@ pub const FT4_JS: &str = include_str!("../assets/web/plugins/ft4.js");
 pub const FT2_JS: &str = include_str!("../assets/web/plugins/ft2.js");
 pub const WSPR_JS: &str = include_str!("../assets/web/plugins/wspr.js");
 pub const CW_JS: &str = include_str!("../assets/web/plugins/cw.js");
+pub const PSK_JS: &str = include_str!("../assets/web/plugins/psk.js");
+pub const DSC_JS: &str = include_str!("../assets/web/plugins/dsc.js");
+pub const POCSAG_JS: &str = include_str!("../assets/web/plugins/pocsag.js");
+pub const ADSB_JS: &str = include_str!("../assets/web/plugins/adsb.js");
+pub const ACARS_JS: &str = include_str!("../assets/web/plugins/acars.js");
 pub const SAT_JS: &str = include_str!("../assets/web/plugins/sat.js");
 pub const WEFAX_JS: &str = include_str!("../assets/web/plugins/wefax.js");
 pub const BOOKMARKS_JS: &str = include_str!("../assets/web/plugins/bookmarks.js");
+pub const LOGBOOK_JS: &str = include_str!("../assets/web/plugins/logbook.js");
 pub const SCHEDULER_JS: &str = include_str!("../assets/web/plugins/scheduler.js");
 pub const SAT_SCHEDULER_JS: &str = include_str!("../assets/web/plugins/sat-scheduler.js");
 pub const BACKGROUND_DECODE_JS: &str = include_str!("../assets/web/plugins/background-decode.js");
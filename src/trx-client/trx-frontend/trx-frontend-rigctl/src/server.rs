@@ -17,14 +17,15 @@ use trx_protocol::{mode_to_string, parse_mode};
 
 use trx_core::radio::freq::Freq;
 use trx_core::rig::state::RigSnapshot;
-use trx_core::{RigCommand, RigMode, RigRequest, RigState};
+use trx_core::{RigCommand, RigError, RigMode, RigRequest, RigState};
 use trx_frontend::FrontendSpawner;
 
 /// rigctl-compatible frontend.
 ///
-/// This exposes a small subset of the rigctl/rigctld ASCII protocol to allow
-/// existing tooling to drive the rig. The implementation is intentionally
-/// minimal and only covers the operations supported by the core rig task.
+/// This speaks the rigctld ASCII protocol, short and long command forms with
+/// plain or extended replies, so Hamlib's `netrigctl` backend and the
+/// programs built on it can drive the rig.  Commands the core rig task has
+/// no equivalent for answer with Hamlib's `RPRT` error codes.
 pub struct RigctlFrontend;
 
 impl FrontendSpawner for RigctlFrontend {
@@ -75,34 +76,40 @@ async fn serve(
 async fn handle_client(
     stream: TcpStream,
     addr: SocketAddr,
-    mut state_rx: watch::Receiver<RigState>,
+    state_rx: watch::Receiver<RigState>,
     rig_tx: mpsc::Sender<RigRequest>,
 ) -> std::io::Result<()> {
     let (reader, mut writer) = stream.into_split();
     let mut reader = BufReader::new(reader);
     let mut line = String::new();
+    let mut session = Session::default();
 
-    loop {
-        line.clear();
-        let bytes_read = reader.read_line(&mut line).await?;
-        if bytes_read == 0 {
-            debug!("rigctl client {} disconnected", addr);
-            break;
-        }
+    let result = async {
+        loop {
+            line.clear();
+            let bytes_read = reader.read_line(&mut line).await?;
+            if bytes_read == 0 {
+                debug!("rigctl client {} disconnected", addr);
+                break;
+            }
 
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
 
-        match process_command(trimmed, &mut state_rx, &rig_tx).await {
-            CommandResult::Reply(resp) => writer.write_all(resp.as_bytes()).await?,
-            CommandResult::Close => break,
+            match process_command(trimmed, &mut session, &state_rx, &rig_tx).await {
+                CommandResult::Reply(resp) => writer.write_all(resp.as_bytes()).await?,
+                CommandResult::Close => break,
+            }
+            writer.flush().await?;
         }
-        writer.flush().await?;
+        Ok(())
     }
+    .await;
 
-    Ok(())
+    session.finish(&rig_tx).await;
+    result
 }
 
 enum CommandResult {
@@ -110,208 +117,623 @@ enum CommandResult {
     Close,
 }
 
+/// Hamlib `rig_errcode_e` values, reported as `RPRT -<code>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rprt {
+    /// Invalid parameter (`RIG_EINVAL`).
+    Inval = 1,
+    /// The command is not implemented (`RIG_ENIMPL`).
+    Enimpl = 4,
+    /// Timed out waiting for the rig (`RIG_ETIMEOUT`).
+    Timeout = 5,
+    /// Communication with the rig failed (`RIG_EIO`).
+    Io = 6,
+    /// The rig refused the command (`RIG_ERJCTED`).
+    Rejected = 9,
+    /// The rig lacks the feature (`RIG_ENAVAIL`).
+    Navail = 11,
+}
+
+/// A failed command: the code sent to the client and a message for the log.
+#[derive(Debug)]
+struct CmdError {
+    code: Rprt,
+    msg: String,
+}
+
+impl CmdError {
+    fn new(code: Rprt, msg: impl Into<String>) -> Self {
+        Self {
+            code,
+            msg: msg.into(),
+        }
+    }
+
+    fn inval(msg: impl Into<String>) -> Self {
+        Self::new(Rprt::Inval, msg)
+    }
+
+    fn navail(msg: impl Into<String>) -> Self {
+        Self::new(Rprt::Navail, msg)
+    }
+
+    fn enimpl() -> Self {
+        Self::new(Rprt::Enimpl, "unsupported command")
+    }
+}
+
+/// Result of a successful command.
+enum Reply {
+    /// Values of a get command with their Hamlib labels.
+    Values(Vec<(&'static str, String)>),
+    /// A set command succeeded.
+    Done,
+    /// Lines printed verbatim (`dump_state`, `dump_caps`).
+    Raw(Vec<String>),
+}
+
+/// How replies are framed.  A `+`, `;`, `|` or `,` before the command
+/// selects rigctld's extended response: the command is echoed, values are
+/// labelled and separated by that character (newline for `+`), and every
+/// reply ends with `RPRT n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReplyMode {
+    Plain,
+    Extended(char),
+}
+
+/// Per-connection state.  Split is emulated: with split on, keying PTT
+/// retunes to the split frequency for the over and back afterwards.  Mode
+/// lock is emulated too: while set, `set_mode` is rejected.
+#[derive(Debug, Default)]
+struct Session {
+    lock_mode: bool,
+    split: bool,
+    split_tx_vfo: Option<String>,
+    split_tx_hz: Option<u64>,
+    /// RX frequency to return to while keyed on the split frequency.
+    split_rx_hz: Option<u64>,
+}
+
+impl Session {
+    /// Unkey and retune if the client left while keyed on split.
+    async fn finish(&mut self, rig_tx: &mpsc::Sender<RigRequest>) {
+        if let Some(rx_hz) = self.split_rx_hz.take() {
+            let _ = send_rig_command(rig_tx, RigCommand::SetPtt(false)).await;
+            let _ = send_set_freq_with_compat_retry(rig_tx, rx_hz).await;
+        }
+    }
+}
+
+/// Map a short (`f`) or long (`\get_freq`) command to its long name.
+fn command_name(op: &str) -> Option<&'static str> {
+    let name = match op {
+        "q" | "Q" => "quit",
+        "f" => "get_freq",
+        "F" => "set_freq",
+        "m" => "get_mode",
+        "M" => "set_mode",
+        "v" => "get_vfo",
+        "V" => "set_vfo",
+        "t" => "get_ptt",
+        "T" => "set_ptt",
+        "s" => "get_split_vfo",
+        "S" => "set_split_vfo",
+        "i" => "get_split_freq",
+        "I" => "set_split_freq",
+        "j" => "get_rit",
+        "J" => "set_rit",
+        "z" => "get_xit",
+        "Z" => "set_xit",
+        "l" => "get_level",
+        "L" => "set_level",
+        "b" => "send_morse",
+        "_" => "get_info",
+        "1" => "dump_caps",
+        _ => {
+            // Long names, with or without the leading backslash.
+            const LONG: &[&str] = &[
+                "quit",
+                "get_freq",
+                "set_freq",
+                "get_mode",
+                "set_mode",
+                "get_vfo",
+                "set_vfo",
+                "get_ptt",
+                "set_ptt",
+                "get_split_vfo",
+                "set_split_vfo",
+                "get_split_freq",
+                "set_split_freq",
+                "get_rit",
+                "set_rit",
+                "get_xit",
+                "set_xit",
+                "get_level",
+                "set_level",
+                "send_morse",
+                "get_info",
+                "dump_caps",
+                "dump_state",
+                "chk_vfo",
+                "get_powerstat",
+                "set_powerstat",
+                "get_lock_mode",
+                "set_lock_mode",
+            ];
+            let long = op.strip_prefix('\\').unwrap_or(op);
+            let long = if long == "dumpcaps" {
+                "dump_caps"
+            } else {
+                long
+            };
+            return LONG.iter().find(|name| **name == long).copied();
+        }
+    };
+    Some(name)
+}
+
 async fn process_command(
     cmd_line: &str,
-    state_rx: &mut watch::Receiver<RigState>,
+    session: &mut Session,
+    state_rx: &watch::Receiver<RigState>,
     rig_tx: &mpsc::Sender<RigRequest>,
 ) -> CommandResult {
     debug!("rigctl command: {}", cmd_line);
-    let mut parts = cmd_line.split_whitespace();
-    let Some(raw_op) = parts.next() else {
-        return CommandResult::Reply(err_response("empty command"));
+    let (mode, line) = match cmd_line.chars().next() {
+        Some(c @ (';' | '|' | ',')) => (ReplyMode::Extended(c), &cmd_line[1..]),
+        Some('+') => (ReplyMode::Extended('\n'), &cmd_line[1..]),
+        _ => (ReplyMode::Plain, cmd_line),
     };
-    let extended = raw_op.starts_with('+');
-    let op = raw_op.trim_start_matches('+').trim_end_matches(':');
-
-    let resp = match op {
-        "q" | "Q" | "\\q" | "\\quit" => return CommandResult::Close,
-        "f" | "\\get_freq" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => ok_response(op, extended, [snapshot.status.freq.hz.to_string()]),
-            Err(e) => err_response(&e),
-        },
-        "F" | "\\set_freq" => match parts.next().and_then(parse_freq_hz_arg) {
-            Some(freq) => match send_set_freq_with_compat_retry(rig_tx, freq).await {
-                Ok(_) => ok_only(op, extended),
-                Err(e) => err_response(&e),
-            },
-            None => err_response("expected frequency in Hz"),
-        },
-        "l" | "\\get_level" => {
-            // Hamlib may probe optional levels during open (e.g. KEYSPD).
-            // Return a benign default to keep client compatibility.
-            let _level_name = parts.next();
-            ok_response(op, extended, ["0"])
-        }
-        "m" | "\\get_mode" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => {
-                let mode = rig_mode_to_str(&snapshot.status.mode);
-                ok_response(op, extended, [mode, "0".to_string()])
+    let line = line.trim_start();
+    let (op, rest) = line
+        .split_once(char::is_whitespace)
+        .map_or((line, ""), |(op, rest)| (op, rest.trim()));
+    let op = op.trim_end_matches(':');
+    let args: Vec<&str> = rest.split_whitespace().collect();
+
+    let Some(name) = command_name(op) else {
+        warn!("rigctl unsupported command: {}", cmd_line);
+        return CommandResult::Reply(format_reply(
+            op.trim_start_matches('\\'),
+            &args,
+            mode,
+            Err(CmdError::enimpl()),
+        ));
+    };
+    if name == "quit" {
+        return CommandResult::Close;
+    }
+    let result = execute(name, &args, rest, session, state_rx, rig_tx).await;
+    CommandResult::Reply(format_reply(name, &args, mode, result))
+}
+
+async fn execute(
+    name: &str,
+    args: &[&str],
+    rest: &str,
+    session: &mut Session,
+    state_rx: &watch::Receiver<RigState>,
+    rig_tx: &mpsc::Sender<RigRequest>,
+) -> Result<Reply, CmdError> {
+    match name {
+        "get_freq" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            // While keyed on the split frequency the RX VFO is the one kept.
+            let hz = session.split_rx_hz.unwrap_or(snapshot.status.freq.hz);
+            Ok(values([("Frequency", hz.to_string())]))
+        }
+        "set_freq" => {
+            let freq = args
+                .first()
+                .and_then(|a| parse_freq_hz_arg(a))
+                .ok_or_else(|| CmdError::inval("expected frequency in Hz"))?;
+            send_set_freq_with_compat_retry(rig_tx, freq).await?;
+            Ok(Reply::Done)
+        }
+        "get_mode" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            let passband = snapshot.filter.as_ref().map_or(0, |f| f.bandwidth_hz);
+            Ok(values([
+                ("Mode", rig_mode_to_str(&snapshot.status.mode)),
+                ("Passband", passband.to_string()),
+            ]))
+        }
+        "set_mode" => {
+            let mode_str = args
+                .first()
+                .ok_or_else(|| CmdError::inval("expected mode"))?;
+            if session.lock_mode {
+                return Err(CmdError::new(Rprt::Rejected, "mode is locked"));
             }
-            Err(e) => err_response(&e),
-        },
-        "M" | "\\set_mode" => {
-            let Some(mode_str) = parts.next() else {
-                return CommandResult::Reply(err_response("expected mode"));
+            // Passband: -1 keeps the current width, 0 the mode default.
+            let passband = match args.get(1) {
+                Some(pb) => pb
+                    .parse::<i64>()
+                    .map_err(|_| CmdError::inval("expected passband in Hz"))?,
+                None => -1,
             };
-            let mode = parse_mode(mode_str);
-            match send_rig_command(rig_tx, RigCommand::SetMode(mode)).await {
-                Ok(_) => ok_only(op, extended),
-                Err(e) => err_response(&e),
+            let snapshot =
+                send_rig_command(rig_tx, RigCommand::SetMode(parse_mode(mode_str))).await?;
+            if passband > 0 && snapshot.info.capabilities.filter_controls {
+                let hz =
+                    u32::try_from(passband).map_err(|_| CmdError::inval("passband too large"))?;
+                send_rig_command(rig_tx, RigCommand::SetBandwidth(hz)).await?;
             }
+            Ok(Reply::Done)
         }
-        "t" | "\\get_ptt" | "get_ptt" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => ok_response(
-                op,
-                extended,
-                [if snapshot.status.tx_en { "1" } else { "0" }.to_string()],
-            ),
-            Err(e) => err_response(&e),
-        },
-        "T" | "\\set_ptt" | "set_ptt" => match parse_ptt_tokens(parts.collect()) {
-            Some(v) => {
-                let snapshot = match current_snapshot(state_rx) {
-                    Some(s) => s,
-                    None => match request_snapshot(rig_tx).await {
-                        Ok(s) => s,
-                        Err(e) => return CommandResult::Reply(err_response(&e)),
-                    },
-                };
-                if !rig_supports_ptt(&snapshot) {
-                    return CommandResult::Reply(err_response("PTT not supported"));
-                }
-
-                match parse_ptt_arg(&v) {
-                    Some(ptt) => {
-                        debug!("rigctl ptt request: cmd='{}' parsed_ptt={}", cmd_line, ptt);
-                        match send_rig_command(rig_tx, RigCommand::SetPtt(ptt)).await {
-                            Ok(_) => ok_only(op, extended),
-                            Err(e) => err_response(&e),
-                        }
-                    }
-                    None => err_response("expected PTT state (0/1)"),
-                }
+        "get_ptt" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(values([("PTT", bool_arg(snapshot.status.tx_en))]))
+        }
+        "set_ptt" => {
+            let ptt = parse_ptt_tokens(args.to_vec())
+                .as_deref()
+                .and_then(parse_ptt_arg)
+                .ok_or_else(|| CmdError::inval("expected PTT state (0/1)"))?;
+            let snapshot = snapshot_or_request(state_rx, rig_tx).await?;
+            if !rig_supports_ptt(&snapshot) {
+                return Err(CmdError::navail("PTT not supported"));
             }
-            _ => err_response("expected PTT state (0/1)"),
-        },
-        "v" | "\\get_vfo" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => ok_response(op, extended, [active_vfo_label(&snapshot)]),
-            Err(e) => err_response(&e),
-        },
-        "V" | "\\set_vfo" => {
-            let Some(target) = parts.next() else {
-                return CommandResult::Reply(err_response("expected VFO (VFOA/VFOB)"));
+            debug!("rigctl ptt request: args={:?} parsed_ptt={}", args, ptt);
+            set_ptt(session, rig_tx, &snapshot, ptt).await?;
+            Ok(Reply::Done)
+        }
+        "get_vfo" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(values([("VFO", active_vfo_label(&snapshot))]))
+        }
+        "set_vfo" => {
+            let target = args
+                .first()
+                .ok_or_else(|| CmdError::inval("expected VFO (VFOA/VFOB)"))?;
+            set_vfo_target(target, rig_tx).await?;
+            Ok(Reply::Done)
+        }
+        "get_split_vfo" => {
+            let tx_vfo = match &session.split_tx_vfo {
+                Some(vfo) if session.split => vfo.clone(),
+                _ => active_vfo_label(&request_snapshot(rig_tx).await?),
             };
-            match set_vfo_target(target, rig_tx).await {
-                Ok(()) => ok_only(op, extended),
-                Err(e) => err_response(&e),
-            }
+            Ok(values([
+                ("Split", bool_arg(session.split)),
+                ("TX VFO", tx_vfo),
+            ]))
+        }
+        "set_split_vfo" => {
+            let split = match args.first() {
+                Some(v) if is_true(v) => true,
+                Some(v) if is_false(v) => false,
+                _ => return Err(CmdError::inval("expected split state (0/1)")),
+            };
+            let tx_vfo = match args.get(1) {
+                Some(v) => {
+                    Some(normalize_vfo_name(v).ok_or_else(|| CmdError::inval("expected TX VFO"))?)
+                }
+                None => None,
+            };
+            session.split = split;
+            session.split_tx_vfo = tx_vfo.or_else(|| split.then(|| "VFOB".to_string()));
+            Ok(Reply::Done)
+        }
+        "get_split_freq" => {
+            let hz = match session.split_tx_hz {
+                Some(hz) => hz,
+                None => request_snapshot(rig_tx).await?.status.freq.hz,
+            };
+            Ok(values([("TX Frequency", hz.to_string())]))
+        }
+        "set_split_freq" => {
+            let hz = parse_vfo_tokens(args)
+                .and_then(parse_freq_hz_arg)
+                .ok_or_else(|| CmdError::inval("expected TX frequency in Hz"))?;
+            session.split_tx_hz = Some(hz);
+            Ok(Reply::Done)
+        }
+        "get_rit" | "get_xit" => {
+            let xit = name == "get_xit";
+            let snapshot = request_snapshot(rig_tx).await?;
+            let label = if xit { "XIT" } else { "RIT" };
+            Ok(values([(
+                label,
+                clarifier_offset(&snapshot, xit).to_string(),
+            )]))
+        }
+        "set_rit" | "set_xit" => {
+            let offset_hz = parse_offset_tokens(args.to_vec())
+                .ok_or_else(|| CmdError::inval("expected offset in Hz"))?;
+            let cmd = if name == "set_xit" {
+                RigCommand::SetXit(offset_hz)
+            } else {
+                RigCommand::SetRit(offset_hz)
+            };
+            send_rig_command(rig_tx, cmd).await?;
+            Ok(Reply::Done)
+        }
+        "get_level" => {
+            let level = args
+                .first()
+                .ok_or_else(|| CmdError::inval("expected level name"))?;
+            let snapshot = request_snapshot(rig_tx).await?;
+            Ok(values([("Level Value", get_level(level, &snapshot)?)]))
         }
-        "s" | "\\get_split_vfo" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => {
-                // split state, tx vfo
-                ok_response(op, extended, ["0".to_string(), active_vfo_label(&snapshot)])
+        "set_level" => {
+            let [level, value] = args else {
+                return Err(CmdError::inval("expected level name and value"));
+            };
+            let level = level.to_ascii_uppercase();
+            // An unavailable level is reported before its value is looked at.
+            let snapshot = snapshot_or_request(state_rx, rig_tx).await?;
+            check_level(&level, &snapshot)?;
+            let value = value
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or_else(|| CmdError::inval("expected level value"))?;
+            send_rig_command(rig_tx, set_level_command(&level, value)?).await?;
+            Ok(Reply::Done)
+        }
+        "send_morse" => {
+            if rest.is_empty() {
+                return Err(CmdError::inval("expected text"));
             }
-            Err(e) => err_response(&e),
-        },
-        "S" | "\\set_split_vfo" => match parts.next() {
-            Some(v) if is_false(v) => ok_only(op, extended),
-            Some(v) if is_true(v) => err_response("split mode not supported"),
-            _ => err_response("expected split state (0/1)"),
-        },
-        "\\get_info" => {
-            let snapshot = match current_snapshot(state_rx) {
-                Some(s) => s,
-                None => match request_snapshot(rig_tx).await {
-                    Ok(s) => s,
-                    Err(e) => return CommandResult::Reply(err_response(&e)),
+            send_rig_command(
+                rig_tx,
+                RigCommand::SendCw {
+                    text: rest.to_string(),
+                    call: None,
                 },
+            )
+            .await?;
+            Ok(Reply::Done)
+        }
+        "get_powerstat" => {
+            let snapshot = request_snapshot(rig_tx).await?;
+            // Backends that do not report power are assumed on.
+            Ok(values([(
+                "Power Status",
+                bool_arg(snapshot.enabled != Some(false)),
+            )]))
+        }
+        "set_powerstat" => {
+            // RIG_POWER_OFF = 0, RIG_POWER_ON = 1, RIG_POWER_OPERATE = 4.
+            let cmd = match args.first().map(|a| a.parse::<u32>()) {
+                Some(Ok(0)) => RigCommand::PowerOff,
+                Some(Ok(1 | 4)) => RigCommand::PowerOn,
+                Some(Ok(_)) => return Err(CmdError::navail("power state not supported")),
+                _ => return Err(CmdError::inval("expected power state")),
+            };
+            send_rig_command(rig_tx, cmd).await?;
+            Ok(Reply::Done)
+        }
+        "get_lock_mode" => Ok(values([("Locked", bool_arg(session.lock_mode))])),
+        "set_lock_mode" => {
+            session.lock_mode = match args.first() {
+                Some(v) if is_true(v) => true,
+                Some(v) if is_false(v) => false,
+                _ => return Err(CmdError::inval("expected lock state (0/1)")),
             };
+            Ok(Reply::Done)
+        }
+        "chk_vfo" => {
+            // VFO mode is off: commands never carry a VFO argument.
+            Ok(values([("ChkVFO", "0".to_string())]))
+        }
+        "get_info" => {
+            let snapshot = snapshot_or_request(state_rx, rig_tx).await?;
             let info = format!(
                 "Model: {} {}; Version: {}",
                 snapshot.info.manufacturer, snapshot.info.model, snapshot.info.revision
             );
-            ok_response(op, extended, [info])
+            Ok(values([("Info", info)]))
+        }
+        "dump_state" => Ok(Reply::Raw(dump_state_lines(
+            &request_snapshot(rig_tx).await?,
+        ))),
+        "dump_caps" => Ok(Reply::Raw(dump_caps_lines(
+            &request_snapshot(rig_tx).await?,
+        ))),
+        _ => Err(CmdError::enimpl()),
+    }
+}
+
+fn values<const N: usize>(values: [(&'static str, String); N]) -> Reply {
+    Reply::Values(values.into())
+}
+
+fn bool_arg(value: bool) -> String {
+    if value { "1" } else { "0" }.to_string()
+}
+
+/// Frame a command result as rigctld would.
+fn format_reply(
+    name: &str,
+    args: &[&str],
+    mode: ReplyMode,
+    result: Result<Reply, CmdError>,
+) -> String {
+    let code = match &result {
+        Ok(_) => 0,
+        Err(e) => {
+            warn!("rigctl command error: {}", e.msg);
+            -(e.code as i32)
         }
-        "\\get_powerstat" | "get_powerstat" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => {
-                let val = snapshot.enabled.unwrap_or(false);
-                ok_response(op, extended, [if val { "1" } else { "0" }.to_string()])
+    };
+    let mut resp = String::new();
+    match mode {
+        ReplyMode::Plain => match result {
+            Ok(Reply::Values(values)) => {
+                for (_, value) in values {
+                    resp.push_str(&value);
+                    resp.push('\n');
+                }
             }
-            Err(e) => err_response(&e),
-        },
-        "\\chk_vfo" | "chk_vfo" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => ok_response(op, extended, [active_vfo_label(&snapshot)]),
-            Err(e) => err_response(&e),
-        },
-        "\\dump_state" | "dump_state" => match request_snapshot(rig_tx).await {
-            Ok(snapshot) => ok_response(op, extended, dump_state_lines(&snapshot)),
-            Err(e) => err_response(&e),
+            Ok(Reply::Raw(lines)) => {
+                for line in lines {
+                    resp.push_str(&line);
+                    resp.push('\n');
+                }
+            }
+            Ok(Reply::Done) | Err(_) => resp.push_str(&format!("RPRT {code}\n")),
         },
-        "1" | "\\dump_caps" | "dump_caps" | "\\dumpcaps" | "dumpcaps" => {
-            match request_snapshot(rig_tx).await {
-                Ok(snapshot) => dump_caps_response(op, extended, &snapshot),
-                Err(e) => err_response(&e),
+        ReplyMode::Extended(sep) => {
+            resp.push_str(name);
+            resp.push(':');
+            for arg in args {
+                resp.push(' ');
+                resp.push_str(arg);
             }
+            resp.push(sep);
+            match result {
+                Ok(Reply::Values(values)) => {
+                    for (label, value) in values {
+                        resp.push_str(&format!("{label}: {value}{sep}"));
+                    }
+                }
+                Ok(Reply::Raw(lines)) => {
+                    for line in lines {
+                        resp.push_str(&line);
+                        resp.push(sep);
+                    }
+                }
+                Ok(Reply::Done) | Err(_) => {}
+            }
+            resp.push_str(&format!("RPRT {code}\n"));
         }
-        "i" | "I" => {
-            let snapshot = match current_snapshot(state_rx) {
-                Some(s) => s,
-                None => match request_snapshot(rig_tx).await {
-                    Ok(s) => s,
-                    Err(e) => return CommandResult::Reply(err_response(&e)),
-                },
-            };
-            let info_line = format!("{} {}", snapshot.info.manufacturer, snapshot.info.model);
-            ok_response(op, extended, [info_line])
-        }
-        _ => {
-            warn!("rigctl unsupported command: {}", cmd_line);
-            err_response("unsupported command")
-        }
-    };
-
-    CommandResult::Reply(resp)
+    }
+    resp
 }
 
-fn ok_response<I, S>(op: &str, extended: bool, lines: I) -> String
-where
-    I: IntoIterator<Item = S>,
-    S: Into<String>,
-{
-    if extended {
-        let mut resp = String::new();
-        for line in lines {
-            resp.push_str(op);
-            resp.push_str(": ");
-            resp.push_str(&line.into());
-            resp.push('\n');
+/// Key or unkey, retuning around the over when split is on.
+async fn set_ptt(
+    session: &mut Session,
+    rig_tx: &mpsc::Sender<RigRequest>,
+    snapshot: &RigSnapshot,
+    ptt: bool,
+) -> Result<(), CmdError> {
+    let rx_hz = snapshot.status.freq.hz;
+    match session.split_tx_hz {
+        Some(tx_hz) if ptt && session.split && session.split_rx_hz.is_none() && tx_hz != rx_hz => {
+            send_set_freq_with_compat_retry(rig_tx, tx_hz).await?;
+            if let Err(e) = send_rig_command(rig_tx, RigCommand::SetPtt(true)).await {
+                let _ = send_set_freq_with_compat_retry(rig_tx, rx_hz).await;
+                return Err(e);
+            }
+            session.split_rx_hz = Some(rx_hz);
         }
-        resp.push_str("RPRT 0\n");
-        resp
-    } else {
-        let mut resp = String::new();
-        for line in lines {
-            let line = line.into();
-            if !line.is_empty() {
-                resp.push_str(&line);
-                resp.push('\n');
+        _ => {
+            send_rig_command(rig_tx, RigCommand::SetPtt(ptt)).await?;
+            if !ptt {
+                if let Some(rx_hz) = session.split_rx_hz.take() {
+                    send_set_freq_with_compat_retry(rig_tx, rx_hz).await?;
+                }
             }
         }
-        resp
     }
+    Ok(())
 }
 
-fn ok_only(op: &str, extended: bool) -> String {
-    if extended {
-        format!("{op}:\nRPRT 0\n")
+/// Hamlib level names; any other name is an invalid argument.
+const HAMLIB_LEVELS: &[&str] = &[
+    "PREAMP",
+    "ATT",
+    "VOXDELAY",
+    "AF",
+    "RF",
+    "SQL",
+    "IF",
+    "APF",
+    "NR",
+    "PBT_IN",
+    "PBT_OUT",
+    "CWPITCH",
+    "RFPOWER",
+    "MICGAIN",
+    "KEYSPD",
+    "NOTCHF",
+    "COMP",
+    "AGC",
+    "BKINDL",
+    "BAL",
+    "METER",
+    "VOXGAIN",
+    "ANTIVOX",
+    "SLOPE_LOW",
+    "SLOPE_HIGH",
+    "BKIN_DLYMS",
+    "RAWSTR",
+    "SQLSTAT",
+    "SWR",
+    "ALC",
+    "STRENGTH",
+];
+
+/// Full-scale TX limit that `RFPOWER` 1.0 maps to.
+const TX_LIMIT_MAX: f64 = 255.0;
+/// SDR squelch threshold range covered by `SQL` 0.0–1.0, as on the web UI.
+const SQUELCH_MIN_DB: f64 = -120.0;
+const SQUELCH_MAX_DB: f64 = -30.0;
+
+/// Check that `level` (upper case) is a Hamlib level the rig serves:
+/// unknown names are invalid, known ones the rig lacks are unavailable.
+fn check_level(level: &str, snapshot: &RigSnapshot) -> Result<(), CmdError> {
+    if !HAMLIB_LEVELS.contains(&level) {
+        return Err(CmdError::inval(format!("unknown level {level}")));
+    }
+    let available = match level {
+        "RFPOWER" => snapshot.info.capabilities.tx_limit,
+        "SQL" => snapshot
+            .filter
+            .as_ref()
+            .is_some_and(|f| f.sdr_squelch_enabled.is_some()),
+        _ => false,
+    };
+    if available {
+        Ok(())
     } else {
-        "RPRT 0\n".to_string()
+        Err(CmdError::navail(format!("level {level} not available")))
     }
 }
 
-fn err_response(msg: &str) -> String {
-    warn!("rigctl command error: {}", msg);
-    "RPRT -1\n".to_string()
+fn get_level(level: &str, snapshot: &RigSnapshot) -> Result<String, CmdError> {
+    let level = level.to_ascii_uppercase();
+    check_level(&level, snapshot)?;
+    let value = match level.as_str() {
+        "RFPOWER" => snapshot
+            .status
+            .tx
+            .as_ref()
+            .and_then(|tx| tx.limit)
+            .map(|limit| f64::from(limit) / TX_LIMIT_MAX),
+        _ => snapshot.filter.as_ref().and_then(|f| {
+            match (f.sdr_squelch_enabled, f.sdr_squelch_threshold_db) {
+                (Some(false), _) => Some(0.0),
+                (Some(true), Some(db)) => Some(
+                    ((db - SQUELCH_MIN_DB) / (SQUELCH_MAX_DB - SQUELCH_MIN_DB)).clamp(0.0, 1.0),
+                ),
+                _ => None,
+            }
+        }),
+    };
+    value
+        .map(|v| format!("{v:.6}"))
+        .ok_or_else(|| CmdError::navail(format!("level {level} not reported yet")))
+}
+
+/// Command for a level `check_level` has accepted.
+fn set_level_command(level: &str, value: f64) -> Result<RigCommand, CmdError> {
+    if !(0.0..=1.0).contains(&value) {
+        return Err(CmdError::inval(format!("{level} must be 0.0-1.0")));
+    }
+    if level == "RFPOWER" {
+        Ok(RigCommand::SetTxLimit((value * TX_LIMIT_MAX).round() as u8))
+    } else {
+        Ok(RigCommand::SetSdrSquelch {
+            enabled: value > 0.0,
+            threshold_db: SQUELCH_MIN_DB + value * (SQUELCH_MAX_DB - SQUELCH_MIN_DB),
+        })
+    }
 }
 
 fn rig_supports_ptt(snapshot: &RigSnapshot) -> bool {
@@ -324,14 +746,25 @@ fn rig_supports_ptt(snapshot: &RigSnapshot) -> bool {
             .any(|b| b.tx_allowed)
 }
 
-async fn request_snapshot(rig_tx: &mpsc::Sender<RigRequest>) -> Result<RigSnapshot, String> {
+async fn request_snapshot(rig_tx: &mpsc::Sender<RigRequest>) -> Result<RigSnapshot, CmdError> {
     send_rig_command(rig_tx, RigCommand::GetSnapshot).await
 }
 
+/// The cached snapshot, or a fresh one if none has arrived yet.
+async fn snapshot_or_request(
+    state_rx: &watch::Receiver<RigState>,
+    rig_tx: &mpsc::Sender<RigRequest>,
+) -> Result<RigSnapshot, CmdError> {
+    match current_snapshot(state_rx) {
+        Some(snapshot) => Ok(snapshot),
+        None => request_snapshot(rig_tx).await,
+    }
+}
+
 async fn send_rig_command(
     rig_tx: &mpsc::Sender<RigRequest>,
     cmd: RigCommand,
-) -> Result<RigSnapshot, String> {
+) -> Result<RigSnapshot, CmdError> {
     let (resp_tx, resp_rx) = oneshot::channel();
     rig_tx
         .send(RigRequest {
@@ -340,26 +773,40 @@ async fn send_rig_command(
             rig_id_override: None,
         })
         .await
-        .map_err(|e| format!("failed to send to rig: {e:?}"))?;
+        .map_err(|e| CmdError::new(Rprt::Io, format!("failed to send to rig: {e:?}")))?;
 
     match timeout(Duration::from_secs(15), resp_rx).await {
         Ok(Ok(Ok(snapshot))) => Ok(snapshot),
-        Ok(Ok(Err(err))) => Err(err.message),
-        Ok(Err(e)) => Err(format!("rig response error: {e:?}")),
-        Err(_) => Err("rig response timeout".into()),
+        Ok(Ok(Err(err))) => Err(rig_error(err)),
+        Ok(Err(e)) => Err(CmdError::new(
+            Rprt::Io,
+            format!("rig response error: {e:?}"),
+        )),
+        Err(_) => Err(CmdError::new(Rprt::Timeout, "rig response timeout")),
     }
 }
 
+fn rig_error(err: RigError) -> CmdError {
+    let code = if err.message.contains("not supported") {
+        Rprt::Navail
+    } else if err.is_transient() {
+        Rprt::Io
+    } else {
+        Rprt::Rejected
+    };
+    CmdError::new(code, err.message)
+}
+
 async fn send_set_freq_with_compat_retry(
     rig_tx: &mpsc::Sender<RigRequest>,
     freq_hz: u64,
-) -> Result<RigSnapshot, String> {
+) -> Result<RigSnapshot, CmdError> {
     match send_rig_command(rig_tx, RigCommand::SetFreq(Freq { hz: freq_hz })).await {
         Ok(snapshot) => Ok(snapshot),
         Err(e) => {
             // FT-817 backend requires 10 Hz alignment; some hamlib clients submit
             // values with 1 Hz granularity.
-            if e.contains("multiple of 10 Hz") {
+            if e.msg.contains("multiple of 10 Hz") {
                 let rounded = ((freq_hz + 5) / 10) * 10;
                 if rounded != freq_hz {
                     return send_rig_command(rig_tx, RigCommand::SetFreq(Freq { hz: rounded }))
@@ -434,55 +881,21 @@ fn dump_state_lines(snapshot: &RigSnapshot) -> Vec<String> {
     lines
 }
 
-fn dump_caps_response(op: &str, extended: bool, snapshot: &RigSnapshot) -> String {
+fn dump_caps_lines(snapshot: &RigSnapshot) -> Vec<String> {
     // netrigctl_open expects `setting=value` lines terminated by `done`.
     // Unknown keys are tolerated by Hamlib, but malformed lines are not.
-    let mut resp = String::new();
-    let push = |buf: &mut String, key: &str, val: String| {
-        buf.push_str(key);
-        buf.push('=');
-        buf.push_str(&val);
-        buf.push('\n');
-    };
-
-    push(&mut resp, "protocol_version", "1".to_string());
-    push(&mut resp, "rig_model", "2".to_string());
-    push(&mut resp, "model_name", snapshot.info.model.clone());
-    push(&mut resp, "mfg_name", snapshot.info.manufacturer.clone());
-    push(&mut resp, "backend_version", snapshot.info.revision.clone());
-    push(
-        &mut resp,
-        "vfo_count",
-        snapshot.info.capabilities.num_vfos.to_string(),
-    );
-    push(
-        &mut resp,
-        "has_vfo_b",
-        if snapshot.info.capabilities.num_vfos >= 2 {
-            "1".to_string()
-        } else {
-            "0".to_string()
-        },
-    );
-    push(
-        &mut resp,
-        "can_ptt",
-        if rig_supports_ptt(snapshot) {
-            "1".to_string()
-        } else {
-            "0".to_string()
-        },
-    );
-    resp.push_str("done\n");
-    if extended {
-        ok_response(
-            op,
-            true,
-            resp.lines().map(|s| s.to_string()).collect::<Vec<_>>(),
-        )
-    } else {
-        resp
-    }
+    let caps = &snapshot.info.capabilities;
+    vec![
+        "protocol_version=1".to_string(),
+        "rig_model=2".to_string(),
+        format!("model_name={}", snapshot.info.model),
+        format!("mfg_name={}", snapshot.info.manufacturer),
+        format!("backend_version={}", snapshot.info.revision),
+        format!("vfo_count={}", caps.num_vfos),
+        format!("has_vfo_b={}", bool_arg(caps.num_vfos >= 2)),
+        format!("can_ptt={}", bool_arg(rig_supports_ptt(snapshot))),
+        "done".to_string(),
+    ]
 }
 
 fn active_vfo_label(snapshot: &RigSnapshot) -> String {
@@ -499,8 +912,9 @@ fn active_vfo_label(snapshot: &RigSnapshot) -> String {
         .unwrap_or_else(|| "VFOA".to_string())
 }
 
-async fn set_vfo_target(target: &str, rig_tx: &mpsc::Sender<RigRequest>) -> Result<(), String> {
-    let desired = normalize_vfo_name(target).ok_or_else(|| "expected VFOA or VFOB".to_string())?;
+async fn set_vfo_target(target: &str, rig_tx: &mpsc::Sender<RigRequest>) -> Result<(), CmdError> {
+    let desired =
+        normalize_vfo_name(target).ok_or_else(|| CmdError::inval("expected VFOA or VFOB"))?;
     let snapshot = request_snapshot(rig_tx).await?;
     let current = active_vfo_label(&snapshot);
     if current == desired {
@@ -514,7 +928,7 @@ async fn set_vfo_target(target: &str, rig_tx: &mpsc::Sender<RigRequest>) -> Resu
             .as_ref()
             .is_some_and(|v| v.entries.len() >= 2);
     if !supports_toggle {
-        return Err("VFO selection not supported".to_string());
+        return Err(CmdError::navail("VFO selection not supported"));
     }
 
     send_rig_command(rig_tx, RigCommand::ToggleVfo).await?;
@@ -522,7 +936,7 @@ async fn set_vfo_target(target: &str, rig_tx: &mpsc::Sender<RigRequest>) -> Resu
     if active_vfo_label(&after) == desired {
         Ok(())
     } else {
-        Err("failed to switch VFO".to_string())
+        Err(CmdError::new(Rprt::Rejected, "failed to switch VFO"))
     }
 }
 
@@ -577,6 +991,35 @@ fn parse_ptt_tokens(tokens: Vec<&str>) -> Option<String> {
     }
 }
 
+/// Single argument, optionally after a VFO name.
+fn parse_vfo_tokens<'a>(tokens: &[&'a str]) -> Option<&'a str> {
+    match tokens {
+        [only] => Some(only),
+        [first, second] if normalize_vfo_name(first).is_some() => Some(second),
+        _ => None,
+    }
+}
+
+/// RIT/XIT offset argument, optionally after a VFO name.
+fn parse_offset_tokens(tokens: Vec<&str>) -> Option<i32> {
+    let arg = match tokens.as_slice() {
+        [only] => only,
+        [first, second] if normalize_vfo_name(first).is_some() => second,
+        _ => return None,
+    };
+    let hz = arg.parse::<f64>().ok()?;
+    hz.is_finite().then(|| hz.round() as i32)
+}
+
+/// Current RIT (or XIT) offset; 0 while it is off.
+fn clarifier_offset(snapshot: &RigSnapshot, xit: bool) -> i32 {
+    snapshot
+        .clarifier
+        .as_ref()
+        .filter(|c| if xit { c.xit } else { c.rit })
+        .map_or(0, |c| c.offset_hz)
+}
+
 fn parse_freq_hz_arg(s: &str) -> Option<u64> {
     if let Ok(hz) = s.parse::<u64>() {
         return Some(hz);
@@ -602,7 +1045,10 @@ fn parse_freq_hz_arg(s: &str) -> Option<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use trx_core::rig::{RigAccessMethod, RigCapabilities, RigInfo, RigStatus, RigTxStatus};
+    use trx_core::rig::{
+        ClarifierState, RigAccessMethod, RigCapabilities, RigInfo, RigStatus, RigTxStatus,
+    };
+    use trx_core::{RigFilterState, WfmDenoiseLevel};
 
     fn test_snapshot() -> RigSnapshot {
         RigSnapshot {
@@ -620,6 +1066,7 @@ mod tests {
                     attenuator: false,
                     preamp: false,
                     rit: false,
+                    xit: false,
                     rpt: false,
                     split: false,
                     tx: true,
@@ -627,6 +1074,11 @@ mod tests {
                     vfo_switch: true,
                     filter_controls: false,
                     signal_meter: true,
+                    memory_channels: 0,
+                    memory_write: false,
+                    band_stack: false,
+                    ctcss_decode: false,
+                    dcs: false,
                 },
                 access: RigAccessMethod::Tcp {
                     addr: "127.0.0.1:4532".to_string(),
@@ -660,34 +1112,328 @@ mod tests {
             cw_auto: false,
             cw_wpm: 0,
             cw_tone_hz: 0,
+            psk_mode: trx_core::decode::PskMode::default(),
+            psk_freq_hz: 0,
+            psk_waterfall: false,
+            sat_tracking: None,
+            rotator: None,
+            cw_keyer: None,
+            memories: Vec::new(),
+            repeater: None,
+            clarifier: None,
             filter: None,
             spectrum: None,
             vchan_rds: None,
         }
     }
 
+    fn test_filter() -> RigFilterState {
+        RigFilterState {
+            bandwidth_hz: 2_400,
+            cw_center_hz: 700,
+            sdr_gain_db: None,
+            sdr_lna_gain_db: None,
+            sdr_agc_enabled: None,
+            sdr_squelch_enabled: Some(false),
+            sdr_squelch_threshold_db: Some(-120.0),
+            sdr_nb_enabled: None,
+            sdr_nb_threshold: None,
+            wfm_deemphasis_us: 50,
+            wfm_stereo: false,
+            wfm_stereo_detected: false,
+            wfm_denoise: WfmDenoiseLevel::Off,
+            wfm_cci: 0,
+            wfm_aci: 0,
+            sam_stereo_width: 1.0,
+            sam_carrier_sync: false,
+        }
+    }
+
+    /// Minimal rig task: applies commands to a snapshot and records them.
+    struct FakeRig {
+        rig_tx: mpsc::Sender<RigRequest>,
+        state_rx: watch::Receiver<RigState>,
+        commands: Arc<std::sync::Mutex<Vec<String>>>,
+        session: Session,
+    }
+
+    impl FakeRig {
+        fn spawn(mut snapshot: RigSnapshot) -> Self {
+            let (rig_tx, mut rig_rx) = mpsc::channel::<RigRequest>(8);
+            let commands = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let log = commands.clone();
+            tokio::spawn(async move {
+                while let Some(req) = rig_rx.recv().await {
+                    if !matches!(req.cmd, RigCommand::GetSnapshot) {
+                        log.lock().unwrap().push(format!("{:?}", req.cmd));
+                    }
+                    match req.cmd {
+                        RigCommand::SetFreq(freq) => snapshot.status.freq = freq,
+                        RigCommand::SetMode(mode) => snapshot.status.mode = mode,
+                        RigCommand::SetPtt(ptt) => snapshot.status.tx_en = ptt,
+                        RigCommand::PowerOn => snapshot.enabled = Some(true),
+                        RigCommand::PowerOff => snapshot.enabled = Some(false),
+                        RigCommand::Lock => snapshot.status.lock = Some(true),
+                        RigCommand::Unlock => snapshot.status.lock = Some(false),
+                        RigCommand::SetTxLimit(limit) => {
+                            if let Some(tx) = snapshot.status.tx.as_mut() {
+                                tx.limit = Some(limit);
+                            }
+                        }
+                        RigCommand::SetSdrSquelch {
+                            enabled,
+                            threshold_db,
+                        } => {
+                            if let Some(filter) = snapshot.filter.as_mut() {
+                                filter.sdr_squelch_enabled = Some(enabled);
+                                filter.sdr_squelch_threshold_db = Some(threshold_db);
+                            }
+                        }
+                        RigCommand::SetRit(offset_hz) => {
+                            snapshot.clarifier = Some(ClarifierState {
+                                offset_hz,
+                                rit: true,
+                                xit: false,
+                            });
+                        }
+                        _ => {}
+                    }
+                    let _ = req.respond_to.send(Ok(snapshot.clone()));
+                }
+            });
+            let (_, state_rx) = watch::channel(RigState::new_uninitialized());
+            Self {
+                rig_tx,
+                state_rx,
+                commands,
+                session: Session::default(),
+            }
+        }
+
+        async fn cmd(&mut self, line: &str) -> String {
+            match process_command(line, &mut self.session, &self.state_rx, &self.rig_tx).await {
+                CommandResult::Reply(resp) => resp,
+                CommandResult::Close => "<close>".to_string(),
+            }
+        }
+
+        fn take_commands(&self) -> Vec<String> {
+            std::mem::take(&mut *self.commands.lock().unwrap())
+        }
+    }
+
+    #[tokio::test]
+    async fn netrigctl_open_sequence() {
+        let mut rig = FakeRig::spawn(test_snapshot());
+        assert_eq!(rig.cmd("\\chk_vfo").await, "0\n");
+        let state = rig.cmd("\\dump_state").await;
+        assert!(state.starts_with("1\n1\n0\n"));
+        assert!(state.ends_with("done\n"));
+        let caps = rig.cmd("1").await;
+        assert!(caps
+            .lines()
+            .all(|line| line == "done" || line.contains('=')));
+        assert!(caps.contains("model_name=Virtual\n"));
+        assert!(caps.ends_with("done\n"));
+        assert_eq!(rig.cmd("q").await, "<close>");
+    }
+
+    #[tokio::test]
+    async fn plain_and_extended_replies() {
+        let mut rig = FakeRig::spawn(test_snapshot());
+        assert_eq!(rig.cmd("f").await, "7100000\n");
+        assert_eq!(rig.cmd("get_freq").await, "7100000\n");
+        assert_eq!(rig.cmd("F 14074000").await, "RPRT 0\n");
+        assert_eq!(
+            rig.cmd("+\\get_freq").await,
+            "get_freq:\nFrequency: 14074000\nRPRT 0\n"
+        );
+        assert_eq!(
+            rig.cmd(";\\set_freq 7074000").await,
+            "set_freq: 7074000;RPRT 0\n"
+        );
+        assert_eq!(
+            rig.cmd("|m").await,
+            "get_mode:|Mode: USB|Passband: 0|RPRT 0\n"
+        );
+        assert_eq!(
+            rig.cmd("+_").await,
+            "get_info:\nInfo: Model: TRX Virtual; Version: 0.1.0\nRPRT 0\n"
+        );
+        assert_eq!(
+            rig.take_commands(),
+            [
+                "SetFreq(Freq { hz: 14074000 })",
+                "SetFreq(Freq { hz: 7074000 })"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_use_hamlib_codes() {
+        let mut rig = FakeRig::spawn(test_snapshot());
+        assert_eq!(rig.cmd("F fast").await, "RPRT -1\n");
+        assert_eq!(rig.cmd("F").await, "RPRT -1\n");
+        assert_eq!(rig.cmd("\\get_ctcss_tone").await, "RPRT -4\n");
+        assert_eq!(rig.cmd("+Y").await, "Y:\nRPRT -4\n");
+        assert_eq!(rig.cmd("\\no_such_command 1").await, "RPRT -4\n");
+        assert_eq!(rig.cmd("\\set_lock_mode 2").await, "RPRT -1\n");
+        assert_eq!(rig.cmd("\\set_powerstat 2").await, "RPRT -11\n");
+        assert!(rig.take_commands().is_empty());
+    }
+
+    #[tokio::test]
+    async fn levels_map_to_tx_limit_and_squelch() {
+        let mut snapshot = test_snapshot();
+        snapshot.filter = Some(test_filter());
+        let mut rig = FakeRig::spawn(snapshot);
+        assert_eq!(rig.cmd("L RFPOWER 0.5").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("l RFPOWER").await, "0.501961\n");
+        assert_eq!(rig.cmd("l SQL").await, "0.000000\n");
+        assert_eq!(rig.cmd("L SQL 0.5").await, "RPRT 0\n");
+        assert_eq!(
+            rig.cmd("+l SQL").await,
+            "get_level: SQL\nLevel Value: 0.500000\nRPRT 0\n"
+        );
+        assert_eq!(rig.cmd("L AF 0.3").await, "RPRT -11\n");
+        assert_eq!(rig.cmd("L VOLUME 0.3").await, "RPRT -1\n");
+        assert_eq!(rig.cmd("L RFPOWER 2").await, "RPRT -1\n");
+        assert_eq!(rig.cmd("l KEYSPD").await, "RPRT -11\n");
+        assert_eq!(rig.cmd("l VOLUME").await, "RPRT -1\n");
+        assert_eq!(rig.cmd("m").await, "USB\n2400\n");
+        assert_eq!(
+            rig.take_commands(),
+            [
+                "SetTxLimit(128)",
+                "SetSdrSquelch { enabled: true, threshold_db: -75.0 }",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn rfpower_needs_tx_limit_capability() {
+        let mut snapshot = test_snapshot();
+        snapshot.info.capabilities.tx_limit = false;
+        let mut rig = FakeRig::spawn(snapshot);
+        assert_eq!(rig.cmd("L RFPOWER 0.5").await, "RPRT -11\n");
+        // Unavailable wins over an out-of-range value.
+        assert_eq!(rig.cmd("L RFPOWER 2").await, "RPRT -11\n");
+        assert_eq!(rig.cmd("L RFPOWER fast").await, "RPRT -11\n");
+        assert_eq!(rig.cmd("l RFPOWER").await, "RPRT -11\n");
+        assert!(rig.take_commands().is_empty());
+    }
+
+    #[tokio::test]
+    async fn split_retunes_around_ptt() {
+        let mut rig = FakeRig::spawn(test_snapshot());
+        assert_eq!(rig.cmd("s").await, "0\nVFOA\n");
+        assert_eq!(rig.cmd("S 1 VFOB").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("I 7105000").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("s").await, "1\nVFOB\n");
+        assert_eq!(rig.cmd("i").await, "7105000\n");
+        assert_eq!(rig.cmd("T 1").await, "RPRT 0\n");
+        // The RX frequency is still reported while keyed on the TX one.
+        assert_eq!(rig.cmd("f").await, "7100000\n");
+        assert_eq!(rig.cmd("T 0").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("f").await, "7100000\n");
+        assert_eq!(
+            rig.take_commands(),
+            [
+                "SetFreq(Freq { hz: 7105000 })",
+                "SetPtt(true)",
+                "SetPtt(false)",
+                "SetFreq(Freq { hz: 7100000 })",
+            ]
+        );
+
+        // Dropping the connection while keyed unkeys and retunes.
+        assert_eq!(rig.cmd("T 1").await, "RPRT 0\n");
+        rig.take_commands();
+        rig.session.finish(&rig.rig_tx).await;
+        assert_eq!(
+            rig.take_commands(),
+            ["SetPtt(false)", "SetFreq(Freq { hz: 7100000 })"]
+        );
+    }
+
+    #[tokio::test]
+    async fn power_lock_rit_and_morse() {
+        let mut rig = FakeRig::spawn(test_snapshot());
+        assert_eq!(rig.cmd("\\get_powerstat").await, "1\n");
+        assert_eq!(rig.cmd("\\set_powerstat 0").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("\\get_powerstat").await, "0\n");
+        assert_eq!(rig.cmd("\\set_lock_mode 1").await, "RPRT 0\n");
+        assert_eq!(
+            rig.cmd("+\\get_lock_mode").await,
+            "get_lock_mode:\nLocked: 1\nRPRT 0\n"
+        );
+        // Mode lock is held by the frontend, not the rig's panel lock.
+        assert_eq!(rig.cmd("M LSB 0").await, "RPRT -9\n");
+        assert_eq!(rig.cmd("\\set_lock_mode 0").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("M LSB 0").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("J -300").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("j").await, "-300\n");
+        assert_eq!(rig.cmd("b CQ TEST").await, "RPRT 0\n");
+        assert_eq!(rig.cmd("b").await, "RPRT -1\n");
+        assert_eq!(
+            rig.take_commands(),
+            [
+                "PowerOff",
+                "SetMode(LSB)",
+                "SetRit(-300)",
+                "SendCw { text: \"CQ TEST\", call: None }",
+            ]
+        );
+    }
+
     #[test]
     fn dump_caps_is_setting_value_and_ends_with_done() {
-        let response = dump_caps_response("dump_caps", false, &test_snapshot());
-        let lines: Vec<&str> = response.lines().collect();
+        let lines = dump_caps_lines(&test_snapshot());
         assert!(lines
             .iter()
-            .all(|line| *line == "done" || line.contains('=')));
-        assert_eq!(lines.last(), Some(&"done"));
-        assert!(response.contains("model_name=Virtual\n"));
-        assert!(response.contains("mfg_name=TRX\n"));
+            .all(|line| line == "done" || line.contains('=')));
+        assert_eq!(lines.last().map(String::as_str), Some("done"));
+        assert!(lines.contains(&"model_name=Virtual".to_string()));
+        assert!(lines.contains(&"mfg_name=TRX".to_string()));
     }
 
     #[test]
     fn ok_response_does_not_append_rprt_status() {
-        let response = ok_response("f", false, ["7100000"]);
+        let response = format_reply(
+            "get_freq",
+            &[],
+            ReplyMode::Plain,
+            Ok(values([("Frequency", "7100000".to_string())])),
+        );
         assert_eq!(response, "7100000\n");
     }
 
     #[test]
     fn ok_response_extended_includes_command_prefix_and_status() {
-        let response = ok_response("\\get_freq", true, ["7100000"]);
-        assert_eq!(response, "\\get_freq: 7100000\nRPRT 0\n");
+        let response = format_reply(
+            "get_freq",
+            &[],
+            ReplyMode::Extended('\n'),
+            Ok(values([("Frequency", "7100000".to_string())])),
+        );
+        assert_eq!(response, "get_freq:\nFrequency: 7100000\nRPRT 0\n");
+    }
+
+    #[test]
+    fn ok_response_extended_echoes_args_with_separator() {
+        let response = format_reply(
+            "set_freq",
+            &["7100000"],
+            ReplyMode::Extended(';'),
+            Ok(Reply::Done),
+        );
+        assert_eq!(response, "set_freq: 7100000;RPRT 0\n");
+    }
+
+    #[test]
+    fn ok_response_for_set_is_rprt_zero() {
+        let response = format_reply("set_freq", &["7100000"], ReplyMode::Plain, Ok(Reply::Done));
+        assert_eq!(response, "RPRT 0\n");
     }
 
     #[test]
@@ -716,4 +1462,25 @@ mod tests {
             Some("ON_DATA".to_string())
         );
     }
+
+    #[test]
+    fn parse_offset_tokens_accepts_optional_vfo_prefix() {
+        assert_eq!(parse_offset_tokens(vec!["-500"]), Some(-500));
+        assert_eq!(parse_offset_tokens(vec!["VFOA", "120.000000"]), Some(120));
+        assert_eq!(parse_offset_tokens(vec!["fast"]), None);
+        assert_eq!(parse_offset_tokens(vec![]), None);
+    }
+
+    #[test]
+    fn clarifier_offset_is_zero_while_off() {
+        let mut snapshot = test_snapshot();
+        assert_eq!(clarifier_offset(&snapshot, false), 0);
+        snapshot.clarifier = Some(ClarifierState {
+            offset_hz: -300,
+            rit: true,
+            xit: false,
+        });
+        assert_eq!(clarifier_offset(&snapshot, false), -300);
+        assert_eq!(clarifier_offset(&snapshot, true), 0);
+    }
 }
@@ -75,7 +75,7 @@ const BEHAVIOR_KEYS: &[&str] = &[
 const REMOTE_KEYS: &[&str] = &["url", "rig_id", "auth", "poll_interval_ms"];
 
 /// Known sub-keys within [frontends].
-const FRONTENDS_KEYS: &[&str] = &["http", "rigctl", "http_json", "audio"];
+const FRONTENDS_KEYS: &[&str] = &["http", "rigctl", "flrig", "http_json", "audio"];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum DetectedType {
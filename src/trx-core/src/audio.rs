@@ -74,6 +74,38 @@ pub const AUDIO_MSG_LRPT_PROGRESS: u8 = 0x18;
 pub const AUDIO_MSG_WEFAX_DECODE: u8 = 0x19;
 /// Server → client: WEFAX decode progress (JSON `DecodedMessage::WefaxProgress`).
 pub const AUDIO_MSG_WEFAX_PROGRESS: u8 = 0x1A;
+/// Server → client: PSK31/63/125 or QPSK31 text fragment (JSON `DecodedMessage::Psk`).
+pub const AUDIO_MSG_PSK_DECODE: u8 = 0x1B;
+/// Server → client: DSC call (JSON `DecodedMessage::Dsc`).
+pub const AUDIO_MSG_DSC_DECODE: u8 = 0x1C;
+/// Server → client: POCSAG page (JSON `DecodedMessage::Pocsag`).
+pub const AUDIO_MSG_POCSAG_DECODE: u8 = 0x1D;
+/// Server → client: ADS-B aircraft update (JSON `DecodedMessage::Adsb`).
+pub const AUDIO_MSG_ADSB_DECODE: u8 = 0x1E;
+/// Server → client: ACARS block (JSON `DecodedMessage::Acars`).
+pub const AUDIO_MSG_ACARS_DECODE: u8 = 0x1F;
+/// Server → client: CW skimmer spot (JSON `DecodedMessage::CwSpot`).
+pub const AUDIO_MSG_CW_SPOT: u8 = 0x20;
+
+// ---------------------------------------------------------------------------
+// Multiplexed connection (both directions, see `crate::mux`)
+// ---------------------------------------------------------------------------
+
+/// Session handshake.  Client → server: JSON `MuxHello`; server → client:
+/// JSON `MuxHelloAck`.  Always the first frame on a multiplexed connection.
+pub const AUDIO_MSG_MUX_HELLO: u8 = 0x40;
+/// Open a logical stream.  Payload: `[4 B BE stream id][JSON MuxOpen]`.
+pub const AUDIO_MSG_MUX_OPEN: u8 = 0x41;
+/// Stream bytes.  Payload: `[4 B BE stream id][data]`.
+pub const AUDIO_MSG_MUX_DATA: u8 = 0x42;
+/// Grant the peer more send credit on a stream.
+/// Payload: `[4 B BE stream id][4 B BE byte count]`.
+pub const AUDIO_MSG_MUX_WINDOW: u8 = 0x43;
+/// Close a stream in both directions.  Payload: `[4 B BE stream id]`.
+pub const AUDIO_MSG_MUX_CLOSE: u8 = 0x44;
+/// Server → client: JSON `UplinkHello`.  First frame on a connection the
+/// server dialled out; the client then opens a multiplexed session on it.
+pub const AUDIO_MSG_UPLINK_HELLO: u8 = 0x45;
 
 /// Maximum payload size for normal messages (1 MB).
 const MAX_PAYLOAD_SIZE: u32 = 1_048_576;
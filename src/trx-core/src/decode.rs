@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: BSD-2-Clause
 
-//! Shared types for server-side decoded messages (APRS, AIS, CW).
+//! Shared types for server-side decoded messages (APRS, AIS, ADS-B, ACARS, DSC, POCSAG, CW, PSK).
 
 use serde::{Deserialize, Serialize};
 
@@ -14,12 +14,24 @@ pub enum DecodedMessage {
     Ais(AisMessage),
     #[serde(rename = "vdes")]
     Vdes(VdesMessage),
+    #[serde(rename = "adsb")]
+    Adsb(AdsbMessage),
+    #[serde(rename = "acars")]
+    Acars(AcarsMessage),
+    #[serde(rename = "dsc")]
+    Dsc(DscMessage),
+    #[serde(rename = "pocsag")]
+    Pocsag(PocsagMessage),
     #[serde(rename = "aprs")]
     Aprs(AprsPacket),
     #[serde(rename = "hf_aprs")]
     HfAprs(AprsPacket),
     #[serde(rename = "cw")]
     Cw(CwEvent),
+    #[serde(rename = "cw_spot")]
+    CwSpot(CwSpot),
+    #[serde(rename = "psk")]
+    Psk(PskEvent),
     #[serde(rename = "ft8")]
     Ft8(Ft8Message),
     #[serde(rename = "ft4")]
@@ -44,8 +56,14 @@ impl DecodedMessage {
         match self {
             Self::Ais(m) => m.rig_id = Some(id),
             Self::Vdes(m) => m.rig_id = Some(id),
+            Self::Adsb(m) => m.rig_id = Some(id),
+            Self::Acars(m) => m.rig_id = Some(id),
+            Self::Dsc(m) => m.rig_id = Some(id),
+            Self::Pocsag(m) => m.rig_id = Some(id),
             Self::Aprs(m) | Self::HfAprs(m) => m.rig_id = Some(id),
             Self::Cw(m) => m.rig_id = Some(id),
+            Self::CwSpot(m) => m.rig_id = Some(id),
+            Self::Psk(m) => m.rig_id = Some(id),
             Self::Ft8(m) | Self::Ft4(m) | Self::Ft2(m) => m.rig_id = Some(id),
             Self::Wspr(m) => m.rig_id = Some(id),
             Self::LrptImage(m) => m.rig_id = Some(id),
@@ -60,8 +78,14 @@ impl DecodedMessage {
         match self {
             Self::Ais(m) => m.rig_id.as_deref(),
             Self::Vdes(m) => m.rig_id.as_deref(),
+            Self::Adsb(m) => m.rig_id.as_deref(),
+            Self::Acars(m) => m.rig_id.as_deref(),
+            Self::Dsc(m) => m.rig_id.as_deref(),
+            Self::Pocsag(m) => m.rig_id.as_deref(),
             Self::Aprs(m) | Self::HfAprs(m) => m.rig_id.as_deref(),
             Self::Cw(m) => m.rig_id.as_deref(),
+            Self::CwSpot(m) => m.rig_id.as_deref(),
+            Self::Psk(m) => m.rig_id.as_deref(),
             Self::Ft8(m) | Self::Ft4(m) | Self::Ft2(m) => m.rig_id.as_deref(),
             Self::Wspr(m) => m.rig_id.as_deref(),
             Self::LrptImage(m) => m.rig_id.as_deref(),
@@ -70,6 +94,31 @@ impl DecodedMessage {
             Self::WefaxProgress(m) => m.rig_id.as_deref(),
         }
     }
+
+    /// Wire `type` tag of the variant, e.g. `"ft8"` or `"hf_aprs"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Ais(_) => "ais",
+            Self::Vdes(_) => "vdes",
+            Self::Adsb(_) => "adsb",
+            Self::Acars(_) => "acars",
+            Self::Dsc(_) => "dsc",
+            Self::Pocsag(_) => "pocsag",
+            Self::Aprs(_) => "aprs",
+            Self::HfAprs(_) => "hf_aprs",
+            Self::Cw(_) => "cw",
+            Self::CwSpot(_) => "cw_spot",
+            Self::Psk(_) => "psk",
+            Self::Ft8(_) => "ft8",
+            Self::Ft4(_) => "ft4",
+            Self::Ft2(_) => "ft2",
+            Self::Wspr(_) => "wspr",
+            Self::LrptImage(_) => "lrpt_image",
+            Self::LrptProgress(_) => "lrpt_progress",
+            Self::Wefax(_) => "wefax",
+            Self::WefaxProgress(_) => "wefax_progress",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -166,6 +215,218 @@ pub struct VdesMessage {
     pub fec_state: Option<String>,
 }
 
+/// DSC channel type, which determines the modulation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DscBand {
+    /// VHF channel 70: 1200 baud AFSK (1300/2100 Hz) over FM.
+    #[default]
+    Vhf,
+    /// MF/HF distress frequencies: 100 baud FSK (1615/1785 Hz) in USB.
+    Hf,
+}
+
+impl DscBand {
+    /// Symbol rate in baud.
+    pub fn baud(self) -> f32 {
+        match self {
+            Self::Vhf => 1200.0,
+            Self::Hf => 100.0,
+        }
+    }
+
+    /// Audio tone frequencies `(low, high)` in Hz.
+    pub fn tones_hz(self) -> (f32, f32) {
+        match self {
+            Self::Vhf => (1300.0, 2100.0),
+            Self::Hf => (1615.0, 1785.0),
+        }
+    }
+}
+
+/// DSC call format specifier (ITU-R M.493).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DscFormat {
+    Distress,
+    AllShips,
+    Group,
+    Individual,
+    GeographicArea,
+}
+
+/// DSC call category, in increasing order of priority.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum DscCategory {
+    Routine,
+    Safety,
+    Urgency,
+    Distress,
+}
+
+impl DscCategory {
+    /// Whether the call should be surfaced as a high-priority alert.
+    pub fn is_priority(self) -> bool {
+        matches!(self, Self::Urgency | Self::Distress)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DscMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rig_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_ms: Option<i64>,
+    pub band: DscBand,
+    pub format: DscFormat,
+    pub category: DscCategory,
+    /// MMSI of the calling station
+    pub self_mmsi: u32,
+    /// Called station (individual) or group MMSI
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_mmsi: Option<u32>,
+    /// Vessel in distress, for distress acknowledgements and relays
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distress_mmsi: Option<u32>,
+    /// Nature of distress, e.g. "Flooding"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nature: Option<String>,
+    /// First telecommand, e.g. "Distress acknowledgement"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub telecommand: Option<String>,
+    /// Reported position
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lon: Option<f64>,
+    /// UTC time of the reported position ("HH:MM")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utc_time: Option<String>,
+    /// Whether the end-of-sequence error-check character matched
+    pub ecc_ok: bool,
+    /// Decoded information symbols, format specifier through EOS
+    pub symbols: Vec<u8>,
+    /// Name of the calling vessel, from AIS if it has been heard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vessel_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vessel_callsign: Option<String>,
+    /// Last AIS position of the calling vessel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vessel_lat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vessel_lon: Option<f64>,
+}
+
+/// Latest known state of one aircraft, built from Mode S / ADS-B frames.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdsbMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rig_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_ms: Option<i64>,
+    /// 24-bit ICAO aircraft address
+    pub icao: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callsign: Option<String>,
+    /// Barometric (or GNSS when no baro is sent) altitude in feet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub altitude_ft: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lon: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ground_speed_kt: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_deg: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vertical_rate_fpm: Option<i32>,
+    /// Mode A code as four octal digits, e.g. 7700
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub squawk: Option<u16>,
+    #[serde(default)]
+    pub on_ground: bool,
+    /// Frames received from this aircraft since it was first seen
+    pub messages: u32,
+    /// Signal level of the latest frame in dBFS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rssi_db: Option<f32>,
+}
+
+/// One ACARS block (ARINC 618 air-ground link).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcarsMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rig_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_ms: Option<i64>,
+    /// Channel frequency the block was heard on, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub freq_hz: Option<u64>,
+    /// Mode character, e.g. `2`
+    pub mode: String,
+    /// Aircraft registration with the leading dots stripped, e.g. `N123AB`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration: Option<String>,
+    /// Technical acknowledgement character; `None` for a NAK
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ack: Option<String>,
+    /// Two-character message label, e.g. `H1` or `_d`
+    pub label: String,
+    pub block_id: String,
+    /// Downlink (aircraft to ground) blocks carry a digit block ID
+    pub downlink: bool,
+    /// Message sequence number (downlink only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msn: Option<String>,
+    /// Flight number (downlink only), e.g. `BA0123`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flight: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Whether more blocks of the same message follow (ETB terminator)
+    #[serde(default)]
+    pub more: bool,
+    /// Bits repaired using the parity and block check
+    pub corrected_bits: u32,
+}
+
+/// How a POCSAG page body is encoded.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PocsagContent {
+    /// Address only, no message codewords.
+    Tone,
+    /// 4-bit BCD digits.
+    Numeric,
+    /// 7-bit ASCII.
+    Alpha,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PocsagMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rig_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_ms: Option<i64>,
+    /// Bit rate the page was received at (512, 1200 or 2400)
+    pub baud: u16,
+    pub capcode: u32,
+    /// Function bits (0-3) from the address codeword
+    pub function: u8,
+    pub content: PocsagContent,
+    /// Message body; `None` for tone-only pages or when redacted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Bit errors corrected across the page's codewords
+    pub corrected_bits: u32,
+    /// Whether the body was withheld by the server's privacy setting
+    #[serde(default)]
+    pub redacted: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AprsPacket {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -203,6 +464,113 @@ pub struct CwEvent {
     pub signal_on: bool,
 }
 
+/// A callsign heard by the CW skimmer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CwSpot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rig_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_ms: Option<i64>,
+    /// Skimmer callsign reported as the spotter, e.g. `N0CALL-#`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spotter: Option<String>,
+    pub call: String,
+    /// Absolute signal frequency
+    pub freq_hz: u64,
+    pub snr_db: i32,
+    pub wpm: u32,
+    /// How the call was announced: `CQ` or `DE`
+    pub kind: String,
+}
+
+impl CwSpot {
+    /// The spot as a DX cluster entry, so skimmer and cluster spots share
+    /// one table.
+    pub fn to_dx_spot(&self, now_ms: i64) -> crate::dxspot::DxSpot {
+        crate::dxspot::DxSpot {
+            spotter: self
+                .spotter
+                .as_deref()
+                .unwrap_or("SKIMMER")
+                .trim_end_matches("-#")
+                .to_string(),
+            call: self.call.clone(),
+            freq_hz: self.freq_hz,
+            comment: self.kind.clone(),
+            ts_ms: self.ts_ms.unwrap_or(now_ms),
+            mode: Some("CW".to_string()),
+            snr_db: Some(self.snr_db),
+            wpm: Some(self.wpm),
+            count: 1,
+        }
+    }
+}
+
+/// PSK keyboard-mode variant.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PskMode {
+    #[default]
+    Bpsk31,
+    Bpsk63,
+    Bpsk125,
+    Qpsk31,
+}
+
+impl PskMode {
+    /// Symbol rate in baud.
+    pub fn baud(self) -> f32 {
+        match self {
+            Self::Bpsk31 | Self::Qpsk31 => 31.25,
+            Self::Bpsk63 => 62.5,
+            Self::Bpsk125 => 125.0,
+        }
+    }
+
+    /// Whether the mode uses QPSK with the K=5 convolutional code.
+    pub fn is_qpsk(self) -> bool {
+        matches!(self, Self::Qpsk31)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Bpsk31 => "bpsk31",
+            Self::Bpsk63 => "bpsk63",
+            Self::Bpsk125 => "bpsk125",
+            Self::Qpsk31 => "qpsk31",
+        }
+    }
+
+    /// Parse a mode name, accepting the common "PSK31"/"BPSK31" spellings.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "bpsk31" | "psk31" => Some(Self::Bpsk31),
+            "bpsk63" | "psk63" => Some(Self::Bpsk63),
+            "bpsk125" | "psk125" => Some(Self::Bpsk125),
+            "qpsk31" => Some(Self::Qpsk31),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PskEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rig_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts_ms: Option<i64>,
+    /// Modulation the text was decoded with
+    pub mode: PskMode,
+    /// Decoder channel identifier, stable while the signal is tracked
+    pub channel: u32,
+    /// Audio frequency of the tracked carrier (Hz), including AFC correction
+    pub freq_hz: f32,
+    /// Approximate SNR in a 2.5 kHz reference bandwidth (dB)
+    pub snr_db: f32,
+    /// Decoded text fragment (one or more characters)
+    pub text: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ft8Message {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,260 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Satellite transponders and Doppler correction.
+//!
+//! Frequencies are kept in the satellite's frame of reference: the operator
+//! picks a point in the downlink passband and the rig is retuned to where
+//! that point is heard on the ground.  For linear transponders the matching
+//! uplink follows the same passband offset, mirrored when the transponder
+//! inverts.
+
+use serde::{Deserialize, Serialize};
+
+use crate::rig::state::RigMode;
+
+/// Speed of light in km/s.
+const SPEED_OF_LIGHT_KM_S: f64 = 299_792.458;
+
+/// An inclusive frequency range in Hz; `low_hz == high_hz` for single-channel
+/// FM transponders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FreqRange {
+    pub low_hz: u64,
+    pub high_hz: u64,
+}
+
+impl FreqRange {
+    const fn new(low_hz: u64, high_hz: u64) -> Self {
+        Self { low_hz, high_hz }
+    }
+
+    pub fn center_hz(&self) -> u64 {
+        (self.low_hz + self.high_hz) / 2
+    }
+
+    pub fn clamp(&self, hz: u64) -> u64 {
+        hz.clamp(self.low_hz, self.high_hz)
+    }
+}
+
+/// One transponder or beacon on a satellite.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transponder {
+    /// NORAD catalog number of the satellite.
+    pub norad_id: u32,
+    /// Satellite display name.
+    pub satellite: String,
+    /// Transponder name, unique per satellite.
+    pub name: String,
+    pub downlink: FreqRange,
+    /// `None` for receive-only beacons and telemetry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uplink: Option<FreqRange>,
+    /// The uplink passband is mirrored onto the downlink.
+    #[serde(default)]
+    pub inverting: bool,
+    /// Receive mode for the downlink.
+    pub mode: RigMode,
+}
+
+impl Transponder {
+    /// Uplink frequency paired with a downlink frequency, both in the
+    /// satellite frame.
+    pub fn uplink_for(&self, downlink_hz: u64) -> Option<u64> {
+        let uplink = self.uplink?;
+        let offset = self.downlink.clamp(downlink_hz) - self.downlink.low_hz;
+        Some(if self.inverting {
+            uplink.high_hz.saturating_sub(offset).max(uplink.low_hz)
+        } else {
+            (uplink.low_hz + offset).min(uplink.high_hz)
+        })
+    }
+}
+
+fn transponder(
+    norad_id: u32,
+    satellite: &str,
+    name: &str,
+    downlink: FreqRange,
+    uplink: Option<FreqRange>,
+    inverting: bool,
+    mode: RigMode,
+) -> Transponder {
+    Transponder {
+        norad_id,
+        satellite: satellite.to_string(),
+        name: name.to_string(),
+        downlink,
+        uplink,
+        inverting,
+        mode,
+    }
+}
+
+/// Built-in transponder database for commonly worked amateur satellites.
+pub fn builtin_transponders() -> Vec<Transponder> {
+    let fm = |hz| Some(FreqRange::new(hz, hz));
+    vec![
+        transponder(
+            25544,
+            "ISS",
+            "FM voice",
+            FreqRange::new(437_800_000, 437_800_000),
+            fm(145_990_000),
+            false,
+            RigMode::FM,
+        ),
+        transponder(
+            25544,
+            "ISS",
+            "APRS",
+            FreqRange::new(145_825_000, 145_825_000),
+            fm(145_825_000),
+            false,
+            RigMode::PKT,
+        ),
+        transponder(
+            27607,
+            "SO-50",
+            "FM voice",
+            FreqRange::new(436_795_000, 436_795_000),
+            fm(145_850_000),
+            false,
+            RigMode::FM,
+        ),
+        transponder(
+            43678,
+            "PO-101",
+            "FM voice",
+            FreqRange::new(145_900_000, 145_900_000),
+            fm(437_500_000),
+            false,
+            RigMode::FM,
+        ),
+        transponder(
+            44909,
+            "RS-44",
+            "Linear",
+            FreqRange::new(435_610_000, 435_670_000),
+            Some(FreqRange::new(145_935_000, 145_995_000)),
+            true,
+            RigMode::USB,
+        ),
+        transponder(
+            7530,
+            "AO-7",
+            "Mode B linear",
+            FreqRange::new(145_925_000, 145_975_000),
+            Some(FreqRange::new(432_125_000, 432_175_000)),
+            true,
+            RigMode::USB,
+        ),
+        transponder(
+            24278,
+            "FO-29",
+            "Linear",
+            FreqRange::new(435_800_000, 435_900_000),
+            Some(FreqRange::new(145_900_000, 146_000_000)),
+            true,
+            RigMode::USB,
+        ),
+        transponder(
+            39444,
+            "AO-73",
+            "Linear",
+            FreqRange::new(145_950_000, 145_970_000),
+            Some(FreqRange::new(435_130_000, 435_150_000)),
+            true,
+            RigMode::USB,
+        ),
+        transponder(
+            39444,
+            "AO-73",
+            "Telemetry",
+            FreqRange::new(145_935_000, 145_935_000),
+            None,
+            false,
+            RigMode::USB,
+        ),
+    ]
+}
+
+/// Find a transponder by satellite and optional name; the first transponder
+/// of the satellite is used when no name is given.
+pub fn find_transponder(norad_id: u32, name: Option<&str>) -> Option<Transponder> {
+    builtin_transponders()
+        .into_iter()
+        .find(|t| t.norad_id == norad_id && name.is_none_or(|n| t.name.eq_ignore_ascii_case(n)))
+}
+
+/// Frequency heard on the ground for a signal sent at `sat_hz`.
+pub fn observed_downlink_hz(sat_hz: u64, range_rate_km_s: f64) -> u64 {
+    (sat_hz as f64 * (1.0 - range_rate_km_s / SPEED_OF_LIGHT_KM_S)).round() as u64
+}
+
+/// Inverse of [`observed_downlink_hz`].
+pub fn satellite_downlink_hz(observed_hz: u64, range_rate_km_s: f64) -> u64 {
+    (observed_hz as f64 / (1.0 - range_rate_km_s / SPEED_OF_LIGHT_KM_S)).round() as u64
+}
+
+/// Frequency to transmit so that the satellite receives `sat_hz`.
+pub fn transmit_uplink_hz(sat_hz: u64, range_rate_km_s: f64) -> u64 {
+    (sat_hz as f64 / (1.0 - range_rate_km_s / SPEED_OF_LIGHT_KM_S)).round() as u64
+}
+
+/// Live state of Doppler tracking, published with the rig state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SatTrackingState {
+    pub norad_id: u32,
+    pub satellite: String,
+    pub transponder: String,
+    /// Selected downlink frequency in the satellite frame.
+    pub downlink_hz: u64,
+    /// Paired uplink frequency in the satellite frame.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uplink_hz: Option<u64>,
+    /// Corrected receive frequency.
+    pub rx_hz: u64,
+    /// Corrected transmit frequency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_hz: Option<u64>,
+    /// Whether the rig accepted the uplink on its split VFO.
+    #[serde(default)]
+    pub split_active: bool,
+    pub elevation_deg: f64,
+    pub azimuth_deg: f64,
+    pub range_km: f64,
+    pub range_rate_km_s: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approaching_satellite_is_heard_high() {
+        // 7 km/s closing speed on 70 cm is roughly +10 kHz.
+        let rx = observed_downlink_hz(437_800_000, -7.0);
+        assert!((10_200..10_250).contains(&(rx - 437_800_000)), "{rx}");
+        assert_eq!(satellite_downlink_hz(rx, -7.0), 437_800_000);
+        assert!(transmit_uplink_hz(145_990_000, -7.0) < 145_990_000);
+        assert!(transmit_uplink_hz(145_990_000, 7.0) > 145_990_000);
+    }
+
+    #[test]
+    fn inverting_transponder_mirrors_uplink() {
+        let rs44 = find_transponder(44909, None).unwrap();
+        assert_eq!(rs44.uplink_for(435_610_000), Some(145_995_000));
+        assert_eq!(rs44.uplink_for(435_640_000), Some(145_965_000));
+        assert_eq!(rs44.uplink_for(435_700_000), Some(145_935_000));
+
+        let iss = find_transponder(25544, Some("fm voice")).unwrap();
+        assert_eq!(iss.uplink_for(437_800_000), Some(145_990_000));
+        assert!(find_transponder(39444, Some("Telemetry"))
+            .unwrap()
+            .uplink_for(145_935_000)
+            .is_none());
+    }
+}
@@ -0,0 +1,369 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! DX cluster and Reverse Beacon Network spots.
+//!
+//! Cluster nodes and the RBN telnet feed both emit one spot per line in the
+//! classic `DX de SPOTTER: FREQ CALL comment HHMMZ` layout.  RBN comments
+//! additionally start with the mode followed by `NN dB` and `NN WPM` fields.
+
+use serde::{Deserialize, Serialize};
+
+/// Two spots of the same callsign closer than this are the same signal.
+pub const SPOT_MERGE_HZ: u64 = 1_000;
+
+/// Modes recognised in spot comments.
+const KNOWN_MODES: &[&str] = &[
+    "CW", "SSB", "USB", "LSB", "AM", "FM", "RTTY", "FT8", "FT4", "JS8", "PSK31", "PSK63", "BPSK",
+    "JT65", "JT9", "Q65", "MSK144", "FST4", "WSPR", "SSTV", "OLIVIA",
+];
+
+/// A single DX spot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DxSpot {
+    /// Station that reported the spot (cluster user or skimmer).
+    pub spotter: String,
+    /// Spotted callsign.
+    pub call: String,
+    pub freq_hz: u64,
+    #[serde(default)]
+    pub comment: String,
+    /// Time the spot was received or last refreshed (Unix ms).
+    pub ts_ms: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snr_db: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wpm: Option<u32>,
+    /// Number of reports merged into this spot.
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+/// Parse one line of DX cluster / RBN output.
+///
+/// Returns `None` for anything that is not a spot (banners, prompts, talk).
+pub fn parse_cluster_line(line: &str, now_ms: i64) -> Option<DxSpot> {
+    let line = line.trim();
+    let rest = line
+        .strip_prefix("DX de ")
+        .or_else(|| line.strip_prefix("DX DE "))?;
+    let (spotter, rest) = rest.split_once(':')?;
+    let spotter = spotter.trim().trim_end_matches("-#").to_uppercase();
+    if spotter.is_empty() {
+        return None;
+    }
+
+    let mut tokens = rest.split_whitespace();
+    let freq_khz: f64 = tokens.next()?.parse().ok()?;
+    if !freq_khz.is_finite() || freq_khz <= 0.0 {
+        return None;
+    }
+    let call = tokens.next()?.to_uppercase();
+    let mut comment: Vec<&str> = tokens.collect();
+    // Drop the trailing `HHMMZ` (optionally followed by a locator).
+    if let Some(pos) = comment.iter().rposition(|t| is_spot_time(t)) {
+        if comment.len() - pos <= 2 {
+            comment.truncate(pos);
+        }
+    }
+
+    let mode = comment
+        .iter()
+        .map(|t| t.to_uppercase())
+        .find(|t| KNOWN_MODES.contains(&t.as_str()));
+    let snr_db = value_before(&comment, "dB").and_then(|v| v.parse().ok());
+    let wpm = value_before(&comment, "WPM").and_then(|v| v.parse().ok());
+
+    Some(DxSpot {
+        spotter,
+        call,
+        freq_hz: (freq_khz * 1000.0).round() as u64,
+        comment: comment.join(" "),
+        ts_ms: now_ms,
+        mode,
+        snr_db,
+        wpm,
+        count: 1,
+    })
+}
+
+/// Render a spot in the cluster layout.  Mode, SNR and speed are written in
+/// front of the comment the way RBN nodes do, so [`parse_cluster_line`]
+/// reads them back.
+pub fn format_spot_line(spot: &DxSpot) -> String {
+    let mut comment: Vec<String> = Vec::new();
+    if let Some(mode) = &spot.mode {
+        comment.push(format!("{mode:<5}"));
+    }
+    if let Some(snr) = spot.snr_db {
+        comment.push(format!("{snr:>3} dB"));
+    }
+    if let Some(wpm) = spot.wpm {
+        comment.push(format!("{wpm:>2} WPM"));
+    }
+    if !spot.comment.is_empty() {
+        comment.push(spot.comment.clone());
+    }
+    let minutes = spot.ts_ms.div_euclid(60_000).rem_euclid(24 * 60);
+    format!(
+        "DX de {:<10}{:>8.1}  {:<12} {:<30} {:02}{:02}Z",
+        format!("{}:", spot.spotter),
+        spot.freq_hz as f64 / 1000.0,
+        spot.call,
+        comment.join(" "),
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+fn is_spot_time(token: &str) -> bool {
+    token.len() == 5
+        && token.ends_with(['Z', 'z'])
+        && token[..4].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Token preceding a unit label, e.g. `18` in `18 dB`.
+fn value_before<'a>(tokens: &[&'a str], unit: &str) -> Option<&'a str> {
+    tokens
+        .windows(2)
+        .find(|w| w[1].eq_ignore_ascii_case(unit))
+        .map(|w| w[0])
+}
+
+/// Which spots to keep.  Empty lists match everything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpotFilter {
+    /// Modes to keep (e.g. `["CW", "FT8"]`); spots without a mode are dropped
+    /// when this is non-empty.
+    pub modes: Vec<String>,
+    /// Callsigns to keep; a trailing `*` matches a prefix (`"DL*"`).
+    pub calls: Vec<String>,
+    pub min_freq_hz: Option<u64>,
+    pub max_freq_hz: Option<u64>,
+}
+
+impl SpotFilter {
+    pub fn matches(&self, spot: &DxSpot) -> bool {
+        if self.min_freq_hz.is_some_and(|min| spot.freq_hz < min)
+            || self.max_freq_hz.is_some_and(|max| spot.freq_hz > max)
+        {
+            return false;
+        }
+        if !self.modes.is_empty()
+            && !spot
+                .mode
+                .as_deref()
+                .is_some_and(|m| self.modes.iter().any(|f| f.eq_ignore_ascii_case(m)))
+        {
+            return false;
+        }
+        if !self.calls.is_empty() {
+            let call = spot.call.to_uppercase();
+            return self.calls.iter().any(|pattern| {
+                let pattern = pattern.trim().to_uppercase();
+                match pattern.strip_suffix('*') {
+                    Some(prefix) => call.starts_with(prefix),
+                    None => call == pattern,
+                }
+            });
+        }
+        true
+    }
+}
+
+/// Deduplicated, age-limited set of current spots.
+#[derive(Debug, Clone)]
+pub struct SpotTable {
+    max_age_ms: i64,
+    max_spots: usize,
+    spots: Vec<DxSpot>,
+}
+
+impl SpotTable {
+    pub fn new(max_age_ms: i64, max_spots: usize) -> Self {
+        Self {
+            max_age_ms,
+            max_spots,
+            spots: Vec::new(),
+        }
+    }
+
+    pub fn max_age_ms(&self) -> i64 {
+        self.max_age_ms
+    }
+
+    /// Add a spot, merging it into an existing spot of the same callsign
+    /// within [`SPOT_MERGE_HZ`].  Returns the stored spot.
+    pub fn insert(&mut self, spot: DxSpot) -> DxSpot {
+        self.prune(spot.ts_ms);
+        let existing = self.spots.iter_mut().find(|s| {
+            s.call.eq_ignore_ascii_case(&spot.call)
+                && s.freq_hz.abs_diff(spot.freq_hz) <= SPOT_MERGE_HZ
+        });
+        if let Some(existing) = existing {
+            existing.count = existing.count.saturating_add(spot.count);
+            existing.freq_hz = spot.freq_hz;
+            existing.spotter = spot.spotter;
+            existing.ts_ms = existing.ts_ms.max(spot.ts_ms);
+            if !spot.comment.is_empty() {
+                existing.comment = spot.comment;
+            }
+            existing.mode = spot.mode.or(existing.mode.take());
+            existing.snr_db = spot.snr_db.or(existing.snr_db);
+            existing.wpm = spot.wpm.or(existing.wpm);
+            return existing.clone();
+        }
+        if self.spots.len() >= self.max_spots.max(1) {
+            if let Some(oldest) = self
+                .spots
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, s)| s.ts_ms)
+                .map(|(i, _)| i)
+            {
+                self.spots.swap_remove(oldest);
+            }
+        }
+        self.spots.push(spot.clone());
+        spot
+    }
+
+    /// Drop spots older than the configured age.
+    pub fn prune(&mut self, now_ms: i64) {
+        let cutoff = now_ms.saturating_sub(self.max_age_ms);
+        self.spots.retain(|s| s.ts_ms >= cutoff);
+    }
+
+    /// Current spots, newest first.
+    pub fn spots(&self) -> Vec<DxSpot> {
+        let mut spots = self.spots.clone();
+        spots.sort_by_key(|s| std::cmp::Reverse(s.ts_ms));
+        spots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_cluster_spot() {
+        let spot = parse_cluster_line(
+            "DX de SP5XYZ:     14025.0  dl1abc       CQ up 2                        1234Z",
+            1000,
+        )
+        .unwrap();
+        assert_eq!(spot.spotter, "SP5XYZ");
+        assert_eq!(spot.call, "DL1ABC");
+        assert_eq!(spot.freq_hz, 14_025_000);
+        assert_eq!(spot.comment, "CQ up 2");
+        assert_eq!(spot.mode, None);
+        assert_eq!(spot.ts_ms, 1000);
+    }
+
+    #[test]
+    fn parses_rbn_spot() {
+        let spot = parse_cluster_line(
+            "DX de EA5WU-#:   7012.3  K1ABC        CW    18 dB  24 WPM  CQ      0915Z",
+            0,
+        )
+        .unwrap();
+        assert_eq!(spot.spotter, "EA5WU");
+        assert_eq!(spot.freq_hz, 7_012_300);
+        assert_eq!(spot.mode.as_deref(), Some("CW"));
+        assert_eq!(spot.snr_db, Some(18));
+        assert_eq!(spot.wpm, Some(24));
+    }
+
+    #[test]
+    fn formatted_spot_parses_back() {
+        let spot = DxSpot {
+            spotter: "N0CALL-#".to_string(),
+            call: "DL1ABC".to_string(),
+            freq_hz: 14_025_100,
+            comment: "CQ".to_string(),
+            ts_ms: (12 * 60 + 34) * 60_000,
+            mode: Some("CW".to_string()),
+            snr_db: Some(18),
+            wpm: Some(24),
+            count: 1,
+        };
+        let line = format_spot_line(&spot);
+        assert!(
+            line.starts_with("DX de N0CALL-#:  14025.1  DL1ABC"),
+            "{line}"
+        );
+        assert!(line.ends_with("1234Z"), "{line}");
+        let parsed = parse_cluster_line(&line, 0).unwrap();
+        assert_eq!(parsed.spotter, "N0CALL");
+        assert_eq!(parsed.call, spot.call);
+        assert_eq!(parsed.freq_hz, spot.freq_hz);
+        assert_eq!(parsed.mode, spot.mode);
+        assert_eq!(parsed.snr_db, spot.snr_db);
+        assert_eq!(parsed.wpm, spot.wpm);
+        assert_eq!(parsed.comment, "CW 18 dB 24 WPM CQ");
+    }
+
+    #[test]
+    fn rejects_non_spot_lines() {
+        assert!(parse_cluster_line("Please enter your call:", 0).is_none());
+        assert!(parse_cluster_line("DX de X: abc K1ABC", 0).is_none());
+        assert!(parse_cluster_line("To ALL de N0CALL: hello", 0).is_none());
+    }
+
+    #[test]
+    fn filter_matches_modes_calls_and_range() {
+        let spot = parse_cluster_line("DX de A1A: 14074.0 DL1ABC FT8 -10 dB 1200Z", 0).unwrap();
+        let mut filter = SpotFilter::default();
+        assert!(filter.matches(&spot));
+        filter.modes = vec!["cw".into()];
+        assert!(!filter.matches(&spot));
+        filter.modes = vec!["ft8".into()];
+        filter.calls = vec!["DL*".into()];
+        assert!(filter.matches(&spot));
+        filter.calls = vec!["DL1AB".into()];
+        assert!(!filter.matches(&spot));
+        filter.calls.clear();
+        filter.max_freq_hz = Some(7_300_000);
+        assert!(!filter.matches(&spot));
+    }
+
+    #[test]
+    fn table_merges_and_ages_out() {
+        let mut table = SpotTable::new(60_000, 10);
+        let a = parse_cluster_line("DX de A1A: 14025.0 K1ABC CW 1200Z", 0).unwrap();
+        let b = parse_cluster_line("DX de B2B: 14025.4 K1ABC CW 1201Z", 10_000).unwrap();
+        table.insert(a);
+        let merged = table.insert(b);
+        assert_eq!(merged.count, 2);
+        assert_eq!(merged.spotter, "B2B");
+        assert_eq!(table.spots().len(), 1);
+
+        let c = parse_cluster_line("DX de C3C: 14030.0 K1ABC CW 1202Z", 20_000).unwrap();
+        table.insert(c);
+        assert_eq!(table.spots().len(), 2);
+
+        table.prune(75_000);
+        assert_eq!(table.spots().len(), 1);
+        assert_eq!(table.spots()[0].spotter, "C3C");
+    }
+
+    #[test]
+    fn table_evicts_oldest_when_full() {
+        let mut table = SpotTable::new(i64::MAX, 2);
+        for (i, call) in ["K1A", "K1B", "K1C"].iter().enumerate() {
+            let line = format!("DX de X1X: 14000.0 {call} 1200Z");
+            table.insert(parse_cluster_line(&line, i as i64).unwrap());
+        }
+        let calls: Vec<_> = table.spots().into_iter().map(|s| s.call).collect();
+        assert_eq!(calls, vec!["K1C", "K1B"]);
+    }
+}
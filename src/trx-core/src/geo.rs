@@ -7,10 +7,15 @@
 //! Uses SGP4 orbital propagation to compute the ground track and geographic
 //! bounds of a satellite pass, given the satellite identity, pass timestamps,
 //! and receiver station coordinates.
+//!
+//! The TLE catalogue is merged from CelesTrak, configured URLs and local
+//! files, and uploads; it is saved to disk so air-gapped stations keep their
+//! last known elements across restarts.
 
 use sgp4::{Constants, Elements, MinutesSinceEpoch};
 use std::collections::HashMap;
 use std::f64::consts::PI;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 use std::time::Duration;
 
@@ -23,13 +28,17 @@ pub struct PassPredictionResult {
     pub satellite_count: usize,
     /// Whether predictions are based on live CelesTrak TLE data.
     pub tle_source: TleSource,
+    /// Set when some elements are older than the configured age threshold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tle_warning: Option<String>,
 }
 
 /// Indicates the origin of the TLE data used for predictions.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TleSource {
-    /// Live TLE data fetched from CelesTrak.
+    /// TLE catalogue loaded (CelesTrak, configured sources, uploads or the
+    /// saved cache).
     Celestrak,
     /// No TLE data available yet (CelesTrak fetch pending or failed).
     Unavailable,
@@ -56,6 +65,8 @@ const CELESTRAK_HAM_URL: &str =
 /// How often to refresh TLEs after the initial fetch (24 hours).
 const TLE_REFRESH_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
 
+const MS_PER_DAY: f64 = 86_400_000.0;
+
 /// Satellite category based on TLE source group.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -66,7 +77,7 @@ pub enum SatCategory {
 }
 
 /// A single TLE entry: satellite name + two-line element set.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TleEntry {
     pub name: String,
     pub line1: String,
@@ -79,6 +90,36 @@ pub struct TleEntry {
 /// Keys are NORAD catalog numbers; values contain the satellite name and TLE lines.
 static TLE_STORE: RwLock<Option<HashMap<u32, TleEntry>>> = RwLock::new(None);
 
+/// File the merged catalogue is saved to after every change, if configured.
+static TLE_CACHE_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Where TLEs are loaded from.
+#[derive(Debug, Clone)]
+pub struct TleOptions {
+    /// Fetch the weather, NOAA and amateur groups from CelesTrak.
+    pub celestrak: bool,
+    /// Extra URLs serving TLE text.
+    pub urls: Vec<String>,
+    /// Local TLE files, or directories whose files are all read.
+    pub paths: Vec<PathBuf>,
+    /// File the merged catalogue is restored from and saved to.
+    pub cache_path: Option<PathBuf>,
+    /// Interval between refreshes of URLs and local files.
+    pub refresh_interval: Duration,
+}
+
+impl Default for TleOptions {
+    fn default() -> Self {
+        Self {
+            celestrak: true,
+            urls: Vec::new(),
+            paths: Vec::new(),
+            cache_path: None,
+            refresh_interval: TLE_REFRESH_INTERVAL,
+        }
+    }
+}
+
 /// Geographic bounds for a satellite image overlay: `[south, west, north, east]`.
 pub type GeoBounds = [f64; 4];
 
@@ -115,6 +156,9 @@ pub struct PassPrediction {
     pub azimuth_los_deg: f64,
     /// Pass duration in seconds.
     pub duration_s: u64,
+    /// Age of the orbital elements at the start of the prediction window.
+    #[serde(default)]
+    pub tle_age_days: f64,
 }
 
 /// Map satellite name patterns to NORAD catalog numbers.
@@ -190,40 +234,123 @@ fn tle_for_satellite(name: &str) -> Option<(String, String)> {
 }
 
 // ---------------------------------------------------------------------------
-// CelesTrak TLE refresh
+// TLE sources
 // ---------------------------------------------------------------------------
 
-/// Parse a CelesTrak 3-line TLE response into a map of NORAD ID → TleEntry.
+/// Parse TLE text into a map of NORAD ID → TleEntry.
+///
+/// Accepts the 3-line format CelesTrak serves as well as bare 2-line sets,
+/// which are named after their catalogue number.
 fn parse_tle_response(body: &str, category: SatCategory) -> HashMap<u32, TleEntry> {
     let mut result = HashMap::new();
-    let lines: Vec<&str> = body.lines().map(|l| l.trim_end()).collect();
+    let lines: Vec<&str> = body
+        .lines()
+        .map(|l| l.trim_end())
+        .filter(|l| !l.trim().is_empty())
+        .collect();
     let mut i = 0;
-    while i + 2 < lines.len() {
-        let name_line = lines[i].trim();
-        let line1 = lines[i + 1];
-        let line2 = lines[i + 2];
+    while i + 1 < lines.len() {
+        let line1 = lines[i];
+        let line2 = lines[i + 1];
         // Validate TLE line markers
-        if line1.starts_with("1 ") && line2.starts_with("2 ") {
-            // Extract NORAD catalog number from line 1 columns 2-6
-            if let Ok(norad_id) = line1[2..7].trim().parse::<u32>() {
-                result.insert(
-                    norad_id,
-                    TleEntry {
-                        name: name_line.to_string(),
-                        line1: line1.to_string(),
-                        line2: line2.to_string(),
-                        category,
+        if !(line1.starts_with("1 ") && line2.starts_with("2 ")) {
+            i += 1;
+            continue;
+        }
+        // Extract NORAD catalog number from line 1 columns 2-6
+        let norad_id = line1.get(2..7).and_then(|id| id.trim().parse::<u32>().ok());
+        if let Some(norad_id) = norad_id {
+            let name = match i.checked_sub(1).map(|n| lines[n].trim()) {
+                Some(name) if !name.starts_with("2 ") => name,
+                _ => "",
+            };
+            let name = name.strip_prefix("0 ").unwrap_or(name);
+            result.insert(
+                norad_id,
+                TleEntry {
+                    name: if name.is_empty() {
+                        format!("NORAD {norad_id}")
+                    } else {
+                        name.to_string()
                     },
-                );
-            }
+                    line1: line1.to_string(),
+                    line2: line2.to_string(),
+                    category,
+                },
+            );
         }
-        i += 3;
+        i += 2;
     }
     result
 }
 
-/// Fetch TLEs from a CelesTrak URL and merge them into the global store.
-async fn fetch_and_merge_tles(url: &str, category: SatCategory) -> Result<usize, String> {
+/// Epoch of a TLE entry in milliseconds since the Unix epoch, read from
+/// the `YYDDD.DDDDDDDD` field of line 1.
+fn tle_epoch_ms(entry: &TleEntry) -> Option<i64> {
+    let field = entry.line1.get(18..32)?.trim();
+    let yy: i64 = field.get(..2)?.parse().ok()?;
+    let day: f64 = field.get(2..)?.parse().ok()?;
+    // Two-digit years 57-99 are 1957-1999, per the TLE convention.
+    let year = if yy < 57 { 2000 + yy } else { 1900 + yy };
+    let is_leap = |y: i64| (y % 4 == 0 && y % 100 != 0) || y % 400 == 0;
+    let days_to_year: i64 = (1970..year)
+        .map(|y| if is_leap(y) { 366 } else { 365 })
+        .sum();
+    Some(days_to_year * 86_400_000 + ((day - 1.0) * MS_PER_DAY).round() as i64)
+}
+
+/// Merge `entries` into `store`, keeping whichever set has the newer epoch.
+///
+/// Entries from sources without a category inherit the one already known
+/// for the satellite.  Returns the number of entries added or replaced.
+fn merge_tle_entries(store: &mut HashMap<u32, TleEntry>, entries: HashMap<u32, TleEntry>) -> usize {
+    let mut merged = 0;
+    for (norad_id, mut entry) in entries {
+        if let Some(existing) = store.get(&norad_id) {
+            if tle_epoch_ms(existing) > tle_epoch_ms(&entry) {
+                continue;
+            }
+            if entry.category == SatCategory::Other {
+                entry.category = existing.category;
+            }
+        }
+        store.insert(norad_id, entry);
+        merged += 1;
+    }
+    merged
+}
+
+/// Merge `entries` into the global store and save the catalogue.
+fn merge_into_store(entries: HashMap<u32, TleEntry>) -> usize {
+    let merged = {
+        let mut guard = match TLE_STORE.write() {
+            Ok(g) => g,
+            Err(e) => e.into_inner(),
+        };
+        merge_tle_entries(guard.get_or_insert_with(HashMap::new), entries)
+    };
+    if merged > 0 {
+        save_tle_cache();
+    }
+    merged
+}
+
+/// Merge TLE text (for example an uploaded file) into the global store.
+///
+/// Returns the number of element sets parsed, or an error when the text
+/// contains none.
+pub fn load_tle_text(body: &str) -> Result<usize, String> {
+    let tles = parse_tle_response(body, SatCategory::Other);
+    let count = tles.len();
+    if count == 0 {
+        return Err("no valid TLEs found".to_string());
+    }
+    merge_into_store(tles);
+    Ok(count)
+}
+
+/// Fetch TLE text from a URL.
+async fn fetch_tle_text(url: &str) -> Result<String, String> {
     let response = reqwest::Client::builder()
         .timeout(Duration::from_secs(30))
         .build()
@@ -231,43 +358,71 @@ async fn fetch_and_merge_tles(url: &str, category: SatCategory) -> Result<usize,
         .get(url)
         .send()
         .await
-        .map_err(|e| format!("CelesTrak fetch failed: {e}"))?;
+        .map_err(|e| format!("fetch failed: {e}"))?;
 
     if !response.status().is_success() {
-        return Err(format!("CelesTrak returned HTTP {}", response.status()));
+        return Err(format!("HTTP {}", response.status()));
     }
 
-    let body = response
+    response
         .text()
         .await
-        .map_err(|e| format!("Failed to read CelesTrak response: {e}"))?;
+        .map_err(|e| format!("failed to read response: {e}"))
+}
 
+/// Fetch TLEs from a URL and merge them into the global store.
+async fn fetch_and_merge_tles(url: &str, category: SatCategory) -> Result<usize, String> {
+    let body = fetch_tle_text(url).await?;
     let tles = parse_tle_response(&body, category);
     let count = tles.len();
 
     if count == 0 {
-        return Err("CelesTrak response contained no valid TLEs".to_string());
+        return Err("response contained no valid TLEs".to_string());
     }
 
-    match TLE_STORE.write() {
-        Ok(mut guard) => {
-            if let Some(store) = guard.as_mut() {
-                store.extend(tles);
-            } else {
-                *guard = Some(tles);
+    merge_into_store(tles);
+    Ok(count)
+}
+
+/// Read TLEs from a local file, or from every file in a directory.
+fn read_tle_path(path: &Path) -> Result<HashMap<u32, TleEntry>, String> {
+    let mut files = Vec::new();
+    if path.is_dir() {
+        let dir = std::fs::read_dir(path).map_err(|e| e.to_string())?;
+        files.extend(
+            dir.filter_map(Result::ok)
+                .map(|e| e.path())
+                .filter(|p| p.is_file()),
+        );
+        files.sort();
+    } else {
+        files.push(path.to_path_buf());
+    }
+
+    let mut tles = HashMap::new();
+    for file in files {
+        let body =
+            std::fs::read_to_string(&file).map_err(|e| format!("{}: {e}", file.display()))?;
+        merge_tle_entries(&mut tles, parse_tle_response(&body, SatCategory::Other));
+    }
+    Ok(tles)
+}
+
+/// Load every configured local path into the global store.
+fn load_tle_paths(paths: &[PathBuf]) {
+    for path in paths {
+        match read_tle_path(path) {
+            Ok(tles) if tles.is_empty() => {
+                tracing::warn!("TLE refresh: no valid TLEs in {}", path.display())
             }
-        }
-        Err(e) => {
-            let mut guard = e.into_inner();
-            if let Some(store) = guard.as_mut() {
-                store.extend(tles);
-            } else {
-                *guard = Some(tles);
+            Ok(tles) => {
+                let n = tles.len();
+                merge_into_store(tles);
+                tracing::info!("TLE refresh: loaded {n} TLEs from {}", path.display())
             }
+            Err(e) => tracing::warn!("TLE refresh: cannot read {} ({e})", path.display()),
         }
     }
-
-    Ok(count)
 }
 
 /// Fetch fresh TLE data from CelesTrak and update the global store.
@@ -277,6 +432,106 @@ pub async fn refresh_tles_from_celestrak() -> Result<usize, String> {
     fetch_and_merge_tles(CELESTRAK_WEATHER_URL, SatCategory::Weather).await
 }
 
+/// Refresh the store from CelesTrak (when enabled), configured URLs and
+/// local paths.  Failures are logged; previously loaded TLEs stay in use.
+async fn refresh_tle_sources(options: &TleOptions) {
+    if options.celestrak {
+        let groups = [
+            ("weather", CELESTRAK_WEATHER_URL, SatCategory::Weather),
+            ("NOAA", CELESTRAK_NOAA_URL, SatCategory::Weather),
+            ("amateur", CELESTRAK_HAM_URL, SatCategory::Amateur),
+        ];
+        for (group, url, category) in groups {
+            match fetch_and_merge_tles(url, category).await {
+                Ok(n) => {
+                    tracing::info!("TLE refresh: loaded {n} {group} satellite TLEs from CelesTrak")
+                }
+                Err(e) => tracing::warn!(
+                    "TLE refresh: CelesTrak {group} fetch failed ({e}), keeping previous TLEs"
+                ),
+            }
+        }
+    }
+    for url in &options.urls {
+        match fetch_and_merge_tles(url, SatCategory::Other).await {
+            Ok(n) => tracing::info!("TLE refresh: loaded {n} TLEs from {url}"),
+            Err(e) => tracing::warn!("TLE refresh: {url} failed ({e}), keeping previous TLEs"),
+        }
+    }
+    load_tle_paths(&options.paths);
+}
+
+// ---------------------------------------------------------------------------
+// TLE cache
+// ---------------------------------------------------------------------------
+
+/// Write the catalogue to the configured cache file, if any.
+fn save_tle_cache() {
+    let path = match TLE_CACHE_PATH.read() {
+        Ok(g) => g.clone(),
+        Err(e) => e.into_inner().clone(),
+    };
+    let Some(path) = path else {
+        return;
+    };
+    let mut entries: Vec<TleEntry> = {
+        let guard = match TLE_STORE.read() {
+            Ok(g) => g,
+            Err(e) => e.into_inner(),
+        };
+        match guard.as_ref() {
+            Some(store) => store.values().cloned().collect(),
+            None => return,
+        }
+    };
+    entries.sort_by(|a, b| a.line1.cmp(&b.line1));
+    if let Err(e) = write_tle_cache(&path, &entries) {
+        tracing::warn!("TLE cache: cannot write {} ({e})", path.display());
+    }
+}
+
+fn write_tle_cache(path: &Path, entries: &[TleEntry]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_vec(entries).map_err(|e| e.to_string())?;
+    // Write to a sibling file first so a crash never leaves a torn cache.
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, json).map_err(|e| e.to_string())?;
+    std::fs::rename(&tmp, path).map_err(|e| e.to_string())
+}
+
+fn read_tle_cache(path: &Path) -> Result<HashMap<u32, TleEntry>, String> {
+    let json = std::fs::read(path).map_err(|e| e.to_string())?;
+    let entries: Vec<TleEntry> = serde_json::from_slice(&json).map_err(|e| e.to_string())?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| {
+            let norad_id = entry.line1.get(2..7)?.trim().parse().ok()?;
+            Some((norad_id, entry))
+        })
+        .collect())
+}
+
+/// Restore the catalogue saved by a previous run.
+fn load_tle_cache(path: &Path) {
+    if !path.exists() {
+        return;
+    }
+    match read_tle_cache(path) {
+        Ok(tles) => {
+            let n = tles.len();
+            let mut guard = match TLE_STORE.write() {
+                Ok(g) => g,
+                Err(e) => e.into_inner(),
+            };
+            merge_tle_entries(guard.get_or_insert_with(HashMap::new), tles);
+            tracing::info!("TLE cache: restored {n} TLEs from {}", path.display());
+        }
+        Err(e) => tracing::warn!("TLE cache: cannot read {} ({e})", path.display()),
+    }
+}
+
 /// Seed the global TLE store with hardcoded fallback TLEs so that
 /// weather satellite predictions are available immediately, even before
 /// the first CelesTrak fetch completes.  CelesTrak data will overwrite
@@ -331,76 +586,57 @@ fn seed_hardcoded_tles() {
     }
 }
 
-/// Spawn a background task that fetches TLEs from CelesTrak on start and
-/// then refreshes once per day.
+/// Spawn a background task that loads TLEs from the configured sources on
+/// start and then refreshes them every `options.refresh_interval`.
 ///
-/// The task runs until the process exits.  Fetch failures are logged but
-/// do not stop the periodic refresh — hardcoded fallback TLEs remain usable.
-pub fn spawn_tle_refresh_task() {
+/// The saved catalogue and local files are loaded before this returns so
+/// predictions work offline straight away.  The task runs until the process
+/// exits; fetch failures are logged but do not stop the periodic refresh.
+pub fn spawn_tle_refresh_task(options: TleOptions) {
     // Seed the store with hardcoded TLEs immediately so that predictions
     // are available before the first CelesTrak fetch completes.
     seed_hardcoded_tles();
 
-    tokio::spawn(async {
-        // Initial fetch at startup: weather + NOAA + amateur satellites.
-        match fetch_and_merge_tles(CELESTRAK_WEATHER_URL, SatCategory::Weather).await {
-            Ok(n) => {
-                tracing::info!("TLE refresh: loaded {n} weather satellite TLEs from CelesTrak")
-            }
-            Err(e) => {
-                tracing::warn!("TLE refresh: weather fetch failed ({e}), using hardcoded TLEs")
-            }
-        }
-        match fetch_and_merge_tles(CELESTRAK_NOAA_URL, SatCategory::Weather).await {
-            Ok(n) => {
-                tracing::info!("TLE refresh: loaded {n} NOAA satellite TLEs from CelesTrak")
-            }
-            Err(e) => {
-                tracing::warn!("TLE refresh: NOAA fetch failed ({e})")
-            }
+    if let Some(path) = &options.cache_path {
+        load_tle_cache(path);
+        match TLE_CACHE_PATH.write() {
+            Ok(mut g) => *g = Some(path.clone()),
+            Err(e) => *e.into_inner() = Some(path.clone()),
         }
-        match fetch_and_merge_tles(CELESTRAK_HAM_URL, SatCategory::Amateur).await {
-            Ok(n) => {
-                tracing::info!("TLE refresh: loaded {n} amateur satellite TLEs from CelesTrak")
-            }
-            Err(e) => tracing::warn!("TLE refresh: amateur fetch failed ({e})"),
-        }
-
-        // Periodic refresh every 24 hours.
-        let mut interval = tokio::time::interval(TLE_REFRESH_INTERVAL);
-        // The first tick fires immediately; skip it since we just fetched.
-        interval.tick().await;
+    }
+    load_tle_paths(&options.paths);
 
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(options.refresh_interval);
         loop {
+            // The first tick fires immediately: initial fetch at startup.
             interval.tick().await;
-            match fetch_and_merge_tles(CELESTRAK_WEATHER_URL, SatCategory::Weather).await {
-                Ok(n) => {
-                    tracing::info!("TLE refresh: updated {n} weather satellite TLEs from CelesTrak")
-                }
-                Err(e) => {
-                    tracing::warn!("TLE refresh: weather fetch failed ({e}), keeping previous TLEs")
-                }
-            }
-            match fetch_and_merge_tles(CELESTRAK_NOAA_URL, SatCategory::Weather).await {
-                Ok(n) => {
-                    tracing::info!("TLE refresh: updated {n} NOAA satellite TLEs from CelesTrak")
-                }
-                Err(e) => {
-                    tracing::warn!("TLE refresh: NOAA fetch failed ({e}), keeping previous TLEs")
-                }
-            }
-            match fetch_and_merge_tles(CELESTRAK_HAM_URL, SatCategory::Amateur).await {
-                Ok(n) => {
-                    tracing::info!("TLE refresh: updated {n} amateur satellite TLEs from CelesTrak")
-                }
-                Err(e) => {
-                    tracing::warn!("TLE refresh: amateur fetch failed ({e}), keeping previous TLEs")
-                }
-            }
+            refresh_tle_sources(&options).await;
         }
     });
 }
 
+/// Warn about satellites whose elements are older than `max_age_days`.
+fn tle_age_warning(
+    store: &HashMap<u32, TleEntry>,
+    now_ms: i64,
+    max_age_days: f64,
+) -> Option<String> {
+    let stale: Vec<(f64, &str)> = store
+        .values()
+        .filter_map(|entry| {
+            let age_days = (now_ms - tle_epoch_ms(entry)?) as f64 / MS_PER_DAY;
+            (age_days > max_age_days).then_some((age_days, entry.name.as_str()))
+        })
+        .collect();
+    let (oldest_days, oldest) = stale.iter().copied().max_by(|a, b| a.0.total_cmp(&b.0))?;
+    Some(format!(
+        "{} of {} TLEs are older than {max_age_days:.0} days (oldest: {oldest}, {oldest_days:.0} days)",
+        stale.len(),
+        store.len()
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Pass prediction
 // ---------------------------------------------------------------------------
@@ -481,6 +717,7 @@ fn find_passes_for_sat(
         Err(_) => return vec![],
     };
     let epoch_ms = elements_epoch_ms(&elements);
+    let tle_age_days = ((start_ms - epoch_ms) as f64 / MS_PER_DAY * 10.0).round() / 10.0;
 
     let obs_ecef = latlon_to_ecef(obs_lat, obs_lon);
     let obs_lat_rad = obs_lat * PI / 180.0;
@@ -531,6 +768,7 @@ fn find_passes_for_sat(
                 azimuth_aos_deg: (aos_az * 10.0).round() / 10.0,
                 azimuth_los_deg: (prev_az * 10.0).round() / 10.0,
                 duration_s: ((t_ms - aos_ms) / 1000) as u64,
+                tle_age_days,
             });
             in_pass = false;
             max_el = 0.0;
@@ -550,6 +788,7 @@ fn find_passes_for_sat(
             azimuth_aos_deg: (aos_az * 10.0).round() / 10.0,
             azimuth_los_deg: (prev_az * 10.0).round() / 10.0,
             duration_s: ((start_ms + window_ms - aos_ms) / 1000) as u64,
+            tle_age_days,
         });
     }
 
@@ -559,7 +798,8 @@ fn find_passes_for_sat(
 /// Compute upcoming passes for all satellites in the TLE store over the next
 /// `window_ms` milliseconds, starting from `start_ms`.
 ///
-/// Iterates over every satellite fetched from CelesTrak (weather + amateur).
+/// Iterates over every satellite in the TLE store.  A warning is attached
+/// when any elements are older than `max_tle_age_days`.
 /// Returns [`TleSource::Unavailable`] when CelesTrak data has not been
 /// fetched yet — the hardcoded fallback TLEs use approximate orbital
 /// elements and are NOT suitable for pass-time predictions.
@@ -569,6 +809,7 @@ pub fn compute_upcoming_passes(
     station_lon: f64,
     start_ms: i64,
     window_ms: i64,
+    max_tle_age_days: f64,
 ) -> PassPredictionResult {
     let guard = match TLE_STORE.read() {
         Ok(g) => g,
@@ -594,6 +835,7 @@ pub fn compute_upcoming_passes(
             passes: all_passes,
             satellite_count,
             tle_source: TleSource::Celestrak,
+            tle_warning: tle_age_warning(store, start_ms, max_tle_age_days),
         }
     } else {
         // No CelesTrak data available — don't use hardcoded TLEs for
@@ -603,6 +845,7 @@ pub fn compute_upcoming_passes(
             passes: vec![],
             satellite_count: 0,
             tle_source: TleSource::Unavailable,
+            tle_warning: None,
         }
     }
 }
@@ -727,6 +970,111 @@ pub fn estimate_pass_geo_from_station(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Live look angles
+// ---------------------------------------------------------------------------
+
+/// Earth rotation rate in rad/s.
+const EARTH_ROTATION_RAD_S: f64 = 7.292_115e-5;
+
+/// Instantaneous geometry of a satellite as seen from the station.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SatLook {
+    /// Elevation in degrees above the horizon.
+    pub elevation_deg: f64,
+    /// Azimuth in degrees (0 = N, 90 = E).
+    pub azimuth_deg: f64,
+    /// Slant range in km.
+    pub range_km: f64,
+    /// Range rate in km/s; negative while the satellite approaches.
+    pub range_rate_km_s: f64,
+}
+
+/// Name of a satellite in the TLE store.
+pub fn satellite_name(norad_id: u32) -> Option<String> {
+    let guard = match TLE_STORE.read() {
+        Ok(g) => g,
+        Err(e) => e.into_inner(),
+    };
+    guard.as_ref()?.get(&norad_id).map(|e| e.name.clone())
+}
+
+/// Compute where a satellite is relative to the station at `time_ms`.
+///
+/// Returns `None` when the satellite is not in the TLE store or
+/// propagation fails.
+pub fn look_at(norad_id: u32, station_lat: f64, station_lon: f64, time_ms: i64) -> Option<SatLook> {
+    let entry = {
+        let guard = match TLE_STORE.read() {
+            Ok(g) => g,
+            Err(e) => e.into_inner(),
+        };
+        guard.as_ref()?.get(&norad_id)?.clone()
+    };
+    look_from_tle(
+        &entry.line1,
+        &entry.line2,
+        station_lat,
+        station_lon,
+        time_ms,
+    )
+}
+
+/// Look angles and range rate from the SGP4 state vector.
+///
+/// Works in the inertial frame: the station is rotated into ECI and given
+/// the velocity of the Earth's rotation, so the range rate is the projection
+/// of the relative velocity onto the line of sight.
+fn look_from_tle(
+    line1: &str,
+    line2: &str,
+    station_lat: f64,
+    station_lon: f64,
+    time_ms: i64,
+) -> Option<SatLook> {
+    let elements = Elements::from_tle(None, line1.as_bytes(), line2.as_bytes()).ok()?;
+    let constants = Constants::from_elements(&elements).ok()?;
+    let minutes = (time_ms - elements_epoch_ms(&elements)) as f64 / 60_000.0;
+    let pred = constants.propagate(MinutesSinceEpoch(minutes)).ok()?;
+
+    let obs_ecef = latlon_to_ecef(station_lat, station_lon);
+    let sat_ecef = eci_to_ecef(
+        pred.position[0],
+        pred.position[1],
+        pred.position[2],
+        time_ms,
+    );
+    let (elevation_deg, azimuth_deg) = compute_az_el(
+        sat_ecef,
+        obs_ecef,
+        station_lat.to_radians(),
+        station_lon.to_radians(),
+    );
+
+    let gmst = gmst_from_ms(time_ms);
+    let obs_eci = [
+        obs_ecef[0] * gmst.cos() - obs_ecef[1] * gmst.sin(),
+        obs_ecef[0] * gmst.sin() + obs_ecef[1] * gmst.cos(),
+        obs_ecef[2],
+    ];
+    let obs_vel = [
+        -EARTH_ROTATION_RAD_S * obs_eci[1],
+        EARTH_ROTATION_RAD_S * obs_eci[0],
+        0.0,
+    ];
+    let rel: [f64; 3] = std::array::from_fn(|i| pred.position[i] - obs_eci[i]);
+    let rel_vel: [f64; 3] = std::array::from_fn(|i| pred.velocity[i] - obs_vel[i]);
+    let range_km = rel.iter().map(|c| c * c).sum::<f64>().sqrt();
+    let range_rate_km_s = rel.iter().zip(rel_vel).map(|(r, v)| r * v).sum::<f64>() / range_km;
+
+    Some(SatLook {
+        elevation_deg,
+        azimuth_deg,
+        range_km,
+        range_rate_km_s,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Coordinate helpers
 // ---------------------------------------------------------------------------
@@ -877,6 +1225,62 @@ NOAA 19
         assert!(parse_tle_response("not a tle\n", SatCategory::Other).is_empty());
     }
 
+    #[test]
+    fn test_parse_tle_two_line_sets() {
+        let (l1, l2) = hardcoded_tle(33591).unwrap();
+        let (m1, m2) = hardcoded_tle(25338).unwrap();
+        let body = format!("{l1}\n{l2}\n\n0 NOAA 15\n{m1}\n{m2}\ngarbage\n");
+        let tles = parse_tle_response(&body, SatCategory::Other);
+        assert_eq!(tles.len(), 2);
+        assert_eq!(tles[&33591].name, "NORAD 33591");
+        assert_eq!(tles[&25338].name, "NOAA 15");
+    }
+
+    #[test]
+    fn test_merge_keeps_newer_epoch() {
+        let entry = |line1: &str, category| TleEntry {
+            name: "NOAA 19".to_string(),
+            line1: line1.to_string(),
+            line2: hardcoded_tle(33591).unwrap().1.to_string(),
+            category,
+        };
+        let newer = "1 33591U 09005A   26090.50000000  .00000028  00000-0  20000-4 0  9996";
+        let older = hardcoded_tle(33591).unwrap().0;
+        let mut store = HashMap::from([(33591, entry(newer, SatCategory::Weather))]);
+
+        let stale = HashMap::from([(33591, entry(older, SatCategory::Other))]);
+        assert_eq!(merge_tle_entries(&mut store, stale), 0);
+        assert_eq!(store[&33591].line1, newer);
+
+        let mut store = HashMap::from([(33591, entry(older, SatCategory::Weather))]);
+        let fresh = HashMap::from([(33591, entry(newer, SatCategory::Other))]);
+        assert_eq!(merge_tle_entries(&mut store, fresh), 1);
+        assert_eq!(store[&33591].line1, newer);
+        assert_eq!(store[&33591].category, SatCategory::Weather);
+    }
+
+    #[test]
+    fn test_tle_age_warning() {
+        let (l1, l2) = hardcoded_tle(33591).unwrap();
+        let store = parse_tle_response(&format!("NOAA 19\n{l1}\n{l2}\n"), SatCategory::Weather);
+        let epoch_ms = tle_epoch_ms(&store[&33591]).unwrap();
+        assert!(tle_age_warning(&store, epoch_ms + 3 * 86_400_000, 14.0).is_none());
+        let warning = tle_age_warning(&store, epoch_ms + 30 * 86_400_000, 14.0).unwrap();
+        assert!(warning.contains("NOAA 19, 30 days"), "{warning}");
+    }
+
+    #[test]
+    fn test_tle_cache_roundtrip() {
+        let (l1, l2) = hardcoded_tle(28654).unwrap();
+        let tles = parse_tle_response(&format!("NOAA 18\n{l1}\n{l2}\n"), SatCategory::Weather);
+        let path = std::env::temp_dir().join(format!("trx-tle-cache-{}.json", std::process::id()));
+        write_tle_cache(&path, &tles.values().cloned().collect::<Vec<_>>()).unwrap();
+        let restored = read_tle_cache(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(restored[&28654].name, "NOAA 18");
+        assert_eq!(restored[&28654].category, SatCategory::Weather);
+    }
+
     #[test]
     fn test_compute_pass_geo_noaa19() {
         // Simulate a ~12 minute pass
@@ -1041,12 +1445,41 @@ NOAA 19
     fn test_compute_upcoming_passes_no_store() {
         // With empty TLE store, should return unavailable source, not
         // fabricated predictions from hardcoded TLEs.
-        let result = compute_upcoming_passes(48.0, 11.0, 1774800000000, 86_400_000);
+        let result = compute_upcoming_passes(48.0, 11.0, 1774800000000, 86_400_000, 14.0);
         assert!(matches!(result.tle_source, TleSource::Unavailable));
         assert!(result.passes.is_empty());
         assert_eq!(result.satellite_count, 0);
     }
 
+    #[test]
+    fn test_range_rate_matches_range_derivative() {
+        let (line1, line2) = hardcoded_tle(33591).unwrap();
+        let t_ms = 1_774_800_000_000;
+        let look = |t| look_from_tle(line1, line2, 48.0, 11.0, t).unwrap();
+        let now = look(t_ms);
+        let slope = (look(t_ms + 1000).range_km - look(t_ms - 1000).range_km) / 2.0;
+        assert!(
+            (now.range_rate_km_s - slope).abs() < 0.01,
+            "range rate {} vs slope {slope}",
+            now.range_rate_km_s
+        );
+        assert!(now.range_rate_km_s.abs() < 8.0);
+        assert!(now.range_km > 500.0);
+    }
+
+    #[test]
+    fn test_tle_epoch_ms_matches_sgp4() {
+        let (line1, line2) = hardcoded_tle(33591).unwrap();
+        let elements = Elements::from_tle(None, line1.as_bytes(), line2.as_bytes()).unwrap();
+        let entry = TleEntry {
+            name: "NOAA 19".to_string(),
+            line1: line1.to_string(),
+            line2: line2.to_string(),
+            category: SatCategory::Weather,
+        };
+        assert_eq!(tle_epoch_ms(&entry), Some(elements_epoch_ms(&elements)));
+    }
+
     #[test]
     fn test_elements_epoch_ms() {
         // Parse a TLE and verify the epoch converts to a reasonable timestamp
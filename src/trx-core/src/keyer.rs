@@ -0,0 +1,343 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Text-to-Morse keyer: element timing and message macros.
+//!
+//! Timing follows the PARIS standard (one dit is `1200 / wpm` ms).  Weighting
+//! lengthens or shortens the key-down part of every element while keeping
+//! the element period fixed, and Farnsworth spacing stretches only the gaps
+//! between characters and words so the overall speed drops to the
+//! Farnsworth rate while characters keep their full-speed sound.
+//!
+//! Text between angle brackets is sent as a single prosign without
+//! inter-character gaps, e.g. `<AR>` or `<SK>`.
+
+use serde::{Deserialize, Serialize};
+
+/// Standard weighting: a dit is key-down for exactly one dit length.
+pub const DEFAULT_WEIGHT: u32 = 50;
+
+/// Keyer speed and shape.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KeyerSettings {
+    /// Character speed in words per minute.
+    pub wpm: u32,
+    /// Key-down share of the dit period in percent (50 = standard).
+    pub weight: u32,
+    /// Overall speed with Farnsworth spacing; ignored unless below `wpm`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub farnsworth_wpm: Option<u32>,
+}
+
+impl Default for KeyerSettings {
+    fn default() -> Self {
+        Self {
+            wpm: 25,
+            weight: DEFAULT_WEIGHT,
+            farnsworth_wpm: None,
+        }
+    }
+}
+
+/// One key-down or key-up interval of a keyed message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyElement {
+    pub key_down: bool,
+    pub duration_ms: u32,
+}
+
+/// Message memory as configured on the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CwMemory {
+    pub label: String,
+    /// Message text; may contain macros (see [`expand_macros`]).
+    pub text: String,
+}
+
+/// How keyed CW reaches the transmitter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CwKeyerOutput {
+    /// CAT keying when the backend supports it, tone audio otherwise.
+    #[default]
+    Auto,
+    /// Backend CAT keying commands only.
+    Cat,
+    /// PTT plus a keyed sidetone on the TX audio path.
+    Tone,
+}
+
+/// Live keyer state, published with the rig state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CwKeyerState {
+    pub settings: KeyerSettings,
+    pub output: CwKeyerOutput,
+    /// Audio tone used for tone keying, in Hz.
+    pub tone_hz: u32,
+    /// Next serial number for the `{SERIAL}` macro.
+    pub serial: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub memories: Vec<CwMemory>,
+    /// Expanded text currently being sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sending: Option<String>,
+}
+
+/// Values substituted into message macros.
+#[derive(Debug, Clone, Copy)]
+pub struct MacroContext<'a> {
+    pub mycall: Option<&'a str>,
+    pub call: Option<&'a str>,
+    pub serial: u32,
+}
+
+/// ITU Morse code for one character, `None` for characters without one.
+pub fn morse_code(c: char) -> Option<&'static str> {
+    let code = match c.to_ascii_uppercase() {
+        'A' => ".-",
+        'B' => "-...",
+        'C' => "-.-.",
+        'D' => "-..",
+        'E' => ".",
+        'F' => "..-.",
+        'G' => "--.",
+        'H' => "....",
+        'I' => "..",
+        'J' => ".---",
+        'K' => "-.-",
+        'L' => ".-..",
+        'M' => "--",
+        'N' => "-.",
+        'O' => "---",
+        'P' => ".--.",
+        'Q' => "--.-",
+        'R' => ".-.",
+        'S' => "...",
+        'T' => "-",
+        'U' => "..-",
+        'V' => "...-",
+        'W' => ".--",
+        'X' => "-..-",
+        'Y' => "-.--",
+        'Z' => "--..",
+        '0' => "-----",
+        '1' => ".----",
+        '2' => "..---",
+        '3' => "...--",
+        '4' => "....-",
+        '5' => ".....",
+        '6' => "-....",
+        '7' => "--...",
+        '8' => "---..",
+        '9' => "----.",
+        '.' => ".-.-.-",
+        ',' => "--..--",
+        '?' => "..--..",
+        '\'' => ".----.",
+        '!' => "-.-.--",
+        '/' => "-..-.",
+        '(' => "-.--.",
+        ')' => "-.--.-",
+        '&' => ".-...",
+        ':' => "---...",
+        ';' => "-.-.-.",
+        '=' => "-...-",
+        '+' => ".-.-.",
+        '-' => "-....-",
+        '_' => "..--.-",
+        '"' => ".-..-.",
+        '$' => "...-..-",
+        '@' => ".--.-.",
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// Expand `{MYCALL}`, `{CALL}` and `{SERIAL}` in a message template.
+///
+/// Serial numbers are zero-padded to three digits.  Unknown macros and
+/// macros without a value are errors so a half-filled exchange is never sent.
+pub fn expand_macros(template: &str, ctx: &MacroContext<'_>) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            return Err("unterminated macro".to_string());
+        };
+        let name = after[..end].trim().to_ascii_uppercase();
+        match name.as_str() {
+            "MYCALL" => out.push_str(
+                ctx.mycall
+                    .filter(|c| !c.is_empty())
+                    .ok_or("{MYCALL} used but no station callsign is configured")?,
+            ),
+            "CALL" => out.push_str(
+                ctx.call
+                    .filter(|c| !c.is_empty())
+                    .ok_or("{CALL} used but no call was given")?,
+            ),
+            "SERIAL" => out.push_str(&format!("{:03}", ctx.serial)),
+            _ => return Err(format!("unknown macro {{{name}}}")),
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out.to_ascii_uppercase())
+}
+
+/// Whether a template consumes a serial number.
+pub fn uses_serial(template: &str) -> bool {
+    template.to_ascii_uppercase().contains("{SERIAL}")
+}
+
+/// Expand already macro-free text into key-down/key-up intervals.
+///
+/// Characters without a Morse code are skipped.  The result never starts or
+/// ends with a key-up interval.
+pub fn key_timeline(text: &str, settings: &KeyerSettings) -> Vec<KeyElement> {
+    let wpm = settings.wpm.clamp(5, 60) as f64;
+    let dit = 1200.0 / wpm;
+    let weight = settings.weight.clamp(25, 75) as f64 / 50.0;
+    let dit_on = dit * weight;
+    let dah_on = 3.0 * dit + (dit_on - dit);
+    let element_gap = 2.0 * dit - dit_on;
+
+    // Farnsworth: total delay per PARIS word spread as 3:7 over the 19 dit
+    // units of character and word gaps (ARRL timing).
+    let (char_gap, word_gap) = match settings.farnsworth_wpm {
+        Some(f) if (f as f64) < wpm && f > 0 => {
+            let s = f as f64;
+            let ta_ms = (60.0 * wpm - 37.2 * s) / (s * wpm) * 1000.0;
+            (3.0 * ta_ms / 19.0, 7.0 * ta_ms / 19.0)
+        }
+        _ => (3.0 * dit, 7.0 * dit),
+    };
+    // Heavier weighting borrows the extra key-down time from the gap that
+    // follows, so the overall speed does not change.
+    let char_gap = char_gap - (dit_on - dit);
+    let word_gap = word_gap - (dit_on - dit);
+
+    let mut out: Vec<KeyElement> = Vec::new();
+    let mut push = |key_down: bool, ms: f64| {
+        let duration_ms = ms.round().max(1.0) as u32;
+        match out.last_mut() {
+            Some(last) if last.key_down == key_down => last.duration_ms += duration_ms,
+            _ => out.push(KeyElement {
+                key_down,
+                duration_ms,
+            }),
+        }
+    };
+
+    let mut pending_gap: Option<f64> = None;
+    let mut in_prosign = false;
+    for c in text.chars() {
+        match c {
+            '<' => {
+                in_prosign = true;
+                continue;
+            }
+            '>' => {
+                in_prosign = false;
+                continue;
+            }
+            c if c.is_whitespace() => {
+                if pending_gap.is_some() {
+                    pending_gap = Some(word_gap);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        let Some(code) = morse_code(c) else {
+            continue;
+        };
+        if let Some(gap) = pending_gap.take() {
+            push(false, gap);
+        }
+        for (i, sym) in code.chars().enumerate() {
+            if i > 0 {
+                push(false, element_gap);
+            }
+            push(true, if sym == '-' { dah_on } else { dit_on });
+        }
+        pending_gap = Some(if in_prosign { element_gap } else { char_gap });
+    }
+    out
+}
+
+/// Total length of a keyed message in milliseconds.
+pub fn timeline_duration_ms(timeline: &[KeyElement]) -> u64 {
+    timeline.iter().map(|e| e.duration_ms as u64).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paris_takes_one_minute_per_wpm() {
+        let settings = KeyerSettings {
+            wpm: 20,
+            ..KeyerSettings::default()
+        };
+        // PARIS plus its trailing word gap is 50 dit units.
+        let ms = timeline_duration_ms(&key_timeline("PARIS", &settings)) + 7 * 60;
+        assert_eq!(ms, 50 * 60);
+
+        let farnsworth = KeyerSettings {
+            wpm: 20,
+            farnsworth_wpm: Some(10),
+            ..KeyerSettings::default()
+        };
+        let slow = key_timeline("PARIS", &farnsworth);
+        let word_gap = 7.0 * (60.0 * 20.0 - 37.2 * 10.0) / (10.0 * 20.0) * 1000.0 / 19.0;
+        let total = timeline_duration_ms(&slow) as f64 + word_gap;
+        assert!((total - 6000.0).abs() < 5.0, "{total}");
+        // Characters themselves keep the 20 WPM element length.
+        assert_eq!(slow[0].duration_ms, 60);
+
+        let heavy = KeyerSettings {
+            wpm: 20,
+            weight: 60,
+            ..KeyerSettings::default()
+        };
+        let e = key_timeline("EE", &heavy);
+        assert_eq!(e[0].duration_ms, 72);
+        assert_eq!(e[1].duration_ms, 180 - 12);
+    }
+
+    #[test]
+    fn prosigns_run_together() {
+        let settings = KeyerSettings {
+            wpm: 20,
+            ..KeyerSettings::default()
+        };
+        // <AR> is .-.-. with element gaps only.
+        let ar = key_timeline("<AR>", &settings);
+        let plus = key_timeline("+", &settings);
+        assert_eq!(ar, plus);
+    }
+
+    #[test]
+    fn macros_expand_and_reject_missing_values() {
+        let ctx = MacroContext {
+            mycall: Some("dl1abc"),
+            call: Some("k1xyz"),
+            serial: 7,
+        };
+        assert_eq!(
+            expand_macros("{CALL} de {MYCALL} 5nn {serial}", &ctx).unwrap(),
+            "K1XYZ DE DL1ABC 5NN 007"
+        );
+        assert!(uses_serial("tu {Serial}"));
+
+        let no_call = MacroContext { call: None, ..ctx };
+        assert!(expand_macros("{CALL} TU", &no_call).is_err());
+        assert!(expand_macros("{FOO}", &ctx).is_err());
+        assert!(expand_macros("{CALL", &ctx).is_err());
+    }
+}
@@ -4,10 +4,16 @@
 
 pub mod audio;
 pub mod decode;
+pub mod doppler;
+pub mod dxspot;
 pub mod geo;
+pub mod keyer;
 pub mod math;
+pub mod metrics;
+pub mod mux;
 pub mod radio;
 pub mod rig;
+pub mod rotator;
 pub mod vchan;
 
 pub type DynResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
@@ -0,0 +1,476 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Health and performance metrics with Prometheus text exposition.
+//!
+//! A [`Registry`] owns named metric families.  Call sites fetch a handle
+//! ([`Counter`], [`Gauge`] or [`Histogram`]) once at startup and update it
+//! with relaxed atomics, so hot paths such as the SDR read loop never take a
+//! lock.  [`RigMetrics`] bundles the per-rig handles shared by the SDR
+//! pipeline, the rig task, the audio stack and the reporting uplinks.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Upper bounds (seconds) of the duration histogram buckets.  Spans one DSP
+/// block (~2 ms at 2 Msps) up to a slow CAT round trip.
+pub const DURATION_BUCKETS: [f64; 12] = [
+    0.0001, 0.00025, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 1.0,
+];
+
+/// Monotonically increasing counter.
+#[derive(Debug, Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    pub fn inc(&self) {
+        self.add(1);
+    }
+
+    pub fn add(&self, n: u64) {
+        self.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Value that can go up and down.
+#[derive(Debug, Default)]
+pub struct Gauge(AtomicI64);
+
+impl Gauge {
+    pub fn set(&self, value: i64) {
+        self.0.store(value, Ordering::Relaxed);
+    }
+
+    pub fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn dec(&self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Duration histogram over [`DURATION_BUCKETS`].
+#[derive(Debug, Default)]
+pub struct Histogram {
+    /// Per-bucket observation counts; the last slot is `+Inf`.
+    buckets: [AtomicU64; DURATION_BUCKETS.len() + 1],
+    count: AtomicU64,
+    sum_nanos: AtomicU64,
+}
+
+impl Histogram {
+    pub fn observe(&self, elapsed: Duration) {
+        let secs = elapsed.as_secs_f64();
+        let idx = DURATION_BUCKETS
+            .iter()
+            .position(|bound| secs <= *bound)
+            .unwrap_or(DURATION_BUCKETS.len());
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.sum_nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    /// Run `f` and record how long it took.
+    pub fn time<T>(&self, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = f();
+        self.observe(started.elapsed());
+        result
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+type LabelSet = Vec<(String, String)>;
+
+#[derive(Debug, Clone)]
+enum Series {
+    Counter(Arc<Counter>),
+    Gauge(Arc<Gauge>),
+    Histogram(Arc<Histogram>),
+}
+
+impl Series {
+    fn kind(&self) -> &'static str {
+        match self {
+            Series::Counter(_) => "counter",
+            Series::Gauge(_) => "gauge",
+            Series::Histogram(_) => "histogram",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Family {
+    help: &'static str,
+    kind: &'static str,
+    series: BTreeMap<LabelSet, Series>,
+}
+
+/// Collection of metric families rendered by the `/metrics` endpoint.
+#[derive(Debug, Default)]
+pub struct Registry {
+    families: Mutex<BTreeMap<&'static str, Family>>,
+}
+
+impl Registry {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Counter `name` with `labels`; repeated calls return the same handle.
+    pub fn counter(
+        &self,
+        name: &'static str,
+        help: &'static str,
+        labels: &[(&str, &str)],
+    ) -> Arc<Counter> {
+        match self.series(name, help, labels, || {
+            Series::Counter(Arc::new(Counter::default()))
+        }) {
+            Series::Counter(counter) => counter,
+            _ => unreachable!("kind checked by Registry::series"),
+        }
+    }
+
+    /// Gauge `name` with `labels`; repeated calls return the same handle.
+    pub fn gauge(
+        &self,
+        name: &'static str,
+        help: &'static str,
+        labels: &[(&str, &str)],
+    ) -> Arc<Gauge> {
+        match self.series(name, help, labels, || {
+            Series::Gauge(Arc::new(Gauge::default()))
+        }) {
+            Series::Gauge(gauge) => gauge,
+            _ => unreachable!("kind checked by Registry::series"),
+        }
+    }
+
+    /// Duration histogram `name` with `labels`; repeated calls return the
+    /// same handle.  The name should end in `_seconds`.
+    pub fn histogram(
+        &self,
+        name: &'static str,
+        help: &'static str,
+        labels: &[(&str, &str)],
+    ) -> Arc<Histogram> {
+        match self.series(name, help, labels, || {
+            Series::Histogram(Arc::new(Histogram::default()))
+        }) {
+            Series::Histogram(histogram) => histogram,
+            _ => unreachable!("kind checked by Registry::series"),
+        }
+    }
+
+    fn series(
+        &self,
+        name: &'static str,
+        help: &'static str,
+        labels: &[(&str, &str)],
+        make: impl FnOnce() -> Series,
+    ) -> Series {
+        let series = make();
+        let mut families = self.families.lock().unwrap_or_else(|e| e.into_inner());
+        let family = families.entry(name).or_insert_with(|| Family {
+            help,
+            kind: series.kind(),
+            series: BTreeMap::new(),
+        });
+        assert_eq!(
+            family.kind,
+            series.kind(),
+            "metric {name} registered with two different types"
+        );
+        let key = labels
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        family.series.entry(key).or_insert(series).clone()
+    }
+
+    /// Render every family in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let families = self.families.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::new();
+        for (name, family) in families.iter() {
+            let _ = writeln!(out, "# HELP {name} {}", family.help);
+            let _ = writeln!(out, "# TYPE {name} {}", family.kind);
+            for (labels, series) in &family.series {
+                match series {
+                    Series::Counter(c) => {
+                        let _ = writeln!(out, "{name}{} {}", format_labels(labels, None), c.get());
+                    }
+                    Series::Gauge(g) => {
+                        let _ = writeln!(out, "{name}{} {}", format_labels(labels, None), g.get());
+                    }
+                    Series::Histogram(h) => render_histogram(&mut out, name, labels, h),
+                }
+            }
+        }
+        out
+    }
+}
+
+fn render_histogram(out: &mut String, name: &str, labels: &LabelSet, histogram: &Histogram) {
+    let mut cumulative = 0u64;
+    for (idx, bucket) in histogram.buckets.iter().enumerate() {
+        cumulative += bucket.load(Ordering::Relaxed);
+        let le = DURATION_BUCKETS
+            .get(idx)
+            .map_or_else(|| "+Inf".to_string(), |bound| bound.to_string());
+        let _ = writeln!(
+            out,
+            "{name}_bucket{} {cumulative}",
+            format_labels(labels, Some(&le))
+        );
+    }
+    let sum = histogram.sum_nanos.load(Ordering::Relaxed) as f64 / 1e9;
+    let _ = writeln!(out, "{name}_sum{} {sum}", format_labels(labels, None));
+    let _ = writeln!(
+        out,
+        "{name}_count{} {}",
+        format_labels(labels, None),
+        histogram.count()
+    );
+}
+
+fn format_labels(labels: &LabelSet, le: Option<&str>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(k, v)| format!("{k}=\"{}\"", escape_label_value(v)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{le}\""));
+    }
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Metric handles for one reporting uplink.
+#[derive(Debug, Clone)]
+pub struct UplinkMetrics {
+    /// 1 while connected (or, for UDP uplinks, while the socket is open).
+    pub up: Arc<Gauge>,
+    /// Spots or packets delivered.
+    pub sent: Arc<Counter>,
+    /// Failed sends and connection attempts.
+    pub errors: Arc<Counter>,
+}
+
+/// Per-rig metric handles, labelled with `rig="<rig_id>"`.
+#[derive(Debug)]
+pub struct RigMetrics {
+    registry: Arc<Registry>,
+    rig_id: String,
+    /// Failed IQ source reads, including overflows.
+    pub iq_read_errors: Arc<Counter>,
+    /// IQ source reads that reported an overflow or overrun.
+    pub iq_overflows: Arc<Counter>,
+    /// Time spent running every DSP channel over one IQ block.
+    pub dsp_block_seconds: Arc<Histogram>,
+    /// Time spent Opus-encoding one RX audio frame.
+    pub opus_encode_seconds: Arc<Histogram>,
+    /// Round trip of one client command through the rig task.
+    pub cat_command_seconds: Arc<Histogram>,
+    /// Duration of one CAT state poll, including retries.
+    pub cat_poll_seconds: Arc<Histogram>,
+    /// CAT state reads retried after an error.
+    pub cat_retries: Arc<Counter>,
+    /// Clients connected to the rig's audio listener.
+    pub audio_clients: Arc<Gauge>,
+}
+
+impl RigMetrics {
+    pub fn new(registry: Arc<Registry>, rig_id: &str) -> Self {
+        let rig = [("rig", rig_id)];
+        Self {
+            iq_read_errors: registry.counter(
+                "trx_iq_read_errors_total",
+                "Failed IQ source reads, including overflows.",
+                &rig,
+            ),
+            iq_overflows: registry.counter(
+                "trx_iq_overflows_total",
+                "IQ source reads that reported an overflow or overrun.",
+                &rig,
+            ),
+            dsp_block_seconds: registry.histogram(
+                "trx_dsp_block_seconds",
+                "Time spent running all DSP channels over one IQ block.",
+                &rig,
+            ),
+            opus_encode_seconds: registry.histogram(
+                "trx_opus_encode_seconds",
+                "Time spent Opus-encoding one RX audio frame.",
+                &rig,
+            ),
+            cat_command_seconds: registry.histogram(
+                "trx_cat_command_seconds",
+                "Round trip of one client command through the rig task.",
+                &rig,
+            ),
+            cat_poll_seconds: registry.histogram(
+                "trx_cat_poll_seconds",
+                "Duration of one CAT state poll, including retries.",
+                &rig,
+            ),
+            cat_retries: registry.counter(
+                "trx_cat_retries_total",
+                "CAT state reads retried after an error.",
+                &rig,
+            ),
+            audio_clients: registry.gauge(
+                "trx_connected_clients",
+                "Clients currently connected, by link.",
+                &[("link", "audio"), ("rig", rig_id)],
+            ),
+            rig_id: rig_id.to_string(),
+            registry,
+        }
+    }
+
+    pub fn rig_id(&self) -> &str {
+        &self.rig_id
+    }
+
+    /// Decoded messages emitted by `decoder`.
+    pub fn decoded(&self, decoder: &str) -> Arc<Counter> {
+        self.registry.counter(
+            "trx_decoder_messages_total",
+            "Messages emitted by a decoder.",
+            &[("decoder", decoder), ("rig", &self.rig_id)],
+        )
+    }
+
+    /// PCM or IQ frames `decoder` dropped because it fell behind its input.
+    pub fn decoder_lag(&self, decoder: &str) -> Arc<Counter> {
+        self.registry.counter(
+            "trx_decoder_lagged_frames_total",
+            "Input frames a decoder dropped because it fell behind.",
+            &[("decoder", decoder), ("rig", &self.rig_id)],
+        )
+    }
+
+    /// Messages dropped by slow receivers of the `channel` broadcast.
+    pub fn broadcast_lag(&self, channel: &str) -> Arc<Counter> {
+        self.registry.counter(
+            "trx_broadcast_lagged_total",
+            "Messages dropped by slow receivers of a broadcast channel.",
+            &[("channel", channel), ("rig", &self.rig_id)],
+        )
+    }
+
+    /// Status handles for the `uplink` reporting task of this rig.
+    pub fn uplink(&self, uplink: &str) -> UplinkMetrics {
+        let labels = [("rig", self.rig_id.as_str()), ("uplink", uplink)];
+        UplinkMetrics {
+            up: self.registry.gauge(
+                "trx_uplink_up",
+                "1 while a reporting uplink is connected.",
+                &labels,
+            ),
+            sent: self.registry.counter(
+                "trx_uplink_sent_total",
+                "Spots or packets delivered by a reporting uplink.",
+                &labels,
+            ),
+            errors: self.registry.counter(
+                "trx_uplink_errors_total",
+                "Failed sends and connection attempts of a reporting uplink.",
+                &labels,
+            ),
+        }
+    }
+}
+
+impl Default for RigMetrics {
+    /// Handles backed by a private registry that is never exported; used
+    /// where no `/metrics` endpoint is wired up (tests, ad-hoc backends).
+    fn default() -> Self {
+        Self::new(Registry::new(), "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_are_shared_per_label_set() {
+        let registry = Registry::new();
+        let a = registry.counter("trx_test_total", "Test.", &[("rig", "hf")]);
+        let b = registry.counter("trx_test_total", "Test.", &[("rig", "hf")]);
+        let c = registry.counter("trx_test_total", "Test.", &[("rig", "vhf")]);
+        a.inc();
+        b.add(2);
+        assert_eq!(a.get(), 3);
+        assert_eq!(c.get(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "two different types")]
+    fn rejects_type_conflicts() {
+        let registry = Registry::new();
+        registry.counter("trx_test", "Test.", &[]);
+        registry.gauge("trx_test", "Test.", &[]);
+    }
+
+    #[test]
+    fn renders_text_exposition() {
+        let registry = Registry::new();
+        let metrics = RigMetrics::new(registry.clone(), "hf \"1\"");
+        metrics.iq_overflows.add(4);
+        metrics.audio_clients.inc();
+        metrics
+            .dsp_block_seconds
+            .observe(Duration::from_micros(800));
+        metrics.dsp_block_seconds.observe(Duration::from_secs(2));
+        metrics.decoded("ft8").inc();
+
+        let text = registry.render();
+        assert!(text.contains("# TYPE trx_iq_overflows_total counter\n"));
+        assert!(text.contains("trx_iq_overflows_total{rig=\"hf \\\"1\\\"\"} 4\n"));
+        assert!(text.contains("trx_connected_clients{link=\"audio\",rig=\"hf \\\"1\\\"\"} 1\n"));
+        assert!(
+            text.contains("trx_decoder_messages_total{decoder=\"ft8\",rig=\"hf \\\"1\\\"\"} 1\n")
+        );
+        assert!(text.contains("# TYPE trx_dsp_block_seconds histogram\n"));
+        assert!(
+            text.contains("trx_dsp_block_seconds_bucket{rig=\"hf \\\"1\\\"\",le=\"0.0005\"} 0\n")
+        );
+        assert!(
+            text.contains("trx_dsp_block_seconds_bucket{rig=\"hf \\\"1\\\"\",le=\"0.001\"} 1\n")
+        );
+        assert!(text.contains("trx_dsp_block_seconds_bucket{rig=\"hf \\\"1\\\"\",le=\"+Inf\"} 2\n"));
+        assert!(text.contains("trx_dsp_block_seconds_sum{rig=\"hf \\\"1\\\"\"} 2.0008\n"));
+        assert!(text.contains("trx_dsp_block_seconds_count{rig=\"hf \\\"1\\\"\"} 2\n"));
+    }
+}
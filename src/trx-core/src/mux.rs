@@ -0,0 +1,874 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Multiplexed client connections.
+//!
+//! One transport carries many logical byte streams, so a client needs a
+//! single authenticated connection for control requests, state pushes,
+//! meters, spectrum, decodes and audio.  Every frame uses the
+//! `[type][len][payload]` framing of [`crate::audio`] with the
+//! `AUDIO_MSG_MUX_*` types; stream frames start with a 4-byte stream id.
+//! Clients open odd ids, servers even ones.
+//!
+//! Each stream is flow controlled on its own: the receiver grants send
+//! credit with `AUDIO_MSG_MUX_WINDOW` as the application consumes data, so
+//! a stalled spectrum reader never holds up audio.  Outgoing frames are
+//! queued per [`MuxPriority`] and the writer always drains the highest
+//! priority first.  Data is cut into [`MAX_DATA_CHUNK`] pieces, so a large
+//! history blob delays control traffic by one chunk at most.
+//!
+//! A server that cannot accept inbound connections dials out to the client
+//! instead and introduces itself with an [`UplinkHello`].  From then on the
+//! roles are unchanged: the client opens the session and its streams.
+
+use std::collections::HashMap;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadBuf};
+use tokio::sync::{mpsc, watch};
+
+use crate::audio::{
+    read_audio_msg, write_audio_msg, write_audio_msg_buffered, AUDIO_MSG_MUX_CLOSE,
+    AUDIO_MSG_MUX_DATA, AUDIO_MSG_MUX_HELLO, AUDIO_MSG_MUX_OPEN, AUDIO_MSG_MUX_WINDOW,
+    AUDIO_MSG_UPLINK_HELLO,
+};
+
+/// Protocol version exchanged in the handshake.
+pub const MUX_VERSION: u8 = 1;
+/// Send credit every stream starts with, in bytes.
+pub const INITIAL_WINDOW: u32 = 256 * 1024;
+/// Largest data frame the writer emits.
+pub const MAX_DATA_CHUNK: usize = 16 * 1024;
+/// Streams a peer may have open at once.
+pub const MAX_STREAMS: usize = 256;
+/// Stream opens waiting for the acceptor before new ones are refused.
+const ACCEPT_BACKLOG: usize = 16;
+
+/// First frame from the client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MuxHello {
+    pub version: u8,
+    /// Authorization token, checked before any stream is opened.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl MuxHello {
+    pub fn new(token: Option<String>) -> Self {
+        Self {
+            version: MUX_VERSION,
+            token,
+        }
+    }
+}
+
+/// Server answer to [`MuxHello`]; `error` set means the session is refused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MuxHelloAck {
+    pub version: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// First frame on a connection the server dialled out to a client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UplinkHello {
+    pub version: u8,
+    /// Name the client knows the server by.
+    pub station: String,
+    /// Token the client checks before it uses the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl UplinkHello {
+    pub fn new(station: String, token: Option<String>) -> Self {
+        Self {
+            version: MUX_VERSION,
+            station,
+            token,
+        }
+    }
+
+    pub async fn write<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> io::Result<()> {
+        let json = serde_json::to_vec(self).map_err(io::Error::other)?;
+        write_audio_msg(writer, AUDIO_MSG_UPLINK_HELLO, &json).await
+    }
+
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> io::Result<Self> {
+        let (msg_type, payload) = read_audio_msg(reader).await?;
+        if msg_type != AUDIO_MSG_UPLINK_HELLO {
+            return Err(invalid_data(format!(
+                "expected uplink hello, got frame type {msg_type:#04x}"
+            )));
+        }
+        let hello: Self = serde_json::from_slice(&payload)
+            .map_err(|e| invalid_data(format!("bad uplink hello: {e}")))?;
+        if hello.version != MUX_VERSION {
+            return Err(invalid_data(format!(
+                "unsupported uplink version {}",
+                hello.version
+            )));
+        }
+        Ok(hello)
+    }
+}
+
+/// What a stream carries; the server hands it to the matching handler.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MuxStreamKind {
+    /// JSON control protocol, exactly as on the control port.
+    Control,
+    /// Audio protocol of one rig, exactly as on its audio port.  `None`
+    /// selects the server's default rig.
+    Audio {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rig_id: Option<String>,
+    },
+}
+
+/// Scheduling class of a stream's outgoing frames.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MuxPriority {
+    /// Commands, state pushes and meters.
+    High,
+    /// Audio and decodes.
+    #[default]
+    Normal,
+    /// Spectrum and other bulk transfers.
+    Low,
+}
+
+/// Payload of `AUDIO_MSG_MUX_OPEN`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MuxOpen {
+    pub stream: MuxStreamKind,
+    #[serde(default)]
+    pub priority: MuxPriority,
+}
+
+impl MuxOpen {
+    pub fn control(priority: MuxPriority) -> Self {
+        Self {
+            stream: MuxStreamKind::Control,
+            priority,
+        }
+    }
+
+    pub fn audio(rig_id: Option<String>) -> Self {
+        Self {
+            stream: MuxStreamKind::Audio { rig_id },
+            priority: MuxPriority::Normal,
+        }
+    }
+}
+
+type Frame = (u8, Vec<u8>);
+
+/// Send credit of one stream, shared between the stream and the reader task
+/// that receives the peer's window updates.
+#[derive(Debug)]
+struct SendWindow {
+    inner: Mutex<SendWindowInner>,
+}
+
+#[derive(Debug)]
+struct SendWindowInner {
+    credit: u32,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl SendWindow {
+    fn new() -> Self {
+        Self {
+            inner: Mutex::new(SendWindowInner {
+                credit: INITIAL_WINDOW,
+                closed: false,
+                waker: None,
+            }),
+        }
+    }
+
+    /// Take up to `want` bytes of credit, waiting while none is left.
+    fn reserve(&self, cx: &mut Context<'_>, want: usize) -> Poll<io::Result<usize>> {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if inner.closed {
+            return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+        }
+        if inner.credit == 0 {
+            inner.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let n = want.min(inner.credit as usize);
+        inner.credit -= n as u32;
+        Poll::Ready(Ok(n))
+    }
+
+    fn grant(&self, bytes: u32) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.credit = inner.credit.saturating_add(bytes);
+        if let Some(waker) = inner.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn close(&self) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.closed = true;
+        if let Some(waker) = inner.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct StreamEntry {
+    /// Bytes the peer may still send before it needs more credit.
+    recv_window: u32,
+    incoming: mpsc::UnboundedSender<Vec<u8>>,
+    send: Arc<SendWindow>,
+}
+
+#[derive(Debug)]
+struct Session {
+    /// Outgoing frames, indexed by [`MuxPriority`].
+    queues: [mpsc::UnboundedSender<Frame>; 3],
+    streams: Mutex<HashMap<u32, StreamEntry>>,
+    next_id: AtomicU32,
+    /// Parity of the ids the peer opens.
+    peer_parity: u32,
+    closed: watch::Sender<bool>,
+}
+
+impl Session {
+    fn send(&self, priority: MuxPriority, frame: Frame) -> io::Result<()> {
+        self.queues[priority as usize]
+            .send(frame)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "mux session closed"))
+    }
+
+    fn register(self: &Arc<Self>, id: u32, priority: MuxPriority) -> io::Result<MuxStream> {
+        let (incoming_tx, incoming_rx) = mpsc::unbounded_channel();
+        let send = Arc::new(SendWindow::new());
+        let mut streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        if streams.contains_key(&id) {
+            return Err(invalid_data(format!("mux stream {id} already open")));
+        }
+        streams.insert(
+            id,
+            StreamEntry {
+                recv_window: INITIAL_WINDOW,
+                incoming: incoming_tx,
+                send: send.clone(),
+            },
+        );
+        Ok(MuxStream {
+            id,
+            priority,
+            session: self.clone(),
+            incoming: incoming_rx,
+            chunk: Vec::new(),
+            pos: 0,
+            consumed: 0,
+            send,
+        })
+    }
+
+    /// Return `bytes` of receive credit on `id` to the peer.
+    fn grant(&self, id: u32, bytes: u32) {
+        let mut streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = streams.get_mut(&id) {
+            entry.recv_window = entry.recv_window.saturating_add(bytes);
+            let mut payload = id.to_be_bytes().to_vec();
+            payload.extend_from_slice(&bytes.to_be_bytes());
+            let _ = self.send(MuxPriority::High, (AUDIO_MSG_MUX_WINDOW, payload));
+        }
+    }
+
+    fn handle_frame(
+        self: &Arc<Self>,
+        msg_type: u8,
+        payload: &[u8],
+        accept_tx: Option<&mpsc::Sender<(MuxOpen, MuxStream)>>,
+    ) -> io::Result<()> {
+        if payload.len() < 4 {
+            return Err(invalid_data("mux frame without stream id"));
+        }
+        let id = u32::from_be_bytes(payload[..4].try_into().unwrap());
+        let body = &payload[4..];
+        match msg_type {
+            AUDIO_MSG_MUX_OPEN => {
+                if id % 2 != self.peer_parity {
+                    return Err(invalid_data(format!(
+                        "peer opened stream {id} from our range"
+                    )));
+                }
+                let open: MuxOpen = serde_json::from_slice(body)
+                    .map_err(|e| invalid_data(format!("bad mux open: {e}")))?;
+                let open_count = self.streams.lock().unwrap_or_else(|e| e.into_inner()).len();
+                match accept_tx {
+                    Some(tx) if open_count < MAX_STREAMS => {
+                        let stream = self.register(id, open.priority)?;
+                        // A refused stream is dropped here, which closes it.
+                        let _ = tx.try_send((open, stream));
+                    }
+                    _ => {
+                        let _ = self.send(MuxPriority::High, close_frame(id));
+                    }
+                }
+            }
+            AUDIO_MSG_MUX_DATA => {
+                let mut streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(entry) = streams.get_mut(&id) {
+                    let len = body.len() as u32;
+                    if len > entry.recv_window {
+                        return Err(invalid_data(format!(
+                            "peer overran the window of stream {id}"
+                        )));
+                    }
+                    entry.recv_window -= len;
+                    let _ = entry.incoming.send(body.to_vec());
+                }
+            }
+            AUDIO_MSG_MUX_WINDOW => {
+                if body.len() < 4 {
+                    return Err(invalid_data("mux window frame too short"));
+                }
+                let bytes = u32::from_be_bytes(body[..4].try_into().unwrap());
+                let streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(entry) = streams.get(&id) {
+                    entry.send.grant(bytes);
+                }
+            }
+            AUDIO_MSG_MUX_CLOSE => {
+                let entry = self
+                    .streams
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(&id);
+                // Dropping the entry ends the stream's reads.
+                if let Some(entry) = entry {
+                    entry.send.close();
+                }
+            }
+            other => {
+                return Err(invalid_data(format!(
+                    "unexpected frame type {other:#04x} on mux session"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// End the session: every stream sees EOF and failing writes.
+    fn shutdown(&self) {
+        self.closed.send_replace(true);
+        let streams: Vec<StreamEntry> = self
+            .streams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .drain()
+            .map(|(_, entry)| entry)
+            .collect();
+        for entry in streams {
+            entry.send.close();
+        }
+    }
+}
+
+fn close_frame(id: u32) -> Frame {
+    (AUDIO_MSG_MUX_CLOSE, id.to_be_bytes().to_vec())
+}
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+/// Next queued frame, highest priority first.
+fn next_frame(queues: &mut [mpsc::UnboundedReceiver<Frame>; 3]) -> Option<Frame> {
+    queues.iter_mut().find_map(|queue| queue.try_recv().ok())
+}
+
+async fn write_loop<W>(
+    mut writer: W,
+    mut queues: [mpsc::UnboundedReceiver<Frame>; 3],
+    session: Arc<Session>,
+) where
+    W: AsyncWrite + Unpin,
+{
+    let mut closed = session.closed.subscribe();
+    loop {
+        let frame = match next_frame(&mut queues) {
+            Some(frame) => frame,
+            None => {
+                if writer.flush().await.is_err() {
+                    break;
+                }
+                let [high, normal, low] = &mut queues;
+                tokio::select! {
+                    biased;
+                    _ = closed.wait_for(|closed| *closed) => break,
+                    Some(frame) = high.recv() => frame,
+                    Some(frame) = normal.recv() => frame,
+                    Some(frame) = low.recv() => frame,
+                    else => break,
+                }
+            }
+        };
+        if write_audio_msg_buffered(&mut writer, frame.0, &frame.1)
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+    session.shutdown();
+    let _ = writer.shutdown().await;
+}
+
+async fn read_loop<R>(
+    mut reader: R,
+    session: Arc<Session>,
+    accept_tx: Option<mpsc::Sender<(MuxOpen, MuxStream)>>,
+) where
+    R: AsyncRead + Unpin,
+{
+    let mut closed = session.closed.subscribe();
+    loop {
+        let (msg_type, payload) = tokio::select! {
+            _ = closed.wait_for(|closed| *closed) => break,
+            msg = read_audio_msg(&mut reader) => match msg {
+                Ok(msg) => msg,
+                Err(e) => {
+                    if e.kind() != io::ErrorKind::UnexpectedEof {
+                        tracing::debug!("Mux session read failed: {}", e);
+                    }
+                    break;
+                }
+            },
+        };
+        if let Err(e) = session.handle_frame(msg_type, &payload, accept_tx.as_ref()) {
+            tracing::warn!("Mux session protocol error: {}", e);
+            break;
+        }
+    }
+    session.shutdown();
+}
+
+/// Handle to a multiplexed session.  Clones share the session; it ends when
+/// the peer disconnects or [`Mux::close`] is called.
+#[derive(Debug, Clone)]
+pub struct Mux {
+    session: Arc<Session>,
+}
+
+impl Mux {
+    /// Client side: send `hello` and wait for the server to accept it.
+    pub async fn connect<R, W>(mut reader: R, mut writer: W, hello: &MuxHello) -> io::Result<Self>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
+        let json = serde_json::to_vec(hello).map_err(io::Error::other)?;
+        write_audio_msg(&mut writer, AUDIO_MSG_MUX_HELLO, &json).await?;
+        let (msg_type, payload) = read_audio_msg(&mut reader).await?;
+        if msg_type != AUDIO_MSG_MUX_HELLO {
+            return Err(invalid_data(format!(
+                "expected mux hello, got frame type {msg_type:#04x}"
+            )));
+        }
+        let ack: MuxHelloAck = serde_json::from_slice(&payload)
+            .map_err(|e| invalid_data(format!("bad mux hello: {e}")))?;
+        if let Some(error) = ack.error {
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, error));
+        }
+        if ack.version != MUX_VERSION {
+            return Err(invalid_data(format!(
+                "unsupported mux version {}",
+                ack.version
+            )));
+        }
+        Ok(Self::start(reader, writer, 1, None))
+    }
+
+    /// Server side: read the client's hello, answer it and start the
+    /// session when `authorize` accepts it.  Streams opened by the client
+    /// arrive on the returned receiver.
+    pub async fn accept<R, W, F>(
+        mut reader: R,
+        mut writer: W,
+        authorize: F,
+    ) -> io::Result<(Self, MuxHello, mpsc::Receiver<(MuxOpen, MuxStream)>)>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        W: AsyncWrite + Unpin + Send + 'static,
+        F: FnOnce(&MuxHello) -> Result<(), String>,
+    {
+        let (msg_type, payload) = read_audio_msg(&mut reader).await?;
+        if msg_type != AUDIO_MSG_MUX_HELLO {
+            return Err(invalid_data(format!(
+                "expected mux hello, got frame type {msg_type:#04x}"
+            )));
+        }
+        let hello: MuxHello = serde_json::from_slice(&payload)
+            .map_err(|e| invalid_data(format!("bad mux hello: {e}")))?;
+        let verdict = if hello.version == MUX_VERSION {
+            authorize(&hello)
+        } else {
+            Err(format!("unsupported mux version {}", hello.version))
+        };
+        let ack = MuxHelloAck {
+            version: MUX_VERSION,
+            error: verdict.as_ref().err().cloned(),
+        };
+        let json = serde_json::to_vec(&ack).map_err(io::Error::other)?;
+        write_audio_msg(&mut writer, AUDIO_MSG_MUX_HELLO, &json).await?;
+        if let Err(error) = verdict {
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, error));
+        }
+        let (accept_tx, accept_rx) = mpsc::channel(ACCEPT_BACKLOG);
+        Ok((
+            Self::start(reader, writer, 2, Some(accept_tx)),
+            hello,
+            accept_rx,
+        ))
+    }
+
+    fn start<R, W>(
+        reader: R,
+        writer: W,
+        first_id: u32,
+        accept_tx: Option<mpsc::Sender<(MuxOpen, MuxStream)>>,
+    ) -> Self
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
+        let (high_tx, high_rx) = mpsc::unbounded_channel();
+        let (normal_tx, normal_rx) = mpsc::unbounded_channel();
+        let (low_tx, low_rx) = mpsc::unbounded_channel();
+        let session = Arc::new(Session {
+            queues: [high_tx, normal_tx, low_tx],
+            streams: Mutex::new(HashMap::new()),
+            next_id: AtomicU32::new(first_id),
+            peer_parity: (first_id + 1) % 2,
+            closed: watch::channel(false).0,
+        });
+        tokio::spawn(write_loop(
+            writer,
+            [high_rx, normal_rx, low_rx],
+            session.clone(),
+        ));
+        tokio::spawn(read_loop(reader, session.clone(), accept_tx));
+        Self { session }
+    }
+
+    /// Open a new stream.  The peer refuses it by closing it, which shows up
+    /// as EOF on the first read.
+    pub fn open(&self, open: &MuxOpen) -> io::Result<MuxStream> {
+        if self.is_closed() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "mux session closed",
+            ));
+        }
+        let id = self.session.next_id.fetch_add(2, Ordering::Relaxed);
+        let stream = self.session.register(id, open.priority)?;
+        let mut payload = id.to_be_bytes().to_vec();
+        payload.extend(serde_json::to_vec(open).map_err(io::Error::other)?);
+        self.session
+            .send(MuxPriority::High, (AUDIO_MSG_MUX_OPEN, payload))?;
+        Ok(stream)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        *self.session.closed.borrow()
+    }
+
+    /// Wait until the session has ended.
+    pub async fn closed(&self) {
+        let mut closed = self.session.closed.subscribe();
+        let _ = closed.wait_for(|closed| *closed).await;
+    }
+
+    /// End the session and every stream on it.
+    pub fn close(&self) {
+        self.session.shutdown();
+    }
+}
+
+/// One logical stream of a [`Mux`] session.  Dropping it closes the stream
+/// on both ends.
+#[derive(Debug)]
+pub struct MuxStream {
+    id: u32,
+    priority: MuxPriority,
+    session: Arc<Session>,
+    incoming: mpsc::UnboundedReceiver<Vec<u8>>,
+    chunk: Vec<u8>,
+    pos: usize,
+    /// Bytes read since the last window update.
+    consumed: u32,
+    send: Arc<SendWindow>,
+}
+
+impl MuxStream {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn priority(&self) -> MuxPriority {
+        self.priority
+    }
+}
+
+impl AsyncRead for MuxStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        while this.pos >= this.chunk.len() {
+            match this.incoming.poll_recv(cx) {
+                Poll::Ready(Some(chunk)) => {
+                    this.chunk = chunk;
+                    this.pos = 0;
+                }
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        let n = buf.remaining().min(this.chunk.len() - this.pos);
+        buf.put_slice(&this.chunk[this.pos..this.pos + n]);
+        this.pos += n;
+        this.consumed += n as u32;
+        if this.consumed >= INITIAL_WINDOW / 2 {
+            this.session.grant(this.id, this.consumed);
+            this.consumed = 0;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for MuxStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let n = match self.send.reserve(cx, buf.len().min(MAX_DATA_CHUNK)) {
+            Poll::Ready(Ok(n)) => n,
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        };
+        let mut payload = Vec::with_capacity(4 + n);
+        payload.extend_from_slice(&self.id.to_be_bytes());
+        payload.extend_from_slice(&buf[..n]);
+        self.session
+            .send(self.priority, (AUDIO_MSG_MUX_DATA, payload))?;
+        Poll::Ready(Ok(n))
+    }
+
+    /// Frames are handed to the session writer as they are written, and it
+    /// flushes whenever its queues run empty.
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    /// Streams close when dropped; a half-close has no meaning here.
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for MuxStream {
+    fn drop(&mut self) {
+        let entry = self
+            .session
+            .streams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.id);
+        // The close frame shares the stream's queue so it follows its data.
+        if entry.is_some() {
+            let _ = self.session.send(self.priority, close_frame(self.id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use tokio::io::{AsyncReadExt, DuplexStream};
+    use tokio::time::timeout;
+
+    async fn pair(
+        authorize: fn(&MuxHello) -> Result<(), String>,
+    ) -> (
+        io::Result<Mux>,
+        io::Result<(Mux, MuxHello, mpsc::Receiver<(MuxOpen, MuxStream)>)>,
+    ) {
+        let (client_io, server_io): (DuplexStream, DuplexStream) = tokio::io::duplex(64 * 1024);
+        let (client_r, client_w) = tokio::io::split(client_io);
+        let (server_r, server_w) = tokio::io::split(server_io);
+        let server = tokio::spawn(Mux::accept(server_r, server_w, authorize));
+        let client = Mux::connect(client_r, client_w, &MuxHello::new(Some("t".into()))).await;
+        (client, server.await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn streams_carry_data_both_ways() {
+        let (client, server) = pair(|_| Ok(())).await;
+        let client = client.unwrap();
+        let (_server, hello, mut incoming) = server.unwrap();
+        assert_eq!(hello.token.as_deref(), Some("t"));
+
+        let mut control = client.open(&MuxOpen::control(MuxPriority::High)).unwrap();
+        let mut audio = client.open(&MuxOpen::audio(Some("hf".into()))).unwrap();
+        control.write_all(b"ping\n").await.unwrap();
+        audio.write_all(b"opus").await.unwrap();
+
+        let (open, mut server_control) = incoming.recv().await.unwrap();
+        assert_eq!(open, MuxOpen::control(MuxPriority::High));
+        let (open, mut server_audio) = incoming.recv().await.unwrap();
+        assert_eq!(
+            open.stream,
+            MuxStreamKind::Audio {
+                rig_id: Some("hf".into())
+            }
+        );
+
+        let mut buf = [0u8; 5];
+        server_control.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"ping\n");
+        let mut buf = [0u8; 4];
+        server_audio.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"opus");
+
+        server_control.write_all(b"pong\n").await.unwrap();
+        let mut buf = [0u8; 5];
+        control.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"pong\n");
+
+        // Dropping one end closes the stream on the other.
+        drop(server_audio);
+        let mut rest = Vec::new();
+        audio.read_to_end(&mut rest).await.unwrap();
+        assert!(rest.is_empty());
+        assert!(audio.write_all(b"more").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn stalled_stream_does_not_block_others() {
+        let (client, server) = pair(|_| Ok(())).await;
+        let client = client.unwrap();
+        let (_server, _, mut incoming) = server.unwrap();
+
+        let mut bulk = client.open(&MuxOpen::control(MuxPriority::Low)).unwrap();
+        let mut control = client.open(&MuxOpen::control(MuxPriority::High)).unwrap();
+        let (_, mut server_bulk) = incoming.recv().await.unwrap();
+        let (_, mut server_control) = incoming.recv().await.unwrap();
+
+        // Nobody reads the bulk stream, so its writer runs out of credit.
+        let block = vec![0u8; INITIAL_WINDOW as usize];
+        bulk.write_all(&block).await.unwrap();
+        assert!(timeout(Duration::from_millis(100), bulk.write_all(b"x"))
+            .await
+            .is_err());
+
+        control.write_all(b"still here").await.unwrap();
+        let mut buf = [0u8; 10];
+        server_control.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"still here");
+
+        // Reading the bulk stream returns credit and unblocks its writer.
+        let mut drained = vec![0u8; INITIAL_WINDOW as usize];
+        server_bulk.read_exact(&mut drained).await.unwrap();
+        timeout(Duration::from_secs(1), bulk.write_all(b"x"))
+            .await
+            .expect("window update")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn refused_hello_fails_both_sides() {
+        let (client, server) = pair(|_| Err("invalid authorization token".into())).await;
+        let err = client.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(err.to_string(), "invalid authorization token");
+        assert!(server.is_err());
+    }
+
+    #[tokio::test]
+    async fn closing_the_session_ends_every_stream() {
+        let (client, server) = pair(|_| Ok(())).await;
+        let client = client.unwrap();
+        let (server, _, mut incoming) = server.unwrap();
+        let mut stream = client.open(&MuxOpen::audio(None)).unwrap();
+        let (_, _server_stream) = incoming.recv().await.unwrap();
+
+        server.close();
+        timeout(Duration::from_secs(1), client.closed())
+            .await
+            .expect("client notices the closed session");
+        let mut rest = Vec::new();
+        stream.read_to_end(&mut rest).await.unwrap();
+        assert!(client.open(&MuxOpen::audio(None)).is_err());
+    }
+
+    #[tokio::test]
+    async fn server_dialled_connection_carries_a_session() {
+        let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+        let (mut client_r, client_w) = tokio::io::split(client_io);
+        let (server_r, mut server_w) = tokio::io::split(server_io);
+
+        let hello = UplinkHello::new("field".into(), Some("s".into()));
+        hello.write(&mut server_w).await.unwrap();
+        let server = tokio::spawn(Mux::accept(server_r, server_w, |_| Ok(())));
+
+        assert_eq!(UplinkHello::read(&mut client_r).await.unwrap(), hello);
+        let client = Mux::connect(client_r, client_w, &MuxHello::new(None))
+            .await
+            .unwrap();
+        let (_server, _, mut incoming) = server.await.unwrap().unwrap();
+        let mut stream = client.open(&MuxOpen::control(MuxPriority::High)).unwrap();
+        stream.write_all(b"hi").await.unwrap();
+        let (_, mut server_stream) = incoming.recv().await.unwrap();
+        let mut buf = [0u8; 2];
+        server_stream.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"hi");
+    }
+
+    #[test]
+    fn writer_drains_higher_priorities_first() {
+        let (high_tx, high_rx) = mpsc::unbounded_channel();
+        let (normal_tx, normal_rx) = mpsc::unbounded_channel();
+        let (low_tx, low_rx) = mpsc::unbounded_channel();
+        let mut queues = [high_rx, normal_rx, low_rx];
+        low_tx.send((AUDIO_MSG_MUX_DATA, vec![3])).unwrap();
+        normal_tx.send((AUDIO_MSG_MUX_DATA, vec![2])).unwrap();
+        high_tx.send((AUDIO_MSG_MUX_DATA, vec![1])).unwrap();
+        let order: Vec<u8> = std::iter::from_fn(|| next_frame(&mut queues))
+            .map(|(_, payload)| payload[0])
+            .collect();
+        assert_eq!(order, vec![1, 2, 3]);
+    }
+}
@@ -2,7 +2,9 @@
 //
 // SPDX-License-Identifier: BSD-2-Clause
 
+use crate::decode::PskMode;
 use crate::radio::freq::Freq;
+use crate::rig::memory::RigMemory;
 use crate::rig::state::WfmDenoiseLevel;
 use crate::RigMode;
 
@@ -27,6 +29,14 @@ pub enum RigCommand {
     SetCwAuto(bool),
     SetCwWpm(u32),
     SetCwToneHz(u32),
+    SetPskDecodeEnabled(bool),
+    SetPskMode(PskMode),
+    SetPskFreqHz(u32),
+    SetPskWaterfall(bool),
+    SetDscDecodeEnabled(bool),
+    SetPocsagDecodeEnabled(bool),
+    SetAdsbDecodeEnabled(bool),
+    SetAcarsDecodeEnabled(bool),
     SetFt8DecodeEnabled(bool),
     SetFt4DecodeEnabled(bool),
     SetFt2DecodeEnabled(bool),
@@ -36,6 +46,11 @@ pub enum RigCommand {
     ResetAprsDecoder,
     ResetHfAprsDecoder,
     ResetCwDecoder,
+    ResetPskDecoder,
+    ResetDscDecoder,
+    ResetPocsagDecoder,
+    ResetAdsbDecoder,
+    ResetAcarsDecoder,
     ResetFt8Decoder,
     ResetFt4Decoder,
     ResetFt2Decoder,
@@ -46,13 +61,82 @@ pub enum RigCommand {
     SetSdrGain(f64),
     SetSdrLnaGain(f64),
     SetSdrAgc(bool),
-    SetSdrSquelch { enabled: bool, threshold_db: f64 },
-    SetSdrNoiseBlanker { enabled: bool, threshold: f64 },
+    SetSdrSquelch {
+        enabled: bool,
+        threshold_db: f64,
+    },
+    SetSdrNoiseBlanker {
+        enabled: bool,
+        threshold: f64,
+    },
     SetWfmDeemphasis(u32),
     SetWfmStereo(bool),
     SetWfmDenoise(WfmDenoiseLevel),
     SetSamStereoWidth(f32),
     SetSamCarrierSync(bool),
     SetRecorderEnabled(bool),
+    /// Follow a satellite, correcting the rig for Doppler shift.
+    StartSatTracking {
+        norad_id: u32,
+        transponder: Option<String>,
+        downlink_hz: Option<u64>,
+    },
+    StopSatTracking,
+    /// Turn the antenna rotator to a fixed position.
+    SetRotatorPosition {
+        azimuth_deg: f64,
+        elevation_deg: f64,
+    },
+    StopRotator,
+    /// Keep the antenna rotator pointed at a satellite.
+    StartRotatorTracking {
+        norad_id: u32,
+    },
+    StopRotatorTracking,
+    /// Key a CW message; `{MYCALL}`, `{CALL}` and `{SERIAL}` are expanded
+    /// on the server, `call` supplies the `{CALL}` value.
+    SendCw {
+        text: String,
+        call: Option<String>,
+    },
+    /// Stop sending immediately and drop PTT.
+    AbortCw,
+    SetCwKeyer {
+        wpm: u32,
+        weight: u32,
+        farnsworth_wpm: Option<u32>,
+    },
+    /// Set the next serial number for the `{SERIAL}` macro.
+    SetCwSerial(u32),
+    /// Read up to `count` memory channels starting at `first` into the
+    /// published state; `count` is capped at `MEMORY_PAGE_SIZE`.
+    ReadMemories {
+        first: u16,
+        count: u16,
+    },
+    WriteMemory(RigMemory),
+    RecallMemory(u16),
+    /// Recall the band-stack register of the band containing `freq_hz`.
+    RecallBandStack {
+        freq_hz: u64,
+    },
+    /// Repeater transmit offset in Hz; negative for a minus shift, 0 for
+    /// simplex.
+    SetRepeaterOffset(i32),
+    /// Swap to the repeater input: tune by the offset and invert it.
+    SetRepeaterReverse(bool),
+    /// CTCSS tone, `None` for off; `decode` also enables tone squelch.
+    SetCtcss {
+        tone_hz: Option<f32>,
+        decode: bool,
+    },
+    /// DCS code, `None` for off.
+    SetDcs(Option<u16>),
+    /// RIT offset in Hz; 0 turns RIT off.  RIT and XIT share one offset.
+    SetRit(i32),
+    /// XIT offset in Hz; 0 turns XIT off.
+    SetXit(i32),
+    /// Merge TLE text into the server's satellite catalogue.
+    LoadTles(String),
     GetSpectrum,
 }
@@ -446,6 +446,14 @@ pub fn command_from_rig_command(cmd: RigCommand) -> Box<dyn RigCommandHandler> {
         | RigCommand::SetCwAuto(_)
         | RigCommand::SetCwWpm(_)
         | RigCommand::SetCwToneHz(_)
+        | RigCommand::SetPskDecodeEnabled(_)
+        | RigCommand::SetPskMode(_)
+        | RigCommand::SetPskFreqHz(_)
+        | RigCommand::SetPskWaterfall(_)
+        | RigCommand::SetDscDecodeEnabled(_)
+        | RigCommand::SetPocsagDecodeEnabled(_)
+        | RigCommand::SetAdsbDecodeEnabled(_)
+        | RigCommand::SetAcarsDecodeEnabled(_)
         | RigCommand::SetFt8DecodeEnabled(_)
         | RigCommand::SetFt4DecodeEnabled(_)
         | RigCommand::SetFt2DecodeEnabled(_)
@@ -454,6 +462,11 @@ pub fn command_from_rig_command(cmd: RigCommand) -> Box<dyn RigCommandHandler> {
         | RigCommand::ResetHfAprsDecoder
         | RigCommand::ResetAprsDecoder
         | RigCommand::ResetCwDecoder
+        | RigCommand::ResetPskDecoder
+        | RigCommand::ResetDscDecoder
+        | RigCommand::ResetPocsagDecoder
+        | RigCommand::ResetAdsbDecoder
+        | RigCommand::ResetAcarsDecoder
         | RigCommand::ResetFt8Decoder
         | RigCommand::ResetFt4Decoder
         | RigCommand::ResetFt2Decoder
@@ -474,6 +487,27 @@ pub fn command_from_rig_command(cmd: RigCommand) -> Box<dyn RigCommandHandler> {
         | RigCommand::SetSamStereoWidth(_)
         | RigCommand::SetSamCarrierSync(_)
         | RigCommand::SetRecorderEnabled(_)
+        | RigCommand::StartSatTracking { .. }
+        | RigCommand::StopSatTracking
+        | RigCommand::SetRotatorPosition { .. }
+        | RigCommand::StopRotator
+        | RigCommand::StartRotatorTracking { .. }
+        | RigCommand::StopRotatorTracking
+        | RigCommand::SendCw { .. }
+        | RigCommand::AbortCw
+        | RigCommand::SetCwKeyer { .. }
+        | RigCommand::SetCwSerial(_)
+        | RigCommand::ReadMemories { .. }
+        | RigCommand::WriteMemory(_)
+        | RigCommand::RecallMemory(_)
+        | RigCommand::RecallBandStack { .. }
+        | RigCommand::SetRepeaterOffset(_)
+        | RigCommand::SetRepeaterReverse(_)
+        | RigCommand::SetCtcss { .. }
+        | RigCommand::SetDcs(_)
+        | RigCommand::SetRit(_)
+        | RigCommand::SetXit(_)
+        | RigCommand::LoadTles(_)
         | RigCommand::GetSpectrum => Box::new(GetSnapshotCommand),
     }
 }
@@ -513,6 +547,7 @@ mod tests {
                         attenuator: false,
                         preamp: false,
                         rit: false,
+                        xit: false,
                         rpt: false,
                         split: false,
                         tx: true,
@@ -520,6 +555,11 @@ mod tests {
                         vfo_switch: true,
                         filter_controls: false,
                         signal_meter: true,
+                        memory_channels: 0,
+                        memory_write: false,
+                        band_stack: false,
+                        ctcss_decode: false,
+                        dcs: false,
                     },
                     access: RigAccessMethod::Serial {
                         path: "/dev/test".to_string(),
@@ -572,6 +612,7 @@ mod tests {
                         attenuator: false,
                         preamp: false,
                         rit: false,
+                        xit: false,
                         rpt: false,
                         split: false,
                         tx: true,
@@ -579,6 +620,11 @@ mod tests {
                         vfo_switch: true,
                         filter_controls: false,
                         signal_meter: true,
+                        memory_channels: 0,
+                        memory_write: false,
+                        band_stack: false,
+                        ctcss_decode: false,
+                        dcs: false,
                     },
                     access: RigAccessMethod::Serial {
                         path: "/dev/test".to_string(),
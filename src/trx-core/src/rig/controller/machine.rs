@@ -542,6 +542,7 @@ mod tests {
                 attenuator: false,
                 preamp: false,
                 rit: false,
+                xit: false,
                 rpt: false,
                 split: false,
                 tx: true,
@@ -549,6 +550,11 @@ mod tests {
                 vfo_switch: true,
                 filter_controls: false,
                 signal_meter: true,
+                memory_channels: 0,
+                memory_write: false,
+                band_stack: false,
+                ctcss_decode: false,
+                dcs: false,
             },
             access: RigAccessMethod::Serial {
                 path: "/dev/test".to_string(),
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Rig memory channels.
+//!
+//! Channels are numbered from 1 as shown on the rig's display.  Backends
+//! that cannot read some field (for example the tone frequency on rigs whose
+//! CAT memory reply only carries the tone mode) leave it at its default.
+
+use serde::{Deserialize, Serialize};
+
+use crate::radio::freq::Freq;
+use crate::rig::state::RigMode;
+
+/// Channels read per `ReadMemories` command.  Some rigs need several CAT
+/// round trips per channel, so larger ranges are read page by page to stay
+/// inside the command timeout.
+pub const MEMORY_PAGE_SIZE: u16 = 8;
+
+/// Contents of one memory channel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RigMemory {
+    pub channel: u16,
+    /// Channel tag; empty when unnamed or when the rig has no tags.
+    #[serde(default)]
+    pub name: String,
+    pub freq: Freq,
+    pub mode: RigMode,
+    /// CTCSS tone in Hz, `None` when no tone is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctcss_hz: Option<f32>,
+    /// Repeater transmit offset in Hz; negative for a minus shift, 0 for
+    /// simplex, `None` when the rig does not report the shift size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset_hz: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_defaults_missing_fields() {
+        let mem: RigMemory = serde_json::from_str(
+            r#"{"channel":3,"freq":{"hz":145600000},"mode":"FM","offset_hz":-600000}"#,
+        )
+        .unwrap();
+        assert_eq!(mem.name, "");
+        assert_eq!(mem.ctcss_hz, None);
+        assert_eq!(mem.offset_hz, Some(-600_000));
+    }
+}
@@ -16,6 +16,8 @@ pub type RigStatusFuture<'a> =
 
 pub mod command;
 pub mod controller;
+pub mod memory;
+pub mod repeater;
 pub mod request;
 pub mod response;
 pub mod state;
@@ -48,7 +50,12 @@ pub struct RigCapabilities {
     pub lockable: bool,
     pub attenuator: bool,
     pub preamp: bool,
+    /// Backend supports RIT via set_clarifier.
     pub rit: bool,
+    /// Backend supports XIT via set_clarifier.
+    #[serde(default)]
+    pub xit: bool,
+    /// Backend supports set_repeater_offset and CTCSS encode via set_ctcss.
     pub rpt: bool,
     pub split: bool,
     /// Backend supports transmit: PTT, power on/off, TX meters, TX audio.
@@ -61,6 +68,21 @@ pub struct RigCapabilities {
     pub filter_controls: bool,
     /// Backend returns a meaningful RX signal strength value.
     pub signal_meter: bool,
+    /// Number of memory channels readable with `read_memory` (0 = none).
+    #[serde(default)]
+    pub memory_channels: u16,
+    /// Backend supports write_memory.
+    #[serde(default)]
+    pub memory_write: bool,
+    /// Backend supports recall_band_stack.
+    #[serde(default)]
+    pub band_stack: bool,
+    /// Backend can mute receive audio until a CTCSS tone is heard.
+    #[serde(default)]
+    pub ctcss_decode: bool,
+    /// Backend supports set_dcs.
+    #[serde(default)]
+    pub dcs: bool,
 }
 
 fn default_min_freq_step_hz() -> u64 {
@@ -149,6 +171,144 @@ pub trait RigCat: Rig + Send {
 
     fn unlock<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>>;
 
+    /// Transmit on the other VFO at `freq` (split operation), or return to
+    /// simplex with `None`.  Default: not supported.
+    fn set_split_tx_freq<'a>(
+        &'a mut self,
+        _freq: Option<Freq>,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("set_split_tx_freq"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Send `text` (plain Morse text, macros already expanded) with the
+    /// rig's internal keyer at `wpm`.  Returns once the text is queued; the
+    /// rig keys the transmitter itself.  Default: not supported, in which
+    /// case the server falls back to PTT plus tone audio.
+    fn send_cw<'a>(
+        &'a mut self,
+        _text: String,
+        _wpm: u32,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("send_cw"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Abort CW queued with [`send_cw`](RigCat::send_cw).  Default: not
+    /// supported.
+    fn stop_cw<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("stop_cw"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Read memory `channel`; `Ok(None)` for an empty channel.  Default: not
+    /// supported.
+    fn read_memory<'a>(
+        &'a mut self,
+        _channel: u16,
+    ) -> Pin<Box<dyn Future<Output = DynResult<Option<memory::RigMemory>>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("read_memory"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Store `memory` in channel `memory.channel`.  Default: not supported.
+    fn write_memory<'a>(
+        &'a mut self,
+        _memory: memory::RigMemory,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("write_memory"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Make memory `channel` the operating frequency.  Default: not
+    /// supported.
+    fn recall_memory<'a>(
+        &'a mut self,
+        _channel: u16,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("recall_memory"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Recall the band-stack register of the band containing `freq`, i.e.
+    /// the last frequency and mode used on that band.  Default: not
+    /// supported.
+    fn recall_band_stack<'a>(
+        &'a mut self,
+        _freq: Freq,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("recall_band_stack"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Set the repeater transmit offset in Hz: negative for a minus shift,
+    /// positive for plus, 0 for simplex.  Default: not supported.
+    fn set_repeater_offset<'a>(
+        &'a mut self,
+        _offset_hz: i32,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("set_repeater_offset"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Set the CTCSS tone, or turn tones off with `None`.  With `decode`
+    /// the receiver also stays muted until the tone is heard; otherwise the
+    /// tone is only transmitted.  Turns DCS off.  Default: not supported.
+    fn set_ctcss<'a>(
+        &'a mut self,
+        _tone_hz: Option<f32>,
+        _decode: bool,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("set_ctcss"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Enable DCS encode and decode with `code` (see
+    /// [`repeater::DCS_CODES`]), or turn it off with `None`.  Turns CTCSS
+    /// off.  Default: not supported.
+    fn set_dcs<'a>(
+        &'a mut self,
+        _code: Option<u16>,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("set_dcs"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
+    /// Set the clarifier offset in Hz and whether it shifts receive (RIT),
+    /// transmit (XIT) or both; with both off the offset is kept but unused.
+    /// Default: not supported.
+    fn set_clarifier<'a>(
+        &'a mut self,
+        _offset_hz: i32,
+        _rit: bool,
+        _xit: bool,
+    ) -> Pin<Box<dyn Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(std::future::ready(Err(
+            Box::new(response::RigError::not_supported("set_clarifier"))
+                as Box<dyn std::error::Error + Send + Sync>,
+        )))
+    }
+
     fn as_audio_source(&self) -> Option<&dyn AudioSource> {
         None
     }
@@ -362,9 +522,59 @@ pub struct RigRxStatus {
 pub struct RigControl {
     pub enabled: Option<bool>,
     pub lock: Option<bool>,
+    /// Clarifier offset in Hz, shared by RIT and XIT.
     pub clar_hz: Option<i32>,
+    /// RIT: the clarifier offset shifts the receive frequency.
     pub clar_on: Option<bool>,
+    /// XIT: the clarifier offset shifts the transmit frequency.
+    pub xit_on: Option<bool>,
     pub rpt_offset_hz: Option<i32>,
+    pub rpt_reverse: Option<bool>,
     pub ctcss_hz: Option<f32>,
+    pub ctcss_decode: Option<bool>,
     pub dcs_code: Option<u16>,
 }
+
+/// Clarifier settings published with the rig state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClarifierState {
+    /// Offset in Hz, shared by RIT and XIT.
+    pub offset_hz: i32,
+    #[serde(default)]
+    pub rit: bool,
+    #[serde(default)]
+    pub xit: bool,
+}
+
+impl RigControl {
+    /// Clarifier settings for publishing, `None` until any has been set.
+    pub fn clarifier(&self) -> Option<ClarifierState> {
+        if self.clar_hz.is_none() && self.clar_on.is_none() && self.xit_on.is_none() {
+            return None;
+        }
+        Some(ClarifierState {
+            offset_hz: self.clar_hz.unwrap_or(0),
+            rit: self.clar_on.unwrap_or(false),
+            xit: self.xit_on.unwrap_or(false),
+        })
+    }
+
+    /// Repeater settings for publishing, `None` until any has been set.
+    pub fn repeater(&self) -> Option<repeater::RepeaterState> {
+        if self.rpt_offset_hz.is_none()
+            && self.rpt_reverse.is_none()
+            && self.ctcss_hz.is_none()
+            && self.ctcss_decode.is_none()
+            && self.dcs_code.is_none()
+        {
+            return None;
+        }
+        Some(repeater::RepeaterState {
+            offset_hz: self.rpt_offset_hz.unwrap_or(0),
+            reverse: self.rpt_reverse.unwrap_or(false),
+            ctcss_hz: self.ctcss_hz,
+            ctcss_decode: self.ctcss_decode.unwrap_or(false),
+            dcs_code: self.dcs_code,
+        })
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Repeater shift and CTCSS/DCS tone settings.
+
+use serde::{Deserialize, Serialize};
+
+/// Standard EIA CTCSS tones in Hz, in the order most rigs index them.
+pub const CTCSS_TONES: [f32; 50] = [
+    67.0, 69.3, 71.9, 74.4, 77.0, 79.7, 82.5, 85.4, 88.5, 91.5, 94.8, 97.4, 100.0, 103.5, 107.2,
+    110.9, 114.8, 118.8, 123.0, 127.3, 131.8, 136.5, 141.3, 146.2, 151.4, 156.7, 159.8, 162.2,
+    165.5, 167.9, 171.3, 173.8, 177.3, 179.9, 183.5, 186.2, 189.9, 192.8, 196.6, 199.5, 203.5,
+    206.5, 210.7, 218.1, 225.7, 229.1, 233.6, 241.8, 250.3, 254.1,
+];
+
+/// Standard DCS codes.  Codes are octal on air but written here as the
+/// three digits shown on the rig, e.g. `23` for "023".
+pub const DCS_CODES: [u16; 104] = [
+    23, 25, 26, 31, 32, 36, 43, 47, 51, 53, 54, 65, 71, 72, 73, 74, 114, 115, 116, 122, 125, 131,
+    132, 134, 143, 145, 152, 155, 156, 162, 165, 172, 174, 205, 212, 223, 225, 226, 243, 244, 245,
+    246, 251, 252, 255, 261, 263, 265, 266, 271, 274, 306, 311, 315, 325, 331, 332, 343, 346, 351,
+    356, 364, 365, 371, 411, 412, 413, 423, 431, 432, 445, 446, 452, 454, 455, 462, 464, 465, 466,
+    503, 506, 516, 523, 526, 532, 546, 565, 606, 612, 624, 627, 631, 632, 654, 662, 664, 703, 712,
+    723, 731, 732, 734, 743, 754,
+];
+
+/// Index of `hz` in [`CTCSS_TONES`], tolerating rounding in the input.
+pub fn ctcss_tone_index(hz: f32) -> Option<usize> {
+    CTCSS_TONES.iter().position(|&t| (t - hz).abs() < 0.05)
+}
+
+/// Index of `code` in [`DCS_CODES`].
+pub fn dcs_code_index(code: u16) -> Option<usize> {
+    DCS_CODES.iter().position(|&c| c == code)
+}
+
+/// Repeater settings published with the rig state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepeaterState {
+    /// Transmit offset in Hz; negative for a minus shift, 0 for simplex.
+    pub offset_hz: i32,
+    /// Listening on the repeater input: the receive frequency was moved by
+    /// the offset and the offset inverted.
+    #[serde(default)]
+    pub reverse: bool,
+    /// CTCSS tone in Hz, `None` when no tone is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctcss_hz: Option<f32>,
+    /// Keep the receiver muted until the CTCSS tone is heard (tone squelch)
+    /// instead of only transmitting it.
+    #[serde(default)]
+    pub ctcss_decode: bool,
+    /// DCS code, `None` when DCS is off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dcs_code: Option<u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tone_lookups_match_table_entries() {
+        assert_eq!(ctcss_tone_index(67.0), Some(0));
+        assert_eq!(ctcss_tone_index(88.5), Some(8));
+        assert_eq!(ctcss_tone_index(254.1), Some(49));
+        assert_eq!(ctcss_tone_index(88.0), None);
+
+        assert_eq!(dcs_code_index(23), Some(0));
+        assert_eq!(dcs_code_index(754), Some(103));
+        assert_eq!(dcs_code_index(24), None);
+        assert!(DCS_CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+}
@@ -5,8 +5,16 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::decode::PskMode;
+use crate::doppler::SatTrackingState;
+use crate::keyer::CwKeyerState;
 use crate::radio::freq::Freq;
-use crate::rig::{RigControl, RigInfo, RigRxStatus, RigStatus, RigStatusProvider, RigTxStatus};
+use crate::rig::memory::RigMemory;
+use crate::rig::repeater::RepeaterState;
+use crate::rig::{
+    ClarifierState, RigControl, RigInfo, RigRxStatus, RigStatus, RigStatusProvider, RigTxStatus,
+};
+use crate::rotator::RotatorState;
 
 /// Decoder enable/disable flags grouped for cleaner state management.
 ///
@@ -21,6 +29,16 @@ pub struct DecoderConfig {
     #[serde(default)]
     pub cw_decode_enabled: bool,
     #[serde(default)]
+    pub psk_decode_enabled: bool,
+    #[serde(default)]
+    pub dsc_decode_enabled: bool,
+    #[serde(default)]
+    pub pocsag_decode_enabled: bool,
+    #[serde(default)]
+    pub adsb_decode_enabled: bool,
+    #[serde(default)]
+    pub acars_decode_enabled: bool,
+    #[serde(default)]
     pub ft8_decode_enabled: bool,
     #[serde(default)]
     pub ft4_decode_enabled: bool,
@@ -50,6 +68,16 @@ pub struct DecoderResetSeqs {
     #[serde(default, skip_serializing)]
     pub cw_decode_reset_seq: u64,
     #[serde(default, skip_serializing)]
+    pub psk_decode_reset_seq: u64,
+    #[serde(default, skip_serializing)]
+    pub dsc_decode_reset_seq: u64,
+    #[serde(default, skip_serializing)]
+    pub pocsag_decode_reset_seq: u64,
+    #[serde(default, skip_serializing)]
+    pub adsb_decode_reset_seq: u64,
+    #[serde(default, skip_serializing)]
+    pub acars_decode_reset_seq: u64,
+    #[serde(default, skip_serializing)]
     pub ft8_decode_reset_seq: u64,
     #[serde(default, skip_serializing)]
     pub ft4_decode_reset_seq: u64,
@@ -63,6 +91,10 @@ pub struct DecoderResetSeqs {
     pub wefax_decode_reset_seq: u64,
 }
 
+fn default_psk_freq_hz() -> u32 {
+    1000
+}
+
 /// Simple transceiver state representation held by the rig task.
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct RigState {
@@ -95,6 +127,26 @@ pub struct RigState {
     pub cw_wpm: u32,
     #[serde(default)]
     pub cw_tone_hz: u32,
+    #[serde(default)]
+    pub psk_mode: PskMode,
+    /// Audio offset followed by the PSK decoder in single-channel mode.
+    #[serde(default = "default_psk_freq_hz")]
+    pub psk_freq_hz: u32,
+    /// Decode every PSK signal in the passband instead of one offset.
+    #[serde(default)]
+    pub psk_waterfall: bool,
+    /// Doppler tracking status while a satellite is being followed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sat_tracking: Option<SatTrackingState>,
+    /// Antenna rotator position and tracking status, when one is configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotator: Option<RotatorState>,
+    /// CW keyer settings, memories and send status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cw_keyer: Option<CwKeyerState>,
+    /// Filled channels from the most recent `ReadMemories` page.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub memories: Vec<RigMemory>,
     /// Filter state for backends that support runtime filter adjustment.
     /// Skipped in serde; flows into RigSnapshot via snapshot().
     #[serde(skip)]
@@ -154,11 +206,14 @@ impl Default for RigControl {
     fn default() -> Self {
         Self {
             rpt_offset_hz: None,
+            rpt_reverse: None,
             ctcss_hz: None,
+            ctcss_decode: None,
             dcs_code: None,
             lock: Some(false),
             clar_hz: None,
             clar_on: None,
+            xit_on: None,
             enabled: Some(false),
         }
     }
@@ -189,6 +244,13 @@ impl RigState {
             cw_auto: true,
             cw_wpm: 15,
             cw_tone_hz: 700,
+            psk_mode: PskMode::default(),
+            psk_freq_hz: default_psk_freq_hz(),
+            psk_waterfall: false,
+            sat_tracking: None,
+            rotator: None,
+            cw_keyer: None,
+            memories: Vec::new(),
             filter: None,
             spectrum: None,
             vchan_rds: None,
@@ -222,17 +284,22 @@ impl RigState {
     /// Convert snapshot to full state (remote client).
     pub fn from_snapshot(snapshot: RigSnapshot) -> Self {
         let lock = snapshot.status.lock;
+        let repeater = snapshot.repeater;
+        let clarifier = snapshot.clarifier;
         Self {
             rig_info: Some(snapshot.info),
             status: snapshot.status,
             initialized: snapshot.initialized,
             control: RigControl {
-                rpt_offset_hz: None,
-                ctcss_hz: None,
-                dcs_code: None,
+                rpt_offset_hz: repeater.as_ref().map(|r| r.offset_hz),
+                rpt_reverse: repeater.as_ref().map(|r| r.reverse),
+                ctcss_hz: repeater.as_ref().and_then(|r| r.ctcss_hz),
+                ctcss_decode: repeater.as_ref().map(|r| r.ctcss_decode),
+                dcs_code: repeater.as_ref().and_then(|r| r.dcs_code),
                 lock,
-                clar_hz: None,
-                clar_on: None,
+                clar_hz: clarifier.as_ref().map(|c| c.offset_hz),
+                clar_on: clarifier.as_ref().map(|c| c.rit),
+                xit_on: clarifier.as_ref().map(|c| c.xit),
                 enabled: snapshot.enabled,
             },
             server_callsign: snapshot.server_callsign,
@@ -246,6 +313,13 @@ impl RigState {
             cw_auto: snapshot.cw_auto,
             cw_wpm: snapshot.cw_wpm,
             cw_tone_hz: snapshot.cw_tone_hz,
+            psk_mode: snapshot.psk_mode,
+            psk_freq_hz: snapshot.psk_freq_hz,
+            psk_waterfall: snapshot.psk_waterfall,
+            sat_tracking: snapshot.sat_tracking,
+            rotator: snapshot.rotator,
+            cw_keyer: snapshot.cw_keyer,
+            memories: snapshot.memories,
             filter: snapshot.filter,
             spectrum: None, // spectrum flows through /api/spectrum, not persistent state
             vchan_rds: None, // vchan RDS flows through /api/spectrum, not persistent state
@@ -281,6 +355,15 @@ impl RigState {
             cw_auto: self.cw_auto,
             cw_wpm: self.cw_wpm,
             cw_tone_hz: self.cw_tone_hz,
+            psk_mode: self.psk_mode,
+            psk_freq_hz: self.psk_freq_hz,
+            psk_waterfall: self.psk_waterfall,
+            sat_tracking: self.sat_tracking.clone(),
+            rotator: self.rotator.clone(),
+            cw_keyer: self.cw_keyer.clone(),
+            memories: self.memories.clone(),
+            repeater: self.control.repeater(),
+            clarifier: self.control.clarifier(),
             filter: self.filter.clone(),
             spectrum: self.spectrum.clone(),
             vchan_rds: self.vchan_rds.clone(),
@@ -486,6 +569,32 @@ pub struct RigSnapshot {
     pub cw_wpm: u32,
     #[serde(default)]
     pub cw_tone_hz: u32,
+    #[serde(default)]
+    pub psk_mode: PskMode,
+    /// Audio offset followed by the PSK decoder in single-channel mode.
+    #[serde(default = "default_psk_freq_hz")]
+    pub psk_freq_hz: u32,
+    /// Decode every PSK signal in the passband instead of one offset.
+    #[serde(default)]
+    pub psk_waterfall: bool,
+    /// Doppler tracking status while a satellite is being followed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sat_tracking: Option<SatTrackingState>,
+    /// Antenna rotator position and tracking status, when one is configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotator: Option<RotatorState>,
+    /// CW keyer settings, memories and send status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cw_keyer: Option<CwKeyerState>,
+    /// Filled channels from the most recent `ReadMemories` page.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub memories: Vec<RigMemory>,
+    /// Repeater shift and tone settings, once any has been set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repeater: Option<RepeaterState>,
+    /// RIT/XIT offset and state, once either has been set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clarifier: Option<ClarifierState>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<RigFilterState>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
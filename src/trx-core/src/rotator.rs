@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Antenna rotator control.
+//!
+//! Rotators are driven by the rig task next to the [`RigCat`](crate::rig::RigCat)
+//! backend.  Azimuth is measured clockwise from north over the rotator's
+//! mechanical travel `0..=az_max_deg`, so a 450° rotator reports 0–450°.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+
+use crate::DynResult;
+
+pub type RotatorFuture<'a, T> = Pin<Box<dyn Future<Output = DynResult<T>> + Send + 'a>>;
+
+/// Static description of a rotator backend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RotatorInfo {
+    pub model: String,
+    /// End of azimuth travel: 360 for plain rotators, 450 with overlap.
+    pub az_max_deg: f64,
+    /// End of elevation travel: 90, or 180 for rotators that can flip over.
+    pub el_max_deg: f64,
+}
+
+/// An azimuth/elevation pair in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RotatorPosition {
+    pub azimuth_deg: f64,
+    pub elevation_deg: f64,
+}
+
+/// Common operations every rotator backend implements.
+pub trait Rotator: Send {
+    fn info(&self) -> &RotatorInfo;
+
+    fn get_position<'a>(&'a mut self) -> RotatorFuture<'a, RotatorPosition>;
+
+    /// Start moving to `pos`; returns once the command is accepted.
+    fn set_position<'a>(&'a mut self, pos: RotatorPosition) -> RotatorFuture<'a, ()>;
+
+    fn stop<'a>(&'a mut self) -> RotatorFuture<'a, ()>;
+}
+
+/// Live rotator state, published with the rig state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RotatorState {
+    pub info: RotatorInfo,
+    /// Last reported position; `None` until the first successful read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<RotatorPosition>,
+    /// Position most recently commanded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<RotatorPosition>,
+    /// Satellite being followed, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_norad_id: Option<u32>,
+    /// Last communication error; cleared on the next successful read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Clamp a manually requested position to the rotator's travel.
+pub fn clamp_position(info: &RotatorInfo, pos: RotatorPosition) -> RotatorPosition {
+    RotatorPosition {
+        azimuth_deg: pos.azimuth_deg.clamp(0.0, info.az_max_deg),
+        elevation_deg: pos.elevation_deg.clamp(0.0, info.el_max_deg),
+    }
+}
+
+/// Signed azimuth change from `from` to `to`, in `(-180, 180]`.
+fn az_delta(from: f64, to: f64) -> f64 {
+    let d = (to - from).rem_euclid(360.0);
+    if d > 180.0 {
+        d - 360.0
+    } else {
+        d
+    }
+}
+
+/// Point the other way round with the elevation axis past zenith.
+fn flipped(look: RotatorPosition) -> RotatorPosition {
+    RotatorPosition {
+        azimuth_deg: (look.azimuth_deg + 180.0).rem_euclid(360.0),
+        elevation_deg: 180.0 - look.elevation_deg,
+    }
+}
+
+/// How a satellite pass is laid onto the rotator's travel.
+///
+/// A pass whose ground track crosses the azimuth stop would make the rotator
+/// swing a full turn mid-pass.  When the track fits inside the overlap of a
+/// 450° rotator it is shifted by 360°, and rotators with 180° elevation can
+/// instead flip over and track from the opposite azimuth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassPlan {
+    /// Track with the elevation axis past zenith.
+    pub flip: bool,
+    /// The whole pass fits without crossing the azimuth stop.
+    pub fits: bool,
+    last_az: f64,
+    az_max_deg: f64,
+    el_max_deg: f64,
+}
+
+impl PassPlan {
+    /// Plan a pass from look angles sampled over its visible part.
+    pub fn new(info: &RotatorInfo, track: &[RotatorPosition]) -> Self {
+        let plain = |flip| Self {
+            flip,
+            fits: false,
+            last_az: track
+                .first()
+                .map_or(0.0, |p| p.azimuth_deg.rem_euclid(360.0)),
+            az_max_deg: info.az_max_deg,
+            el_max_deg: info.el_max_deg,
+        };
+        let Some(first) = track.first() else {
+            return plain(false);
+        };
+        let flips: &[bool] = if info.el_max_deg >= 180.0 {
+            &[false, true]
+        } else {
+            &[false]
+        };
+        for &flip in flips {
+            let orient = |p: RotatorPosition| if flip { flipped(p) } else { p };
+            let start = orient(*first).azimuth_deg.rem_euclid(360.0);
+            let (mut az, mut lo, mut hi) = (start, start, start);
+            for pair in track.windows(2) {
+                az += az_delta(orient(pair[0]).azimuth_deg, orient(pair[1]).azimuth_deg);
+                lo = lo.min(az);
+                hi = hi.max(az);
+            }
+            for shift in [0.0, 360.0, -360.0] {
+                if lo + shift >= 0.0 && hi + shift <= info.az_max_deg {
+                    return Self {
+                        flip,
+                        fits: true,
+                        last_az: start + shift,
+                        ..plain(flip)
+                    };
+                }
+            }
+        }
+        plain(false)
+    }
+
+    /// Rotator position for a live look angle, continuing from the previous
+    /// call so the track never jumps across the azimuth stop.
+    pub fn position_for(&mut self, look: RotatorPosition) -> RotatorPosition {
+        let look = RotatorPosition {
+            elevation_deg: look.elevation_deg.max(0.0),
+            ..look
+        };
+        let look = if self.flip { flipped(look) } else { look };
+        let mut az = self.last_az + az_delta(self.last_az, look.azimuth_deg);
+        if az < 0.0 {
+            az += 360.0;
+        }
+        if az > self.az_max_deg {
+            az -= 360.0;
+        }
+        self.last_az = az.clamp(0.0, self.az_max_deg);
+        RotatorPosition {
+            azimuth_deg: self.last_az,
+            elevation_deg: look.elevation_deg.clamp(0.0, self.el_max_deg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(az_max_deg: f64, el_max_deg: f64) -> RotatorInfo {
+        RotatorInfo {
+            model: "test".to_string(),
+            az_max_deg,
+            el_max_deg,
+        }
+    }
+
+    fn track(azimuths: &[f64]) -> Vec<RotatorPosition> {
+        azimuths
+            .iter()
+            .enumerate()
+            .map(|(i, &azimuth_deg)| RotatorPosition {
+                azimuth_deg,
+                elevation_deg: 10.0 + i as f64,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pass_across_north_uses_overlap() {
+        // NW → N → NE crosses the stop at north.
+        let pass = track(&[300.0, 330.0, 0.0, 30.0, 60.0]);
+        let mut plan = PassPlan::new(&info(450.0, 90.0), &pass);
+        assert!(plan.fits && !plan.flip);
+        let azimuths: Vec<f64> = pass
+            .iter()
+            .map(|&p| plan.position_for(p).azimuth_deg)
+            .collect();
+        assert_eq!(azimuths, vec![300.0, 330.0, 360.0, 390.0, 420.0]);
+
+        // A 360° rotator cannot avoid the swing.
+        assert!(!PassPlan::new(&info(360.0, 90.0), &pass).fits);
+    }
+
+    #[test]
+    fn flip_avoids_stop_on_180_degree_elevation() {
+        let pass = track(&[300.0, 330.0, 0.0, 30.0, 60.0]);
+        let mut plan = PassPlan::new(&info(360.0, 180.0), &pass);
+        assert!(plan.fits && plan.flip);
+        let first = plan.position_for(pass[0]);
+        assert_eq!(first.azimuth_deg, 120.0);
+        assert_eq!(first.elevation_deg, 170.0);
+        assert_eq!(plan.position_for(pass[2]).azimuth_deg, 180.0);
+    }
+
+    #[test]
+    fn southern_pass_is_unchanged() {
+        let pass = track(&[100.0, 150.0, 200.0, 250.0]);
+        let mut plan = PassPlan::new(&info(450.0, 180.0), &pass);
+        assert!(plan.fits && !plan.flip);
+        assert_eq!(plan.position_for(pass[3]).azimuth_deg, 250.0);
+        let below = RotatorPosition {
+            azimuth_deg: 260.0,
+            elevation_deg: -3.0,
+        };
+        assert_eq!(plan.position_for(below).elevation_deg, 0.0);
+    }
+}
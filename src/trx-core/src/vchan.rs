@@ -146,6 +146,13 @@ pub trait VirtualChannelManager: Send + Sync {
         // `Err(RecvError::Closed)` on first poll, signalling "no events".
         broadcast::channel::<Uuid>(1).1
     }
+
+    /// Hardware centre frequency of the IQ stream the channels are cut from.
+    ///
+    /// `None` for backends without a wideband capture.
+    fn center_hz(&self) -> Option<i64> {
+        None
+    }
 }
 
 /// Convenience alias used in `RigHandle`.
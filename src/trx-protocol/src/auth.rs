@@ -5,6 +5,9 @@
 //! Authorization and token handling utilities.
 
 use std::collections::HashSet;
+use std::time::SystemTime;
+
+use crate::types::{ClientCommand, Scope, ScopeDenial};
 
 /// Strip the "Bearer " prefix from a token string (case-insensitive).
 ///
@@ -81,9 +84,243 @@ impl TokenValidator for NoAuthValidator {
     }
 }
 
+/// Scope a client needs to run `cmd` on a rig.
+pub fn required_scope(cmd: &ClientCommand) -> Scope {
+    use ClientCommand::*;
+    match cmd {
+        GetState
+        | GetRigs
+        | GetSatPasses
+        | GetTxLimit
+        | GetSpectrum
+        | ReadMemories { .. }
+        | SubscribeMeter
+        | SubscribeState
+        | SubscribeDecode => Scope::Read,
+        SetPtt { .. } | SetTxLimit { .. } | SendCw { .. } => Scope::Tx,
+        PowerOn | PowerOff | WriteMemory { .. } | SetRecorderEnabled { .. } | LoadTles { .. } => {
+            Scope::Admin
+        }
+        SetFreq { .. }
+        | SetCenterFreq { .. }
+        | SetMode { .. }
+        | ToggleVfo
+        | Lock
+        | Unlock
+        | SetAprsDecodeEnabled { .. }
+        | SetHfAprsDecodeEnabled { .. }
+        | SetCwDecodeEnabled { .. }
+        | SetCwAuto { .. }
+        | SetCwWpm { .. }
+        | SetCwToneHz { .. }
+        | SetPskDecodeEnabled { .. }
+        | SetPskMode { .. }
+        | SetPskFreqHz { .. }
+        | SetPskWaterfall { .. }
+        | SetDscDecodeEnabled { .. }
+        | SetPocsagDecodeEnabled { .. }
+        | SetAdsbDecodeEnabled { .. }
+        | SetAcarsDecodeEnabled { .. }
+        | SetFt8DecodeEnabled { .. }
+        | SetFt4DecodeEnabled { .. }
+        | SetFt2DecodeEnabled { .. }
+        | SetWsprDecodeEnabled { .. }
+        | SetLrptDecodeEnabled { .. }
+        | SetWefaxDecodeEnabled { .. }
+        | ResetAprsDecoder
+        | ResetHfAprsDecoder
+        | ResetCwDecoder
+        | ResetPskDecoder
+        | ResetDscDecoder
+        | ResetPocsagDecoder
+        | ResetAdsbDecoder
+        | ResetAcarsDecoder
+        | ResetFt8Decoder
+        | ResetFt4Decoder
+        | ResetFt2Decoder
+        | ResetWsprDecoder
+        | ResetLrptDecoder
+        | ResetWefaxDecoder
+        | SetBandwidth { .. }
+        | SetSdrGain { .. }
+        | SetSdrLnaGain { .. }
+        | SetSdrAgc { .. }
+        | SetSdrSquelch { .. }
+        | SetSdrNoiseBlanker { .. }
+        | SetWfmDeemphasis { .. }
+        | SetWfmStereo { .. }
+        | SetWfmDenoise { .. }
+        | SetSamStereoWidth { .. }
+        | SetSamCarrierSync { .. }
+        | StartSatTracking { .. }
+        | StopSatTracking
+        | SetRotatorPosition { .. }
+        | StopRotator
+        | StartRotatorTracking { .. }
+        | StopRotatorTracking
+        | AbortCw
+        | SetCwKeyer { .. }
+        | SetCwSerial { .. }
+        | RecallMemory { .. }
+        | RecallBandStack { .. }
+        | SetRepeaterOffset { .. }
+        | SetRepeaterReverse { .. }
+        | SetCtcss { .. }
+        | SetDcs { .. }
+        | SetRit { .. }
+        | SetXit { .. } => Scope::Control,
+    }
+}
+
+/// SHA-256 of a token, as stored in `token_sha256` config entries.
+pub fn hash_token(token: &str) -> [u8; 32] {
+    let digest = ring::digest::digest(&ring::digest::SHA256, token.as_bytes());
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest.as_ref());
+    out
+}
+
+/// Parse a hex-encoded SHA-256 token hash (64 hex digits).
+pub fn parse_token_hash(hex: &str) -> Result<[u8; 32], String> {
+    let hex = hex.trim();
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err("token hash must be 64 hex digits (SHA-256)".into());
+    }
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .map_err(|_| "token hash must be 64 hex digits (SHA-256)".to_string())?;
+    }
+    Ok(out)
+}
+
+/// One configured token with its scope, rig allow list and expiry.
+#[derive(Debug, Clone)]
+pub struct TokenGrant {
+    /// Label used in logs; never the token itself.
+    pub name: String,
+    /// SHA-256 of the token.
+    pub token_sha256: [u8; 32],
+    pub scope: Scope,
+    /// Rig IDs this token may access; empty = every rig.
+    pub rigs: Vec<String>,
+    /// The token is rejected from this instant on.
+    pub expires_at: Option<SystemTime>,
+}
+
+impl TokenGrant {
+    /// Full access to every rig, without expiry.
+    pub fn unrestricted(name: impl Into<String>, token: &str) -> Self {
+        Self {
+            name: name.into(),
+            token_sha256: hash_token(token),
+            scope: Scope::Admin,
+            rigs: Vec::new(),
+            expires_at: None,
+        }
+    }
+
+    /// Scope this grant holds on `rig_id`, if any.
+    pub fn scope_for(&self, rig_id: &str) -> Option<Scope> {
+        if self.rigs.is_empty() || self.rigs.iter().any(|r| r == rig_id) {
+            Some(self.scope)
+        } else {
+            None
+        }
+    }
+}
+
+/// Outcome of authenticating a request token.
+#[derive(Debug, Clone, Copy)]
+pub enum Access<'a> {
+    /// No tokens are configured; everything is allowed.
+    Open,
+    Granted(&'a TokenGrant),
+}
+
+impl Access<'_> {
+    /// Scope on `rig_id`, or `None` when the token may not see the rig.
+    pub fn scope_for(&self, rig_id: &str) -> Option<Scope> {
+        match self {
+            Self::Open => Some(Scope::Admin),
+            Self::Granted(grant) => grant.scope_for(rig_id),
+        }
+    }
+
+    /// Check that the token holds `required` on `rig_id`.
+    pub fn authorize(&self, rig_id: &str, required: Scope) -> Result<(), ScopeDenial> {
+        let granted = self.scope_for(rig_id);
+        if granted.is_some_and(|scope| scope >= required) {
+            Ok(())
+        } else {
+            Err(ScopeDenial { required, granted })
+        }
+    }
+}
+
+/// Token validator with per-token scopes, rig allow lists and expiry.
+/// Tokens are only held as SHA-256 hashes.
+#[derive(Debug, Clone, Default)]
+pub struct ScopedTokenValidator {
+    grants: Vec<TokenGrant>,
+}
+
+impl ScopedTokenValidator {
+    pub fn new(grants: Vec<TokenGrant>) -> Self {
+        Self { grants }
+    }
+
+    /// Build a validator where every token has full access, matching the
+    /// behaviour of [`SimpleTokenValidator`].
+    pub fn from_tokens(tokens: impl IntoIterator<Item = String>) -> Self {
+        Self::new(
+            tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| TokenGrant::unrestricted(format!("tokens[{i}]"), &token))
+                .collect(),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.grants.is_empty()
+    }
+
+    /// Resolve a request token to its grant.
+    pub fn authenticate(
+        &self,
+        token: &Option<String>,
+        now: SystemTime,
+    ) -> Result<Access<'_>, String> {
+        if self.grants.is_empty() {
+            return Ok(Access::Open);
+        }
+        let Some(token) = token.as_ref() else {
+            return Err("missing authorization token".into());
+        };
+        let hash = hash_token(strip_bearer(token));
+        let grant = self
+            .grants
+            .iter()
+            .find(|grant| grant.token_sha256 == hash)
+            .ok_or_else(|| "invalid authorization token".to_string())?;
+        if grant.expires_at.is_some_and(|expiry| now >= expiry) {
+            return Err("authorization token expired".into());
+        }
+        Ok(Access::Granted(grant))
+    }
+}
+
+impl TokenValidator for ScopedTokenValidator {
+    fn validate(&self, token: &Option<String>) -> Result<(), String> {
+        self.authenticate(token, SystemTime::now()).map(|_| ())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_strip_bearer_with_prefix() {
@@ -220,4 +457,124 @@ mod tests {
             .validate(&Some("Bearer secret123".to_string()))
             .is_ok());
     }
+
+    fn grant(token: &str, scope: Scope, rigs: &[&str]) -> TokenGrant {
+        TokenGrant {
+            name: token.to_string(),
+            token_sha256: hash_token(token),
+            scope,
+            rigs: rigs.iter().map(|r| r.to_string()).collect(),
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn test_required_scope_mapping() {
+        assert_eq!(required_scope(&ClientCommand::GetState), Scope::Read);
+        assert_eq!(required_scope(&ClientCommand::SubscribeState), Scope::Read);
+        assert_eq!(
+            required_scope(&ClientCommand::SetFreq { freq_hz: 7_074_000 }),
+            Scope::Control
+        );
+        assert_eq!(
+            required_scope(&ClientCommand::SetPtt { ptt: true }),
+            Scope::Tx
+        );
+        assert_eq!(required_scope(&ClientCommand::PowerOff), Scope::Admin);
+    }
+
+    #[test]
+    fn test_scope_order_and_parse() {
+        assert!(Scope::Read < Scope::Control);
+        assert!(Scope::Control < Scope::Tx);
+        assert!(Scope::Tx < Scope::Admin);
+        assert_eq!("tx".parse::<Scope>(), Ok(Scope::Tx));
+        assert!("root".parse::<Scope>().is_err());
+    }
+
+    #[test]
+    fn test_parse_token_hash() {
+        // printf %s secret | sha256sum
+        let hex = "2bb80d537b1da3e38bd30361aa855686bde0eacd7162fef6a25fe97bf527a25b";
+        assert_eq!(parse_token_hash(hex), Ok(hash_token("secret")));
+        assert!(parse_token_hash("abc").is_err());
+        assert!(parse_token_hash(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_scoped_validator_scopes_and_rigs() {
+        let validator = ScopedTokenValidator::new(vec![
+            grant("viewer", Scope::Read, &[]),
+            grant("hf-op", Scope::Tx, &["hf"]),
+        ]);
+        let now = SystemTime::now();
+
+        let viewer = validator
+            .authenticate(&Some("viewer".to_string()), now)
+            .unwrap();
+        assert!(viewer.authorize("vhf", Scope::Read).is_ok());
+        assert_eq!(
+            viewer.authorize("vhf", Scope::Control),
+            Err(ScopeDenial {
+                required: Scope::Control,
+                granted: Some(Scope::Read),
+            })
+        );
+
+        let op = validator
+            .authenticate(&Some("Bearer hf-op".to_string()), now)
+            .unwrap();
+        assert!(op.authorize("hf", Scope::Tx).is_ok());
+        assert!(op.authorize("hf", Scope::Admin).is_err());
+        assert_eq!(
+            op.authorize("vhf", Scope::Read),
+            Err(ScopeDenial {
+                required: Scope::Read,
+                granted: None,
+            })
+        );
+
+        assert_eq!(
+            validator.authenticate(&None, now).unwrap_err(),
+            "missing authorization token"
+        );
+        assert_eq!(
+            validator
+                .authenticate(&Some("nope".to_string()), now)
+                .unwrap_err(),
+            "invalid authorization token"
+        );
+    }
+
+    #[test]
+    fn test_scoped_validator_expiry() {
+        let now = SystemTime::now();
+        let mut expiring = grant("temp", Scope::Control, &[]);
+        expiring.expires_at = Some(now + Duration::from_secs(60));
+        let validator = ScopedTokenValidator::new(vec![expiring]);
+        let token = Some("temp".to_string());
+
+        assert!(validator.authenticate(&token, now).is_ok());
+        assert_eq!(
+            validator
+                .authenticate(&token, now + Duration::from_secs(60))
+                .unwrap_err(),
+            "authorization token expired"
+        );
+    }
+
+    #[test]
+    fn test_scoped_validator_open_and_legacy_tokens() {
+        let open = ScopedTokenValidator::default();
+        let access = open.authenticate(&None, SystemTime::now()).unwrap();
+        assert!(access.authorize("any", Scope::Admin).is_ok());
+
+        let legacy = ScopedTokenValidator::from_tokens(vec!["secret".to_string()]);
+        assert!(legacy.validate(&Some("secret".to_string())).is_ok());
+        assert!(legacy.validate(&None).is_err());
+        let access = legacy
+            .authenticate(&Some("secret".to_string()), SystemTime::now())
+            .unwrap();
+        assert!(access.authorize("any", Scope::Admin).is_ok());
+    }
 }
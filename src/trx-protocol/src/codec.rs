@@ -90,7 +90,7 @@ pub fn parse_envelope(input: &str) -> Result<ClientEnvelope, serde_json::Error>
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     #[test]
@@ -280,6 +280,7 @@ mod tests {
             state: None,
             rigs: None,
             sat_passes: None,
+            denied: None,
             error: None,
         };
         let json = serde_json::to_string(&resp).unwrap();
@@ -298,6 +299,7 @@ mod tests {
             state: None,
             rigs: None,
             sat_passes: None,
+            denied: None,
             error: Some("bad".to_string()),
         };
         let json = serde_json::to_string(&resp).unwrap();
@@ -317,6 +319,7 @@ mod tests {
             state: None,
             rigs: None,
             sat_passes: None,
+            denied: None,
             error: None,
         };
         let json = serde_json::to_string(&resp).unwrap();
@@ -326,6 +329,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_push_event_is_tagged_by_event() {
+        use crate::types::{MeterUpdate, PushEvent};
+        use trx_core::decode::{CwEvent, DecodedMessage};
+
+        let meter = PushEvent::Meter(MeterUpdate {
+            rig_id: "hf".to_string(),
+            sig_dbm: -73.5,
+            ts_ms: 12,
+        });
+        let json = serde_json::to_value(&meter).unwrap();
+        assert_eq!(json["event"], "meter");
+        assert_eq!(json["data"]["rig_id"], "hf");
+
+        let decode = PushEvent::Decode(Box::new(DecodedMessage::Cw(CwEvent {
+            rig_id: Some("hf".to_string()),
+            text: "CQ".to_string(),
+            wpm: 20,
+            tone_hz: 700,
+            signal_on: true,
+        })));
+        let json = serde_json::to_value(&decode).unwrap();
+        assert_eq!(json["event"], "decode");
+        assert_eq!(json["data"]["type"], "cw");
+        assert_eq!(json["data"]["text"], "CQ");
+        assert!(json.get("success").is_none());
+    }
+
+    #[test]
+    fn test_parse_envelope_subscribe_decode() {
+        let envelope = parse_envelope(r#"{"cmd":"subscribe_decode"}"#).unwrap();
+        assert!(matches!(envelope.cmd, ClientCommand::SubscribeDecode));
+    }
+
     // --- UC-09: filter field serialization tests ---
 
     #[test]
@@ -415,7 +452,7 @@ mod tests {
         assert!(!f.sam_carrier_sync);
     }
 
-    fn minimal_snapshot() -> trx_core::rig::state::RigSnapshot {
+    pub(crate) fn minimal_snapshot() -> trx_core::rig::state::RigSnapshot {
         use trx_core::radio::freq::{Band, Freq};
         use trx_core::rig::state::{RigMode, RigSnapshot};
         use trx_core::rig::{RigAccessMethod, RigCapabilities, RigInfo, RigStatus};
@@ -438,6 +475,7 @@ mod tests {
                     attenuator: false,
                     preamp: false,
                     rit: false,
+                    xit: false,
                     rpt: false,
                     split: false,
                     tx: false,
@@ -445,6 +483,11 @@ mod tests {
                     vfo_switch: false,
                     filter_controls: true,
                     signal_meter: true,
+                    memory_channels: 0,
+                    memory_write: false,
+                    band_stack: false,
+                    ctcss_decode: false,
+                    dcs: false,
                 },
                 access: RigAccessMethod::Tcp {
                     addr: "127.0.0.1:1234".to_string(),
@@ -473,6 +516,15 @@ mod tests {
             cw_auto: false,
             cw_wpm: 0,
             cw_tone_hz: 0,
+            psk_mode: trx_core::decode::PskMode::default(),
+            psk_freq_hz: 0,
+            psk_waterfall: false,
+            sat_tracking: None,
+            rotator: None,
+            cw_keyer: None,
+            memories: Vec::new(),
+            repeater: None,
+            clarifier: None,
             filter: None,
             spectrum: None,
             vchan_rds: None,
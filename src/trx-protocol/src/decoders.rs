@@ -20,7 +20,7 @@ use serde::Serialize;
 pub enum DecoderActivation {
     /// Automatically active when the rig mode matches.
     ModeBound,
-    /// User-controlled toggle; only runs in `active_modes`.
+    /// User-controlled toggle; only runs in `active_modes` (empty = any mode).
     Toggle,
 }
 
@@ -33,7 +33,7 @@ pub struct DecoderDescriptor {
     pub label: &'static str,
     /// How the decoder is activated.
     pub activation: DecoderActivation,
-    /// Rig modes where this decoder operates (upper-case).
+    /// Rig modes where this decoder operates (upper-case; empty = any mode).
     pub active_modes: &'static [&'static str],
     /// Whether the decoder can run on SDR virtual channels
     /// (background-decode / scheduler).
@@ -122,6 +122,46 @@ pub const DECODER_REGISTRY: &[DecoderDescriptor] = &[
         background_decode: true,
         bookmark_selectable: true,
     },
+    DecoderDescriptor {
+        id: "psk",
+        label: "PSK",
+        activation: DecoderActivation::Toggle,
+        active_modes: &["DIG", "USB", "LSB"],
+        background_decode: false,
+        bookmark_selectable: false,
+    },
+    DecoderDescriptor {
+        id: "dsc",
+        label: "DSC",
+        activation: DecoderActivation::Toggle,
+        active_modes: &["FM", "PKT", "DIG", "USB"],
+        background_decode: false,
+        bookmark_selectable: false,
+    },
+    DecoderDescriptor {
+        id: "pocsag",
+        label: "POCSAG",
+        activation: DecoderActivation::Toggle,
+        active_modes: &["FM"],
+        background_decode: true,
+        bookmark_selectable: true,
+    },
+    DecoderDescriptor {
+        id: "adsb",
+        label: "ADS-B",
+        activation: DecoderActivation::Toggle,
+        active_modes: &[],
+        background_decode: false,
+        bookmark_selectable: false,
+    },
+    DecoderDescriptor {
+        id: "acars",
+        label: "ACARS",
+        activation: DecoderActivation::Toggle,
+        active_modes: &["AM"],
+        background_decode: true,
+        bookmark_selectable: true,
+    },
     DecoderDescriptor {
         id: "lrpt",
         label: "Meteor LRPT",
@@ -11,11 +11,19 @@ pub mod auth;
 pub mod codec;
 pub mod decoders;
 pub mod mapping;
+pub mod state_stream;
 pub mod types;
 
 // Re-export commonly used items
-pub use auth::{NoAuthValidator, SimpleTokenValidator, TokenValidator};
+pub use auth::{
+    required_scope, NoAuthValidator, ScopedTokenValidator, SimpleTokenValidator, TokenGrant,
+    TokenValidator,
+};
 pub use codec::{mode_to_string, parse_envelope, parse_mode};
 pub use decoders::{DecoderActivation, DecoderDescriptor, DECODER_REGISTRY};
-pub use mapping::{client_command_to_rig, rig_command_to_client};
-pub use types::{ClientCommand, ClientEnvelope, ClientResponse, MeterUpdate, RigEntry};
+pub use mapping::{client_command_to_rig, is_client_only, rig_command_to_client};
+pub use state_stream::{StateDecoder, StateEncoder};
+pub use types::{
+    ClientCommand, ClientEnvelope, ClientResponse, MeterUpdate, PushEvent, RigEntry, Scope,
+    ScopeDenial, StatePayload, StateUpdate,
+};
@@ -10,9 +10,9 @@ use trx_core::rig::command::RigCommand;
 use crate::codec::{mode_to_string, parse_mode};
 use crate::types::ClientCommand;
 
-/// Generates `client_command_to_rig` and `rig_command_to_client` from a
-/// single definition table, eliminating the mechanical duplication of
-/// mapping every variant by hand.
+/// Generates `client_command_to_rig`, `rig_command_to_client` and
+/// `is_client_only` from a single definition table, eliminating the
+/// mechanical duplication of mapping every variant by hand.
 ///
 /// Supported row forms (each section is introduced by a keyword):
 ///
@@ -77,6 +77,12 @@ macro_rules! define_command_mapping {
             }
         }
 
+        /// `true` for commands that [`client_command_to_rig`] cannot map and
+        /// the caller has to answer itself.
+        pub fn is_client_only(cmd: &ClientCommand) -> bool {
+            matches!(cmd, $( ClientCommand::$co )|*)
+        }
+
         /// Convert a [`RigCommand`] back to a [`ClientCommand`].
         ///
         /// This is the inverse of [`client_command_to_rig`], converting
@@ -102,7 +108,7 @@ macro_rules! define_command_mapping {
 
 define_command_mapping! {
     // ── Client-only variants (no RigCommand counterpart) ─────────────
-    client_only: GetRigs, GetSatPasses, SubscribeMeter;
+    client_only: GetRigs, GetSatPasses, SubscribeMeter, SubscribeState, SubscribeDecode;
 
     // ── Unit variants (no payload) ───────────────────────────────────
     unit:
@@ -117,12 +123,21 @@ define_command_mapping! {
         ResetAprsDecoder     <=> ResetAprsDecoder,
         ResetHfAprsDecoder   <=> ResetHfAprsDecoder,
         ResetCwDecoder       <=> ResetCwDecoder,
+        ResetPskDecoder      <=> ResetPskDecoder,
+        ResetDscDecoder      <=> ResetDscDecoder,
+        ResetPocsagDecoder   <=> ResetPocsagDecoder,
+        ResetAdsbDecoder     <=> ResetAdsbDecoder,
+        ResetAcarsDecoder    <=> ResetAcarsDecoder,
         ResetFt8Decoder      <=> ResetFt8Decoder,
         ResetFt4Decoder      <=> ResetFt4Decoder,
         ResetFt2Decoder      <=> ResetFt2Decoder,
         ResetWsprDecoder     <=> ResetWsprDecoder,
         ResetLrptDecoder     <=> ResetLrptDecoder,
-        ResetWefaxDecoder    <=> ResetWefaxDecoder;
+        ResetWefaxDecoder    <=> ResetWefaxDecoder,
+        StopSatTracking      <=> StopSatTracking,
+        StopRotator          <=> StopRotator,
+        StopRotatorTracking  <=> StopRotatorTracking,
+        AbortCw              <=> AbortCw;
 
     // ── Single-field struct <=> tuple ────────────────────────────────
     field:
@@ -134,6 +149,14 @@ define_command_mapping! {
         SetCwAuto             { enabled }        <=> SetCwAuto,
         SetCwWpm              { wpm }            <=> SetCwWpm,
         SetCwToneHz           { tone_hz }        <=> SetCwToneHz,
+        SetPskDecodeEnabled   { enabled }        <=> SetPskDecodeEnabled,
+        SetPskMode            { mode }           <=> SetPskMode,
+        SetPskFreqHz          { freq_hz }        <=> SetPskFreqHz,
+        SetPskWaterfall       { enabled }        <=> SetPskWaterfall,
+        SetDscDecodeEnabled   { enabled }        <=> SetDscDecodeEnabled,
+        SetPocsagDecodeEnabled { enabled }       <=> SetPocsagDecodeEnabled,
+        SetAdsbDecodeEnabled  { enabled }        <=> SetAdsbDecodeEnabled,
+        SetAcarsDecodeEnabled { enabled }        <=> SetAcarsDecodeEnabled,
         SetFt8DecodeEnabled   { enabled }        <=> SetFt8DecodeEnabled,
         SetFt4DecodeEnabled   { enabled }        <=> SetFt4DecodeEnabled,
         SetFt2DecodeEnabled   { enabled }        <=> SetFt2DecodeEnabled,
@@ -149,12 +172,29 @@ define_command_mapping! {
         SetWfmDenoise         { level }          <=> SetWfmDenoise,
         SetSamStereoWidth     { width }          <=> SetSamStereoWidth,
         SetSamCarrierSync     { enabled }        <=> SetSamCarrierSync,
-        SetRecorderEnabled    { enabled }        <=> SetRecorderEnabled;
+        SetRecorderEnabled    { enabled }        <=> SetRecorderEnabled,
+        SetCwSerial           { serial }         <=> SetCwSerial,
+        WriteMemory           { memory }         <=> WriteMemory,
+        RecallMemory          { channel }        <=> RecallMemory,
+        SetRepeaterOffset     { offset_hz }      <=> SetRepeaterOffset,
+        SetRepeaterReverse    { enabled }        <=> SetRepeaterReverse,
+        SetDcs                { code }           <=> SetDcs,
+        SetRit                { offset_hz }      <=> SetRit,
+        SetXit                { offset_hz }      <=> SetXit,
+        LoadTles              { tle }            <=> LoadTles;
 
     // ── Multi-field struct passthrough ───────────────────────────────
     multi:
         SetSdrSquelch      { enabled, threshold_db } <=> SetSdrSquelch,
-        SetSdrNoiseBlanker { enabled, threshold }    <=> SetSdrNoiseBlanker;
+        SetSdrNoiseBlanker { enabled, threshold }    <=> SetSdrNoiseBlanker,
+        StartSatTracking   { norad_id, transponder, downlink_hz } <=> StartSatTracking,
+        SetRotatorPosition { azimuth_deg, elevation_deg } <=> SetRotatorPosition,
+        StartRotatorTracking { norad_id } <=> StartRotatorTracking,
+        SendCw             { text, call } <=> SendCw,
+        SetCwKeyer         { wpm, weight, farnsworth_wpm } <=> SetCwKeyer,
+        ReadMemories       { first, count } <=> ReadMemories,
+        RecallBandStack    { freq_hz } <=> RecallBandStack,
+        SetCtcss           { tone_hz, decode } <=> SetCtcss;
 
     // ── Freq conversions (u64 <=> Freq) ──────────────────────────────
     freq:
@@ -169,6 +209,7 @@ define_command_mapping! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use trx_core::decode::PskMode;
     use trx_core::rig::state::RigMode;
 
     #[test]
@@ -181,6 +222,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_client_only() {
+        assert!(is_client_only(&ClientCommand::GetRigs));
+        assert!(is_client_only(&ClientCommand::SubscribeDecode));
+        assert!(!is_client_only(&ClientCommand::GetState));
+        assert!(!is_client_only(&ClientCommand::SetFreq {
+            freq_hz: 7_074_000
+        }));
+    }
+
     #[test]
     fn test_client_command_to_rig_set_freq() {
         let cmd = ClientCommand::SetFreq { freq_hz: 14100000 };
@@ -345,6 +396,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_client_command_to_rig_set_psk_mode() {
+        let cmd = ClientCommand::SetPskMode {
+            mode: PskMode::Qpsk31,
+        };
+        if let RigCommand::SetPskMode(mode) = client_command_to_rig(cmd) {
+            assert_eq!(mode, PskMode::Qpsk31);
+        } else {
+            panic!("Expected SetPskMode");
+        }
+    }
+
     #[test]
     fn test_client_command_to_rig_set_ft8_decode_enabled() {
         let cmd = ClientCommand::SetFt8DecodeEnabled { enabled: true };
@@ -575,6 +638,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rig_command_to_client_set_psk_freq_hz() {
+        let cmd = RigCommand::SetPskFreqHz(1500);
+        if let ClientCommand::SetPskFreqHz { freq_hz } = rig_command_to_client(cmd) {
+            assert_eq!(freq_hz, 1500);
+        } else {
+            panic!("Expected SetPskFreqHz");
+        }
+    }
+
     #[test]
     fn test_rig_command_to_client_set_ft8_decode_enabled() {
         let cmd = RigCommand::SetFt8DecodeEnabled(true);
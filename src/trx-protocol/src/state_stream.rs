@@ -0,0 +1,296 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Encoding and decoding of `SubscribeState` push streams.
+//!
+//! The server sends a full snapshot first and then JSON merge patches
+//! (RFC 7386) against the previously sent state.  Every frame carries a
+//! sequence number.  A client that sees a gap drops the base state and
+//! resubscribes; the server also repeats a full snapshot every
+//! [`STATE_RESYNC_INTERVAL`] frames.
+//!
+//! An idle stream carries an empty line every [`STATE_KEEPALIVE_INTERVAL`].
+//! A client that hears nothing for [`STATE_IDLE_TIMEOUT`] treats the
+//! connection as dead and resubscribes.
+
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+
+use trx_core::rig::state::RigSnapshot;
+
+use crate::types::{StatePayload, StateUpdate};
+
+/// Number of frames between two full snapshots on a state stream.
+pub const STATE_RESYNC_INTERVAL: u64 = 64;
+
+/// Idle time after which the server writes an empty line on a state stream.
+pub const STATE_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Silence after which a client drops a state stream and resubscribes.
+pub const STATE_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Server side of a state stream: turns successive snapshots into frames.
+#[derive(Debug, Default)]
+pub struct StateEncoder {
+    seq: u64,
+    last: Option<Value>,
+}
+
+impl StateEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encode `snapshot` for `rig_id`.  Returns `None` when nothing changed
+    /// since the previous frame.
+    pub fn encode(&mut self, rig_id: &str, snapshot: &RigSnapshot) -> Option<StateUpdate> {
+        let value = serde_json::to_value(snapshot).ok()?;
+        if self.last.as_ref() == Some(&value) {
+            return None;
+        }
+        let payload = match &self.last {
+            Some(last) if !(self.seq + 1).is_multiple_of(STATE_RESYNC_INTERVAL) => {
+                StatePayload::Diff {
+                    patch: merge_patch_diff(last, &value)?,
+                }
+            }
+            _ => StatePayload::Full {
+                state: Box::new(snapshot.clone()),
+            },
+        };
+        self.seq += 1;
+        self.last = Some(value);
+        Some(StateUpdate {
+            rig_id: rig_id.to_string(),
+            seq: self.seq,
+            payload,
+        })
+    }
+}
+
+/// Client side of a state stream: applies frames and reports gaps.
+#[derive(Debug, Default)]
+pub struct StateDecoder {
+    seq: Option<u64>,
+    last: Option<Value>,
+}
+
+impl StateDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply one frame and return the resulting snapshot.
+    ///
+    /// A diff that does not directly follow the last applied frame is
+    /// rejected and the decoder waits for the next full snapshot.
+    pub fn apply(&mut self, update: StateUpdate) -> Result<RigSnapshot, String> {
+        match update.payload {
+            StatePayload::Full { state } => {
+                let value = serde_json::to_value(&state).map_err(|e| e.to_string())?;
+                self.seq = Some(update.seq);
+                self.last = Some(value);
+                Ok(*state)
+            }
+            StatePayload::Diff { patch } => {
+                let expected = self.seq.map(|seq| seq + 1);
+                let mut value = match &self.last {
+                    Some(last) if expected == Some(update.seq) => last.clone(),
+                    _ => {
+                        self.reset();
+                        return Err(format!(
+                            "state diff {} out of sequence (expected {:?})",
+                            update.seq, expected
+                        ));
+                    }
+                };
+                apply_merge_patch(&mut value, &patch);
+                match serde_json::from_value(value.clone()) {
+                    Ok(snapshot) => {
+                        self.seq = Some(update.seq);
+                        self.last = Some(value);
+                        Ok(snapshot)
+                    }
+                    Err(e) => {
+                        self.reset();
+                        Err(format!(
+                            "state diff {} produced an invalid snapshot: {e}",
+                            update.seq
+                        ))
+                    }
+                }
+            }
+        }
+    }
+
+    /// `true` while waiting for a full snapshot.
+    pub fn needs_resync(&self) -> bool {
+        self.last.is_none()
+    }
+
+    fn reset(&mut self) {
+        self.seq = None;
+        self.last = None;
+    }
+}
+
+/// Build a merge patch that turns `old` into `new`.  Returns `None` when the
+/// values are equal.
+pub fn merge_patch_diff(old: &Value, new: &Value) -> Option<Value> {
+    if old == new {
+        return None;
+    }
+    let (Value::Object(old), Value::Object(new)) = (old, new) else {
+        return Some(new.clone());
+    };
+    let mut patch = Map::new();
+    for (key, new_value) in new {
+        match old.get(key) {
+            // Recurse only when both sides are objects; scalars, arrays and
+            // type changes are replaced wholesale.
+            Some(old_value) if old_value.is_object() && new_value.is_object() => {
+                if let Some(nested) = merge_patch_diff(old_value, new_value) {
+                    patch.insert(key.clone(), nested);
+                }
+            }
+            Some(old_value) if old_value == new_value => {}
+            _ => {
+                patch.insert(key.clone(), new_value.clone());
+            }
+        }
+    }
+    for key in old.keys() {
+        if !new.contains_key(key) {
+            patch.insert(key.clone(), Value::Null);
+        }
+    }
+    Some(Value::Object(patch))
+}
+
+/// Apply an RFC 7386 merge patch to `target` in place.
+pub fn apply_merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(target) = target else {
+        return;
+    };
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            apply_merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::tests::minimal_snapshot;
+    use serde_json::json;
+
+    #[test]
+    fn merge_patch_roundtrip() {
+        let old = json!({"a": 1, "b": {"c": 2, "d": 3}, "e": [1, 2], "f": "x"});
+        let new = json!({"a": 1, "b": {"c": 5, "d": 3}, "e": [1], "g": null});
+        let patch = merge_patch_diff(&old, &new).unwrap();
+        assert_eq!(
+            patch,
+            json!({"b": {"c": 5}, "e": [1], "g": null, "f": null})
+        );
+
+        let mut applied = old.clone();
+        apply_merge_patch(&mut applied, &patch);
+        // `g: null` is removed by the patch, which deserializes the same as
+        // an absent optional field.
+        assert_eq!(applied, json!({"a": 1, "b": {"c": 5, "d": 3}, "e": [1]}));
+        assert_eq!(merge_patch_diff(&new, &new), None);
+    }
+
+    #[test]
+    fn encoder_sends_full_then_diffs() {
+        let mut encoder = StateEncoder::new();
+        let mut decoder = StateDecoder::new();
+        let mut snapshot = minimal_snapshot();
+
+        let first = encoder.encode("hf", &snapshot).unwrap();
+        assert_eq!(first.seq, 1);
+        assert!(matches!(first.payload, StatePayload::Full { .. }));
+        assert_eq!(decoder.apply(first).unwrap(), snapshot);
+        assert!(encoder.encode("hf", &snapshot).is_none());
+
+        snapshot.status.freq.hz = 7_074_000;
+        snapshot.server_callsign = Some("N0CALL".to_string());
+        let second = encoder.encode("hf", &snapshot).unwrap();
+        assert_eq!(second.seq, 2);
+        let StatePayload::Diff { patch } = &second.payload else {
+            panic!("expected diff");
+        };
+        assert_eq!(
+            patch,
+            &json!({"status": {"freq": {"hz": 7_074_000}}, "server_callsign": "N0CALL"})
+        );
+        let wire = serde_json::to_value(&second).unwrap();
+        assert_eq!(wire["kind"], "diff");
+        assert_eq!(wire["rig_id"], "hf");
+        assert_eq!(decoder.apply(second).unwrap(), snapshot);
+
+        snapshot.server_callsign = None;
+        let third = encoder.encode("hf", &snapshot).unwrap();
+        assert_eq!(decoder.apply(third).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn encoder_resends_full_snapshot_periodically() {
+        let mut encoder = StateEncoder::new();
+        let mut snapshot = minimal_snapshot();
+        let mut full_seqs = Vec::new();
+        for hz in 0..(STATE_RESYNC_INTERVAL * 2) {
+            snapshot.status.freq.hz = 14_000_000 + hz;
+            let update = encoder.encode("hf", &snapshot).unwrap();
+            if matches!(update.payload, StatePayload::Full { .. }) {
+                full_seqs.push(update.seq);
+            }
+        }
+        assert_eq!(
+            full_seqs,
+            vec![1, STATE_RESYNC_INTERVAL, STATE_RESYNC_INTERVAL * 2]
+        );
+    }
+
+    #[test]
+    fn decoder_rejects_gaps_until_resync() {
+        let mut encoder = StateEncoder::new();
+        let mut decoder = StateDecoder::new();
+        let mut snapshot = minimal_snapshot();
+
+        decoder
+            .apply(encoder.encode("hf", &snapshot).unwrap())
+            .unwrap();
+        snapshot.status.freq.hz += 1;
+        let _lost = encoder.encode("hf", &snapshot).unwrap();
+        snapshot.status.freq.hz += 1;
+        let err = decoder
+            .apply(encoder.encode("hf", &snapshot).unwrap())
+            .unwrap_err();
+        assert!(err.contains("out of sequence"));
+        assert!(decoder.needs_resync());
+
+        let full = StateUpdate {
+            rig_id: "hf".to_string(),
+            seq: 10,
+            payload: StatePayload::Full {
+                state: Box::new(snapshot.clone()),
+            },
+        };
+        assert_eq!(decoder.apply(full).unwrap(), snapshot);
+        assert!(!decoder.needs_resync());
+    }
+}
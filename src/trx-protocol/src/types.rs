@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use trx_core::decode::{DecodedMessage, PskMode};
+use trx_core::rig::memory::RigMemory;
 use trx_core::rig::state::RigSnapshot;
 use trx_core::WfmDenoiseLevel;
 
@@ -55,6 +57,30 @@ pub enum ClientCommand {
     SetCwToneHz {
         tone_hz: u32,
     },
+    SetPskDecodeEnabled {
+        enabled: bool,
+    },
+    SetPskMode {
+        mode: PskMode,
+    },
+    SetPskFreqHz {
+        freq_hz: u32,
+    },
+    SetPskWaterfall {
+        enabled: bool,
+    },
+    SetDscDecodeEnabled {
+        enabled: bool,
+    },
+    SetPocsagDecodeEnabled {
+        enabled: bool,
+    },
+    SetAdsbDecodeEnabled {
+        enabled: bool,
+    },
+    SetAcarsDecodeEnabled {
+        enabled: bool,
+    },
     SetFt8DecodeEnabled {
         enabled: bool,
     },
@@ -76,6 +102,11 @@ pub enum ClientCommand {
     ResetAprsDecoder,
     ResetHfAprsDecoder,
     ResetCwDecoder,
+    ResetPskDecoder,
+    ResetDscDecoder,
+    ResetPocsagDecoder,
+    ResetAdsbDecoder,
+    ResetAcarsDecoder,
     ResetFt8Decoder,
     ResetFt4Decoder,
     ResetFt2Decoder,
@@ -120,12 +151,91 @@ pub enum ClientCommand {
     SetRecorderEnabled {
         enabled: bool,
     },
+    StartSatTracking {
+        norad_id: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        transponder: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        downlink_hz: Option<u64>,
+    },
+    StopSatTracking,
+    SetRotatorPosition {
+        azimuth_deg: f64,
+        elevation_deg: f64,
+    },
+    StopRotator,
+    StartRotatorTracking {
+        norad_id: u32,
+    },
+    StopRotatorTracking,
+    SendCw {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        call: Option<String>,
+    },
+    AbortCw,
+    SetCwKeyer {
+        wpm: u32,
+        weight: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        farnsworth_wpm: Option<u32>,
+    },
+    SetCwSerial {
+        serial: u32,
+    },
+    ReadMemories {
+        first: u16,
+        count: u16,
+    },
+    WriteMemory {
+        memory: RigMemory,
+    },
+    RecallMemory {
+        channel: u16,
+    },
+    RecallBandStack {
+        freq_hz: u64,
+    },
+    SetRepeaterOffset {
+        offset_hz: i32,
+    },
+    SetRepeaterReverse {
+        enabled: bool,
+    },
+    SetCtcss {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tone_hz: Option<f32>,
+        #[serde(default)]
+        decode: bool,
+    },
+    SetDcs {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<u16>,
+    },
+    SetRit {
+        offset_hz: i32,
+    },
+    SetXit {
+        offset_hz: i32,
+    },
+    LoadTles {
+        tle: String,
+    },
     GetSpectrum,
     /// Subscribe to a per-rig meter stream on this connection.  After the
     /// server receives this command, the connection becomes a one-way flow of
     /// newline-delimited `MeterUpdate` JSON frames and no further commands or
     /// regular responses are sent.  Intended for a dedicated TCP connection.
     SubscribeMeter,
+    /// Subscribe to per-rig state pushes on this connection.  Like
+    /// `SubscribeMeter`, the connection becomes a one-way flow of
+    /// newline-delimited `StateUpdate` frames.  Requires protocol version
+    /// [`STATE_PUSH_VERSION`] on the server.
+    SubscribeState,
+    /// Subscribe to decoded messages.  Only served by the client's WebSocket
+    /// endpoint, which pushes them as [`PushEvent::Decode`] frames; the
+    /// server delivers decodes on the audio connection instead.
+    SubscribeDecode,
 }
 
 /// Fast meter sample pushed by the server on a dedicated meter stream.
@@ -141,6 +251,41 @@ pub struct MeterUpdate {
     pub ts_ms: u64,
 }
 
+/// State frame pushed by the server on a `SubscribeState` stream.
+///
+/// See [`crate::state_stream`] for how frames are produced and applied.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StateUpdate {
+    /// Rig identifier this frame belongs to.
+    pub rig_id: String,
+    /// Frame counter, starting at 1 and incremented by one per frame.
+    pub seq: u64,
+    #[serde(flatten)]
+    pub payload: StatePayload,
+}
+
+/// Body of a [`StateUpdate`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StatePayload {
+    /// Complete snapshot; replaces whatever the client held.
+    Full { state: Box<RigSnapshot> },
+    /// JSON merge patch (RFC 7386) against the state after frame `seq - 1`.
+    Diff { patch: serde_json::Value },
+}
+
+/// Event frame pushed on a WebSocket connection next to regular responses.
+///
+/// Serialized as `{"event": "state" | "meter" | "decode", "data": {...}}`.
+/// Responses never carry an `event` field, so clients can tell the two apart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum PushEvent {
+    State(StateUpdate),
+    Meter(MeterUpdate),
+    Decode(Box<DecodedMessage>),
+}
+
 /// Envelope for client commands with optional authentication token and rig routing.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ClientEnvelope {
@@ -149,7 +294,7 @@ pub struct ClientEnvelope {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rig_id: Option<String>,
     /// Protocol version advertised by the client. Absent for legacy clients.
-    /// Current version: 1.
+    /// Current version: 2.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub protocol_version: Option<u32>,
     #[serde(flatten)]
@@ -157,7 +302,13 @@ pub struct ClientEnvelope {
 }
 
 /// Current protocol version.
-pub const PROTOCOL_VERSION: u32 = 1;
+///
+/// - 1: request/response commands and `SubscribeMeter`.
+/// - 2: `SubscribeState` push streams.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// First protocol version that supports `SubscribeState`.
+pub const STATE_PUSH_VERSION: u32 = 2;
 
 /// One entry in the GetRigs response: a rig's ID and its current snapshot.
 #[derive(Debug, Serialize, Deserialize)]
@@ -188,5 +339,68 @@ pub struct ClientResponse {
     /// Populated only for GetSatPasses responses.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sat_passes: Option<trx_core::geo::PassPredictionResult>,
+    /// Set when the token lacks the scope the command needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denied: Option<ScopeDenial>,
     pub error: Option<String>,
 }
+
+/// Access level of an auth token on a rig.  Each scope includes the ones
+/// before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    /// Read state, spectrum, meters and satellite passes.
+    Read,
+    /// Tune, change modes, decoders, filters and other receive settings.
+    Control,
+    /// Key the transmitter (PTT, CW) and change the TX limit.
+    Tx,
+    /// Power the rig on or off, write memories, record and load TLEs.
+    Admin,
+}
+
+impl Scope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Control => "control",
+            Self::Tx => "tx",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Scope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read" => Ok(Self::Read),
+            "control" => Ok(Self::Control),
+            "tx" => Ok(Self::Tx),
+            "admin" => Ok(Self::Admin),
+            other => Err(format!(
+                "unknown scope '{other}' (expected read, control, tx or admin)"
+            )),
+        }
+    }
+}
+
+/// Why a command was refused, so clients can hide controls the token may
+/// not use.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeDenial {
+    /// Scope the command needs.
+    pub required: Scope,
+    /// Scope the token holds on the target rig; absent when the token has
+    /// no access to that rig.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub granted: Option<Scope>,
+}
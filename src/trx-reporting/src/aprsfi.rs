@@ -13,6 +13,7 @@ use tokio::time::{self, Duration, Instant};
 use tracing::{debug, info, warn};
 
 use trx_core::decode::{AprsPacket, DecodedMessage};
+use trx_core::metrics::UplinkMetrics;
 
 use crate::AprsFiConfig;
 
@@ -105,12 +106,14 @@ fn format_beacon(callsign: &str, lat: f64, lon: f64, sym_table: char, sym_code:
 /// Subscribes to the decoded-message broadcast channel and forwards every
 /// CRC-valid APRS packet to the configured APRS-IS server as a TNC2 line.
 /// Reconnects automatically with exponential backoff (1 s → 2 s → … → 60 s).
+/// `metrics` tracks the login state, forwarded packets and failures.
 pub async fn run_aprsfi_uplink(
     cfg: AprsFiConfig,
     callsign: String,
     latitude: Option<f64>,
     longitude: Option<f64>,
     mut decode_rx: broadcast::Receiver<DecodedMessage>,
+    metrics: UplinkMetrics,
 ) {
     let passcode: u16 = if cfg.passcode == -1 {
         compute_passcode(&callsign)
@@ -169,6 +172,7 @@ pub async fn run_aprsfi_uplink(
                 time::sleep(Duration::from_secs(backoff_secs)).await;
                 backoff_secs = (backoff_secs * 2).min(60);
                 stats_reconnects += 1;
+                metrics.errors.inc();
                 continue 'reconnect;
             }
         };
@@ -198,6 +202,7 @@ pub async fn run_aprsfi_uplink(
             time::sleep(Duration::from_secs(backoff_secs)).await;
             backoff_secs = (backoff_secs * 2).min(60);
             stats_reconnects += 1;
+            metrics.errors.inc();
             continue 'reconnect;
         }
 
@@ -242,6 +247,7 @@ pub async fn run_aprsfi_uplink(
             time::sleep(Duration::from_secs(backoff_secs)).await;
             backoff_secs = (backoff_secs * 2).min(60);
             stats_reconnects += 1;
+            metrics.errors.inc();
             continue 'reconnect;
         }
 
@@ -255,6 +261,7 @@ pub async fn run_aprsfi_uplink(
 
         // Successful connection — reset backoff
         backoff_secs = 1;
+        metrics.up.set(1);
 
         // ----------------------------------------------------------------
         // Forward loop
@@ -274,6 +281,7 @@ pub async fn run_aprsfi_uplink(
                     if let Err(e) = write_half.write_all(b"# trx-rs keepalive\r\n").await {
                         warn!("APRS-IS IGate: keepalive write failed: {}", e);
                         stats_write_errors += 1;
+                        metrics.errors.inc();
                         break 'forward;
                     }
                 }
@@ -291,6 +299,7 @@ pub async fn run_aprsfi_uplink(
                         if let Err(e) = write_half.write_all(pkt.as_bytes()).await {
                             warn!("APRS-IS IGate: beacon write failed: {}", e);
                             stats_write_errors += 1;
+                            metrics.errors.inc();
                             break 'forward;
                         }
                         debug!("APRS-IS IGate: sent position beacon");
@@ -344,9 +353,11 @@ pub async fn run_aprsfi_uplink(
                             if let Err(e) = write_half.write_all(tnc2.as_bytes()).await {
                                 warn!("APRS-IS IGate: packet write failed: {}", e);
                                 stats_write_errors += 1;
+                                metrics.errors.inc();
                                 break 'forward;
                             }
                             stats_forwarded += 1;
+                            metrics.sent.inc();
                         }
                         Ok(_) => {
                             // Non-APRS messages (FT8, WSPR, CW) are silently skipped
@@ -355,6 +366,7 @@ pub async fn run_aprsfi_uplink(
                             warn!("APRS-IS IGate: dropped {} decode events (channel lagged)", n);
                         }
                         Err(broadcast::error::RecvError::Closed) => {
+                            metrics.up.set(0);
                             return;
                         }
                     }
@@ -363,6 +375,7 @@ pub async fn run_aprsfi_uplink(
         }
 
         // Forward loop exited due to a write error or server EOF — reconnect with backoff
+        metrics.up.set(0);
         stats_reconnects += 1;
         warn!(
             "APRS-IS IGate: disconnected from {}:{}, reconnecting in {}s",
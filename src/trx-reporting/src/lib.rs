@@ -2,11 +2,16 @@
 //
 // SPDX-License-Identifier: BSD-2-Clause
 
-//! Reporting uplink tasks: PSK Reporter and APRS-IS IGate.
+//! Reporting uplink tasks: PSK Reporter, APRS-IS IGate and MQTT, plus the
+//! Prometheus `/metrics` endpoint.
 
 pub mod aprsfi;
+pub mod metrics;
+pub mod mqtt;
 pub mod pskreporter;
 
+use std::net::IpAddr;
+
 use serde::{Deserialize, Serialize};
 
 /// PSK Reporter uplink configuration.
@@ -81,3 +86,74 @@ impl Default for AprsFiConfig {
         }
     }
 }
+
+/// MQTT publisher configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MqttConfig {
+    /// Whether the MQTT publisher is enabled
+    pub enabled: bool,
+    /// Broker hostname
+    pub host: String,
+    /// Broker TCP port
+    pub port: u16,
+    /// MQTT client identifier; also prefixes Home Assistant unique IDs
+    pub client_id: String,
+    /// Broker username (optional)
+    pub username: Option<String>,
+    /// Broker password, sent together with `username`
+    pub password: Option<String>,
+    /// Topic prefix. Rig topics are `<prefix>/<rig_id>/...`.
+    pub topic_prefix: String,
+    /// Minimum time between two state publishes of one rig, in milliseconds.
+    pub state_interval_ms: u64,
+    /// Minimum time between two meter publishes of one rig, in milliseconds.
+    pub meter_interval_ms: u64,
+    /// Publish Home Assistant MQTT discovery payloads.
+    pub discovery: bool,
+    /// Home Assistant discovery topic prefix.
+    pub discovery_prefix: String,
+    /// Accept JSON commands on `<prefix>/<rig_id>/command`.
+    pub commands: bool,
+}
+
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "localhost".to_string(),
+            port: 1883,
+            client_id: "trx-server".to_string(),
+            username: None,
+            password: None,
+            topic_prefix: "trx".to_string(),
+            state_interval_ms: 1000,
+            meter_interval_ms: 1000,
+            discovery: true,
+            discovery_prefix: "homeassistant".to_string(),
+            commands: false,
+        }
+    }
+}
+
+/// Prometheus `/metrics` endpoint configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Whether the metrics endpoint is enabled
+    pub enabled: bool,
+    /// IP address to listen on
+    pub listen: IpAddr,
+    /// TCP port to listen on
+    pub port: u16,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen: IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+            port: 9464,
+        }
+    }
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Prometheus `/metrics` endpoint.
+//!
+//! A deliberately small HTTP/1.1 responder: every connection carries one
+//! request and is closed after the response.  `GET /metrics` renders the
+//! shared [`Registry`]; any other path is a 404.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::time::{self, Duration};
+use tracing::{debug, info};
+
+use trx_core::decode::DecodedMessage;
+use trx_core::metrics::{Counter, Registry, RigMetrics};
+
+/// Time allowed for a scraper to send its request head.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Request head lines read before the request is answered anyway.
+const MAX_HEADER_LINES: usize = 64;
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Serve `registry` on `addr` until the task is dropped.
+pub async fn run_metrics_server(addr: SocketAddr, registry: Arc<Registry>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("Metrics endpoint on http://{}/metrics", addr);
+    loop {
+        let (socket, peer) = listener.accept().await?;
+        let registry = registry.clone();
+        tokio::spawn(async move {
+            if let Err(e) = serve_scrape(socket, &registry).await {
+                debug!("Metrics request from {} failed: {}", peer, e);
+            }
+        });
+    }
+}
+
+async fn serve_scrape(socket: TcpStream, registry: &Registry) -> std::io::Result<()> {
+    let (reader, mut writer) = socket.into_split();
+    let mut reader = BufReader::new(reader);
+    let mut request_line = String::new();
+    let head = time::timeout(REQUEST_TIMEOUT, async {
+        reader.read_line(&mut request_line).await?;
+        // Drain the headers so the peer does not see a reset before reading
+        // the response.
+        let mut header = String::new();
+        for _ in 0..MAX_HEADER_LINES {
+            header.clear();
+            if reader.read_line(&mut header).await? == 0 || header.trim().is_empty() {
+                break;
+            }
+        }
+        Ok::<_, std::io::Error>(())
+    })
+    .await;
+    match head {
+        Ok(result) => result?,
+        Err(_) => return Ok(()),
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+    let path = path.split('?').next().unwrap_or(path);
+    let (status, content_type, body) = match (method, path) {
+        ("GET", "/metrics") => ("200 OK", CONTENT_TYPE, registry.render()),
+        ("GET", _) => ("404 Not Found", "text/plain", "not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_string(),
+        ),
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    writer.write_all(response.as_bytes()).await?;
+    writer.shutdown().await
+}
+
+/// Count every message on a rig's decode channel into
+/// `trx_decoder_messages_total`, labelled by message type.
+pub async fn count_decodes(
+    metrics: Arc<RigMetrics>,
+    mut decode_rx: broadcast::Receiver<DecodedMessage>,
+) {
+    let lag = metrics.broadcast_lag("decode");
+    let mut counters: HashMap<&'static str, Arc<Counter>> = HashMap::new();
+    loop {
+        match decode_rx.recv().await {
+            Ok(msg) => {
+                let kind = msg.kind();
+                counters
+                    .entry(kind)
+                    .or_insert_with(|| metrics.decoded(kind))
+                    .inc();
+            }
+            Err(broadcast::error::RecvError::Lagged(n)) => lag.add(n),
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::AsyncReadExt;
+
+    use trx_core::decode::CwEvent;
+
+    async fn fetch(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.expect("connect");
+        stream.write_all(request.as_bytes()).await.expect("write");
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .await
+            .expect("read response");
+        response
+    }
+
+    #[tokio::test]
+    async fn serves_registry_on_metrics_path() {
+        let registry = Registry::new();
+        let metrics = RigMetrics::new(registry.clone(), "hf");
+        metrics.iq_read_errors.add(3);
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let server = tokio::spawn(run_metrics_server(addr, registry));
+        // Wait for the server to rebind the port.
+        let mut ready = false;
+        for _ in 0..50 {
+            if TcpStream::connect(addr).await.is_ok() {
+                ready = true;
+                break;
+            }
+            time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(ready, "metrics server did not start");
+
+        let response = fetch(addr, "GET /metrics HTTP/1.1\r\nHost: trx\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+        assert!(response.contains(CONTENT_TYPE));
+        assert!(response.contains("trx_iq_read_errors_total{rig=\"hf\"} 3\n"));
+
+        let response = fetch(addr, "GET / HTTP/1.1\r\n\r\n").await;
+        assert!(
+            response.starts_with("HTTP/1.1 404 Not Found\r\n"),
+            "{response}"
+        );
+        let response = fetch(addr, "POST /metrics HTTP/1.1\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 405"), "{response}");
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn counts_decodes_by_type() {
+        let registry = Registry::new();
+        let metrics = Arc::new(RigMetrics::new(registry.clone(), "hf"));
+        let (decode_tx, decode_rx) = broadcast::channel(8);
+        let task = tokio::spawn(count_decodes(metrics.clone(), decode_rx));
+        for _ in 0..2 {
+            decode_tx
+                .send(DecodedMessage::Cw(CwEvent {
+                    rig_id: None,
+                    text: "CQ".to_string(),
+                    wpm: 18,
+                    tone_hz: 600,
+                    signal_on: true,
+                }))
+                .unwrap();
+        }
+        drop(decode_tx);
+        task.await.unwrap();
+        assert_eq!(metrics.decoded("cw").get(), 2);
+        assert!(registry
+            .render()
+            .contains("trx_decoder_messages_total{decoder=\"cw\",rig=\"hf\"} 2\n"));
+    }
+}
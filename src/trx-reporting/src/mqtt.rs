@@ -0,0 +1,778 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! MQTT publisher — rig state, throttled meter samples and decoded messages
+//! as JSON, Home Assistant discovery, and an optional command topic.
+//!
+//! Topics, for a prefix of `trx`:
+//!
+//! - `trx/status` — `online` / `offline` (retained, last will)
+//! - `trx/<rig_id>/state` — `RigSnapshot` (retained)
+//! - `trx/<rig_id>/meter` — `MeterUpdate`
+//! - `trx/<rig_id>/decode/<type>` — `DecodedMessage`, e.g. `decode/ft8`
+//! - `trx/<rig_id>/command` — `ClientEnvelope` JSON in, answered on
+//!   `trx/<rig_id>/command/result` with a `ClientResponse`
+//!
+//! Commands carry a token and are checked against the control port's
+//! tokens and scopes, since anyone who can publish to the broker can reach
+//! the command topic.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use rumqttc::{AsyncClient, Event, LastWill, MqttOptions, Packet, QoS};
+use serde_json::{json, Value};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+use tokio::task::JoinSet;
+use tokio::time::{self, Duration, Instant};
+use tracing::{debug, info, warn};
+
+use trx_core::decode::DecodedMessage;
+use trx_core::rig::request::RigRequest;
+use trx_core::rig::state::RigState;
+use trx_protocol::auth::{required_scope, ScopedTokenValidator};
+use trx_protocol::{
+    client_command_to_rig, is_client_only, parse_envelope, ClientResponse, MeterUpdate,
+};
+
+use crate::MqttConfig;
+
+/// Requests the MQTT client may queue while the broker is unreachable.
+/// Meter and decode publishes are dropped once it is full.
+const CLIENT_QUEUE_CAPACITY: usize = 64;
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(12);
+
+/// One rig published over MQTT.
+pub struct MqttRig {
+    pub rig_id: String,
+    pub display_name: String,
+    pub state_rx: watch::Receiver<RigState>,
+    pub meter_rx: broadcast::Receiver<MeterUpdate>,
+    pub decode_rx: broadcast::Receiver<DecodedMessage>,
+    pub rig_tx: mpsc::Sender<RigRequest>,
+}
+
+/// Messages sent after every (re)connect.
+struct Announcement {
+    availability: String,
+    /// Retained discovery payloads.
+    discovery: Vec<(String, Value)>,
+    command_topics: Vec<String>,
+}
+
+/// Run the MQTT publisher for all `rigs` over one broker connection.
+/// Commands are authorized with `validator`.
+///
+/// Reconnects automatically with exponential backoff (1 s → 2 s → … → 60 s).
+pub async fn run_mqtt_uplink(
+    cfg: MqttConfig,
+    rigs: Vec<MqttRig>,
+    validator: Arc<ScopedTokenValidator>,
+) {
+    let prefix = cfg.topic_prefix.trim_end_matches('/').to_string();
+    let availability = format!("{prefix}/status");
+
+    let mut options = MqttOptions::new(cfg.client_id.as_str(), cfg.host.as_str(), cfg.port);
+    options.set_keep_alive(KEEP_ALIVE);
+    options.set_last_will(LastWill::new(
+        availability.as_str(),
+        "offline",
+        QoS::AtLeastOnce,
+        true,
+    ));
+    if let Some(username) = &cfg.username {
+        options.set_credentials(username, cfg.password.clone().unwrap_or_default());
+    }
+    let (client, mut eventloop) = AsyncClient::new(options, CLIENT_QUEUE_CAPACITY);
+
+    let state_interval = Duration::from_millis(cfg.state_interval_ms);
+    let meter_interval = Duration::from_millis(cfg.meter_interval_ms);
+    let mut announcement = Announcement {
+        availability,
+        discovery: Vec::new(),
+        command_topics: Vec::new(),
+    };
+    let mut command_targets = HashMap::new();
+    // Dropping the sets when the uplink is cancelled aborts all tasks.
+    let mut publishers = JoinSet::new();
+    let mut commands = JoinSet::new();
+
+    for rig in rigs {
+        if cfg.discovery {
+            announcement.discovery.extend(discovery_messages(
+                &cfg,
+                &prefix,
+                &rig.rig_id,
+                &rig.display_name,
+            ));
+        }
+        if cfg.commands {
+            let topic = rig_topic(&prefix, &rig.rig_id, "command");
+            announcement.command_topics.push(topic.clone());
+            command_targets.insert(topic, (rig.rig_id.clone(), rig.rig_tx));
+        }
+        publishers.spawn(publish_state(
+            client.clone(),
+            rig_topic(&prefix, &rig.rig_id, "state"),
+            rig.state_rx,
+            state_interval,
+        ));
+        publishers.spawn(publish_meters(
+            client.clone(),
+            rig_topic(&prefix, &rig.rig_id, "meter"),
+            rig.meter_rx,
+            meter_interval,
+        ));
+        publishers.spawn(publish_decodes(
+            client.clone(),
+            rig_topic(&prefix, &rig.rig_id, "decode"),
+            rig.decode_rx,
+        ));
+    }
+    let announcement = Arc::new(announcement);
+
+    let mut backoff_secs: u64 = 1;
+    loop {
+        while commands.try_join_next().is_some() {}
+
+        match eventloop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                info!("MQTT: connected to {}:{}", cfg.host, cfg.port);
+                backoff_secs = 1;
+                // Announce from a task: the client queue only drains while
+                // this loop keeps polling.
+                tokio::spawn(announce(client.clone(), announcement.clone()));
+            }
+            Ok(Event::Incoming(Packet::Publish(publish))) => {
+                let Some((rig_id, rig_tx)) = command_targets.get(&publish.topic) else {
+                    continue;
+                };
+                let client = client.clone();
+                let rig_id = rig_id.clone();
+                let rig_tx = rig_tx.clone();
+                let validator = validator.clone();
+                let result_topic = format!("{}/result", publish.topic);
+                commands.spawn(async move {
+                    let resp = run_command(&rig_id, &rig_tx, &validator, &publish.payload).await;
+                    match serde_json::to_vec(&resp) {
+                        Ok(payload) => {
+                            let _ = client
+                                .publish(result_topic, QoS::AtLeastOnce, false, payload)
+                                .await;
+                        }
+                        Err(e) => warn!("MQTT: failed to encode command result: {}", e),
+                    }
+                });
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(
+                    "MQTT: connection to {}:{} failed: {}, retrying in {}s",
+                    cfg.host, cfg.port, e, backoff_secs
+                );
+                time::sleep(Duration::from_secs(backoff_secs)).await;
+                backoff_secs = (backoff_secs * 2).min(60);
+            }
+        }
+    }
+}
+
+async fn announce(client: AsyncClient, announcement: Arc<Announcement>) {
+    let _ = client
+        .publish(
+            announcement.availability.as_str(),
+            QoS::AtLeastOnce,
+            true,
+            "online",
+        )
+        .await;
+    for (topic, config) in &announcement.discovery {
+        let _ = client
+            .publish(topic.as_str(), QoS::AtLeastOnce, true, config.to_string())
+            .await;
+    }
+    for topic in &announcement.command_topics {
+        let _ = client.subscribe(topic.as_str(), QoS::AtLeastOnce).await;
+    }
+}
+
+/// Publish the rig snapshot whenever it changes, at most once per `interval`.
+async fn publish_state(
+    client: AsyncClient,
+    topic: String,
+    mut state_rx: watch::Receiver<RigState>,
+    interval: Duration,
+) {
+    let mut last: Option<Vec<u8>> = None;
+    loop {
+        let snapshot = state_rx.borrow_and_update().snapshot();
+        if let Some(snapshot) = snapshot {
+            match serde_json::to_vec(&snapshot) {
+                Ok(payload) if last.as_ref() != Some(&payload) => {
+                    if client
+                        .publish(topic.as_str(), QoS::AtLeastOnce, true, payload.clone())
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                    last = Some(payload);
+                    time::sleep(interval).await;
+                }
+                Ok(_) => {}
+                Err(e) => warn!("MQTT: failed to encode state for {}: {}", topic, e),
+            }
+        }
+        if state_rx.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Forward meter samples, dropping those that arrive within `interval` of
+/// the last published one.
+async fn publish_meters(
+    client: AsyncClient,
+    topic: String,
+    mut meter_rx: broadcast::Receiver<MeterUpdate>,
+    interval: Duration,
+) {
+    let mut last_sent: Option<Instant> = None;
+    loop {
+        let update = match meter_rx.recv().await {
+            Ok(update) => update,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+        if last_sent.is_some_and(|sent| sent.elapsed() < interval) {
+            continue;
+        }
+        let Ok(payload) = serde_json::to_vec(&update) else {
+            continue;
+        };
+        if let Err(e) = client.try_publish(topic.as_str(), QoS::AtMostOnce, false, payload) {
+            debug!("MQTT: meter sample dropped: {}", e);
+        }
+        last_sent = Some(Instant::now());
+    }
+}
+
+/// Forward every decoded message to `<topic>/<type>`.
+async fn publish_decodes(
+    client: AsyncClient,
+    topic: String,
+    mut decode_rx: broadcast::Receiver<DecodedMessage>,
+) {
+    loop {
+        let msg = match decode_rx.recv().await {
+            Ok(msg) => msg,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!("MQTT: decode stream lagged, {} messages dropped", skipped);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+        let Some((kind, payload)) = decode_payload(&msg) else {
+            continue;
+        };
+        if let Err(e) =
+            client.try_publish(format!("{topic}/{kind}"), QoS::AtLeastOnce, false, payload)
+        {
+            debug!("MQTT: decoded message dropped: {}", e);
+        }
+    }
+}
+
+/// Serialize a decoded message and return it with its `type` tag.
+fn decode_payload(msg: &DecodedMessage) -> Option<(String, Vec<u8>)> {
+    let value = serde_json::to_value(msg).ok()?;
+    let kind = value.get("type")?.as_str()?.to_string();
+    Some((kind, value.to_string().into_bytes()))
+}
+
+/// Run one command received on a rig's command topic, if its token holds
+/// the command's scope on the rig.
+async fn run_command(
+    rig_id: &str,
+    rig_tx: &mpsc::Sender<RigRequest>,
+    validator: &ScopedTokenValidator,
+    payload: &[u8],
+) -> ClientResponse {
+    let envelope = match std::str::from_utf8(payload)
+        .map_err(|e| e.to_string())
+        .and_then(|text| parse_envelope(text.trim()).map_err(|e| e.to_string()))
+    {
+        Ok(envelope) => envelope,
+        Err(e) => return command_response(rig_id, Err(format!("Invalid JSON: {e}"))),
+    };
+    if is_client_only(&envelope.cmd) {
+        return command_response(rig_id, Err("command is not available over MQTT".into()));
+    }
+    let access = match validator.authenticate(&envelope.token, SystemTime::now()) {
+        Ok(access) => access,
+        Err(e) => {
+            warn!("MQTT: command for rig '{}' refused: {}", rig_id, e);
+            return command_response(rig_id, Err(e));
+        }
+    };
+    if let Err(denied) = access.authorize(rig_id, required_scope(&envelope.cmd)) {
+        warn!(
+            "MQTT: command for rig '{}' refused: needs '{}'",
+            rig_id, denied.required
+        );
+        let mut resp = command_response(
+            rig_id,
+            Err(format!(
+                "insufficient scope: '{}' required",
+                denied.required
+            )),
+        );
+        resp.denied = Some(denied);
+        return resp;
+    }
+
+    let (respond_to, resp_rx) = oneshot::channel();
+    let req = RigRequest {
+        cmd: client_command_to_rig(envelope.cmd),
+        respond_to,
+        rig_id_override: None,
+    };
+    if rig_tx.send(req).await.is_err() {
+        return command_response(rig_id, Err("rig task not available".into()));
+    }
+    let result = match time::timeout(COMMAND_TIMEOUT, resp_rx).await {
+        Ok(Ok(Ok(snapshot))) => Ok(snapshot),
+        Ok(Ok(Err(err))) => Err(err.message),
+        Ok(Err(_)) => Err("Internal error waiting for rig response".into()),
+        Err(_) => Err("Request timed out waiting for rig response".into()),
+    };
+    command_response(rig_id, result)
+}
+
+fn command_response(
+    rig_id: &str,
+    result: Result<trx_core::rig::state::RigSnapshot, String>,
+) -> ClientResponse {
+    let (state, error) = match result {
+        Ok(snapshot) => (Some(snapshot), None),
+        Err(e) => (None, Some(e)),
+    };
+    ClientResponse {
+        success: error.is_none(),
+        rig_id: Some(rig_id.to_string()),
+        protocol_version: None,
+        state,
+        rigs: None,
+        sat_passes: None,
+        denied: None,
+        error,
+    }
+}
+
+fn rig_topic(prefix: &str, rig_id: &str, leaf: &str) -> String {
+    format!("{prefix}/{rig_id}/{leaf}")
+}
+
+/// Home Assistant discovery payloads for one rig: frequency, mode, signal
+/// strength and PTT.
+fn discovery_messages(
+    cfg: &MqttConfig,
+    prefix: &str,
+    rig_id: &str,
+    display_name: &str,
+) -> Vec<(String, Value)> {
+    let node_id: String = format!("{}_{}", cfg.client_id, rig_id)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let state_topic = rig_topic(prefix, rig_id, "state");
+    let meter_topic = rig_topic(prefix, rig_id, "meter");
+    let device = json!({
+        "identifiers": [node_id],
+        "name": display_name,
+        "manufacturer": "trx-rs",
+        "sw_version": env!("CARGO_PKG_VERSION"),
+    });
+
+    let entities = [
+        (
+            "sensor",
+            "frequency",
+            "Frequency",
+            &state_topic,
+            "{{ value_json.status.freq.hz }}",
+            Some(("Hz", "frequency")),
+        ),
+        (
+            "sensor",
+            "mode",
+            "Mode",
+            &state_topic,
+            "{{ value_json.status.mode }}",
+            None,
+        ),
+        (
+            "sensor",
+            "signal",
+            "Signal",
+            &meter_topic,
+            "{{ value_json.sig_dbm }}",
+            Some(("dBm", "signal_strength")),
+        ),
+        (
+            "binary_sensor",
+            "ptt",
+            "PTT",
+            &state_topic,
+            "{{ 'ON' if value_json.status.tx_en else 'OFF' }}",
+            None,
+        ),
+    ];
+
+    entities
+        .into_iter()
+        .map(|(component, object_id, name, topic, template, unit)| {
+            let mut config = json!({
+                "name": name,
+                "unique_id": format!("{node_id}_{object_id}"),
+                "state_topic": topic,
+                "value_template": template,
+                "availability_topic": format!("{prefix}/status"),
+                "device": device,
+            });
+            if let Some((unit, device_class)) = unit {
+                config["unit_of_measurement"] = unit.into();
+                config["device_class"] = device_class.into();
+            }
+            (
+                format!(
+                    "{}/{component}/{node_id}/{object_id}/config",
+                    cfg.discovery_prefix
+                ),
+                config,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bytes::BytesMut;
+    use rumqttc::{ConnAck, ConnectReturnCode, PubAck, Publish, SubAck, SubscribeReasonCode};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    use trx_core::decode::CwEvent;
+    use trx_core::rig::command::RigCommand;
+    use trx_protocol::auth::{hash_token, TokenGrant};
+    use trx_protocol::types::Scope;
+
+    const MAX_PACKET: usize = 1 << 20;
+
+    /// Minimal MQTT 3.1.1 broker side for one client: acks the connection,
+    /// subscriptions and QoS 1 publishes, and hands publishes to the test.
+    struct TestBroker {
+        stream: TcpStream,
+        buf: BytesMut,
+    }
+
+    impl TestBroker {
+        async fn accept(listener: &TcpListener) -> Self {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut broker = Self {
+                stream,
+                buf: BytesMut::new(),
+            };
+            assert!(matches!(broker.read().await, Packet::Connect(_)));
+            broker
+                .write(Packet::ConnAck(ConnAck::new(
+                    ConnectReturnCode::Success,
+                    false,
+                )))
+                .await;
+            broker
+        }
+
+        async fn read(&mut self) -> Packet {
+            loop {
+                match Packet::read(&mut self.buf, MAX_PACKET) {
+                    Ok(packet) => return packet,
+                    Err(rumqttc::Error::InsufficientBytes(_)) => {}
+                    Err(e) => panic!("bad packet: {e:?}"),
+                }
+                let n = self.stream.read_buf(&mut self.buf).await.expect("read");
+                assert!(n > 0, "client closed the connection");
+            }
+        }
+
+        async fn write(&mut self, packet: Packet) {
+            let mut out = BytesMut::new();
+            packet.write(&mut out, MAX_PACKET).expect("encode");
+            self.stream.write_all(&out).await.expect("write");
+        }
+
+        /// Next packet from the client, acknowledged where MQTT requires it.
+        async fn next(&mut self) -> Packet {
+            let packet = self.read().await;
+            match &packet {
+                Packet::Publish(publish) if publish.qos == QoS::AtLeastOnce => {
+                    self.write(Packet::PubAck(PubAck::new(publish.pkid))).await;
+                }
+                Packet::Subscribe(sub) => {
+                    let codes = sub
+                        .filters
+                        .iter()
+                        .map(|_| SubscribeReasonCode::Success(QoS::AtLeastOnce))
+                        .collect();
+                    self.write(Packet::SubAck(SubAck::new(sub.pkid, codes)))
+                        .await;
+                }
+                Packet::PingReq => self.write(Packet::PingResp).await,
+                _ => {}
+            }
+            packet
+        }
+
+        /// Next publish from the client whose topic satisfies `want`.
+        async fn publish_where(&mut self, want: impl Fn(&str) -> bool) -> Publish {
+            loop {
+                if let Packet::Publish(publish) = self.next().await {
+                    if want(&publish.topic) {
+                        return publish;
+                    }
+                }
+            }
+        }
+    }
+
+    fn test_rig_state() -> RigState {
+        let mut state = RigState::new_uninitialized();
+        state.rig_info = Some(trx_core::rig::RigInfo {
+            manufacturer: "Test".to_string(),
+            model: "Dummy".to_string(),
+            revision: "1".to_string(),
+            capabilities: trx_core::rig::RigCapabilities {
+                min_freq_step_hz: 1,
+                supported_bands: vec![trx_core::radio::freq::Band {
+                    low_hz: 14_000_000,
+                    high_hz: 14_350_000,
+                    tx_allowed: true,
+                }],
+                supported_modes: vec![trx_core::RigMode::USB],
+                num_vfos: 1,
+                lock: false,
+                lockable: true,
+                attenuator: false,
+                preamp: false,
+                rit: false,
+                xit: false,
+                rpt: false,
+                split: false,
+                tx: true,
+                tx_limit: true,
+                vfo_switch: true,
+                filter_controls: false,
+                signal_meter: true,
+                memory_channels: 0,
+                memory_write: false,
+                band_stack: false,
+                ctcss_decode: false,
+                dcs: false,
+            },
+            access: trx_core::rig::RigAccessMethod::Tcp {
+                addr: "127.0.0.1:1234".to_string(),
+            },
+        });
+        state.status.freq.hz = 14_074_000;
+        state
+    }
+
+    #[test]
+    fn discovery_payloads_reference_rig_topics() {
+        let cfg = MqttConfig::default();
+        let messages = discovery_messages(&cfg, "trx", "hf 1", "HF Rig");
+        assert_eq!(messages.len(), 4);
+
+        let (topic, config) = &messages[0];
+        assert_eq!(
+            topic,
+            "homeassistant/sensor/trx-server_hf_1/frequency/config"
+        );
+        assert_eq!(config["state_topic"], "trx/hf 1/state");
+        assert_eq!(config["unique_id"], "trx-server_hf_1_frequency");
+        assert_eq!(config["device_class"], "frequency");
+        assert_eq!(config["availability_topic"], "trx/status");
+        assert_eq!(config["device"]["name"], "HF Rig");
+
+        let (topic, config) = &messages[3];
+        assert_eq!(
+            topic,
+            "homeassistant/binary_sensor/trx-server_hf_1/ptt/config"
+        );
+        assert!(config.get("unit_of_measurement").is_none());
+    }
+
+    #[test]
+    fn decode_payload_uses_message_type() {
+        let msg = DecodedMessage::Cw(CwEvent {
+            rig_id: None,
+            text: "CQ".to_string(),
+            wpm: 18,
+            tone_hz: 600,
+            signal_on: true,
+        });
+        let (kind, payload) = decode_payload(&msg).unwrap();
+        assert_eq!(kind, "cw");
+        let value: Value = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(value["text"], "CQ");
+    }
+
+    #[tokio::test]
+    async fn publishes_to_broker_and_runs_commands() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let cfg = MqttConfig {
+            enabled: true,
+            host: "127.0.0.1".to_string(),
+            port: listener.local_addr().unwrap().port(),
+            commands: true,
+            ..MqttConfig::default()
+        };
+
+        let (state_tx, state_rx) = watch::channel(test_rig_state());
+        let (meter_tx, meter_rx) = broadcast::channel(4);
+        let (decode_tx, decode_rx) = broadcast::channel(4);
+        let (rig_tx, mut rig_rx) = mpsc::channel::<RigRequest>(4);
+        let uplink = tokio::spawn(run_mqtt_uplink(
+            cfg,
+            vec![MqttRig {
+                rig_id: "hf".to_string(),
+                display_name: "HF".to_string(),
+                state_rx,
+                meter_rx,
+                decode_rx,
+                rig_tx,
+            }],
+            Arc::new(ScopedTokenValidator::new(vec![
+                TokenGrant {
+                    name: "viewer".to_string(),
+                    token_sha256: hash_token("viewer"),
+                    scope: Scope::Read,
+                    rigs: Vec::new(),
+                    expires_at: None,
+                },
+                TokenGrant {
+                    name: "operator".to_string(),
+                    token_sha256: hash_token("operator"),
+                    scope: Scope::Control,
+                    rigs: Vec::new(),
+                    expires_at: None,
+                },
+            ])),
+        ));
+
+        let mut broker = TestBroker::accept(&listener).await;
+
+        // State, announcement and command subscription, in any order.
+        let (mut state, mut online, mut discovery, mut subscribed) = (None, None, 0, None);
+        while state.is_none() || online.is_none() || subscribed.is_none() {
+            match broker.next().await {
+                Packet::Publish(publish) if publish.topic == "trx/hf/state" => {
+                    state = Some(publish)
+                }
+                Packet::Publish(publish) if publish.topic == "trx/status" => online = Some(publish),
+                Packet::Publish(publish) if publish.topic.starts_with("homeassistant/") => {
+                    discovery += 1
+                }
+                Packet::Subscribe(sub) => subscribed = Some(sub),
+                _ => {}
+            }
+        }
+        let state = state.unwrap();
+        assert!(state.retain);
+        let value: Value = serde_json::from_slice(&state.payload).unwrap();
+        assert_eq!(value["status"]["freq"]["hz"], 14_074_000);
+        assert_eq!(&online.unwrap().payload[..], b"online");
+        assert_eq!(discovery, 4);
+        assert_eq!(subscribed.unwrap().filters[0].path, "trx/hf/command");
+
+        meter_tx
+            .send(MeterUpdate {
+                rig_id: "hf".to_string(),
+                sig_dbm: -80.0,
+                ts_ms: 1,
+            })
+            .unwrap();
+        let meter = broker.publish_where(|t| t == "trx/hf/meter").await;
+        let value: Value = serde_json::from_slice(&meter.payload).unwrap();
+        assert_eq!(value["sig_dbm"], -80.0);
+
+        decode_tx
+            .send(DecodedMessage::Cw(CwEvent {
+                rig_id: Some("hf".to_string()),
+                text: "TEST".to_string(),
+                wpm: 20,
+                tone_hz: 700,
+                signal_on: false,
+            }))
+            .unwrap();
+        let decode = broker
+            .publish_where(|t| t.starts_with("trx/hf/decode/"))
+            .await;
+        assert_eq!(decode.topic, "trx/hf/decode/cw");
+
+        // Commands without a token, or with too little scope, are refused
+        // before they reach the rig.
+        for (payload, denied) in [
+            (r#"{"cmd":"set_freq","freq_hz":7074000}"#, None),
+            (
+                r#"{"token":"viewer","cmd":"set_freq","freq_hz":7074000}"#,
+                Some(Scope::Read),
+            ),
+        ] {
+            broker
+                .write(Packet::Publish(Publish::new(
+                    "trx/hf/command",
+                    QoS::AtMostOnce,
+                    payload,
+                )))
+                .await;
+            let result = broker.publish_where(|t| t == "trx/hf/command/result").await;
+            let resp: ClientResponse = serde_json::from_slice(&result.payload).unwrap();
+            assert!(!resp.success);
+            assert_eq!(resp.denied.and_then(|d| d.granted), denied);
+        }
+        assert!(rig_rx.try_recv().is_err());
+
+        broker
+            .write(Packet::Publish(Publish::new(
+                "trx/hf/command",
+                QoS::AtMostOnce,
+                r#"{"token":"operator","cmd":"set_freq","freq_hz":7074000}"#,
+            )))
+            .await;
+        let req = rig_rx.recv().await.expect("rig request");
+        assert!(matches!(req.cmd, RigCommand::SetFreq(freq) if freq.hz == 7_074_000));
+        let mut snapshot_state = test_rig_state();
+        snapshot_state.status.freq.hz = 7_074_000;
+        let _ = req.respond_to.send(Ok(snapshot_state.snapshot().unwrap()));
+
+        let result = broker.publish_where(|t| t == "trx/hf/command/result").await;
+        let resp: ClientResponse = serde_json::from_slice(&result.payload).unwrap();
+        assert!(resp.success);
+        assert_eq!(resp.rig_id.as_deref(), Some("hf"));
+
+        drop(state_tx);
+        uplink.abort();
+    }
+}
@@ -11,6 +11,7 @@ use tokio::time::{self, Duration, Instant};
 use tracing::{info, warn};
 
 use trx_core::decode::DecodedMessage;
+use trx_core::metrics::UplinkMetrics;
 use trx_core::rig::state::RigState;
 
 use crate::PskReporterConfig;
@@ -63,6 +64,10 @@ struct Spot {
     flow_start_seconds: u32,
 }
 
+/// Run the PSK Reporter uplink task.
+///
+/// Batches spots from the decoded-message channel and sends them every five
+/// minutes.  `metrics` tracks the socket state, sent spots and send failures.
 pub async fn run_pskreporter_uplink(
     cfg: PskReporterConfig,
     receiver_callsign: String,
@@ -70,6 +75,7 @@ pub async fn run_pskreporter_uplink(
     longitude: Option<f64>,
     mut state_rx: watch::Receiver<RigState>,
     mut decode_rx: broadcast::Receiver<DecodedMessage>,
+    metrics: UplinkMetrics,
 ) {
     let receiver_locator = match cfg.receiver_locator.clone().or_else(|| {
         if let (Some(lat), Some(lon)) = (latitude, longitude) {
@@ -101,9 +107,11 @@ pub async fn run_pskreporter_uplink(
         Ok(client) => client,
         Err(err) => {
             warn!("PSK Reporter init failed: {}", err);
+            metrics.errors.inc();
             return;
         }
     };
+    metrics.up.set(1);
 
     info!(
         "PSK Reporter uplink active ({}:{} as {} / {})",
@@ -141,8 +149,10 @@ pub async fn run_pskreporter_uplink(
                     if let Err(err) = client.send_spots(&spots).await {
                         warn!("PSK Reporter send failed: {}", err);
                         stats_send_err += 1;
+                        metrics.errors.inc();
                     } else {
                         stats_sent += n;
+                        metrics.sent.add(n);
                     }
                 }
             }
@@ -183,6 +193,7 @@ pub async fn run_pskreporter_uplink(
             }
         }
     }
+    metrics.up.set(0);
 }
 
 fn decoded_to_spot(decoded: DecodedMessage, base_freq_hz: u64) -> Option<Spot> {
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! TCP feeds for external ADS-B tools.
+//!
+//! Every connected client gets a copy of the decoder output: raw frames in
+//! Beast binary format on one port and BaseStation (SBS-1) text on another.
+//! Feeds are write-only; anything a client sends is ignored.
+
+use std::net::SocketAddr;
+
+use bytes::Bytes;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tracing::{info, warn};
+
+/// Frames buffered per client before a slow reader starts losing data.
+const FEED_CHANNEL_CAPACITY: usize = 1024;
+
+/// Create the broadcast channel a feed listener serves from.
+pub fn feed_channel() -> broadcast::Sender<Bytes> {
+    broadcast::channel(FEED_CHANNEL_CAPACITY).0
+}
+
+/// Accept feed clients on `addr` until shutdown.
+pub async fn run_feed_listener(
+    name: &'static str,
+    addr: SocketAddr,
+    feed_tx: broadcast::Sender<Bytes>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("ADS-B {} feed on {}", name, addr);
+
+    loop {
+        tokio::select! {
+            accept = listener.accept() => {
+                let (socket, peer) = accept?;
+                info!("ADS-B {} client connected: {}", name, peer);
+                let feed_rx = feed_tx.subscribe();
+                let client_shutdown_rx = shutdown_rx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = serve_feed_client(socket, feed_rx, client_shutdown_rx).await {
+                        warn!("ADS-B {} client {} error: {:?}", name, peer, e);
+                    }
+                    info!("ADS-B {} client {} disconnected", name, peer);
+                });
+            }
+            changed = shutdown_rx.changed() => {
+                match changed {
+                    Ok(()) if *shutdown_rx.borrow() => break,
+                    Ok(()) => {}
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn serve_feed_client(
+    mut socket: TcpStream,
+    mut feed_rx: broadcast::Receiver<Bytes>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    socket.set_nodelay(true)?;
+    loop {
+        tokio::select! {
+            recv = feed_rx.recv() => {
+                match recv {
+                    Ok(chunk) => socket.write_all(&chunk).await?,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("ADS-B feed: client dropped {} messages", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            changed = shutdown_rx.changed() => {
+                match changed {
+                    Ok(()) if *shutdown_rx.borrow() => break,
+                    Ok(()) => {}
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn clients_receive_broadcast_frames() {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        let feed_tx = feed_channel();
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let server = tokio::spawn(run_feed_listener("test", addr, feed_tx.clone(), shutdown_rx));
+
+        let mut client = loop {
+            match TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            }
+        };
+        while feed_tx.receiver_count() == 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        feed_tx.send(Bytes::from_static(b"MSG,8\r\n")).unwrap();
+
+        let mut buf = [0u8; 7];
+        client.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"MSG,8\r\n");
+
+        shutdown_tx.send(true).unwrap();
+        server.await.unwrap().unwrap();
+    }
+}
@@ -21,39 +21,53 @@ use std::io::Write as _;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::{broadcast, mpsc, watch};
 use tracing::{error, info, info_span, warn};
+use trx_app::tls::{TlsAcceptor, TransportStream};
 
+use trx_adsb::AdsbDecoder;
 use trx_ais::AisDecoder;
 use trx_aprs::AprsDecoder;
 use trx_core::audio::{
     parse_vchan_uuid_msg, read_audio_msg, write_audio_msg, write_vchan_audio_frame,
-    write_vchan_uuid_msg, AudioStreamInfo, AUDIO_MSG_AIS_DECODE, AUDIO_MSG_APRS_DECODE,
-    AUDIO_MSG_CW_DECODE, AUDIO_MSG_FT2_DECODE, AUDIO_MSG_FT4_DECODE, AUDIO_MSG_FT8_DECODE,
+    write_vchan_uuid_msg, AudioStreamInfo, AUDIO_MSG_ACARS_DECODE, AUDIO_MSG_ADSB_DECODE, AUDIO_MSG_AIS_DECODE, AUDIO_MSG_APRS_DECODE,
+    AUDIO_MSG_CW_DECODE, AUDIO_MSG_CW_SPOT, AUDIO_MSG_DSC_DECODE, AUDIO_MSG_FT2_DECODE, AUDIO_MSG_FT4_DECODE, AUDIO_MSG_FT8_DECODE,
     AUDIO_MSG_HF_APRS_DECODE, AUDIO_MSG_HISTORY_COMPRESSED, AUDIO_MSG_LRPT_IMAGE,
-    AUDIO_MSG_LRPT_PROGRESS, AUDIO_MSG_RX_FRAME, AUDIO_MSG_STREAM_INFO, AUDIO_MSG_TX_FRAME,
+    AUDIO_MSG_LRPT_PROGRESS, AUDIO_MSG_POCSAG_DECODE, AUDIO_MSG_PSK_DECODE, AUDIO_MSG_RX_FRAME, AUDIO_MSG_STREAM_INFO, AUDIO_MSG_TX_FRAME,
     AUDIO_MSG_VCHAN_ALLOCATED, AUDIO_MSG_VCHAN_BW, AUDIO_MSG_VCHAN_DESTROYED, AUDIO_MSG_VCHAN_FREQ,
     AUDIO_MSG_VCHAN_MODE, AUDIO_MSG_VCHAN_REMOVE, AUDIO_MSG_VCHAN_SUB, AUDIO_MSG_VCHAN_UNSUB,
     AUDIO_MSG_VDES_DECODE, AUDIO_MSG_WEFAX_DECODE, AUDIO_MSG_WEFAX_PROGRESS, AUDIO_MSG_WSPR_DECODE,
 };
 use trx_core::decode::{
-    AisMessage, AprsPacket, CwEvent, DecodedMessage, Ft8Message, LrptImage, LrptProgress,
-    VdesMessage, WefaxMessage, WsprMessage,
+    AcarsMessage, AdsbMessage, AisMessage, AprsPacket, CwEvent, CwSpot, DecodedMessage, DscBand, DscMessage, Ft8Message, LrptImage,
+    LrptProgress, PocsagMessage, PskEvent, VdesMessage, WefaxMessage, WsprMessage,
 };
+use trx_core::metrics::{Counter, Histogram, RigMetrics};
 use trx_core::rig::state::{RigMode, RigState};
 use trx_core::vchan::SharedVChanManager;
-use trx_cw::CwDecoder;
+use trx_acars::AcarsDecoder;
+use trx_cw::{CwDecoder, CwSkimmer};
+use trx_dsc::DscDecoder;
 use trx_ftx::Ft8Decoder;
+use trx_pocsag::PocsagDecoder;
+use trx_psk::PskDecoder;
 use trx_vdes::VdesDecoder;
 use trx_wspr::WsprDecoder;
 use uuid::Uuid;
 
-use crate::config::AudioConfig;
+use crate::config::{AudioConfig, PocsagConfig, SkimmerConfig};
+use crate::rig_handle::MuxAudioStream;
 use trx_decode_log::DecoderLoggers;
 
 const APRS_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 const HF_APRS_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 const AIS_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 const VDES_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+const DSC_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+const POCSAG_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+const ACARS_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+/// Aircraft drop off the table once they have been silent this long.
+const ADSB_HISTORY_RETENTION: Duration = Duration::from_secs(60 * 60);
 const CW_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+const PSK_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 const FT8_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 const WSPR_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 const LRPT_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
@@ -64,6 +78,8 @@ const WEFAX_HISTORY_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
 const MAX_HISTORY_ENTRIES: usize = 10_000;
 /// Silence timeout before auto-finalising an LRPT pass (30 s without new MCUs).
 const LRPT_PASS_SILENCE_TIMEOUT: Duration = Duration::from_secs(30);
+/// Time allowed for a client to complete the TLS handshake.
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
 const FT8_SAMPLE_RATE: u32 = 12_000;
 #[cfg(feature = "ft2")]
 const FT2_ASYNC_BUFFER_SAMPLES: usize = 45_000;
@@ -220,9 +236,15 @@ fn classify_stream_error(err: &str) -> &'static str {
 pub struct DecoderHistories {
     pub ais: Mutex<VecDeque<(Instant, AisMessage)>>,
     pub vdes: Mutex<VecDeque<(Instant, VdesMessage)>>,
+    pub dsc: Mutex<VecDeque<(Instant, DscMessage)>>,
+    pub pocsag: Mutex<VecDeque<(Instant, PocsagMessage)>>,
+    /// Latest state per aircraft, oldest first.
+    pub adsb: Mutex<VecDeque<(Instant, AdsbMessage)>>,
+    pub acars: Mutex<VecDeque<(Instant, AcarsMessage)>>,
     pub aprs: Mutex<VecDeque<(Instant, AprsPacket)>>,
     pub hf_aprs: Mutex<VecDeque<(Instant, AprsPacket)>>,
     pub cw: Mutex<VecDeque<(Instant, CwEvent)>>,
+    pub psk: Mutex<VecDeque<(Instant, PskEvent)>>,
     pub ft8: Mutex<VecDeque<(Instant, Ft8Message)>>,
     pub ft4: Mutex<VecDeque<(Instant, Ft8Message)>>,
     pub ft2: Mutex<VecDeque<(Instant, Ft8Message)>>,
@@ -257,9 +279,14 @@ impl DecoderHistories {
         Arc::new(Self {
             ais: Mutex::new(VecDeque::new()),
             vdes: Mutex::new(VecDeque::new()),
+            dsc: Mutex::new(VecDeque::new()),
+            pocsag: Mutex::new(VecDeque::new()),
+            adsb: Mutex::new(VecDeque::new()),
+            acars: Mutex::new(VecDeque::new()),
             aprs: Mutex::new(VecDeque::new()),
             hf_aprs: Mutex::new(VecDeque::new()),
             cw: Mutex::new(VecDeque::new()),
+            psk: Mutex::new(VecDeque::new()),
             ft8: Mutex::new(VecDeque::new()),
             ft4: Mutex::new(VecDeque::new()),
             ft2: Mutex::new(VecDeque::new()),
@@ -330,6 +357,192 @@ impl DecoderHistories {
         h.iter().map(|(_, msg)| msg.clone()).collect()
     }
 
+    /// Fill in the vessel fields of a DSC call from the most recent AIS
+    /// reports of the calling station.
+    pub fn annotate_dsc_from_ais(&self, msg: &mut DscMessage) {
+        let h = lock_or_recover(&self.ais, "ais_history");
+        for (_, ais) in h.iter().rev().filter(|(_, ais)| ais.mmsi == msg.self_mmsi) {
+            if msg.vessel_name.is_none() {
+                msg.vessel_name = ais.vessel_name.clone();
+            }
+            if msg.vessel_callsign.is_none() {
+                msg.vessel_callsign = ais.callsign.clone();
+            }
+            if msg.vessel_lat.is_none() {
+                if let (Some(lat), Some(lon)) = (ais.lat, ais.lon) {
+                    msg.vessel_lat = Some(lat);
+                    msg.vessel_lon = Some(lon);
+                }
+            }
+            if msg.vessel_name.is_some() && msg.vessel_lat.is_some() {
+                break;
+            }
+        }
+    }
+
+    // --- DSC ---
+
+    fn prune_dsc(history: &mut VecDeque<(Instant, DscMessage)>) {
+        let cutoff = Instant::now() - DSC_HISTORY_RETENTION;
+        while let Some((ts, _)) = history.front() {
+            if *ts < cutoff {
+                history.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn record_dsc_message(&self, mut msg: DscMessage) {
+        if msg.ts_ms.is_none() {
+            msg.ts_ms = Some(current_timestamp_ms());
+        }
+        let mut h = lock_or_recover(&self.dsc, "dsc_history");
+        let before = h.len();
+        h.push_back((Instant::now(), msg));
+        Self::prune_dsc(&mut h);
+        enforce_capacity(&mut h, MAX_HISTORY_ENTRIES);
+        self.adjust_total_count(before, h.len());
+    }
+
+    pub fn snapshot_dsc_history(&self) -> Vec<DscMessage> {
+        let mut h = lock_or_recover(&self.dsc, "dsc_history");
+        let before = h.len();
+        Self::prune_dsc(&mut h);
+        self.adjust_total_count(before, h.len());
+        h.iter().map(|(_, msg)| msg.clone()).collect()
+    }
+
+    pub fn clear_dsc_history(&self) {
+        let mut h = lock_or_recover(&self.dsc, "dsc_history");
+        let before = h.len();
+        h.clear();
+        self.adjust_total_count(before, 0);
+    }
+
+    // --- POCSAG ---
+
+    fn prune_pocsag(history: &mut VecDeque<(Instant, PocsagMessage)>) {
+        let cutoff = Instant::now() - POCSAG_HISTORY_RETENTION;
+        while let Some((ts, _)) = history.front() {
+            if *ts < cutoff {
+                history.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn record_pocsag_message(&self, mut msg: PocsagMessage) {
+        if msg.ts_ms.is_none() {
+            msg.ts_ms = Some(current_timestamp_ms());
+        }
+        let mut h = lock_or_recover(&self.pocsag, "pocsag_history");
+        let before = h.len();
+        h.push_back((Instant::now(), msg));
+        Self::prune_pocsag(&mut h);
+        enforce_capacity(&mut h, MAX_HISTORY_ENTRIES);
+        self.adjust_total_count(before, h.len());
+    }
+
+    pub fn snapshot_pocsag_history(&self) -> Vec<PocsagMessage> {
+        let mut h = lock_or_recover(&self.pocsag, "pocsag_history");
+        let before = h.len();
+        Self::prune_pocsag(&mut h);
+        self.adjust_total_count(before, h.len());
+        h.iter().map(|(_, msg)| msg.clone()).collect()
+    }
+
+    pub fn clear_pocsag_history(&self) {
+        let mut h = lock_or_recover(&self.pocsag, "pocsag_history");
+        let before = h.len();
+        h.clear();
+        self.adjust_total_count(before, 0);
+    }
+
+    // --- ADS-B ---
+
+    fn prune_adsb(history: &mut VecDeque<(Instant, AdsbMessage)>) {
+        let cutoff = Instant::now() - ADSB_HISTORY_RETENTION;
+        while let Some((ts, _)) = history.front() {
+            if *ts < cutoff {
+                history.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Record an aircraft update, replacing any earlier entry for the same
+    /// ICAO address so the history is one row per aircraft.
+    pub fn record_adsb_message(&self, mut msg: AdsbMessage) {
+        if msg.ts_ms.is_none() {
+            msg.ts_ms = Some(current_timestamp_ms());
+        }
+        let mut h = lock_or_recover(&self.adsb, "adsb_history");
+        let before = h.len();
+        h.retain(|(_, prev)| prev.icao != msg.icao);
+        h.push_back((Instant::now(), msg));
+        Self::prune_adsb(&mut h);
+        enforce_capacity(&mut h, MAX_HISTORY_ENTRIES);
+        self.adjust_total_count(before, h.len());
+    }
+
+    pub fn snapshot_adsb_history(&self) -> Vec<AdsbMessage> {
+        let mut h = lock_or_recover(&self.adsb, "adsb_history");
+        let before = h.len();
+        Self::prune_adsb(&mut h);
+        self.adjust_total_count(before, h.len());
+        h.iter().map(|(_, msg)| msg.clone()).collect()
+    }
+
+    pub fn clear_adsb_history(&self) {
+        let mut h = lock_or_recover(&self.adsb, "adsb_history");
+        let before = h.len();
+        h.clear();
+        self.adjust_total_count(before, 0);
+    }
+
+    // --- ACARS ---
+
+    fn prune_acars(history: &mut VecDeque<(Instant, AcarsMessage)>) {
+        let cutoff = Instant::now() - ACARS_HISTORY_RETENTION;
+        while let Some((ts, _)) = history.front() {
+            if *ts < cutoff {
+                history.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn record_acars_message(&self, mut msg: AcarsMessage) {
+        if msg.ts_ms.is_none() {
+            msg.ts_ms = Some(current_timestamp_ms());
+        }
+        let mut h = lock_or_recover(&self.acars, "acars_history");
+        let before = h.len();
+        h.push_back((Instant::now(), msg));
+        Self::prune_acars(&mut h);
+        enforce_capacity(&mut h, MAX_HISTORY_ENTRIES);
+        self.adjust_total_count(before, h.len());
+    }
+
+    pub fn snapshot_acars_history(&self) -> Vec<AcarsMessage> {
+        let mut h = lock_or_recover(&self.acars, "acars_history");
+        let before = h.len();
+        Self::prune_acars(&mut h);
+        self.adjust_total_count(before, h.len());
+        h.iter().map(|(_, msg)| msg.clone()).collect()
+    }
+
+    pub fn clear_acars_history(&self) {
+        let mut h = lock_or_recover(&self.acars, "acars_history");
+        let before = h.len();
+        h.clear();
+        self.adjust_total_count(before, 0);
+    }
+
     // --- VDES ---
 
     fn prune_vdes(history: &mut VecDeque<(Instant, VdesMessage)>) {
@@ -492,6 +705,48 @@ impl DecoderHistories {
         self.adjust_total_count(before, 0);
     }
 
+    // --- PSK ---
+
+    fn prune_psk(history: &mut VecDeque<(Instant, PskEvent)>) {
+        let cutoff = Instant::now() - PSK_HISTORY_RETENTION;
+        while let Some((ts, _)) = history.front() {
+            if *ts < cutoff {
+                history.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn record_psk_event(&self, mut evt: PskEvent) {
+        if evt.ts_ms.is_none() {
+            evt.ts_ms = Some(current_timestamp_ms());
+        }
+        let mut h = lock_or_recover(&self.psk, "psk_history");
+        let before = h.len();
+        h.push_back((Instant::now(), evt));
+        Self::prune_psk(&mut h);
+        enforce_capacity(&mut h, MAX_HISTORY_ENTRIES);
+        self.adjust_total_count(before, h.len());
+    }
+
+    pub fn snapshot_psk_history(&self) -> Vec<PskEvent> {
+        let mut h = lock_or_recover(&self.psk, "psk_history");
+        let before = h.len();
+        Self::prune_psk(&mut h);
+        self.adjust_total_count(before, h.len());
+        h.iter()
+            .map(|(_, evt): &(Instant, PskEvent)| evt.clone())
+            .collect()
+    }
+
+    pub fn clear_psk_history(&self) {
+        let mut h = lock_or_recover(&self.psk, "psk_history");
+        let before = h.len();
+        h.clear();
+        self.adjust_total_count(before, 0);
+    }
+
     // --- FT8 ---
 
     fn prune_ft8(history: &mut VecDeque<(Instant, Ft8Message)>) {
@@ -763,12 +1018,14 @@ impl DecoderHistories {
 ///
 /// Opens the configured input device via cpal, accumulates PCM samples into
 /// frames of `frame_duration_ms` length, encodes each frame with Opus, and
-/// broadcasts the resulting packets.
+/// broadcasts the resulting packets.  Encode time is recorded in
+/// `opus_encode`.
 pub fn spawn_audio_capture(
     cfg: &AudioConfig,
     tx: broadcast::Sender<Bytes>,
     pcm_tx: Option<broadcast::Sender<Vec<f32>>>,
     shutdown_rx: watch::Receiver<bool>,
+    opus_encode: Arc<Histogram>,
 ) -> std::thread::JoinHandle<()> {
     let sample_rate = cfg.sample_rate;
     let channels = cfg.channels as u16;
@@ -786,6 +1043,7 @@ pub fn spawn_audio_capture(
             tx,
             pcm_tx,
             shutdown_rx,
+            opus_encode,
         ) {
             error!("Audio capture thread error: {}", e);
         }
@@ -868,6 +1126,7 @@ fn run_capture(
     tx: broadcast::Sender<Bytes>,
     pcm_tx: Option<broadcast::Sender<Vec<f32>>>,
     shutdown_rx: watch::Receiver<bool>,
+    opus_encode: Arc<Histogram>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     use cpal::traits::{DeviceTrait, StreamTrait};
     use std::sync::mpsc::{RecvTimeoutError, TryRecvError as StdTryRecvError};
@@ -1004,7 +1263,7 @@ fn run_capture(
                         if let Some(ref pcm_tx) = pcm_tx {
                             let _ = pcm_tx.send(frame.clone());
                         }
-                        match encoder.encode_float(&frame, &mut opus_buf) {
+                        match opus_encode.time(|| encoder.encode_float(&frame, &mut opus_buf)) {
                             Ok(len) => {
                                 let packet = Bytes::copy_from_slice(&opus_buf[..len]);
                                 let _ = tx.send(packet);
@@ -1256,6 +1515,7 @@ fn run_playback(
 }
 
 /// Run the APRS decoder task. Only processes PCM when rig mode is PKT.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_aprs_decoder(
     sample_rate: u32,
     channels: u16,
@@ -1264,6 +1524,7 @@ pub async fn run_aprs_decoder(
     decode_tx: broadcast::Sender<DecodedMessage>,
     decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     run_aprs_decoder_inner(
         "APRS",
@@ -1275,10 +1536,12 @@ pub async fn run_aprs_decoder(
         decode_logs,
         histories,
         false,
+        lag,
     )
     .await;
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run_hf_aprs_decoder(
     sample_rate: u32,
     channels: u16,
@@ -1287,6 +1550,7 @@ pub async fn run_hf_aprs_decoder(
     decode_tx: broadcast::Sender<DecodedMessage>,
     decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     run_aprs_decoder_inner(
         "HF APRS",
@@ -1298,6 +1562,7 @@ pub async fn run_hf_aprs_decoder(
         decode_logs,
         histories,
         true,
+        lag,
     )
     .await;
 }
@@ -1313,6 +1578,7 @@ async fn run_aprs_decoder_inner(
     decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
     is_hf: bool,
+    lag: Arc<Counter>,
 ) {
     info!(
         "{} decoder started ({}Hz, {} ch)",
@@ -1419,6 +1685,7 @@ async fn run_aprs_decoder_inner(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("{} decoder: dropped {} PCM frames", label, n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -1464,6 +1731,7 @@ fn downmix_if_needed(frame: Vec<f32>, channels: u16) -> Vec<f32> {
 }
 
 /// Run the AIS decoder task. Only processes PCM when rig mode is AIS.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_ais_decoder(
     sample_rate: u32,
     channels: u16,
@@ -1472,6 +1740,7 @@ pub async fn run_ais_decoder(
     mut state_rx: watch::Receiver<RigState>,
     decode_tx: broadcast::Sender<DecodedMessage>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     info!("AIS decoder started ({}Hz, {} ch)", sample_rate, channels);
     let mut decoder_a = AisDecoder::new(sample_rate);
@@ -1515,6 +1784,7 @@ pub async fn run_ais_decoder(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("AIS decoder A: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -1539,6 +1809,7 @@ pub async fn run_ais_decoder(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("AIS decoder B: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -1554,9 +1825,703 @@ pub async fn run_ais_decoder(
                             decoder_b.reset();
                             was_active = false;
                         }
-                        if active {
-                            pcm_a_rx = pcm_a_rx.resubscribe();
-                            pcm_b_rx = pcm_b_rx.resubscribe();
+                        if active {
+                            pcm_a_rx = pcm_a_rx.resubscribe();
+                            pcm_b_rx = pcm_b_rx.resubscribe();
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+}
+
+/// Run the VDES decoder task. Only processes IQ when rig mode is VDES.
+pub async fn run_vdes_decoder(
+    sample_rate: u32,
+    mut iq_rx: broadcast::Receiver<Vec<Complex<f32>>>,
+    mut state_rx: watch::Receiver<RigState>,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+    histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
+) {
+    info!("VDES decoder started ({}Hz complex baseband)", sample_rate);
+    let mut decoder = VdesDecoder::new(sample_rate);
+    let mut was_active = false;
+    let mut active = matches!(state_rx.borrow().status.mode, RigMode::VDES);
+
+    loop {
+        if !active {
+            match state_rx.changed().await {
+                Ok(()) => {
+                    let state = state_rx.borrow();
+                    active = matches!(state.status.mode, RigMode::VDES);
+                    if active {
+                        iq_rx = iq_rx.resubscribe();
+                    }
+                }
+                Err(_) => break,
+            }
+            continue;
+        }
+
+        tokio::select! {
+            recv = iq_rx.recv() => {
+                match recv {
+                    Ok(block) => {
+                        was_active = true;
+                        let messages =
+                            tokio::task::block_in_place(|| {
+                                let _span = info_span!("vdes_decode").entered();
+                                decoder.process_samples(&block, "Main")
+                            });
+                        for mut msg in messages {
+                            if msg.ts_ms.is_none() {
+                                msg.ts_ms = Some(current_timestamp_ms());
+                            }
+                            histories.record_vdes_message(msg.clone());
+                            let _ = decode_tx.send(DecodedMessage::Vdes(msg));
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
+                        warn!("VDES decoder: dropped {} IQ blocks", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            changed = state_rx.changed() => {
+                match changed {
+                    Ok(()) => {
+                        let state = state_rx.borrow();
+                        active = matches!(state.status.mode, RigMode::VDES);
+                        if !active && was_active {
+                            decoder.reset();
+                            was_active = false;
+                        }
+                        if active {
+                            iq_rx = iq_rx.resubscribe();
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+}
+
+/// Run the ADS-B / Mode S decoder on raw 2 Msps capture IQ.
+///
+/// Decodes while the ADS-B toggle is on, and continuously when a Beast or
+/// SBS-1 feed is configured so external tools are not cut off; aircraft are
+/// only recorded and sent to clients while the toggle is on.
+pub async fn run_adsb_decoder(
+    mut iq_rx: broadcast::Receiver<Vec<Complex<f32>>>,
+    mut state_rx: watch::Receiver<RigState>,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+    histories: Arc<DecoderHistories>,
+    beast_tx: Option<broadcast::Sender<Bytes>>,
+    sbs_tx: Option<broadcast::Sender<Bytes>>,
+    lag: Arc<Counter>,
+) {
+    info!(
+        "ADS-B decoder started ({}Hz complex baseband)",
+        trx_adsb::SAMPLE_RATE
+    );
+    let mut decoder = AdsbDecoder::new();
+    let has_feed = beast_tx.is_some() || sbs_tx.is_some();
+    let mut last_reset_seq: u64 = 0;
+    let mut enabled = state_rx.borrow().decoders.adsb_decode_enabled;
+
+    loop {
+        if !enabled && !has_feed {
+            match state_rx.changed().await {
+                Ok(()) => {
+                    let state = state_rx.borrow();
+                    enabled = state.decoders.adsb_decode_enabled;
+                    if enabled {
+                        iq_rx = iq_rx.resubscribe();
+                    }
+                    if state.reset_seqs.adsb_decode_reset_seq != last_reset_seq {
+                        last_reset_seq = state.reset_seqs.adsb_decode_reset_seq;
+                        decoder.reset();
+                        info!("ADS-B decoder reset (seq={})", last_reset_seq);
+                    }
+                }
+                Err(_) => break,
+            }
+            continue;
+        }
+
+        tokio::select! {
+            recv = iq_rx.recv() => {
+                match recv {
+                    Ok(block) => {
+                        let now_ms = current_timestamp_ms();
+                        let out = tokio::task::block_in_place(|| {
+                            let _span = info_span!("adsb_decode").entered();
+                            decoder.process_iq(&block, now_ms)
+                        });
+                        if let Some(tx) = beast_tx.as_ref() {
+                            for frame in &out.frames {
+                                let _ = tx.send(Bytes::from(frame.to_beast()));
+                            }
+                        }
+                        if let Some(tx) = sbs_tx.as_ref() {
+                            for line in out.sbs_lines {
+                                let _ = tx.send(Bytes::from(line));
+                            }
+                        }
+                        if enabled {
+                            for msg in out.aircraft {
+                                histories.record_adsb_message(msg.clone());
+                                let _ = decode_tx.send(DecodedMessage::Adsb(msg));
+                            }
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
+                        warn!("ADS-B decoder: dropped {} IQ blocks", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            changed = state_rx.changed() => {
+                match changed {
+                    Ok(()) => {
+                        let state = state_rx.borrow();
+                        enabled = state.decoders.adsb_decode_enabled;
+                        if state.reset_seqs.adsb_decode_reset_seq != last_reset_seq {
+                            last_reset_seq = state.reset_seqs.adsb_decode_reset_seq;
+                            decoder.reset();
+                            info!("ADS-B decoder reset (seq={})", last_reset_seq);
+                        }
+                        if !enabled && !has_feed {
+                            decoder.reset();
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+}
+
+/// Run the CW skimmer over raw SDR IQ.
+///
+/// The skimmed window follows the dial and is re-centred once the dial has
+/// moved by more than a quarter of the span.  Spots are published as
+/// `DecodedMessage::CwSpot` and, with `telnet_tx`, as RBN-style lines.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_cw_skimmer(
+    iq_rate: u32,
+    cfg: SkimmerConfig,
+    spotter: String,
+    mut iq_rx: broadcast::Receiver<Vec<Complex<f32>>>,
+    state_rx: watch::Receiver<RigState>,
+    vchan_manager: SharedVChanManager,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+    telnet_tx: Option<broadcast::Sender<Bytes>>,
+    lag: Arc<Counter>,
+) {
+    let mut skimmer = CwSkimmer::new(iq_rate, cfg.span_hz);
+    skimmer.set_max_channels(cfg.max_channels);
+    skimmer.set_snr_threshold_db(cfg.snr_threshold_db);
+    let span = skimmer.span_hz() as f64;
+    // Keep the window inside the capture.
+    let max_offset = ((iq_rate as f64 - span) / 2.0).max(0.0);
+    info!(
+        "CW skimmer started ({} Hz window of {} Hz IQ, spotter {})",
+        skimmer.span_hz(),
+        iq_rate,
+        spotter
+    );
+    let mut last_center: Option<i64> = None;
+
+    loop {
+        let block = match iq_rx.recv().await {
+            Ok(block) => block,
+            Err(broadcast::error::RecvError::Lagged(n)) => {
+                lag.add(n);
+                warn!("CW skimmer: dropped {} IQ blocks", n);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let Some(center_hz) = vchan_manager.center_hz() else {
+            continue;
+        };
+        if last_center != Some(center_hz) {
+            last_center = Some(center_hz);
+            skimmer.reset();
+        }
+        let dial_hz = state_rx.borrow().status.freq.hz as i64;
+        let wanted = ((dial_hz - center_hz) as f64).clamp(-max_offset, max_offset);
+        if (wanted - skimmer.window_offset_hz()).abs() > span / 4.0 {
+            skimmer.set_window_offset_hz(wanted);
+        }
+
+        let spots = tokio::task::block_in_place(|| {
+            let _span = info_span!("cw_skimmer").entered();
+            skimmer.process_iq(&block)
+        });
+        for spot in spots {
+            let freq_hz = center_hz as f64 + spot.offset_hz;
+            if freq_hz <= 0.0 {
+                continue;
+            }
+            let now_ms = current_timestamp_ms();
+            let cw_spot = CwSpot {
+                rig_id: None,
+                ts_ms: Some(now_ms),
+                spotter: Some(spotter.clone()),
+                call: spot.call,
+                // Spots are reported to the nearest 100 Hz, like RBN.
+                freq_hz: ((freq_hz / 100.0).round() * 100.0) as u64,
+                snr_db: spot.snr_db.round() as i32,
+                wpm: spot.wpm,
+                kind: spot.kind.to_string(),
+            };
+            if let Some(tx) = telnet_tx.as_ref() {
+                let line = trx_core::dxspot::format_spot_line(&cw_spot.to_dx_spot(now_ms));
+                let _ = tx.send(Bytes::from(format!("{line}\r\n")));
+            }
+            let _ = decode_tx.send(DecodedMessage::CwSpot(cw_spot));
+        }
+    }
+}
+
+/// Run the CW decoder task. Only processes PCM when rig mode is CW or CWR.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_cw_decoder(
+    sample_rate: u32,
+    channels: u16,
+    mut pcm_rx: broadcast::Receiver<Vec<f32>>,
+    mut state_rx: watch::Receiver<RigState>,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+    decode_logs: Option<Arc<DecoderLoggers>>,
+    histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
+) {
+    info!("CW decoder started ({}Hz, {} ch)", sample_rate, channels);
+    let mut decoder = CwDecoder::new(sample_rate);
+    let mut was_active = false;
+    let mut last_reset_seq: u64 = 0;
+    let mut active = state_rx.borrow().decoders.cw_decode_enabled
+        && matches!(state_rx.borrow().status.mode, RigMode::CW | RigMode::CWR);
+    let mut last_auto = state_rx.borrow().cw_auto;
+    let mut last_wpm = state_rx.borrow().cw_wpm;
+    let mut last_tone = state_rx.borrow().cw_tone_hz;
+    decoder.set_auto(last_auto);
+    decoder.set_wpm(last_wpm);
+    decoder.set_tone_hz(last_tone);
+
+    loop {
+        if !active {
+            match state_rx.changed().await {
+                Ok(()) => {
+                    let state = state_rx.borrow();
+                    active = state.decoders.cw_decode_enabled
+                        && matches!(state.status.mode, RigMode::CW | RigMode::CWR);
+                    if active {
+                        pcm_rx = pcm_rx.resubscribe();
+                    }
+                    if state.cw_auto != last_auto {
+                        last_auto = state.cw_auto;
+                        decoder.set_auto(last_auto);
+                    }
+                    if state.cw_wpm != last_wpm {
+                        last_wpm = state.cw_wpm;
+                        decoder.set_wpm(last_wpm);
+                    }
+                    if state.cw_tone_hz != last_tone {
+                        last_tone = state.cw_tone_hz;
+                        decoder.set_tone_hz(last_tone);
+                    }
+                    if state.reset_seqs.cw_decode_reset_seq != last_reset_seq {
+                        last_reset_seq = state.reset_seqs.cw_decode_reset_seq;
+                        decoder.reset();
+                        info!("CW decoder reset (seq={})", last_reset_seq);
+                    }
+                }
+                Err(_) => break,
+            }
+            continue;
+        }
+
+        tokio::select! {
+            recv = pcm_rx.recv() => {
+                match recv {
+                    Ok(frame) => {
+                        let (process_enabled, cw_auto, cw_wpm, cw_tone_hz, reset_seq) = {
+                            let state = state_rx.borrow();
+                            (
+                                state.decoders.cw_decode_enabled
+                                    && matches!(state.status.mode, RigMode::CW | RigMode::CWR),
+                                state.cw_auto,
+                                state.cw_wpm,
+                                state.cw_tone_hz,
+                                state.reset_seqs.cw_decode_reset_seq,
+                            )
+                        };
+                        if cw_auto != last_auto {
+                            last_auto = cw_auto;
+                            decoder.set_auto(last_auto);
+                        }
+                        if cw_wpm != last_wpm {
+                            last_wpm = cw_wpm;
+                            decoder.set_wpm(last_wpm);
+                        }
+                        if cw_tone_hz != last_tone {
+                            last_tone = cw_tone_hz;
+                            decoder.set_tone_hz(last_tone);
+                        }
+                        if reset_seq != last_reset_seq {
+                            last_reset_seq = reset_seq;
+                            decoder.reset();
+                            info!("CW decoder reset (seq={})", last_reset_seq);
+                            pcm_rx = pcm_rx.resubscribe();
+                            continue;
+                        }
+                        if !process_enabled {
+                            if was_active {
+                                decoder.reset();
+                                was_active = false;
+                            }
+                            active = false;
+                            continue;
+                        }
+
+                        // Downmix to mono if stereo
+                        let mono = if channels > 1 {
+                            let num_frames = frame.len() / channels as usize;
+                            let mut mono = Vec::with_capacity(num_frames);
+                            for i in 0..num_frames {
+                                mono.push(frame[i * channels as usize]);
+                            }
+                            mono
+                        } else {
+                            frame
+                        };
+                        was_active = true;
+                        let events = tokio::task::block_in_place(|| {
+                            let _span = info_span!("cw_decode").entered();
+                            decoder.process_samples(&mono)
+                        });
+                        let latest_reset_seq = state_rx.borrow().reset_seqs.cw_decode_reset_seq;
+                        if latest_reset_seq != reset_seq {
+                            last_reset_seq = latest_reset_seq;
+                            decoder.reset();
+                            info!("CW decoder reset (seq={})", last_reset_seq);
+                            pcm_rx = pcm_rx.resubscribe();
+                            continue;
+                        }
+                        for evt in events {
+                            if let Some(logger) = decode_logs.as_ref() {
+                                logger.log_cw(&evt);
+                            }
+                            histories.record_cw_event(evt.clone());
+                            let _ = decode_tx.send(DecodedMessage::Cw(evt));
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
+                        warn!("CW decoder: dropped {} PCM frames", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            changed = state_rx.changed() => {
+                match changed {
+                    Ok(()) => {
+                        let state = state_rx.borrow();
+                        active = state.decoders.cw_decode_enabled
+                            && matches!(state.status.mode, RigMode::CW | RigMode::CWR);
+                        if state.cw_auto != last_auto {
+                            last_auto = state.cw_auto;
+                            decoder.set_auto(last_auto);
+                        }
+                        if state.cw_wpm != last_wpm {
+                            last_wpm = state.cw_wpm;
+                            decoder.set_wpm(last_wpm);
+                        }
+                        if state.cw_tone_hz != last_tone {
+                            last_tone = state.cw_tone_hz;
+                            decoder.set_tone_hz(last_tone);
+                        }
+                        if state.reset_seqs.cw_decode_reset_seq != last_reset_seq {
+                            last_reset_seq = state.reset_seqs.cw_decode_reset_seq;
+                            decoder.reset();
+                            info!("CW decoder reset (seq={})", last_reset_seq);
+                        }
+                        if !active && was_active {
+                            decoder.reset();
+                            was_active = false;
+                        }
+                        if active {
+                            pcm_rx = pcm_rx.resubscribe();
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+}
+
+fn psk_decode_active(state: &RigState) -> bool {
+    state.decoders.psk_decode_enabled
+        && matches!(
+            state.status.mode,
+            RigMode::DIG | RigMode::USB | RigMode::LSB
+        )
+}
+
+fn apply_psk_settings(decoder: &mut PskDecoder, state: &RigState) {
+    decoder.set_mode(state.psk_mode);
+    decoder.set_center_hz(state.psk_freq_hz);
+    decoder.set_waterfall(state.psk_waterfall);
+}
+
+/// Run the PSK31/63/125 / QPSK31 decoder task.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_psk_decoder(
+    sample_rate: u32,
+    channels: u16,
+    mut pcm_rx: broadcast::Receiver<Vec<f32>>,
+    mut state_rx: watch::Receiver<RigState>,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+    decode_logs: Option<Arc<DecoderLoggers>>,
+    histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
+) {
+    info!("PSK decoder started ({}Hz, {} ch)", sample_rate, channels);
+    let mut decoder = PskDecoder::new(sample_rate);
+    let mut was_active = false;
+    let mut last_reset_seq: u64 = 0;
+    let mut active = psk_decode_active(&state_rx.borrow());
+    apply_psk_settings(&mut decoder, &state_rx.borrow());
+
+    loop {
+        if !active {
+            match state_rx.changed().await {
+                Ok(()) => {
+                    let state = state_rx.borrow();
+                    active = psk_decode_active(&state);
+                    if active {
+                        pcm_rx = pcm_rx.resubscribe();
+                    }
+                    apply_psk_settings(&mut decoder, &state);
+                    if state.reset_seqs.psk_decode_reset_seq != last_reset_seq {
+                        last_reset_seq = state.reset_seqs.psk_decode_reset_seq;
+                        decoder.reset();
+                        info!("PSK decoder reset (seq={})", last_reset_seq);
+                    }
+                }
+                Err(_) => break,
+            }
+            continue;
+        }
+
+        tokio::select! {
+            recv = pcm_rx.recv() => {
+                match recv {
+                    Ok(frame) => {
+                        let (process_enabled, reset_seq) = {
+                            let state = state_rx.borrow();
+                            apply_psk_settings(&mut decoder, &state);
+                            (
+                                psk_decode_active(&state),
+                                state.reset_seqs.psk_decode_reset_seq,
+                            )
+                        };
+                        if reset_seq != last_reset_seq {
+                            last_reset_seq = reset_seq;
+                            decoder.reset();
+                            info!("PSK decoder reset (seq={})", last_reset_seq);
+                            pcm_rx = pcm_rx.resubscribe();
+                            continue;
+                        }
+                        if !process_enabled {
+                            if was_active {
+                                decoder.reset();
+                                was_active = false;
+                            }
+                            active = false;
+                            continue;
+                        }
+
+                        let mono = downmix_mono(frame, channels);
+                        was_active = true;
+                        let events = tokio::task::block_in_place(|| {
+                            let _span = info_span!("psk_decode").entered();
+                            decoder.process_samples(&mono)
+                        });
+                        let latest_reset_seq = state_rx.borrow().reset_seqs.psk_decode_reset_seq;
+                        if latest_reset_seq != reset_seq {
+                            last_reset_seq = latest_reset_seq;
+                            decoder.reset();
+                            info!("PSK decoder reset (seq={})", last_reset_seq);
+                            pcm_rx = pcm_rx.resubscribe();
+                            continue;
+                        }
+                        for mut evt in events {
+                            evt.ts_ms = Some(current_timestamp_ms());
+                            if let Some(logger) = decode_logs.as_ref() {
+                                logger.log_psk(&evt);
+                            }
+                            histories.record_psk_event(evt.clone());
+                            let _ = decode_tx.send(DecodedMessage::Psk(evt));
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
+                        warn!("PSK decoder: dropped {} PCM frames", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            changed = state_rx.changed() => {
+                match changed {
+                    Ok(()) => {
+                        let state = state_rx.borrow();
+                        active = psk_decode_active(&state);
+                        apply_psk_settings(&mut decoder, &state);
+                        if state.reset_seqs.psk_decode_reset_seq != last_reset_seq {
+                            last_reset_seq = state.reset_seqs.psk_decode_reset_seq;
+                            decoder.reset();
+                            info!("PSK decoder reset (seq={})", last_reset_seq);
+                        }
+                        if !active && was_active {
+                            decoder.reset();
+                            was_active = false;
+                        }
+                        if active {
+                            pcm_rx = pcm_rx.resubscribe();
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+}
+
+/// DSC band implied by the current mode, or `None` when DSC is inactive.
+///
+/// VHF channel 70 is received in FM; MF/HF distress frequencies in USB with
+/// the dial 1.7 kHz below the assigned frequency.
+fn dsc_decode_band(state: &RigState) -> Option<DscBand> {
+    if !state.decoders.dsc_decode_enabled {
+        return None;
+    }
+    match state.status.mode {
+        RigMode::FM | RigMode::PKT => Some(DscBand::Vhf),
+        RigMode::USB | RigMode::DIG => Some(DscBand::Hf),
+        _ => None,
+    }
+}
+
+/// Run the DSC (ITU-R M.493) decoder task.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_dsc_decoder(
+    sample_rate: u32,
+    channels: u16,
+    mut pcm_rx: broadcast::Receiver<Vec<f32>>,
+    mut state_rx: watch::Receiver<RigState>,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+    decode_logs: Option<Arc<DecoderLoggers>>,
+    histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
+) {
+    info!("DSC decoder started ({}Hz, {} ch)", sample_rate, channels);
+    let mut decoder = DscDecoder::new(sample_rate);
+    let mut was_active = false;
+    let mut last_reset_seq: u64 = 0;
+    let mut band = dsc_decode_band(&state_rx.borrow());
+
+    loop {
+        let Some(active_band) = band else {
+            match state_rx.changed().await {
+                Ok(()) => {
+                    let state = state_rx.borrow();
+                    band = dsc_decode_band(&state);
+                    if band.is_some() {
+                        pcm_rx = pcm_rx.resubscribe();
+                    }
+                    if state.reset_seqs.dsc_decode_reset_seq != last_reset_seq {
+                        last_reset_seq = state.reset_seqs.dsc_decode_reset_seq;
+                        decoder.reset();
+                        info!("DSC decoder reset (seq={})", last_reset_seq);
+                    }
+                }
+                Err(_) => break,
+            }
+            continue;
+        };
+        decoder.set_band(active_band);
+
+        tokio::select! {
+            recv = pcm_rx.recv() => {
+                match recv {
+                    Ok(frame) => {
+                        let reset_seq = state_rx.borrow().reset_seqs.dsc_decode_reset_seq;
+                        if reset_seq != last_reset_seq {
+                            last_reset_seq = reset_seq;
+                            decoder.reset();
+                            info!("DSC decoder reset (seq={})", last_reset_seq);
+                            pcm_rx = pcm_rx.resubscribe();
+                            continue;
+                        }
+
+                        let mono = downmix_mono(frame, channels);
+                        was_active = true;
+                        let messages = tokio::task::block_in_place(|| {
+                            let _span = info_span!("dsc_decode").entered();
+                            decoder.process_samples(&mono)
+                        });
+                        for mut msg in messages {
+                            msg.ts_ms = Some(current_timestamp_ms());
+                            histories.annotate_dsc_from_ais(&mut msg);
+                            if msg.category.is_priority() {
+                                warn!(
+                                    "DSC {:?} call from MMSI {:09}: {}",
+                                    msg.category,
+                                    msg.self_mmsi,
+                                    msg.nature.as_deref().unwrap_or("no details")
+                                );
+                            }
+                            if let Some(logger) = decode_logs.as_ref() {
+                                logger.log_dsc(&msg);
+                            }
+                            histories.record_dsc_message(msg.clone());
+                            let _ = decode_tx.send(DecodedMessage::Dsc(msg));
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
+                        warn!("DSC decoder: dropped {} PCM frames", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            changed = state_rx.changed() => {
+                match changed {
+                    Ok(()) => {
+                        let state = state_rx.borrow();
+                        band = dsc_decode_band(&state);
+                        if state.reset_seqs.dsc_decode_reset_seq != last_reset_seq {
+                            last_reset_seq = state.reset_seqs.dsc_decode_reset_seq;
+                            decoder.reset();
+                            info!("DSC decoder reset (seq={})", last_reset_seq);
+                        }
+                        if band.is_none() && was_active {
+                            decoder.reset();
+                            was_active = false;
+                        }
+                        if band.is_some() {
+                            pcm_rx = pcm_rx.resubscribe();
                         }
                     }
                     Err(_) => break,
@@ -1566,27 +2531,42 @@ pub async fn run_ais_decoder(
     }
 }
 
-/// Run the VDES decoder task. Only processes IQ when rig mode is VDES.
-pub async fn run_vdes_decoder(
+/// Run the POCSAG pager decoder task. Only processes PCM when the decoder
+/// is enabled and the rig mode is FM.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_pocsag_decoder(
     sample_rate: u32,
-    mut iq_rx: broadcast::Receiver<Vec<Complex<f32>>>,
+    channels: u16,
+    mut pcm_rx: broadcast::Receiver<Vec<f32>>,
     mut state_rx: watch::Receiver<RigState>,
     decode_tx: broadcast::Sender<DecodedMessage>,
+    decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
+    pocsag_cfg: PocsagConfig,
+    lag: Arc<Counter>,
 ) {
-    info!("VDES decoder started ({}Hz complex baseband)", sample_rate);
-    let mut decoder = VdesDecoder::new(sample_rate);
+    info!("POCSAG decoder started ({}Hz, {} ch)", sample_rate, channels);
+    let mut decoder = PocsagDecoder::new(sample_rate);
     let mut was_active = false;
-    let mut active = matches!(state_rx.borrow().status.mode, RigMode::VDES);
+    let mut last_reset_seq: u64 = 0;
+    let is_active = |state: &RigState| -> bool {
+        state.decoders.pocsag_decode_enabled && matches!(state.status.mode, RigMode::FM)
+    };
+    let mut active = is_active(&state_rx.borrow());
 
     loop {
         if !active {
             match state_rx.changed().await {
                 Ok(()) => {
                     let state = state_rx.borrow();
-                    active = matches!(state.status.mode, RigMode::VDES);
+                    active = is_active(&state);
                     if active {
-                        iq_rx = iq_rx.resubscribe();
+                        pcm_rx = pcm_rx.resubscribe();
+                    }
+                    if state.reset_seqs.pocsag_decode_reset_seq != last_reset_seq {
+                        last_reset_seq = state.reset_seqs.pocsag_decode_reset_seq;
+                        decoder.reset();
+                        info!("POCSAG decoder reset (seq={})", last_reset_seq);
                     }
                 }
                 Err(_) => break,
@@ -1595,25 +2575,37 @@ pub async fn run_vdes_decoder(
         }
 
         tokio::select! {
-            recv = iq_rx.recv() => {
+            recv = pcm_rx.recv() => {
                 match recv {
-                    Ok(block) => {
+                    Ok(frame) => {
+                        let reset_seq = state_rx.borrow().reset_seqs.pocsag_decode_reset_seq;
+                        if reset_seq != last_reset_seq {
+                            last_reset_seq = reset_seq;
+                            decoder.reset();
+                            info!("POCSAG decoder reset (seq={})", last_reset_seq);
+                            pcm_rx = pcm_rx.resubscribe();
+                            continue;
+                        }
+
+                        let mut mono = downmix_mono(frame, channels);
+                        apply_decode_audio_gate(&mut mono);
                         was_active = true;
-                        let messages =
-                            tokio::task::block_in_place(|| {
-                                let _span = info_span!("vdes_decode").entered();
-                                decoder.process_samples(&block, "Main")
-                            });
-                        for mut msg in messages {
-                            if msg.ts_ms.is_none() {
-                                msg.ts_ms = Some(current_timestamp_ms());
+                        let pages = tokio::task::block_in_place(|| {
+                            let _span = info_span!("pocsag_decode").entered();
+                            decoder.process_samples(&mono)
+                        });
+                        for mut msg in pages.into_iter().filter_map(|msg| pocsag_cfg.apply(msg)) {
+                            msg.ts_ms = Some(current_timestamp_ms());
+                            if let Some(logger) = decode_logs.as_ref() {
+                                logger.log_pocsag(&msg);
                             }
-                            histories.record_vdes_message(msg.clone());
-                            let _ = decode_tx.send(DecodedMessage::Vdes(msg));
+                            histories.record_pocsag_message(msg.clone());
+                            let _ = decode_tx.send(DecodedMessage::Pocsag(msg));
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
-                        warn!("VDES decoder: dropped {} IQ blocks", n);
+                        lag.add(n);
+                        warn!("POCSAG decoder: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
                 }
@@ -1622,13 +2614,18 @@ pub async fn run_vdes_decoder(
                 match changed {
                     Ok(()) => {
                         let state = state_rx.borrow();
-                        active = matches!(state.status.mode, RigMode::VDES);
+                        active = is_active(&state);
+                        if state.reset_seqs.pocsag_decode_reset_seq != last_reset_seq {
+                            last_reset_seq = state.reset_seqs.pocsag_decode_reset_seq;
+                            decoder.reset();
+                            info!("POCSAG decoder reset (seq={})", last_reset_seq);
+                        }
                         if !active && was_active {
                             decoder.reset();
                             was_active = false;
                         }
                         if active {
-                            iq_rx = iq_rx.resubscribe();
+                            pcm_rx = pcm_rx.resubscribe();
                         }
                     }
                     Err(_) => break,
@@ -1638,8 +2635,10 @@ pub async fn run_vdes_decoder(
     }
 }
 
-/// Run the CW decoder task. Only processes PCM when rig mode is CW or CWR.
-pub async fn run_cw_decoder(
+/// Run the ACARS decoder task. Only processes PCM when the decoder is
+/// enabled and the rig mode is AM.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_acars_decoder(
     sample_rate: u32,
     channels: u16,
     mut pcm_rx: broadcast::Receiver<Vec<f32>>,
@@ -1647,46 +2646,30 @@ pub async fn run_cw_decoder(
     decode_tx: broadcast::Sender<DecodedMessage>,
     decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
-    info!("CW decoder started ({}Hz, {} ch)", sample_rate, channels);
-    let mut decoder = CwDecoder::new(sample_rate);
+    info!("ACARS decoder started ({}Hz, {} ch)", sample_rate, channels);
+    let mut decoder = AcarsDecoder::new(sample_rate);
     let mut was_active = false;
     let mut last_reset_seq: u64 = 0;
-    let mut active = state_rx.borrow().decoders.cw_decode_enabled
-        && matches!(state_rx.borrow().status.mode, RigMode::CW | RigMode::CWR);
-    let mut last_auto = state_rx.borrow().cw_auto;
-    let mut last_wpm = state_rx.borrow().cw_wpm;
-    let mut last_tone = state_rx.borrow().cw_tone_hz;
-    decoder.set_auto(last_auto);
-    decoder.set_wpm(last_wpm);
-    decoder.set_tone_hz(last_tone);
+    let is_active = |state: &RigState| -> bool {
+        state.decoders.acars_decode_enabled && matches!(state.status.mode, RigMode::AM)
+    };
+    let mut active = is_active(&state_rx.borrow());
 
     loop {
         if !active {
             match state_rx.changed().await {
                 Ok(()) => {
                     let state = state_rx.borrow();
-                    active = state.decoders.cw_decode_enabled
-                        && matches!(state.status.mode, RigMode::CW | RigMode::CWR);
+                    active = is_active(&state);
                     if active {
                         pcm_rx = pcm_rx.resubscribe();
                     }
-                    if state.cw_auto != last_auto {
-                        last_auto = state.cw_auto;
-                        decoder.set_auto(last_auto);
-                    }
-                    if state.cw_wpm != last_wpm {
-                        last_wpm = state.cw_wpm;
-                        decoder.set_wpm(last_wpm);
-                    }
-                    if state.cw_tone_hz != last_tone {
-                        last_tone = state.cw_tone_hz;
-                        decoder.set_tone_hz(last_tone);
-                    }
-                    if state.reset_seqs.cw_decode_reset_seq != last_reset_seq {
-                        last_reset_seq = state.reset_seqs.cw_decode_reset_seq;
+                    if state.reset_seqs.acars_decode_reset_seq != last_reset_seq {
+                        last_reset_seq = state.reset_seqs.acars_decode_reset_seq;
                         decoder.reset();
-                        info!("CW decoder reset (seq={})", last_reset_seq);
+                        info!("ACARS decoder reset (seq={})", last_reset_seq);
                     }
                 }
                 Err(_) => break,
@@ -1698,79 +2681,36 @@ pub async fn run_cw_decoder(
             recv = pcm_rx.recv() => {
                 match recv {
                     Ok(frame) => {
-                        let (process_enabled, cw_auto, cw_wpm, cw_tone_hz, reset_seq) = {
-                            let state = state_rx.borrow();
-                            (
-                                state.decoders.cw_decode_enabled
-                                    && matches!(state.status.mode, RigMode::CW | RigMode::CWR),
-                                state.cw_auto,
-                                state.cw_wpm,
-                                state.cw_tone_hz,
-                                state.reset_seqs.cw_decode_reset_seq,
-                            )
-                        };
-                        if cw_auto != last_auto {
-                            last_auto = cw_auto;
-                            decoder.set_auto(last_auto);
-                        }
-                        if cw_wpm != last_wpm {
-                            last_wpm = cw_wpm;
-                            decoder.set_wpm(last_wpm);
-                        }
-                        if cw_tone_hz != last_tone {
-                            last_tone = cw_tone_hz;
-                            decoder.set_tone_hz(last_tone);
-                        }
+                        let reset_seq = state_rx.borrow().reset_seqs.acars_decode_reset_seq;
                         if reset_seq != last_reset_seq {
                             last_reset_seq = reset_seq;
                             decoder.reset();
-                            info!("CW decoder reset (seq={})", last_reset_seq);
+                            info!("ACARS decoder reset (seq={})", last_reset_seq);
                             pcm_rx = pcm_rx.resubscribe();
                             continue;
                         }
-                        if !process_enabled {
-                            if was_active {
-                                decoder.reset();
-                                was_active = false;
-                            }
-                            active = false;
-                            continue;
-                        }
 
-                        // Downmix to mono if stereo
-                        let mono = if channels > 1 {
-                            let num_frames = frame.len() / channels as usize;
-                            let mut mono = Vec::with_capacity(num_frames);
-                            for i in 0..num_frames {
-                                mono.push(frame[i * channels as usize]);
-                            }
-                            mono
-                        } else {
-                            frame
-                        };
+                        let mut mono = downmix_mono(frame, channels);
+                        apply_decode_audio_gate(&mut mono);
                         was_active = true;
-                        let events = tokio::task::block_in_place(|| {
-                            let _span = info_span!("cw_decode").entered();
+                        let blocks = tokio::task::block_in_place(|| {
+                            let _span = info_span!("acars_decode").entered();
                             decoder.process_samples(&mono)
                         });
-                        let latest_reset_seq = state_rx.borrow().reset_seqs.cw_decode_reset_seq;
-                        if latest_reset_seq != reset_seq {
-                            last_reset_seq = latest_reset_seq;
-                            decoder.reset();
-                            info!("CW decoder reset (seq={})", last_reset_seq);
-                            pcm_rx = pcm_rx.resubscribe();
-                            continue;
-                        }
-                        for evt in events {
+                        let freq_hz = state_rx.borrow().status.freq.hz;
+                        for mut msg in blocks {
+                            msg.ts_ms = Some(current_timestamp_ms());
+                            msg.freq_hz = Some(freq_hz);
                             if let Some(logger) = decode_logs.as_ref() {
-                                logger.log_cw(&evt);
+                                logger.log_acars(&msg);
                             }
-                            histories.record_cw_event(evt.clone());
-                            let _ = decode_tx.send(DecodedMessage::Cw(evt));
+                            histories.record_acars_message(msg.clone());
+                            let _ = decode_tx.send(DecodedMessage::Acars(msg));
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
-                        warn!("CW decoder: dropped {} PCM frames", n);
+                        lag.add(n);
+                        warn!("ACARS decoder: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
                 }
@@ -1779,24 +2719,11 @@ pub async fn run_cw_decoder(
                 match changed {
                     Ok(()) => {
                         let state = state_rx.borrow();
-                        active = state.decoders.cw_decode_enabled
-                            && matches!(state.status.mode, RigMode::CW | RigMode::CWR);
-                        if state.cw_auto != last_auto {
-                            last_auto = state.cw_auto;
-                            decoder.set_auto(last_auto);
-                        }
-                        if state.cw_wpm != last_wpm {
-                            last_wpm = state.cw_wpm;
-                            decoder.set_wpm(last_wpm);
-                        }
-                        if state.cw_tone_hz != last_tone {
-                            last_tone = state.cw_tone_hz;
-                            decoder.set_tone_hz(last_tone);
-                        }
-                        if state.reset_seqs.cw_decode_reset_seq != last_reset_seq {
-                            last_reset_seq = state.reset_seqs.cw_decode_reset_seq;
+                        active = is_active(&state);
+                        if state.reset_seqs.acars_decode_reset_seq != last_reset_seq {
+                            last_reset_seq = state.reset_seqs.acars_decode_reset_seq;
                             decoder.reset();
-                            info!("CW decoder reset (seq={})", last_reset_seq);
+                            info!("ACARS decoder reset (seq={})", last_reset_seq);
                         }
                         if !active && was_active {
                             decoder.reset();
@@ -1869,6 +2796,7 @@ fn resample_to_12k(samples: &[f32], sample_rate: u32) -> Option<Vec<f32>> {
 }
 
 /// Run the FT8 decoder task. Only processes PCM when rig mode is DIG/USB and enabled.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_ft8_decoder(
     sample_rate: u32,
     channels: u16,
@@ -1877,6 +2805,7 @@ pub async fn run_ft8_decoder(
     decode_tx: broadcast::Sender<DecodedMessage>,
     decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     run_ftx_decoder_inner(
         "FT8",
@@ -1888,6 +2817,7 @@ pub async fn run_ft8_decoder(
         decode_logs,
         histories,
         false,
+        lag,
     )
     .await;
 }
@@ -1900,6 +2830,7 @@ pub async fn run_ft4_decoder(
     state_rx: watch::Receiver<RigState>,
     decode_tx: broadcast::Sender<DecodedMessage>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     run_ftx_decoder_inner(
         "FT4",
@@ -1911,6 +2842,7 @@ pub async fn run_ft4_decoder(
         None,
         histories,
         true,
+        lag,
     )
     .await;
 }
@@ -1927,6 +2859,7 @@ async fn run_ftx_decoder_inner(
     decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
     is_ft4: bool,
+    lag: Arc<Counter>,
 ) {
     info!(
         "{} decoder started ({}Hz, {} ch)",
@@ -2084,6 +3017,7 @@ async fn run_ftx_decoder_inner(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("{} decoder: dropped {} PCM frames", label, n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -2125,6 +3059,7 @@ pub async fn run_ft2_decoder(
     mut state_rx: watch::Receiver<RigState>,
     decode_tx: broadcast::Sender<DecodedMessage>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     info!("FT2 decoder started ({}Hz, {} ch)", sample_rate, channels);
     let mut decoder = match Ft8Decoder::new_ft2(FT8_SAMPLE_RATE) {
@@ -2244,6 +3179,7 @@ pub async fn run_ft2_decoder(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("FT2 decoder: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -2282,6 +3218,7 @@ pub async fn run_ft2_decoder(
 ///
 /// Note: decoding engine integration is intentionally staged; this task already
 /// participates in enable/disable/reset flow and transport plumbing.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_wspr_decoder(
     sample_rate: u32,
     channels: u16,
@@ -2290,6 +3227,7 @@ pub async fn run_wspr_decoder(
     decode_tx: broadcast::Sender<DecodedMessage>,
     decode_logs: Option<Arc<DecoderLoggers>>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     info!("WSPR decoder started ({}Hz, {} ch)", sample_rate, channels);
     let decoder = match WsprDecoder::new() {
@@ -2415,6 +3353,7 @@ pub async fn run_wspr_decoder(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("WSPR decoder: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -2454,6 +3393,7 @@ pub async fn run_wspr_decoder(
 /// The task is idle until `state.decoders.lrpt_decode_enabled` becomes `true`.
 /// When disabled (or 30 s of silence elapses with no new MCUs), the
 /// accumulated image is saved and broadcast.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_lrpt_decoder(
     sample_rate: u32,
     channels: u16,
@@ -2462,6 +3402,7 @@ pub async fn run_lrpt_decoder(
     decode_tx: broadcast::Sender<DecodedMessage>,
     histories: Arc<DecoderHistories>,
     output_dir: std::path::PathBuf,
+    lag: Arc<Counter>,
 ) {
     use trx_wxsat::lrpt::LrptDecoder;
 
@@ -2527,6 +3468,7 @@ pub async fn run_lrpt_decoder(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("LRPT decoder: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -2699,6 +3641,7 @@ pub async fn run_wefax_decoder(
     mut state_rx: watch::Receiver<RigState>,
     decode_tx: broadcast::Sender<DecodedMessage>,
     histories: Arc<DecoderHistories>,
+    lag: Arc<Counter>,
 ) {
     use trx_wefax::{WefaxConfig, WefaxDecoder, WefaxEvent};
 
@@ -2838,6 +3781,7 @@ pub async fn run_wefax_decoder(
                         }
                     }
                     Err(broadcast::error::RecvError::Lagged(n)) => {
+                        lag.add(n);
                         warn!("WEFAX decoder: dropped {} PCM frames", n);
                     }
                     Err(broadcast::error::RecvError::Closed) => break,
@@ -2952,6 +3896,72 @@ async fn run_background_aprs_decoder(
     }
 }
 
+async fn run_background_pocsag_decoder(
+    sample_rate: u32,
+    channels: u16,
+    mut pcm_rx: broadcast::Receiver<Vec<f32>>,
+    pocsag_cfg: PocsagConfig,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+) {
+    info!(
+        "Background POCSAG decoder started ({}Hz, {} ch)",
+        sample_rate, channels
+    );
+    let mut decoder = PocsagDecoder::new(sample_rate);
+
+    loop {
+        match pcm_rx.recv().await {
+            Ok(frame) => {
+                let mut mono = downmix_if_needed(frame, channels);
+                apply_decode_audio_gate(&mut mono);
+                for msg in decoder.process_samples(&mono) {
+                    let Some(mut msg) = pocsag_cfg.apply(msg) else {
+                        continue;
+                    };
+                    msg.ts_ms = Some(current_timestamp_ms());
+                    let _ = decode_tx.send(DecodedMessage::Pocsag(msg));
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(n)) => {
+                warn!("Background POCSAG decoder: dropped {} PCM frames", n);
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+async fn run_background_acars_decoder(
+    sample_rate: u32,
+    channels: u16,
+    mut pcm_rx: broadcast::Receiver<Vec<f32>>,
+    base_freq_hz: u64,
+    decode_tx: broadcast::Sender<DecodedMessage>,
+) {
+    info!(
+        "Background ACARS decoder started ({}Hz, {} ch, {} Hz)",
+        sample_rate, channels, base_freq_hz
+    );
+    let mut decoder = AcarsDecoder::new(sample_rate);
+
+    loop {
+        match pcm_rx.recv().await {
+            Ok(frame) => {
+                let mut mono = downmix_if_needed(frame, channels);
+                apply_decode_audio_gate(&mut mono);
+                for mut msg in decoder.process_samples(&mono) {
+                    msg.ts_ms = Some(current_timestamp_ms());
+                    msg.freq_hz = Some(base_freq_hz);
+                    let _ = decode_tx.send(DecodedMessage::Acars(msg));
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(n)) => {
+                warn!("Background ACARS decoder: dropped {} PCM frames", n);
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
 async fn run_background_ais_decoder(
     sample_rate: u32,
     channels: u16,
@@ -3302,7 +4312,11 @@ async fn run_background_wspr_decoder(
     }
 }
 
-/// Run the audio TCP listener, accepting client connections.
+/// Run the audio TCP listener, accepting client connections.  With `tls` set
+/// every connection must complete a TLS handshake first.  Connected clients
+/// and frames they miss are recorded in `metrics`.  Audio streams of
+/// multiplexed control connections arrive on `mux_streams`; their TX audio is
+/// only accepted when the session token holds tx scope.
 #[allow(clippy::too_many_arguments)]
 pub async fn run_audio_listener(
     addr: SocketAddr,
@@ -3313,30 +4327,29 @@ pub async fn run_audio_listener(
     mut shutdown_rx: watch::Receiver<bool>,
     histories: Arc<DecoderHistories>,
     vchan_manager: Option<SharedVChanManager>,
+    pocsag_cfg: PocsagConfig,
+    tls: Option<TlsAcceptor>,
+    metrics: Arc<RigMetrics>,
+    mut mux_streams: mpsc::Receiver<MuxAudioStream>,
 ) -> std::io::Result<()> {
     let listener = TcpListener::bind(addr).await?;
-    info!("Audio listener on {}", addr);
+    info!(
+        "Audio listener on {}{}",
+        addr,
+        if tls.is_some() { " (TLS)" } else { "" }
+    );
 
     loop {
-        tokio::select! {
+        // Streams handed over from a multiplexed control connection are
+        // already past TLS and authorization.
+        let (conn, peer) = tokio::select! {
             accept = listener.accept() => {
                 let (socket, peer) = accept?;
-                info!("Audio client connected: {}", peer);
-
-                let rx_audio = rx_audio.clone();
-                let tx_audio = tx_audio.clone();
-                let info = stream_info.clone();
-                let decode_tx = decode_tx.clone();
-                let client_shutdown_rx = shutdown_rx.clone();
-                let client_histories = histories.clone();
-                let client_vchan_mgr = vchan_manager.clone();
-
-                tokio::spawn(async move {
-                    if let Err(e) = handle_audio_client(socket, peer, rx_audio, tx_audio, info, decode_tx, client_shutdown_rx, client_histories, client_vchan_mgr).await {
-                        warn!("Audio client {} error: {:?}", peer, e);
-                    }
-                    info!("Audio client {} disconnected", peer);
-                });
+                (AudioConn::Tcp(socket), peer)
+            }
+            Some(mux) = mux_streams.recv() => {
+                let peer = mux.peer;
+                (AudioConn::Mux(mux), peer)
             }
             changed = shutdown_rx.changed() => {
                 match changed {
@@ -3344,15 +4357,59 @@ pub async fn run_audio_listener(
                         info!("Audio listener shutting down");
                         break;
                     }
-                    Ok(()) => {}
+                    Ok(()) => continue,
                     Err(_) => break,
                 }
             }
-        }
+        };
+        info!("Audio client connected: {}", peer);
+
+        let rx_audio = rx_audio.clone();
+        let tx_audio = tx_audio.clone();
+        let info = stream_info.clone();
+        let decode_tx = decode_tx.clone();
+        let client_shutdown_rx = shutdown_rx.clone();
+        let client_histories = histories.clone();
+        let client_vchan_mgr = vchan_manager.clone();
+        let client_pocsag_cfg = pocsag_cfg.clone();
+        let client_tls = tls.clone();
+        let client_metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            // The audio port is guarded by TLS and network access alone, so
+            // its clients may always send TX audio.
+            let (socket, tx_allowed) = match conn {
+                AudioConn::Mux(mux) => (mux.stream, mux.tx_allowed),
+                AudioConn::Tcp(socket) => match tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, TransportStream::accept(socket, client_tls.as_ref())).await {
+                    Ok(Ok(socket)) => (socket, true),
+                    Ok(Err(e)) => {
+                        warn!("Audio client {} TLS handshake failed: {}", peer, e);
+                        return;
+                    }
+                    Err(_) => {
+                        warn!("Audio client {} TLS handshake timed out", peer);
+                        return;
+                    }
+                },
+            };
+            client_metrics.audio_clients.inc();
+            if let Err(e) = handle_audio_client(socket, peer, rx_audio, tx_audio, tx_allowed, info, decode_tx, client_shutdown_rx, client_histories, client_vchan_mgr, client_pocsag_cfg, &client_metrics).await {
+                warn!("Audio client {} error: {:?}", peer, e);
+            }
+            client_metrics.audio_clients.dec();
+            info!("Audio client {} disconnected", peer);
+        });
     }
     Ok(())
 }
 
+/// A new audio client: a socket from the audio port, or a stream of a
+/// multiplexed control connection.
+enum AudioConn {
+    Tcp(TcpStream),
+    Mux(MuxAudioStream),
+}
+
 /// Returns the next destroyed-channel UUID, or `pending()` when the receiver
 /// has been closed or is not present.  Disables itself on close so the
 /// enclosing `select!` never busy-loops on a dead channel.
@@ -3372,17 +4429,20 @@ async fn recv_destroyed(rx: &mut Option<broadcast::Receiver<Uuid>>) -> Option<Uu
 
 #[allow(clippy::too_many_arguments)]
 async fn handle_audio_client(
-    socket: TcpStream,
+    socket: TransportStream,
     peer: SocketAddr,
     rx_audio: broadcast::Sender<Bytes>,
     tx_audio: mpsc::Sender<Bytes>,
+    tx_allowed: bool,
     stream_info: AudioStreamInfo,
     decode_tx: broadcast::Sender<DecodedMessage>,
     mut shutdown_rx: watch::Receiver<bool>,
     histories: Arc<DecoderHistories>,
     vchan_manager: Option<SharedVChanManager>,
+    pocsag_cfg: PocsagConfig,
+    metrics: &RigMetrics,
 ) -> std::io::Result<()> {
-    let (reader, writer) = socket.into_split();
+    let (reader, writer) = tokio::io::split(socket);
     let mut reader = tokio::io::BufReader::new(reader);
     let mut writer = tokio::io::BufWriter::new(writer);
 
@@ -3463,6 +4523,31 @@ async fn handle_audio_client(
             DecodedMessage::Cw,
             AUDIO_MSG_CW_DECODE
         );
+        push_history!(
+            histories.snapshot_dsc_history(),
+            DecodedMessage::Dsc,
+            AUDIO_MSG_DSC_DECODE
+        );
+        push_history!(
+            histories.snapshot_pocsag_history(),
+            DecodedMessage::Pocsag,
+            AUDIO_MSG_POCSAG_DECODE
+        );
+        push_history!(
+            histories.snapshot_adsb_history(),
+            DecodedMessage::Adsb,
+            AUDIO_MSG_ADSB_DECODE
+        );
+        push_history!(
+            histories.snapshot_acars_history(),
+            DecodedMessage::Acars,
+            AUDIO_MSG_ACARS_DECODE
+        );
+        push_history!(
+            histories.snapshot_psk_history(),
+            DecodedMessage::Psk,
+            AUDIO_MSG_PSK_DECODE
+        );
         push_history!(
             histories.snapshot_lrpt_history(),
             DecodedMessage::LrptImage,
@@ -3505,6 +4590,8 @@ async fn handle_audio_client(
     let mut rx_sub = rx_audio.subscribe();
     let mut decode_sub = decode_tx.subscribe();
     let mut writer_for_rx = writer;
+    let rx_lag = metrics.broadcast_lag("rx_audio");
+    let decode_lag = metrics.broadcast_lag("decode");
 
     // (uuid, opus_bytes) produced by per-channel encoder tasks.
     let (vchan_frame_tx, mut vchan_frame_rx) = mpsc::channel::<(Uuid, Bytes)>(256);
@@ -3538,6 +4625,7 @@ async fn handle_audio_client(
                             }
                         }
                         Err(broadcast::error::RecvError::Lagged(n)) => {
+                            rx_lag.add(n);
                             warn!("Audio RX: {} dropped {} frames", peer, n);
                         }
                         Err(broadcast::error::RecvError::Closed) => break,
@@ -3552,6 +4640,12 @@ async fn handle_audio_client(
                                 DecodedMessage::Aprs(_) => AUDIO_MSG_APRS_DECODE,
                                 DecodedMessage::HfAprs(_) => AUDIO_MSG_HF_APRS_DECODE,
                                 DecodedMessage::Cw(_) => AUDIO_MSG_CW_DECODE,
+                                DecodedMessage::CwSpot(_) => AUDIO_MSG_CW_SPOT,
+                                DecodedMessage::Psk(_) => AUDIO_MSG_PSK_DECODE,
+                                DecodedMessage::Dsc(_) => AUDIO_MSG_DSC_DECODE,
+                                DecodedMessage::Pocsag(_) => AUDIO_MSG_POCSAG_DECODE,
+                                DecodedMessage::Adsb(_) => AUDIO_MSG_ADSB_DECODE,
+                                DecodedMessage::Acars(_) => AUDIO_MSG_ACARS_DECODE,
                                 DecodedMessage::Ft8(_) => AUDIO_MSG_FT8_DECODE,
                                 DecodedMessage::Ft4(_) => AUDIO_MSG_FT4_DECODE,
                                 DecodedMessage::Ft2(_) => AUDIO_MSG_FT2_DECODE,
@@ -3570,6 +4664,7 @@ async fn handle_audio_client(
                             }
                         }
                         Err(broadcast::error::RecvError::Lagged(n)) => {
+                            decode_lag.add(n);
                             warn!("Audio decode: {} dropped {} messages", peer, n);
                         }
                         Err(broadcast::error::RecvError::Closed) => break,
@@ -3584,6 +4679,12 @@ async fn handle_audio_client(
                                 DecodedMessage::Aprs(_) => AUDIO_MSG_APRS_DECODE,
                                 DecodedMessage::HfAprs(_) => AUDIO_MSG_HF_APRS_DECODE,
                                 DecodedMessage::Cw(_) => AUDIO_MSG_CW_DECODE,
+                                DecodedMessage::CwSpot(_) => AUDIO_MSG_CW_SPOT,
+                                DecodedMessage::Psk(_) => AUDIO_MSG_PSK_DECODE,
+                                DecodedMessage::Dsc(_) => AUDIO_MSG_DSC_DECODE,
+                                DecodedMessage::Pocsag(_) => AUDIO_MSG_POCSAG_DECODE,
+                                DecodedMessage::Adsb(_) => AUDIO_MSG_ADSB_DECODE,
+                                DecodedMessage::Acars(_) => AUDIO_MSG_ACARS_DECODE,
                                 DecodedMessage::Ft8(_) => AUDIO_MSG_FT8_DECODE,
                                 DecodedMessage::Ft4(_) => AUDIO_MSG_FT4_DECODE,
                                 DecodedMessage::Ft2(_) => AUDIO_MSG_FT2_DECODE,
@@ -3753,6 +4854,29 @@ async fn handle_audio_client(
                                             )
                                             .await;
                                         }),
+                                        "pocsag" => {
+                                            let pocsag_cfg = pocsag_cfg.clone();
+                                            tokio::spawn(async move {
+                                                run_background_pocsag_decoder(
+                                                    sr,
+                                                    ch_count,
+                                                    task_rx,
+                                                    pocsag_cfg,
+                                                    decode_tx,
+                                                )
+                                                .await;
+                                            })
+                                        }
+                                        "acars" => tokio::spawn(async move {
+                                            run_background_acars_decoder(
+                                                sr,
+                                                ch_count,
+                                                task_rx,
+                                                base_freq_hz,
+                                                decode_tx,
+                                            )
+                                            .await;
+                                        }),
                                         "hf-aprs" => tokio::spawn(async move {
                                             run_background_hf_aprs_decoder(
                                                 sr,
@@ -3851,6 +4975,7 @@ async fn handle_audio_client(
     });
 
     // Read TX frames (and virtual-channel sub/unsub commands) from client.
+    let mut tx_refused = false;
     loop {
         let msg = tokio::select! {
             msg = read_audio_msg(&mut reader) => msg,
@@ -3870,7 +4995,12 @@ async fn handle_audio_client(
         };
         match msg {
             Ok((AUDIO_MSG_TX_FRAME, payload)) => {
-                let _ = tx_audio.send(Bytes::from(payload)).await;
+                if tx_allowed {
+                    let _ = tx_audio.send(Bytes::from(payload)).await;
+                } else if !tx_refused {
+                    tx_refused = true;
+                    warn!("Audio client {} has no tx scope; dropping its TX audio", peer);
+                }
             }
             Ok((AUDIO_MSG_VCHAN_SUB, payload)) => {
                 if let Some(ref mgr) = vchan_manager {
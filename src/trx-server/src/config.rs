@@ -15,18 +15,24 @@ use std::net::IpAddr;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
+use trx_app::tls::{TlsClientConfig, TlsServerConfig};
 use trx_app::{validate_log_level, validate_tokens, ConfigError, ConfigFile};
 pub use trx_decode_log::DecodeLogsConfig;
 
+use trx_core::decode::PocsagMessage;
+use trx_core::keyer::{CwKeyerOutput, CwMemory, DEFAULT_WEIGHT};
 use trx_core::rig::state::RigMode;
+use trx_protocol::auth::{hash_token, parse_token_hash, ScopedTokenValidator, TokenGrant};
+use trx_protocol::types::Scope;
 
 /// Per-rig instance configuration for multi-rig setups.
 ///
 /// Each entry in `[[rigs]]` becomes one of these.  The flat top-level
 /// `[rig]` / `[audio]` / `[sdr]` / `[pskreporter]` / `[aprsfi]` /
-/// `[behavior]` / `[decode_logs]` fields are still supported via
-/// `ServerConfig::resolved_rigs()` which synthesises a single-element list
-/// with `id = "default"` when `rigs` is empty.
+/// `[behavior]` / `[decode_logs]` / `[pocsag]` / `[adsb]` / `[skimmer]` fields
+/// are still
+/// supported via `ServerConfig::resolved_rigs()` which synthesises a
+/// single-element list with `id = "default"` when `rigs` is empty.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RigInstanceConfig {
@@ -52,6 +58,16 @@ pub struct RigInstanceConfig {
     pub aprsfi: AprsFiConfig,
     /// Decoder file logging for this rig.
     pub decode_logs: DecodeLogsConfig,
+    /// POCSAG capcode filter and privacy settings for this rig.
+    pub pocsag: PocsagConfig,
+    /// ADS-B Beast/SBS-1 TCP feeds for this rig.
+    pub adsb: AdsbConfig,
+    /// CW skimmer across this rig's SDR passband.
+    pub skimmer: SkimmerConfig,
+    /// Antenna rotator driven alongside this rig.
+    pub rotator: RotatorConfig,
+    /// CW keyer and message memories for this rig.
+    pub cw_keyer: CwKeyerConfig,
 }
 
 impl Default for RigInstanceConfig {
@@ -67,6 +83,11 @@ impl Default for RigInstanceConfig {
             pskreporter: PskReporterConfig::default(),
             aprsfi: AprsFiConfig::default(),
             decode_logs: DecodeLogsConfig::default(),
+            pocsag: PocsagConfig::default(),
+            adsb: AdsbConfig::default(),
+            skimmer: SkimmerConfig::default(),
+            rotator: RotatorConfig::default(),
+            cw_keyer: CwKeyerConfig::default(),
         }
     }
 }
@@ -99,10 +120,28 @@ pub struct ServerConfig {
     pub aprsfi: AprsFiConfig,
     /// Decoder file logging configuration (legacy flat)
     pub decode_logs: DecodeLogsConfig,
+    /// POCSAG decoder settings (legacy flat)
+    pub pocsag: PocsagConfig,
+    /// ADS-B feed settings (legacy flat)
+    pub adsb: AdsbConfig,
+    /// CW skimmer settings (legacy flat)
+    pub skimmer: SkimmerConfig,
+    /// Antenna rotator settings (legacy flat)
+    pub rotator: RotatorConfig,
+    /// CW keyer settings (legacy flat)
+    pub cw_keyer: CwKeyerConfig,
     /// SDR pipeline configuration (legacy flat; used when [rig.access] type = "sdr").
     pub sdr: SdrConfig,
     /// Timeout and buffer-size tuning knobs.
     pub timeouts: TimeoutsConfig,
+    /// Satellite TLE sources and caching.
+    pub tle: TleConfig,
+    /// MQTT publisher shared by all rigs.
+    pub mqtt: MqttConfig,
+    /// Prometheus `/metrics` endpoint.
+    pub metrics: MetricsConfig,
+    /// Outbound connection to a trx-client.
+    pub uplink: UplinkConfig,
     /// Multi-rig instance list. When non-empty, takes priority over the flat fields.
     #[serde(rename = "rigs", default)]
     pub rigs: Vec<RigInstanceConfig>,
@@ -249,6 +288,8 @@ pub struct ListenConfig {
     pub port: u16,
     /// Authentication configuration
     pub auth: AuthConfig,
+    /// TLS settings (plaintext when disabled)
+    pub tls: TlsServerConfig,
 }
 
 impl Default for ListenConfig {
@@ -258,16 +299,209 @@ impl Default for ListenConfig {
             listen: IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
             port: 4530,
             auth: AuthConfig::default(),
+            tls: TlsServerConfig::default(),
         }
     }
 }
 
+/// Outbound connection to a trx-client (`[uplink]`), for sites that cannot
+/// accept inbound connections.  The client's requests and audio then run
+/// over the connection the server opened.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UplinkConfig {
+    /// Whether the server dials out
+    pub enabled: bool,
+    /// Client uplink listener (`host:port` or `tls://host:port`)
+    pub url: String,
+    /// Name the client knows this server by (its `uplink://<station>` remotes)
+    pub station: String,
+    /// Token presented to the client's uplink listener
+    pub token: Option<String>,
+    /// Longest wait between reconnect attempts, in seconds
+    pub max_backoff_secs: u64,
+    /// TLS settings (`enabled` is also set by a `tls://` URL)
+    pub tls: TlsClientConfig,
+}
+
+impl Default for UplinkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            station: String::new(),
+            token: None,
+            max_backoff_secs: 60,
+            tls: TlsClientConfig::default(),
+        }
+    }
+}
+
+/// Default port of the client's uplink listener.
+pub const DEFAULT_UPLINK_PORT: u16 = 4540;
+
+/// Where an uplink dials: socket address, host name for TLS, and whether
+/// TLS is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UplinkEndpoint {
+    pub addr: String,
+    pub host: String,
+    pub tls: bool,
+}
+
+impl UplinkConfig {
+    /// Parse `url`, applying the default port.
+    pub fn endpoint(&self) -> Result<UplinkEndpoint, String> {
+        let url = self.url.trim();
+        let (rest, tls) = match url.strip_prefix("tls://") {
+            Some(rest) => (rest, true),
+            None => (url.strip_prefix("tcp://").unwrap_or(url), self.tls.enabled),
+        };
+        let (host, port) = match rest.strip_prefix('[') {
+            Some(v6) => {
+                let (host, tail) = v6
+                    .split_once(']')
+                    .ok_or("missing closing ']' for IPv6 host")?;
+                (host, tail.strip_prefix(':'))
+            }
+            None if rest.matches(':').count() == 1 => {
+                let (host, port) = rest.split_once(':').unwrap_or((rest, ""));
+                (host, Some(port))
+            }
+            None => (rest, None),
+        };
+        if host.is_empty() {
+            return Err("host is empty".to_string());
+        }
+        let port = match port {
+            Some(port) => port
+                .parse::<u16>()
+                .ok()
+                .filter(|port| *port > 0)
+                .ok_or_else(|| format!("invalid port '{port}'"))?,
+            None => DEFAULT_UPLINK_PORT,
+        };
+        let addr = if host.contains(':') {
+            format!("[{host}]:{port}")
+        } else {
+            format!("{host}:{port}")
+        };
+        Ok(UplinkEndpoint {
+            addr,
+            host: host.to_string(),
+            tls,
+        })
+    }
+}
+
 /// Authentication configuration for the TCP listener.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AuthConfig {
-    /// Valid authentication tokens (empty = no auth required)
+    /// Valid authentication tokens with full access to every rig
+    /// (empty together with `grants` = no auth required)
     pub tokens: Vec<String>,
+    /// Scoped tokens (`[[listen.auth.grants]]`)
+    pub grants: Vec<TokenGrantConfig>,
+}
+
+impl AuthConfig {
+    /// Build the listener's token validator.  Entries in `tokens` get full
+    /// access; `grants` keep their scope, rig list and expiry.
+    pub fn validator(&self) -> Result<ScopedTokenValidator, String> {
+        let mut grants: Vec<TokenGrant> = self
+            .tokens
+            .iter()
+            .filter(|t| !t.is_empty())
+            .enumerate()
+            .map(|(i, token)| TokenGrant::unrestricted(format!("tokens[{i}]"), token))
+            .collect();
+        for (i, grant) in self.grants.iter().enumerate() {
+            let path = format!("[[listen.auth.grants]][{i}]");
+            grants.push(grant.to_grant(&path)?);
+        }
+        Ok(ScopedTokenValidator::new(grants))
+    }
+}
+
+/// One scoped token in `[[listen.auth.grants]]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TokenGrantConfig {
+    /// Label used in logs
+    pub name: Option<String>,
+    /// Plaintext token; prefer `token_sha256`
+    pub token: Option<String>,
+    /// Hex SHA-256 of the token (`printf %s TOKEN | sha256sum`)
+    pub token_sha256: Option<String>,
+    /// Access level: read, control, tx or admin
+    pub scope: Scope,
+    /// Rig IDs the token may access (empty = all rigs)
+    pub rigs: Vec<String>,
+    /// RFC 3339 timestamp, or a date valid through its end (UTC)
+    pub expires: Option<String>,
+}
+
+impl Default for TokenGrantConfig {
+    fn default() -> Self {
+        Self {
+            name: None,
+            token: None,
+            token_sha256: None,
+            scope: Scope::Read,
+            rigs: Vec::new(),
+            expires: None,
+        }
+    }
+}
+
+impl TokenGrantConfig {
+    fn to_grant(&self, path: &str) -> Result<TokenGrant, String> {
+        let token_sha256 = match (&self.token, &self.token_sha256) {
+            (Some(token), None) if !token.trim().is_empty() => hash_token(token.trim()),
+            (None, Some(hash)) => {
+                parse_token_hash(hash).map_err(|e| format!("{path}.token_sha256: {e}"))?
+            }
+            (Some(_), Some(_)) => {
+                return Err(format!(
+                    "{path}: set either token or token_sha256, not both"
+                ));
+            }
+            _ => return Err(format!("{path}: token or token_sha256 is required")),
+        };
+        if self.rigs.iter().any(|r| r.trim().is_empty()) {
+            return Err(format!("{path}.rigs must not contain empty rig IDs"));
+        }
+        let expires_at = self
+            .expires
+            .as_deref()
+            .map(parse_expiry)
+            .transpose()
+            .map_err(|e| format!("{path}.expires: {e}"))?;
+        Ok(TokenGrant {
+            name: self.name.clone().unwrap_or_else(|| path.to_string()),
+            token_sha256,
+            scope: self.scope,
+            rigs: self.rigs.clone(),
+            expires_at,
+        })
+    }
+}
+
+/// Parse an expiry as an RFC 3339 timestamp, or as a date that stays valid
+/// until the end of that day (UTC).
+fn parse_expiry(value: &str) -> Result<std::time::SystemTime, String> {
+    let value = value.trim();
+    if let Ok(ts) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.into());
+    }
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("'{value}' is not an RFC 3339 timestamp or YYYY-MM-DD date"))?;
+    let end = date
+        .succ_opt()
+        .and_then(|next| next.and_hms_opt(0, 0, 0))
+        .ok_or_else(|| format!("'{value}' is out of range"))?;
+    Ok(end.and_utc().into())
 }
 
 /// Audio streaming configuration.
@@ -294,6 +528,8 @@ pub struct AudioConfig {
     pub frame_duration_ms: u16,
     /// Opus bitrate in bits per second
     pub bitrate_bps: u32,
+    /// TLS settings (plaintext when disabled)
+    pub tls: TlsServerConfig,
 }
 
 impl Default for AudioConfig {
@@ -309,11 +545,218 @@ impl Default for AudioConfig {
             channels: 2,
             frame_duration_ms: 20,
             bitrate_bps: 256000,
+            tls: TlsServerConfig::default(),
+        }
+    }
+}
+
+pub use trx_reporting::{AprsFiConfig, MetricsConfig, MqttConfig, PskReporterConfig};
+
+/// POCSAG pager decoder settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PocsagConfig {
+    /// Only report pages addressed to these capcodes; empty reports all.
+    pub capcodes: Vec<u32>,
+    /// Withhold message bodies from history, logs and clients.
+    pub suppress_message_body: bool,
+}
+
+impl PocsagConfig {
+    /// Apply the capcode filter and privacy setting to a decoded page.
+    /// Returns `None` when the page should be dropped.
+    pub fn apply(&self, mut msg: PocsagMessage) -> Option<PocsagMessage> {
+        if !self.capcodes.is_empty() && !self.capcodes.contains(&msg.capcode) {
+            return None;
+        }
+        if self.suppress_message_body && msg.text.is_some() {
+            msg.text = None;
+            msg.redacted = true;
         }
+        Some(msg)
     }
 }
 
-pub use trx_reporting::{AprsFiConfig, PskReporterConfig};
+/// ADS-B external feed settings.
+///
+/// The decoder itself runs whenever the SDR captures at 2 Msps; these
+/// options only expose its output to tools such as tar1090 or Virtual Radar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdsbConfig {
+    /// Serve raw frames in Beast binary format.
+    pub beast_enabled: bool,
+    /// Serve decoded aircraft as BaseStation (SBS-1) text.
+    pub sbs_enabled: bool,
+    /// IP address to listen on for feed connections
+    pub listen: IpAddr,
+    /// TCP port for the Beast feed
+    pub beast_port: u16,
+    /// TCP port for the SBS-1 feed
+    pub sbs_port: u16,
+}
+
+impl Default for AdsbConfig {
+    fn default() -> Self {
+        Self {
+            beast_enabled: false,
+            sbs_enabled: false,
+            listen: IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+            beast_port: 30005,
+            sbs_port: 30003,
+        }
+    }
+}
+
+/// CW skimmer settings.
+///
+/// The skimmer decodes every CW signal in a window of the SDR capture
+/// centred on the dial and reports the callsigns it hears as spots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SkimmerConfig {
+    pub enabled: bool,
+    /// Width of the skimmed window (Hz), clamped to the SDR sample rate.
+    pub span_hz: u32,
+    /// Maximum number of signals decoded at once (1-32).
+    pub max_channels: usize,
+    /// Signal level above the noise floor needed to start decoding (dB).
+    pub snr_threshold_db: f32,
+    /// Spotter callsign in spots; defaults to `[general].callsign` + `-#`.
+    pub spotter: Option<String>,
+    /// Serve spots as an RBN-compatible telnet feed.
+    pub telnet_enabled: bool,
+    /// IP address to listen on for telnet connections
+    pub telnet_listen: IpAddr,
+    /// TCP port for the telnet feed
+    pub telnet_port: u16,
+}
+
+impl Default for SkimmerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            span_hz: 48_000,
+            max_channels: 32,
+            snr_threshold_db: 10.0,
+            spotter: None,
+            telnet_enabled: false,
+            telnet_listen: IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+            telnet_port: 7300,
+        }
+    }
+}
+
+/// Satellite TLE sources.
+///
+/// Local `files` and the saved catalogue let air-gapped stations predict
+/// passes without CelesTrak.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TleConfig {
+    /// Fetch weather, NOAA and amateur TLE groups from CelesTrak.
+    pub celestrak: bool,
+    /// Extra URLs serving TLE text.
+    pub urls: Vec<String>,
+    /// Local TLE files, or directories whose files are all read.
+    pub files: Vec<PathBuf>,
+    /// Hours between refreshes of CelesTrak, URLs and files.
+    pub refresh_hours: u64,
+    /// Save the merged catalogue and restore it on startup.
+    pub persist: bool,
+    /// Elements older than this many days are flagged in pass predictions.
+    pub max_age_days: f64,
+}
+
+impl Default for TleConfig {
+    fn default() -> Self {
+        Self {
+            celestrak: true,
+            urls: Vec::new(),
+            files: Vec::new(),
+            refresh_hours: 24,
+            persist: true,
+            max_age_days: 14.0,
+        }
+    }
+}
+
+/// Antenna rotator settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RotatorConfig {
+    pub enabled: bool,
+    /// Rotator backend: "rotctld" (Hamlib, TCP) or "gs232" (Yaesu, serial).
+    pub model: String,
+    /// How to reach the rotator; rotctld defaults to 127.0.0.1:4533.
+    pub access: AccessConfig,
+    /// End of azimuth travel in degrees: 360, or 450 for overlap rotators.
+    pub az_max_deg: f64,
+    /// End of elevation travel in degrees: 90, or 180 for flip-over rotators.
+    pub el_max_deg: f64,
+    /// Minimum pointing change before a tracking update is sent.
+    pub step_deg: f64,
+}
+
+impl Default for RotatorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            model: "rotctld".to_string(),
+            access: AccessConfig::default(),
+            az_max_deg: 360.0,
+            el_max_deg: 90.0,
+            step_deg: 2.0,
+        }
+    }
+}
+
+/// CW keyer settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CwKeyerConfig {
+    pub enabled: bool,
+    /// "auto" (CAT keying when the backend has it, else tone), "cat" or "tone".
+    pub output: CwKeyerOutput,
+    pub wpm: u32,
+    /// Key-down share of the dit period in percent (50 = standard).
+    pub weight: u32,
+    /// Overall speed with Farnsworth spacing; must be below `wpm`.
+    pub farnsworth_wpm: Option<u32>,
+    /// Sidetone pitch for tone keying in Hz.
+    pub tone_hz: u32,
+    /// Silence between PTT on and the first element (tone keying).
+    pub ptt_lead_ms: u32,
+    /// Hold time after the last element before PTT is released.
+    pub ptt_tail_ms: u32,
+    /// Message memories; text may use {MYCALL}, {CALL} and {SERIAL}.
+    pub memories: Vec<CwMemory>,
+}
+
+impl Default for CwKeyerConfig {
+    fn default() -> Self {
+        let memory = |label: &str, text: &str| CwMemory {
+            label: label.to_string(),
+            text: text.to_string(),
+        };
+        Self {
+            enabled: false,
+            output: CwKeyerOutput::Auto,
+            wpm: 25,
+            weight: DEFAULT_WEIGHT,
+            farnsworth_wpm: None,
+            tone_hz: 700,
+            ptt_lead_ms: 50,
+            ptt_tail_ms: 150,
+            memories: vec![
+                memory("CQ", "CQ CQ DE {MYCALL} {MYCALL} K"),
+                memory("Exch", "{CALL} 5NN {SERIAL}"),
+                memory("TU", "TU {MYCALL}"),
+                memory("AGN", "AGN?"),
+            ],
+        }
+    }
+}
 
 /// Top-level SDR configuration (only used when [rig.access] type = "sdr").
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -494,9 +937,11 @@ impl ServerConfig {
         }
 
         validate_tokens("[listen.auth].tokens", &self.listen.auth.tokens)?;
+        self.listen.auth.validator()?;
         if self.listen.enabled && self.listen.port == 0 {
             return Err("[listen].port must be > 0 when listener is enabled".to_string());
         }
+        self.listen.tls.validate("[listen.tls]")?;
 
         if self.audio.enabled {
             if self.audio.port == 0 {
@@ -525,6 +970,7 @@ impl ServerConfig {
             if self.audio.bitrate_bps == 0 {
                 return Err("[audio].bitrate_bps must be > 0".to_string());
             }
+            self.audio.tls.validate("[audio.tls]")?;
         }
 
         if self.pskreporter.enabled {
@@ -564,6 +1010,14 @@ impl ServerConfig {
         }
         validate_sdr_squelch_config("[sdr.squelch]", &self.sdr.squelch)?;
         validate_sdr_nb_config("[sdr.noise_blanker]", &self.sdr.noise_blanker)?;
+        validate_adsb_config("[adsb]", &self.adsb)?;
+        validate_skimmer_config("[skimmer]", &self.skimmer)?;
+        validate_rotator_config("[rotator]", &self.rotator)?;
+        validate_cw_keyer_config("[cw_keyer]", &self.cw_keyer, &self.audio)?;
+        validate_tle_config(&self.tle)?;
+        validate_mqtt_config(&self.mqtt, &self.listen.auth)?;
+        validate_metrics_config(&self.metrics, &self.listen)?;
+        validate_uplink_config(&self.uplink)?;
 
         // Multi-rig uniqueness checks.
         if !self.rigs.is_empty() {
@@ -585,6 +1039,11 @@ impl ServerConfig {
                         rig.audio.port, rig.id
                     ));
                 }
+                if rig.audio.enabled {
+                    rig.audio
+                        .tls
+                        .validate(&format!("[[rigs]] [audio.tls] (rig id: \"{}\")", rig.id))?;
+                }
                 if let Some(max_gain) = rig.sdr.gain.max_value {
                     if !max_gain.is_finite() {
                         return Err(format!(
@@ -607,6 +1066,23 @@ impl ServerConfig {
                     &format!("[[rigs]] [sdr.noise_blanker] (rig id: \"{}\")", rig.id),
                     &rig.sdr.noise_blanker,
                 )?;
+                validate_adsb_config(
+                    &format!("[[rigs]] [adsb] (rig id: \"{}\")", rig.id),
+                    &rig.adsb,
+                )?;
+                validate_skimmer_config(
+                    &format!("[[rigs]] [skimmer] (rig id: \"{}\")", rig.id),
+                    &rig.skimmer,
+                )?;
+                validate_rotator_config(
+                    &format!("[[rigs]] [rotator] (rig id: \"{}\")", rig.id),
+                    &rig.rotator,
+                )?;
+                validate_cw_keyer_config(
+                    &format!("[[rigs]] [cw_keyer] (rig id: \"{}\")", rig.id),
+                    &rig.cw_keyer,
+                    &rig.audio,
+                )?;
             }
             if enabled_count == 0 {
                 return Err(
@@ -622,6 +1098,10 @@ impl ServerConfig {
             }
             if self.decode_logs.aprs_file.trim().is_empty()
                 || self.decode_logs.cw_file.trim().is_empty()
+                || self.decode_logs.psk_file.trim().is_empty()
+                || self.decode_logs.dsc_file.trim().is_empty()
+                || self.decode_logs.pocsag_file.trim().is_empty()
+                || self.decode_logs.acars_file.trim().is_empty()
                 || self.decode_logs.ft8_file.trim().is_empty()
                 || self.decode_logs.wspr_file.trim().is_empty()
             {
@@ -752,6 +1232,11 @@ impl ServerConfig {
             pskreporter: self.pskreporter.clone(),
             aprsfi: self.aprsfi.clone(),
             decode_logs: self.decode_logs.clone(),
+            pocsag: self.pocsag.clone(),
+            adsb: self.adsb.clone(),
+            skimmer: self.skimmer.clone(),
+            rotator: self.rotator.clone(),
+            cw_keyer: self.cw_keyer.clone(),
         }]
     }
 
@@ -789,8 +1274,17 @@ impl ServerConfig {
             pskreporter: PskReporterConfig::default(),
             aprsfi: AprsFiConfig::default(),
             decode_logs: DecodeLogsConfig::default(),
+            pocsag: PocsagConfig::default(),
+            adsb: AdsbConfig::default(),
+            skimmer: SkimmerConfig::default(),
+            rotator: RotatorConfig::default(),
+            cw_keyer: CwKeyerConfig::default(),
             sdr: SdrConfig::default(),
             timeouts: TimeoutsConfig::default(),
+            tle: TleConfig::default(),
+            mqtt: MqttConfig::default(),
+            metrics: MetricsConfig::default(),
+            uplink: UplinkConfig::default(),
             rigs: Vec::new(),
         };
         toml::to_string_pretty(&Wrapper { inner: example }).unwrap_or_default()
@@ -885,6 +1379,210 @@ fn validate_sdr_squelch_config(path: &str, squelch: &SdrSquelchConfig) -> Result
     Ok(())
 }
 
+fn validate_adsb_config(path: &str, adsb: &AdsbConfig) -> Result<(), String> {
+    if adsb.beast_enabled && adsb.beast_port == 0 {
+        return Err(format!("{path}.beast_port must be > 0 when beast_enabled"));
+    }
+    if adsb.sbs_enabled && adsb.sbs_port == 0 {
+        return Err(format!("{path}.sbs_port must be > 0 when sbs_enabled"));
+    }
+    if adsb.beast_enabled && adsb.sbs_enabled && adsb.beast_port == adsb.sbs_port {
+        return Err(format!("{path}.beast_port and sbs_port must differ"));
+    }
+    Ok(())
+}
+
+fn validate_skimmer_config(path: &str, skimmer: &SkimmerConfig) -> Result<(), String> {
+    if !(4_000..=192_000).contains(&skimmer.span_hz) {
+        return Err(format!("{path}.span_hz must be in range 4000..=192000"));
+    }
+    if !(1..=32).contains(&skimmer.max_channels) {
+        return Err(format!("{path}.max_channels must be in range 1..=32"));
+    }
+    if !(skimmer.snr_threshold_db.is_finite() && (0.0..=40.0).contains(&skimmer.snr_threshold_db)) {
+        return Err(format!("{path}.snr_threshold_db must be in range 0..=40"));
+    }
+    if skimmer.telnet_enabled && skimmer.telnet_port == 0 {
+        return Err(format!(
+            "{path}.telnet_port must be > 0 when telnet_enabled"
+        ));
+    }
+    Ok(())
+}
+
+fn validate_tle_config(tle: &TleConfig) -> Result<(), String> {
+    if tle.refresh_hours == 0 {
+        return Err("[tle].refresh_hours must be > 0".to_string());
+    }
+    if !(tle.max_age_days.is_finite() && tle.max_age_days > 0.0) {
+        return Err("[tle].max_age_days must be > 0".to_string());
+    }
+    for url in &tle.urls {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(format!("[tle].urls entry '{url}' must be an http(s) URL"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_mqtt_config(mqtt: &MqttConfig, auth: &AuthConfig) -> Result<(), String> {
+    if !mqtt.enabled {
+        return Ok(());
+    }
+    if mqtt.host.trim().is_empty() {
+        return Err("[mqtt].host must not be empty".to_string());
+    }
+    if mqtt.port == 0 {
+        return Err("[mqtt].port must be > 0".to_string());
+    }
+    if mqtt.client_id.trim().is_empty() {
+        return Err("[mqtt].client_id must not be empty".to_string());
+    }
+    for (field, topic) in [
+        ("topic_prefix", &mqtt.topic_prefix),
+        ("discovery_prefix", &mqtt.discovery_prefix),
+    ] {
+        if topic.trim_matches('/').is_empty() || topic.contains(['+', '#']) {
+            return Err(format!(
+                "[mqtt].{field} must be a non-empty topic without wildcards"
+            ));
+        }
+    }
+    if mqtt.password.is_some() && mqtt.username.is_none() {
+        return Err("[mqtt].password requires [mqtt].username".to_string());
+    }
+    // Anyone who can publish to the broker reaches the command topic.
+    if mqtt.commands && auth.tokens.is_empty() && auth.grants.is_empty() {
+        return Err("[mqtt].commands requires [listen.auth] tokens or grants".to_string());
+    }
+    Ok(())
+}
+
+fn validate_metrics_config(metrics: &MetricsConfig, listen: &ListenConfig) -> Result<(), String> {
+    if !metrics.enabled {
+        return Ok(());
+    }
+    if metrics.port == 0 {
+        return Err("[metrics].port must be > 0".to_string());
+    }
+    if listen.enabled && listen.port == metrics.port {
+        return Err(format!(
+            "[metrics].port {} clashes with [listen].port",
+            metrics.port
+        ));
+    }
+    Ok(())
+}
+
+fn validate_uplink_config(uplink: &UplinkConfig) -> Result<(), String> {
+    if !uplink.enabled {
+        return Ok(());
+    }
+    uplink
+        .endpoint()
+        .map_err(|e| format!("[uplink].url '{}': {e}", uplink.url))?;
+    if uplink.station.trim().is_empty() {
+        return Err("[uplink].station must not be empty".to_string());
+    }
+    if uplink.token.as_ref().is_some_and(|t| t.trim().is_empty()) {
+        return Err("[uplink].token must not be empty when set".to_string());
+    }
+    if uplink.max_backoff_secs == 0 {
+        return Err("[uplink].max_backoff_secs must be > 0".to_string());
+    }
+    uplink.tls.validate("[uplink.tls]")
+}
+
+fn validate_rotator_config(path: &str, rotator: &RotatorConfig) -> Result<(), String> {
+    if !rotator.enabled {
+        return Ok(());
+    }
+    match rotator
+        .model
+        .to_ascii_lowercase()
+        .replace(['-', '_'], "")
+        .as_str()
+    {
+        "rotctld" => {
+            if rotator.access.access_type.as_deref().unwrap_or("tcp") != "tcp" {
+                return Err(format!("{path}.access.type must be 'tcp' for rotctld"));
+            }
+        }
+        "gs232" | "gs232a" | "gs232b" => {
+            if rotator.access.access_type.as_deref().unwrap_or("serial") != "serial" {
+                return Err(format!("{path}.access.type must be 'serial' for gs232"));
+            }
+            if rotator
+                .access
+                .port
+                .as_deref()
+                .unwrap_or("")
+                .trim()
+                .is_empty()
+            {
+                return Err(format!("{path}.access.port must be set for gs232"));
+            }
+        }
+        other => {
+            return Err(format!(
+                "{path}.model '{other}' is invalid (expected 'rotctld' or 'gs232')"
+            ))
+        }
+    }
+    if !(360.0..=450.0).contains(&rotator.az_max_deg) {
+        return Err(format!("{path}.az_max_deg must be in range 360..=450"));
+    }
+    if !(0.0..=180.0).contains(&rotator.el_max_deg) {
+        return Err(format!("{path}.el_max_deg must be in range 0..=180"));
+    }
+    if !(rotator.step_deg > 0.0 && rotator.step_deg <= 30.0) {
+        return Err(format!("{path}.step_deg must be in range (0, 30]"));
+    }
+    Ok(())
+}
+
+fn validate_cw_keyer_config(
+    path: &str,
+    keyer: &CwKeyerConfig,
+    audio: &AudioConfig,
+) -> Result<(), String> {
+    if !keyer.enabled {
+        return Ok(());
+    }
+    if !(5..=60).contains(&keyer.wpm) {
+        return Err(format!("{path}.wpm must be in range 5..=60"));
+    }
+    if !(25..=75).contains(&keyer.weight) {
+        return Err(format!("{path}.weight must be in range 25..=75"));
+    }
+    if let Some(f) = keyer.farnsworth_wpm {
+        if f < 5 || f >= keyer.wpm {
+            return Err(format!(
+                "{path}.farnsworth_wpm must be at least 5 and below wpm"
+            ));
+        }
+    }
+    if !(300..=3000).contains(&keyer.tone_hz) {
+        return Err(format!("{path}.tone_hz must be in range 300..=3000"));
+    }
+    if keyer.ptt_lead_ms > 1000 || keyer.ptt_tail_ms > 2000 {
+        return Err(format!(
+            "{path}.ptt_lead_ms must be <= 1000 and ptt_tail_ms <= 2000"
+        ));
+    }
+    if keyer.output == CwKeyerOutput::Tone && !(audio.enabled && audio.tx_enabled) {
+        return Err(format!(
+            "{path}.output = \"tone\" needs audio with tx_enabled = true"
+        ));
+    }
+    for memory in &keyer.memories {
+        if memory.label.trim().is_empty() || memory.text.trim().is_empty() {
+            return Err(format!("{path}.memories entries need a label and text"));
+        }
+    }
+    Ok(())
+}
+
 fn validate_sdr_nb_config(path: &str, nb: &SdrNoiseBlankerConfig) -> Result<(), String> {
     if !nb.threshold.is_finite() {
         return Err(format!("{path}.threshold must be finite"));
@@ -995,6 +1693,65 @@ tokens = ["secret123"]
         assert_eq!(config.listen.auth.tokens, vec!["secret123".to_string()]);
     }
 
+    #[test]
+    fn test_parse_scoped_auth_grants() {
+        let toml_str = r#"
+[listen.auth]
+tokens = ["legacy"]
+
+[[listen.auth.grants]]
+name = "logger"
+token_sha256 = "2bb80d537b1da3e38bd30361aa855686bde0eacd7162fef6a25fe97bf527a25b"
+scope = "control"
+rigs = ["hf"]
+expires = "2030-01-31"
+
+[[listen.auth.grants]]
+token = "viewer"
+"#;
+
+        let config: ServerConfig = toml::from_str(toml_str).unwrap();
+        assert!(config.validate().is_ok());
+        let grants = &config.listen.auth.grants;
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[0].scope, Scope::Control);
+        assert_eq!(grants[1].scope, Scope::Read);
+
+        let validator = config.listen.auth.validator().unwrap();
+        let now = std::time::SystemTime::now();
+        let logger = validator
+            .authenticate(&Some("secret".to_string()), now)
+            .unwrap();
+        assert!(logger.authorize("hf", Scope::Control).is_ok());
+        assert!(logger.authorize("hf", Scope::Tx).is_err());
+        assert!(logger.authorize("vhf", Scope::Read).is_err());
+        let legacy = validator
+            .authenticate(&Some("legacy".to_string()), now)
+            .unwrap();
+        assert!(legacy.authorize("vhf", Scope::Admin).is_ok());
+
+        let expiry = parse_expiry("2030-01-31").unwrap();
+        assert_eq!(expiry, parse_expiry("2030-02-01T00:00:00Z").unwrap());
+    }
+
+    #[test]
+    fn test_scoped_auth_grant_validation() {
+        let mut config = ServerConfig::default();
+        config.listen.auth.grants = vec![TokenGrantConfig::default()];
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .contains("token or token_sha256"));
+
+        config.listen.auth.grants[0].token_sha256 = Some("abc".to_string());
+        assert!(config.validate().unwrap_err().contains("token_sha256"));
+
+        config.listen.auth.grants[0].token_sha256 = None;
+        config.listen.auth.grants[0].token = Some("t".to_string());
+        config.listen.auth.grants[0].expires = Some("next week".to_string());
+        assert!(config.validate().unwrap_err().contains("expires"));
+    }
+
     #[test]
     fn test_example_combined_toml_parses() {
         let example = ServerConfig::example_combined_toml();
@@ -1270,6 +2027,270 @@ tokens = ["secret123"]
         );
     }
 
+    #[test]
+    fn test_validate_rejects_shared_adsb_feed_port() {
+        let mut cfg = ServerConfig::default();
+        cfg.rig.access.port = Some("/dev/ttyUSB0".to_string());
+        cfg.rig.access.baud = Some(9600);
+        cfg.adsb.beast_enabled = true;
+        cfg.adsb.sbs_enabled = true;
+        cfg.adsb.sbs_port = cfg.adsb.beast_port;
+        let err = cfg
+            .validate()
+            .expect_err("expected adsb port validation error");
+        assert!(err.contains("[adsb]"), "unexpected validation error: {err}");
+    }
+
+    #[test]
+    fn test_parse_and_validate_skimmer_config() {
+        let toml_str = r#"
+            [rig.access]
+            type = "sdr"
+            args = "driver=rtlsdr"
+
+            [skimmer]
+            enabled = true
+            span_hz = 96000
+            telnet_enabled = true
+        "#;
+        let mut cfg: ServerConfig = toml::from_str(toml_str).unwrap();
+        assert!(cfg.skimmer.enabled);
+        assert_eq!(cfg.skimmer.span_hz, 96_000);
+        assert_eq!(cfg.skimmer.telnet_port, 7300);
+        assert!(cfg.validate().is_ok());
+
+        cfg.skimmer.max_channels = 0;
+        let err = cfg
+            .validate()
+            .expect_err("expected skimmer validation error");
+        assert!(
+            err.contains("[skimmer].max_channels"),
+            "unexpected validation error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_tle_config() {
+        let toml_str = r#"
+[rig]
+model = "ft817"
+
+[rig.access]
+type = "serial"
+port = "/dev/ttyUSB0"
+baud = 9600
+
+[tle]
+celestrak = false
+files = ["/var/lib/trx-rs/tle"]
+max_age_days = 7.0
+"#;
+        let cfg: ServerConfig = toml::from_str(toml_str).unwrap();
+        assert!(!cfg.tle.celestrak);
+        assert_eq!(cfg.tle.files, vec![PathBuf::from("/var/lib/trx-rs/tle")]);
+        assert_eq!(cfg.tle.max_age_days, 7.0);
+        assert!(cfg.tle.persist);
+        assert!(cfg.validate().is_ok());
+
+        let mut cfg = cfg;
+        cfg.tle.urls = vec!["ftp://example.org/tle.txt".to_string()];
+        let err = cfg.validate().expect_err("non-http TLE URL");
+        assert!(
+            err.contains("[tle].urls"),
+            "unexpected validation error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_and_validate_mqtt_config() {
+        let toml_str = r#"
+[rig]
+model = "ft817"
+
+[rig.access]
+type = "serial"
+port = "/dev/ttyUSB0"
+baud = 9600
+
+[mqtt]
+enabled = true
+host = "broker.lan"
+username = "trx"
+password = "secret"
+commands = true
+
+[listen.auth]
+tokens = ["secret123"]
+"#;
+        let cfg: ServerConfig = toml::from_str(toml_str).unwrap();
+        assert!(cfg.mqtt.enabled);
+        assert_eq!(cfg.mqtt.host, "broker.lan");
+        assert_eq!(cfg.mqtt.port, 1883);
+        assert_eq!(cfg.mqtt.topic_prefix, "trx");
+        assert!(cfg.mqtt.discovery);
+        assert!(cfg.mqtt.commands);
+        assert!(cfg.validate().is_ok());
+
+        let mut open = cfg.clone();
+        open.listen.auth.tokens.clear();
+        let err = open.validate().expect_err("commands without tokens");
+        assert!(
+            err.contains("[mqtt].commands"),
+            "unexpected validation error: {err}"
+        );
+
+        let mut cfg = cfg;
+        cfg.mqtt.topic_prefix = "trx/#".to_string();
+        let err = cfg.validate().expect_err("wildcard prefix");
+        assert!(
+            err.contains("[mqtt].topic_prefix"),
+            "unexpected validation error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_and_validate_metrics_config() {
+        let toml_str = r#"
+[rig]
+model = "ft817"
+
+[rig.access]
+type = "serial"
+port = "/dev/ttyUSB0"
+baud = 9600
+
+[metrics]
+enabled = true
+listen = "0.0.0.0"
+"#;
+        let cfg: ServerConfig = toml::from_str(toml_str).unwrap();
+        assert!(cfg.metrics.enabled);
+        assert_eq!(cfg.metrics.listen.to_string(), "0.0.0.0");
+        assert_eq!(cfg.metrics.port, 9464);
+        assert!(cfg.validate().is_ok());
+
+        let mut cfg = cfg;
+        cfg.metrics.port = cfg.listen.port;
+        let err = cfg.validate().expect_err("port clash");
+        assert!(
+            err.contains("[metrics].port"),
+            "unexpected validation error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_and_validate_uplink_config() {
+        let toml_str = r#"
+[rig]
+model = "ft817"
+
+[rig.access]
+type = "serial"
+port = "/dev/ttyUSB0"
+baud = 9600
+
+[uplink]
+enabled = true
+url = "tls://home.example.com"
+station = "field"
+token = "station-secret"
+"#;
+        let cfg: ServerConfig = toml::from_str(toml_str).unwrap();
+        assert!(cfg.uplink.enabled);
+        assert_eq!(cfg.uplink.max_backoff_secs, 60);
+        assert_eq!(
+            cfg.uplink.endpoint().unwrap(),
+            UplinkEndpoint {
+                addr: "home.example.com:4540".to_string(),
+                host: "home.example.com".to_string(),
+                tls: true,
+            }
+        );
+        assert!(cfg.validate().is_ok());
+
+        let mut v6 = cfg.uplink.clone();
+        v6.url = "[2001:db8::1]:4600".to_string();
+        assert_eq!(v6.endpoint().unwrap().addr, "[2001:db8::1]:4600");
+        assert!(!v6.endpoint().unwrap().tls);
+
+        let mut cfg = cfg;
+        cfg.uplink.station = " ".to_string();
+        let err = cfg.validate().expect_err("empty station");
+        assert!(err.contains("[uplink].station"), "{err}");
+        cfg.uplink.station = "field".to_string();
+        cfg.uplink.url = "home.example.com:0".to_string();
+        let err = cfg.validate().expect_err("bad port");
+        assert!(err.contains("[uplink].url"), "{err}");
+    }
+
+    #[test]
+    fn test_validate_rotator_config() {
+        let mut cfg = ServerConfig::default();
+        cfg.rig.access.port = Some("/dev/ttyUSB0".to_string());
+        cfg.rig.access.baud = Some(9600);
+        cfg.rotator.enabled = true;
+        cfg.rotator.az_max_deg = 450.0;
+        assert!(cfg.validate().is_ok());
+
+        cfg.rotator.model = "gs232".to_string();
+        let err = cfg.validate().expect_err("gs232 without a port");
+        assert!(
+            err.contains("[rotator].access.port"),
+            "unexpected validation error: {err}"
+        );
+
+        cfg.rotator.access.port = Some("/dev/ttyUSB1".to_string());
+        cfg.rotator.az_max_deg = 540.0;
+        let err = cfg.validate().expect_err("az_max_deg out of range");
+        assert!(
+            err.contains("az_max_deg"),
+            "unexpected validation error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_and_validate_cw_keyer_config() {
+        let toml_str = r#"
+[rig]
+model = "ft817"
+
+[rig.access]
+type = "serial"
+port = "/dev/ttyUSB0"
+baud = 9600
+
+[cw_keyer]
+enabled = true
+output = "tone"
+wpm = 28
+farnsworth_wpm = 18
+
+[[cw_keyer.memories]]
+label = "CQ"
+text = "CQ TEST {MYCALL}"
+"#;
+        let mut cfg: ServerConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(cfg.cw_keyer.output, CwKeyerOutput::Tone);
+        assert_eq!(cfg.cw_keyer.weight, DEFAULT_WEIGHT);
+        assert_eq!(cfg.cw_keyer.memories.len(), 1);
+        assert!(cfg.validate().is_ok());
+
+        cfg.cw_keyer.farnsworth_wpm = Some(30);
+        let err = cfg.validate().expect_err("farnsworth above wpm");
+        assert!(
+            err.contains("[cw_keyer].farnsworth_wpm"),
+            "unexpected validation error: {err}"
+        );
+
+        cfg.cw_keyer.farnsworth_wpm = None;
+        cfg.audio.tx_enabled = false;
+        let err = cfg.validate().expect_err("tone keying without TX audio");
+        assert!(
+            err.contains("tx_enabled"),
+            "unexpected validation error: {err}"
+        );
+    }
+
     // --- MR-08: multi-rig config tests ---
 
     #[test]
@@ -1286,6 +2307,36 @@ tokens = ["secret123"]
         assert_eq!(rigs[0].rig.model, Some("ft817".to_string()));
     }
 
+    #[test]
+    fn test_pocsag_capcode_filter_and_redaction() {
+        let page = PocsagMessage {
+            rig_id: None,
+            ts_ms: None,
+            baud: 1200,
+            capcode: 1_234_567,
+            function: 3,
+            content: trx_core::decode::PocsagContent::Alpha,
+            text: Some("private".to_string()),
+            corrected_bits: 0,
+            redacted: false,
+        };
+        let cfg: PocsagConfig =
+            toml::from_str("capcodes = [1234567]\nsuppress_message_body = true").unwrap();
+        let kept = cfg.apply(page.clone()).expect("capcode is listed");
+        assert_eq!(kept.text, None);
+        assert!(kept.redacted);
+
+        let other = PocsagConfig {
+            capcodes: vec![42],
+            suppress_message_body: false,
+        };
+        assert!(other.apply(page.clone()).is_none());
+        assert_eq!(
+            PocsagConfig::default().apply(page).unwrap().text.as_deref(),
+            Some("private")
+        );
+    }
+
     #[test]
     fn test_resolved_rigs_multi_rig_toml() {
         let toml_str = r#"
@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Tone keying for the CW keyer.
+//!
+//! When the rig backend has no CAT keying, the rig task keys PTT and hands
+//! the message timeline to this task, which renders a shaped sidetone and
+//! feeds it as Opus frames into the same TX audio path browser clients use.
+//! PTT itself stays with the rig task so an abort can drop it immediately.
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+use trx_core::keyer::KeyElement;
+
+/// Rise and fall time of each element, to keep key clicks off the air.
+const RAMP_MS: f32 = 5.0;
+/// Peak amplitude of the keyed tone.
+const TONE_LEVEL: f32 = 0.5;
+/// Frames sent ahead of real time so playback never starves.
+const PREBUFFER_FRAMES: usize = 2;
+
+/// Work for the tone keyer.
+#[derive(Debug)]
+pub enum CwToneCommand {
+    /// Play `timeline` as a tone of `tone_hz`, starting after `lead_ms` of
+    /// silence that covers the transmitter's PTT switching time.
+    Send {
+        timeline: Vec<KeyElement>,
+        tone_hz: u32,
+        lead_ms: u32,
+    },
+    /// Stop playing the current message.
+    Abort,
+}
+
+/// Render a keyed message as interleaved PCM.
+pub fn render_tone(
+    timeline: &[KeyElement],
+    tone_hz: u32,
+    lead_ms: u32,
+    sample_rate: u32,
+    channels: usize,
+) -> Vec<f32> {
+    let ms_to_samples = |ms: u32| (ms as u64 * sample_rate as u64 / 1000) as usize;
+    let ramp = ((RAMP_MS * sample_rate as f32 / 1000.0) as usize).max(1);
+    let step = std::f32::consts::TAU * tone_hz as f32 / sample_rate as f32;
+
+    let mut mono: Vec<f32> = vec![0.0; ms_to_samples(lead_ms)];
+    let mut phase = 0.0f32;
+    for element in timeline {
+        let n = ms_to_samples(element.duration_ms);
+        if !element.key_down {
+            mono.resize(mono.len() + n, 0.0);
+            continue;
+        }
+        let edge = ramp.min(n / 2);
+        for i in 0..n {
+            let gain = if i < edge {
+                0.5 - 0.5 * (std::f32::consts::PI * i as f32 / edge as f32).cos()
+            } else if i >= n - edge {
+                0.5 - 0.5 * (std::f32::consts::PI * (n - 1 - i) as f32 / edge as f32).cos()
+            } else {
+                1.0
+            };
+            mono.push(TONE_LEVEL * gain * phase.sin());
+            phase = (phase + step) % std::f32::consts::TAU;
+        }
+    }
+
+    if channels <= 1 {
+        return mono;
+    }
+    mono.iter()
+        .flat_map(|&s| std::iter::repeat_n(s, channels))
+        .collect()
+}
+
+/// Play keyed messages into the TX audio channel until `rx` closes.
+pub async fn run_tone_keyer(
+    sample_rate: u32,
+    channels: u8,
+    frame_duration_ms: u16,
+    bitrate_bps: u32,
+    mut rx: mpsc::Receiver<CwToneCommand>,
+    tx_audio: mpsc::Sender<Bytes>,
+) {
+    let opus_ch = match channels {
+        1 => opus::Channels::Mono,
+        2 => opus::Channels::Stereo,
+        n => {
+            error!("CW keyer: unsupported channel count {}", n);
+            return;
+        }
+    };
+    let mut encoder = match opus::Encoder::new(sample_rate, opus_ch, opus::Application::Audio) {
+        Ok(e) => e,
+        Err(e) => {
+            error!("CW keyer: Opus encoder init failed: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = encoder.set_bitrate(opus::Bitrate::Bits(bitrate_bps as i32)) {
+        warn!("CW keyer: set_bitrate failed: {}", e);
+    }
+    let frame_samples =
+        (sample_rate as usize * frame_duration_ms as usize / 1000) * channels as usize;
+    let frame_duration = Duration::from_millis(frame_duration_ms as u64);
+    let mut opus_buf = vec![0u8; 4096];
+
+    while let Some(cmd) = rx.recv().await {
+        let CwToneCommand::Send {
+            timeline,
+            tone_hz,
+            lead_ms,
+        } = cmd
+        else {
+            continue;
+        };
+        let mut pcm = render_tone(&timeline, tone_hz, lead_ms, sample_rate, channels as usize);
+        pcm.resize(pcm.len().div_ceil(frame_samples) * frame_samples, 0.0);
+
+        let mut ticker = tokio::time::interval(frame_duration);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Burst);
+        let mut frames = pcm.chunks(frame_samples).enumerate();
+        loop {
+            tokio::select! {
+                cmd = rx.recv() => match cmd {
+                    Some(CwToneCommand::Abort) | None => break,
+                    Some(CwToneCommand::Send { .. }) => {
+                        warn!("CW keyer: message already playing, ignoring new one");
+                    }
+                },
+                _ = ticker.tick() => {
+                    // Prime the playback buffer, then follow real time.
+                    let mut sent_any = false;
+                    for (idx, frame) in frames.by_ref() {
+                        match encoder.encode_float(frame, &mut opus_buf) {
+                            Ok(len) => {
+                                let pkt = Bytes::copy_from_slice(&opus_buf[..len]);
+                                if tx_audio.send(pkt).await.is_err() {
+                                    return;
+                                }
+                            }
+                            Err(e) => warn!("CW keyer: Opus encode error: {}", e),
+                        }
+                        sent_any = true;
+                        if idx + 1 >= PREBUFFER_FRAMES {
+                            break;
+                        }
+                    }
+                    if !sent_any {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rendered_tone_follows_the_timeline() {
+        let timeline = [
+            KeyElement {
+                key_down: true,
+                duration_ms: 60,
+            },
+            KeyElement {
+                key_down: false,
+                duration_ms: 60,
+            },
+            KeyElement {
+                key_down: true,
+                duration_ms: 180,
+            },
+        ];
+        let pcm = render_tone(&timeline, 700, 50, 48_000, 2);
+        assert_eq!(pcm.len(), (50 + 60 + 60 + 180) * 48 * 2);
+
+        let mono: Vec<f32> = pcm.chunks(2).map(|c| c[0]).collect();
+        let peak = |from_ms: usize, to_ms: usize| {
+            mono[from_ms * 48..to_ms * 48]
+                .iter()
+                .fold(0.0f32, |m, s| m.max(s.abs()))
+        };
+        assert_eq!(peak(0, 50), 0.0);
+        assert!(peak(60, 100) > 0.45);
+        assert_eq!(peak(110, 170), 0.0);
+        assert!(peak(200, 330) > 0.45);
+        // Shaped edges: the first and last samples of an element are silent.
+        assert!(mono[50 * 48].abs() < 1e-3);
+        assert!(mono.last().unwrap().abs() < 1e-3);
+    }
+}
@@ -19,7 +19,8 @@ use pickledb::{PickleDb, PickleDbDumpPolicy, SerializationMethod};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use trx_core::decode::{
-    AisMessage, AprsPacket, CwEvent, Ft8Message, VdesMessage, WefaxMessage, WsprMessage,
+    AcarsMessage, AdsbMessage, AisMessage, AprsPacket, CwEvent, DscMessage, Ft8Message,
+    PocsagMessage, PskEvent, VdesMessage, WefaxMessage, WsprMessage,
 };
 
 use crate::audio::DecoderHistories;
@@ -123,6 +124,31 @@ pub fn load_all(db: &PickleDb, rig_id: &str, histories: &Arc<DecoderHistories>)
             h.push_back(e);
         }
     }
+    if let Ok(mut h) = histories.psk.lock() {
+        for e in load_key::<PskEvent>(db, &k("psk")) {
+            h.push_back(e);
+        }
+    }
+    if let Ok(mut h) = histories.dsc.lock() {
+        for e in load_key::<DscMessage>(db, &k("dsc")) {
+            h.push_back(e);
+        }
+    }
+    if let Ok(mut h) = histories.pocsag.lock() {
+        for e in load_key::<PocsagMessage>(db, &k("pocsag")) {
+            h.push_back(e);
+        }
+    }
+    if let Ok(mut h) = histories.adsb.lock() {
+        for e in load_key::<AdsbMessage>(db, &k("adsb")) {
+            h.push_back(e);
+        }
+    }
+    if let Ok(mut h) = histories.acars.lock() {
+        for e in load_key::<AcarsMessage>(db, &k("acars")) {
+            h.push_back(e);
+        }
+    }
     if let Ok(mut h) = histories.ft8.lock() {
         for e in load_key::<Ft8Message>(db, &k("ft8")) {
             h.push_back(e);
@@ -167,6 +193,31 @@ pub fn flush_all(db: &mut PickleDb, rig_id: &str, histories: &Arc<DecoderHistori
         drop(h);
         save_key(db, &k("cw"), &snapshot);
     }
+    if let Ok(h) = histories.psk.lock() {
+        let snapshot = h.clone();
+        drop(h);
+        save_key(db, &k("psk"), &snapshot);
+    }
+    if let Ok(h) = histories.dsc.lock() {
+        let snapshot = h.clone();
+        drop(h);
+        save_key(db, &k("dsc"), &snapshot);
+    }
+    if let Ok(h) = histories.pocsag.lock() {
+        let snapshot = h.clone();
+        drop(h);
+        save_key(db, &k("pocsag"), &snapshot);
+    }
+    if let Ok(h) = histories.adsb.lock() {
+        let snapshot = h.clone();
+        drop(h);
+        save_key(db, &k("adsb"), &snapshot);
+    }
+    if let Ok(h) = histories.acars.lock() {
+        let snapshot = h.clone();
+        drop(h);
+        save_key(db, &k("acars"), &snapshot);
+    }
     if let Ok(h) = histories.ft8.lock() {
         let snapshot = h.clone();
         drop(h);
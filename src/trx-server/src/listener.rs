@@ -11,26 +11,31 @@
 //! When absent the first rig in the map is used (backward compat).
 
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
-use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader};
+use serde::Serialize;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader, WriteHalf};
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::{oneshot, watch};
 use tokio::time;
 use tracing::{error, info, warn};
 
+use trx_app::tls::{TlsAcceptor, TransportStream};
+use trx_core::audio::AUDIO_MSG_MUX_HELLO;
+use trx_core::metrics::Gauge;
+use trx_core::mux::{Mux, MuxStreamKind};
 use trx_core::rig::command::RigCommand;
 use trx_core::rig::request::RigRequest;
-use trx_protocol::auth::{SimpleTokenValidator, TokenValidator};
+use trx_protocol::auth::{required_scope, ScopedTokenValidator};
 use trx_protocol::codec::parse_envelope;
 use trx_protocol::mapping;
-use trx_protocol::types::{ClientCommand, RigEntry};
+use trx_protocol::state_stream::{StateEncoder, STATE_KEEPALIVE_INTERVAL};
+use trx_protocol::types::{ClientCommand, RigEntry, Scope, PROTOCOL_VERSION};
 use trx_protocol::ClientResponse;
 
-use crate::rig_handle::RigHandle;
+use crate::rig_handle::{MuxAudioStream, RigHandle};
 
 /// Fallback I/O timeout used when no config value is provided.
 const DEFAULT_IO_TIMEOUT: Duration = Duration::from_secs(10);
@@ -100,13 +105,39 @@ impl ConnectionTracker {
 }
 
 /// Shared state passed to each client handler.
-struct ClientContext {
+#[derive(Clone)]
+pub(crate) struct ClientContext {
     rigs: Arc<HashMap<String, RigHandle>>,
     default_rig_id: String,
-    validator: Arc<SimpleTokenValidator>,
+    validator: Arc<ScopedTokenValidator>,
     station_coords: Option<(f64, f64)>,
+    tle_max_age_days: f64,
     sat_pass_cache: Arc<Mutex<Option<SatPassCache>>>,
     timeouts: ListenerTimeouts,
+    tls: Option<TlsAcceptor>,
+}
+
+impl ClientContext {
+    pub(crate) fn new(
+        rigs: Arc<HashMap<String, RigHandle>>,
+        default_rig_id: String,
+        validator: Arc<ScopedTokenValidator>,
+        station_coords: Option<(f64, f64)>,
+        tle_max_age_days: f64,
+        timeouts: ListenerTimeouts,
+        tls: Option<TlsAcceptor>,
+    ) -> Self {
+        Self {
+            rigs,
+            default_rig_id,
+            validator,
+            station_coords,
+            tle_max_age_days,
+            sat_pass_cache: Arc::new(Mutex::new(None)),
+            timeouts,
+            tls,
+        }
+    }
 }
 
 /// Run the JSON TCP listener, accepting client connections.
@@ -114,19 +145,36 @@ struct ClientContext {
 /// `rigs` is a shared map from rig_id → `RigHandle`.  The first entry (by
 /// insertion order — deterministic after MR-07 iterates `resolved_rigs()` in
 /// order) is the default rig for backward-compat clients that omit `rig_id`.
+/// With `tls` set every connection must complete a TLS handshake first.
+/// `validator` decides which rigs and commands each token may use.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_listener(
     addr: SocketAddr,
     rigs: Arc<HashMap<String, RigHandle>>,
     default_rig_id: String,
-    auth_tokens: HashSet<String>,
+    validator: ScopedTokenValidator,
     station_coords: Option<(f64, f64)>,
+    tle_max_age_days: f64,
     timeouts: ListenerTimeouts,
+    tls: Option<TlsAcceptor>,
+    clients: Arc<Gauge>,
     mut shutdown_rx: watch::Receiver<bool>,
 ) -> std::io::Result<()> {
     let listener = TcpListener::bind(addr).await?;
-    info!("Listening on {}", addr);
-    let validator = Arc::new(SimpleTokenValidator::new(auth_tokens));
-    let sat_pass_cache: Arc<Mutex<Option<SatPassCache>>> = Arc::new(Mutex::new(None));
+    info!(
+        "Listening on {}{}",
+        addr,
+        if tls.is_some() { " (TLS)" } else { "" }
+    );
+    let ctx = ClientContext::new(
+        rigs,
+        default_rig_id,
+        Arc::new(validator),
+        station_coords,
+        tle_max_age_days,
+        timeouts,
+        tls,
+    );
     let conn_tracker = Arc::new(Mutex::new(ConnectionTracker::new()));
 
     loop {
@@ -147,20 +195,16 @@ pub async fn run_listener(
 
                 info!("Client connected: {}", peer);
 
-                let ctx = ClientContext {
-                    rigs: Arc::clone(&rigs),
-                    default_rig_id: default_rig_id.clone(),
-                    validator: Arc::clone(&validator),
-                    station_coords,
-                    sat_pass_cache: Arc::clone(&sat_pass_cache),
-                    timeouts,
-                };
+                let ctx = ctx.clone();
                 let client_shutdown_rx = shutdown_rx.clone();
                 let tracker_clone = Arc::clone(&conn_tracker);
+                let clients = Arc::clone(&clients);
                 tokio::spawn(async move {
+                    clients.inc();
                     if let Err(e) = handle_client(socket, peer, ctx, client_shutdown_rx).await {
                         error!("Client {} error: {:?}", peer, e);
                     }
+                    clients.dec();
                     // Release connection slot when client disconnects.
                     if let Ok(mut tracker) = tracker_clone.lock() {
                         tracker.release(peer_ip);
@@ -234,9 +278,9 @@ async fn read_limited_line<R: AsyncBufRead + Unpin>(
     }
 }
 
-async fn send_response(
-    writer: &mut tokio::net::tcp::OwnedWriteHalf,
-    response: &ClientResponse,
+async fn send_response<T: Serialize>(
+    writer: &mut WriteHalf<BufReader<TransportStream>>,
+    response: &T,
     io_timeout: Duration,
 ) -> std::io::Result<()> {
     let resp_line = serde_json::to_string(response).map_err(std::io::Error::other)? + "\n";
@@ -257,6 +301,147 @@ async fn handle_client(
     socket: TcpStream,
     addr: SocketAddr,
     ctx: ClientContext,
+    shutdown_rx: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    // Disable Nagle so small frames (command responses, meter samples) ship
+    // immediately instead of sitting in the kernel's send buffer for up to
+    // ~40 ms waiting for more payload.
+    let _ = socket.set_nodelay(true);
+    let io_timeout = ctx.timeouts.io_timeout;
+    let socket = time::timeout(
+        io_timeout,
+        TransportStream::accept(socket, ctx.tls.as_ref()),
+    )
+    .await
+    .map_err(|_| std::io::Error::new(std::io::ErrorKind::TimedOut, "TLS handshake timeout"))??;
+
+    // A multiplexed session starts with a binary hello frame, a plain
+    // control connection with a JSON line.
+    let mut socket = BufReader::new(socket);
+    let first = time::timeout(io_timeout, socket.fill_buf())
+        .await
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "read timeout waiting for client request",
+            )
+        })??
+        .first()
+        .copied();
+    match first {
+        Some(AUDIO_MSG_MUX_HELLO) => serve_mux(socket, addr, ctx, shutdown_rx).await,
+        Some(_) => serve_control(socket, addr, ctx, shutdown_rx).await,
+        None => {
+            info!("Client {} disconnected", addr);
+            Ok(())
+        }
+    }
+}
+
+/// Serve a multiplexed session: control streams run the regular request
+/// loop, audio streams are handed to the rig's audio listener.
+pub(crate) async fn serve_mux(
+    socket: BufReader<TransportStream>,
+    addr: SocketAddr,
+    ctx: ClientContext,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    let tls = socket.get_ref().is_tls();
+    let (reader, writer) = tokio::io::split(socket);
+    let validator = Arc::clone(&ctx.validator);
+    let (mux, hello, mut streams) = time::timeout(
+        ctx.timeouts.io_timeout,
+        Mux::accept(reader, writer, |hello| {
+            validator
+                .authenticate(&hello.token, SystemTime::now())
+                .map(|_| ())
+        }),
+    )
+    .await
+    .map_err(|_| std::io::Error::new(std::io::ErrorKind::TimedOut, "mux handshake timeout"))??;
+    info!("Client {} opened a multiplexed session", addr);
+
+    loop {
+        let (open, stream) = tokio::select! {
+            next = streams.recv() => match next {
+                Some(next) => next,
+                None => break,
+            },
+            changed = shutdown_rx.changed() => {
+                match changed {
+                    Ok(()) if *shutdown_rx.borrow() => break,
+                    Ok(()) => continue,
+                    Err(_) => break,
+                }
+            }
+        };
+        let stream = TransportStream::Mux {
+            stream: Box::new(stream),
+            tls,
+        };
+        match open.stream {
+            MuxStreamKind::Control => {
+                let ctx = ctx.clone();
+                let stream_shutdown_rx = shutdown_rx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) =
+                        serve_control(BufReader::new(stream), addr, ctx, stream_shutdown_rx).await
+                    {
+                        info!("Client {} control stream ended: {}", addr, e);
+                    }
+                });
+            }
+            MuxStreamKind::Audio { rig_id } => {
+                let rig_id = rig_id.unwrap_or_else(|| ctx.default_rig_id.clone());
+                // The session token stands in for the audio port's TLS and
+                // network-level access; it needs at least read scope, and tx
+                // scope for the stream to carry TX audio.
+                let allowed = validator
+                    .authenticate(&hello.token, SystemTime::now())
+                    .and_then(|access| {
+                        access
+                            .authorize(&rig_id, Scope::Read)
+                            .map_err(|denied| format!("needs '{}'", denied.required))?;
+                        Ok(access.scope_for(&rig_id) >= Some(Scope::Tx))
+                    });
+                let sender = ctx.rigs.get(&rig_id).and_then(|h| h.audio_streams.as_ref());
+                match (allowed, sender) {
+                    (Err(e), _) => {
+                        warn!("Client {} denied audio on rig '{}': {}", addr, rig_id, e)
+                    }
+                    (Ok(_), None) => {
+                        warn!(
+                            "Client {} asked for audio of rig '{}', which has none",
+                            addr, rig_id
+                        )
+                    }
+                    (Ok(tx_allowed), Some(sender)) => {
+                        let audio = MuxAudioStream {
+                            stream,
+                            peer: addr,
+                            tx_allowed,
+                        };
+                        if sender.try_send(audio).is_err() {
+                            warn!(
+                                "Audio listener of rig '{}' is not accepting streams",
+                                rig_id
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+    mux.close();
+    info!("Client {} multiplexed session closed", addr);
+    Ok(())
+}
+
+/// Serve the JSON request loop on one control connection or stream.
+async fn serve_control(
+    socket: BufReader<TransportStream>,
+    addr: SocketAddr,
+    ctx: ClientContext,
     mut shutdown_rx: watch::Receiver<bool>,
 ) -> std::io::Result<()> {
     let ClientContext {
@@ -264,14 +449,12 @@ async fn handle_client(
         default_rig_id,
         validator,
         station_coords,
+        tle_max_age_days,
         sat_pass_cache,
         timeouts,
+        tls: _,
     } = ctx;
-    // Disable Nagle so small frames (command responses, meter samples) ship
-    // immediately instead of sitting in the kernel's send buffer for up to
-    // ~40 ms waiting for more payload.
-    let _ = socket.set_nodelay(true);
-    let (reader, mut writer) = socket.into_split();
+    let (reader, mut writer) = tokio::io::split(socket);
     let mut reader = BufReader::new(reader);
 
     loop {
@@ -322,10 +505,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: false,
                     rig_id: None,
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: None,
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: Some(format!("Invalid JSON: {}", e)),
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -333,31 +517,85 @@ async fn handle_client(
             }
         };
 
-        if let Err(err) = validator.as_ref().validate(&envelope.token) {
+        let access = match validator.authenticate(&envelope.token, SystemTime::now()) {
+            Ok(access) => access,
+            Err(err) => {
+                let resp = ClientResponse {
+                    success: false,
+                    rig_id: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
+                    state: None,
+                    rigs: None,
+                    sat_passes: None,
+                    denied: None,
+                    error: Some(err),
+                };
+                send_response(&mut writer, &resp, timeouts.io_timeout).await?;
+                continue;
+            }
+        };
+
+        // Resolve rig_id from the envelope (absent = default).
+        let target_rig_id = envelope
+            .rig_id
+            .as_deref()
+            .unwrap_or(&default_rig_id)
+            .to_string();
+
+        // Authorize before anything is answered.  GetRigs and GetSatPasses
+        // are server-wide: they need the scope on the target rig or, when
+        // that is out of reach, on any rig the server runs.
+        let required = required_scope(&envelope.cmd);
+        let authorized = access.authorize(&target_rig_id, required);
+        let authorized = if matches!(
+            envelope.cmd,
+            ClientCommand::GetRigs | ClientCommand::GetSatPasses
+        ) {
+            authorized.or_else(|denied| {
+                if rigs.keys().any(|id| access.authorize(id, required).is_ok()) {
+                    Ok(())
+                } else {
+                    Err(denied)
+                }
+            })
+        } else {
+            authorized
+        };
+        if let Err(denied) = authorized {
+            let granted = match denied.granted {
+                Some(scope) => format!("token has '{}'", scope),
+                None => "token has no access to this rig".to_string(),
+            };
+            warn!(
+                "Client {} denied on rig '{}': needs '{}', {}",
+                addr, target_rig_id, denied.required, granted
+            );
+            let error = format!(
+                "insufficient scope: '{}' required ({})",
+                denied.required, granted
+            );
             let resp = ClientResponse {
                 success: false,
-                rig_id: None,
-                protocol_version: None,
+                rig_id: Some(target_rig_id.clone()),
+                protocol_version: Some(PROTOCOL_VERSION),
                 state: None,
                 rigs: None,
                 sat_passes: None,
-                error: Some(err),
+                denied: Some(denied),
+                error: Some(error),
             };
             send_response(&mut writer, &resp, timeouts.io_timeout).await?;
             continue;
         }
 
-        // Resolve rig_id from the envelope (absent = default).
-        let target_rig_id = envelope
-            .rig_id
-            .as_deref()
-            .unwrap_or(&default_rig_id)
-            .to_string();
-
         // GetRigs: aggregate all rig states and return without hitting any task.
         if matches!(envelope.cmd, ClientCommand::GetRigs) {
             let mut entries: Vec<RigEntry> = Vec::new();
             for handle in rigs.values() {
+                // Only list rigs the token may see.
+                if access.scope_for(&handle.rig_id).is_none() {
+                    continue;
+                }
                 let state = handle.state_rx.borrow().clone();
                 if let Some(snapshot) = state.snapshot() {
                     entries.push(RigEntry {
@@ -371,10 +609,11 @@ async fn handle_client(
             let resp = ClientResponse {
                 success: true,
                 rig_id: Some("server".to_string()),
-                protocol_version: None,
+                protocol_version: Some(PROTOCOL_VERSION),
                 state: None,
                 rigs: Some(entries),
                 sat_passes: None,
+                denied: None,
                 error: None,
             };
             send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -407,7 +646,13 @@ async fn handle_client(
                 let fresh = match time::timeout(
                     Duration::from_secs(30),
                     tokio::task::spawn_blocking(move || {
-                        trx_core::geo::compute_upcoming_passes(lat, lon, now_ms, window_ms)
+                        trx_core::geo::compute_upcoming_passes(
+                            lat,
+                            lon,
+                            now_ms,
+                            window_ms,
+                            tle_max_age_days,
+                        )
                     }),
                 )
                 .await
@@ -419,6 +664,7 @@ async fn handle_client(
                             passes: vec![],
                             satellite_count: 0,
                             tle_source: trx_core::geo::TleSource::Unavailable,
+                            tle_warning: None,
                         }
                     }
                     Err(_) => {
@@ -427,6 +673,7 @@ async fn handle_client(
                             passes: vec![],
                             satellite_count: 0,
                             tle_source: trx_core::geo::TleSource::Unavailable,
+                            tle_warning: None,
                         }
                     }
                 };
@@ -443,15 +690,17 @@ async fn handle_client(
                     passes: vec![],
                     satellite_count: 0,
                     tle_source: trx_core::geo::TleSource::Unavailable,
+                    tle_warning: None,
                 }
             };
             let resp = ClientResponse {
                 success: true,
                 rig_id: Some("server".to_string()),
-                protocol_version: None,
+                protocol_version: Some(PROTOCOL_VERSION),
                 state: None,
                 rigs: None,
                 sat_passes: Some(result),
+                denied: None,
                 error: None,
             };
             send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -466,10 +715,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: false,
                     rig_id: Some(target_rig_id.clone()),
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: None,
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: Some(format!("Unknown rig_id: {}", target_rig_id)),
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -477,11 +727,33 @@ async fn handle_client(
             }
         };
 
+        // SubscribeDecode is only served by the client's WebSocket endpoint;
+        // decoded messages reach clients over the audio connection.
+        if matches!(envelope.cmd, ClientCommand::SubscribeDecode) {
+            let resp = ClientResponse {
+                success: false,
+                rig_id: Some(target_rig_id.clone()),
+                protocol_version: Some(PROTOCOL_VERSION),
+                state: None,
+                rigs: None,
+                sat_passes: None,
+                denied: None,
+                error: Some(
+                    "subscribe_decode is not served on the control link; \
+                     decoded messages are carried on the audio connection"
+                        .to_string(),
+                ),
+            };
+            send_response(&mut writer, &resp, timeouts.io_timeout).await?;
+            continue;
+        }
+
         // SubscribeMeter: turns this connection into a one-way meter stream.
         // No regular responses are produced; the connection lives until the
         // client disconnects or shutdown fires.
         if matches!(envelope.cmd, ClientCommand::SubscribeMeter) {
             let mut meter_rx = handle.meter_tx.subscribe();
+            let meter_lag = handle.metrics.broadcast_lag("meter");
             let io_timeout = timeouts.io_timeout;
             info!(
                 "Client {} subscribed to meter stream for rig '{}'",
@@ -510,7 +782,10 @@ async fn handle_client(
                                     }
                                 }
                             }
-                            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                            Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                                meter_lag.add(n);
+                                continue;
+                            }
                             Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
                         }
                     }
@@ -526,6 +801,57 @@ async fn handle_client(
             break;
         }
 
+        // SubscribeState: turns this connection into a one-way stream of
+        // `StateUpdate` frames, starting with a full snapshot and followed by
+        // diffs whenever the rig state changes.  An idle stream gets an empty
+        // line now and then so the client can tell it from a dead link.
+        if matches!(envelope.cmd, ClientCommand::SubscribeState) {
+            let mut state_rx = handle.state_rx.clone();
+            let mut encoder = StateEncoder::new();
+            info!(
+                "Client {} subscribed to state stream for rig '{}'",
+                addr, target_rig_id
+            );
+            loop {
+                let update = state_rx
+                    .borrow_and_update()
+                    .snapshot()
+                    .and_then(|snapshot| encoder.encode(&target_rig_id, &snapshot));
+                if let Some(update) = update {
+                    if let Err(e) = send_response(&mut writer, &update, timeouts.io_timeout).await {
+                        info!("Client {} state write failed: {}", addr, e);
+                        break;
+                    }
+                }
+                tokio::select! {
+                    changed = state_rx.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                    }
+                    changed = shutdown_rx.changed() => {
+                        match changed {
+                            Ok(()) if *shutdown_rx.borrow() => break,
+                            Ok(()) => {}
+                            Err(_) => break,
+                        }
+                    }
+                    _ = time::sleep(STATE_KEEPALIVE_INTERVAL) => {
+                        let sent = time::timeout(timeouts.io_timeout, async {
+                            writer.write_all(b"\n").await?;
+                            writer.flush().await
+                        })
+                        .await;
+                        if !matches!(sent, Ok(Ok(()))) {
+                            info!("Client {} state keepalive failed", addr);
+                            break;
+                        }
+                    }
+                }
+            }
+            break;
+        }
+
         let rig_cmd = mapping::client_command_to_rig(envelope.cmd);
         // Fast path: serve GetSnapshot directly from the watch channel
         // so clients get a response even while the rig task is initializing.
@@ -535,10 +861,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: true,
                     rig_id: Some(target_rig_id.clone()),
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: Some(snapshot),
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: None,
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -563,10 +890,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: false,
                     rig_id: Some(target_rig_id.clone()),
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: None,
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: Some("Internal error: rig task not available".into()),
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -576,10 +904,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: false,
                     rig_id: Some(target_rig_id.clone()),
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: None,
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: Some("Internal error: request queue timeout".into()),
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -595,10 +924,11 @@ async fn handle_client(
                         let resp = ClientResponse {
                             success: false,
                             rig_id: Some(target_rig_id.clone()),
-                            protocol_version: None,
+                            protocol_version: Some(PROTOCOL_VERSION),
                             state: None,
                             rigs: None,
                             sat_passes: None,
+                            denied: None,
                             error: Some("Request timed out waiting for rig response".into()),
                         };
                         send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -621,10 +951,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: true,
                     rig_id: Some(target_rig_id.clone()),
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: Some(snapshot),
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: None,
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -633,10 +964,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: false,
                     rig_id: Some(target_rig_id.clone()),
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: None,
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: Some(err.message),
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -646,10 +978,11 @@ async fn handle_client(
                 let resp = ClientResponse {
                     success: false,
                     rig_id: Some(target_rig_id.clone()),
-                    protocol_version: None,
+                    protocol_version: Some(PROTOCOL_VERSION),
                     state: None,
                     rigs: None,
                     sat_passes: None,
+                    denied: None,
                     error: Some("Internal error waiting for rig response".into()),
                 };
                 send_response(&mut writer, &resp, timeouts.io_timeout).await?;
@@ -703,6 +1036,7 @@ mod tests {
                 attenuator: false,
                 preamp: false,
                 rit: false,
+                xit: false,
                 rpt: false,
                 split: false,
                 tx: true,
@@ -710,6 +1044,11 @@ mod tests {
                 vfo_switch: true,
                 filter_controls: false,
                 signal_meter: true,
+                memory_channels: 0,
+                memory_write: false,
+                band_stack: false,
+                ctcss_decode: false,
+                dcs: false,
             },
             access: RigAccessMethod::Tcp {
                 addr: "127.0.0.1:1234".to_string(),
@@ -730,6 +1069,8 @@ mod tests {
             state_rx,
             audio_port: 4531,
             meter_tx,
+            metrics: Arc::default(),
+            audio_streams: None,
         };
         let mut map = HashMap::new();
         map.insert("default".to_string(), handle);
@@ -749,9 +1090,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            auth,
+            ScopedTokenValidator::from_tokens(auth),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -777,6 +1121,83 @@ mod tests {
         let _ = handle.await;
     }
 
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn listener_enforces_token_scope() {
+        use trx_protocol::auth::{hash_token, TokenGrant};
+        use trx_protocol::types::Scope;
+
+        let addr = loopback_addr();
+        let (rigs, default_id) = make_rigs(sample_state());
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+        let validator = ScopedTokenValidator::new(vec![
+            TokenGrant {
+                name: "viewer".to_string(),
+                token_sha256: hash_token("viewer"),
+                scope: Scope::Read,
+                rigs: Vec::new(),
+                expires_at: None,
+            },
+            TokenGrant {
+                name: "stranger".to_string(),
+                token_sha256: hash_token("stranger"),
+                scope: Scope::Admin,
+                rigs: vec!["elsewhere".to_string()],
+                expires_at: None,
+            },
+        ]);
+        let handle = tokio::spawn(run_listener(
+            addr,
+            rigs,
+            default_id,
+            validator,
+            None,
+            14.0,
+            ListenerTimeouts::default(),
+            None,
+            Arc::default(),
+            shutdown_rx,
+        ));
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let stream = TcpStream::connect(addr).await.expect("connect");
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+
+        let resp = send_and_recv(
+            &mut writer,
+            &mut reader,
+            br#"{"token":"viewer","cmd":"get_state"}"#,
+        )
+        .await;
+        assert!(resp.success);
+
+        let resp = send_and_recv(
+            &mut writer,
+            &mut reader,
+            br#"{"token":"viewer","cmd":"set_ptt","ptt":true}"#,
+        )
+        .await;
+        assert!(!resp.success);
+        let denied = resp.denied.expect("scope denial");
+        assert_eq!(denied.required, Scope::Tx);
+        assert_eq!(denied.granted, Some(Scope::Read));
+
+        // Server-wide queries still need a rig the token can see.
+        for cmd in ["get_rigs", "get_sat_passes"] {
+            let line = format!(r#"{{"token":"stranger","cmd":"{cmd}"}}"#);
+            let resp = send_and_recv(&mut writer, &mut reader, line.as_bytes()).await;
+            assert!(!resp.success, "{cmd} answered without access");
+            assert_eq!(resp.denied.expect("scope denial").granted, None);
+        }
+
+        let _ = shutdown_tx.send(true);
+        handle.abort();
+        let _ = handle.await;
+    }
+
     #[tokio::test]
     #[ignore = "requires TCP bind permissions"]
     async fn listener_serves_get_state_snapshot() {
@@ -788,9 +1209,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            HashSet::new(),
+            ScopedTokenValidator::default(),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -831,9 +1255,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            HashSet::new(),
+            ScopedTokenValidator::default(),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -895,6 +1322,7 @@ mod tests {
                 attenuator: false,
                 preamp: false,
                 rit: false,
+                xit: false,
                 rpt: false,
                 split: false,
                 tx: true,
@@ -902,6 +1330,11 @@ mod tests {
                 vfo_switch: true,
                 filter_controls: false,
                 signal_meter: true,
+                memory_channels: 0,
+                memory_write: false,
+                band_stack: false,
+                ctcss_decode: false,
+                dcs: false,
             },
             access: RigAccessMethod::Tcp {
                 addr: "127.0.0.1:0".to_string(),
@@ -931,6 +1364,8 @@ mod tests {
             state_rx: state_rx_a,
             audio_port: 4531,
             meter_tx: meter_tx_a,
+            metrics: Arc::default(),
+            audio_streams: None,
         };
 
         let (tx_b, rx_b) = mpsc::channel::<RigRequest>(8);
@@ -943,6 +1378,8 @@ mod tests {
             state_rx: state_rx_b,
             audio_port: 4532,
             meter_tx: meter_tx_b,
+            metrics: Arc::default(),
+            audio_streams: None,
         };
 
         let mut map = HashMap::new();
@@ -980,9 +1417,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            HashSet::new(),
+            ScopedTokenValidator::default(),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -1030,6 +1470,76 @@ mod tests {
         let _ = handle.await;
     }
 
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn mux_session_serves_control_streams() {
+        use trx_core::mux::{MuxHello, MuxOpen, MuxPriority};
+
+        let state_hf = sample_state_custom("HF-Dummy", 14_200_000, trx_core::RigMode::USB);
+        let state_vhf = sample_state_custom("VHF-Dummy", 145_500_000, trx_core::RigMode::FM);
+
+        let (rigs, default_id, _rx_a, _rx_b) = make_two_rigs(state_hf, state_vhf);
+        let addr = loopback_addr();
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+        let handle = tokio::spawn(run_listener(
+            addr,
+            rigs,
+            default_id,
+            ScopedTokenValidator::default(),
+            None,
+            14.0,
+            ListenerTimeouts::default(),
+            None,
+            Arc::default(),
+            shutdown_rx,
+        ));
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let stream = TcpStream::connect(addr).await.expect("connect");
+        let (read_half, write_half) = stream.into_split();
+        let mux = Mux::connect(read_half, write_half, &MuxHello::new(None))
+            .await
+            .expect("mux handshake");
+
+        // Two control streams share the connection and answer independently.
+        let hf = mux
+            .open(&MuxOpen::control(MuxPriority::High))
+            .expect("open");
+        let vhf = mux.open(&MuxOpen::control(MuxPriority::Low)).expect("open");
+        let (hf_reader, mut hf_writer) = tokio::io::split(hf);
+        let (vhf_reader, mut vhf_writer) = tokio::io::split(vhf);
+        let mut hf_reader = BufReader::new(hf_reader);
+        let mut vhf_reader = BufReader::new(vhf_reader);
+
+        vhf_writer
+            .write_all(b"{\"rig_id\":\"rig_vhf\",\"cmd\":\"get_state\"}\n")
+            .await
+            .expect("write");
+        hf_writer
+            .write_all(b"{\"rig_id\":\"rig_hf\",\"cmd\":\"get_state\"}\n")
+            .await
+            .expect("write");
+
+        let mut line = String::new();
+        hf_reader.read_line(&mut line).await.expect("read");
+        let resp: ClientResponse = serde_json::from_str(line.trim_end()).expect("json");
+        assert_eq!(resp.state.expect("hf snapshot").info.model, "HF-Dummy");
+
+        line.clear();
+        vhf_reader.read_line(&mut line).await.expect("read");
+        let resp: ClientResponse = serde_json::from_str(line.trim_end()).expect("json");
+        assert_eq!(resp.state.expect("vhf snapshot").info.model, "VHF-Dummy");
+
+        let _ = shutdown_tx.send(true);
+        tokio::time::timeout(std::time::Duration::from_secs(1), mux.closed())
+            .await
+            .expect("session closes on shutdown");
+        handle.abort();
+        let _ = handle.await;
+    }
+
     #[tokio::test]
     #[ignore = "requires TCP bind permissions"]
     async fn multi_rig_default_fallback() {
@@ -1045,9 +1555,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            HashSet::new(),
+            ScopedTokenValidator::default(),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -1083,9 +1596,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            HashSet::new(),
+            ScopedTokenValidator::default(),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -1143,9 +1659,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            HashSet::new(),
+            ScopedTokenValidator::default(),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -1200,9 +1719,12 @@ mod tests {
             addr,
             rigs,
             default_id,
-            HashSet::new(),
+            ScopedTokenValidator::default(),
             None,
+            14.0,
             ListenerTimeouts::default(),
+            None,
+            Arc::default(),
             shutdown_rx,
         ));
 
@@ -1240,4 +1762,73 @@ mod tests {
         handle.abort();
         let _ = handle.await;
     }
+
+    #[tokio::test]
+    #[ignore = "requires TCP bind permissions"]
+    async fn listener_streams_state_updates() {
+        use trx_protocol::{StateDecoder, StatePayload, StateUpdate};
+
+        let addr = loopback_addr();
+        let (rig_tx, _rig_rx) = mpsc::channel::<RigRequest>(8);
+        let (state_tx, state_rx) = watch::channel(sample_state());
+        let (meter_tx, _) = tokio::sync::broadcast::channel(8);
+        let handle = RigHandle {
+            rig_id: "default".to_string(),
+            display_name: "Default Rig".to_string(),
+            rig_tx,
+            state_rx,
+            audio_port: 4531,
+            meter_tx,
+            metrics: Arc::default(),
+            audio_streams: None,
+        };
+        let rigs = Arc::new(HashMap::from([("default".to_string(), handle)]));
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+        let handle = tokio::spawn(run_listener(
+            addr,
+            rigs,
+            "default".to_string(),
+            ScopedTokenValidator::default(),
+            None,
+            14.0,
+            ListenerTimeouts::default(),
+            None,
+            Arc::default(),
+            shutdown_rx,
+        ));
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let stream = TcpStream::connect(addr).await.expect("connect");
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+        writer
+            .write_all(br#"{"cmd":"subscribe_state"}"#)
+            .await
+            .expect("write");
+        writer.write_all(b"\n").await.expect("newline");
+        writer.flush().await.expect("flush");
+
+        let mut decoder = StateDecoder::new();
+        let mut line = String::new();
+        reader.read_line(&mut line).await.expect("read full");
+        let full: StateUpdate = serde_json::from_str(line.trim_end()).expect("frame json");
+        assert_eq!(full.seq, 1);
+        assert!(matches!(full.payload, StatePayload::Full { .. }));
+        decoder.apply(full).expect("apply full");
+
+        state_tx.send_modify(|state| state.status.freq.hz = 145_500_000);
+        line.clear();
+        reader.read_line(&mut line).await.expect("read diff");
+        let diff: StateUpdate = serde_json::from_str(line.trim_end()).expect("frame json");
+        assert_eq!(diff.seq, 2);
+        assert!(matches!(diff.payload, StatePayload::Diff { .. }));
+        let snapshot = decoder.apply(diff).expect("apply diff");
+        assert_eq!(snapshot.status.freq.hz, 145_500_000);
+
+        let _ = shutdown_tx.send(true);
+        handle.abort();
+        let _ = handle.await;
+    }
 }
@@ -2,16 +2,19 @@
 //
 // SPDX-License-Identifier: BSD-2-Clause
 
+mod adsb_feed;
 mod audio;
 mod config;
+mod cw_keyer;
 mod error;
 mod history_store;
 mod listener;
 mod rig_handle;
 mod rig_task;
+mod skimmer_feed;
+mod uplink;
 
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -26,20 +29,26 @@ use tracing::{error, info, warn};
 
 use trx_core::audio::AudioStreamInfo;
 
+use trx_app::tls::TlsAcceptor;
 use trx_app::{init_logging, normalize_name};
 use trx_backend::{register_builtin_backends_on, RegistrationContext, RigAccess};
+use trx_core::keyer::{CwKeyerState, KeyerSettings};
+use trx_core::metrics::{Registry, RigMetrics};
 use trx_core::rig::controller::{AdaptivePolling, ExponentialBackoff};
 use trx_core::rig::request::RigRequest;
 use trx_core::rig::state::RigState;
+use trx_core::rotator::Rotator;
 use trx_core::DynResult;
 
 use audio::DecoderHistories;
 use config::{RigInstanceConfig, ServerConfig};
-use rig_handle::RigHandle;
+use rig_handle::{MuxAudioStream, RigHandle};
 use trx_decode_log::DecoderLoggers;
 
 const PKG_DESCRIPTION: &str = concat!(env!("CARGO_PKG_NAME"), " - rig server daemon");
 const RETRY_MAX_DELAY_SECS: u64 = 2;
+/// Audio streams of multiplexed connections waiting for a rig's audio listener.
+const MUX_AUDIO_BACKLOG: usize = 8;
 
 #[derive(Debug, Parser)]
 #[command(
@@ -219,6 +228,41 @@ fn access_from_rig_instance(rig_cfg: &RigInstanceConfig) -> DynResult<RigAccess>
     }
 }
 
+/// Build the antenna rotator configured for a rig instance, if any.
+///
+/// A rotator that cannot be opened is logged and left out so the rig
+/// itself still starts.
+fn rotator_from_rig_instance(rig_cfg: &RigInstanceConfig) -> Option<Box<dyn Rotator>> {
+    let cfg = &rig_cfg.rotator;
+    if !cfg.enabled {
+        return None;
+    }
+    let access = if cfg.model.eq_ignore_ascii_case("rotctld") {
+        RigAccess::Tcp {
+            addr: format!(
+                "{}:{}",
+                cfg.access.host.as_deref().unwrap_or("127.0.0.1"),
+                cfg.access.tcp_port.unwrap_or(4533)
+            ),
+        }
+    } else {
+        RigAccess::Serial {
+            path: cfg.access.port.clone().unwrap_or_default(),
+            baud: cfg.access.baud.unwrap_or(9600),
+        }
+    };
+    match trx_backend::build_rotator(&cfg.model, access, cfg.az_max_deg, cfg.el_max_deg) {
+        Ok(rotator) => {
+            info!("[{}] Rotator: {}", rig_cfg.id, cfg.model);
+            Some(rotator)
+        }
+        Err(e) => {
+            warn!("[{}] Rotator disabled: {}", rig_cfg.id, e);
+            None
+        }
+    }
+}
+
 async fn wait_for_shutdown(mut shutdown_rx: watch::Receiver<bool>) {
     if *shutdown_rx.borrow() {
         return;
@@ -280,6 +324,8 @@ type SdrRigBuildResult = DynResult<(
         tokio::sync::broadcast::Receiver<Vec<f32>>,
     ),
     tokio::sync::broadcast::Receiver<Vec<num_complex::Complex<f32>>>,
+    OptionalSdrAdsbIqRx,
+    OptionalSdrSkimmerIqRx,
     trx_core::vchan::SharedVChanManager,
 )>;
 
@@ -287,10 +333,15 @@ type OptionalSdrRig = Option<Box<dyn trx_core::rig::RigCat>>;
 type OptionalSdrPcmRx = Option<broadcast::Receiver<Vec<f32>>>;
 type OptionalSdrAisPcmRx = Option<(broadcast::Receiver<Vec<f32>>, broadcast::Receiver<Vec<f32>>)>;
 type OptionalSdrVdesIqRx = Option<broadcast::Receiver<Vec<num_complex::Complex<f32>>>>;
+type OptionalSdrAdsbIqRx = Option<broadcast::Receiver<Vec<num_complex::Complex<f32>>>>;
+type OptionalSdrSkimmerIqRx = Option<broadcast::Receiver<Vec<num_complex::Complex<f32>>>>;
 
 /// Build a `SoapySdrRig` with full channel config from a `RigInstanceConfig`.
 #[cfg(feature = "soapysdr")]
-fn build_sdr_rig_from_instance(rig_cfg: &RigInstanceConfig) -> SdrRigBuildResult {
+fn build_sdr_rig_from_instance(
+    rig_cfg: &RigInstanceConfig,
+    metrics: Arc<RigMetrics>,
+) -> SdrRigBuildResult {
     use trx_core::radio::freq::Freq;
     use trx_core::rig::AudioSource;
 
@@ -350,6 +401,7 @@ fn build_sdr_rig_from_instance(rig_cfg: &RigInstanceConfig) -> SdrRigBuildResult
         max_virtual_channels: rig_cfg.sdr.max_virtual_channels,
         nb_enabled: rig_cfg.sdr.noise_blanker.enabled,
         nb_threshold: rig_cfg.sdr.noise_blanker.threshold,
+        metrics,
     })?;
 
     let pcm_rx = sdr_rig.subscribe_pcm();
@@ -361,6 +413,10 @@ fn build_sdr_rig_from_instance(rig_cfg: &RigInstanceConfig) -> SdrRigBuildResult
     // IQ tap in ChannelDsp actually fires.  Fall back to channel 0 when no
     // explicit VDES channel has been configured.
     let vdes_iq = sdr_rig.subscribe_iq_channel(vdes_channel_idx);
+    // The Mode S decoder works on raw capture samples and needs exactly 2 Msps.
+    let adsb_iq =
+        (rig_cfg.sdr.sample_rate == trx_adsb::SAMPLE_RATE).then(|| sdr_rig.subscribe_iq());
+    let skimmer_iq = rig_cfg.skimmer.enabled.then(|| sdr_rig.subscribe_iq());
     // Extract the virtual channel manager before the rig is consumed by Box.
     let vchan_manager: trx_core::vchan::SharedVChanManager = sdr_rig.channel_manager();
     Ok((
@@ -368,6 +424,8 @@ fn build_sdr_rig_from_instance(rig_cfg: &RigInstanceConfig) -> SdrRigBuildResult
         pcm_rx,
         ais_pcm,
         vdes_iq,
+        adsb_iq,
+        skimmer_iq,
         vchan_manager,
     ))
 }
@@ -384,6 +442,7 @@ fn build_rig_task_config(
     registry: Arc<RegistrationContext>,
     histories: Arc<DecoderHistories>,
     timeouts: &config::TimeoutsConfig,
+    metrics: Arc<RigMetrics>,
 ) -> rig_task::RigTaskConfig {
     let pskreporter_status = if rig_cfg.pskreporter.enabled {
         let has_locator = rig_cfg.pskreporter.receiver_locator.is_some()
@@ -448,8 +507,26 @@ fn build_rig_task_config(
         histories,
         vfo_prime: rig_cfg.behavior.vfo_prime,
         prebuilt_rig: None,
+        rotator: rotator_from_rig_instance(rig_cfg),
+        rotator_step_deg: rig_cfg.rotator.step_deg,
+        cw_keyer: rig_cfg.cw_keyer.enabled.then(|| CwKeyerState {
+            settings: KeyerSettings {
+                wpm: rig_cfg.cw_keyer.wpm,
+                weight: rig_cfg.cw_keyer.weight,
+                farnsworth_wpm: rig_cfg.cw_keyer.farnsworth_wpm,
+            },
+            output: rig_cfg.cw_keyer.output,
+            tone_hz: rig_cfg.cw_keyer.tone_hz,
+            serial: 1,
+            memories: rig_cfg.cw_keyer.memories.clone(),
+            sending: None,
+        }),
+        cw_ptt_lead: Duration::from_millis(rig_cfg.cw_keyer.ptt_lead_ms as u64),
+        cw_ptt_tail: Duration::from_millis(rig_cfg.cw_keyer.ptt_tail_ms as u64),
+        cw_tone_tx: None,
         command_exec_timeout: Duration::from_millis(timeouts.command_exec_timeout_ms),
         poll_refresh_timeout: Duration::from_millis(timeouts.poll_refresh_timeout_ms),
+        metrics,
     }
 }
 
@@ -470,7 +547,14 @@ fn spawn_rig_audio_stack(
     sdr_pcm_rx: OptionalSdrPcmRx,
     sdr_ais_pcm_rx: OptionalSdrAisPcmRx,
     sdr_vdes_iq_rx: OptionalSdrVdesIqRx,
+    sdr_adsb_iq_rx: OptionalSdrAdsbIqRx,
+    sdr_skimmer_iq_rx: OptionalSdrSkimmerIqRx,
     vchan_manager: Option<trx_core::vchan::SharedVChanManager>,
+    cw_tone_rx: Option<mpsc::Receiver<cw_keyer::CwToneCommand>>,
+    audio_tls: Option<TlsAcceptor>,
+    decode_tx: broadcast::Sender<trx_core::decode::DecodedMessage>,
+    metrics: Arc<RigMetrics>,
+    mux_audio_rx: mpsc::Receiver<MuxAudioStream>,
 ) -> Vec<JoinHandle<()>> {
     let mut handles: Vec<JoinHandle<()>> = Vec::new();
 
@@ -478,6 +562,16 @@ fn spawn_rig_audio_stack(
         return handles;
     }
 
+    let count_decode_rx = decode_tx.subscribe();
+    let count_metrics = metrics.clone();
+    let count_shutdown_rx = shutdown_rx.clone();
+    handles.push(tokio::spawn(async move {
+        tokio::select! {
+            _ = trx_reporting::metrics::count_decodes(count_metrics, count_decode_rx) => {}
+            _ = wait_for_shutdown(count_shutdown_rx) => {}
+        }
+    }));
+
     let audio_listen = SocketAddr::from((
         listen_override.unwrap_or(rig_cfg.audio.listen),
         rig_cfg.audio.port,
@@ -494,8 +588,6 @@ fn spawn_rig_audio_stack(
 
     // PCM tap for server-side decoders
     let (pcm_tx, _) = broadcast::channel::<Vec<f32>>(64);
-    // Decoded messages broadcast
-    let (decode_tx, _) = broadcast::channel::<trx_core::decode::DecodedMessage>(256);
 
     if rig_cfg.pskreporter.enabled {
         let cs = callsign.clone().unwrap_or_default();
@@ -509,6 +601,7 @@ fn spawn_rig_audio_stack(
             let pr_state_rx = state_rx.clone();
             let pr_decode_rx = decode_tx.subscribe();
             let pr_shutdown_rx = shutdown_rx.clone();
+            let pr_metrics = metrics.uplink("pskreporter");
             handles.push(tokio::spawn(async move {
                 tokio::select! {
                     _ = trx_reporting::pskreporter::run_pskreporter_uplink(
@@ -517,7 +610,8 @@ fn spawn_rig_audio_stack(
                         latitude,
                         longitude,
                         pr_state_rx,
-                        pr_decode_rx
+                        pr_decode_rx,
+                        pr_metrics
                     ) => {}
                     _ = wait_for_shutdown(pr_shutdown_rx) => {}
                 }
@@ -542,9 +636,10 @@ fn spawn_rig_audio_stack(
             let ai_cfg = rig_cfg.aprsfi.clone();
             let ai_decode_rx = decode_tx.subscribe();
             let ai_shutdown_rx = shutdown_rx.clone();
+            let ai_metrics = metrics.uplink("aprsis");
             handles.push(tokio::spawn(async move {
                 tokio::select! {
-                    _ = trx_reporting::aprsfi::run_aprsfi_uplink(ai_cfg, cs, latitude, longitude, ai_decode_rx) => {}
+                    _ = trx_reporting::aprsfi::run_aprsfi_uplink(ai_cfg, cs, latitude, longitude, ai_decode_rx, ai_metrics) => {}
                     _ = wait_for_shutdown(ai_shutdown_rx) => {}
                 }
             }));
@@ -576,6 +671,8 @@ fn spawn_rig_audio_stack(
                 * rig_cfg.audio.frame_duration_ms as usize)
                 / 1000;
             let sdr_bitrate_bps = rig_cfg.audio.bitrate_bps;
+            let sdr_opus_encode = metrics.opus_encode_seconds.clone();
+            let sdr_lag = metrics.broadcast_lag("sdr_pcm");
             handles.push(tokio::spawn(async move {
                 let opus_ch = match sdr_channels {
                     1 => opus::Channels::Mono,
@@ -623,7 +720,9 @@ fn spawn_rig_audio_stack(
                             if rx_audio_tx_sdr.receiver_count() == 0 {
                                 continue;
                             }
-                            match encoder.encode_float(&pcm_frame, &mut opus_buf) {
+                            match sdr_opus_encode
+                                .time(|| encoder.encode_float(&pcm_frame, &mut opus_buf))
+                            {
                                 Ok(len) => {
                                     let pkt = Bytes::copy_from_slice(&opus_buf[..len]);
                                     let _ = rx_audio_tx_sdr.send(pkt);
@@ -634,6 +733,7 @@ fn spawn_rig_audio_stack(
                             }
                         }
                         Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                            sdr_lag.add(n);
                             tracing::warn!("SDR audio bridge: dropped {} frames", n);
                         }
                         Err(_) => break,
@@ -647,6 +747,7 @@ fn spawn_rig_audio_stack(
                 rx_audio_tx.clone(),
                 Some(pcm_tx.clone()),
                 shutdown_rx.clone(),
+                metrics.opus_encode_seconds.clone(),
             );
         }
 
@@ -657,11 +758,12 @@ fn spawn_rig_audio_stack(
         let aprs_sr = rig_cfg.audio.sample_rate;
         let aprs_ch = rig_cfg.audio.channels;
         let aprs_shutdown_rx = shutdown_rx.clone();
+        let aprs_lag = metrics.decoder_lag("aprs");
         let aprs_logs = decoder_logs.clone();
         let aprs_histories = histories.clone();
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_aprs_decoder(aprs_sr, aprs_ch as u16, aprs_pcm_rx, aprs_state_rx, aprs_decode_tx, aprs_logs, aprs_histories) => {}
+                _ = audio::run_aprs_decoder(aprs_sr, aprs_ch as u16, aprs_pcm_rx, aprs_state_rx, aprs_decode_tx, aprs_logs, aprs_histories, aprs_lag) => {}
                 _ = wait_for_shutdown(aprs_shutdown_rx) => {}
             }
         }));
@@ -673,11 +775,12 @@ fn spawn_rig_audio_stack(
         let hf_aprs_sr = rig_cfg.audio.sample_rate;
         let hf_aprs_ch = rig_cfg.audio.channels;
         let hf_aprs_shutdown_rx = shutdown_rx.clone();
+        let hf_aprs_lag = metrics.decoder_lag("hf_aprs");
         let hf_aprs_logs = decoder_logs.clone();
         let hf_aprs_histories = histories.clone();
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_hf_aprs_decoder(hf_aprs_sr, hf_aprs_ch as u16, hf_aprs_pcm_rx, hf_aprs_state_rx, hf_aprs_decode_tx, hf_aprs_logs, hf_aprs_histories) => {}
+                _ = audio::run_hf_aprs_decoder(hf_aprs_sr, hf_aprs_ch as u16, hf_aprs_pcm_rx, hf_aprs_state_rx, hf_aprs_decode_tx, hf_aprs_logs, hf_aprs_histories, hf_aprs_lag) => {}
                 _ = wait_for_shutdown(hf_aprs_shutdown_rx) => {}
             }
         }));
@@ -686,12 +789,13 @@ fn spawn_rig_audio_stack(
             let ais_state_rx = state_rx.clone();
             let ais_decode_tx = decode_tx.clone();
             let ais_shutdown_rx = shutdown_rx.clone();
+            let ais_lag = metrics.decoder_lag("ais");
             let ais_histories = histories.clone();
             let ais_sr = rig_cfg.audio.sample_rate;
             let ais_ch = rig_cfg.audio.channels as u16;
             handles.push(tokio::spawn(async move {
                 tokio::select! {
-                    _ = audio::run_ais_decoder(ais_sr, ais_ch, ais_a_pcm_rx, ais_b_pcm_rx, ais_state_rx, ais_decode_tx, ais_histories) => {}
+                    _ = audio::run_ais_decoder(ais_sr, ais_ch, ais_a_pcm_rx, ais_b_pcm_rx, ais_state_rx, ais_decode_tx, ais_histories, ais_lag) => {}
                     _ = wait_for_shutdown(ais_shutdown_rx) => {}
                 }
             }));
@@ -701,6 +805,7 @@ fn spawn_rig_audio_stack(
             let vdes_state_rx = state_rx.clone();
             let vdes_decode_tx = decode_tx.clone();
             let vdes_shutdown_rx = shutdown_rx.clone();
+            let vdes_lag = metrics.decoder_lag("vdes");
             let vdes_histories = histories.clone();
             // Mirror channel.rs pipeline_rates: target = audio_sr.max(96_000),
             // decim = sdr_sr / target, actual IQ rate = sdr_sr / decim.
@@ -712,12 +817,107 @@ fn spawn_rig_audio_stack(
             };
             handles.push(tokio::spawn(async move {
                 tokio::select! {
-                    _ = audio::run_vdes_decoder(vdes_sr, vdes_iq_rx, vdes_state_rx, vdes_decode_tx, vdes_histories) => {}
+                    _ = audio::run_vdes_decoder(vdes_sr, vdes_iq_rx, vdes_state_rx, vdes_decode_tx, vdes_histories, vdes_lag) => {}
                     _ = wait_for_shutdown(vdes_shutdown_rx) => {}
                 }
             }));
         }
 
+        if let Some(adsb_iq_rx) = sdr_adsb_iq_rx {
+            let adsb_cfg = &rig_cfg.adsb;
+            let mut spawn_feed = |enabled: bool, name: &'static str, port: u16| {
+                if !enabled {
+                    return None;
+                }
+                let feed_tx = adsb_feed::feed_channel();
+                let addr = SocketAddr::from((adsb_cfg.listen, port));
+                let feed_listener_tx = feed_tx.clone();
+                let feed_shutdown_rx = shutdown_rx.clone();
+                handles.push(tokio::spawn(async move {
+                    if let Err(e) =
+                        adsb_feed::run_feed_listener(name, addr, feed_listener_tx, feed_shutdown_rx)
+                            .await
+                    {
+                        error!("ADS-B {} feed error: {:?}", name, e);
+                    }
+                }));
+                Some(feed_tx)
+            };
+            let beast_tx = spawn_feed(adsb_cfg.beast_enabled, "Beast", adsb_cfg.beast_port);
+            let sbs_tx = spawn_feed(adsb_cfg.sbs_enabled, "SBS-1", adsb_cfg.sbs_port);
+
+            let adsb_state_rx = state_rx.clone();
+            let adsb_decode_tx = decode_tx.clone();
+            let adsb_shutdown_rx = shutdown_rx.clone();
+            let adsb_lag = metrics.decoder_lag("adsb");
+            let adsb_histories = histories.clone();
+            handles.push(tokio::spawn(async move {
+                tokio::select! {
+                    _ = audio::run_adsb_decoder(adsb_iq_rx, adsb_state_rx, adsb_decode_tx, adsb_histories, beast_tx, sbs_tx, adsb_lag) => {}
+                    _ = wait_for_shutdown(adsb_shutdown_rx) => {}
+                }
+            }));
+        } else if rig_cfg.adsb.beast_enabled || rig_cfg.adsb.sbs_enabled {
+            warn!(
+                "[{}] ADS-B feeds need an SDR capturing at {} Hz; feeds not started",
+                rig_cfg.id,
+                trx_adsb::SAMPLE_RATE
+            );
+        }
+
+        match (sdr_skimmer_iq_rx, vchan_manager.clone()) {
+            (Some(skimmer_iq_rx), Some(skimmer_vchan)) => {
+                let skimmer_cfg = rig_cfg.skimmer.clone();
+                let spotter = skimmer_cfg.spotter.clone().unwrap_or_else(|| {
+                    let cs = callsign.as_deref().unwrap_or("").trim().to_uppercase();
+                    if cs.is_empty() {
+                        "SKIMMER-#".to_string()
+                    } else {
+                        format!("{cs}-#")
+                    }
+                });
+                let telnet_tx = skimmer_cfg.telnet_enabled.then(|| {
+                    let feed_tx = skimmer_feed::feed_channel();
+                    let addr =
+                        SocketAddr::from((skimmer_cfg.telnet_listen, skimmer_cfg.telnet_port));
+                    let listener_tx = feed_tx.clone();
+                    let listener_spotter = spotter.clone();
+                    let listener_shutdown_rx = shutdown_rx.clone();
+                    handles.push(tokio::spawn(async move {
+                        if let Err(e) = skimmer_feed::run_telnet_listener(
+                            addr,
+                            listener_spotter,
+                            listener_tx,
+                            listener_shutdown_rx,
+                        )
+                        .await
+                        {
+                            error!("CW skimmer telnet feed error: {:?}", e);
+                        }
+                    }));
+                    feed_tx
+                });
+                let skimmer_sr = rig_cfg.sdr.sample_rate;
+                let skimmer_state_rx = state_rx.clone();
+                let skimmer_decode_tx = decode_tx.clone();
+                let skimmer_shutdown_rx = shutdown_rx.clone();
+                let skimmer_lag = metrics.decoder_lag("cw_skimmer");
+                handles.push(tokio::spawn(async move {
+                    tokio::select! {
+                        _ = audio::run_cw_skimmer(skimmer_sr, skimmer_cfg, spotter, skimmer_iq_rx, skimmer_state_rx, skimmer_vchan, skimmer_decode_tx, telnet_tx, skimmer_lag) => {}
+                        _ = wait_for_shutdown(skimmer_shutdown_rx) => {}
+                    }
+                }));
+            }
+            _ if rig_cfg.skimmer.enabled => {
+                warn!(
+                    "[{}] CW skimmer needs an SDR rig ([rig.access] type = \"sdr\"); not started",
+                    rig_cfg.id
+                );
+            }
+            _ => {}
+        }
+
         // Spawn CW decoder task
         let cw_pcm_rx = pcm_tx.subscribe();
         let cw_state_rx = state_rx.clone();
@@ -725,15 +925,85 @@ fn spawn_rig_audio_stack(
         let cw_sr = rig_cfg.audio.sample_rate;
         let cw_ch = rig_cfg.audio.channels;
         let cw_shutdown_rx = shutdown_rx.clone();
+        let cw_lag = metrics.decoder_lag("cw");
         let cw_logs = decoder_logs.clone();
         let cw_histories = histories.clone();
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_cw_decoder(cw_sr, cw_ch as u16, cw_pcm_rx, cw_state_rx, cw_decode_tx, cw_logs, cw_histories) => {}
+                _ = audio::run_cw_decoder(cw_sr, cw_ch as u16, cw_pcm_rx, cw_state_rx, cw_decode_tx, cw_logs, cw_histories, cw_lag) => {}
                 _ = wait_for_shutdown(cw_shutdown_rx) => {}
             }
         }));
 
+        // Spawn PSK decoder task
+        let psk_pcm_rx = pcm_tx.subscribe();
+        let psk_state_rx = state_rx.clone();
+        let psk_decode_tx = decode_tx.clone();
+        let psk_sr = rig_cfg.audio.sample_rate;
+        let psk_ch = rig_cfg.audio.channels;
+        let psk_shutdown_rx = shutdown_rx.clone();
+        let psk_lag = metrics.decoder_lag("psk");
+        let psk_logs = decoder_logs.clone();
+        let psk_histories = histories.clone();
+        handles.push(tokio::spawn(async move {
+            tokio::select! {
+                _ = audio::run_psk_decoder(psk_sr, psk_ch as u16, psk_pcm_rx, psk_state_rx, psk_decode_tx, psk_logs, psk_histories, psk_lag) => {}
+                _ = wait_for_shutdown(psk_shutdown_rx) => {}
+            }
+        }));
+
+        // Spawn DSC decoder task
+        let dsc_pcm_rx = pcm_tx.subscribe();
+        let dsc_state_rx = state_rx.clone();
+        let dsc_decode_tx = decode_tx.clone();
+        let dsc_sr = rig_cfg.audio.sample_rate;
+        let dsc_ch = rig_cfg.audio.channels;
+        let dsc_shutdown_rx = shutdown_rx.clone();
+        let dsc_lag = metrics.decoder_lag("dsc");
+        let dsc_logs = decoder_logs.clone();
+        let dsc_histories = histories.clone();
+        handles.push(tokio::spawn(async move {
+            tokio::select! {
+                _ = audio::run_dsc_decoder(dsc_sr, dsc_ch as u16, dsc_pcm_rx, dsc_state_rx, dsc_decode_tx, dsc_logs, dsc_histories, dsc_lag) => {}
+                _ = wait_for_shutdown(dsc_shutdown_rx) => {}
+            }
+        }));
+
+        // Spawn POCSAG decoder task
+        let pocsag_pcm_rx = pcm_tx.subscribe();
+        let pocsag_state_rx = state_rx.clone();
+        let pocsag_decode_tx = decode_tx.clone();
+        let pocsag_sr = rig_cfg.audio.sample_rate;
+        let pocsag_ch = rig_cfg.audio.channels;
+        let pocsag_shutdown_rx = shutdown_rx.clone();
+        let pocsag_lag = metrics.decoder_lag("pocsag");
+        let pocsag_logs = decoder_logs.clone();
+        let pocsag_histories = histories.clone();
+        let pocsag_cfg = rig_cfg.pocsag.clone();
+        handles.push(tokio::spawn(async move {
+            tokio::select! {
+                _ = audio::run_pocsag_decoder(pocsag_sr, pocsag_ch as u16, pocsag_pcm_rx, pocsag_state_rx, pocsag_decode_tx, pocsag_logs, pocsag_histories, pocsag_cfg, pocsag_lag) => {}
+                _ = wait_for_shutdown(pocsag_shutdown_rx) => {}
+            }
+        }));
+
+        // Spawn ACARS decoder task
+        let acars_pcm_rx = pcm_tx.subscribe();
+        let acars_state_rx = state_rx.clone();
+        let acars_decode_tx = decode_tx.clone();
+        let acars_sr = rig_cfg.audio.sample_rate;
+        let acars_ch = rig_cfg.audio.channels;
+        let acars_shutdown_rx = shutdown_rx.clone();
+        let acars_lag = metrics.decoder_lag("acars");
+        let acars_logs = decoder_logs.clone();
+        let acars_histories = histories.clone();
+        handles.push(tokio::spawn(async move {
+            tokio::select! {
+                _ = audio::run_acars_decoder(acars_sr, acars_ch as u16, acars_pcm_rx, acars_state_rx, acars_decode_tx, acars_logs, acars_histories, acars_lag) => {}
+                _ = wait_for_shutdown(acars_shutdown_rx) => {}
+            }
+        }));
+
         // Spawn FT8 decoder task
         let ft8_pcm_rx = pcm_tx.subscribe();
         let ft8_state_rx = state_rx.clone();
@@ -741,11 +1011,12 @@ fn spawn_rig_audio_stack(
         let ft8_sr = rig_cfg.audio.sample_rate;
         let ft8_ch = rig_cfg.audio.channels;
         let ft8_shutdown_rx = shutdown_rx.clone();
+        let ft8_lag = metrics.decoder_lag("ft8");
         let ft8_logs = decoder_logs.clone();
         let ft8_histories = histories.clone();
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_ft8_decoder(ft8_sr, ft8_ch as u16, ft8_pcm_rx, ft8_state_rx, ft8_decode_tx, ft8_logs, ft8_histories) => {}
+                _ = audio::run_ft8_decoder(ft8_sr, ft8_ch as u16, ft8_pcm_rx, ft8_state_rx, ft8_decode_tx, ft8_logs, ft8_histories, ft8_lag) => {}
                 _ = wait_for_shutdown(ft8_shutdown_rx) => {}
             }
         }));
@@ -757,10 +1028,11 @@ fn spawn_rig_audio_stack(
         let ft4_sr = rig_cfg.audio.sample_rate;
         let ft4_ch = rig_cfg.audio.channels;
         let ft4_shutdown_rx = shutdown_rx.clone();
+        let ft4_lag = metrics.decoder_lag("ft4");
         let ft4_histories = histories.clone();
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_ft4_decoder(ft4_sr, ft4_ch as u16, ft4_pcm_rx, ft4_state_rx, ft4_decode_tx, ft4_histories) => {}
+                _ = audio::run_ft4_decoder(ft4_sr, ft4_ch as u16, ft4_pcm_rx, ft4_state_rx, ft4_decode_tx, ft4_histories, ft4_lag) => {}
                 _ = wait_for_shutdown(ft4_shutdown_rx) => {}
             }
         }));
@@ -774,10 +1046,11 @@ fn spawn_rig_audio_stack(
             let ft2_sr = rig_cfg.audio.sample_rate;
             let ft2_ch = rig_cfg.audio.channels;
             let ft2_shutdown_rx = shutdown_rx.clone();
+            let ft2_lag = metrics.decoder_lag("ft2");
             let ft2_histories = histories.clone();
             handles.push(tokio::spawn(async move {
                 tokio::select! {
-                    _ = audio::run_ft2_decoder(ft2_sr, ft2_ch as u16, ft2_pcm_rx, ft2_state_rx, ft2_decode_tx, ft2_histories) => {}
+                    _ = audio::run_ft2_decoder(ft2_sr, ft2_ch as u16, ft2_pcm_rx, ft2_state_rx, ft2_decode_tx, ft2_histories, ft2_lag) => {}
                     _ = wait_for_shutdown(ft2_shutdown_rx) => {}
                 }
             }));
@@ -790,11 +1063,12 @@ fn spawn_rig_audio_stack(
         let wspr_sr = rig_cfg.audio.sample_rate;
         let wspr_ch = rig_cfg.audio.channels;
         let wspr_shutdown_rx = shutdown_rx.clone();
+        let wspr_lag = metrics.decoder_lag("wspr");
         let wspr_logs = decoder_logs.clone();
         let wspr_histories = histories.clone();
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_wspr_decoder(wspr_sr, wspr_ch as u16, wspr_pcm_rx, wspr_state_rx, wspr_decode_tx, wspr_logs, wspr_histories) => {}
+                _ = audio::run_wspr_decoder(wspr_sr, wspr_ch as u16, wspr_pcm_rx, wspr_state_rx, wspr_decode_tx, wspr_logs, wspr_histories, wspr_lag) => {}
                 _ = wait_for_shutdown(wspr_shutdown_rx) => {}
             }
         }));
@@ -806,6 +1080,7 @@ fn spawn_rig_audio_stack(
         let lrpt_sr = rig_cfg.audio.sample_rate;
         let lrpt_ch = rig_cfg.audio.channels;
         let lrpt_shutdown_rx = shutdown_rx.clone();
+        let lrpt_lag = metrics.decoder_lag("lrpt");
         let lrpt_histories = histories.clone();
         let lrpt_output_dir = dirs::cache_dir()
             .unwrap_or_else(|| std::path::PathBuf::from(".cache"))
@@ -813,7 +1088,7 @@ fn spawn_rig_audio_stack(
             .join("lrpt");
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_lrpt_decoder(lrpt_sr, lrpt_ch as u16, lrpt_pcm_rx, lrpt_state_rx, lrpt_decode_tx, lrpt_histories, lrpt_output_dir) => {}
+                _ = audio::run_lrpt_decoder(lrpt_sr, lrpt_ch as u16, lrpt_pcm_rx, lrpt_state_rx, lrpt_decode_tx, lrpt_histories, lrpt_output_dir, lrpt_lag) => {}
                 _ = wait_for_shutdown(lrpt_shutdown_rx) => {}
             }
         }));
@@ -825,10 +1100,11 @@ fn spawn_rig_audio_stack(
         let wefax_sr = rig_cfg.audio.sample_rate;
         let wefax_ch = rig_cfg.audio.channels;
         let wefax_shutdown_rx = shutdown_rx.clone();
+        let wefax_lag = metrics.decoder_lag("wefax");
         let wefax_histories = histories.clone();
         handles.push(tokio::spawn(async move {
             tokio::select! {
-                _ = audio::run_wefax_decoder(wefax_sr, wefax_ch as u16, wefax_pcm_rx, wefax_state_rx, wefax_decode_tx, wefax_histories) => {}
+                _ = audio::run_wefax_decoder(wefax_sr, wefax_ch as u16, wefax_pcm_rx, wefax_state_rx, wefax_decode_tx, wefax_histories, wefax_lag) => {}
                 _ = wait_for_shutdown(wefax_shutdown_rx) => {}
             }
         }));
@@ -837,10 +1113,28 @@ fn spawn_rig_audio_stack(
     if rig_cfg.audio.tx_enabled {
         let _playback_thread =
             audio::spawn_audio_playback(&rig_cfg.audio, tx_audio_rx, shutdown_rx.clone());
+
+        if let Some(cw_tone_rx) = cw_tone_rx {
+            let keyer_audio_tx = tx_audio_tx.clone();
+            let keyer_shutdown_rx = shutdown_rx.clone();
+            let (sample_rate, channels, frame_ms, bitrate) = (
+                rig_cfg.audio.sample_rate,
+                rig_cfg.audio.channels,
+                rig_cfg.audio.frame_duration_ms,
+                rig_cfg.audio.bitrate_bps,
+            );
+            handles.push(tokio::spawn(async move {
+                tokio::select! {
+                    _ = cw_keyer::run_tone_keyer(sample_rate, channels, frame_ms, bitrate, cw_tone_rx, keyer_audio_tx) => {}
+                    _ = wait_for_shutdown(keyer_shutdown_rx) => {}
+                }
+            }));
+        }
     }
 
     let audio_shutdown_rx = shutdown_rx.clone();
     let audio_histories = histories;
+    let audio_pocsag_cfg = rig_cfg.pocsag.clone();
     handles.push(tokio::spawn(async move {
         if let Err(e) = audio::run_audio_listener(
             audio_listen,
@@ -851,6 +1145,10 @@ fn spawn_rig_audio_stack(
             audio_shutdown_rx,
             audio_histories,
             vchan_manager,
+            audio_pocsag_cfg,
+            audio_tls,
+            metrics,
+            mux_audio_rx,
         )
         .await
         {
@@ -988,7 +1286,9 @@ async fn main() -> DynResult<()> {
         .map(|r| r.id.clone())
         .unwrap_or_else(|| "default".to_string());
 
+    let metrics_registry = Registry::new();
     let mut rig_handles: HashMap<String, RigHandle> = HashMap::new();
+    let mut mqtt_rigs: Vec<trx_reporting::mqtt::MqttRig> = Vec::new();
 
     for rig_cfg in &resolved_rigs {
         let rig_model = normalize_name(rig_cfg.rig.model.as_deref().unwrap_or(""));
@@ -1016,31 +1316,58 @@ async fn main() -> DynResult<()> {
             }
         }
 
+        let rig_metrics = Arc::new(RigMetrics::new(metrics_registry.clone(), &rig_cfg.id));
+
         // Build SDR rig when applicable.
         #[cfg(feature = "soapysdr")]
         let mut sdr_vchan_manager: Option<trx_core::vchan::SharedVChanManager> = None;
         #[cfg(feature = "soapysdr")]
-        let (sdr_prebuilt_rig, sdr_pcm_rx, sdr_ais_pcm_rx, sdr_vdes_iq_rx): (
+        let (
+            sdr_prebuilt_rig,
+            sdr_pcm_rx,
+            sdr_ais_pcm_rx,
+            sdr_vdes_iq_rx,
+            sdr_adsb_iq_rx,
+            sdr_skimmer_iq_rx,
+        ): (
             OptionalSdrRig,
             OptionalSdrPcmRx,
             OptionalSdrAisPcmRx,
             OptionalSdrVdesIqRx,
+            OptionalSdrAdsbIqRx,
+            OptionalSdrSkimmerIqRx,
         ) = if rig_cfg.rig.access.access_type.as_deref() == Some("sdr") {
-            let (rig, pcm_rx, ais_pcm_rx, vdes_iq_rx, vchan_mgr) =
-                build_sdr_rig_from_instance(rig_cfg)?;
+            let (rig, pcm_rx, ais_pcm_rx, vdes_iq_rx, adsb_iq_rx, skimmer_iq_rx, vchan_mgr) =
+                build_sdr_rig_from_instance(rig_cfg, rig_metrics.clone())?;
             sdr_vchan_manager = Some(vchan_mgr);
-            (Some(rig), Some(pcm_rx), Some(ais_pcm_rx), Some(vdes_iq_rx))
+            (
+                Some(rig),
+                Some(pcm_rx),
+                Some(ais_pcm_rx),
+                Some(vdes_iq_rx),
+                adsb_iq_rx,
+                skimmer_iq_rx,
+            )
         } else {
-            (None, None, None, None)
+            (None, None, None, None, None, None)
         };
 
         #[cfg(not(feature = "soapysdr"))]
-        let (sdr_prebuilt_rig, sdr_pcm_rx, sdr_ais_pcm_rx, sdr_vdes_iq_rx): (
+        let (
+            sdr_prebuilt_rig,
+            sdr_pcm_rx,
+            sdr_ais_pcm_rx,
+            sdr_vdes_iq_rx,
+            sdr_adsb_iq_rx,
+            sdr_skimmer_iq_rx,
+        ): (
             OptionalSdrRig,
             OptionalSdrPcmRx,
             OptionalSdrAisPcmRx,
             OptionalSdrVdesIqRx,
-        ) = (None, None, None, None);
+            OptionalSdrAdsbIqRx,
+            OptionalSdrSkimmerIqRx,
+        ) = (None, None, None, None, None, None);
 
         let histories = DecoderHistories::new();
         if let Ok(db_guard) = history_db.lock() {
@@ -1088,7 +1415,19 @@ async fn main() -> DynResult<()> {
             Arc::clone(&registry),
             histories.clone(),
             &cfg.timeouts,
+            rig_metrics.clone(),
         );
+        // Tone keying feeds the TX audio path, so it needs audio TX.
+        let cw_tone_rx = if task_config.cw_keyer.is_some()
+            && rig_cfg.audio.enabled
+            && rig_cfg.audio.tx_enabled
+        {
+            let (cw_tone_tx, cw_tone_rx) = mpsc::channel::<cw_keyer::CwToneCommand>(4);
+            task_config.cw_tone_tx = Some(cw_tone_tx);
+            Some(cw_tone_rx)
+        } else {
+            None
+        };
         if let Some(prebuilt) = sdr_prebuilt_rig {
             task_config.prebuilt_rig = Some(prebuilt);
             // SDR signal strength is a pre-computed field read — no serial
@@ -1137,6 +1476,28 @@ async fn main() -> DynResult<()> {
         #[cfg(not(feature = "soapysdr"))]
         let audio_vchan_manager: Option<trx_core::vchan::SharedVChanManager> = None;
 
+        let audio_tls = if rig_cfg.audio.enabled {
+            rig_cfg
+                .audio
+                .tls
+                .acceptor()
+                .map_err(|e| format!("[audio.tls] for rig '{}': {}", rig_cfg.id, e))?
+        } else {
+            None
+        };
+        // Decoded messages broadcast
+        let (decode_tx, _) = broadcast::channel::<trx_core::decode::DecodedMessage>(256);
+        if cfg.mqtt.enabled {
+            mqtt_rigs.push(trx_reporting::mqtt::MqttRig {
+                rig_id: rig_cfg.id.clone(),
+                display_name: rig_cfg.display_name().to_string(),
+                state_rx: state_rx.clone(),
+                meter_rx: meter_tx.subscribe(),
+                decode_rx: decode_tx.subscribe(),
+                rig_tx: rig_tx.clone(),
+            });
+        }
+        let (mux_audio_tx, mux_audio_rx) = mpsc::channel(MUX_AUDIO_BACKLOG);
         let audio_handles = spawn_rig_audio_stack(
             rig_cfg,
             state_rx.clone(),
@@ -1149,7 +1510,14 @@ async fn main() -> DynResult<()> {
             sdr_pcm_rx,
             sdr_ais_pcm_rx,
             sdr_vdes_iq_rx,
+            sdr_adsb_iq_rx,
+            sdr_skimmer_iq_rx,
             audio_vchan_manager,
+            cw_tone_rx,
+            audio_tls,
+            decode_tx,
+            rig_metrics.clone(),
+            mux_audio_rx,
         );
         task_handles.extend(audio_handles);
 
@@ -1162,46 +1530,127 @@ async fn main() -> DynResult<()> {
                 state_rx,
                 audio_port: rig_cfg.audio.port,
                 meter_tx,
+                metrics: rig_metrics,
+                audio_streams: rig_cfg.audio.enabled.then_some(mux_audio_tx),
             },
         );
     }
 
+    // One MQTT connection publishes every rig; commands use the control
+    // port's tokens.
+    if cfg.mqtt.enabled {
+        let mqtt_cfg = cfg.mqtt.clone();
+        let mqtt_auth = Arc::new(cfg.listen.auth.validator()?);
+        let mqtt_shutdown_rx = shutdown_rx.clone();
+        task_handles.push(tokio::spawn(async move {
+            tokio::select! {
+                _ = trx_reporting::mqtt::run_mqtt_uplink(mqtt_cfg, mqtt_rigs, mqtt_auth) => {}
+                _ = wait_for_shutdown(mqtt_shutdown_rx) => {}
+            }
+        }));
+    }
+
+    if cfg.metrics.enabled {
+        let metrics_addr = SocketAddr::from((cfg.metrics.listen, cfg.metrics.port));
+        let registry = metrics_registry.clone();
+        let metrics_shutdown_rx = shutdown_rx.clone();
+        task_handles.push(tokio::spawn(async move {
+            tokio::select! {
+                result = trx_reporting::metrics::run_metrics_server(metrics_addr, registry) => {
+                    if let Err(e) = result {
+                        error!("Metrics endpoint error: {:?}", e);
+                    }
+                }
+                _ = wait_for_shutdown(metrics_shutdown_rx) => {}
+            }
+        }));
+    }
+
     // Spawn periodic flush of decode history to disk (every 60 s).
     history_store::spawn_flush_task(history_db, rig_histories_for_flush);
 
-    // Start periodic TLE refresh from CelesTrak (on start + once/day).
+    // Load TLEs from the saved catalogue and local files, then refresh from
+    // CelesTrak and configured URLs periodically.
     // Called once globally rather than per-rig to avoid redundant HTTP fetches
     // and write-lock contention on the TLE store.
-    trx_core::geo::spawn_tle_refresh_task();
+    trx_core::geo::spawn_tle_refresh_task(trx_core::geo::TleOptions {
+        celestrak: cfg.tle.celestrak,
+        urls: cfg.tle.urls.clone(),
+        paths: cfg.tle.files.clone(),
+        cache_path: cfg.tle.persist.then(|| {
+            dirs::cache_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("trx-rs")
+                .join("tle.json")
+        }),
+        refresh_interval: Duration::from_secs(cfg.tle.refresh_hours * 3600),
+    });
+
+    let auth = cfg.listen.auth.validator()?;
+    let rigs_arc = Arc::new(rig_handles);
+    let listener_timeouts = listener::ListenerTimeouts {
+        io_timeout: Duration::from_millis(cfg.timeouts.io_timeout_ms),
+        request_timeout: Duration::from_millis(cfg.timeouts.request_timeout_ms),
+    };
+    let tle_max_age_days = cfg.tle.max_age_days;
+    let station_coords = latitude.zip(longitude);
+
+    // Dial out to a trx-client that cannot reach this server.
+    if cfg.uplink.enabled {
+        let endpoint = cfg
+            .uplink
+            .endpoint()
+            .map_err(|e| format!("[uplink]: {}", e))?;
+        let mut uplink_tls = cfg.uplink.tls.clone();
+        uplink_tls.enabled = endpoint.tls;
+        let connector = uplink_tls
+            .connector(&endpoint.host)
+            .map_err(|e| format!("[uplink.tls]: {}", e))?;
+        let ctx = listener::ClientContext::new(
+            rigs_arc.clone(),
+            default_rig_id.clone(),
+            Arc::new(auth.clone()),
+            station_coords,
+            tle_max_age_days,
+            listener_timeouts,
+            None,
+        );
+        task_handles.push(tokio::spawn(uplink::run_uplink(
+            cfg.uplink.clone(),
+            endpoint,
+            connector,
+            ctx,
+            shutdown_rx.clone(),
+        )));
+    }
 
     // Start JSON TCP listener.
     if cfg.listen.enabled {
         let listen_ip = cli.listen.unwrap_or(cfg.listen.listen);
         let listen_port = cli.port.unwrap_or(cfg.listen.port);
         let listen_addr = SocketAddr::from((listen_ip, listen_port));
-        let auth_tokens: HashSet<String> = cfg
-            .listen
-            .auth
-            .tokens
-            .iter()
-            .filter(|t| !t.is_empty())
-            .cloned()
-            .collect();
-        let rigs_arc = Arc::new(rig_handles);
         let listener_shutdown_rx = shutdown_rx.clone();
-        let listener_timeouts = listener::ListenerTimeouts {
-            io_timeout: Duration::from_millis(cfg.timeouts.io_timeout_ms),
-            request_timeout: Duration::from_millis(cfg.timeouts.request_timeout_ms),
-        };
+        let listen_tls = cfg
+            .listen
+            .tls
+            .acceptor()
+            .map_err(|e| format!("[listen.tls]: {}", e))?;
+        let control_clients = metrics_registry.gauge(
+            "trx_connected_clients",
+            "Clients currently connected, by link.",
+            &[("link", "control")],
+        );
         task_handles.push(tokio::spawn(async move {
-            let station_coords = latitude.zip(longitude);
             if let Err(e) = listener::run_listener(
                 listen_addr,
                 rigs_arc,
                 default_rig_id,
-                auth_tokens,
+                auth,
                 station_coords,
+                tle_max_age_days,
                 listener_timeouts,
+                listen_tls,
+                control_clients,
                 listener_shutdown_rx,
             )
             .await
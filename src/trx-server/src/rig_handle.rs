@@ -4,8 +4,13 @@
 
 //! Thin handle giving the listener access to one rig's task and state.
 
+use std::net::SocketAddr;
+use std::sync::Arc;
+
 use tokio::sync::{broadcast, mpsc, watch};
 
+use trx_app::tls::TransportStream;
+use trx_core::metrics::RigMetrics;
 use trx_core::rig::request::RigRequest;
 use trx_core::rig::state::RigState;
 use trx_protocol::MeterUpdate;
@@ -34,4 +39,19 @@ pub struct RigHandle {
     /// ~6–7 Hz (CAT).  Consumed by `SubscribeMeter` clients; independent of
     /// the slower `state_rx` snapshot path.
     pub meter_tx: broadcast::Sender<MeterUpdate>,
+    /// Health counters for this rig, shared with its tasks.
+    pub metrics: Arc<RigMetrics>,
+    /// Hands audio streams of multiplexed connections to the rig's audio
+    /// listener; `None` when audio is disabled.
+    pub audio_streams: Option<mpsc::Sender<MuxAudioStream>>,
+}
+
+/// An audio stream of a multiplexed connection, authorized for at least
+/// read scope on its rig.
+pub struct MuxAudioStream {
+    pub stream: TransportStream,
+    pub peer: SocketAddr,
+    /// Whether the session token holds `tx` scope; TX audio frames on the
+    /// stream are dropped without it.
+    pub tx_allowed: bool,
 }
@@ -12,6 +12,10 @@ use tokio::time::{self, Instant};
 use tracing::{debug, error, info, warn};
 
 use trx_backend::{RegistrationContext, RigAccess};
+use trx_core::doppler::{self, SatTrackingState};
+use trx_core::geo;
+use trx_core::keyer::{self, CwKeyerOutput, CwKeyerState, MacroContext};
+use trx_core::metrics::{Counter, RigMetrics};
 use trx_core::radio::freq::Freq;
 use trx_core::rig::command::RigCommand;
 use trx_core::rig::controller::{
@@ -19,19 +23,36 @@ use trx_core::rig::controller::{
     PollingPolicy, ReadyStateData, RetryPolicy, RigCatExecutor, RigEventEmitter, RigMachineState,
     RigStateMachine, TransmittingStateData, ValidationResult,
 };
+use trx_core::rig::memory::MEMORY_PAGE_SIZE;
+use trx_core::rig::repeater::{ctcss_tone_index, dcs_code_index};
 use trx_core::rig::request::RigRequest;
 use trx_core::rig::state::{RigMode, RigSnapshot, RigState};
 use trx_core::rig::{RigCat, RigRxStatus, RigTxStatus};
+use trx_core::rotator::{self, PassPlan, Rotator, RotatorPosition, RotatorState};
 use trx_core::{DynResult, RigError, RigResult};
 use trx_protocol::MeterUpdate;
 
 use crate::audio::DecoderHistories;
+use crate::cw_keyer::CwToneCommand;
 use crate::error::is_invalid_bcd_error;
 
 /// Fallback poll refresh timeout used when no config value is provided.
 const DEFAULT_POLL_REFRESH_TIMEOUT: Duration = Duration::from_secs(8);
 /// Fallback command execution timeout used when no config value is provided.
 const DEFAULT_COMMAND_EXEC_TIMEOUT: Duration = Duration::from_secs(10);
+/// How often satellite Doppler tracking recomputes the corrected frequencies.
+const SAT_TRACK_INTERVAL: Duration = Duration::from_secs(1);
+/// A rig frequency further than this from where tracking left it is taken
+/// as a manual retune within the passband.
+const SAT_MANUAL_TUNE_TOLERANCE_HZ: u64 = 25;
+/// How often the rotator position is read and tracking targets updated.
+const ROTATOR_INTERVAL: Duration = Duration::from_secs(1);
+/// How far ahead to look for the next pass when tracking with the rotator.
+const ROTATOR_PLAN_HORIZON_MS: i64 = 2 * 60 * 60 * 1000;
+/// Sample spacing of the predicted pass track.
+const ROTATOR_PLAN_STEP_MS: i64 = 20_000;
+/// Retry interval while no pass is predicted within the horizon.
+const ROTATOR_REPLAN_INTERVAL_MS: i64 = 60_000;
 /// Configuration for the rig task.
 pub struct RigTaskConfig {
     pub registry: Arc<RegistrationContext>,
@@ -60,10 +81,24 @@ pub struct RigTaskConfig {
     /// `SoapySdrRig` (built with channel config) without duplicating the
     /// pipeline construction.
     pub prebuilt_rig: Option<Box<dyn RigCat>>,
+    /// Antenna rotator driven alongside the rig.
+    pub rotator: Option<Box<dyn Rotator>>,
+    /// Minimum pointing change before a tracking update is sent.
+    pub rotator_step_deg: f64,
+    /// CW keyer settings and memories; `None` when the keyer is disabled.
+    pub cw_keyer: Option<CwKeyerState>,
+    /// PTT lead-in before the first element when tone keying.
+    pub cw_ptt_lead: Duration,
+    /// PTT hold time after the last element.
+    pub cw_ptt_tail: Duration,
+    /// Tone keyer on the TX audio path, when audio TX is available.
+    pub cw_tone_tx: Option<mpsc::Sender<CwToneCommand>>,
     /// Maximum time to wait for a single rig command to complete.
     pub command_exec_timeout: Duration,
     /// Maximum time for a CAT poll refresh cycle.
     pub poll_refresh_timeout: Duration,
+    /// CAT latency and retry metrics for this rig.
+    pub metrics: Arc<RigMetrics>,
 }
 
 impl Default for RigTaskConfig {
@@ -92,12 +127,25 @@ impl Default for RigTaskConfig {
             histories: DecoderHistories::new(),
             vfo_prime: true,
             prebuilt_rig: None,
+            rotator: None,
+            rotator_step_deg: 2.0,
+            cw_keyer: None,
+            cw_ptt_lead: Duration::from_millis(50),
+            cw_ptt_tail: Duration::from_millis(150),
+            cw_tone_tx: None,
             command_exec_timeout: DEFAULT_COMMAND_EXEC_TIMEOUT,
             poll_refresh_timeout: DEFAULT_POLL_REFRESH_TIMEOUT,
+            metrics: Arc::default(),
         }
     }
 }
 
+/// CAT read retry policy together with the counter each retry is recorded in.
+struct CatRetry {
+    backoff: ExponentialBackoff,
+    retries: Arc<Counter>,
+}
+
 /// Command context implementation for validation.
 struct TaskCommandContext<'a> {
     machine: &'a RigStateMachine,
@@ -118,6 +166,17 @@ pub async fn run_rig_task(
     mut shutdown_rx: watch::Receiver<bool>,
 ) -> DynResult<()> {
     let histories = config.histories.clone();
+    let mut rotator = config.rotator.map(|rotator| RotatorControl {
+        rotator,
+        step_deg: config.rotator_step_deg,
+        track: None,
+    });
+    let mut cw_keyer = config.cw_keyer.is_some().then(|| CwKeyerControl {
+        tone_tx: config.cw_tone_tx.clone(),
+        ptt_lead: config.cw_ptt_lead,
+        ptt_tail: config.cw_ptt_tail,
+        job: None,
+    });
     info!(
         "[{}] Opening rig backend {}",
         config.rig_id, config.rig_model
@@ -152,6 +211,15 @@ pub async fn run_rig_task(
     );
     state.pskreporter_status = config.pskreporter_status.clone();
     state.aprs_is_status = config.aprs_is_status.clone();
+    state.rotator = rotator.as_ref().map(|ctl| RotatorState {
+        info: ctl.rotator.info().clone(),
+        position: None,
+        target: None,
+        tracking: None,
+        tracking_norad_id: None,
+        error: None,
+    });
+    state.cw_keyer = config.cw_keyer.clone();
 
     // Timeout configuration
     let command_exec_timeout = config.command_exec_timeout;
@@ -159,7 +227,11 @@ pub async fn run_rig_task(
 
     // Polling configuration
     let polling = &config.polling;
-    let retry = &config.retry;
+    let metrics = config.metrics.clone();
+    let retry = &CatRetry {
+        backoff: config.retry.clone(),
+        retries: metrics.cat_retries.clone(),
+    };
     let mut poll_pause_until: Option<Instant> = None;
     let mut last_power_on: Option<Instant> = None;
     let mut initial_status_read = false;
@@ -278,6 +350,10 @@ pub async fn run_rig_task(
     let rig_id = config.rig_id.clone();
     let mut meter_tick: std::pin::Pin<Box<tokio::time::Sleep>> =
         Box::pin(tokio::time::sleep(meter_tick_duration));
+    let mut sat_tick = time::interval(SAT_TRACK_INTERVAL);
+    sat_tick.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+    let mut rotator_tick = time::interval(ROTATOR_INTERVAL);
+    rotator_tick.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
 
     // Main task loop
     let mut current_poll_duration = polling.interval(state.status.tx_en);
@@ -346,6 +422,50 @@ pub async fn run_rig_task(
                     }
                 }
             }
+            // Doppler retune while a satellite is being tracked.
+            _ = sat_tick.tick(), if state.sat_tracking.is_some() => {
+                if matches!(state.control.enabled, Some(false)) || state.status.tx_en {
+                    continue;
+                }
+                match track_satellite(&mut rig, &mut state).await {
+                    Ok(retuned) => {
+                        if retuned {
+                            poll_pause_until = Some(Instant::now() + Duration::from_millis(200));
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Stopping satellite tracking: {}", e);
+                        stop_sat_tracking(&mut rig, &mut state).await;
+                    }
+                }
+                let _ = state_tx.send(state.clone());
+            }
+            // Rotator position read-back and pass tracking.
+            _ = rotator_tick.tick(), if rotator.is_some() => {
+                let Some(ctl) = rotator.as_mut() else { continue; };
+                let before = state.rotator.clone();
+                step_rotator(ctl, &mut state).await;
+                if state.rotator != before {
+                    let _ = state_tx.send(state.clone());
+                }
+            }
+            // End of a keyed CW message: release PTT after the tail.
+            _ = time::sleep_until(cw_job_end(&cw_keyer)), if cw_job_active(&cw_keyer) => {
+                let Some(ctl) = cw_keyer.as_mut() else { continue; };
+                let old_state = state.clone();
+                finish_cw(ctl, &mut rig, &mut state).await;
+                let old_machine_state = machine.state().clone();
+                sync_machine_state(&mut machine, &state);
+                let new_machine_state = machine.state().clone();
+                emit_state_changes(
+                    &emitter,
+                    &old_state,
+                    &state,
+                    &old_machine_state,
+                    &new_machine_state,
+                );
+                let _ = state_tx.send(state.clone());
+            }
             _ = &mut poll_sleep => {
                 poll_sleep = Box::pin(tokio::time::sleep(current_poll_duration));
                 // Check if polling is paused
@@ -364,12 +484,14 @@ pub async fn run_rig_task(
 
                 // Poll rig state
                 let old_state = state.clone();
-                match time::timeout(
+                let poll_started = Instant::now();
+                let polled = time::timeout(
                     poll_refresh_timeout,
                     refresh_state_with_retry(&mut rig, &mut state, retry),
                 )
-                .await
-                {
+                .await;
+                metrics.cat_poll_seconds.observe(poll_started.elapsed());
+                match polled {
                     Ok(Ok(())) => {
                         let old_machine_state = machine.state().clone();
                         sync_machine_state(&mut machine, &state);
@@ -435,6 +557,8 @@ pub async fn run_rig_task(
                             state_tx: &state_tx,
                             retry,
                             histories: &histories,
+                            rotator: &mut rotator,
+                            cw_keyer: &mut cw_keyer,
                         };
                         let result = match time::timeout(
                             command_exec_timeout,
@@ -475,6 +599,8 @@ pub async fn run_rig_task(
                         state_tx: &state_tx,
                         retry,
                         histories: &histories,
+                        rotator: &mut rotator,
+                        cw_keyer: &mut cw_keyer,
                     };
                     let result =
                         match time::timeout(command_exec_timeout, process_command(cmd, &mut cmd_ctx))
@@ -497,6 +623,7 @@ pub async fn run_rig_task(
 
                     if log_command {
                         let elapsed = started.elapsed();
+                        metrics.cat_command_seconds.observe(elapsed);
                         if elapsed > Duration::from_millis(500) {
                             warn!("Rig command {} took {:?}", cmd_label, elapsed);
                         } else {
@@ -521,8 +648,10 @@ struct CommandExecContext<'a> {
     poll_pause_until: &'a mut Option<Instant>,
     last_power_on: &'a mut Option<Instant>,
     state_tx: &'a watch::Sender<RigState>,
-    retry: &'a ExponentialBackoff,
+    retry: &'a CatRetry,
     histories: &'a Arc<DecoderHistories>,
+    rotator: &'a mut Option<RotatorControl>,
+    cw_keyer: &'a mut Option<CwKeyerControl>,
 }
 
 async fn process_command(
@@ -556,6 +685,385 @@ async fn process_command(
             let _ = ctx.state_tx.send(ctx.state.clone());
             return snapshot_from(ctx.state);
         }
+        RigCommand::SetPskDecodeEnabled(en) => {
+            ctx.state.decoders.psk_decode_enabled = en;
+            info!("PSK decode {}", if en { "enabled" } else { "disabled" });
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetPskMode(mode) => {
+            ctx.state.psk_mode = mode;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetPskFreqHz(freq_hz) => {
+            ctx.state.psk_freq_hz = freq_hz.clamp(100, 4_000);
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetPskWaterfall(en) => {
+            ctx.state.psk_waterfall = en;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetDscDecodeEnabled(en) => {
+            ctx.state.decoders.dsc_decode_enabled = en;
+            info!("DSC decode {}", if en { "enabled" } else { "disabled" });
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetPocsagDecodeEnabled(en) => {
+            ctx.state.decoders.pocsag_decode_enabled = en;
+            info!("POCSAG decode {}", if en { "enabled" } else { "disabled" });
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetAdsbDecodeEnabled(en) => {
+            ctx.state.decoders.adsb_decode_enabled = en;
+            info!("ADS-B decode {}", if en { "enabled" } else { "disabled" });
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetAcarsDecodeEnabled(en) => {
+            ctx.state.decoders.acars_decode_enabled = en;
+            info!("ACARS decode {}", if en { "enabled" } else { "disabled" });
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::StartSatTracking {
+            norad_id,
+            transponder,
+            downlink_hz,
+        } => {
+            let Some(tp) = doppler::find_transponder(norad_id, transponder.as_deref()) else {
+                return Err(RigError::invalid_state(format!(
+                    "no transponder data for NORAD {norad_id}"
+                )));
+            };
+            if ctx.state.sat_tracking.is_some() {
+                stop_sat_tracking(ctx.rig, ctx.state).await;
+            }
+            if ctx.state.status.mode != tp.mode {
+                if let Err(e) = ctx.rig.set_mode(tp.mode.clone()).await {
+                    return Err(RigError::communication(format!("set_mode: {e}")));
+                }
+                ctx.state.apply_mode(tp.mode.clone());
+            }
+            let downlink_hz = tp
+                .downlink
+                .clamp(downlink_hz.unwrap_or_else(|| tp.downlink.center_hz()));
+            ctx.state.sat_tracking = Some(SatTrackingState {
+                norad_id,
+                satellite: tp.satellite.clone(),
+                transponder: tp.name.clone(),
+                downlink_hz,
+                uplink_hz: tp.uplink_for(downlink_hz),
+                rx_hz: 0,
+                tx_hz: None,
+                split_active: false,
+                elevation_deg: 0.0,
+                azimuth_deg: 0.0,
+                range_km: 0.0,
+                range_rate_km_s: 0.0,
+            });
+            if let Err(e) = track_satellite(ctx.rig, ctx.state).await {
+                stop_sat_tracking(ctx.rig, ctx.state).await;
+                return Err(RigError::invalid_state(e));
+            }
+            info!("Tracking {} {}", tp.satellite, tp.name);
+            *ctx.poll_pause_until = Some(Instant::now() + Duration::from_millis(200));
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::StopSatTracking => {
+            if ctx.state.sat_tracking.is_some() {
+                stop_sat_tracking(ctx.rig, ctx.state).await;
+                info!("Satellite tracking stopped");
+            }
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetRotatorPosition {
+            azimuth_deg,
+            elevation_deg,
+        } => {
+            let Some(ctl) = ctx.rotator.as_mut() else {
+                return Err(RigError::not_supported("rotator"));
+            };
+            let target = rotator::clamp_position(
+                ctl.rotator.info(),
+                RotatorPosition {
+                    azimuth_deg,
+                    elevation_deg,
+                },
+            );
+            ctl.track = None;
+            if let Err(e) = ctl.rotator.set_position(target).await {
+                return Err(RigError::communication(format!("rotator: {e}")));
+            }
+            if let Some(rs) = ctx.state.rotator.as_mut() {
+                rs.target = Some(target);
+                rs.tracking = None;
+                rs.tracking_norad_id = None;
+            }
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::StopRotator => {
+            let Some(ctl) = ctx.rotator.as_mut() else {
+                return Err(RigError::not_supported("rotator"));
+            };
+            ctl.track = None;
+            if let Err(e) = ctl.rotator.stop().await {
+                return Err(RigError::communication(format!("rotator: {e}")));
+            }
+            if let Some(rs) = ctx.state.rotator.as_mut() {
+                rs.target = None;
+                rs.tracking = None;
+                rs.tracking_norad_id = None;
+            }
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::StartRotatorTracking { norad_id } => {
+            let Some(ctl) = ctx.rotator.as_mut() else {
+                return Err(RigError::not_supported("rotator"));
+            };
+            let (Some(lat), Some(lon)) = (ctx.state.server_latitude, ctx.state.server_longitude)
+            else {
+                return Err(RigError::invalid_state(
+                    "station latitude/longitude not configured",
+                ));
+            };
+            if geo::look_at(norad_id, lat, lon, now_ms()).is_none() {
+                return Err(RigError::invalid_state(format!(
+                    "no TLE for NORAD {norad_id}"
+                )));
+            }
+            let name = geo::satellite_name(norad_id).unwrap_or_else(|| format!("NORAD {norad_id}"));
+            info!("Rotator tracking {}", name);
+            ctl.track = Some(RotatorTrack {
+                norad_id,
+                pass: None,
+                replan_at_ms: 0,
+            });
+            if let Some(rs) = ctx.state.rotator.as_mut() {
+                rs.tracking = Some(name);
+                rs.tracking_norad_id = Some(norad_id);
+                rs.target = None;
+            }
+            step_rotator(ctl, ctx.state).await;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::StopRotatorTracking => {
+            let Some(ctl) = ctx.rotator.as_mut() else {
+                return Err(RigError::not_supported("rotator"));
+            };
+            if ctl.track.take().is_some() {
+                info!("Rotator tracking stopped");
+            }
+            if let Some(rs) = ctx.state.rotator.as_mut() {
+                rs.tracking = None;
+                rs.tracking_norad_id = None;
+            }
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SendCw { text, call } => {
+            let Some(ctl) = ctx.cw_keyer.as_mut() else {
+                return Err(RigError::not_supported("cw keyer"));
+            };
+            let old_state = ctx.state.clone();
+            start_cw(ctl, ctx.rig, ctx.state, &text, call.as_deref()).await?;
+            let old_machine_state = ctx.machine.state().clone();
+            sync_machine_state(ctx.machine, ctx.state);
+            let new_machine_state = ctx.machine.state().clone();
+            emit_state_changes(
+                ctx.emitter,
+                &old_state,
+                ctx.state,
+                &old_machine_state,
+                &new_machine_state,
+            );
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::AbortCw => {
+            let old_state = ctx.state.clone();
+            if let Some(ctl) = ctx.cw_keyer.as_mut() {
+                abort_cw(ctl, ctx.rig).await;
+            }
+            // Drop PTT even without a keyer job: abort is the panic button.
+            if let Err(e) = ctx.rig.set_ptt(false).await {
+                return Err(RigError::communication(format!("set_ptt: {e}")));
+            }
+            ctx.state.apply_ptt(false);
+            if let Some(ks) = ctx.state.cw_keyer.as_mut() {
+                ks.sending = None;
+            }
+            let old_machine_state = ctx.machine.state().clone();
+            sync_machine_state(ctx.machine, ctx.state);
+            let new_machine_state = ctx.machine.state().clone();
+            emit_state_changes(
+                ctx.emitter,
+                &old_state,
+                ctx.state,
+                &old_machine_state,
+                &new_machine_state,
+            );
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetCwKeyer {
+            wpm,
+            weight,
+            farnsworth_wpm,
+        } => {
+            let Some(ks) = ctx.state.cw_keyer.as_mut() else {
+                return Err(RigError::not_supported("cw keyer"));
+            };
+            let wpm = wpm.clamp(5, 60);
+            ks.settings = keyer::KeyerSettings {
+                wpm,
+                weight: weight.clamp(25, 75),
+                farnsworth_wpm: farnsworth_wpm.filter(|&f| (5..wpm).contains(&f)),
+            };
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetCwSerial(serial) => {
+            let Some(ks) = ctx.state.cw_keyer.as_mut() else {
+                return Err(RigError::not_supported("cw keyer"));
+            };
+            ks.serial = serial;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::ReadMemories { first, count } => {
+            let mut memories = Vec::new();
+            for channel in first..first.saturating_add(count.min(MEMORY_PAGE_SIZE)) {
+                match ctx.rig.read_memory(channel).await {
+                    Ok(Some(memory)) => memories.push(memory),
+                    Ok(None) => {}
+                    Err(e) => return Err(backend_error("read_memory", e)),
+                }
+            }
+            ctx.state.memories = memories;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::WriteMemory(memory) => {
+            let channel = memory.channel;
+            if let Err(e) = ctx.rig.write_memory(memory).await {
+                return Err(backend_error("write_memory", e));
+            }
+            info!("Wrote memory channel {}", channel);
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::RecallMemory(channel) => {
+            if let Err(e) = ctx.rig.recall_memory(channel).await {
+                return Err(backend_error("recall_memory", e));
+            }
+            return refresh_after_recall(ctx).await;
+        }
+        RigCommand::RecallBandStack { freq_hz } => {
+            if let Err(e) = ctx.rig.recall_band_stack(Freq { hz: freq_hz }).await {
+                return Err(backend_error("recall_band_stack", e));
+            }
+            return refresh_after_recall(ctx).await;
+        }
+        RigCommand::SetRepeaterOffset(offset_hz) => {
+            let caps = &ctx.rig.info().capabilities;
+            // Receive-only backends have no shift to set; the offset is
+            // still recorded so reverse can listen on the input.
+            if caps.rpt || caps.tx {
+                if let Err(e) = ctx.rig.set_repeater_offset(offset_hz).await {
+                    return Err(backend_error("set_repeater_offset", e));
+                }
+            }
+            ctx.state.control.rpt_offset_hz = Some(offset_hz);
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetRepeaterReverse(reverse) => {
+            if ctx.state.control.rpt_reverse.unwrap_or(false) == reverse {
+                return snapshot_from(ctx.state);
+            }
+            let offset_hz = ctx.state.control.rpt_offset_hz.unwrap_or(0);
+            if offset_hz == 0 {
+                return Err(RigError::invalid_state("reverse needs a repeater offset"));
+            }
+            let prev_freq_hz = ctx.state.status.freq.hz;
+            let freq = Freq {
+                hz: prev_freq_hz.saturating_add_signed(offset_hz as i64),
+            };
+            if let Err(e) = ctx.rig.set_freq(freq).await {
+                return Err(RigError::communication(format!("set_freq: {e}")));
+            }
+            ctx.state.apply_freq(freq);
+            invalidate_main_decoder_windows_on_freq_change(ctx.state, prev_freq_hz);
+            if ctx.rig.info().capabilities.rpt {
+                if let Err(e) = ctx.rig.set_repeater_offset(-offset_hz).await {
+                    return Err(backend_error("set_repeater_offset", e));
+                }
+            }
+            ctx.state.control.rpt_offset_hz = Some(-offset_hz);
+            ctx.state.control.rpt_reverse = Some(reverse);
+            *ctx.poll_pause_until = Some(Instant::now() + Duration::from_millis(200));
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetCtcss { tone_hz, decode } => {
+            if let Some(hz) = tone_hz {
+                if ctcss_tone_index(hz).is_none() {
+                    return Err(RigError::invalid_state(format!(
+                        "{hz} Hz is not a standard CTCSS tone"
+                    )));
+                }
+            }
+            if let Err(e) = ctx.rig.set_ctcss(tone_hz, decode).await {
+                return Err(backend_error("set_ctcss", e));
+            }
+            let control = &mut ctx.state.control;
+            control.ctcss_hz = tone_hz;
+            control.ctcss_decode = Some(decode && tone_hz.is_some());
+            if tone_hz.is_some() {
+                control.dcs_code = None;
+            }
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetDcs(code) => {
+            if let Some(code) = code {
+                if dcs_code_index(code).is_none() {
+                    return Err(RigError::invalid_state(format!(
+                        "{code:03} is not a standard DCS code"
+                    )));
+                }
+            }
+            if let Err(e) = ctx.rig.set_dcs(code).await {
+                return Err(backend_error("set_dcs", e));
+            }
+            let control = &mut ctx.state.control;
+            control.dcs_code = code;
+            if code.is_some() {
+                control.ctcss_hz = None;
+                control.ctcss_decode = Some(false);
+            }
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::SetRit(offset_hz) => return apply_clarifier(ctx, offset_hz, false).await,
+        RigCommand::SetXit(offset_hz) => return apply_clarifier(ctx, offset_hz, true).await,
+        RigCommand::LoadTles(tle) => {
+            // The catalogue is shared by all rigs; any rig task can merge it.
+            match geo::load_tle_text(&tle) {
+                Ok(n) => info!("Loaded {} uploaded TLEs", n),
+                Err(e) => return Err(RigError::invalid_state(format!("TLE upload: {e}"))),
+            }
+            return snapshot_from(ctx.state);
+        }
         RigCommand::SetFt8DecodeEnabled(en) => {
             ctx.state.decoders.ft8_decode_enabled = en;
             info!("FT8 decode {}", if en { "enabled" } else { "disabled" });
@@ -603,6 +1111,36 @@ async fn process_command(
             let _ = ctx.state_tx.send(ctx.state.clone());
             return snapshot_from(ctx.state);
         }
+        RigCommand::ResetPskDecoder => {
+            ctx.histories.clear_psk_history();
+            ctx.state.reset_seqs.psk_decode_reset_seq += 1;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::ResetDscDecoder => {
+            ctx.histories.clear_dsc_history();
+            ctx.state.reset_seqs.dsc_decode_reset_seq += 1;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::ResetPocsagDecoder => {
+            ctx.histories.clear_pocsag_history();
+            ctx.state.reset_seqs.pocsag_decode_reset_seq += 1;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::ResetAcarsDecoder => {
+            ctx.histories.clear_acars_history();
+            ctx.state.reset_seqs.acars_decode_reset_seq += 1;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
+        RigCommand::ResetAdsbDecoder => {
+            ctx.histories.clear_adsb_history();
+            ctx.state.reset_seqs.adsb_decode_reset_seq += 1;
+            let _ = ctx.state_tx.send(ctx.state.clone());
+            return snapshot_from(ctx.state);
+        }
         RigCommand::ResetFt8Decoder => {
             ctx.histories.clear_ft8_history();
             ctx.state.reset_seqs.ft8_decode_reset_seq += 1;
@@ -952,22 +1490,78 @@ async fn process_command(
     }
 }
 
+/// Apply a RIT (`xit == false`) or XIT request.  The two share one offset:
+/// a non-zero `offset_hz` replaces it and turns that side on, 0 turns that
+/// side off and keeps the offset for the other.
+async fn apply_clarifier(
+    ctx: &mut CommandExecContext<'_>,
+    offset_hz: i32,
+    xit: bool,
+) -> RigResult<RigSnapshot> {
+    let control = &ctx.state.control;
+    let mut rit_on = control.clar_on.unwrap_or(false);
+    let mut xit_on = control.xit_on.unwrap_or(false);
+    let enable = offset_hz != 0;
+    if xit {
+        xit_on = enable;
+    } else {
+        rit_on = enable;
+    }
+    let offset_hz = if enable {
+        offset_hz
+    } else {
+        control.clar_hz.unwrap_or(0)
+    };
+    if let Err(e) = ctx.rig.set_clarifier(offset_hz, rit_on, xit_on).await {
+        return Err(backend_error("set_clarifier", e));
+    }
+    let control = &mut ctx.state.control;
+    control.clar_hz = Some(offset_hz);
+    control.clar_on = Some(rit_on);
+    control.xit_on = Some(xit_on);
+    let _ = ctx.state_tx.send(ctx.state.clone());
+    snapshot_from(ctx.state)
+}
+
+/// Pass backend `RigError`s (e.g. not supported) through unchanged; anything
+/// else is a CAT failure.
+fn backend_error(op: &str, e: Box<dyn std::error::Error + Send + Sync>) -> RigError {
+    match e.downcast::<RigError>() {
+        Ok(rig_err) => *rig_err,
+        Err(e) => RigError::communication(format!("{op}: {e}")),
+    }
+}
+
+/// Re-read frequency and mode after a memory or band-stack recall moved
+/// the rig somewhere new.
+async fn refresh_after_recall(ctx: &mut CommandExecContext<'_>) -> RigResult<RigSnapshot> {
+    // Give the rig a moment to switch before reading it back.
+    time::sleep(Duration::from_millis(150)).await;
+    if let Err(e) = refresh_state_with_retry(ctx.rig, ctx.state, ctx.retry).await {
+        return Err(RigError::communication(format!("CAT error: {e}")));
+    }
+    *ctx.poll_pause_until = Some(Instant::now() + Duration::from_millis(300));
+    let _ = ctx.state_tx.send(ctx.state.clone());
+    snapshot_from(ctx.state)
+}
+
 /// Refresh state from CAT with retry logic using the retry policy.
 async fn refresh_state_with_retry(
     rig: &mut Box<dyn RigCat>,
     state: &mut RigState,
-    retry: &ExponentialBackoff,
+    retry: &CatRetry,
 ) -> DynResult<()> {
     let mut last_err: Option<Box<dyn std::error::Error + Send + Sync>> = None;
-    let max = retry.max_attempts() as usize;
+    let max = retry.backoff.max_attempts() as usize;
 
     for attempt in 0..max {
         match refresh_state_from_cat(rig, state).await {
             Ok(()) => return Ok(()),
             Err(e) => {
                 let rig_err = RigError::communication(e.to_string());
-                if retry.should_retry(attempt as u32, &rig_err) && attempt + 1 < max {
-                    let delay = retry.delay(attempt as u32);
+                if retry.backoff.should_retry(attempt as u32, &rig_err) && attempt + 1 < max {
+                    let delay = retry.backoff.delay(attempt as u32);
+                    retry.retries.inc();
                     warn!(
                         "Retrying CAT state read (attempt {} of {}, delay {:?})",
                         attempt + 1,
@@ -1042,7 +1636,7 @@ async fn refresh_state_from_cat(rig: &mut Box<dyn RigCat>, state: &mut RigState)
 async fn refresh_after_power_on(
     rig: &mut Box<dyn RigCat>,
     state: &mut RigState,
-    retry: &ExponentialBackoff,
+    retry: &CatRetry,
 ) -> DynResult<()> {
     let mut last_err = String::new();
     for attempt in 1..=3 {
@@ -1080,7 +1674,7 @@ async fn refresh_after_power_on(
 async fn apply_initial_tune(
     rig: &mut Box<dyn RigCat>,
     state: &mut RigState,
-    retry: &ExponentialBackoff,
+    retry: &CatRetry,
     initial_freq_hz: u64,
     initial_mode: &RigMode,
 ) -> DynResult<()> {
@@ -1108,7 +1702,7 @@ async fn apply_initial_tune(
 async fn prime_vfo_state(
     rig: &mut Box<dyn RigCat>,
     state: &mut RigState,
-    retry: &ExponentialBackoff,
+    retry: &CatRetry,
 ) -> DynResult<()> {
     // Ensure panel is unlocked
     let _ = rig.unlock().await;
@@ -1128,6 +1722,388 @@ async fn prime_vfo_state(
     Ok(())
 }
 
+/// Recompute the Doppler shift of the tracked satellite and retune the rig.
+///
+/// Returns whether the receive frequency was changed, or an error when the
+/// satellite can no longer be followed.
+async fn track_satellite(rig: &mut Box<dyn RigCat>, state: &mut RigState) -> Result<bool, String> {
+    let Some(mut tracking) = state.sat_tracking.clone() else {
+        return Ok(false);
+    };
+    let (Some(lat), Some(lon)) = (state.server_latitude, state.server_longitude) else {
+        return Err("station latitude/longitude not configured".to_string());
+    };
+    let Some(tp) = doppler::find_transponder(tracking.norad_id, Some(&tracking.transponder)) else {
+        return Err(format!(
+            "no transponder data for NORAD {}",
+            tracking.norad_id
+        ));
+    };
+    let Some(look) = geo::look_at(tracking.norad_id, lat, lon, now_ms()) else {
+        return Err(format!("no TLE for NORAD {}", tracking.norad_id));
+    };
+
+    // Follow the operator around the passband: when the rig is no longer
+    // where tracking left it, the new frequency becomes the downlink.
+    let caps = state.rig_info.as_ref().map(|info| &info.capabilities);
+    let min_step = caps.map_or(1, |c| c.min_freq_step_hz.max(1));
+    let can_split = caps.is_some_and(|c| c.split);
+    let tolerance = SAT_MANUAL_TUNE_TOLERANCE_HZ.max(2 * min_step);
+    if tracking.rx_hz != 0 && state.status.freq.hz.abs_diff(tracking.rx_hz) > tolerance {
+        tracking.downlink_hz = tp.downlink.clamp(doppler::satellite_downlink_hz(
+            state.status.freq.hz,
+            tracking.range_rate_km_s,
+        ));
+    }
+    tracking.uplink_hz = tp.uplink_for(tracking.downlink_hz);
+
+    // FM channels are wide enough that sub-kHz steps only add CAT traffic.
+    let threshold = match tp.mode {
+        RigMode::FM | RigMode::PKT => 250,
+        _ => 10,
+    }
+    .max(min_step);
+    let rx_hz = doppler::observed_downlink_hz(tracking.downlink_hz, look.range_rate_km_s);
+    let rx_hz = rx_hz - rx_hz % min_step;
+    let mut retuned = false;
+    if tracking.rx_hz == 0 || state.status.freq.hz.abs_diff(rx_hz) >= threshold {
+        rig.set_freq(Freq { hz: rx_hz })
+            .await
+            .map_err(|e| format!("set_freq: {e}"))?;
+        state.apply_freq(Freq { hz: rx_hz });
+        tracking.rx_hz = rx_hz;
+        retuned = true;
+    }
+
+    let tx_hz = tracking
+        .uplink_hz
+        .map(|hz| doppler::transmit_uplink_hz(hz, look.range_rate_km_s));
+    match tx_hz {
+        Some(hz) if can_split => {
+            let hz = hz - hz % min_step;
+            let moved = tracking
+                .tx_hz
+                .is_none_or(|prev| prev.abs_diff(hz) >= threshold);
+            if !tracking.split_active || moved {
+                match rig.set_split_tx_freq(Some(Freq { hz })).await {
+                    Ok(()) => {
+                        tracking.tx_hz = Some(hz);
+                        tracking.split_active = true;
+                        retuned = true;
+                    }
+                    Err(e) => {
+                        if tracking.split_active || tracking.tx_hz.is_none() {
+                            warn!("Split uplink retune failed: {:?}", e);
+                        }
+                        tracking.tx_hz = Some(hz);
+                        tracking.split_active = false;
+                    }
+                }
+            }
+        }
+        _ => tracking.tx_hz = tx_hz,
+    }
+
+    tracking.elevation_deg = look.elevation_deg;
+    tracking.azimuth_deg = look.azimuth_deg;
+    tracking.range_km = look.range_km;
+    tracking.range_rate_km_s = look.range_rate_km_s;
+    state.sat_tracking = Some(tracking);
+    Ok(retuned)
+}
+
+/// Stop Doppler tracking and release the split uplink.
+async fn stop_sat_tracking(rig: &mut Box<dyn RigCat>, state: &mut RigState) {
+    if let Some(tracking) = state.sat_tracking.take() {
+        if tracking.split_active {
+            if let Err(e) = rig.set_split_tx_freq(None).await {
+                warn!("Failed to clear split uplink: {:?}", e);
+            }
+        }
+    }
+}
+
+/// Rotator owned by the rig task.
+struct RotatorControl {
+    rotator: Box<dyn Rotator>,
+    step_deg: f64,
+    track: Option<RotatorTrack>,
+}
+
+/// Satellite followed by the rotator.
+struct RotatorTrack {
+    norad_id: u32,
+    /// Current or next pass; `None` until planned.
+    pass: Option<PlannedPass>,
+    /// Earliest time to look for a pass again after none was found.
+    replan_at_ms: i64,
+}
+
+struct PlannedPass {
+    plan: PassPlan,
+    /// Look angle at acquisition of signal, held until the pass starts.
+    aos: RotatorPosition,
+    aos_ms: i64,
+    los_ms: i64,
+}
+
+/// Predict the current or next visible pass and lay it onto the rotator.
+fn plan_rotator_pass(
+    rotator: &dyn Rotator,
+    norad_id: u32,
+    lat: f64,
+    lon: f64,
+    now_ms: i64,
+) -> Option<PlannedPass> {
+    let mut track: Vec<RotatorPosition> = Vec::new();
+    let mut aos_ms = now_ms;
+    let mut los_ms = now_ms;
+    let mut t = now_ms;
+    while t <= now_ms + ROTATOR_PLAN_HORIZON_MS {
+        let look = geo::look_at(norad_id, lat, lon, t)?;
+        if look.elevation_deg >= 0.0 {
+            if track.is_empty() {
+                aos_ms = t;
+            }
+            los_ms = t;
+            track.push(RotatorPosition {
+                azimuth_deg: look.azimuth_deg,
+                elevation_deg: look.elevation_deg,
+            });
+        } else if !track.is_empty() {
+            los_ms = t;
+            break;
+        }
+        t += ROTATOR_PLAN_STEP_MS;
+    }
+    let aos = *track.first()?;
+    let plan = PassPlan::new(rotator.info(), &track);
+    if !plan.fits {
+        debug!(
+            "Rotator pass of NORAD {} crosses the azimuth stop",
+            norad_id
+        );
+    }
+    Some(PlannedPass {
+        plan,
+        aos,
+        aos_ms,
+        los_ms,
+    })
+}
+
+/// Read back the rotator position and, while tracking, point it at the
+/// satellite.  Errors are recorded in the published rotator state.
+async fn step_rotator(ctl: &mut RotatorControl, state: &mut RigState) {
+    let Some(rs) = state.rotator.as_mut() else {
+        return;
+    };
+    if let (Some(track), Some(lat), Some(lon)) = (
+        ctl.track.as_mut(),
+        state.server_latitude,
+        state.server_longitude,
+    ) {
+        let now = now_ms();
+        if track.pass.as_ref().is_some_and(|p| now > p.los_ms) {
+            track.pass = None;
+        }
+        if track.pass.is_none() && now >= track.replan_at_ms {
+            track.pass = plan_rotator_pass(ctl.rotator.as_ref(), track.norad_id, lat, lon, now);
+            if track.pass.is_none() {
+                track.replan_at_ms = now + ROTATOR_REPLAN_INTERVAL_MS;
+            }
+        }
+        let target = track.pass.as_mut().and_then(|pass| {
+            let look = if now < pass.aos_ms {
+                pass.aos
+            } else {
+                let look = geo::look_at(track.norad_id, lat, lon, now)?;
+                RotatorPosition {
+                    azimuth_deg: look.azimuth_deg,
+                    elevation_deg: look.elevation_deg,
+                }
+            };
+            Some(pass.plan.position_for(look))
+        });
+        if let Some(target) = target {
+            let moved = rs.target.is_none_or(|prev| {
+                (prev.azimuth_deg - target.azimuth_deg).abs() >= ctl.step_deg
+                    || (prev.elevation_deg - target.elevation_deg).abs() >= ctl.step_deg
+            });
+            if moved {
+                match ctl.rotator.set_position(target).await {
+                    Ok(()) => rs.target = Some(target),
+                    Err(e) => rs.error = Some(e.to_string()),
+                }
+            }
+        }
+    }
+    match ctl.rotator.get_position().await {
+        Ok(pos) => {
+            rs.position = Some(pos);
+            rs.error = None;
+        }
+        Err(e) => {
+            if rs.error.is_none() {
+                warn!("Rotator read failed: {}", e);
+            }
+            rs.error = Some(e.to_string());
+        }
+    }
+}
+
+/// CW keyer owned by the rig task.
+struct CwKeyerControl {
+    tone_tx: Option<mpsc::Sender<CwToneCommand>>,
+    ptt_lead: Duration,
+    ptt_tail: Duration,
+    job: Option<CwJob>,
+}
+
+/// A message being keyed.
+struct CwJob {
+    /// Whether the rig task keyed PTT (tone keying) and must release it.
+    ptt: bool,
+    ends_at: Instant,
+}
+
+fn cw_job_active(keyer: &Option<CwKeyerControl>) -> bool {
+    keyer.as_ref().is_some_and(|k| k.job.is_some())
+}
+
+/// When the message being keyed ends; only meaningful while a job is active.
+fn cw_job_end(keyer: &Option<CwKeyerControl>) -> Instant {
+    keyer
+        .as_ref()
+        .and_then(|k| k.job.as_ref())
+        .map_or_else(Instant::now, |j| j.ends_at)
+}
+
+/// Expand macros and start keying `text`, via CAT when the backend and
+/// configured output allow it, otherwise with PTT plus tone audio.
+async fn start_cw(
+    ctl: &mut CwKeyerControl,
+    rig: &mut Box<dyn RigCat>,
+    state: &mut RigState,
+    text: &str,
+    call: Option<&str>,
+) -> RigResult<()> {
+    if !state.initialized || matches!(state.control.enabled, Some(false)) {
+        return Err(RigError::invalid_state("rig is not ready to transmit"));
+    }
+    if ctl.job.is_some() {
+        return Err(RigError::invalid_state("CW is already being sent"));
+    }
+    let Some(ks) = state.cw_keyer.as_ref() else {
+        return Err(RigError::not_supported("cw keyer"));
+    };
+    let ctx = MacroContext {
+        mycall: state.server_callsign.as_deref(),
+        call,
+        serial: ks.serial,
+    };
+    let expanded = keyer::expand_macros(text, &ctx).map_err(RigError::invalid_state)?;
+    let timeline = keyer::key_timeline(&expanded, &ks.settings);
+    if timeline.is_empty() {
+        return Err(RigError::invalid_state("nothing to send"));
+    }
+    let duration = Duration::from_millis(keyer::timeline_duration_ms(&timeline));
+    let (output, wpm, tone_hz) = (ks.output, ks.settings.wpm, ks.tone_hz);
+
+    let mut via_cat = false;
+    if output != CwKeyerOutput::Tone {
+        match rig.send_cw(expanded.clone(), wpm).await {
+            Ok(()) => via_cat = true,
+            Err(e) => {
+                let unsupported = e
+                    .downcast_ref::<RigError>()
+                    .is_some_and(|re| !re.is_transient());
+                if output == CwKeyerOutput::Cat || !unsupported {
+                    return Err(RigError::communication(format!("send_cw: {e}")));
+                }
+                debug!("CAT keying unavailable ({}), using tone keying", e);
+            }
+        }
+    }
+
+    let ends_at = if via_cat {
+        Instant::now() + duration
+    } else {
+        let Some(tone_tx) = ctl.tone_tx.as_ref() else {
+            return Err(RigError::not_supported(
+                "tone keying (audio TX is not enabled)",
+            ));
+        };
+        if let Err(e) = rig.set_ptt(true).await {
+            return Err(RigError::communication(format!("set_ptt: {e}")));
+        }
+        state.apply_ptt(true);
+        let cmd = CwToneCommand::Send {
+            timeline,
+            tone_hz,
+            lead_ms: ctl.ptt_lead.as_millis() as u32,
+        };
+        if tone_tx.send(cmd).await.is_err() {
+            let _ = rig.set_ptt(false).await;
+            state.apply_ptt(false);
+            return Err(RigError::invalid_state("tone keyer is not running"));
+        }
+        Instant::now() + ctl.ptt_lead + duration + ctl.ptt_tail
+    };
+
+    info!("Sending CW: {}", expanded);
+    ctl.job = Some(CwJob {
+        ptt: !via_cat,
+        ends_at,
+    });
+    if let Some(ks) = state.cw_keyer.as_mut() {
+        if keyer::uses_serial(text) {
+            ks.serial = ks.serial.saturating_add(1);
+        }
+        ks.sending = Some(expanded);
+    }
+    Ok(())
+}
+
+/// Release PTT once a keyed message has played out.
+async fn finish_cw(ctl: &mut CwKeyerControl, rig: &mut Box<dyn RigCat>, state: &mut RigState) {
+    let Some(job) = ctl.job.take() else {
+        return;
+    };
+    if job.ptt {
+        if let Err(e) = rig.set_ptt(false).await {
+            error!("CW keyer: failed to release PTT: {}", e);
+        } else {
+            state.apply_ptt(false);
+        }
+    }
+    if let Some(ks) = state.cw_keyer.as_mut() {
+        ks.sending = None;
+    }
+}
+
+/// Stop the tone keyer and any CAT keying in progress.  The caller drops PTT.
+async fn abort_cw(ctl: &mut CwKeyerControl, rig: &mut Box<dyn RigCat>) {
+    let Some(job) = ctl.job.take() else {
+        return;
+    };
+    if job.ptt {
+        if let Some(tone_tx) = ctl.tone_tx.as_ref() {
+            let _ = tone_tx.try_send(CwToneCommand::Abort);
+        }
+    } else if let Err(e) = rig.stop_cw().await {
+        warn!("CW keyer: stop_cw failed: {}", e);
+    }
+    info!("CW sending aborted");
+}
+
+fn now_ms() -> i64 {
+    match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        Ok(d) => d.as_millis() as i64,
+        Err(_) => 0,
+    }
+}
+
 /// Map raw signal strength to S-meter value based on mode.
 fn map_signal_strength(mode: &RigMode, raw: u8) -> f64 {
     // FT-817 returns 0-15 for signal strength
@@ -1153,6 +2129,11 @@ fn invalidate_main_decoder_windows_on_freq_change(state: &mut RigState, prev_fre
     match state.status.mode {
         RigMode::PKT => {
             state.reset_seqs.aprs_decode_reset_seq += 1;
+            state.reset_seqs.dsc_decode_reset_seq += 1;
+        }
+        RigMode::FM => {
+            state.reset_seqs.dsc_decode_reset_seq += 1;
+            state.reset_seqs.pocsag_decode_reset_seq += 1;
         }
         RigMode::DIG => {
             state.reset_seqs.hf_aprs_decode_reset_seq += 1;
@@ -1160,16 +2141,26 @@ fn invalidate_main_decoder_windows_on_freq_change(state: &mut RigState, prev_fre
             state.reset_seqs.ft4_decode_reset_seq += 1;
             state.reset_seqs.ft2_decode_reset_seq += 1;
             state.reset_seqs.wspr_decode_reset_seq += 1;
+            state.reset_seqs.psk_decode_reset_seq += 1;
+            state.reset_seqs.dsc_decode_reset_seq += 1;
         }
         RigMode::USB => {
             state.reset_seqs.ft8_decode_reset_seq += 1;
             state.reset_seqs.ft4_decode_reset_seq += 1;
             state.reset_seqs.ft2_decode_reset_seq += 1;
             state.reset_seqs.wspr_decode_reset_seq += 1;
+            state.reset_seqs.psk_decode_reset_seq += 1;
+            state.reset_seqs.dsc_decode_reset_seq += 1;
+        }
+        RigMode::LSB => {
+            state.reset_seqs.psk_decode_reset_seq += 1;
         }
         RigMode::CW | RigMode::CWR => {
             state.reset_seqs.cw_decode_reset_seq += 1;
         }
+        RigMode::AM => {
+            state.reset_seqs.acars_decode_reset_seq += 1;
+        }
         _ => {}
     }
 }
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! RBN-compatible telnet output for the CW skimmer.
+//!
+//! Clients are greeted with the usual `Please enter your call:` prompt and
+//! then receive every spot as a `DX de ...` line, which is what contest
+//! loggers and cluster software expect from a Reverse Beacon Network node.
+//! Commands sent after login are read and ignored.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use bytes::Bytes;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tracing::{info, warn};
+
+/// Spot lines buffered per client before a slow reader starts losing data.
+const FEED_CHANNEL_CAPACITY: usize = 256;
+/// How long a client may take to answer the login prompt.
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Create the broadcast channel the telnet listener serves from.
+pub fn feed_channel() -> broadcast::Sender<Bytes> {
+    broadcast::channel(FEED_CHANNEL_CAPACITY).0
+}
+
+/// Accept telnet clients on `addr` until shutdown.
+pub async fn run_telnet_listener(
+    addr: SocketAddr,
+    spotter: String,
+    feed_tx: broadcast::Sender<Bytes>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("CW skimmer telnet feed on {}", addr);
+
+    loop {
+        tokio::select! {
+            accept = listener.accept() => {
+                let (socket, peer) = accept?;
+                let feed_rx = feed_tx.subscribe();
+                let client_shutdown_rx = shutdown_rx.clone();
+                let spotter = spotter.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = serve_telnet_client(socket, &spotter, feed_rx, client_shutdown_rx).await {
+                        warn!("CW skimmer telnet client {} error: {:?}", peer, e);
+                    }
+                    info!("CW skimmer telnet client {} disconnected", peer);
+                });
+            }
+            changed = shutdown_rx.changed() => {
+                match changed {
+                    Ok(()) if *shutdown_rx.borrow() => break,
+                    Ok(()) => {}
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn serve_telnet_client(
+    socket: TcpStream,
+    spotter: &str,
+    mut feed_rx: broadcast::Receiver<Bytes>,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    socket.set_nodelay(true)?;
+    let peer = socket.peer_addr()?;
+    let (reader, mut writer) = socket.into_split();
+    let mut reader = BufReader::new(reader);
+
+    writer.write_all(b"Please enter your call: ").await?;
+    let mut call = String::new();
+    match tokio::time::timeout(LOGIN_TIMEOUT, reader.read_line(&mut call)).await {
+        Ok(Ok(0)) | Err(_) => return Ok(()),
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => return Err(e),
+    }
+    let call = call.trim().to_uppercase();
+    info!("CW skimmer telnet client {} logged in as {}", peer, call);
+    writer
+        .write_all(
+            format!("\r\nHello {call}, this is {spotter}\r\n\r\n{spotter} de SKIMMER >\r\n")
+                .as_bytes(),
+        )
+        .await?;
+
+    let mut discard = [0u8; 256];
+    loop {
+        tokio::select! {
+            recv = feed_rx.recv() => {
+                match recv {
+                    Ok(line) => writer.write_all(&line).await?,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        warn!("CW skimmer telnet: client dropped {} spots", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            read = reader.read(&mut discard) => {
+                if read? == 0 {
+                    break;
+                }
+            }
+            changed = shutdown_rx.changed() => {
+                match changed {
+                    Ok(()) if *shutdown_rx.borrow() => break,
+                    Ok(()) => {}
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn client_logs_in_and_receives_spots() {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+
+        let feed_tx = feed_channel();
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let server = tokio::spawn(run_telnet_listener(
+            addr,
+            "N0CALL-#".to_string(),
+            feed_tx.clone(),
+            shutdown_rx,
+        ));
+
+        let client = loop {
+            match TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+        let (reader, mut writer) = client.into_split();
+        let mut reader = BufReader::new(reader);
+        let mut prompt = [0u8; 24];
+        reader.read_exact(&mut prompt).await.unwrap();
+        assert_eq!(&prompt, b"Please enter your call: ");
+        writer.write_all(b"dl1abc\r\n").await.unwrap();
+
+        let mut line = String::new();
+        while !line.contains(" >") {
+            line.clear();
+            reader.read_line(&mut line).await.unwrap();
+        }
+        let spot = "DX de N0CALL-#:  14025.0  K1ABC        CW     18 dB 24 WPM CQ  1234Z\r\n";
+        feed_tx.send(Bytes::from(spot)).unwrap();
+        line.clear();
+        reader.read_line(&mut line).await.unwrap();
+        assert_eq!(line, spot);
+
+        shutdown_tx.send(true).unwrap();
+        server.await.unwrap().unwrap();
+    }
+}
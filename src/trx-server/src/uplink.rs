@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Outbound uplink to a trx-client (`[uplink]`).
+//!
+//! For stations behind CGNAT the server dials the client's uplink listener,
+//! introduces itself with an `UplinkHello` and then serves the connection
+//! exactly like an inbound multiplexed session: the client opens control
+//! and audio streams over it.  The connection is re-established with
+//! exponential backoff whenever it drops.
+
+use std::time::Duration;
+
+use tokio::io::BufReader;
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+use tokio::time;
+use tracing::{info, warn};
+
+use trx_app::tls::{TlsConnector, TransportStream};
+use trx_core::mux::UplinkHello;
+
+use crate::config::{UplinkConfig, UplinkEndpoint};
+use crate::listener::{serve_mux, ClientContext};
+
+/// Time allowed for the TCP connect and TLS handshake.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// First reconnect delay; doubles up to `max_backoff_secs`.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// A session that lasted this long resets the backoff.
+const STABLE_SESSION: Duration = Duration::from_secs(30);
+
+/// Keep the uplink connected until shutdown.
+pub async fn run_uplink(
+    cfg: UplinkConfig,
+    endpoint: UplinkEndpoint,
+    tls: Option<TlsConnector>,
+    ctx: ClientContext,
+    mut shutdown_rx: watch::Receiver<bool>,
+) {
+    let max_backoff = Duration::from_secs(cfg.max_backoff_secs);
+    let mut backoff = INITIAL_BACKOFF;
+    loop {
+        if *shutdown_rx.borrow() {
+            return;
+        }
+        info!(
+            "Uplink: connecting to {}{}",
+            endpoint.addr,
+            if tls.is_some() { " (TLS)" } else { "" }
+        );
+        let started = time::Instant::now();
+        match time::timeout(CONNECT_TIMEOUT, connect(&endpoint, tls.as_ref())).await {
+            Ok(Ok((stream, peer))) => {
+                let hello = UplinkHello::new(cfg.station.clone(), cfg.token.clone());
+                let mut stream = BufReader::new(stream);
+                match hello.write(stream.get_mut()).await {
+                    Ok(()) => {
+                        info!("Uplink: connected to {} as '{}'", peer, cfg.station);
+                        if let Err(e) =
+                            serve_mux(stream, peer, ctx.clone(), shutdown_rx.clone()).await
+                        {
+                            warn!("Uplink: session with {} ended: {}", peer, e);
+                        }
+                    }
+                    Err(e) => warn!("Uplink: hello to {} failed: {}", peer, e),
+                }
+            }
+            Ok(Err(e)) => warn!("Uplink: connect to {} failed: {}", endpoint.addr, e),
+            Err(_) => warn!("Uplink: connect to {} timed out", endpoint.addr),
+        }
+
+        if started.elapsed() >= STABLE_SESSION {
+            backoff = INITIAL_BACKOFF;
+        }
+        tokio::select! {
+            _ = time::sleep(backoff) => {}
+            changed = shutdown_rx.changed() => {
+                if changed.is_err() || *shutdown_rx.borrow() {
+                    return;
+                }
+            }
+        }
+        backoff = (backoff * 2).min(max_backoff);
+    }
+}
+
+async fn connect(
+    endpoint: &UplinkEndpoint,
+    tls: Option<&TlsConnector>,
+) -> std::io::Result<(TransportStream, std::net::SocketAddr)> {
+    let stream = TcpStream::connect(&endpoint.addr).await?;
+    let _ = stream.set_nodelay(true);
+    let peer = stream.peer_addr()?;
+    Ok((TransportStream::connect(stream, tls).await?, peer))
+}
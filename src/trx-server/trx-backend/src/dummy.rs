@@ -104,6 +104,7 @@ impl DummyRig {
                     attenuator: false,
                     preamp: false,
                     rit: false,
+                    xit: false,
                     rpt: false,
                     split: false,
                     tx: true,
@@ -111,6 +112,11 @@ impl DummyRig {
                     vfo_switch: true,
                     filter_controls: false,
                     signal_meter: true,
+                    memory_channels: 0,
+                    memory_write: false,
+                    band_stack: false,
+                    ctcss_decode: false,
+                    dcs: false,
                 },
                 access: RigAccessMethod::Serial {
                     path: "/dev/null".to_string(),
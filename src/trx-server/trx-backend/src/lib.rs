@@ -8,8 +8,10 @@ use trx_core::rig::RigCat;
 use trx_core::DynResult;
 
 mod dummy;
+pub mod rotator;
 pub mod vchan;
 
+pub use rotator::build_rotator;
 pub use vchan::{SharedVChanManager, VChanError, VChannelInfo, VirtualChannelManager};
 
 #[cfg(feature = "ft450d")]
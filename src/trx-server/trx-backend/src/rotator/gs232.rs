@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Yaesu GS-232A/B serial rotator controller.
+//!
+//! `C2` reads the position (`AZ=123  EL=045` on the GS-232B, `+0123+0045`
+//! on the GS-232A), `Waaa eee` starts a move and `S` stops both axes.
+//! Azimuths above 360° are passed through for controllers in 450° mode.
+
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::time::{timeout, Instant};
+use tokio_serial::{ClearBuffer, SerialPort, SerialPortBuilderExt, SerialStream};
+
+use trx_core::rotator::{Rotator, RotatorFuture, RotatorInfo, RotatorPosition};
+use trx_core::DynResult;
+
+const READ_TIMEOUT: Duration = Duration::from_millis(800);
+
+pub struct Gs232Rotator {
+    port: SerialStream,
+    info: RotatorInfo,
+}
+
+impl Gs232Rotator {
+    pub fn new(path: &str, baud: u32, info: RotatorInfo) -> DynResult<Self> {
+        let port = tokio_serial::new(path, baud).open_native_async()?;
+        Ok(Self { port, info })
+    }
+
+    async fn send(&mut self, cmd: &str) -> DynResult<()> {
+        self.port.write_all(format!("{cmd}\r").as_bytes()).await?;
+        Ok(())
+    }
+
+    /// Read one reply line, terminated by CR or LF.
+    async fn read_line(&mut self) -> DynResult<String> {
+        let deadline = Instant::now() + READ_TIMEOUT;
+        let mut line = Vec::new();
+        let mut byte = [0u8; 1];
+        loop {
+            let left = deadline.saturating_duration_since(Instant::now());
+            match timeout(left, self.port.read_exact(&mut byte)).await {
+                Ok(Ok(_)) if matches!(byte[0], b'\r' | b'\n') => {
+                    if !line.is_empty() {
+                        break;
+                    }
+                }
+                Ok(Ok(_)) => line.push(byte[0]),
+                Ok(Err(e)) => return Err(e.into()),
+                Err(_) if !line.is_empty() => break,
+                Err(_) => return Err("GS-232 read timed out".into()),
+            }
+        }
+        Ok(String::from_utf8_lossy(&line).into_owned())
+    }
+}
+
+/// Parse a `C2` reply in either the GS-232B or GS-232A format.
+fn parse_position(reply: &str) -> Option<RotatorPosition> {
+    let number = |s: &str| -> Option<f64> {
+        let digits: String = s
+            .trim_start()
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '-' || *c == '+')
+            .collect();
+        digits.parse().ok()
+    };
+    if let Some(az) = reply.find("AZ=") {
+        return Some(RotatorPosition {
+            azimuth_deg: number(&reply[az + 3..])?,
+            elevation_deg: reply
+                .find("EL=")
+                .and_then(|el| number(&reply[el + 3..]))
+                .unwrap_or(0.0),
+        });
+    }
+    let mut values = reply
+        .split(['+', '-'])
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| s.trim().parse::<f64>());
+    let azimuth_deg = values.next()?.ok()?;
+    let elevation_deg = values.next().and_then(Result::ok).unwrap_or(0.0);
+    Some(RotatorPosition {
+        azimuth_deg,
+        elevation_deg,
+    })
+}
+
+impl Rotator for Gs232Rotator {
+    fn info(&self) -> &RotatorInfo {
+        &self.info
+    }
+
+    fn get_position<'a>(&'a mut self) -> RotatorFuture<'a, RotatorPosition> {
+        Box::pin(async move {
+            let _ = self.port.clear(ClearBuffer::Input);
+            self.send("C2").await?;
+            let reply = self.read_line().await?;
+            parse_position(&reply)
+                .ok_or_else(|| format!("unexpected GS-232 reply: {reply:?}").into())
+        })
+    }
+
+    fn set_position<'a>(&'a mut self, pos: RotatorPosition) -> RotatorFuture<'a, ()> {
+        Box::pin(async move {
+            let az = pos.azimuth_deg.round().clamp(0.0, self.info.az_max_deg) as u32;
+            let el = pos.elevation_deg.round().clamp(0.0, self.info.el_max_deg) as u32;
+            self.send(&format!("W{az:03} {el:03}")).await
+        })
+    }
+
+    fn stop<'a>(&'a mut self) -> RotatorFuture<'a, ()> {
+        Box::pin(async move { self.send("S").await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_both_reply_formats() {
+        let b = parse_position("AZ=123  EL=045").unwrap();
+        assert_eq!((b.azimuth_deg, b.elevation_deg), (123.0, 45.0));
+        let a = parse_position("+0412+0090").unwrap();
+        assert_eq!((a.azimuth_deg, a.elevation_deg), (412.0, 90.0));
+        let az_only = parse_position("AZ=270").unwrap();
+        assert_eq!((az_only.azimuth_deg, az_only.elevation_deg), (270.0, 0.0));
+        assert!(parse_position("?>").is_none());
+    }
+}
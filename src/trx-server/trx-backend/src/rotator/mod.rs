@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Antenna rotator backends.
+
+mod gs232;
+mod rotctld;
+
+use trx_core::rotator::{Rotator, RotatorInfo};
+use trx_core::DynResult;
+
+use crate::RigAccess;
+
+pub use gs232::Gs232Rotator;
+pub use rotctld::RotctldRotator;
+
+/// Instantiate a rotator backend by model name ("rotctld" or "gs232").
+pub fn build_rotator(
+    model: &str,
+    access: RigAccess,
+    az_max_deg: f64,
+    el_max_deg: f64,
+) -> DynResult<Box<dyn Rotator>> {
+    match model.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+        "rotctld" => match access {
+            RigAccess::Tcp { addr } => Ok(Box::new(RotctldRotator::new(
+                addr,
+                RotatorInfo {
+                    model: "rotctld".to_string(),
+                    az_max_deg,
+                    el_max_deg,
+                },
+            ))),
+            _ => Err("rotctld rotator requires tcp access".into()),
+        },
+        "gs232" | "gs232a" | "gs232b" => match access {
+            RigAccess::Serial { path, baud } => Ok(Box::new(Gs232Rotator::new(
+                &path,
+                baud,
+                RotatorInfo {
+                    model: "GS-232".to_string(),
+                    az_max_deg,
+                    el_max_deg,
+                },
+            )?)),
+            _ => Err("GS-232 rotator requires serial access".into()),
+        },
+        _ => Err(format!("Unknown rotator backend: {}", model).into()),
+    }
+}
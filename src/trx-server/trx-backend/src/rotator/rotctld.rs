@@ -0,0 +1,164 @@
+// SPDX-FileCopyrightText: 2026 Stan Grams <sjg@haxx.space>
+//
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Hamlib `rotctld` network rotator.
+//!
+//! Speaks the plain (non-extended) protocol: `p` returns azimuth and
+//! elevation on two lines, `P az el` and `S` answer `RPRT 0`.  The
+//! connection is opened lazily and dropped on any error so the next call
+//! reconnects.
+
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use trx_core::rotator::{Rotator, RotatorFuture, RotatorInfo, RotatorPosition};
+use trx_core::DynResult;
+
+const IO_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub struct RotctldRotator {
+    addr: String,
+    info: RotatorInfo,
+    conn: Option<BufReader<TcpStream>>,
+}
+
+impl RotctldRotator {
+    pub fn new(addr: String, info: RotatorInfo) -> Self {
+        Self {
+            addr,
+            info,
+            conn: None,
+        }
+    }
+
+    /// Send one command and collect up to `lines` reply lines, stopping early
+    /// at an `RPRT` status line.
+    async fn transact(&mut self, cmd: &str, lines: usize) -> DynResult<Vec<String>> {
+        match timeout(IO_TIMEOUT, self.transact_inner(cmd, lines)).await {
+            Ok(Ok(reply)) => Ok(reply),
+            Ok(Err(e)) => {
+                self.conn = None;
+                Err(e)
+            }
+            Err(_) => {
+                self.conn = None;
+                Err(format!("rotctld at {} timed out", self.addr).into())
+            }
+        }
+    }
+
+    async fn transact_inner(&mut self, cmd: &str, lines: usize) -> DynResult<Vec<String>> {
+        let conn = match &mut self.conn {
+            Some(conn) => conn,
+            None => {
+                let stream = TcpStream::connect(&self.addr).await?;
+                stream.set_nodelay(true)?;
+                self.conn.insert(BufReader::new(stream))
+            }
+        };
+        conn.get_mut()
+            .write_all(format!("{cmd}\n").as_bytes())
+            .await?;
+        let mut reply = Vec::new();
+        while reply.len() < lines {
+            let mut line = String::new();
+            if conn.read_line(&mut line).await? == 0 {
+                return Err("rotctld closed the connection".into());
+            }
+            let line = line.trim();
+            if let Some(code) = line.strip_prefix("RPRT") {
+                check_report(code)?;
+                break;
+            }
+            reply.push(line.to_string());
+        }
+        Ok(reply)
+    }
+}
+
+fn check_report(code: &str) -> DynResult<()> {
+    match code.trim().parse::<i32>() {
+        Ok(0) => Ok(()),
+        Ok(code) => Err(format!("rotctld error {code}").into()),
+        Err(_) => Err(format!("malformed rotctld status: RPRT{code}").into()),
+    }
+}
+
+fn parse_position(reply: &[String]) -> DynResult<RotatorPosition> {
+    let [az, el] = reply else {
+        return Err("rotctld returned no position".into());
+    };
+    Ok(RotatorPosition {
+        azimuth_deg: az.parse()?,
+        elevation_deg: el.parse()?,
+    })
+}
+
+impl Rotator for RotctldRotator {
+    fn info(&self) -> &RotatorInfo {
+        &self.info
+    }
+
+    fn get_position<'a>(&'a mut self) -> RotatorFuture<'a, RotatorPosition> {
+        Box::pin(async move { parse_position(&self.transact("p", 2).await?) })
+    }
+
+    fn set_position<'a>(&'a mut self, pos: RotatorPosition) -> RotatorFuture<'a, ()> {
+        Box::pin(async move {
+            let cmd = format!("P {:.1} {:.1}", pos.azimuth_deg, pos.elevation_deg);
+            self.transact(&cmd, 1).await.map(drop)
+        })
+    }
+
+    fn stop<'a>(&'a mut self) -> RotatorFuture<'a, ()> {
+        Box::pin(async move { self.transact("S", 1).await.map(drop) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn talks_to_rotctld() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let server = tokio::spawn(async move {
+            let (mut sock, _) = listener.accept().await.unwrap();
+            let mut received = String::new();
+            let mut buf = [0u8; 64];
+            for reply in ["RPRT 0\n", "123.400000\n45.000000\n", "RPRT -1\n"] {
+                let n = sock.read(&mut buf).await.unwrap();
+                received.push_str(std::str::from_utf8(&buf[..n]).unwrap());
+                sock.write_all(reply.as_bytes()).await.unwrap();
+            }
+            received
+        });
+
+        let mut rot = RotctldRotator::new(
+            addr,
+            RotatorInfo {
+                model: "rotctld".to_string(),
+                az_max_deg: 450.0,
+                el_max_deg: 90.0,
+            },
+        );
+        rot.set_position(RotatorPosition {
+            azimuth_deg: 390.0,
+            elevation_deg: 12.34,
+        })
+        .await
+        .unwrap();
+        let pos = rot.get_position().await.unwrap();
+        assert_eq!(pos.azimuth_deg, 123.4);
+        assert_eq!(pos.elevation_deg, 45.0);
+        assert!(rot.stop().await.is_err());
+        assert_eq!(server.await.unwrap(), "P 390.0 12.3\np\nS\n");
+    }
+}
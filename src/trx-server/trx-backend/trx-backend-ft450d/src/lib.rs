@@ -9,6 +9,8 @@ use tokio::time::{timeout, Duration};
 use tokio_serial::{ClearBuffer, SerialPort, SerialPortBuilderExt, SerialStream};
 
 use trx_core::radio::freq::{Band, Freq};
+use trx_core::rig::memory::RigMemory;
+use trx_core::rig::repeater::ctcss_tone_index;
 use trx_core::rig::{
     Rig, RigAccessMethod, RigCapabilities, RigCat, RigInfo, RigStatusFuture, RigVfo, RigVfoEntry,
 };
@@ -170,12 +172,14 @@ impl Ft450d {
                     RigMode::PKT,
                 ],
                 num_vfos: 2,
-                // CAT only exposes lock and VFO toggle; the other features are panel-only.
+                // CAT only exposes lock, VFO toggle, clarifier and repeater/tone settings;
+                // the other features are panel-only.
                 lockable: true,
                 attenuator: false,
                 preamp: false,
-                rit: false,
-                rpt: false,
+                rit: true,
+                xit: true,
+                rpt: true,
                 split: false,
                 lock: true,
                 tx: true,
@@ -183,6 +187,11 @@ impl Ft450d {
                 vfo_switch: true,
                 filter_controls: false,
                 signal_meter: true,
+                memory_channels: MEMORY_CHANNELS,
+                memory_write: true,
+                band_stack: true,
+                ctcss_decode: true,
+                dcs: false,
             },
             access: RigAccessMethod::Serial {
                 path: path.to_string(),
@@ -318,6 +327,103 @@ impl Ft450d {
         self.read_meter("RM5;").await
     }
 
+    /// Read memory `channel` with `MR`.
+    ///
+    /// The reply carries the repeater shift direction but not its size, so
+    /// `offset_hz` is only filled in for simplex channels, and it carries
+    /// only the tone mode, so `ctcss_hz` is never filled in.  Channel tags
+    /// are not available over CAT.
+    pub async fn read_memory(&mut self, channel: u16) -> DynResult<Option<RigMemory>> {
+        check_memory_channel(channel)?;
+        let resp = self.query(&format!("MR{:03};", channel)).await?;
+        if resp.starts_with('?') {
+            return Ok(None);
+        }
+        parse_memory_reply(channel, &resp).map(Some)
+    }
+
+    /// Store a memory channel with `MW`.
+    ///
+    /// `MW` holds frequency and mode but no tone frequency or shift size, so
+    /// channels with a CTCSS tone or a repeater offset are refused rather
+    /// than stored without them.  The tag is not stored.
+    pub async fn write_memory(&mut self, memory: &RigMemory) -> DynResult<()> {
+        check_memory_channel(memory.channel)?;
+        let mode = encode_mode(&memory.mode)?;
+        if let Some(hz) = memory.ctcss_hz {
+            return Err(format!("FT-450D memories cannot store a {hz} Hz CTCSS tone").into());
+        }
+        if let Some(offset_hz) = memory.offset_hz.filter(|&o| o != 0) {
+            return Err(
+                format!("FT-450D memories cannot store a {offset_hz} Hz repeater offset").into(),
+            );
+        }
+        self.write_cmd(&format!(
+            "MW{:03}{:08}+000000{}0000;",
+            memory.channel, memory.freq.hz, mode
+        ))
+        .await
+    }
+
+    /// Switch to memory mode on `channel` with `MC`.
+    pub async fn recall_memory(&mut self, channel: u16) -> DynResult<()> {
+        check_memory_channel(channel)?;
+        self.write_cmd(&format!("MC{:03};", channel)).await
+    }
+
+    /// Recall the band-stack register of the band containing `freq` with
+    /// `BS`; frequencies outside the amateur bands select general coverage.
+    pub async fn recall_band_stack(&mut self, freq: Freq) -> DynResult<()> {
+        let band = BAND_STACK_BANDS
+            .iter()
+            .position(|&(low, high)| (low..=high).contains(&freq.hz))
+            .unwrap_or(BAND_STACK_BANDS.len());
+        self.write_cmd(&format!("BS{:02};", band)).await
+    }
+
+    /// Set the repeater shift direction with `OS`.
+    ///
+    /// The shift size is a menu setting that CAT cannot change, so only the
+    /// sign of `offset_hz` is used.
+    pub async fn set_repeater_offset(&mut self, offset_hz: i32) -> DynResult<()> {
+        let shift = match offset_hz.signum() {
+            1 => '1',
+            -1 => '2',
+            _ => '0',
+        };
+        self.write_cmd(&format!("OS0{};", shift)).await
+    }
+
+    /// Select a CTCSS tone with `CN` and the tone mode with `CT`.
+    pub async fn set_ctcss(&mut self, tone_hz: Option<f32>, decode: bool) -> DynResult<()> {
+        let Some(hz) = tone_hz else {
+            return self.write_cmd("CT00;").await;
+        };
+        let index = ctcss_tone_index(hz).ok_or_else(|| format!("{hz} Hz is not a CTCSS tone"))?;
+        self.write_cmd(&format!("CN0{:02};", index)).await?;
+        self.write_cmd(if decode { "CT01;" } else { "CT02;" }).await
+    }
+
+    /// Set the clarifier offset with `RC` plus `RU`/`RD`, and switch RIT
+    /// and XIT with `RT` and `XT`.
+    pub async fn set_clarifier(&mut self, offset_hz: i32, rit: bool, xit: bool) -> DynResult<()> {
+        let abs_hz = offset_hz.unsigned_abs();
+        if abs_hz > CLAR_MAX_HZ {
+            return Err(format!(
+                "FT-450D clarifier offset is at most {CLAR_MAX_HZ} Hz, got {offset_hz}"
+            )
+            .into());
+        }
+        // RU/RD step from the current offset, so clear it first.
+        self.write_cmd("RC;").await?;
+        if offset_hz != 0 {
+            let direction = if offset_hz > 0 { "RU" } else { "RD" };
+            self.write_cmd(&format!("{direction}{abs_hz:04};")).await?;
+        }
+        self.write_cmd(if rit { "RT1;" } else { "RT0;" }).await?;
+        self.write_cmd(if xit { "XT1;" } else { "XT0;" }).await
+    }
+
     async fn read_status(&mut self) -> DynResult<(u64, RigMode)> {
         let freq = self.read_freq().await?;
         let mode = self.read_mode().await?;
@@ -498,6 +604,58 @@ impl RigCat for Ft450d {
     ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
         Box::pin(async move { Ft450d::unlock(self).await })
     }
+
+    fn read_memory<'a>(
+        &'a mut self,
+        channel: u16,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<Option<RigMemory>>> + Send + 'a>> {
+        Box::pin(async move { Ft450d::read_memory(self, channel).await })
+    }
+
+    fn write_memory<'a>(
+        &'a mut self,
+        memory: RigMemory,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft450d::write_memory(self, &memory).await })
+    }
+
+    fn recall_memory<'a>(
+        &'a mut self,
+        channel: u16,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft450d::recall_memory(self, channel).await })
+    }
+
+    fn recall_band_stack<'a>(
+        &'a mut self,
+        freq: Freq,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft450d::recall_band_stack(self, freq).await })
+    }
+
+    fn set_repeater_offset<'a>(
+        &'a mut self,
+        offset_hz: i32,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft450d::set_repeater_offset(self, offset_hz).await })
+    }
+
+    fn set_ctcss<'a>(
+        &'a mut self,
+        tone_hz: Option<f32>,
+        decode: bool,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft450d::set_ctcss(self, tone_hz, decode).await })
+    }
+
+    fn set_clarifier<'a>(
+        &'a mut self,
+        offset_hz: i32,
+        rit: bool,
+        xit: bool,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft450d::set_clarifier(self, offset_hz, rit, xit).await })
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -507,6 +665,65 @@ enum Ft450dVfoSide {
     Unknown,
 }
 
+const MEMORY_CHANNELS: u16 = 500;
+
+/// Largest clarifier offset the FT-450D accepts, in Hz.
+const CLAR_MAX_HZ: u32 = 9999;
+
+/// Band ranges in `BS` band-code order; the code after the last entry is
+/// general coverage.
+const BAND_STACK_BANDS: [(u64, u64); 11] = [
+    (1_800_000, 2_000_000),
+    (3_500_000, 4_000_000),
+    (5_250_000, 5_450_000),
+    (7_000_000, 7_300_000),
+    (10_100_000, 10_150_000),
+    (14_000_000, 14_350_000),
+    (18_068_000, 18_168_000),
+    (21_000_000, 21_450_000),
+    (24_890_000, 24_990_000),
+    (28_000_000, 29_700_000),
+    (50_000_000, 54_000_000),
+];
+
+fn check_memory_channel(channel: u16) -> DynResult<()> {
+    if (1..=MEMORY_CHANNELS).contains(&channel) {
+        Ok(())
+    } else {
+        Err(format!("FT-450D memory channels are 1-{MEMORY_CHANNELS}, got {channel}").into())
+    }
+}
+
+/// Parse an `MR` reply: channel (3), frequency (8), clarifier (5), RX and
+/// TX clarifier flags, mode, VFO/memory flag, tone mode, two fixed digits
+/// and the repeater shift.
+fn parse_memory_reply(channel: u16, resp: &str) -> DynResult<RigMemory> {
+    let data = resp
+        .strip_prefix("MR")
+        .ok_or("CAT memory response missing MR")?;
+    if data.len() < 24 || !data.is_ascii() {
+        return Err("CAT memory response too short".into());
+    }
+    let hz: u64 = data[3..11]
+        .parse()
+        .map_err(|_| "CAT memory freq parse failed")?;
+    let mode = decode_mode(data.as_bytes()[18] as char);
+    // The shift size is a menu setting, so a shifted channel's offset is
+    // unknown.
+    let offset_hz = match data.as_bytes()[23] {
+        b'1' | b'2' => None,
+        _ => Some(0),
+    };
+    Ok(RigMemory {
+        channel,
+        name: String::new(),
+        freq: Freq { hz },
+        mode,
+        ctcss_hz: None,
+        offset_hz,
+    })
+}
+
 fn encode_mode(mode: &RigMode) -> DynResult<char> {
     match mode {
         RigMode::LSB => Ok('1'),
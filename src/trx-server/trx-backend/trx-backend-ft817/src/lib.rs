@@ -10,6 +10,8 @@ use tokio_serial::{ClearBuffer, SerialPort, SerialPortBuilderExt, SerialStream};
 
 use trx_core::math::{decode_freq_bcd, encode_freq_bcd};
 use trx_core::radio::freq::{Band, Freq};
+use trx_core::rig::memory::RigMemory;
+use trx_core::rig::repeater::CTCSS_TONES;
 use trx_core::rig::{
     Rig, RigAccessMethod, RigCapabilities, RigCat, RigInfo, RigStatusFuture, RigVfo, RigVfoEntry,
 };
@@ -24,6 +26,7 @@ pub struct Ft817 {
     vfo_b_freq: Option<Freq>,
     vfo_a_mode: Option<RigMode>,
     vfo_b_mode: Option<RigMode>,
+    split: bool,
 }
 
 impl Ft817 {
@@ -199,19 +202,26 @@ impl Ft817 {
                     RigMode::PKT,
                 ],
                 num_vfos: 2,
-                // CAT only exposes lock and VFO toggle; the other features are panel-only.
+                // CAT only exposes lock, VFO toggle, split, clarifier and repeater/tone
+                // settings; the other features are panel-only.
                 lockable: true,
                 attenuator: false,
                 preamp: false,
-                rit: false,
-                rpt: false,
-                split: false,
+                rit: true,
+                xit: false,
+                rpt: true,
+                split: true,
                 lock: true,
                 tx: true,
                 tx_limit: true,
                 vfo_switch: true,
                 filter_controls: false,
                 signal_meter: true,
+                memory_channels: MEMORY_CHANNELS,
+                memory_write: true,
+                band_stack: false,
+                ctcss_decode: true,
+                dcs: true,
             },
             access: RigAccessMethod::Serial {
                 path: path.to_string(),
@@ -226,6 +236,7 @@ impl Ft817 {
             vfo_b_freq: None,
             vfo_a_mode: None,
             vfo_b_mode: None,
+            split: false,
         })
     }
 
@@ -393,6 +404,48 @@ impl Ft817 {
         Ok(())
     }
 
+    /// Transmit on the other VFO at `freq` with split enabled, or return to
+    /// simplex with `None`.
+    ///
+    /// CAT can only tune the active VFO, so the other one is selected,
+    /// tuned and deselected again.
+    pub async fn set_split_tx_freq(&mut self, freq: Option<Freq>) -> DynResult<()> {
+        let Some(freq) = freq else {
+            if self.split {
+                self.set_split(false).await?;
+            }
+            return Ok(());
+        };
+        self.toggle_vfo().await?;
+        let tuned = self.set_freq(freq).await;
+        self.toggle_vfo().await?;
+        tuned?;
+        if !self.split {
+            self.set_split(true).await?;
+        }
+        Ok(())
+    }
+
+    async fn set_split(&mut self, on: bool) -> DynResult<()> {
+        let opcode = if on { CMD_SPLIT_ON } else { CMD_SPLIT_OFF };
+        let frame = [0x00, 0x00, 0x00, 0x00, opcode];
+        self.write_frame(&frame).await?;
+        let mut buf = [0u8; 1];
+        match timeout(Self::READ_TIMEOUT, self.port.read_exact(&mut buf)).await {
+            Ok(Ok(_)) => {
+                tracing::debug!("SPLIT response: 0x{:02X}", buf[0]);
+            }
+            Ok(Err(e)) => {
+                tracing::warn!("SPLIT read failed: {:?}", e);
+            }
+            Err(_) => {
+                tracing::warn!("SPLIT read timed out");
+            }
+        }
+        self.split = on;
+        Ok(())
+    }
+
     /// Read the current signal strength meter (S-meter/PWR) from the radio.
     ///
     /// The returned value is the raw CAT meter byte (0-255). In receive it
@@ -410,6 +463,179 @@ impl Ft817 {
         self.read_meter().await
     }
 
+    /// Read memory `channel` from the EEPROM.
+    ///
+    /// The FT-817 has no CAT memory commands, so the channel flags and the
+    /// 26-byte memory record are read two bytes at a time; a channel takes
+    /// 15 round trips.
+    pub async fn read_memory(&mut self, channel: u16) -> DynResult<Option<RigMemory>> {
+        check_memory_channel(channel)?;
+        let idx = (channel - 1) as usize;
+        let bit = 1u8 << (idx % 8);
+        let visible = self
+            .read_eeprom(EEPROM_MEM_VISIBLE + (idx / 8) as u16)
+            .await?[0];
+        let filled = self
+            .read_eeprom(EEPROM_MEM_FILLED + (idx / 8) as u16)
+            .await?[0];
+        if visible & bit == 0 || filled & bit == 0 {
+            return Ok(None);
+        }
+        let record = self.read_memory_record(idx).await?;
+        Ok(Some(parse_memory_record(channel, &record)))
+    }
+
+    /// Store a memory channel in the EEPROM.
+    ///
+    /// The record is read first and only the byte pairs that change are
+    /// written, with the undocumented `0xBC` command, each read back to
+    /// confirm it.  Record settings `RigMemory` does not cover (tuning
+    /// steps, narrow filters, DCS code) are kept.  The channel is marked
+    /// filled and visible once its record is in place.
+    pub async fn write_memory(&mut self, memory: &RigMemory) -> DynResult<()> {
+        check_memory_channel(memory.channel)?;
+        let idx = (memory.channel - 1) as usize;
+        let stored = self.read_memory_record(idx).await?;
+        let record = encode_memory_record(memory, &stored)?;
+        let base = EEPROM_MEMORIES + idx as u16 * MEMORY_RECORD_LEN as u16;
+        for (i, (new, old)) in record.chunks(2).zip(stored.chunks(2)).enumerate() {
+            if new != old {
+                self.write_eeprom(base + 2 * i as u16, [new[0], new[1]])
+                    .await?;
+            }
+        }
+        let bit = 1u8 << (idx % 8);
+        for flags in [EEPROM_MEM_FILLED, EEPROM_MEM_VISIBLE] {
+            let addr = flags + (idx / 8) as u16;
+            let [first, second] = self.read_eeprom(addr).await?;
+            if first & bit == 0 {
+                self.write_eeprom(addr, [first | bit, second]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Tune the current VFO to the frequency and mode of memory `channel`.
+    ///
+    /// CAT cannot switch the FT-817 into memory mode, so the channel is
+    /// read from the EEPROM and copied to the VFO instead.
+    pub async fn recall_memory(&mut self, channel: u16) -> DynResult<()> {
+        let memory = self
+            .read_memory(channel)
+            .await?
+            .ok_or_else(|| format!("memory channel {channel} is empty"))?;
+        self.set_mode(&memory.mode).await?;
+        self.set_freq(memory.freq).await
+    }
+
+    /// Set the repeater shift direction from the sign of `offset_hz` and,
+    /// unless simplex, the offset itself.
+    pub async fn set_repeater_offset(&mut self, offset_hz: i32) -> DynResult<()> {
+        let direction = match offset_hz.signum() {
+            -1 => RPT_SHIFT_MINUS,
+            1 => RPT_SHIFT_PLUS,
+            _ => RPT_SHIFT_SIMPLEX,
+        };
+        if offset_hz != 0 {
+            let bcd = encode_freq_bcd(offset_hz.unsigned_abs() as u64)?;
+            let frame = [bcd[0], bcd[1], bcd[2], bcd[3], CMD_SET_RPT_OFFSET];
+            self.write_frame(&frame).await?;
+        }
+        self.write_frame(&[direction, 0x00, 0x00, 0x00, CMD_SET_RPT_SHIFT])
+            .await
+    }
+
+    /// Set the CTCSS tone for encode (and decode with `decode`), or turn
+    /// tones off with `None`.
+    pub async fn set_ctcss(&mut self, tone_hz: Option<f32>, decode: bool) -> DynResult<()> {
+        let Some(hz) = tone_hz else {
+            return self
+                .write_frame(&[TONE_MODE_OFF, 0x00, 0x00, 0x00, CMD_SET_TONE_MODE])
+                .await;
+        };
+        // Transmit and receive tones are set together, in 0.1 Hz BCD.
+        let [hi, lo] = encode_bcd4((hz * 10.0).round() as u16);
+        self.write_frame(&[hi, lo, hi, lo, CMD_SET_CTCSS_TONE])
+            .await?;
+        let mode = if decode {
+            TONE_MODE_CTCSS
+        } else {
+            TONE_MODE_ENCODER
+        };
+        self.write_frame(&[mode, 0x00, 0x00, 0x00, CMD_SET_TONE_MODE])
+            .await
+    }
+
+    /// Enable DCS with `code` for both directions, or turn it off.
+    pub async fn set_dcs(&mut self, code: Option<u16>) -> DynResult<()> {
+        let Some(code) = code else {
+            return self
+                .write_frame(&[TONE_MODE_OFF, 0x00, 0x00, 0x00, CMD_SET_TONE_MODE])
+                .await;
+        };
+        let [hi, lo] = encode_bcd4(code);
+        self.write_frame(&[hi, lo, hi, lo, CMD_SET_DCS_CODE])
+            .await?;
+        self.write_frame(&[TONE_MODE_DCS, 0x00, 0x00, 0x00, CMD_SET_TONE_MODE])
+            .await
+    }
+
+    /// Set the clarifier offset and switch it on for RIT.  The FT-817 has
+    /// no XIT; the offset is set in 10 Hz steps.
+    pub async fn set_clarifier(&mut self, offset_hz: i32, rit: bool, xit: bool) -> DynResult<()> {
+        if xit {
+            return Err("XIT not supported on FT-817".into());
+        }
+        let abs_hz = offset_hz.unsigned_abs();
+        if abs_hz > CLAR_MAX_HZ {
+            return Err(format!(
+                "FT-817 clarifier offset is at most {CLAR_MAX_HZ} Hz, got {offset_hz}"
+            )
+            .into());
+        }
+        let sign = if offset_hz < 0 { CLAR_MINUS } else { 0x00 };
+        let [hi, lo] = encode_bcd4(((abs_hz + 5) / 10) as u16);
+        self.write_frame(&[sign, 0x00, hi, lo, CMD_SET_CLAR_FREQ])
+            .await?;
+        let opcode = if rit { CMD_CLAR_ON } else { CMD_CLAR_OFF };
+        self.write_frame(&[0x00, 0x00, 0x00, 0x00, opcode]).await
+    }
+
+    /// Read the 26-byte record of the memory at zero-based `idx`.
+    async fn read_memory_record(&mut self, idx: usize) -> DynResult<[u8; MEMORY_RECORD_LEN]> {
+        let base = EEPROM_MEMORIES + idx as u16 * MEMORY_RECORD_LEN as u16;
+        let mut record = [0u8; MEMORY_RECORD_LEN];
+        for (i, pair) in record.chunks_mut(2).enumerate() {
+            pair.copy_from_slice(&self.read_eeprom(base + 2 * i as u16).await?);
+        }
+        Ok(record)
+    }
+
+    /// Write the two bytes at `addr` and read them back.
+    async fn write_eeprom(&mut self, addr: u16, data: [u8; 2]) -> DynResult<()> {
+        let [hi, lo] = addr.to_be_bytes();
+        self.write_frame(&[hi, lo, data[0], data[1], CMD_WRITE_EEPROM])
+            .await?;
+        if self.read_eeprom(addr).await? == data {
+            Ok(())
+        } else {
+            Err(format!("EEPROM write at 0x{addr:04X} did not read back").into())
+        }
+    }
+
+    async fn read_eeprom(&mut self, addr: u16) -> DynResult<[u8; 2]> {
+        let _ = self.port.clear(ClearBuffer::Input);
+        let [hi, lo] = addr.to_be_bytes();
+        let frame = [hi, lo, 0x00, 0x00, CMD_READ_EEPROM];
+        self.write_frame(&frame).await?;
+
+        let mut buf = [0u8; 2];
+        timeout(Self::READ_TIMEOUT, self.port.read_exact(&mut buf))
+            .await
+            .map_err(|_| "CAT EEPROM read timeout")??;
+        Ok(buf)
+    }
+
     async fn read_status(&mut self) -> DynResult<(u64, RigMode)> {
         // Status request returns frequency (4 BCD bytes, LSB first) and mode code.
         let _ = self.port.clear(ClearBuffer::Input);
@@ -574,6 +800,65 @@ impl RigCat for Ft817 {
     ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
         Box::pin(async move { Ft817::unlock(self).await })
     }
+
+    fn set_split_tx_freq<'a>(
+        &'a mut self,
+        freq: Option<Freq>,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft817::set_split_tx_freq(self, freq).await })
+    }
+
+    fn read_memory<'a>(
+        &'a mut self,
+        channel: u16,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<Option<RigMemory>>> + Send + 'a>> {
+        Box::pin(async move { Ft817::read_memory(self, channel).await })
+    }
+
+    fn write_memory<'a>(
+        &'a mut self,
+        memory: RigMemory,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft817::write_memory(self, &memory).await })
+    }
+
+    fn recall_memory<'a>(
+        &'a mut self,
+        channel: u16,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft817::recall_memory(self, channel).await })
+    }
+
+    fn set_repeater_offset<'a>(
+        &'a mut self,
+        offset_hz: i32,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft817::set_repeater_offset(self, offset_hz).await })
+    }
+
+    fn set_ctcss<'a>(
+        &'a mut self,
+        tone_hz: Option<f32>,
+        decode: bool,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft817::set_ctcss(self, tone_hz, decode).await })
+    }
+
+    fn set_dcs<'a>(
+        &'a mut self,
+        code: Option<u16>,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft817::set_dcs(self, code).await })
+    }
+
+    fn set_clarifier<'a>(
+        &'a mut self,
+        offset_hz: i32,
+        rit: bool,
+        xit: bool,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move { Ft817::set_clarifier(self, offset_hz, rit, xit).await })
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -596,6 +881,8 @@ impl Ft817VfoSide {
 // Command codes per Yaesu CAT protocol.
 const CMD_SET_FREQ: u8 = 0x01;
 const CMD_READ_STATUS: u8 = 0x03;
+const CMD_SPLIT_ON: u8 = 0x02;
+const CMD_SPLIT_OFF: u8 = 0x82;
 const CMD_SET_MODE: u8 = 0x07;
 const CMD_PTT_ON: u8 = 0x08;
 const CMD_PTT_OFF: u8 = 0x88;
@@ -605,6 +892,174 @@ const CMD_TOGGLE_VFO: u8 = 0x81;
 const CMD_LOCK: u8 = 0x00;
 const CMD_UNLOCK: u8 = 0x80;
 const CMD_READ_METER: u8 = 0xE7;
+const CMD_SET_RPT_SHIFT: u8 = 0x09;
+const CMD_SET_RPT_OFFSET: u8 = 0xF9;
+const CMD_SET_TONE_MODE: u8 = 0x0A;
+const CMD_SET_CTCSS_TONE: u8 = 0x0B;
+const CMD_SET_DCS_CODE: u8 = 0x0C;
+const CMD_CLAR_ON: u8 = 0x05;
+const CMD_CLAR_OFF: u8 = 0x85;
+const CMD_SET_CLAR_FREQ: u8 = 0xF5;
+// Undocumented EEPROM read: address in the first two bytes, two bytes back.
+const CMD_READ_EEPROM: u8 = 0xBB;
+// Undocumented EEPROM write: address, then the bytes for it and the next one.
+const CMD_WRITE_EEPROM: u8 = 0xBC;
+
+// Data bytes for CMD_SET_RPT_SHIFT and CMD_SET_TONE_MODE.
+const RPT_SHIFT_MINUS: u8 = 0x09;
+const RPT_SHIFT_PLUS: u8 = 0x49;
+const RPT_SHIFT_SIMPLEX: u8 = 0x89;
+const TONE_MODE_DCS: u8 = 0x0A;
+const TONE_MODE_CTCSS: u8 = 0x2A;
+const TONE_MODE_ENCODER: u8 = 0x4A;
+const TONE_MODE_OFF: u8 = 0x8A;
+// First data byte of CMD_SET_CLAR_FREQ for a negative offset (0x00 is plus).
+const CLAR_MINUS: u8 = 0x01;
+
+/// Largest clarifier offset the FT-817 accepts, in Hz.
+const CLAR_MAX_HZ: u32 = 9990;
+
+// EEPROM layout of the memory channels (as mapped by CHIRP).
+const MEMORY_CHANNELS: u16 = 200;
+const MEMORY_RECORD_LEN: usize = 26;
+const EEPROM_MEM_VISIBLE: u16 = 0x03FD;
+const EEPROM_MEM_FILLED: u16 = 0x0417;
+const EEPROM_MEMORIES: u16 = 0x0431;
+const MEMORY_TAG_LEN: usize = 8;
+
+fn check_memory_channel(channel: u16) -> DynResult<()> {
+    if (1..=MEMORY_CHANNELS).contains(&channel) {
+        Ok(())
+    } else {
+        Err(format!("FT-817 memory channels are 1-{MEMORY_CHANNELS}, got {channel}").into())
+    }
+}
+
+/// Decode a 26-byte EEPROM memory record.
+///
+/// Byte 0 holds the mode in its low three bits, byte 1 the repeater shift
+/// in its top two bits, byte 4 the tone mode and byte 6 the CTCSS tone
+/// index; frequency and offset are big-endian in 10 Hz units at bytes 10
+/// and 14, followed by the eight-character tag.
+fn parse_memory_record(channel: u16, record: &[u8; MEMORY_RECORD_LEN]) -> RigMemory {
+    let be_u32 = |at: usize| {
+        u32::from_be_bytes([record[at], record[at + 1], record[at + 2], record[at + 3]]) as u64
+    };
+    let hz = be_u32(10) * 10;
+    let offset = be_u32(14) * 10;
+    let mode = match record[0] & 0x07 {
+        0 => RigMode::LSB,
+        1 => RigMode::USB,
+        2 => RigMode::CW,
+        3 => RigMode::CWR,
+        4 => RigMode::AM,
+        // The record has no separate wide FM mode; FM on the broadcast band is WFM.
+        5 if (76_000_000..=108_000_000).contains(&hz) => RigMode::WFM,
+        5 => RigMode::FM,
+        6 => RigMode::DIG,
+        _ => RigMode::PKT,
+    };
+    let offset_hz = match record[1] >> 6 {
+        1 => -(offset as i64),
+        2 => offset as i64,
+        // Split channels store the transmit frequency instead of an offset.
+        3 => offset as i64 - hz as i64,
+        _ => 0,
+    };
+    let ctcss_hz = match record[4] & 0x03 {
+        1 | 2 => CTCSS_TONES.get((record[6] & 0x3F) as usize).copied(),
+        _ => None,
+    };
+    let name = record[18..]
+        .iter()
+        .take_while(|&&b| b != 0xFF && b != 0)
+        .map(|&b| b as char)
+        .collect::<String>()
+        .trim_end()
+        .to_string();
+    RigMemory {
+        channel,
+        name,
+        freq: Freq { hz },
+        mode,
+        ctcss_hz,
+        offset_hz: Some(offset_hz),
+    }
+}
+
+/// Update a stored 26-byte memory record (see [`parse_memory_record`]) to
+/// hold `memory`, keeping the bits it does not cover.  Values the record
+/// cannot hold are refused rather than rounded or cut short.
+fn encode_memory_record(
+    memory: &RigMemory,
+    stored: &[u8; MEMORY_RECORD_LEN],
+) -> DynResult<[u8; MEMORY_RECORD_LEN]> {
+    let tens = |hz: u64, what: &str| -> DynResult<[u8; 4]> {
+        if !hz.is_multiple_of(10) {
+            return Err(
+                format!("FT-817 memories store the {what} in 10 Hz steps, got {hz} Hz").into(),
+            );
+        }
+        u32::try_from(hz / 10)
+            .map(u32::to_be_bytes)
+            .map_err(|_| format!("{what} {hz} Hz is out of range").into())
+    };
+    let mode = match memory.mode {
+        RigMode::LSB => 0,
+        RigMode::USB => 1,
+        RigMode::CW => 2,
+        RigMode::CWR => 3,
+        RigMode::AM => 4,
+        RigMode::FM | RigMode::WFM => 5,
+        RigMode::DIG => 6,
+        RigMode::PKT => 7,
+        ref other => return Err(format!("FT-817 memories cannot store mode {other:?}").into()),
+    };
+    let offset_hz = memory.offset_hz.unwrap_or(0);
+    let shift = match offset_hz.signum() {
+        -1 => 1,
+        1 => 2,
+        _ => 0,
+    };
+    let tone = match memory.ctcss_hz {
+        Some(hz) => Some(
+            CTCSS_TONES
+                .iter()
+                .position(|&t| (t - hz).abs() < 0.05)
+                .ok_or_else(|| format!("{hz} Hz is not a CTCSS tone"))? as u8,
+        ),
+        None => None,
+    };
+    let name = memory.name.trim_end();
+    if name.len() > MEMORY_TAG_LEN || !name.bytes().all(|b| b.is_ascii_graphic() || b == b' ') {
+        return Err(format!(
+            "FT-817 memory tags are up to {MEMORY_TAG_LEN} ASCII characters, got '{name}'"
+        )
+        .into());
+    }
+
+    let mut record = *stored;
+    record[0] = (record[0] & !0x07) | mode;
+    record[1] = (record[1] & 0x3F) | (shift << 6);
+    match tone {
+        Some(index) => {
+            record[4] = (record[4] & !0x03) | 1;
+            record[6] = (record[6] & !0x3F) | index;
+        }
+        None => record[4] &= !0x03,
+    }
+    record[10..14].copy_from_slice(&tens(memory.freq.hz, "frequency")?);
+    record[14..18].copy_from_slice(&tens(offset_hz.unsigned_abs(), "offset")?);
+    record[18..].fill(b' ');
+    record[18..18 + name.len()].copy_from_slice(name.as_bytes());
+    Ok(record)
+}
+
+/// Four decimal digits as two BCD bytes, e.g. 885 -> `[0x08, 0x85]`.
+fn encode_bcd4(value: u16) -> [u8; 2] {
+    let d = |div: u16| ((value / div) % 10) as u8;
+    [(d(1000) << 4) | d(100), (d(10) << 4) | d(1)]
+}
 
 fn encode_mode(mode: &RigMode) -> Option<u8> {
     Some(match mode {
@@ -635,3 +1090,71 @@ fn decode_mode(code: u8) -> RigMode {
         other => RigMode::Other(format!("0x{:02X}", other)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_record_round_trips() {
+        let mut stored = [0xFF; MEMORY_RECORD_LEN];
+        stored[0] = 0x80;
+        let memory = RigMemory {
+            channel: 7,
+            name: "GB3XX".to_string(),
+            freq: Freq { hz: 145_725_000 },
+            mode: RigMode::FM,
+            ctcss_hz: Some(77.0),
+            offset_hz: Some(-600_000),
+        };
+        let record = encode_memory_record(&memory, &stored).expect("encode");
+        assert_eq!(parse_memory_record(7, &record), memory);
+        // Bits outside the modelled fields are kept.
+        assert_eq!(record[0], 0x85);
+        assert_eq!(record[1] & 0x3F, 0x3F);
+
+        let simplex = RigMemory {
+            ctcss_hz: None,
+            offset_hz: None,
+            ..memory.clone()
+        };
+        let record = encode_memory_record(&simplex, &record).expect("encode");
+        assert_eq!(
+            parse_memory_record(7, &record),
+            RigMemory {
+                offset_hz: Some(0),
+                ..simplex
+            }
+        );
+    }
+
+    #[test]
+    fn memory_record_refuses_what_it_cannot_hold() {
+        let memory = RigMemory {
+            channel: 1,
+            name: String::new(),
+            freq: Freq { hz: 7_074_000 },
+            mode: RigMode::USB,
+            ctcss_hz: None,
+            offset_hz: None,
+        };
+        let stored = [0; MEMORY_RECORD_LEN];
+        let refused = |m: RigMemory| encode_memory_record(&m, &stored).is_err();
+        assert!(refused(RigMemory {
+            freq: Freq { hz: 7_074_005 },
+            ..memory.clone()
+        }));
+        assert!(refused(RigMemory {
+            ctcss_hz: Some(80.0),
+            ..memory.clone()
+        }));
+        assert!(refused(RigMemory {
+            name: "TOO LONG A TAG".to_string(),
+            ..memory.clone()
+        }));
+        assert!(refused(RigMemory {
+            mode: RigMode::SAM,
+            ..memory
+        }));
+    }
+}
@@ -22,6 +22,7 @@ use std::time::{Duration, Instant};
 use num_complex::Complex;
 use tokio::sync::broadcast;
 use tracing::warn;
+use trx_core::metrics::RigMetrics;
 use trx_core::rig::state::RigMode;
 
 pub use self::channel::{ChannelDsp, NoiseBlankerConfig, VirtualSquelchConfig};
@@ -109,6 +110,8 @@ impl IqSource for MockIqSource {
 pub struct SdrPipeline {
     pub pcm_senders: Vec<broadcast::Sender<Vec<f32>>>,
     pub iq_senders: Vec<broadcast::Sender<Vec<Complex<f32>>>>,
+    /// Raw capture-rate IQ, before any per-channel mixing or decimation.
+    pub raw_iq_tx: broadcast::Sender<Vec<Complex<f32>>>,
     /// All DSP channel slots, including fixed (primary, AIS) and dynamic
     /// (user virtual) channels.  Shared with the IQ read thread via RwLock.
     /// Virtual channels are appended beyond the fixed slots.
@@ -154,6 +157,7 @@ impl SdrPipeline {
         squelch_cfg: VirtualSquelchConfig,
         nb_cfg: NoiseBlankerConfig,
         channels: &[(f64, RigMode, u32)],
+        metrics: Arc<RigMetrics>,
     ) -> Self {
         const IQ_BROADCAST_CAPACITY: usize = 64;
         let (iq_tx, _iq_rx) = broadcast::channel::<Vec<Complex<f32>>>(IQ_BROADCAST_CAPACITY);
@@ -215,6 +219,8 @@ impl SdrPipeline {
         let agc_cmd: Arc<std::sync::Mutex<Option<bool>>> = Arc::new(std::sync::Mutex::new(None));
         let thread_agc_cmd = agc_cmd.clone();
 
+        let raw_iq_tx = iq_tx.clone();
+
         std::thread::Builder::new()
             .name("sdr-iq-read".to_string())
             .spawn(move || {
@@ -228,6 +234,7 @@ impl SdrPipeline {
                     thread_gain_cmd,
                     thread_lna_gain_cmd,
                     thread_agc_cmd,
+                    metrics,
                 );
             })
             .expect("failed to spawn sdr-iq-read thread");
@@ -235,6 +242,7 @@ impl SdrPipeline {
         Self {
             pcm_senders,
             iq_senders,
+            raw_iq_tx,
             channel_dsps,
             spectrum_buf,
             sdr_sample_rate,
@@ -326,6 +334,7 @@ fn iq_read_loop(
     gain_cmd: Arc<std::sync::Mutex<Option<f64>>>,
     lna_gain_cmd: Arc<std::sync::Mutex<Option<f64>>>,
     agc_cmd: Arc<std::sync::Mutex<Option<bool>>>,
+    metrics: Arc<RigMetrics>,
 ) {
     let mut block = vec![Complex::new(0.0_f32, 0.0_f32); IQ_BLOCK_SIZE];
     let block_duration_ms = if sdr_sample_rate > 0 {
@@ -389,6 +398,10 @@ fn iq_read_loop(
                 read_error_streak = read_error_streak.saturating_add(1);
                 let err_lc = e.to_ascii_lowercase();
                 let is_overflow = err_lc.contains("overflow") || err_lc.contains("overrun");
+                metrics.iq_read_errors.inc();
+                if is_overflow {
+                    metrics.iq_overflows.inc();
+                }
                 let restarted = read_error_streak >= 3;
                 let recovered = match source.handle_read_error(&e, read_error_streak) {
                     Ok(result) => result,
@@ -472,6 +485,7 @@ fn iq_read_loop(
         // Hold a read lock only for the duration of this block's DSP pass.
         // Write lock (add/remove channel) waits at most one block (~2 ms).
         {
+            let started = Instant::now();
             let dsps = channel_dsps.read().expect("channel_dsps RwLock poisoned");
             for dsp_arc in dsps.iter() {
                 match dsp_arc.lock() {
@@ -481,6 +495,7 @@ fn iq_read_loop(
                     }
                 }
             }
+            metrics.dsp_block_seconds.observe(started.elapsed());
         }
 
         spectrum.update(samples, &spectrum_buf);
@@ -572,6 +587,7 @@ mod tests {
             VirtualSquelchConfig::default(),
             NoiseBlankerConfig::default(),
             &[(200_000.0, RigMode::USB, 3000)],
+            Arc::default(),
         );
         assert_eq!(pipeline.pcm_senders.len(), 1);
         assert_eq!(pipeline.channel_dsps.read().unwrap().len(), 1);
@@ -590,6 +606,7 @@ mod tests {
             VirtualSquelchConfig::default(),
             NoiseBlankerConfig::default(),
             &[],
+            Arc::default(),
         );
         assert_eq!(pipeline.pcm_senders.len(), 0);
         assert_eq!(pipeline.channel_dsps.read().unwrap().len(), 0);
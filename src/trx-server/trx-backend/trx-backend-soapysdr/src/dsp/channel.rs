@@ -174,6 +174,118 @@ impl VirtualSquelch {
     }
 }
 
+/// Length of one tone-squelch measurement window.
+const CTCSS_WINDOW_MS: f32 = 400.0;
+/// The tone must exceed the mean of the reference bins by this factor.
+const CTCSS_REF_RATIO: f32 = 6.0;
+/// Minimum share of the window's audio energy carried by the tone.
+const CTCSS_MIN_SHARE: f32 = 0.01;
+/// Windows without the tone before the squelch closes again.
+const CTCSS_CLOSE_WINDOWS: u32 = 2;
+
+/// CTCSS tone squelch for FM: keeps audio muted until the tone is heard.
+///
+/// Goertzel filters measure the tone and two references 10 % either side
+/// over fixed windows of demodulated audio.  Voice carries little energy
+/// this low, while noise spreads evenly over all three bins, so the tone
+/// only stands out when a station is actually sending it.
+#[derive(Debug, Clone)]
+struct CtcssSquelch {
+    tone_hz: Option<f32>,
+    sample_rate: f32,
+    window_len: usize,
+    /// Goertzel coefficients for the tone, low and high references.
+    coeffs: [f32; 3],
+    /// Goertzel delay lines `(s1, s2)` for the same three bins.
+    state: [(f32, f32); 3],
+    energy: f32,
+    count: usize,
+    open: bool,
+    misses: u32,
+}
+
+impl CtcssSquelch {
+    fn new(sample_rate: u32) -> Self {
+        let sample_rate = sample_rate.max(1) as f32;
+        Self {
+            tone_hz: None,
+            sample_rate,
+            window_len: ((sample_rate * CTCSS_WINDOW_MS / 1000.0) as usize).max(1),
+            coeffs: [0.0; 3],
+            state: [(0.0, 0.0); 3],
+            energy: 0.0,
+            count: 0,
+            open: false,
+            misses: 0,
+        }
+    }
+
+    fn set_tone(&mut self, tone_hz: Option<f32>) {
+        self.tone_hz = tone_hz;
+        if let Some(hz) = tone_hz {
+            for (coeff, f) in self.coeffs.iter_mut().zip([hz, hz * 0.9, hz * 1.1]) {
+                *coeff = 2.0 * (std::f32::consts::TAU * f / self.sample_rate).cos();
+            }
+        }
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.state = [(0.0, 0.0); 3];
+        self.energy = 0.0;
+        self.count = 0;
+        self.open = false;
+        self.misses = 0;
+    }
+
+    fn process(&mut self, samples: &[f32]) {
+        if self.tone_hz.is_none() {
+            return;
+        }
+        for &x in samples {
+            for (coeff, (s1, s2)) in self.coeffs.iter().zip(self.state.iter_mut()) {
+                let s0 = x + coeff * *s1 - *s2;
+                *s2 = *s1;
+                *s1 = s0;
+            }
+            self.energy += x * x;
+            self.count += 1;
+            if self.count >= self.window_len {
+                self.finish_window();
+            }
+        }
+    }
+
+    fn finish_window(&mut self) {
+        let power = |i: usize| {
+            let (s1, s2) = self.state[i];
+            (s1 * s1 + s2 * s2 - self.coeffs[i] * s1 * s2).max(0.0)
+        };
+        let tone = power(0);
+        let reference = 0.5 * (power(1) + power(2));
+        // A sine of amplitude A gives a Goertzel power of (A·N/2)², and the
+        // window energy of that sine alone is A²·N/2.
+        let share = 2.0 * tone / (self.count as f32 * self.energy.max(1e-12));
+        if tone > CTCSS_REF_RATIO * reference && share > CTCSS_MIN_SHARE {
+            self.open = true;
+            self.misses = 0;
+        } else if self.open {
+            self.misses += 1;
+            if self.misses >= CTCSS_CLOSE_WINDOWS {
+                self.open = false;
+            }
+        }
+        self.state = [(0.0, 0.0); 3];
+        self.energy = 0.0;
+        self.count = 0;
+    }
+
+    /// Whether audio may pass in `mode`; the squelch only applies to FM.
+    fn is_open(&self, mode: &RigMode) -> bool {
+        self.tone_hz.is_none() || !matches!(mode, RigMode::FM) || self.open
+    }
+}
+
 /// Frequency shift for the IQ bandpass filter, expressed as a fraction of Fs.
 ///
 /// For SSB modes the symmetric LPF (cutoff ±BW/2) is modulated by ±cutoff_norm
@@ -295,6 +407,7 @@ pub struct ChannelDsp {
     processing_enabled: bool,
     force_mono_pcm: bool,
     squelch: VirtualSquelch,
+    ctcss: CtcssSquelch,
     noise_blanker: NoiseBlanker,
     last_signal_db: f32,
     /// Smoothed IQ envelope power (linear) for signal strength.
@@ -556,6 +669,7 @@ impl ChannelDsp {
             processing_enabled: true,
             force_mono_pcm,
             squelch: VirtualSquelch::new(squelch_cfg),
+            ctcss: CtcssSquelch::new(audio_sample_rate),
             noise_blanker: NoiseBlanker::new(nb_cfg.enabled, nb_cfg.threshold),
             last_signal_db: -120.0,
             carrier_iq_power: 0.0,
@@ -577,6 +691,11 @@ impl ChannelDsp {
         self.squelch.set_threshold_db(threshold_db);
     }
 
+    /// Mute FM audio until `tone_hz` is received, or disable with `None`.
+    pub fn set_ctcss_squelch(&mut self, tone_hz: Option<f32>) {
+        self.ctcss.set_tone(tone_hz);
+    }
+
     pub fn set_noise_blanker(&mut self, enabled: bool, threshold: f32) {
         self.noise_blanker.set_enabled(enabled);
         self.noise_blanker.set_threshold(threshold);
@@ -589,6 +708,7 @@ impl ChannelDsp {
         }
         self.demodulator = Demodulator::for_mode(mode);
         self.squelch.reset();
+        self.ctcss.reset();
         self.rebuild_filters(true);
     }
 
@@ -867,6 +987,9 @@ impl ChannelDsp {
             out
         } else {
             let mut raw = self.demodulator.demodulate(decimated);
+            if self.mode == RigMode::FM {
+                self.ctcss.process(&raw);
+            }
             for sample in &mut raw {
                 if let Some(dc) = &mut self.audio_dc {
                     *sample = dc.process(*sample);
@@ -884,7 +1007,8 @@ impl ChannelDsp {
                 raw
             }
         };
-        if !self.squelch.update(&self.mode, signal_db) {
+        let squelch_open = self.squelch.update(&self.mode, signal_db);
+        if !squelch_open || !self.ctcss.is_open(&self.mode) {
             audio.fill(0.0);
         }
 
@@ -958,6 +1082,44 @@ mod tests {
         assert_eq!(dsp.demodulator, Demodulator::Fm);
     }
 
+    #[test]
+    fn ctcss_squelch_opens_only_on_its_tone() {
+        use std::f32::consts::TAU;
+
+        const SR: u32 = 8_000;
+        // Sub-audible tone under a louder 1 kHz "voice" tone, one second long.
+        let audio = |tone_hz: f32, start: usize| -> Vec<f32> {
+            (start..start + SR as usize)
+                .map(|n| {
+                    let t = n as f32 / SR as f32;
+                    0.1 * (TAU * tone_hz * t).sin() + 0.5 * (TAU * 1_000.0 * t).sin()
+                })
+                .collect()
+        };
+
+        let mut sq = CtcssSquelch::new(SR);
+        assert!(sq.is_open(&RigMode::FM), "open while no tone is set");
+        sq.set_tone(Some(88.5));
+        assert!(!sq.is_open(&RigMode::FM));
+        assert!(sq.is_open(&RigMode::USB), "only FM is tone squelched");
+
+        sq.process(&audio(91.5, 0));
+        assert!(!sq.is_open(&RigMode::FM), "opened on the wrong tone");
+        sq.process(&audio(88.5, SR as usize));
+        assert!(sq.is_open(&RigMode::FM));
+
+        // Pseudo-random noise without the tone closes it again.
+        let mut seed = 1u32;
+        let noise: Vec<f32> = (0..SR)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (seed >> 8) as f32 / (1u32 << 24) as f32 - 0.5
+            })
+            .collect();
+        sq.process(&noise);
+        assert!(!sq.is_open(&RigMode::FM));
+    }
+
     #[test]
     fn noise_blanker_suppresses_impulse() {
         let mut nb = NoiseBlanker::new(true, 5.0);
@@ -11,6 +11,7 @@ use dsp::IqSource as _;
 use std::pin::Pin;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
+use trx_core::metrics::RigMetrics;
 use trx_core::radio::freq::{Band, Freq};
 use trx_core::rig::response::RigError;
 use trx_core::rig::state::{RigFilterState, SpectrumData, VchanRdsEntry, WfmDenoiseLevel};
@@ -73,6 +74,8 @@ pub struct SoapySdrConfig {
     pub nb_enabled: bool,
     /// Noise blanker impulse threshold multiplier.
     pub nb_threshold: f64,
+    /// IQ read error and DSP timing metrics for this rig.
+    pub metrics: Arc<RigMetrics>,
 }
 
 impl Default for SoapySdrConfig {
@@ -99,6 +102,7 @@ impl Default for SoapySdrConfig {
             max_virtual_channels: 4,
             nb_enabled: false,
             nb_threshold: 10.0,
+            metrics: Arc::default(),
         }
     }
 }
@@ -120,6 +124,8 @@ pub struct SoapySdrRig {
     center_offset_hz: i64,
     /// Actual hardware center frequency currently tuned on the SDR.
     center_hz: i64,
+    /// RIT offset applied to the primary channel's mixer, in Hz.
+    rit_offset_hz: i32,
     /// Used to send hardware retune commands to the IQ read loop.
     retune_cmd: Arc<std::sync::Mutex<Option<f64>>>,
     /// Current WFM deemphasis setting in microseconds.
@@ -194,6 +200,7 @@ impl SoapySdrRig {
         let max_virtual_channels = config.max_virtual_channels;
         let nb_enabled = config.nb_enabled;
         let nb_threshold = config.nb_threshold;
+        let metrics = config.metrics;
         tracing::info!(
             "initialising SoapySDR backend (args={:?}, gain_mode={:?}, gain_db={}, max_gain_db={:?})",
             args,
@@ -293,6 +300,7 @@ impl SoapySdrRig {
                 threshold: nb_threshold as f32,
             },
             &all_channels,
+            metrics,
         ));
 
         let info = RigInfo {
@@ -325,7 +333,8 @@ impl SoapySdrRig {
                 lockable: false,
                 attenuator: false,
                 preamp: false,
-                rit: false,
+                rit: true,
+                xit: false,
                 rpt: false,
                 split: false,
                 tx: false,
@@ -333,6 +342,11 @@ impl SoapySdrRig {
                 vfo_switch: false,
                 filter_controls: true,
                 signal_meter: true,
+                memory_channels: 0,
+                memory_write: false,
+                band_stack: false,
+                ctcss_decode: true,
+                dcs: false,
             },
             // No serial/TCP access for SDR devices; carry args in addr field.
             access: RigAccessMethod::Tcp {
@@ -368,6 +382,7 @@ impl SoapySdrRig {
             spectrum_buf,
             center_offset_hz,
             center_hz: hardware_center_hz,
+            rit_offset_hz: 0,
             retune_cmd,
             wfm_deemphasis_us,
             wfm_stereo: true,
@@ -439,6 +454,7 @@ impl SoapySdrRig {
             max_virtual_channels,
             nb_enabled,
             nb_threshold,
+            metrics: Arc::default(),
         })
     }
 
@@ -458,6 +474,12 @@ impl SoapySdrRig {
         self.channel_manager.clone()
     }
 
+    /// Mixer offset of the primary channel: the dial frequency plus any RIT
+    /// offset, relative to the hardware center.
+    fn primary_channel_if_hz(&self) -> f64 {
+        (self.freq.hz as i64 + i64::from(self.rit_offset_hz) - self.center_hz) as f64
+    }
+
     fn update_ais_channel_offsets(&self) {
         let Some((ais_a_idx, ais_b_idx)) = self.ais_channel_indices else {
             return;
@@ -521,6 +543,12 @@ impl SoapySdrRig {
             rx
         }
     }
+
+    /// Subscribe to the full capture-rate IQ stream, e.g. for wideband
+    /// decoders such as Mode S that need every sample of the SDR span.
+    pub fn subscribe_iq(&self) -> tokio::sync::broadcast::Receiver<Vec<num_complex::Complex<f32>>> {
+        self.pipeline.raw_iq_tx.subscribe()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -602,7 +630,7 @@ impl RigCat for SoapySdrRig {
             {
                 let dsps = self.pipeline.channel_dsps.read().unwrap();
                 if let Some(dsp_arc) = dsps.get(self.primary_channel_idx) {
-                    let channel_if_hz = (self.freq.hz as i64 - self.center_hz) as f64;
+                    let channel_if_hz = self.primary_channel_if_hz();
                     let mut dsp = dsp_arc.lock().unwrap();
                     dsp.set_channel_if_hz(channel_if_hz);
                     if freq_changed {
@@ -756,6 +784,48 @@ impl RigCat for SoapySdrRig {
         })
     }
 
+    /// Receive-only: an encode-only tone has nothing to do, a decoded tone
+    /// gates the primary channel's FM audio.
+    fn set_ctcss<'a>(
+        &'a mut self,
+        tone_hz: Option<f32>,
+        decode: bool,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move {
+            let dsps = self.pipeline.channel_dsps.read().unwrap();
+            if let Some(dsp_arc) = dsps.get(self.primary_channel_idx) {
+                dsp_arc
+                    .lock()
+                    .unwrap()
+                    .set_ctcss_squelch(tone_hz.filter(|_| decode));
+            }
+            Ok(())
+        })
+    }
+
+    /// Receive-only: RIT moves the primary channel's demodulator by the
+    /// offset; there is no transmitter for XIT to shift.
+    fn set_clarifier<'a>(
+        &'a mut self,
+        offset_hz: i32,
+        rit: bool,
+        xit: bool,
+    ) -> Pin<Box<dyn std::future::Future<Output = DynResult<()>> + Send + 'a>> {
+        Box::pin(async move {
+            if xit {
+                return Err(Box::new(RigError::not_supported("xit"))
+                    as Box<dyn std::error::Error + Send + Sync>);
+            }
+            self.rit_offset_hz = if rit { offset_hz } else { 0 };
+            let channel_if_hz = self.primary_channel_if_hz();
+            let dsps = self.pipeline.channel_dsps.read().unwrap();
+            if let Some(dsp_arc) = dsps.get(self.primary_channel_idx) {
+                dsp_arc.lock().unwrap().set_channel_if_hz(channel_if_hz);
+            }
+            Ok(())
+        })
+    }
+
     /// Override: this backend provides demodulated PCM audio.
     fn as_audio_source(&self) -> Option<&dyn AudioSource> {
         Some(self)
@@ -789,7 +859,7 @@ impl RigSdr for SoapySdrRig {
             {
                 let dsps = self.pipeline.channel_dsps.read().unwrap();
                 if let Some(dsp_arc) = dsps.get(self.primary_channel_idx) {
-                    let channel_if_hz = (self.freq.hz as i64 - self.center_hz) as f64;
+                    let channel_if_hz = self.primary_channel_if_hz();
                     dsp_arc.lock().unwrap().set_channel_if_hz(channel_if_hz);
                 }
             }
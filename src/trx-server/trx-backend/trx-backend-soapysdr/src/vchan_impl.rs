@@ -382,6 +382,10 @@ impl VirtualChannelManager for SdrVirtualChannelManager {
         self.destroyed_tx.subscribe()
     }
 
+    fn center_hz(&self) -> Option<i64> {
+        Some(self.center_hz.load(Ordering::Relaxed))
+    }
+
     fn ensure_channel_pcm(
         &self,
         id: Uuid,
@@ -439,6 +443,7 @@ mod tests {
             VirtualSquelchConfig::default(),
             NoiseBlankerConfig::default(),
             &[(0.0, RigMode::USB, 3_000)],
+            Arc::default(),
         ))
     }
 